@@ -4,8 +4,12 @@
 
 use crate::{AuthError, AuthResult, UserAuthenticationService};
 use async_trait::async_trait;
-use github_client::{create_app_client, GitHubClient};
+use github_client::{
+    create_app_client, AppPrivateKey, GitHubClient, InstallationTokenRefresher, TokenRefresher,
+};
 use secrecy::{ExposeSecret, SecretString};
+use std::path::Path;
+use std::sync::Arc;
 
 /// GitHub App authentication service
 ///
@@ -51,6 +55,50 @@ impl GitHubAuthService {
             private_key: SecretString::from(private_key.into()),
         }
     }
+
+    /// Create a new GitHub App authentication service from a validated key
+    ///
+    /// # Parameters
+    /// - `app_id`: GitHub App ID
+    /// - `private_key`: GitHub App private key
+    ///
+    /// # Returns
+    /// New `GitHubAuthService` instance
+    pub fn with_private_key(app_id: u64, private_key: AppPrivateKey) -> Self {
+        Self {
+            app_id,
+            private_key: private_key.into(),
+        }
+    }
+
+    /// Create a new GitHub App authentication service from a private key file
+    ///
+    /// # Parameters
+    /// - `app_id`: GitHub App ID
+    /// - `key_path`: Path to the GitHub App private key in PEM format
+    ///
+    /// # Errors
+    /// Returns `AuthError::Other` if the file is missing, unreadable, or does
+    /// not contain a valid PEM-encoded RSA key
+    pub fn from_key_file(app_id: u64, key_path: impl AsRef<Path>) -> AuthResult<Self> {
+        let private_key =
+            AppPrivateKey::from_file(key_path).map_err(|e| AuthError::Other(e.to_string()))?;
+        Ok(Self::with_private_key(app_id, private_key))
+    }
+
+    /// Create a new GitHub App authentication service from the key file named by
+    /// the `GITHUB_APP_PRIVATE_KEY_PATH` environment variable
+    ///
+    /// # Parameters
+    /// - `app_id`: GitHub App ID
+    ///
+    /// # Errors
+    /// Returns `AuthError::Other` if the variable is not set, or the file it
+    /// names is missing, unreadable, or not a valid PEM-encoded RSA key
+    pub fn from_env(app_id: u64) -> AuthResult<Self> {
+        let private_key = AppPrivateKey::from_env().map_err(|e| AuthError::Other(e.to_string()))?;
+        Ok(Self::with_private_key(app_id, private_key))
+    }
 }
 
 #[async_trait]
@@ -67,15 +115,35 @@ impl UserAuthenticationService for GitHubAuthService {
         let token = client
             .get_installation_token_for_org(org_name)
             .await
-            .map_err(|e| {
-                AuthError::GitHubError(format!(
+            .map_err(|e| match e {
+                github_client::Error::InstallationNotFound(org) => AuthError::AppNotInstalled(org),
+                e => AuthError::GitHubError(format!(
                     "Failed to get installation token for org '{}': {}",
                     org_name, e
-                ))
+                )),
             })?;
 
         Ok(token)
     }
+
+    async fn installation_token_refresher(
+        &self,
+        org_name: &str,
+        base_url: Option<&str>,
+    ) -> Option<Arc<dyn TokenRefresher>> {
+        // Building the App client does not call GitHub; it only fails for an
+        // invalid key, which would already have failed the token request
+        let app_client = create_app_client(self.app_id, self.private_key.expose_secret())
+            .await
+            .ok()?;
+
+        let mut refresher =
+            InstallationTokenRefresher::new(GitHubClient::new(app_client), org_name);
+        if let Some(base_url) = base_url {
+            refresher = refresher.with_base_url(base_url);
+        }
+        Some(Arc::new(refresher))
+    }
 }
 
 impl std::fmt::Debug for GitHubAuthService {
@@ -11,6 +11,8 @@
 //! - Main application wires everything together
 
 use async_trait::async_trait;
+use github_client::TokenRefresher;
+use std::sync::Arc;
 
 mod github_auth_service;
 
@@ -31,6 +33,9 @@ pub enum AuthError {
     #[error("GitHub API error: {0}")]
     GitHubError(String),
 
+    #[error("GitHub App not installed on organization '{0}'")]
+    AppNotInstalled(String),
+
     #[error("Authentication error: {0}")]
     Other(String),
 }
@@ -49,6 +54,29 @@ pub trait UserAuthenticationService: Send + Sync {
     /// Installation token with organization permissions
     ///
     /// # Errors
-    /// Returns `AuthError::GitHubError` if GitHub API fails
+    /// Returns `AuthError::AppNotInstalled` if the app is not installed on the
+    /// organization, or `AuthError::GitHubError` if GitHub API fails
     async fn get_installation_token_for_org(&self, org_name: &str) -> AuthResult<String>;
+
+    /// Get a refresher that obtains a new installation token for an organization
+    ///
+    /// Installation tokens expire one hour after they are issued. Attaching the
+    /// refresher to a `GitHubClient` lets long-running work, such as a batch of
+    /// repository creations, continue after GitHub rejects the original token.
+    ///
+    /// # Parameters
+    /// - `org_name`: Organization name
+    /// - `base_url`: GitHub API base URL for the refreshed client, if not the default
+    ///
+    /// # Returns
+    /// The refresher, or `None` if the service cannot issue new tokens. The
+    /// default implementation returns `None`.
+    async fn installation_token_refresher(
+        &self,
+        org_name: &str,
+        base_url: Option<&str>,
+    ) -> Option<Arc<dyn TokenRefresher>> {
+        let _ = (org_name, base_url);
+        None
+    }
 }
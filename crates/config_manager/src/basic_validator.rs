@@ -29,20 +29,23 @@
 use crate::{
     global_defaults::GlobalDefaults,
     merged_config::MergedConfiguration,
+    overridable::OverridableValue,
     repository_type_config::RepositoryTypeConfig,
     settings::{
         BranchProtectionSettings, EnvironmentConfig, GitHubAppConfig, PullRequestSettings,
-        RepositorySettings, WebhookConfig,
+        RepositorySettings, RuleConfig, RulesetConfig, WebhookConfig,
     },
     team_config::TeamConfig,
-    template_config::TemplateConfig as NewTemplateConfig,
+    template_config::{TemplateConfig as NewTemplateConfig, TemplateVariable},
     validator::{
         ConfigurationValidator, ValidationError, ValidationErrorType, ValidationResult,
         ValidationWarning,
     },
-    ConfigurationResult,
+    ConfigurationError, ConfigurationResult,
 };
 use async_trait::async_trait;
+use github_client::WebhookEvent;
+use std::collections::HashMap;
 
 /// Basic implementation of configuration validation.
 ///
@@ -227,6 +230,22 @@ impl BasicConfigurationValidator {
                     ),
                 });
             }
+
+            // Events loaded from combined files or interpolation are plain
+            // strings, so reject any GitHub would not accept
+            for (event_index, event) in webhook.events.iter().enumerate() {
+                if event.parse::<WebhookEvent>().is_err() {
+                    errors.push(ValidationError {
+                        error_type: ValidationErrorType::InvalidValue,
+                        field_path: format!("webhooks[{}].events[{}]", index, event_index),
+                        message: format!("Unknown webhook event: {}", event),
+                        suggestion: Some(
+                            "Use a GitHub webhook event like 'push', 'pull_request' or '*'"
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
         }
 
         errors
@@ -302,6 +321,68 @@ impl BasicConfigurationValidator {
         errors
     }
 
+    /// Validate template variable names and transforms.
+    ///
+    /// Names must be Handlebars identifiers (an ASCII letter or underscore
+    /// followed by ASCII letters, digits or underscores) and must not redefine
+    /// a built-in variable such as `repo_name` or `timestamp`. Every entry in
+    /// a variable's `transforms` must name a built-in
+    /// [`template_engine::VariableTransform`]. Errors are reported in name
+    /// order so the output is stable.
+    pub fn validate_template_variables(
+        &self,
+        variables: &HashMap<String, TemplateVariable>,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        let mut names: Vec<&String> = variables.keys().collect();
+        names.sort();
+
+        for name in names {
+            if !template_engine::is_valid_variable_name(name) {
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::InvalidValue,
+                    field_path: format!("variables.{}", name),
+                    message: format!(
+                        "Variable name '{}' is not a valid template identifier",
+                        name
+                    ),
+                    suggestion: Some(
+                        "Start with a letter or underscore and use only letters, digits and underscores"
+                            .to_string(),
+                    ),
+                });
+            } else if template_engine::is_built_in_variable_name(name) {
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::BusinessRuleViolation,
+                    field_path: format!("variables.{}", name),
+                    message: format!(
+                        "Variable name '{}' is reserved for a built-in variable",
+                        name
+                    ),
+                    suggestion: Some(
+                        "Rename the variable; built-in values are provided automatically"
+                            .to_string(),
+                    ),
+                });
+            }
+
+            let transforms = variables[name].transforms.as_deref().unwrap_or_default();
+            for (index, transform) in transforms.iter().enumerate() {
+                if let Err(message) = transform.parse::<template_engine::VariableTransform>() {
+                    errors.push(ValidationError {
+                        error_type: ValidationErrorType::InvalidValue,
+                        field_path: format!("variables.{}.transforms[{}]", name, index),
+                        message,
+                        suggestion: None,
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+
     // ========================================================================
     // Business Rule Validation Helpers
     // ========================================================================
@@ -367,6 +448,25 @@ impl BasicConfigurationValidator {
         warnings
     }
 
+    /// Generate warnings for webhooks that have no secret configured.
+    ///
+    /// Without a secret the receiver cannot verify that deliveries originate
+    /// from GitHub.
+    fn validate_webhook_secrets(&self, webhooks: &[WebhookConfig]) -> Vec<ValidationWarning> {
+        webhooks
+            .iter()
+            .enumerate()
+            .filter(|(_, webhook)| webhook.secret.as_deref().is_none_or(str::is_empty))
+            .map(|(index, _)| ValidationWarning {
+                field_path: format!("webhooks[{}].secret", index),
+                message: "Webhook has no secret configured".to_string(),
+                recommendation: Some(
+                    "Configure a secret so the receiver can verify webhook signatures".to_string(),
+                ),
+            })
+            .collect()
+    }
+
     /// Validate branch protection completeness.
     ///
     /// If branch protection is enabled, ensure it's properly configured.
@@ -398,6 +498,105 @@ impl BasicConfigurationValidator {
         errors
     }
 
+    /// Validate that at least one merge strategy remains enabled.
+    ///
+    /// GitHub treats an unset `allow_*` flag as enabled, so this only fires
+    /// when the merged configuration explicitly disables merge commits, squash
+    /// merges and rebase merges at the same time. Such a repository cannot
+    /// merge any pull request.
+    fn validate_merge_strategies_enabled(
+        &self,
+        settings: &PullRequestSettings,
+    ) -> Vec<ValidationError> {
+        if enabled_merge_methods(settings).is_empty() {
+            vec![ValidationError {
+                error_type: ValidationErrorType::BusinessRuleViolation,
+                field_path: "pull_requests".to_string(),
+                message: "All merge strategies are disabled; pull requests could never be merged"
+                    .to_string(),
+                suggestion: Some(
+                    "Set at least one of allow_merge_commit, allow_squash_merge or allow_rebase_merge to true"
+                        .to_string(),
+                ),
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Validate ruleset merge methods against the repository merge flags.
+    ///
+    /// A `pull_request` rule's `allowed_merge_methods` only takes effect for
+    /// methods the repository itself allows. Listing a method whose `allow_*`
+    /// flag is false produces a warning; when none of the listed methods is
+    /// enabled on the repository the rule blocks every merge, which is an
+    /// error.
+    fn validate_ruleset_merge_methods(
+        &self,
+        settings: &PullRequestSettings,
+        rulesets: &[RulesetConfig],
+    ) -> (Vec<ValidationError>, Vec<ValidationWarning>) {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        let enabled = enabled_merge_methods(settings);
+
+        for (ruleset_index, ruleset) in rulesets.iter().enumerate() {
+            for (rule_index, rule) in ruleset.rules.iter().enumerate() {
+                let RuleConfig::PullRequest {
+                    allowed_merge_methods: Some(methods),
+                    ..
+                } = rule
+                else {
+                    continue;
+                };
+
+                let field_path = format!(
+                    "rulesets[{}].rules[{}].allowed_merge_methods",
+                    ruleset_index, rule_index
+                );
+
+                for method in methods {
+                    if let Some(flag) = merge_method_flag(method) {
+                        if !enabled.contains(&method.as_str()) {
+                            warnings.push(ValidationWarning {
+                                field_path: field_path.clone(),
+                                message: format!(
+                                    "Ruleset '{}' allows merge method '{}' but pull_requests.{} is false",
+                                    ruleset.name, method, flag
+                                ),
+                                recommendation: Some(format!(
+                                    "Remove '{}' from allowed_merge_methods or set {} to true",
+                                    method, flag
+                                )),
+                            });
+                        }
+                    }
+                }
+
+                if !methods.iter().any(|m| enabled.contains(&m.as_str())) {
+                    errors.push(ValidationError {
+                        error_type: ValidationErrorType::BusinessRuleViolation,
+                        field_path,
+                        message: format!(
+                            "Ruleset '{}' allows no merge method that is enabled on the repository",
+                            ruleset.name
+                        ),
+                        suggestion: Some(format!(
+                            "Allow at least one of the enabled merge methods: {}",
+                            if enabled.is_empty() {
+                                "none".to_string()
+                            } else {
+                                enabled.join(", ")
+                            }
+                        )),
+                    });
+                }
+            }
+        }
+
+        (errors, warnings)
+    }
+
     // ========================================================================
     // Cross-Field Validation
     // ========================================================================
@@ -456,6 +655,7 @@ impl ConfigurationValidator for BasicConfigurationValidator {
         if let Some(webhooks) = &defaults.webhooks {
             result.add_errors(self.validate_webhooks(webhooks));
             result.add_warnings(self.validate_webhook_urls(webhooks));
+            result.add_warnings(self.validate_webhook_secrets(webhooks));
         }
 
         // Validate GitHub apps
@@ -496,6 +696,7 @@ impl ConfigurationValidator for BasicConfigurationValidator {
         if let Some(webhooks) = &config.webhooks {
             result.add_errors(self.validate_webhooks(webhooks));
             result.add_warnings(self.validate_webhook_urls(webhooks));
+            result.add_warnings(self.validate_webhook_secrets(webhooks));
         }
 
         if let Some(apps) = &config.github_apps {
@@ -534,6 +735,7 @@ impl ConfigurationValidator for BasicConfigurationValidator {
         if let Some(webhooks) = &config.webhooks {
             result.add_errors(self.validate_webhooks(webhooks));
             result.add_warnings(self.validate_webhook_urls(webhooks));
+            result.add_warnings(self.validate_webhook_secrets(webhooks));
         }
 
         if let Some(apps) = &config.github_apps {
@@ -570,6 +772,7 @@ impl ConfigurationValidator for BasicConfigurationValidator {
         if let Some(webhooks) = &config.webhooks {
             result.add_errors(self.validate_webhooks(webhooks));
             result.add_warnings(self.validate_webhook_urls(webhooks));
+            result.add_warnings(self.validate_webhook_secrets(webhooks));
         }
 
         if let Some(apps) = &config.github_apps {
@@ -580,6 +783,10 @@ impl ConfigurationValidator for BasicConfigurationValidator {
             result.add_errors(self.validate_environments(envs));
         }
 
+        if let Some(variables) = &config.variables {
+            result.add_errors(self.validate_template_variables(variables));
+        }
+
         Ok(result)
     }
 
@@ -601,14 +808,74 @@ impl ConfigurationValidator for BasicConfigurationValidator {
         result.add_errors(self.validate_security_policies(merged));
         result.add_errors(self.validate_branch_protection_completeness(&merged.branch_protection));
         result.add_errors(self.validate_conditional_requirements(merged));
+        result.add_errors(self.validate_merge_strategies_enabled(&merged.pull_requests));
+        let (merge_method_errors, merge_method_warnings) =
+            self.validate_ruleset_merge_methods(&merged.pull_requests, &merged.rulesets);
+        result.add_errors(merge_method_errors);
 
         // Warnings
         result.add_warnings(self.validate_webhook_urls(&merged.webhooks));
+        result.add_warnings(self.validate_webhook_secrets(&merged.webhooks));
+        result.add_warnings(merge_method_warnings);
 
         Ok(result)
     }
 }
 
+/// Return the ruleset merge method names the repository allows.
+///
+/// An unset `allow_*` flag counts as enabled because that is GitHub's default.
+fn enabled_merge_methods(settings: &PullRequestSettings) -> Vec<&'static str> {
+    let is_enabled =
+        |flag: &Option<OverridableValue<bool>>| flag.as_ref().is_none_or(|value| value.value);
+
+    let mut methods = Vec::new();
+    if is_enabled(&settings.allow_merge_commit) {
+        methods.push("merge");
+    }
+    if is_enabled(&settings.allow_squash_merge) {
+        methods.push("squash");
+    }
+    if is_enabled(&settings.allow_rebase_merge) {
+        methods.push("rebase");
+    }
+    methods
+}
+
+/// Map a ruleset merge method name to the pull request flag that gates it.
+fn merge_method_flag(method: &str) -> Option<&'static str> {
+    match method {
+        "merge" => Some("allow_merge_commit"),
+        "squash" => Some("allow_squash_merge"),
+        "rebase" => Some("allow_rebase_merge"),
+        _ => None,
+    }
+}
+
+/// Reject a loaded template configuration whose variable names or transforms
+/// are invalid.
+///
+/// Called by the template loaders right after parsing so a bad variable name
+/// or transform surfaces as [`ConfigurationError::ValidationFailed`] at load
+/// time rather than as a confusing rendering failure later on.
+pub(crate) fn ensure_valid_template_variable_names(
+    config: &NewTemplateConfig,
+) -> ConfigurationResult<()> {
+    let Some(variables) = &config.variables else {
+        return Ok(());
+    };
+
+    let errors = BasicConfigurationValidator::new().validate_template_variables(variables);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigurationError::ValidationFailed {
+            error_count: errors.len(),
+            errors,
+        })
+    }
+}
+
 #[cfg(test)]
 #[path = "basic_validator_tests.rs"]
 mod tests;
@@ -4,7 +4,8 @@ use super::*;
 use crate::{
     settings::{
         environment::EnvironmentProtectionRules, BranchProtectionSettings, EnvironmentConfig,
-        GitHubAppConfig, PullRequestSettings, RepositorySettings, WebhookConfig,
+        GitHubAppConfig, PullRequestSettings, RepositorySettings, RuleConfig, RulesetConfig,
+        WebhookConfig,
     },
     OverridableValue,
 };
@@ -435,6 +436,119 @@ fn test_squash_commit_title_allowed_when_squash_merges_enabled() {
     assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
 }
 
+// ============================================================================
+// Merge Strategy Validation Tests
+// ============================================================================
+
+/// Build a ruleset with a single pull_request rule allowing the given methods.
+fn pull_request_ruleset(methods: &[&str]) -> RulesetConfig {
+    RulesetConfig {
+        name: "main-protection".to_string(),
+        target: "branch".to_string(),
+        enforcement: "active".to_string(),
+        bypass_actors: vec![],
+        conditions: None,
+        rules: vec![RuleConfig::PullRequest {
+            dismiss_stale_reviews_on_push: None,
+            require_code_owner_review: None,
+            require_last_push_approval: None,
+            required_approving_review_count: None,
+            required_review_thread_resolution: None,
+            allowed_merge_methods: Some(methods.iter().map(|m| m.to_string()).collect()),
+        }],
+    }
+}
+
+/// Verify a configuration that disables every merge strategy is rejected.
+#[tokio::test]
+async fn test_validate_merged_config_all_merge_strategies_disabled() {
+    let validator = BasicConfigurationValidator::new();
+    let mut merged = MergedConfiguration::default();
+    merged.pull_requests.allow_merge_commit = Some(OverridableValue::fixed(false));
+    merged.pull_requests.allow_squash_merge = Some(OverridableValue::fixed(false));
+    merged.pull_requests.allow_rebase_merge = Some(OverridableValue::fixed(false));
+
+    let result = validator.validate_merged_config(&merged).await.unwrap();
+
+    assert!(!result.is_valid());
+    assert!(result.errors.iter().any(|e| e.field_path == "pull_requests"
+        && e.error_type == ValidationErrorType::BusinessRuleViolation
+        && e.message.contains("All merge strategies are disabled")));
+}
+
+/// Verify unset merge flags count as enabled, matching GitHub's defaults.
+#[test]
+fn test_validate_merge_strategies_unset_flags_are_enabled() {
+    let validator = BasicConfigurationValidator::new();
+    let settings = PullRequestSettings {
+        allow_merge_commit: Some(OverridableValue::fixed(false)),
+        allow_squash_merge: Some(OverridableValue::fixed(false)),
+        ..Default::default()
+    };
+
+    let errors = validator.validate_merge_strategies_enabled(&settings);
+    assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+}
+
+/// Verify a ruleset method whose allow flag is false produces a warning.
+#[test]
+fn test_validate_ruleset_merge_methods_inconsistent_pair_warns() {
+    let validator = BasicConfigurationValidator::new();
+    let settings = PullRequestSettings {
+        allow_merge_commit: Some(OverridableValue::fixed(false)),
+        ..Default::default()
+    };
+
+    let (errors, warnings) = validator
+        .validate_ruleset_merge_methods(&settings, &[pull_request_ruleset(&["merge", "squash"])]);
+
+    assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].field_path,
+        "rulesets[0].rules[0].allowed_merge_methods"
+    );
+    assert!(warnings[0].message.contains("allow_merge_commit is false"));
+}
+
+/// Verify a ruleset allowing only disabled methods is an error.
+#[test]
+fn test_validate_ruleset_merge_methods_no_enabled_method_is_error() {
+    let validator = BasicConfigurationValidator::new();
+    let settings = PullRequestSettings {
+        allow_squash_merge: Some(OverridableValue::fixed(false)),
+        ..Default::default()
+    };
+
+    let (errors, warnings) =
+        validator.validate_ruleset_merge_methods(&settings, &[pull_request_ruleset(&["squash"])]);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].error_type,
+        ValidationErrorType::BusinessRuleViolation
+    );
+    assert!(errors[0].message.contains("allows no merge method"));
+    assert_eq!(warnings.len(), 1);
+}
+
+/// Verify consistent ruleset methods and flags produce no findings.
+#[test]
+fn test_validate_ruleset_merge_methods_consistent() {
+    let validator = BasicConfigurationValidator::new();
+    let settings = PullRequestSettings {
+        allow_squash_merge: Some(OverridableValue::fixed(true)),
+        allow_merge_commit: Some(OverridableValue::fixed(false)),
+        ..Default::default()
+    };
+
+    let (errors, warnings) =
+        validator.validate_ruleset_merge_methods(&settings, &[pull_request_ruleset(&["squash"])]);
+
+    assert!(errors.is_empty());
+    assert!(warnings.is_empty());
+}
+
 // ============================================================================
 // Branch Protection Validation Tests
 // ============================================================================
@@ -449,6 +563,7 @@ fn test_validate_branch_protection_valid() {
             override_allowed: true,
         }),
         required_status_checks_list: Some(vec!["test".to_string(), "lint".to_string()]),
+        required_status_checks: None,
         require_pull_request_reviews: None,
         required_approving_review_count: None,
         restrict_pushes: None,
@@ -475,6 +590,7 @@ fn test_validate_branch_protection_empty_required_checks() {
             override_allowed: true,
         }),
         required_status_checks_list: Some(vec![]),
+        required_status_checks: None,
         require_pull_request_reviews: None,
         required_approving_review_count: None,
         restrict_pushes: None,
@@ -516,6 +632,7 @@ fn test_validate_branch_protection_completeness_missing_review_count() {
         dismiss_stale_reviews: None,
         require_code_owner_reviews: None,
         required_status_checks_list: None,
+        required_status_checks: None,
         strict_required_status_checks: None,
         additional_protected_patterns: None,
     };
@@ -598,6 +715,51 @@ fn test_validate_webhooks_empty_events() {
     assert!(errors[0].message.contains("at least one event"));
 }
 
+/// Verify unknown event names fail validation.
+#[test]
+fn test_validate_webhooks_unknown_event() {
+    let validator = BasicConfigurationValidator::new();
+    let webhooks = vec![WebhookConfig {
+        url: "https://example.com/webhook".to_string(),
+        content_type: "json".to_string(),
+        events: vec!["push".to_string(), "pull-request".to_string()],
+        active: true,
+        secret: None,
+    }];
+
+    let errors = validator.validate_webhooks(&webhooks);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidValue);
+    assert_eq!(errors[0].field_path, "webhooks[0].events[1]");
+    assert!(errors[0].message.contains("pull-request"));
+}
+
+/// Verify the merged configuration rejects webhooks with no or unknown events.
+#[tokio::test]
+async fn test_validate_merged_config_webhook_events() {
+    let validator = BasicConfigurationValidator::new();
+    let mut merged = MergedConfiguration::default();
+    for events in [vec![], vec!["*".to_string(), "deploy".to_string()]] {
+        merged.webhooks.push(WebhookConfig {
+            url: "https://example.com/webhook".to_string(),
+            content_type: "json".to_string(),
+            events,
+            active: true,
+            secret: Some("secret".to_string()),
+        });
+    }
+
+    let result = validator.validate_merged_config(&merged).await.unwrap();
+
+    let paths: Vec<&str> = result
+        .errors
+        .iter()
+        .map(|e| e.field_path.as_str())
+        .filter(|path| path.starts_with("webhooks"))
+        .collect();
+    assert_eq!(paths, vec!["webhooks[0].events", "webhooks[1].events[1]"]);
+}
+
 /// Verify HTTP webhook generates warning.
 #[test]
 fn test_validate_webhook_urls_http_warning() {
@@ -616,6 +778,33 @@ fn test_validate_webhook_urls_http_warning() {
     assert!(warnings[0].message.contains("HTTP instead of HTTPS"));
 }
 
+/// Verify webhooks without a secret generate a warning.
+#[test]
+fn test_validate_webhook_secrets_missing_secret_warning() {
+    let validator = BasicConfigurationValidator::new();
+    let webhooks = vec![
+        WebhookConfig {
+            url: "https://example.com/signed".to_string(),
+            content_type: "json".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            secret: Some("webhook-secret".to_string()),
+        },
+        WebhookConfig {
+            url: "https://example.com/unsigned".to_string(),
+            content_type: "json".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            secret: None,
+        },
+    ];
+
+    let warnings = validator.validate_webhook_secrets(&webhooks);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field_path, "webhooks[1].secret");
+    assert!(warnings[0].message.contains("no secret"));
+}
+
 // ============================================================================
 // GitHub App Validation Tests
 // ============================================================================
@@ -631,6 +820,7 @@ fn test_validate_github_apps_valid() {
     let apps = vec![GitHubAppConfig {
         app_id: 12345,
         permissions,
+        required: None,
     }];
 
     let errors = validator.validate_github_apps(&apps);
@@ -647,6 +837,7 @@ fn test_validate_github_apps_zero_app_id() {
     let apps = vec![GitHubAppConfig {
         app_id: 0,
         permissions,
+        required: None,
     }];
 
     let errors = validator.validate_github_apps(&apps);
@@ -663,6 +854,7 @@ fn test_validate_github_apps_empty_permissions() {
     let apps = vec![GitHubAppConfig {
         app_id: 12345,
         permissions: HashMap::new(),
+        required: None,
     }];
 
     let errors = validator.validate_github_apps(&apps);
@@ -856,6 +1048,9 @@ async fn test_validate_template_config_integration() {
             description: "Test template".to_string(),
             author: "Test Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -927,10 +1122,124 @@ async fn test_validate_merged_config_with_warnings() {
         content_type: "json".to_string(),
         events: vec!["push".to_string()],
         active: true,
-        secret: None,
+        secret: Some("webhook-secret".to_string()),
     });
 
     let result = validator.validate_merged_config(&merged).await.unwrap();
     assert!(result.is_valid()); // Still valid, just warnings
     assert_eq!(result.warnings.len(), 1); // One warning for HTTP
 }
+
+// ============================================================================
+// Template Variable Name Validation Tests
+// ============================================================================
+
+fn variable(description: &str) -> TemplateVariable {
+    TemplateVariable {
+        description: description.to_string(),
+        example: None,
+        required: None,
+        pattern: None,
+        min_length: None,
+        max_length: None,
+        options: None,
+        default: None,
+        required_if: None,
+        secret: None,
+        transforms: None,
+    }
+}
+
+/// Verify valid identifier names pass validation.
+#[test]
+fn test_validate_template_variables_valid_name() {
+    let validator = BasicConfigurationValidator::new();
+    let mut variables = HashMap::new();
+    variables.insert("service_name".to_string(), variable("Service name"));
+    variables.insert("_port2".to_string(), variable("Port"));
+
+    let errors = validator.validate_template_variables(&variables);
+    assert!(errors.is_empty());
+}
+
+/// Verify names that are not Handlebars identifiers fail validation.
+#[test]
+fn test_validate_template_variables_invalid_name() {
+    let validator = BasicConfigurationValidator::new();
+    let mut variables = HashMap::new();
+    variables.insert("service-name".to_string(), variable("Service name"));
+    variables.insert("2fa".to_string(), variable("Second factor"));
+
+    let errors = validator.validate_template_variables(&variables);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidValue);
+    assert_eq!(errors[0].field_path, "variables.2fa");
+    assert_eq!(errors[1].field_path, "variables.service-name");
+    assert!(errors[1]
+        .message
+        .contains("not a valid template identifier"));
+}
+
+/// Verify names that redefine a built-in variable fail validation.
+#[test]
+fn test_validate_template_variables_built_in_collision() {
+    let validator = BasicConfigurationValidator::new();
+    let mut variables = HashMap::new();
+    variables.insert("repo_name".to_string(), variable("Repository name"));
+
+    let errors = validator.validate_template_variables(&variables);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].error_type,
+        ValidationErrorType::BusinessRuleViolation
+    );
+    assert_eq!(errors[0].field_path, "variables.repo_name");
+    assert!(errors[0].message.contains("reserved"));
+}
+
+/// Verify unknown variable transforms fail validation.
+#[test]
+fn test_validate_template_variables_unknown_transform() {
+    let validator = BasicConfigurationValidator::new();
+    let mut variables = HashMap::new();
+    variables.insert(
+        "service_name".to_string(),
+        TemplateVariable {
+            transforms: Some(vec!["trim".to_string(), "title_case".to_string()]),
+            ..variable("Service name")
+        },
+    );
+
+    let errors = validator.validate_template_variables(&variables);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::InvalidValue);
+    assert_eq!(errors[0].field_path, "variables.service_name.transforms[1]");
+    assert!(errors[0].message.contains("'title_case'"));
+}
+
+/// Verify a loaded template with a bad variable name is rejected.
+#[test]
+fn test_ensure_valid_template_variable_names_rejects_loaded_config() {
+    let toml = r#"
+[template]
+name = "svc"
+description = "Service template"
+author = "Platform"
+tags = []
+
+[variables.timestamp]
+description = "Clashes with a built-in"
+"#;
+    let config: NewTemplateConfig = toml::from_str(toml).unwrap();
+
+    match ensure_valid_template_variable_names(&config) {
+        Err(ConfigurationError::ValidationFailed {
+            error_count,
+            errors,
+        }) => {
+            assert_eq!(error_count, 1);
+            assert_eq!(errors[0].field_path, "variables.timestamp");
+        }
+        other => panic!("Expected ValidationFailed, got {:?}", other),
+    }
+}
@@ -0,0 +1,300 @@
+//! Metadata provider for organizations that keep all configuration in one file.
+//!
+//! Small organizations may find the `global/`, `teams/` and `types/` layout of
+//! the metadata repository heavyweight. This module supports an alternative
+//! layout where everything lives in a single `reporoller.toml`:
+//!
+//! ```toml
+//! [global.repository]
+//! issues = { value = true, override_allowed = true }
+//!
+//! [labels.bug]
+//! color = "d73a4a"
+//! description = "Something isn't working"
+//!
+//! [[webhooks]]
+//! url = "https://ci.example.com/hook"
+//! content_type = "json"
+//! events = ["push"]
+//! active = true
+//!
+//! [teams.backend.repository]
+//! wiki = false
+//!
+//! [types.library.repository]
+//! projects = false
+//! ```
+//!
+//! The `[global]` section has the same content as `global/defaults.toml`, each
+//! `[teams.<name>]` section the same content as `teams/<name>/config.toml` and
+//! each `[types.<name>]` section the same content as `types/<name>/config.toml`.
+//! Standard labels go in `[labels.<name>]` and organization-wide webhooks in
+//! `[[webhooks]]`, mirroring `global/standard-labels.toml` and
+//! `global/webhooks.toml`.
+
+use crate::{
+    settings::WebhookConfig, ConfigurationError, ConfigurationResult, DiscoveryMethod,
+    GitHubMetadataProvider, GlobalDefaults, LabelConfig, MetadataProviderConfig,
+    MetadataRepository, MetadataRepositoryProvider, RepositoryTypeConfig, TeamConfig,
+};
+use async_trait::async_trait;
+use github_client::GitHubClient;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "combined_metadata_provider_tests.rs"]
+mod tests;
+
+/// Default path of the combined configuration file in the metadata repository.
+pub const DEFAULT_COMBINED_CONFIG_PATH: &str = "reporoller.toml";
+
+/// Organization configuration parsed from a single combined file.
+///
+/// # Examples
+///
+/// ```
+/// use config_manager::CombinedConfiguration;
+///
+/// let config = CombinedConfiguration::parse(
+///     r#"
+///     [teams.backend.repository]
+///     wiki = false
+///     "#,
+/// )
+/// .unwrap();
+/// assert!(config.teams.contains_key("backend"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct CombinedConfiguration {
+    /// Organization-wide defaults (equivalent to `global/defaults.toml`).
+    #[serde(default)]
+    pub global: GlobalDefaults,
+
+    /// Team configurations keyed by team name.
+    #[serde(default)]
+    pub teams: HashMap<String, TeamConfig>,
+
+    /// Repository type configurations keyed by type name.
+    #[serde(default)]
+    pub types: HashMap<String, RepositoryTypeConfig>,
+
+    /// Standard labels keyed by label name.
+    #[serde(default)]
+    pub labels: HashMap<String, LabelConfig>,
+
+    /// Organization-wide webhooks.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+impl CombinedConfiguration {
+    /// Parse a combined configuration file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigurationError::ParseError` if the TOML is invalid or a
+    /// section does not match its configuration type.
+    pub fn parse(content: &str) -> ConfigurationResult<Self> {
+        Self::parse_file(content, "combined configuration", None)
+    }
+
+    /// Parse a combined configuration file, naming `source` in errors and
+    /// interpolating placeholders when `variables` are given.
+    fn parse_file(
+        content: &str,
+        source: &str,
+        variables: Option<&HashMap<String, String>>,
+    ) -> ConfigurationResult<Self> {
+        let mut config: Self = crate::interpolation::parse_toml(content, source, variables)?;
+
+        // Populate the name field from the map key, as for standard-labels.toml
+        for (name, label) in config.labels.iter_mut() {
+            label.name = name.clone();
+        }
+
+        Ok(config)
+    }
+}
+
+/// Metadata provider that reads organization configuration from one file.
+///
+/// Discovery, template listing and template loading are delegated to a
+/// [`GitHubMetadataProvider`] built from the same [`MetadataProviderConfig`].
+/// The combined file is fetched once per metadata repository and every
+/// section is served from that copy.
+///
+/// # Examples
+///
+/// ```no_run
+/// use config_manager::{CombinedFileMetadataProvider, MetadataProviderConfig, MetadataRepositoryProvider};
+/// use github_client::GitHubClient;
+///
+/// # async fn example(github_client: GitHubClient) {
+/// let config = MetadataProviderConfig::explicit("org-metadata");
+/// let provider = CombinedFileMetadataProvider::new(github_client, config);
+///
+/// let repo = provider.discover_metadata_repository("my-org").await.unwrap();
+/// let defaults = provider.load_global_defaults(&repo).await.unwrap();
+/// # }
+/// ```
+pub struct CombinedFileMetadataProvider {
+    /// Provider used for discovery, file access and templates
+    inner: GitHubMetadataProvider,
+    /// Path of the combined file in the metadata repository
+    file_path: String,
+    /// Parsed combined files, keyed by `org/repo`
+    loaded: Mutex<HashMap<String, Arc<CombinedConfiguration>>>,
+}
+
+impl CombinedFileMetadataProvider {
+    /// Create a provider reading [`DEFAULT_COMBINED_CONFIG_PATH`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `config` - Discovery method configuration
+    pub fn new(client: GitHubClient, config: MetadataProviderConfig) -> Self {
+        Self {
+            inner: GitHubMetadataProvider::new(client, config),
+            file_path: DEFAULT_COMBINED_CONFIG_PATH.to_string(),
+            loaded: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Read the combined configuration from a different path.
+    pub fn with_file_path(mut self, file_path: impl Into<String>) -> Self {
+        self.file_path = file_path.into();
+        self
+    }
+
+    /// Load and cache the combined file for a metadata repository.
+    async fn combined(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<Arc<CombinedConfiguration>> {
+        let key = format!("{}/{}", repo.organization, repo.repository_name);
+        let cached = self
+            .loaded
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            .cloned();
+        if let Some(config) = cached {
+            return Ok(config);
+        }
+
+        let content = self
+            .inner
+            .read_metadata_file(repo, &self.file_path)
+            .await
+            .map_err(|e| ConfigurationError::FileAccessError {
+                path: format!("{}/{}", key, self.file_path),
+                reason: format!("{}", e),
+            })?;
+
+        let config = Arc::new(CombinedConfiguration::parse_file(
+            &content,
+            &self.file_path,
+            self.inner.interpolation_variables(),
+        )?);
+        debug!(
+            teams = config.teams.len(),
+            types = config.types.len(),
+            "Loaded combined configuration from {}/{}",
+            key,
+            self.file_path
+        );
+
+        self.loaded
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, config.clone());
+        Ok(config)
+    }
+}
+
+#[async_trait]
+impl MetadataRepositoryProvider for CombinedFileMetadataProvider {
+    async fn discover_metadata_repository(
+        &self,
+        org: &str,
+    ) -> ConfigurationResult<MetadataRepository> {
+        let mut repo = self.inner.discover_metadata_repository(org).await?;
+        repo.discovery_method = DiscoveryMethod::CombinedFile {
+            repository_name: repo.repository_name.clone(),
+            file_path: self.file_path.clone(),
+        };
+        Ok(repo)
+    }
+
+    async fn load_global_defaults(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<GlobalDefaults> {
+        Ok(self.combined(repo).await?.global.clone())
+    }
+
+    async fn load_team_configuration(
+        &self,
+        repo: &MetadataRepository,
+        team: &str,
+    ) -> ConfigurationResult<Option<TeamConfig>> {
+        Ok(self.combined(repo).await?.teams.get(team).cloned())
+    }
+
+    async fn load_repository_type_configuration(
+        &self,
+        repo: &MetadataRepository,
+        repo_type: &str,
+    ) -> ConfigurationResult<Option<RepositoryTypeConfig>> {
+        Ok(self.combined(repo).await?.types.get(repo_type).cloned())
+    }
+
+    async fn load_standard_labels(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<HashMap<String, LabelConfig>> {
+        Ok(self.combined(repo).await?.labels.clone())
+    }
+
+    async fn load_global_webhooks(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<Vec<WebhookConfig>> {
+        Ok(self.combined(repo).await?.webhooks.clone())
+    }
+
+    async fn list_available_repository_types(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<Vec<String>> {
+        let mut types: Vec<String> = self.combined(repo).await?.types.keys().cloned().collect();
+        types.sort();
+        Ok(types)
+    }
+
+    async fn validate_repository_structure(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<()> {
+        self.inner.validate_repository_structure(repo).await?;
+        self.combined(repo).await.map(|_| ())
+    }
+
+    async fn list_templates(&self, org: &str) -> ConfigurationResult<Vec<String>> {
+        self.inner.list_templates(org).await
+    }
+
+    async fn load_template_configuration(
+        &self,
+        org: &str,
+        template_name: &str,
+    ) -> ConfigurationResult<crate::template_config::TemplateConfig> {
+        self.inner
+            .load_template_configuration(org, template_name)
+            .await
+    }
+}
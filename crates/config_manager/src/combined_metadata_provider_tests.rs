@@ -0,0 +1,224 @@
+//! Tests for the combined-file metadata provider.
+
+use super::*;
+use chrono::Utc;
+
+const GLOBAL_DEFAULTS: &str = r#"
+[repository]
+issues = { value = true, override_allowed = true }
+wiki = { value = false, override_allowed = false }
+
+[pull_requests]
+required_approving_review_count = { value = 1, override_allowed = true }
+"#;
+
+const STANDARD_LABELS: &str = r#"
+[bug]
+color = "d73a4a"
+description = "Something isn't working"
+"#;
+
+const WEBHOOKS: &str = r#"
+[[webhooks]]
+url = "https://ci.example.com/hook"
+content_type = "json"
+events = ["push"]
+active = true
+"#;
+
+const TEAM: &str = r#"
+[repository]
+discussions = false
+"#;
+
+const TYPE: &str = r#"
+[repository]
+projects = false
+"#;
+
+const COMBINED: &str = r#"
+[global.repository]
+issues = { value = true, override_allowed = true }
+wiki = { value = false, override_allowed = false }
+
+[global.pull_requests]
+required_approving_review_count = { value = 1, override_allowed = true }
+
+[labels.bug]
+color = "d73a4a"
+description = "Something isn't working"
+
+[[webhooks]]
+url = "https://ci.example.com/hook"
+content_type = "json"
+events = ["push"]
+active = true
+
+[teams.platform.repository]
+discussions = false
+
+[types.service.repository]
+projects = false
+"#;
+
+fn metadata_repository() -> MetadataRepository {
+    MetadataRepository {
+        organization: "test-org".to_string(),
+        repository_name: ".reporoller".to_string(),
+        discovery_method: DiscoveryMethod::ConfigurationBased {
+            repository_name: ".reporoller".to_string(),
+        },
+        last_updated: Utc::now(),
+    }
+}
+
+fn client_for(mock_server: &wiremock::MockServer) -> GitHubClient {
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    GitHubClient::new(octocrab)
+}
+
+/// Build a mock serving `content` from the contents API at `file_path`.
+fn file_mock(file_path: &str, content: &str) -> wiremock::Mock {
+    use base64::Engine as _;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let api_url = format!("https://api.github.com/repos/test-org/.reporoller/contents/{file_path}");
+    let html_url = format!("https://github.com/test-org/.reporoller/blob/main/{file_path}");
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/test-org/.reporoller/contents/{file_path}"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": file_path.rsplit('/').next().unwrap(),
+            "path": file_path,
+            "sha": "abc123def456",
+            "size": content.len(),
+            "type": "file",
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+            "encoding": "base64",
+            "url": api_url,
+            "html_url": html_url,
+            "git_url": null,
+            "download_url": null,
+            "_links": { "self": api_url, "git": null, "html": html_url }
+        })))
+}
+
+/// Load every organization-level configuration through a provider.
+async fn load_all(
+    provider: &dyn MetadataRepositoryProvider,
+) -> (
+    GlobalDefaults,
+    HashMap<String, LabelConfig>,
+    Vec<WebhookConfig>,
+    Option<TeamConfig>,
+    Option<RepositoryTypeConfig>,
+) {
+    let repo = metadata_repository();
+    (
+        provider.load_global_defaults(&repo).await.unwrap(),
+        provider.load_standard_labels(&repo).await.unwrap(),
+        provider.load_global_webhooks(&repo).await.unwrap(),
+        provider
+            .load_team_configuration(&repo, "platform")
+            .await
+            .unwrap(),
+        provider
+            .load_repository_type_configuration(&repo, "service")
+            .await
+            .unwrap(),
+    )
+}
+
+/// Verify each section parses to the same value as its standalone file.
+#[test]
+fn test_combined_sections_match_standalone_files() {
+    let combined = CombinedConfiguration::parse(COMBINED).unwrap();
+
+    let global: GlobalDefaults = toml::from_str(GLOBAL_DEFAULTS).unwrap();
+    let team: TeamConfig = toml::from_str(TEAM).unwrap();
+    let repo_type: RepositoryTypeConfig = toml::from_str(TYPE).unwrap();
+
+    assert_eq!(combined.global, global);
+    assert_eq!(combined.teams.get("platform"), Some(&team));
+    assert_eq!(combined.types.get("service"), Some(&repo_type));
+    assert_eq!(combined.labels["bug"].name, "bug");
+    assert_eq!(combined.webhooks.len(), 1);
+}
+
+/// Verify an empty combined file yields empty configuration.
+#[test]
+fn test_empty_combined_file() {
+    let combined = CombinedConfiguration::parse("").unwrap();
+    assert_eq!(combined, CombinedConfiguration::default());
+}
+
+/// Verify invalid sections are reported as parse errors.
+#[test]
+fn test_invalid_combined_file() {
+    let result = CombinedConfiguration::parse("[teams]\nplatform = 3\n");
+    assert!(matches!(result, Err(ConfigurationError::ParseError { .. })));
+}
+
+/// Verify the combined provider yields the same configuration as the
+/// multi-file layout, and fetches the combined file only once.
+#[tokio::test]
+async fn test_combined_provider_matches_multi_file_provider() {
+    use wiremock::MockServer;
+
+    let multi_server = MockServer::start().await;
+    file_mock("global/defaults.toml", GLOBAL_DEFAULTS)
+        .mount(&multi_server)
+        .await;
+    file_mock("global/standard-labels.toml", STANDARD_LABELS)
+        .mount(&multi_server)
+        .await;
+    file_mock("global/webhooks.toml", WEBHOOKS)
+        .mount(&multi_server)
+        .await;
+    file_mock("teams/platform/config.toml", TEAM)
+        .mount(&multi_server)
+        .await;
+    file_mock("types/service/config.toml", TYPE)
+        .mount(&multi_server)
+        .await;
+    let multi_provider = GitHubMetadataProvider::new(
+        client_for(&multi_server),
+        MetadataProviderConfig::explicit(".reporoller"),
+    );
+
+    let combined_server = MockServer::start().await;
+    file_mock(DEFAULT_COMBINED_CONFIG_PATH, COMBINED)
+        .expect(1)
+        .mount(&combined_server)
+        .await;
+    let combined_provider = CombinedFileMetadataProvider::new(
+        client_for(&combined_server),
+        MetadataProviderConfig::explicit(".reporoller"),
+    );
+
+    let multi = load_all(&multi_provider).await;
+    let combined = load_all(&combined_provider).await;
+
+    assert_eq!(combined, multi);
+    assert!(combined.3.is_some());
+    assert!(combined.4.is_some());
+
+    let types = combined_provider
+        .list_available_repository_types(&metadata_repository())
+        .await
+        .unwrap();
+    assert_eq!(types, vec!["service".to_string()]);
+
+    let missing_team = combined_provider
+        .load_team_configuration(&metadata_repository(), "unknown")
+        .await
+        .unwrap();
+    assert!(missing_team.is_none());
+}
@@ -0,0 +1,153 @@
+//! Effective configuration diff.
+//!
+//! Compares two [`MergedConfiguration`]s resolved for the same inputs (for
+//! example, before and after a change to the metadata repository) and reports
+//! every setting whose effective value changed. This lets platform teams see
+//! which settings future repositories would receive differently.
+//!
+//! # Field Paths
+//!
+//! Changes are reported per leaf setting using dot-separated paths that match
+//! the serialized form of [`MergedConfiguration`]. Overridable settings have
+//! separate `.value` and `.override_allowed` leaves, so a setting that becomes
+//! fixed is reported as a change to `<setting>.override_allowed`. Lists (such
+//! as webhooks) are compared as a whole.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use config_manager::{diff_configurations, MergedConfiguration, OverridableValue};
+//!
+//! let old = MergedConfiguration::new();
+//! let mut new = MergedConfiguration::new();
+//! new.repository.wiki = Some(OverridableValue::fixed(false));
+//!
+//! let diff = diff_configurations(&old, &new);
+//! assert_eq!(diff.changes.len(), 2);
+//! assert_eq!(diff.changes[0].field_path, "repository.wiki.override_allowed");
+//! ```
+
+use crate::merged_config::{ConfigurationSource, MergedConfiguration};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+#[path = "config_diff_tests.rs"]
+mod tests;
+
+/// A single setting whose effective value differs between two configurations.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigurationChange {
+    /// Dot-separated path of the changed setting.
+    pub field_path: String,
+
+    /// Value in the old configuration (`None` when the setting was not set).
+    pub old_value: Option<Value>,
+
+    /// Value in the new configuration (`None` when the setting is no longer set).
+    pub new_value: Option<Value>,
+
+    /// Configuration level that provided the old value, when known.
+    pub old_source: Option<ConfigurationSource>,
+
+    /// Configuration level that provides the new value, when known.
+    pub new_source: Option<ConfigurationSource>,
+}
+
+/// Structured difference between two merged configurations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConfigurationDiff {
+    /// Changed settings, sorted by field path.
+    pub changes: Vec<ConfigurationChange>,
+}
+
+impl ConfigurationDiff {
+    /// Returns `true` when both configurations are effectively identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the change for the given field path, if it changed.
+    pub fn change_for(&self, field_path: &str) -> Option<&ConfigurationChange> {
+        self.changes.iter().find(|c| c.field_path == field_path)
+    }
+}
+
+/// Computes the effective difference between two merged configurations.
+///
+/// Source attribution is taken from each configuration's source trace, using
+/// the closest traced ancestor of the changed field.
+pub fn diff_configurations(
+    old: &MergedConfiguration,
+    new: &MergedConfiguration,
+) -> ConfigurationDiff {
+    let old_fields = flatten_configuration(old);
+    let new_fields = flatten_configuration(new);
+
+    let mut paths: Vec<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    let changes = paths
+        .into_iter()
+        .filter_map(|path| {
+            let old_value = old_fields.get(path);
+            let new_value = new_fields.get(path);
+            if old_value == new_value {
+                return None;
+            }
+            Some(ConfigurationChange {
+                field_path: path.clone(),
+                old_value: old_value.cloned(),
+                new_value: new_value.cloned(),
+                old_source: old_value.and_then(|_| traced_source(old, path)),
+                new_source: new_value.and_then(|_| traced_source(new, path)),
+            })
+        })
+        .collect();
+
+    ConfigurationDiff { changes }
+}
+
+/// Flattens the serialized configuration into `path -> leaf value` pairs.
+///
+/// Null values are omitted so that unset settings compare as absent.
+fn flatten_configuration(config: &MergedConfiguration) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    // Serializing plain data (string-keyed maps, no custom serializers) cannot fail.
+    if let Ok(value) = serde_json::to_value(config) {
+        flatten_value("", value, &mut fields);
+    }
+    fields
+}
+
+fn flatten_value(prefix: &str, value: Value, fields: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Null => {}
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_value(&path, child, fields);
+            }
+        }
+        leaf => {
+            fields.insert(prefix.to_string(), leaf);
+        }
+    }
+}
+
+/// Finds the source of the closest traced ancestor of `field_path`.
+fn traced_source(config: &MergedConfiguration, field_path: &str) -> Option<ConfigurationSource> {
+    let mut path = field_path;
+    loop {
+        if let Some(source) = config.get_source(path) {
+            return Some(source);
+        }
+        path = &path[..path.rfind('.')?];
+    }
+}
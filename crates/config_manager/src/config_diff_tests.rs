@@ -0,0 +1,94 @@
+//! Tests for effective configuration diffs.
+
+use super::*;
+use crate::OverridableValue;
+use serde_json::json;
+
+#[test]
+fn test_diff_identical_configurations_is_empty() {
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(OverridableValue::allowed(true));
+
+    let diff = diff_configurations(&config, &config.clone());
+
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_diff_branch_protection_flipped_from_overridable_to_fixed() {
+    let mut old = MergedConfiguration::new();
+    old.branch_protection.require_pull_request_reviews = Some(OverridableValue::allowed(true));
+    old.record_source(
+        "branch_protection.require_pull_request_reviews",
+        ConfigurationSource::Global,
+    );
+
+    let mut new = MergedConfiguration::new();
+    new.branch_protection.require_pull_request_reviews = Some(OverridableValue::fixed(true));
+    new.record_source(
+        "branch_protection.require_pull_request_reviews",
+        ConfigurationSource::Global,
+    );
+
+    let diff = diff_configurations(&old, &new);
+
+    assert_eq!(diff.changes.len(), 1, "only override_allowed should change");
+    let change = &diff.changes[0];
+    assert_eq!(
+        change.field_path,
+        "branch_protection.require_pull_request_reviews.override_allowed"
+    );
+    assert_eq!(change.old_value, Some(json!(true)));
+    assert_eq!(change.new_value, Some(json!(false)));
+    assert_eq!(change.old_source, Some(ConfigurationSource::Global));
+    assert_eq!(change.new_source, Some(ConfigurationSource::Global));
+    assert!(diff
+        .change_for("branch_protection.require_pull_request_reviews.value")
+        .is_none());
+}
+
+#[test]
+fn test_diff_reports_added_and_removed_settings() {
+    let mut old = MergedConfiguration::new();
+    old.repository.wiki = Some(OverridableValue::allowed(true));
+
+    let mut new = MergedConfiguration::new();
+    new.pull_requests.required_approving_review_count = Some(OverridableValue::allowed(2));
+    new.record_source(
+        "pull_requests.required_approving_review_count",
+        ConfigurationSource::Template,
+    );
+
+    let diff = diff_configurations(&old, &new);
+
+    let removed = diff
+        .change_for("repository.wiki.value")
+        .expect("wiki removal should be reported");
+    assert_eq!(removed.old_value, Some(json!(true)));
+    assert_eq!(removed.new_value, None);
+    assert_eq!(removed.new_source, None);
+
+    let added = diff
+        .change_for("pull_requests.required_approving_review_count.value")
+        .expect("review count addition should be reported");
+    assert_eq!(added.old_value, None);
+    assert_eq!(added.new_value, Some(json!(2)));
+    assert_eq!(added.old_source, None);
+    assert_eq!(added.new_source, Some(ConfigurationSource::Template));
+}
+
+#[test]
+fn test_diff_changes_are_sorted_by_field_path() {
+    let old = MergedConfiguration::new();
+    let mut new = MergedConfiguration::new();
+    new.repository.wiki = Some(OverridableValue::fixed(false));
+    new.branch_protection.require_pull_request_reviews = Some(OverridableValue::fixed(true));
+
+    let diff = diff_configurations(&old, &new);
+
+    let paths: Vec<&str> = diff.changes.iter().map(|c| c.field_path.as_str()).collect();
+    let mut sorted = paths.clone();
+    sorted.sort();
+    assert_eq!(paths, sorted);
+    assert_eq!(paths.len(), 4);
+}
@@ -2,7 +2,7 @@
 //!
 //! This module provides the `ConfigurationContext` type which carries metadata
 //! about a configuration resolution request. The context includes the organization,
-//! template, and optional team and repository type information.
+//! template, and optional team(s) and repository type information.
 //!
 //! # Examples
 //!
@@ -63,8 +63,8 @@ pub struct ConfigurationContext {
     /// The template name/identifier.
     template: String,
 
-    /// Optional team name for team-specific configuration.
-    team: Option<String>,
+    /// Team names for team-specific configuration, in precedence order.
+    teams: Vec<String>,
 
     /// Optional repository type for type-specific configuration.
     repository_type: Option<String>,
@@ -94,7 +94,7 @@ impl ConfigurationContext {
         Self {
             organization: organization.into(),
             template: template.into(),
-            team: None,
+            teams: Vec::new(),
             repository_type: None,
             created_at: Utc::now(),
         }
@@ -102,7 +102,9 @@ impl ConfigurationContext {
 
     /// Adds team information to the context.
     ///
-    /// Uses builder pattern for ergonomic chaining.
+    /// Uses builder pattern for ergonomic chaining. Calling this more than once
+    /// adds further teams for repositories owned by several teams; see
+    /// [`ConfigurationContext::with_teams`].
     ///
     /// # Arguments
     ///
@@ -118,10 +120,44 @@ impl ConfigurationContext {
     /// assert_eq!(context.team(), Some("backend-team"));
     /// ```
     pub fn with_team(mut self, team: impl Into<String>) -> Self {
-        self.team = Some(team.into());
+        let team = team.into();
+        if !self.teams.contains(&team) {
+            self.teams.push(team);
+        }
         self
     }
 
+    /// Adds several teams to the context.
+    ///
+    /// The configurations of all teams are combined in the given order before
+    /// being merged: additive collections (webhooks, apps, environments, etc.)
+    /// are unioned, and override settings may be set by at most one team unless
+    /// all teams agree on the value. Duplicate team names are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `teams` - The team names, in request order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::ConfigurationContext;
+    ///
+    /// let context = ConfigurationContext::new("my-org", "rust-service")
+    ///     .with_teams(["backend-team", "platform-team"]);
+    /// assert_eq!(context.team(), Some("backend-team"));
+    /// assert_eq!(context.teams(), ["backend-team", "platform-team"]);
+    /// ```
+    pub fn with_teams<I, S>(self, teams: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        teams
+            .into_iter()
+            .fold(self, |context, team| context.with_team(team))
+    }
+
     /// Adds repository type information to the context.
     ///
     /// Uses builder pattern for ergonomic chaining.
@@ -172,7 +208,7 @@ impl ConfigurationContext {
         &self.template
     }
 
-    /// Gets the team name if specified.
+    /// Gets the first team name if any team was specified.
     ///
     /// # Examples
     ///
@@ -186,7 +222,21 @@ impl ConfigurationContext {
     /// assert_eq!(context.team(), Some("backend-team"));
     /// ```
     pub fn team(&self) -> Option<&str> {
-        self.team.as_deref()
+        self.teams.first().map(String::as_str)
+    }
+
+    /// Gets all team names, in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::ConfigurationContext;
+    ///
+    /// let context = ConfigurationContext::new("my-org", "rust-service");
+    /// assert!(context.teams().is_empty());
+    /// ```
+    pub fn teams(&self) -> &[String] {
+        &self.teams
     }
 
     /// Gets the repository type if specified.
@@ -190,3 +190,14 @@ fn test_context_with_long_strings() {
     assert_eq!(context.template().len(), 1000);
     assert_eq!(context.team().map(|s| s.len()), Some(1000));
 }
+
+/// Verify multiple teams are kept in request order without duplicates.
+#[test]
+fn test_context_with_multiple_teams() {
+    let context = ConfigurationContext::new("my-org", "rust-service")
+        .with_teams(["backend-team", "platform-team"])
+        .with_team("backend-team");
+
+    assert_eq!(context.team(), Some("backend-team"));
+    assert_eq!(context.teams(), ["backend-team", "platform-team"]);
+}
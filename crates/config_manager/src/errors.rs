@@ -94,6 +94,22 @@ pub enum ConfigurationError {
         /// Human-readable label for the level that locked the entry (e.g. `"org"`).
         context: String,
     },
+
+    /// Two or more teams owning the same repository set different values for
+    /// the same override setting.
+    ///
+    /// Each entry in `errors` describes one conflicting setting.
+    #[error("Owning teams set conflicting values for {} setting(s)", .errors.len())]
+    TeamConfigurationConflict {
+        /// One validation error per conflicting setting.
+        errors: Vec<ValidationError>,
+    },
+
+    /// The organization does not permit repositories to be created from the
+    /// requested template (see `allowed_templates`/`denied_templates` in the
+    /// global defaults).
+    #[error("Template '{template}' is not allowed in organization '{org}'")]
+    TemplateNotAllowed { org: String, template: String },
 }
 
 /// Result type alias for configuration operations.
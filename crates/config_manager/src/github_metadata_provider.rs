@@ -10,8 +10,11 @@ use crate::{
 };
 use async_trait::async_trait;
 use chrono::Utc;
+use futures::StreamExt;
 use github_client::{GitHubClient, RepositoryClient};
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
 // Reference the tests module in the separate file
@@ -19,6 +22,15 @@ use tracing::{debug, error, info, warn};
 #[path = "github_metadata_provider_tests.rs"]
 mod tests;
 
+/// Default number of metadata files fetched at the same time.
+const DEFAULT_MAX_CONCURRENT_FETCHES: usize = 4;
+
+/// Default delay before retrying a throttled or failed metadata file fetch.
+const DEFAULT_FETCH_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Number of attempts made for each metadata file before giving up.
+const MAX_FETCH_ATTEMPTS: u32 = 3;
+
 /// Configuration for metadata repository discovery.
 ///
 /// **This is an input/configuration type** that specifies how the provider
@@ -47,6 +59,16 @@ mod tests;
 pub struct MetadataProviderConfig {
     /// Discovery method configuration
     discovery: DiscoveryConfig,
+    /// Timeout applied to each GitHub API operation made by the provider
+    request_timeout: Option<Duration>,
+    /// Git ref (branch, tag or commit SHA) to read metadata files from
+    git_ref: Option<String>,
+    /// Maximum number of metadata files fetched at the same time
+    max_concurrent_fetches: usize,
+    /// Initial delay before retrying a failed metadata file fetch
+    fetch_backoff: Duration,
+    /// Values for `${KEY}` placeholders in metadata files; `None` disables interpolation
+    interpolation_variables: Option<HashMap<String, String>>,
 }
 
 /// Internal configuration enum for discovery strategy.
@@ -79,6 +101,11 @@ impl MetadataProviderConfig {
     pub fn explicit(repository_name: impl Into<String>) -> Self {
         Self {
             discovery: DiscoveryConfig::RepositoryName(repository_name.into()),
+            request_timeout: None,
+            git_ref: None,
+            max_concurrent_fetches: DEFAULT_MAX_CONCURRENT_FETCHES,
+            fetch_backoff: DEFAULT_FETCH_BACKOFF,
+            interpolation_variables: None,
         }
     }
 
@@ -98,8 +125,156 @@ impl MetadataProviderConfig {
     pub fn by_topic(topic: impl Into<String>) -> Self {
         Self {
             discovery: DiscoveryConfig::Topic(topic.into()),
+            request_timeout: None,
+            git_ref: None,
+            max_concurrent_fetches: DEFAULT_MAX_CONCURRENT_FETCHES,
+            fetch_backoff: DEFAULT_FETCH_BACKOFF,
+            interpolation_variables: None,
         }
     }
+
+    /// Set the timeout applied to each GitHub API operation made by the provider.
+    ///
+    /// When not set, the timeout already configured on the `GitHubClient` is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum duration of a single GitHub API operation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::MetadataProviderConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = MetadataProviderConfig::explicit("org-metadata")
+    ///     .with_request_timeout(Duration::from_secs(10));
+    /// assert_eq!(config.request_timeout(), Some(Duration::from_secs(10)));
+    /// ```
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the configured per-operation GitHub API timeout, if any.
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Read metadata repository files at the given git ref instead of the
+    /// default branch.
+    ///
+    /// The ref may be a branch, which allows configuration staged on a branch
+    /// (e.g. `config-staging`) to be previewed before it is merged, or a tag or
+    /// commit, which is useful for comparing the configuration produced by two
+    /// metadata commits. Template repositories are always read at their
+    /// default branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - Branch, tag or commit SHA in the metadata repository
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::MetadataProviderConfig;
+    ///
+    /// let config = MetadataProviderConfig::explicit("org-metadata").with_git_ref("v2");
+    /// assert_eq!(config.git_ref(), Some("v2"));
+    ///
+    /// let staged = MetadataProviderConfig::explicit("org-metadata").with_git_ref("config-staging");
+    /// assert_eq!(staged.git_ref(), Some("config-staging"));
+    /// ```
+    pub fn with_git_ref(mut self, git_ref: impl Into<String>) -> Self {
+        self.git_ref = Some(git_ref.into());
+        self
+    }
+
+    /// Get the git ref metadata files are read from, if one is configured.
+    pub fn git_ref(&self) -> Option<&str> {
+        self.git_ref.as_deref()
+    }
+
+    /// Set how many metadata files may be fetched at the same time when they
+    /// are read individually rather than in bulk.
+    ///
+    /// Values below one are treated as one, which fetches the files
+    /// sequentially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::MetadataProviderConfig;
+    ///
+    /// let config = MetadataProviderConfig::explicit("org-metadata")
+    ///     .with_max_concurrent_fetches(8);
+    /// assert_eq!(config.max_concurrent_fetches(), 8);
+    /// ```
+    pub fn with_max_concurrent_fetches(mut self, limit: usize) -> Self {
+        self.max_concurrent_fetches = limit.max(1);
+        self
+    }
+
+    /// Get the maximum number of metadata files fetched at the same time.
+    pub fn max_concurrent_fetches(&self) -> usize {
+        self.max_concurrent_fetches
+    }
+
+    /// Set the initial delay before a throttled or failed metadata file fetch
+    /// is retried.
+    ///
+    /// The delay doubles with each retry and is shared by all fetches in
+    /// flight, so a rate-limited API is not hit by every pending request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::MetadataProviderConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = MetadataProviderConfig::explicit("org-metadata")
+    ///     .with_fetch_backoff(Duration::from_millis(250));
+    /// assert_eq!(config.fetch_backoff(), Duration::from_millis(250));
+    /// ```
+    pub fn with_fetch_backoff(mut self, backoff: Duration) -> Self {
+        self.fetch_backoff = backoff;
+        self
+    }
+
+    /// Get the initial delay before a failed metadata file fetch is retried.
+    pub fn fetch_backoff(&self) -> Duration {
+        self.fetch_backoff
+    }
+
+    /// Replace `${KEY}` placeholders in the string values of metadata files
+    /// with values from the given map.
+    ///
+    /// Applies to global defaults, standard labels, global webhooks, and team
+    /// and repository type configurations, whether read from separate files or
+    /// a combined file. Values are never read from the process environment,
+    /// and a placeholder without a value fails the load.
+    /// When not set, placeholders are kept verbatim.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_manager::MetadataProviderConfig;
+    /// use std::collections::HashMap;
+    ///
+    /// let variables = HashMap::from([("ENV".to_string(), "staging".to_string())]);
+    /// let config = MetadataProviderConfig::explicit("org-metadata")
+    ///     .with_interpolation_variables(variables);
+    /// assert!(config.interpolation_variables().is_some());
+    /// ```
+    pub fn with_interpolation_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.interpolation_variables = Some(variables);
+        self
+    }
+
+    /// Get the values used for `${KEY}` placeholders, if interpolation is enabled.
+    pub fn interpolation_variables(&self) -> Option<&HashMap<String, String>> {
+        self.interpolation_variables.as_ref()
+    }
 }
 
 /// GitHub-based metadata repository provider.
@@ -133,6 +308,11 @@ pub struct GitHubMetadataProvider {
     client: GitHubClient,
     /// Discovery configuration
     config: MetadataProviderConfig,
+    /// Metadata file contents fetched ahead of the loaders, keyed by `org/repo/path`.
+    ///
+    /// Each entry is consumed by the first read of that file. `None` records a
+    /// file that is known not to exist.
+    prefetched: Mutex<HashMap<String, Option<String>>>,
 }
 
 impl GitHubMetadataProvider {
@@ -155,7 +335,144 @@ impl GitHubMetadataProvider {
     /// # }
     /// ```
     pub fn new(client: GitHubClient, config: MetadataProviderConfig) -> Self {
-        Self { client, config }
+        let client = match config.request_timeout {
+            Some(timeout) => client.with_timeout(timeout),
+            None => client,
+        };
+        Self {
+            client,
+            config,
+            prefetched: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Read a metadata repository file, using prefetched content when available.
+    ///
+    /// Falls back to a REST request when the file was not prefetched. A leading
+    /// UTF-8 byte order mark is removed from the returned content.
+    pub(crate) async fn read_metadata_file(
+        &self,
+        repo: &MetadataRepository,
+        file_path: &str,
+    ) -> Result<String, github_client::Error> {
+        let key = prefetch_key(repo, file_path);
+        let prefetched = self
+            .prefetched
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key);
+        if let Some(content) = prefetched {
+            debug!(path = %key, "Using prefetched metadata file");
+            return content
+                .map(strip_utf8_bom)
+                .ok_or(github_client::Error::NotFound);
+        }
+
+        self.client
+            .get_file_content_at_ref(
+                &repo.organization,
+                &repo.repository_name,
+                file_path,
+                self.config.git_ref(),
+            )
+            .await
+            .map(strip_utf8_bom)
+    }
+
+    /// Get the values used for `${KEY}` placeholders, if interpolation is enabled.
+    pub(crate) fn interpolation_variables(&self) -> Option<&HashMap<String, String>> {
+        self.config.interpolation_variables()
+    }
+
+    /// Parse a metadata file, interpolating placeholders when enabled.
+    fn parse_metadata_file<T: serde::de::DeserializeOwned>(
+        &self,
+        content: &str,
+        file_path: &str,
+    ) -> ConfigurationResult<T> {
+        crate::interpolation::parse_toml(content, file_path, self.interpolation_variables())
+    }
+
+    /// Fetch metadata files individually and record them as prefetched.
+    ///
+    /// At most `max_concurrent_fetches` requests are in flight at once. Results
+    /// are recorded in the order of `paths` regardless of which request
+    /// completes first. Missing files are recorded as absent; any other failure
+    /// is logged for that file and left unrecorded, so the later read retries
+    /// it and reports the error through the usual loader.
+    async fn fetch_files_concurrently(&self, repo: &MetadataRepository, paths: &[String]) {
+        let backoff = SharedBackoff::default();
+        let results: Vec<(&String, Result<String, github_client::Error>)> =
+            futures::stream::iter(paths)
+                .map(|file_path| {
+                    let backoff = &backoff;
+                    async move {
+                        let result = self.fetch_with_backoff(repo, file_path, backoff).await;
+                        (file_path, result)
+                    }
+                })
+                .buffered(self.config.max_concurrent_fetches)
+                .collect()
+                .await;
+
+        let mut prefetched = self.prefetched.lock().unwrap_or_else(|e| e.into_inner());
+        for (file_path, result) in results {
+            match result {
+                Ok(content) => {
+                    prefetched.insert(prefetch_key(repo, file_path), Some(content));
+                }
+                Err(github_client::Error::NotFound) => {
+                    prefetched.insert(prefetch_key(repo, file_path), None);
+                }
+                Err(e) => {
+                    warn!(
+                        path = %file_path,
+                        error = %e,
+                        "Failed to fetch metadata file, it will be read again when loaded"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fetch a single metadata file, retrying throttled or failed requests.
+    ///
+    /// Retries wait on the shared backoff, so one throttled request delays
+    /// every concurrent fetch rather than each discovering the limit itself.
+    async fn fetch_with_backoff(
+        &self,
+        repo: &MetadataRepository,
+        file_path: &str,
+        backoff: &SharedBackoff,
+    ) -> Result<String, github_client::Error> {
+        let mut attempt = 0;
+        loop {
+            backoff.wait().await;
+            let result = self
+                .client
+                .get_file_content_at_ref(
+                    &repo.organization,
+                    &repo.repository_name,
+                    file_path,
+                    self.config.git_ref(),
+                )
+                .await;
+
+            match result {
+                Err(e) if is_retryable_fetch_error(&e) && attempt + 1 < MAX_FETCH_ATTEMPTS => {
+                    let delay = self.config.fetch_backoff * 2u32.pow(attempt);
+                    debug!(
+                        path = %file_path,
+                        attempt = attempt + 1,
+                        delay_ms = delay.as_millis() as u64,
+                        "Metadata file fetch failed, backing off before retrying"
+                    );
+                    backoff.extend(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
     /// Discover repository using configuration-based method.
@@ -286,8 +603,7 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
     ) -> ConfigurationResult<GlobalDefaults> {
         let file_path = "global/defaults.toml";
         let content = self
-            .client
-            .get_file_content(&repo.organization, &repo.repository_name, file_path)
+            .read_metadata_file(repo, file_path)
             .await
             .map_err(|e| ConfigurationError::FileAccessError {
                 path: format!(
@@ -297,9 +613,7 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
                 reason: format!("{}", e),
             })?;
 
-        toml::from_str(&content).map_err(|e| ConfigurationError::ParseError {
-            reason: format!("{}: {}", file_path, e),
-        })
+        self.parse_metadata_file(&content, file_path)
     }
 
     async fn load_team_configuration(
@@ -321,16 +635,9 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
 
         let file_path = format!("teams/{}/config.toml", team);
 
-        match self
-            .client
-            .get_file_content(&repo.organization, &repo.repository_name, &file_path)
-            .await
-        {
+        match self.read_metadata_file(repo, &file_path).await {
             Ok(content) => {
-                let config =
-                    toml::from_str(&content).map_err(|e| ConfigurationError::ParseError {
-                        reason: format!("{}: {}", file_path, e),
-                    })?;
+                let config = self.parse_metadata_file(&content, &file_path)?;
                 Ok(Some(config))
             }
             Err(_) => {
@@ -359,16 +666,9 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
 
         let file_path = format!("types/{}/config.toml", repo_type);
 
-        match self
-            .client
-            .get_file_content(&repo.organization, &repo.repository_name, &file_path)
-            .await
-        {
+        match self.read_metadata_file(repo, &file_path).await {
             Ok(content) => {
-                let config =
-                    toml::from_str(&content).map_err(|e| ConfigurationError::ParseError {
-                        reason: format!("{}: {}", file_path, e),
-                    })?;
+                let config = self.parse_metadata_file(&content, &file_path)?;
                 Ok(Some(config))
             }
             Err(_) => {
@@ -384,16 +684,10 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
     ) -> ConfigurationResult<HashMap<String, LabelConfig>> {
         let file_path = "global/standard-labels.toml";
 
-        match self
-            .client
-            .get_file_content(&repo.organization, &repo.repository_name, file_path)
-            .await
-        {
+        match self.read_metadata_file(repo, file_path).await {
             Ok(content) => {
                 let mut labels: HashMap<String, LabelConfig> =
-                    toml::from_str(&content).map_err(|e| ConfigurationError::ParseError {
-                        reason: format!("{}: {}", file_path, e),
-                    })?;
+                    self.parse_metadata_file(&content, file_path)?;
 
                 // Populate the name field from the map key
                 for (name, label) in labels.iter_mut() {
@@ -420,17 +714,71 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
         }
     }
 
-    async fn load_global_webhooks(
+    async fn prefetch_configuration_files(
         &self,
         repo: &MetadataRepository,
-    ) -> ConfigurationResult<Vec<WebhookConfig>> {
-        let file_path = "global/webhooks.toml";
+        repository_type: Option<&str>,
+        teams: &[String],
+    ) -> ConfigurationResult<()> {
+        let mut paths = vec![
+            "global/defaults.toml".to_string(),
+            "global/standard-labels.toml".to_string(),
+            "global/webhooks.toml".to_string(),
+        ];
+        // Names with path separators are rejected when loaded; never prefetch them.
+        let is_safe =
+            |name: &str| !name.contains("..") && !name.contains('/') && !name.contains('\\');
+        if let Some(repo_type) = repository_type.filter(|t| is_safe(t)) {
+            paths.push(format!("types/{}/config.toml", repo_type));
+        }
+        for team in teams.iter().filter(|t| is_safe(t.as_str())) {
+            paths.push(format!("teams/{}/config.toml", team));
+        }
 
+        let path_refs: Vec<&str> = paths.iter().map(String::as_str).collect();
         match self
             .client
-            .get_file_content(&repo.organization, &repo.repository_name, file_path)
+            .get_files_content_at_ref(
+                &repo.organization,
+                &repo.repository_name,
+                &path_refs,
+                self.config.git_ref(),
+            )
             .await
         {
+            Ok(files) => {
+                debug!(
+                    count = files.len(),
+                    "Prefetched metadata files in a single request"
+                );
+                let mut prefetched = self.prefetched.lock().unwrap_or_else(|e| e.into_inner());
+                for (path, content) in files {
+                    prefetched.insert(prefetch_key(repo, &path), content);
+                }
+            }
+            Err(e) => {
+                // Bulk fetching is an optimisation only; fetch the files
+                // individually instead, in parallel as they are independent.
+                warn!(
+                    org = %repo.organization,
+                    repo = %repo.repository_name,
+                    error = %e,
+                    "Bulk fetch of metadata files failed, falling back to individual requests"
+                );
+                self.fetch_files_concurrently(repo, &paths).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn load_global_webhooks(
+        &self,
+        repo: &MetadataRepository,
+    ) -> ConfigurationResult<Vec<WebhookConfig>> {
+        let file_path = "global/webhooks.toml";
+
+        match self.read_metadata_file(repo, file_path).await {
             Ok(content) => {
                 // Parse TOML with array of webhooks using serde
                 #[derive(serde::Deserialize)]
@@ -439,10 +787,7 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
                     webhooks: Vec<WebhookConfig>,
                 }
 
-                let parsed: WebhooksFile =
-                    toml::from_str(&content).map_err(|e| ConfigurationError::ParseError {
-                        reason: format!("{}: {}", file_path, e),
-                    })?;
+                let parsed: WebhooksFile = self.parse_metadata_file(&content, file_path)?;
 
                 debug!(
                     "Loaded {} global webhooks from {}/{}",
@@ -478,7 +823,12 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
         // Use "main" as default branch (same as other file operations in this codebase)
         let entries = self
             .client
-            .list_directory_contents(&repo.organization, &repo.repository_name, "types", "main")
+            .list_directory_contents(
+                &repo.organization,
+                &repo.repository_name,
+                "types",
+                self.config.git_ref().unwrap_or("main"),
+            )
             .await
             .map_err(|e| {
                 error!(
@@ -602,6 +952,7 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
             .client
             .get_file_content(org, template_name, file_path)
             .await
+            .map(strip_utf8_bom)
             .map_err(|e| {
                 tracing::error!(
                     "Failed to fetch template configuration from '{}/{}': {:?}",
@@ -623,8 +974,68 @@ impl MetadataRepositoryProvider for GitHubMetadataProvider {
                 }
             })?;
 
+        crate::basic_validator::ensure_valid_template_variable_names(&config)?;
+
         tracing::debug!("Successfully loaded template configuration: {:?}", config);
 
         Ok(config)
     }
 }
+
+/// Pause shared by metadata file fetches that run at the same time.
+#[derive(Debug, Default)]
+struct SharedBackoff {
+    /// Instant before which no fetch may be started
+    resume_at: Mutex<Option<tokio::time::Instant>>,
+}
+
+impl SharedBackoff {
+    /// Wait until the shared pause, if any, has elapsed.
+    async fn wait(&self) {
+        let resume_at = *self.resume_at.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(resume_at) = resume_at {
+            tokio::time::sleep_until(resume_at).await;
+        }
+    }
+
+    /// Pause all fetches for at least `delay` from now.
+    fn extend(&self, delay: Duration) {
+        let candidate = tokio::time::Instant::now() + delay;
+        let mut resume_at = self.resume_at.lock().unwrap_or_else(|e| e.into_inner());
+        if resume_at.is_none_or(|current| current < candidate) {
+            *resume_at = Some(candidate);
+        }
+    }
+}
+
+/// Whether a failed metadata file fetch may succeed when retried.
+///
+/// Missing files and malformed responses are final; rate limiting and other
+/// API failures (such as server errors) are retried.
+fn is_retryable_fetch_error(error: &github_client::Error) -> bool {
+    matches!(
+        error,
+        github_client::Error::RateLimitExceeded
+            | github_client::Error::ApiError()
+            | github_client::Error::Api { .. }
+    )
+}
+
+/// Build the key under which a prefetched metadata file is stored.
+fn prefetch_key(repo: &MetadataRepository, file_path: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        repo.organization, repo.repository_name, file_path
+    )
+}
+
+/// Remove a leading UTF-8 byte order mark from file content.
+///
+/// Editors on Windows often save files with a BOM, which the TOML parser
+/// rejects as an invalid character before the first key.
+pub(crate) fn strip_utf8_bom(content: String) -> String {
+    match content.strip_prefix('\u{feff}') {
+        Some(stripped) => stripped.to_string(),
+        None => content,
+    }
+}
@@ -330,3 +330,501 @@ color = "d73a4a"
         toml::from_str(invalid_structure);
     assert!(result.is_err(), "Should fail with missing required fields");
 }
+
+#[test]
+fn test_metadata_provider_config_request_timeout() {
+    let config = MetadataProviderConfig::explicit("org-metadata");
+    assert_eq!(config.request_timeout(), None);
+
+    let config = config.with_request_timeout(Duration::from_secs(10));
+    assert_eq!(config.request_timeout(), Some(Duration::from_secs(10)));
+}
+
+#[test]
+fn test_metadata_provider_config_git_ref() {
+    let config = MetadataProviderConfig::by_topic("template-metadata");
+    assert_eq!(config.git_ref(), None);
+
+    let config = config.with_git_ref("abc123");
+    assert_eq!(config.git_ref(), Some("abc123"));
+}
+
+/// Verify that metadata files are read from the configured branch ref.
+#[tokio::test]
+async fn test_load_global_defaults_reads_configured_branch() {
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    let api_url = "https://api.github.com/repos/test-org/.reporoller/contents/global/defaults.toml";
+    let html_url =
+        "https://github.com/test-org/.reporoller/blob/config-staging/global/defaults.toml";
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .and(query_param("ref", "config-staging"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "defaults.toml",
+            "path": "global/defaults.toml",
+            "sha": "abc123def456",
+            "size": 23,
+            "type": "file",
+            // "# staged configuration\n"
+            "content": "IyBzdGFnZWQgY29uZmlndXJhdGlvbgo=",
+            "encoding": "base64",
+            "url": api_url,
+            "html_url": html_url,
+            "git_url": null,
+            "download_url": null,
+            "_links": { "self": api_url, "git": null, "html": html_url }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    let config = MetadataProviderConfig::explicit(".reporoller").with_git_ref("config-staging");
+    let provider = GitHubMetadataProvider::new(GitHubClient::new(octocrab), config);
+
+    let metadata_repo = MetadataRepository {
+        organization: "test-org".to_string(),
+        repository_name: ".reporoller".to_string(),
+        discovery_method: DiscoveryMethod::ConfigurationBased {
+            repository_name: ".reporoller".to_string(),
+        },
+        last_updated: Utc::now(),
+    };
+
+    let result = provider.load_global_defaults(&metadata_repo).await;
+    assert!(
+        result.is_ok(),
+        "Expected defaults from configured branch, got {result:?}"
+    );
+}
+
+const FIXTURE_DEFAULTS: &str = r#"
+[repository]
+issues = { value = true, override_allowed = true }
+wiki = { value = false, override_allowed = false }
+"#;
+
+const FIXTURE_LABELS: &str = r#"
+[bug]
+color = "d73a4a"
+description = "Something isn't working"
+"#;
+
+const FIXTURE_TEAM: &str = r#"
+[repository]
+discussions = false
+"#;
+
+fn fixture_metadata_repository() -> MetadataRepository {
+    MetadataRepository {
+        organization: "test-org".to_string(),
+        repository_name: ".reporoller".to_string(),
+        discovery_method: DiscoveryMethod::ConfigurationBased {
+            repository_name: ".reporoller".to_string(),
+        },
+        last_updated: Utc::now(),
+    }
+}
+
+fn provider_for(mock_server: &wiremock::MockServer) -> GitHubMetadataProvider {
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    GitHubMetadataProvider::new(
+        GitHubClient::new(octocrab),
+        MetadataProviderConfig::explicit(".reporoller"),
+    )
+}
+
+/// Load the fixture files through every file-based provider method.
+async fn load_fixture(
+    provider: &GitHubMetadataProvider,
+) -> (
+    GlobalDefaults,
+    HashMap<String, LabelConfig>,
+    Vec<WebhookConfig>,
+    Option<TeamConfig>,
+    Option<RepositoryTypeConfig>,
+) {
+    let repo = fixture_metadata_repository();
+    (
+        provider.load_global_defaults(&repo).await.unwrap(),
+        provider.load_standard_labels(&repo).await.unwrap(),
+        provider.load_global_webhooks(&repo).await.unwrap(),
+        provider
+            .load_team_configuration(&repo, "platform")
+            .await
+            .unwrap(),
+        provider
+            .load_repository_type_configuration(&repo, "service")
+            .await
+            .unwrap(),
+    )
+}
+
+/// Verify that bulk-fetched (GraphQL) and individually fetched (REST) metadata
+/// files produce identical parsed configuration.
+#[tokio::test]
+async fn test_prefetched_files_match_rest_files() {
+    use base64::Engine as _;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let fixture = [
+        ("global/defaults.toml", FIXTURE_DEFAULTS),
+        ("global/standard-labels.toml", FIXTURE_LABELS),
+        ("teams/platform/config.toml", FIXTURE_TEAM),
+    ];
+
+    // REST path: one contents request per file; unknown files return 404.
+    let rest_server = MockServer::start().await;
+    for (file_path, content) in fixture {
+        let api_url =
+            format!("https://api.github.com/repos/test-org/.reporoller/contents/{file_path}");
+        let html_url = format!("https://github.com/test-org/.reporoller/blob/main/{file_path}");
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/repos/test-org/.reporoller/contents/{file_path}"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "name": file_path.rsplit('/').next().unwrap(),
+                "path": file_path,
+                "sha": "abc123def456",
+                "size": content.len(),
+                "type": "file",
+                "content": base64::engine::general_purpose::STANDARD.encode(content),
+                "encoding": "base64",
+                "url": api_url,
+                "html_url": html_url,
+                "git_url": null,
+                "download_url": null,
+                "_links": { "self": api_url, "git": null, "html": html_url }
+            })))
+            .mount(&rest_server)
+            .await;
+    }
+    let rest_provider = provider_for(&rest_server);
+    let rest_result = load_fixture(&rest_provider).await;
+
+    // GraphQL path: a single request; no REST endpoints are available.
+    let graphql_server = MockServer::start().await;
+    let blob = |content: &str| json!({ "text": content, "isBinary": false });
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": {
+                    "f0": blob(FIXTURE_DEFAULTS),
+                    "f1": blob(FIXTURE_LABELS),
+                    "f2": null,
+                    "f3": null,
+                    "f4": blob(FIXTURE_TEAM)
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&graphql_server)
+        .await;
+    let graphql_provider = provider_for(&graphql_server);
+    graphql_provider
+        .prefetch_configuration_files(
+            &fixture_metadata_repository(),
+            Some("service"),
+            &["platform".to_string()],
+        )
+        .await
+        .unwrap();
+    let graphql_result = load_fixture(&graphql_provider).await;
+
+    assert_eq!(graphql_result, rest_result);
+    assert!(rest_result.0.repository.is_some());
+    assert_eq!(rest_result.1.len(), 1);
+    assert!(rest_result.3.is_some());
+    assert!(rest_result.4.is_none());
+}
+
+/// Verify that a failed bulk fetch falls back to individual REST requests.
+#[tokio::test]
+async fn test_prefetch_failure_falls_back_to_rest() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/teams/platform/config.toml",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let provider = provider_for(&mock_server);
+    let repo = fixture_metadata_repository();
+    provider
+        .prefetch_configuration_files(&repo, None, &["platform".to_string()])
+        .await
+        .expect("Prefetch failures should not be fatal");
+
+    let team = provider
+        .load_team_configuration(&repo, "platform")
+        .await
+        .unwrap();
+    assert!(team.is_none());
+}
+
+#[test]
+fn test_strip_utf8_bom() {
+    assert_eq!(
+        strip_utf8_bom("\u{feff}[repository]".to_string()),
+        "[repository]"
+    );
+    assert_eq!(strip_utf8_bom("[repository]".to_string()), "[repository]");
+    // Only a leading BOM is removed
+    assert_eq!(strip_utf8_bom("a\u{feff}b".to_string()), "a\u{feff}b");
+}
+
+/// Verify that a BOM-prefixed `global/defaults.toml` parses identically to the
+/// same file without a BOM.
+#[tokio::test]
+async fn test_load_global_defaults_with_utf8_bom() {
+    use base64::Engine as _;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    let api_url = "https://api.github.com/repos/test-org/.reporoller/contents/global/defaults.toml";
+    let html_url = "https://github.com/test-org/.reporoller/blob/main/global/defaults.toml";
+    let content = format!("\u{feff}{FIXTURE_DEFAULTS}");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "defaults.toml",
+            "path": "global/defaults.toml",
+            "sha": "abc123def456",
+            "size": content.len(),
+            "type": "file",
+            "content": base64::engine::general_purpose::STANDARD.encode(&content),
+            "encoding": "base64",
+            "url": api_url,
+            "html_url": html_url,
+            "git_url": null,
+            "download_url": null,
+            "_links": { "self": api_url, "git": null, "html": html_url }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let provider = provider_for(&mock_server);
+    let with_bom = provider
+        .load_global_defaults(&fixture_metadata_repository())
+        .await
+        .expect("BOM-prefixed defaults should parse");
+
+    let without_bom: GlobalDefaults = toml::from_str(FIXTURE_DEFAULTS).unwrap();
+    assert_eq!(with_bom, without_bom);
+}
+
+#[test]
+fn test_metadata_provider_config_fetch_settings() {
+    let config = MetadataProviderConfig::explicit("org-metadata");
+    assert_eq!(
+        config.max_concurrent_fetches(),
+        DEFAULT_MAX_CONCURRENT_FETCHES
+    );
+    assert_eq!(config.fetch_backoff(), DEFAULT_FETCH_BACKOFF);
+
+    let config = config
+        .with_max_concurrent_fetches(0)
+        .with_fetch_backoff(Duration::from_millis(20));
+    assert_eq!(config.max_concurrent_fetches(), 1);
+    assert_eq!(config.fetch_backoff(), Duration::from_millis(20));
+}
+
+/// Build a REST contents API response for a metadata file.
+fn contents_response(file_path: &str, content: &str) -> wiremock::ResponseTemplate {
+    use base64::Engine as _;
+
+    let api_url = format!("https://api.github.com/repos/test-org/.reporoller/contents/{file_path}");
+    let html_url = format!("https://github.com/test-org/.reporoller/blob/main/{file_path}");
+    wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "name": file_path.rsplit('/').next().unwrap(),
+        "path": file_path,
+        "sha": "abc123def456",
+        "size": content.len(),
+        "type": "file",
+        "content": base64::engine::general_purpose::STANDARD.encode(content),
+        "encoding": "base64",
+        "url": api_url,
+        "html_url": html_url,
+        "git_url": null,
+        "download_url": null,
+        "_links": { "self": api_url, "git": null, "html": html_url }
+    }))
+}
+
+/// Verify that, when the bulk fetch fails, the files are fetched concurrently,
+/// each exactly once, and load the same configuration as a sequential fetch
+/// even though the responses complete in reverse order.
+#[tokio::test]
+async fn test_prefetch_fallback_fetches_concurrently_with_stable_results() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    // Earlier files respond more slowly, so completion order is reversed.
+    let fixture = [
+        ("global/defaults.toml", FIXTURE_DEFAULTS, 400),
+        ("global/standard-labels.toml", FIXTURE_LABELS, 300),
+        ("teams/platform/config.toml", FIXTURE_TEAM, 200),
+    ];
+
+    async fn prefetch_with_limit(
+        fixture: &[(&str, &str, u64)],
+        limit: usize,
+    ) -> (
+        Duration,
+        (
+            GlobalDefaults,
+            HashMap<String, LabelConfig>,
+            Vec<WebhookConfig>,
+            Option<TeamConfig>,
+            Option<RepositoryTypeConfig>,
+        ),
+    ) {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+        for (file_path, content, delay_ms) in fixture {
+            Mock::given(method("GET"))
+                .and(path(format!(
+                    "/repos/test-org/.reporoller/contents/{file_path}"
+                )))
+                .respond_with(
+                    contents_response(file_path, content)
+                        .set_delay(Duration::from_millis(*delay_ms)),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let octocrab = octocrab::Octocrab::builder()
+            .base_uri(mock_server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+        let provider = GitHubMetadataProvider::new(
+            GitHubClient::new(octocrab),
+            MetadataProviderConfig::explicit(".reporoller").with_max_concurrent_fetches(limit),
+        );
+
+        let started = std::time::Instant::now();
+        provider
+            .prefetch_configuration_files(
+                &fixture_metadata_repository(),
+                None,
+                &["platform".to_string()],
+            )
+            .await
+            .unwrap();
+        let elapsed = started.elapsed();
+
+        (elapsed, load_fixture(&provider).await)
+    }
+
+    let (sequential_elapsed, sequential) = prefetch_with_limit(&fixture, 1).await;
+    let (concurrent_elapsed, concurrent) = prefetch_with_limit(&fixture, 4).await;
+
+    assert!(sequential_elapsed >= Duration::from_millis(900));
+    assert!(
+        concurrent_elapsed < Duration::from_millis(800),
+        "concurrent prefetch took {concurrent_elapsed:?}"
+    );
+    assert_eq!(concurrent, sequential);
+    assert!(concurrent.0.repository.is_some());
+    assert_eq!(concurrent.1.len(), 1);
+    assert!(concurrent.3.is_some());
+}
+
+/// Verify that a throttled file fetch is retried after the backoff instead of
+/// being reported as missing.
+#[tokio::test]
+async fn test_prefetch_fallback_retries_throttled_fetch() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "message": "API rate limit exceeded",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(contents_response("global/defaults.toml", FIXTURE_DEFAULTS))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    let provider = GitHubMetadataProvider::new(
+        GitHubClient::new(octocrab),
+        MetadataProviderConfig::explicit(".reporoller")
+            .with_fetch_backoff(Duration::from_millis(10)),
+    );
+    let repo = fixture_metadata_repository();
+    provider
+        .prefetch_configuration_files(&repo, None, &[])
+        .await
+        .unwrap();
+
+    let defaults = provider.load_global_defaults(&repo).await.unwrap();
+    assert!(defaults.repository.is_some());
+}
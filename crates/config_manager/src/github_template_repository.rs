@@ -84,6 +84,7 @@ impl TemplateRepository for GitHubTemplateRepository {
             .github_client
             .get_file_content(org, template_name, config_path)
             .await
+            .map(crate::github_metadata_provider::strip_utf8_bom)
             .map_err(|e| {
                 let error_msg = e.to_string();
                 // Map GitHub 404 to TemplateConfigurationMissing
@@ -122,6 +123,8 @@ impl TemplateRepository for GitHubTemplateRepository {
             }
         })?;
 
+        crate::basic_validator::ensure_valid_template_variable_names(&config)?;
+
         info!(
             "Template configuration loaded: {}/{} ({})",
             org, template_name, config.template.name
@@ -218,6 +218,211 @@ pub struct GlobalDefaults {
     /// ```
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub naming_rules: Option<Vec<crate::settings::RepositoryNamingRulesConfig>>,
+
+    /// Whether templates may run their declared post-generation transforms.
+    ///
+    /// Templates opt in to built-in transforms (such as `sort-gitignore`) via
+    /// `[templating].post_generation`. Setting this to `false` disables those
+    /// transforms for every template in the organization. Defaults to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// allow_template_post_generation = false
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_template_post_generation: Option<bool>,
+
+    /// Glob patterns of templates that may be used in the organization.
+    ///
+    /// When set, only templates whose name matches at least one pattern can be
+    /// used to create repositories or appear in template listings. When absent,
+    /// every template is allowed unless it matches `denied_templates`.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// allowed_templates = ["rust-*", "python-service"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_templates: Option<Vec<String>>,
+
+    /// Glob patterns of templates that may not be used in the organization.
+    ///
+    /// Takes precedence over `allowed_templates`, so a template matching both
+    /// lists is denied.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// denied_templates = ["internal-*"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denied_templates: Option<Vec<String>>,
+
+    /// GitHub Actions variables to create on every repository (additive).
+    ///
+    /// Variables with the same name at a more specific level (template)
+    /// replace the value defined here.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// [[actions_variables]]
+    /// name = "RUST_VERSION"
+    /// value = "1.80"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions_variables: Option<Vec<crate::settings::ActionsVariableConfig>>,
+
+    /// GitHub Actions secrets to create on every repository (additive).
+    ///
+    /// Only references into the external secret store are configured; the
+    /// values are resolved when a repository is created.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// [[actions_secrets]]
+    /// name = "DEPLOY_TOKEN"
+    /// secret_ref = "DEPLOY_TOKEN_PROD"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions_secrets: Option<Vec<crate::settings::ActionsSecretConfig>>,
+
+    /// Whether the initial commit is created through the GitHub API so that it
+    /// is signed by GitHub and shown as verified.
+    ///
+    /// Enable this when branch protection requires signed commits; the default
+    /// (`false`) pushes a locally created, unsigned commit. Individual creation
+    /// requests may override this setting.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// signed_initial_commit = true
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_initial_commit: Option<bool>,
+
+    /// Whether a CODEOWNERS file naming the repository's owning teams is
+    /// generated when a repository is created.
+    ///
+    /// The generated rule assigns every file to the owning teams and is merged
+    /// into a template-provided CODEOWNERS file rather than replacing it.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// generate_codeowners = true
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generate_codeowners: Option<bool>,
+
+    /// Whether labels that GitHub creates automatically on a new repository
+    /// are removed before the configured labels are applied.
+    ///
+    /// When enabled, every label on the new repository that is not part of the
+    /// merged label set is deleted, leaving only the organization's own label
+    /// scheme. The default (`false`) keeps GitHub's stock labels alongside the
+    /// configured ones.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// replace_default_labels = true
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replace_default_labels: Option<bool>,
+
+    /// Whether GitHub creates an initial commit with a README when it creates
+    /// the repository.
+    ///
+    /// RepoRoller publishes its own initial commit afterwards and replaces
+    /// GitHub's; GitHub's README is only kept when the generated content has
+    /// none. A creation request may override this value.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// auto_init = true
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+
+    /// Name of the GitHub `.gitignore` template (for example `Rust` or `Node`)
+    /// GitHub applies when it creates the repository.
+    ///
+    /// The generated `.gitignore` replaces one rendered from the template. A
+    /// creation request may override this value.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// gitignore_template = "Rust"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitignore_template: Option<String>,
+
+    /// License key (for example `mit` or `apache-2.0`) GitHub applies when it
+    /// creates the repository.
+    ///
+    /// The generated `LICENSE` replaces one rendered from the template. A
+    /// creation request may override this value.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// license_template = "mit"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_template: Option<String>,
+}
+
+impl GlobalDefaults {
+    /// Returns `true` if the organization permits use of the named template.
+    ///
+    /// A template is allowed when it matches no `denied_templates` pattern and
+    /// either `allowed_templates` is absent or the template matches one of its
+    /// patterns. Patterns use glob syntax (`*`, `?`, `[...]`); a pattern that is
+    /// not valid glob syntax only matches the identical template name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use config_manager::GlobalDefaults;
+    ///
+    /// let defaults = GlobalDefaults {
+    ///     allowed_templates: Some(vec!["rust-*".to_string()]),
+    ///     denied_templates: Some(vec!["rust-internal-*".to_string()]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(defaults.is_template_allowed("rust-service"));
+    /// assert!(!defaults.is_template_allowed("rust-internal-tools"));
+    /// assert!(!defaults.is_template_allowed("python-service"));
+    /// ```
+    pub fn is_template_allowed(&self, template_name: &str) -> bool {
+        let matches_any = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| match glob::Pattern::new(pattern) {
+                    Ok(glob) => glob.matches(template_name),
+                    Err(_) => pattern == template_name,
+                })
+        };
+
+        if let Some(denied) = &self.denied_templates {
+            if matches_any(denied) {
+                return false;
+            }
+        }
+
+        match &self.allowed_templates {
+            Some(allowed) => matches_any(allowed),
+            None => true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -235,3 +235,73 @@ fn test_serialize_round_trip_includes_default_teams() {
     let back: GlobalDefaults = toml::from_str(&toml).expect("Failed to deserialize");
     assert_eq!(defaults, back);
 }
+
+#[test]
+fn test_deserialize_template_allow_and_deny_lists() {
+    let toml_str = r#"
+        allowed_templates = ["rust-*", "python-service"]
+        denied_templates = ["rust-internal-*"]
+    "#;
+
+    let defaults: GlobalDefaults = toml::from_str(toml_str).expect("Failed to parse");
+    assert_eq!(
+        defaults.allowed_templates,
+        Some(vec!["rust-*".to_string(), "python-service".to_string()])
+    );
+    assert_eq!(
+        defaults.denied_templates,
+        Some(vec!["rust-internal-*".to_string()])
+    );
+}
+
+#[test]
+fn test_is_template_allowed_without_lists_allows_everything() {
+    let defaults = GlobalDefaults::default();
+    assert!(defaults.is_template_allowed("rust-service"));
+    assert!(defaults.is_template_allowed("internal-tools"));
+}
+
+#[test]
+fn test_is_template_allowed_requires_allowlist_match() {
+    let defaults = GlobalDefaults {
+        allowed_templates: Some(vec!["rust-*".to_string(), "python-service".to_string()]),
+        ..Default::default()
+    };
+
+    assert!(defaults.is_template_allowed("rust-service"));
+    assert!(defaults.is_template_allowed("rust-library"));
+    assert!(defaults.is_template_allowed("python-service"));
+    assert!(!defaults.is_template_allowed("python-library"));
+    assert!(!defaults.is_template_allowed("my-rust-service"));
+}
+
+#[test]
+fn test_is_template_allowed_denylist_takes_precedence() {
+    let defaults = GlobalDefaults {
+        allowed_templates: Some(vec!["rust-*".to_string()]),
+        denied_templates: Some(vec!["rust-internal-?".to_string(), "legacy".to_string()]),
+        ..Default::default()
+    };
+
+    assert!(defaults.is_template_allowed("rust-service"));
+    assert!(!defaults.is_template_allowed("rust-internal-a"));
+    assert!(defaults.is_template_allowed("rust-internal-ab"));
+
+    let deny_only = GlobalDefaults {
+        denied_templates: Some(vec!["legacy".to_string()]),
+        ..Default::default()
+    };
+    assert!(!deny_only.is_template_allowed("legacy"));
+    assert!(deny_only.is_template_allowed("legacy-v2"));
+}
+
+#[test]
+fn test_is_template_allowed_invalid_glob_matches_literally() {
+    let defaults = GlobalDefaults {
+        allowed_templates: Some(vec!["rust-[".to_string()]),
+        ..Default::default()
+    };
+
+    assert!(defaults.is_template_allowed("rust-["));
+    assert!(!defaults.is_template_allowed("rust-service"));
+}
@@ -0,0 +1,126 @@
+//! Variable interpolation in metadata configuration values.
+//!
+//! Metadata files may reference values that differ between environments, such
+//! as deployment URLs in webhook or homepage settings, with `${KEY}`
+//! placeholders. Placeholders are replaced in string values only, using a
+//! caller-supplied map; the process environment is never consulted.
+//!
+//! Interpolation is opt-in through
+//! [`MetadataProviderConfig::with_interpolation_variables`](crate::MetadataProviderConfig::with_interpolation_variables).
+//! Without it, placeholders are kept verbatim.
+
+use crate::{ConfigurationError, ConfigurationResult};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "interpolation_tests.rs"]
+mod tests;
+
+/// Replaces every `${KEY}` placeholder in a string with its value.
+///
+/// # Arguments
+///
+/// * `value` - The string to interpolate
+/// * `variables` - Values for the placeholder keys
+///
+/// # Errors
+///
+/// Returns `ConfigurationError::InvalidConfiguration` if a placeholder names a
+/// key that is not in `variables`, or if a placeholder is not closed.
+///
+/// # Examples
+///
+/// ```
+/// use config_manager::interpolation::interpolate;
+/// use std::collections::HashMap;
+///
+/// let variables = HashMap::from([("ENV".to_string(), "staging".to_string())]);
+/// let url = interpolate("https://${ENV}.example.com/hook", &variables).unwrap();
+/// assert_eq!(url, "https://staging.example.com/hook");
+/// ```
+pub fn interpolate(
+    value: &str,
+    variables: &HashMap<String, String>,
+) -> ConfigurationResult<String> {
+    interpolate_field(value, variables, "value")
+}
+
+/// Parses TOML content, interpolating string values when variables are given.
+///
+/// `file_path` is only used in error messages.
+pub(crate) fn parse_toml<T: DeserializeOwned>(
+    content: &str,
+    file_path: &str,
+    variables: Option<&HashMap<String, String>>,
+) -> ConfigurationResult<T> {
+    let parse_error = |e: toml::de::Error| ConfigurationError::ParseError {
+        reason: format!("{}: {}", file_path, e),
+    };
+
+    let Some(variables) = variables else {
+        return toml::from_str(content).map_err(parse_error);
+    };
+
+    let mut value: toml::Value = toml::from_str(content).map_err(parse_error)?;
+    interpolate_toml_value(&mut value, variables, file_path)?;
+    value.try_into().map_err(parse_error)
+}
+
+/// Interpolates every string inside a TOML value, recursing into tables and arrays.
+fn interpolate_toml_value(
+    value: &mut toml::Value,
+    variables: &HashMap<String, String>,
+    field: &str,
+) -> ConfigurationResult<()> {
+    match value {
+        toml::Value::String(s) => *s = interpolate_field(s, variables, field)?,
+        toml::Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                interpolate_toml_value(item, variables, &format!("{}[{}]", field, index))?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                interpolate_toml_value(item, variables, &format!("{}.{}", field, key))?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Interpolates a single string, naming `field` in any error.
+fn interpolate_field(
+    value: &str,
+    variables: &HashMap<String, String>,
+    field: &str,
+) -> ConfigurationResult<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| ConfigurationError::InvalidConfiguration {
+                field: field.to_string(),
+                reason: format!("Unclosed interpolation placeholder in '{}'", value),
+            })?;
+
+        let key = &after[..end];
+        let replacement =
+            variables
+                .get(key)
+                .ok_or_else(|| ConfigurationError::InvalidConfiguration {
+                    field: field.to_string(),
+                    reason: format!("Unresolved interpolation variable '${{{}}}'", key),
+                })?;
+        result.push_str(replacement);
+        rest = &after[end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
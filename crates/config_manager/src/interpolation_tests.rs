@@ -0,0 +1,76 @@
+//! Tests for the interpolation module.
+
+use super::*;
+use crate::settings::WebhookConfig;
+use crate::GlobalDefaults;
+
+fn variables() -> HashMap<String, String> {
+    HashMap::from([
+        ("ENV".to_string(), "staging".to_string()),
+        ("HOOK_HOST".to_string(), "hooks.example.com".to_string()),
+    ])
+}
+
+#[test]
+fn test_placeholders_are_resolved_from_the_supplied_map() {
+    #[derive(serde::Deserialize)]
+    struct WebhooksFile {
+        webhooks: Vec<WebhookConfig>,
+    }
+
+    let toml = r#"
+        [[webhooks]]
+        url = "https://${HOOK_HOST}/${ENV}/events"
+        content_type = "json"
+        events = ["push"]
+        active = true
+    "#;
+
+    let parsed: WebhooksFile = parse_toml(toml, "global/webhooks.toml", Some(&variables()))
+        .expect("All placeholders should resolve");
+
+    assert_eq!(
+        parsed.webhooks[0].url,
+        "https://hooks.example.com/staging/events"
+    );
+    assert_eq!(parsed.webhooks[0].events, vec!["push".to_string()]);
+}
+
+#[test]
+fn test_unresolved_key_is_an_error() {
+    let toml = r#"
+        [repository]
+        wiki = { value = false, override_allowed = true }
+
+        [[webhooks]]
+        url = "https://${MISSING}/events"
+        content_type = "json"
+        events = ["push"]
+        active = true
+    "#;
+
+    let result: ConfigurationResult<GlobalDefaults> =
+        parse_toml(toml, "global/defaults.toml", Some(&variables()));
+
+    match result {
+        Err(ConfigurationError::InvalidConfiguration { field, reason }) => {
+            assert_eq!(field, "global/defaults.toml.webhooks[0].url");
+            assert!(reason.contains("${MISSING}"), "reason was: {}", reason);
+        }
+        other => panic!("Expected unresolved variable error, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_placeholders_are_kept_when_interpolation_is_off() {
+    let toml = r#"homepage = "https://${ENV}.example.com""#;
+
+    #[derive(serde::Deserialize)]
+    struct Homepage {
+        homepage: String,
+    }
+
+    let parsed: Homepage = parse_toml(toml, "teams/a/config.toml", None).unwrap();
+
+    assert_eq!(parsed.homepage, "https://${ENV}.example.com");
+}
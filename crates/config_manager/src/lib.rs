@@ -22,12 +22,17 @@ pub mod template_loader;
 pub mod github_template_repository;
 
 // Metadata repository provider
+pub mod combined_metadata_provider;
 pub mod github_metadata_provider;
+pub mod interpolation;
 pub mod metadata_provider;
 
 // Configuration merger
 pub mod merger;
 
+// Effective configuration diff
+pub mod config_diff;
+
 // Organization settings manager
 pub mod configuration_context;
 pub mod organization_settings_manager;
@@ -49,12 +54,19 @@ pub mod visibility_policy_provider;
 mod integration_tests;
 
 // Re-export for convenient access
+pub use combined_metadata_provider::{
+    CombinedConfiguration, CombinedFileMetadataProvider, DEFAULT_COMBINED_CONFIG_PATH,
+};
+pub use config_diff::{diff_configurations, ConfigurationChange, ConfigurationDiff};
 pub use configuration_context::ConfigurationContext;
 pub use errors::{ConfigurationError, ConfigurationResult};
 pub use github_metadata_provider::{GitHubMetadataProvider, MetadataProviderConfig};
 pub use github_template_repository::GitHubTemplateRepository;
 pub use global_defaults::GlobalDefaults;
-pub use merged_config::{ConfigurationSource, ConfigurationSourceTrace, MergedConfiguration};
+pub use merged_config::{
+    ConfigurationAuditRecord, ConfigurationSource, ConfigurationSourceTrace, MergedConfiguration,
+    REDACTED_SECRET,
+};
 pub use merger::ConfigurationMerger;
 pub use metadata_provider::{DiscoveryMethod, MetadataRepository, MetadataRepositoryProvider};
 pub use organization_settings_manager::OrganizationSettingsManager;
@@ -64,10 +76,12 @@ pub use repository_type_name::RepositoryTypeName;
 pub use repository_type_validator::RepositoryTypeValidator;
 pub use settings::LabelConfig;
 pub use settings::RepositoryNamingRulesConfig;
+pub use settings::{ActionsSecretConfig, ActionsVariableConfig};
 pub use settings::{NotificationEndpoint, NotificationsConfig};
 pub use team_config::TeamConfig;
 pub use template_config::{
     RepositoryTypePolicy, RepositoryTypeSpec, TemplateConfig, TemplateMetadata, TemplateVariable,
+    VariableGroup, DEFAULT_VARIABLE_GROUP,
 };
 pub use template_loader::{CacheStatistics, TemplateLoader, TemplateRepository};
 pub use validator::{
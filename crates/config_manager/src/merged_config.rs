@@ -34,21 +34,41 @@
 //! config.record_source("pull_requests.required_approving_review_count", ConfigurationSource::Template);
 //! ```
 //!
+//! # Audit Records
+//!
+//! The source trace is not part of the serialized `MergedConfiguration`. To
+//! archive the resolved values together with their sources, create a
+//! [`ConfigurationAuditRecord`] with [`MergedConfiguration::audit_record`]:
+//!
+//! ```rust
+//! use config_manager::{ConfigurationAuditRecord, ConfigurationSource, MergedConfiguration};
+//!
+//! let mut config = MergedConfiguration::new();
+//! config.record_source("repository.issues", ConfigurationSource::Global);
+//!
+//! let json = config.audit_record().to_json().unwrap();
+//! let restored = ConfigurationAuditRecord::from_json(&json).unwrap().into_configuration();
+//! assert_eq!(restored.get_source("repository.issues"), Some(ConfigurationSource::Global));
+//! ```
+//!
 //! See: specs/design/organization-repository-settings.md
 
 use crate::settings::{
-    BranchProtectionSettings, CustomProperty, EnvironmentConfig, GitHubAppConfig, LabelConfig,
-    NotificationsConfig, PullRequestSettings, RepositoryNamingRulesConfig, RepositorySettings,
+    ActionSettings, ActionsSecretConfig, ActionsVariableConfig, BranchProtectionSettings,
+    CustomProperty, EnvironmentConfig, GitHubAppConfig, LabelConfig, NotificationsConfig,
+    PullRequestSettings, PushSettings, RepositoryNamingRulesConfig, RepositorySettings,
     RulesetConfig, WebhookConfig,
 };
-use std::collections::HashMap;
+use crate::template_config::TemplateConfig;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
 
 /// Merged configuration representing the final resolved settings.
 ///
 /// This structure contains the result of merging configuration from all sources
 /// (global, repository type, team, and template) according to the precedence
 /// hierarchy and override policies.
-#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct MergedConfiguration {
     /// Repository feature settings.
     ///
@@ -65,6 +85,18 @@ pub struct MergedConfiguration {
     /// Final resolved branch protection rules.
     pub branch_protection: BranchProtectionSettings,
 
+    /// Push settings.
+    ///
+    /// Final resolved push rules for the default branch (force pushes,
+    /// signed commits, push limits).
+    pub push: PushSettings,
+
+    /// GitHub Actions settings.
+    ///
+    /// Final resolved Actions policy (enabled, default workflow permissions),
+    /// merged from global and team levels.
+    pub actions: ActionSettings,
+
     /// Labels to be created in the repository.
     ///
     /// Merged from all sources, using label name as the key.
@@ -73,12 +105,14 @@ pub struct MergedConfiguration {
 
     /// Webhooks to be created in the repository.
     ///
-    /// Merged from all sources (additive - all webhooks from all sources).
+    /// Merged from all sources (additive), deduplicated by URL. Later sources
+    /// replace earlier sources for the same URL.
     pub webhooks: Vec<WebhookConfig>,
 
     /// Custom properties to set on the repository.
     ///
-    /// Merged from all sources (additive).
+    /// Merged from all sources (additive) by property name; higher-precedence
+    /// sources replace earlier sources for the same name.
     pub custom_properties: Vec<CustomProperty>,
 
     /// Environments to create in the repository.
@@ -88,7 +122,8 @@ pub struct MergedConfiguration {
 
     /// GitHub Apps to install on the repository.
     ///
-    /// Merged from all sources (additive).
+    /// Merged from all sources (additive), deduplicated by app ID. Later
+    /// sources replace earlier sources for the same app.
     pub github_apps: Vec<GitHubAppConfig>,
 
     /// Repository rulesets to apply.
@@ -158,12 +193,67 @@ pub struct MergedConfiguration {
     /// Same semantics as `max_team_access_level` but for collaborators.
     pub max_collaborator_access_level: Option<String>,
 
+    /// Whether the template's post-generation transforms may run.
+    ///
+    /// Comes from `allow_template_post_generation` in `defaults.toml`;
+    /// `true` unless the organization has disabled the feature.
+    pub allow_template_post_generation: bool,
+
+    /// GitHub Actions variables to create in the repository.
+    ///
+    /// Merged from global and template levels; a template variable replaces an
+    /// organization variable with the same name.
+    pub actions_variables: Vec<ActionsVariableConfig>,
+
+    /// GitHub Actions secrets to create in the repository, by reference.
+    ///
+    /// Merged from global and template levels; a template secret replaces an
+    /// organization secret with the same name.
+    pub actions_secrets: Vec<ActionsSecretConfig>,
+
+    /// Whether the initial commit should be created through the GitHub API
+    /// (signed and verified) instead of pushed with git.
+    ///
+    /// Comes from `signed_initial_commit` in `defaults.toml`; `false` unless the
+    /// organization has enabled it.
+    pub signed_initial_commit: bool,
+
+    /// Whether a CODEOWNERS file naming the owning teams should be generated.
+    ///
+    /// Comes from `generate_codeowners` in `defaults.toml`; `false` unless the
+    /// organization has enabled it.
+    pub generate_codeowners: bool,
+
+    /// Whether labels not in [`labels`](Self::labels) are deleted from the new
+    /// repository before the configured labels are applied.
+    ///
+    /// Comes from `replace_default_labels` in `defaults.toml`; `false` unless
+    /// the organization has enabled it.
+    pub replace_default_labels: bool,
+
+    /// Whether GitHub should create an initial commit when it creates the
+    /// repository.
+    ///
+    /// Comes from `auto_init` in `defaults.toml`; `false` unless the
+    /// organization has enabled it.
+    pub auto_init: bool,
+
+    /// GitHub `.gitignore` template applied when the repository is created.
+    ///
+    /// Comes from `gitignore_template` in `defaults.toml`.
+    pub gitignore_template: Option<String>,
+
+    /// License key GitHub applies when the repository is created.
+    ///
+    /// Comes from `license_template` in `defaults.toml`.
+    pub license_template: Option<String>,
+
     /// Source trace tracking which configuration source provided each setting.
     ///
     /// Used for auditing, debugging, and understanding configuration precedence.
     /// Excluded from serialization so it does not leak into HTTP API responses;
-    /// callers that need source attribution should extract it explicitly before
-    /// serialising the parent struct.
+    /// callers that need source attribution should use
+    /// [`MergedConfiguration::audit_record`].
     #[serde(skip)]
     pub source_trace: ConfigurationSourceTrace,
 }
@@ -178,6 +268,8 @@ impl MergedConfiguration {
             repository: RepositorySettings::default(),
             pull_requests: PullRequestSettings::default(),
             branch_protection: BranchProtectionSettings::default(),
+            push: PushSettings::default(),
+            actions: ActionSettings::default(),
             labels: HashMap::new(),
             webhooks: Vec::new(),
             custom_properties: Vec::new(),
@@ -194,6 +286,15 @@ impl MergedConfiguration {
             locked_collaborators: std::collections::HashSet::new(),
             max_team_access_level: None,
             max_collaborator_access_level: None,
+            allow_template_post_generation: true,
+            actions_variables: Vec::new(),
+            actions_secrets: Vec::new(),
+            signed_initial_commit: false,
+            generate_codeowners: false,
+            replace_default_labels: false,
+            auto_init: false,
+            gitignore_template: None,
+            license_template: None,
             source_trace: ConfigurationSourceTrace::new(),
         }
     }
@@ -222,18 +323,176 @@ impl MergedConfiguration {
     pub fn get_source(&self, field_path: &str) -> Option<ConfigurationSource> {
         self.source_trace.get_source(field_path)
     }
+
+    /// Gets the source of a setting, including settings no layer configured.
+    ///
+    /// Returns the layer that set `field_path`, or
+    /// [`ConfigurationSource::GitHubDefault`] when it is one of the
+    /// [`setting_field_paths`](Self::setting_field_paths) and no layer set it.
+    /// Returns None for paths that do not name a setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_path` - Dot-separated path to the setting
+    pub fn resolved_source(&self, field_path: &str) -> Option<ConfigurationSource> {
+        self.get_source(field_path).or_else(|| {
+            Self::setting_field_paths()
+                .iter()
+                .any(|path| path == field_path)
+                .then_some(ConfigurationSource::GitHubDefault)
+        })
+    }
+
+    /// Describes where the value of a setting comes from.
+    ///
+    /// Returns None for paths that do not name a setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_path` - Dot-separated path to the setting
+    pub fn explain(&self, field_path: &str) -> Option<String> {
+        self.resolved_source(field_path).map(|source| match source {
+            ConfigurationSource::GitHubDefault => {
+                format!("{}: not configured; GitHub default applies", field_path)
+            }
+            layer => format!("{}: set by {} configuration", field_path, layer),
+        })
+    }
+
+    /// Lists the dot-separated paths of the settings GitHub has its own
+    /// defaults for (repository, pull request, branch protection and push
+    /// settings), e.g. `repository.wiki`.
+    ///
+    /// The list is computed once and shared.
+    pub fn setting_field_paths() -> &'static [String] {
+        &SETTING_FIELD_PATHS
+    }
+
+    /// Creates an audit record of this configuration and its source trace.
+    ///
+    /// Webhook secrets are replaced with [`REDACTED_SECRET`] so that the record
+    /// can be archived without exposing credentials.
+    pub fn audit_record(&self) -> ConfigurationAuditRecord {
+        let mut configuration = self.clone();
+        for webhook in &mut configuration.webhooks {
+            if webhook.secret.is_some() {
+                webhook.secret = Some(REDACTED_SECRET.to_string());
+            }
+        }
+        let sources = std::mem::take(&mut configuration.source_trace);
+
+        ConfigurationAuditRecord {
+            configuration,
+            sources,
+            variables: BTreeMap::new(),
+        }
+    }
 }
 
+/// Sections of [`MergedConfiguration`] whose fields fall back to GitHub's
+/// defaults when no layer sets them.
+const SETTING_SECTIONS: [&str; 4] = ["repository", "pull_requests", "branch_protection", "push"];
+
+/// Paths of every field in [`SETTING_SECTIONS`].
+///
+/// The settings structs serialize unset fields as null rather than skipping
+/// them, so the keys of an empty configuration name every field.
+static SETTING_FIELD_PATHS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let empty = serde_json::to_value(MergedConfiguration::new()).unwrap_or_default();
+    SETTING_SECTIONS
+        .iter()
+        .flat_map(|section| {
+            empty
+                .get(section)
+                .and_then(|value| value.as_object())
+                .into_iter()
+                .flat_map(move |fields| fields.keys().map(move |f| format!("{section}.{f}")))
+        })
+        .collect()
+});
+
 impl Default for MergedConfiguration {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Placeholder written in place of secrets in a [`ConfigurationAuditRecord`].
+pub const REDACTED_SECRET: &str = "[REDACTED]";
+
+/// The resolved configuration of a repository together with the source of
+/// each setting, for archiving.
+///
+/// Serializes as a JSON object with a `configuration` member holding the
+/// resolved values, a `sources` member mapping each dot-separated field path
+/// to the [`ConfigurationSource`] that provided it and, when recorded, a
+/// `variables` member with the template variables used for rendering.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConfigurationAuditRecord {
+    /// The resolved configuration values.
+    pub configuration: MergedConfiguration,
+
+    /// The source of each configured setting.
+    pub sources: ConfigurationSourceTrace,
+
+    /// Template variable values used for rendering, excluding secret variables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+}
+
+impl ConfigurationAuditRecord {
+    /// Records the template variables used for rendering.
+    ///
+    /// Variables declared with `secret = true` in `template` are left out of
+    /// the record entirely.
+    pub fn with_template_variables(
+        mut self,
+        variables: &HashMap<String, String>,
+        template: Option<&TemplateConfig>,
+    ) -> Self {
+        let declared = template.and_then(|t| t.variables.as_ref());
+        self.variables = variables
+            .iter()
+            .filter(|(name, _)| {
+                !declared
+                    .and_then(|vars| vars.get(*name))
+                    .is_some_and(|var| var.secret.unwrap_or(false))
+            })
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        self
+    }
+
+    /// Serializes the record as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be represented as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parses a record previously produced by [`ConfigurationAuditRecord::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid audit record.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the configuration with its source trace restored.
+    pub fn into_configuration(self) -> MergedConfiguration {
+        let mut configuration = self.configuration;
+        configuration.source_trace = self.sources;
+        configuration
+    }
+}
+
 /// Tracks which configuration source provided each setting.
 ///
 /// Used for auditing, debugging, and understanding configuration precedence.
-#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct ConfigurationSourceTrace {
     /// Map of field path to configuration source.
     sources: HashMap<String, ConfigurationSource>,
@@ -280,7 +539,8 @@ impl ConfigurationSourceTrace {
 ///
 /// Represents which level of the configuration hierarchy provided a setting.
 /// The precedence order is: Template > Team > RepositoryType > Global.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+/// [`ConfigurationSource::GitHubDefault`] marks a setting no level provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ConfigurationSource {
     /// Global organization defaults (lowest precedence).
     Global,
@@ -293,6 +553,12 @@ pub enum ConfigurationSource {
 
     /// Template-specific configuration (highest precedence).
     Template,
+
+    /// Not configured by any level; GitHub's default applies.
+    ///
+    /// Reported by [`MergedConfiguration::resolved_source`], never recorded
+    /// by the merger.
+    GitHubDefault,
 }
 
 impl std::fmt::Display for ConfigurationSource {
@@ -302,6 +568,7 @@ impl std::fmt::Display for ConfigurationSource {
             ConfigurationSource::RepositoryType => write!(f, "RepositoryType"),
             ConfigurationSource::Team => write!(f, "Team"),
             ConfigurationSource::Template => write!(f, "Template"),
+            ConfigurationSource::GitHubDefault => write!(f, "GitHubDefault"),
         }
     }
 }
@@ -244,6 +244,7 @@ fn test_merged_configuration_with_github_apps() {
     config.github_apps.push(GitHubAppConfig {
         app_id: 12345,
         permissions,
+        required: None,
     });
 
     assert_eq!(config.github_apps.len(), 1);
@@ -349,3 +350,158 @@ fn test_additive_collections_from_multiple_sources() {
     // All webhooks should be present (additive)
     assert_eq!(config.webhooks.len(), 3);
 }
+
+#[test]
+fn test_audit_record_round_trip_preserves_values_and_sources() {
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(crate::OverridableValue::allowed(true));
+    config.record_source("repository.issues", ConfigurationSource::Global);
+    config.generate_codeowners = true;
+    config.record_source("generate_codeowners", ConfigurationSource::Team);
+    config.webhooks.push(WebhookConfig {
+        url: "https://example.com/webhook".to_string(),
+        content_type: "json".to_string(),
+        secret: None,
+        events: vec!["push".to_string()],
+        active: true,
+    });
+    config.record_source("webhooks[0]", ConfigurationSource::Template);
+
+    let json = config.audit_record().to_json().unwrap();
+    let restored = ConfigurationAuditRecord::from_json(&json)
+        .unwrap()
+        .into_configuration();
+
+    assert_eq!(restored, config);
+    assert_eq!(
+        restored.get_source("repository.issues"),
+        Some(ConfigurationSource::Global)
+    );
+    assert_eq!(
+        restored.get_source("generate_codeowners"),
+        Some(ConfigurationSource::Team)
+    );
+    assert_eq!(
+        restored.get_source("webhooks[0]"),
+        Some(ConfigurationSource::Template)
+    );
+}
+
+#[test]
+fn test_audit_record_contains_sources_and_redacts_webhook_secrets() {
+    let mut config = MergedConfiguration::new();
+    config.webhooks.push(WebhookConfig {
+        url: "https://example.com/webhook".to_string(),
+        content_type: "json".to_string(),
+        secret: Some("super-secret".to_string()),
+        events: vec!["push".to_string()],
+        active: true,
+    });
+    config.record_source("webhooks[0]", ConfigurationSource::Template);
+
+    let record = config.audit_record();
+    let json = record.to_json().unwrap();
+
+    assert!(!json.contains("super-secret"));
+    assert_eq!(
+        record.configuration.webhooks[0].secret.as_deref(),
+        Some(REDACTED_SECRET)
+    );
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["sources"]["sources"]["webhooks[0]"], "Template");
+    // The original configuration is left untouched.
+    assert_eq!(config.webhooks[0].secret.as_deref(), Some("super-secret"));
+}
+
+#[test]
+fn test_audit_record_excludes_secret_template_variables() {
+    let template: crate::TemplateConfig = toml::from_str(
+        r#"
+        [template]
+        name = "service-template"
+        description = "Service template"
+        author = "Platform Team"
+        tags = []
+
+        [variables.service_name]
+        description = "Service name"
+
+        [variables.api_token]
+        description = "Token written into the generated config"
+        secret = true
+    "#,
+    )
+    .unwrap();
+    let variables = HashMap::from([
+        ("service_name".to_string(), "payments".to_string()),
+        ("api_token".to_string(), "tok-9f8e7d6c".to_string()),
+    ]);
+
+    let record = MergedConfiguration::new()
+        .audit_record()
+        .with_template_variables(&variables, Some(&template));
+    let json = record.to_json().unwrap();
+
+    assert!(!json.contains("tok-9f8e7d6c"));
+    assert!(!json.contains("api_token"));
+    assert_eq!(
+        record.variables.get("service_name").map(String::as_str),
+        Some("payments")
+    );
+
+    let restored = ConfigurationAuditRecord::from_json(&json).unwrap();
+    assert_eq!(restored.variables, record.variables);
+}
+
+#[test]
+fn test_resolved_source_distinguishes_github_default_from_configured_layer() {
+    let mut config = MergedConfiguration::new();
+    config.record_source("repository.issues", ConfigurationSource::Team);
+
+    assert_eq!(
+        config.resolved_source("repository.issues"),
+        Some(ConfigurationSource::Team)
+    );
+    assert_eq!(
+        config.resolved_source("repository.wiki"),
+        Some(ConfigurationSource::GitHubDefault)
+    );
+    assert_eq!(config.resolved_source("repository.no_such_setting"), None);
+    // Resolving does not add entries to the trace.
+    assert_eq!(config.source_trace.field_count(), 1);
+}
+
+#[test]
+fn test_explain_reports_source_of_setting() {
+    let mut config = MergedConfiguration::new();
+    config.record_source(
+        "pull_requests.allow_squash_merge",
+        ConfigurationSource::Global,
+    );
+
+    assert_eq!(
+        config
+            .explain("pull_requests.allow_squash_merge")
+            .as_deref(),
+        Some("pull_requests.allow_squash_merge: set by Global configuration")
+    );
+    assert_eq!(
+        config.explain("push.require_signed_commits").as_deref(),
+        Some("push.require_signed_commits: not configured; GitHub default applies")
+    );
+}
+
+#[test]
+fn test_setting_field_paths_cover_all_settings_sections() {
+    let paths = MergedConfiguration::setting_field_paths();
+
+    for expected in [
+        "repository.issues",
+        "pull_requests.allow_squash_merge",
+        "branch_protection.require_pull_request_reviews",
+        "push.allow_force_pushes",
+    ] {
+        assert!(paths.iter().any(|p| p == expected), "missing {expected}");
+    }
+    assert!(!paths.iter().any(|p| p.starts_with("labels")));
+}
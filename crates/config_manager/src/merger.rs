@@ -18,6 +18,14 @@
 //! `override_allowed = false`. When a lower-precedence layer prohibits overrides,
 //! higher-precedence layers attempting to override that setting will cause an error.
 //!
+//! # Keyed Collections
+//!
+//! Labels, webhooks, and GitHub Apps are merged additively but deduplicated by
+//! key: label name, webhook URL, and app ID respectively. When two layers
+//! define the same key, the higher-precedence layer's entry replaces the lower
+//! one, and the source trace records the surviving layer under
+//! `labels.<name>`, `webhooks.<url>`, or `github_apps.<app_id>`.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -31,6 +39,9 @@
 //!         description: "Example template".to_string(),
 //!         author: "Test".to_string(),
 //!         tags: vec![],
+//!         default_description: None,
+//!         default_topics: vec![],
+//!         required_code_owners: vec![],
 //!     },
 //!     repository: None,
 //!     repository_type: None,
@@ -49,6 +60,10 @@
 //!     teams: None,
 //!     collaborators: None,
 //!     naming_rules: None,
+//!     actions_variables: None,
+//!     actions_secrets: None,
+//!     variable_groups: None,
+//!     base_template: None,
 //! };
 //!
 //! // Merge configurations with precedence rules
@@ -69,14 +84,16 @@ use crate::{
     merged_config::{ConfigurationSource, MergedConfiguration},
     repository_type_config::RepositoryTypeConfig,
     settings::{
-        BranchProtectionSettings, CustomProperty, EnvironmentConfig, GitHubAppConfig,
-        NotificationsConfig, PullRequestSettings, RepositoryNamingRulesConfig, RepositorySettings,
+        ActionSettings, ActionsSecretConfig, ActionsVariableConfig, BranchProtectionSettings,
+        CustomProperty, EnvironmentConfig, GitHubAppConfig, LabelConfig, NotificationsConfig,
+        PullRequestSettings, PushSettings, RepositoryNamingRulesConfig, RepositorySettings,
         RulesetConfig, WebhookConfig,
     },
     team_config::TeamConfig,
     template_config::TemplateConfig as NewTemplateConfig,
     OverridableValue,
 };
+use std::collections::HashMap;
 
 /// Configuration merging engine.
 ///
@@ -102,6 +119,9 @@ use crate::{
 ///         description: "Example".to_string(),
 ///         author: "Test".to_string(),
 ///         tags: vec![],
+///         default_description: None,
+///         default_topics: vec![],
+///         required_code_owners: vec![],
 ///     },
 ///     repository: None,
 ///     repository_type: None,
@@ -120,6 +140,10 @@ use crate::{
 ///     teams: None,
 ///     collaborators: None,
 ///     naming_rules: None,
+///     actions_variables: None,
+///     actions_secrets: None,
+///     variable_groups: None,
+///     base_template: None,
 /// };
 ///
 /// // Merge with all configuration levels
@@ -188,6 +212,9 @@ impl ConfigurationMerger {
     ///         description: "Example".to_string(),
     ///         author: "Test".to_string(),
     ///         tags: vec![],
+    ///         default_description: None,
+    ///         default_topics: vec![],
+    ///         required_code_owners: vec![],
     ///     },
     ///     repository: None,
     ///     repository_type: None,
@@ -206,6 +233,10 @@ impl ConfigurationMerger {
     ///     teams: None,
     ///     collaborators: None,
     ///     naming_rules: None,
+    ///     actions_variables: None,
+    ///     actions_secrets: None,
+    ///     variable_groups: None,
+    ///     base_template: None,
     /// };
     ///
     /// let merged = merger.merge_configurations(&global, None, None, &template)?;
@@ -294,6 +325,22 @@ impl ConfigurationMerger {
                 ConfigurationSource::Global,
             );
         }
+        if let Some(push_settings) = &global.push {
+            merged.push = push_settings.clone();
+            self.track_push_settings_sources(
+                &merged.push,
+                source_updates,
+                ConfigurationSource::Global,
+            );
+        }
+        if let Some(action_settings) = &global.actions {
+            merged.actions = action_settings.clone();
+            self.track_action_settings_sources(
+                &merged.actions,
+                source_updates,
+                ConfigurationSource::Global,
+            );
+        }
 
         // Merge global collections
         if let Some(webhooks) = &global.webhooks {
@@ -345,6 +392,20 @@ impl ConfigurationMerger {
                 ConfigurationSource::Global,
             ));
         }
+        if let Some(variables) = &global.actions_variables {
+            source_updates.extend(self.merge_actions_variables(
+                &mut merged.actions_variables,
+                variables,
+                ConfigurationSource::Global,
+            ));
+        }
+        if let Some(secrets) = &global.actions_secrets {
+            source_updates.extend(self.merge_actions_secrets(
+                &mut merged.actions_secrets,
+                secrets,
+                ConfigurationSource::Global,
+            ));
+        }
     }
 
     /// Applies repository type-specific overrides.
@@ -409,6 +470,13 @@ impl ConfigurationMerger {
         }
 
         // Merge collections additively
+        if let Some(labels) = &repo_type.labels {
+            source_updates.extend(self.merge_labels(
+                &mut merged.labels,
+                labels,
+                ConfigurationSource::RepositoryType,
+            ));
+        }
         if let Some(webhooks) = &repo_type.webhooks {
             source_updates.extend(self.merge_webhooks(
                 &mut merged.webhooks,
@@ -488,6 +556,10 @@ impl ConfigurationMerger {
             .and_then(|rt| rt.branch_protection.as_ref())
             .or(global.branch_protection.as_ref())
             .unwrap_or(&default_bp);
+        let default_push = PushSettings::default();
+        let base_push = global.push.as_ref().unwrap_or(&default_push);
+        let default_actions = ActionSettings::default();
+        let base_actions = global.actions.as_ref().unwrap_or(&default_actions);
 
         // Merge settings with override validation
         if let Some(override_repo) = &team.repository {
@@ -514,6 +586,22 @@ impl ConfigurationMerger {
                 ConfigurationSource::Team,
             )?);
         }
+        if let Some(override_push) = &team.push {
+            source_updates.extend(self.merge_push_settings(
+                &mut merged.push,
+                override_push,
+                base_push,
+                ConfigurationSource::Team,
+            )?);
+        }
+        if let Some(override_actions) = &team.actions {
+            source_updates.extend(self.merge_action_settings(
+                &mut merged.actions,
+                override_actions,
+                base_actions,
+                ConfigurationSource::Team,
+            )?);
+        }
 
         // Merge collections additively
         if let Some(webhooks) = &team.webhooks {
@@ -626,7 +714,24 @@ impl ConfigurationMerger {
             )?);
         }
 
+        // Required code owners are only enforced if their reviews are required
+        if !template.template.required_code_owners.is_empty() {
+            merged.branch_protection.require_code_owner_reviews =
+                Some(OverridableValue::fixed(true));
+            source_updates.push((
+                "branch_protection.require_code_owner_reviews".to_string(),
+                ConfigurationSource::Template,
+            ));
+        }
+
         // Merge collections additively
+        if let Some(labels) = &template.labels {
+            source_updates.extend(self.merge_labels(
+                &mut merged.labels,
+                labels,
+                ConfigurationSource::Template,
+            ));
+        }
         if let Some(webhooks) = &template.webhooks {
             source_updates.extend(self.merge_webhooks(
                 &mut merged.webhooks,
@@ -669,6 +774,20 @@ impl ConfigurationMerger {
                 ConfigurationSource::Template,
             ));
         }
+        if let Some(variables) = &template.actions_variables {
+            source_updates.extend(self.merge_actions_variables(
+                &mut merged.actions_variables,
+                variables,
+                ConfigurationSource::Template,
+            ));
+        }
+        if let Some(secrets) = &template.actions_secrets {
+            source_updates.extend(self.merge_actions_secrets(
+                &mut merged.actions_secrets,
+                secrets,
+                ConfigurationSource::Template,
+            ));
+        }
 
         Ok(())
     }
@@ -695,93 +814,77 @@ impl ConfigurationMerger {
     ) -> ConfigurationResult<Vec<(String, ConfigurationSource)>> {
         let mut source_updates = Vec::new();
 
-        // Merge issues
-        if let Some(override_value) = &override_settings.issues {
-            if let Some(base_value) = &base_settings.issues {
-                self.validate_override("repository.issues", base_value, &override_value.value)?;
-            }
-            target.issues = Some(override_value.clone());
-            source_updates.push(("repository.issues".to_string(), source));
-        }
+        self.merge_field(
+            "repository.issues",
+            &mut target.issues,
+            &override_settings.issues,
+            &base_settings.issues,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge projects
-        if let Some(override_value) = &override_settings.projects {
-            if let Some(base_value) = &base_settings.projects {
-                self.validate_override("repository.projects", base_value, &override_value.value)?;
-            }
-            target.projects = Some(override_value.clone());
-            source_updates.push(("repository.projects".to_string(), source));
-        }
+        self.merge_field(
+            "repository.projects",
+            &mut target.projects,
+            &override_settings.projects,
+            &base_settings.projects,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge discussions
-        if let Some(override_value) = &override_settings.discussions {
-            if let Some(base_value) = &base_settings.discussions {
-                self.validate_override(
-                    "repository.discussions",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.discussions = Some(override_value.clone());
-            source_updates.push(("repository.discussions".to_string(), source));
-        }
+        self.merge_field(
+            "repository.discussions",
+            &mut target.discussions,
+            &override_settings.discussions,
+            &base_settings.discussions,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge wiki
-        if let Some(override_value) = &override_settings.wiki {
-            if let Some(base_value) = &base_settings.wiki {
-                self.validate_override("repository.wiki", base_value, &override_value.value)?;
-            }
-            target.wiki = Some(override_value.clone());
-            source_updates.push(("repository.wiki".to_string(), source));
-        }
+        self.merge_field(
+            "repository.wiki",
+            &mut target.wiki,
+            &override_settings.wiki,
+            &base_settings.wiki,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge pages
-        if let Some(override_value) = &override_settings.pages {
-            if let Some(base_value) = &base_settings.pages {
-                self.validate_override("repository.pages", base_value, &override_value.value)?;
-            }
-            target.pages = Some(override_value.clone());
-            source_updates.push(("repository.pages".to_string(), source));
-        }
+        self.merge_field(
+            "repository.pages",
+            &mut target.pages,
+            &override_settings.pages,
+            &base_settings.pages,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge security_advisories
-        if let Some(override_value) = &override_settings.security_advisories {
-            if let Some(base_value) = &base_settings.security_advisories {
-                self.validate_override(
-                    "repository.security_advisories",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.security_advisories = Some(override_value.clone());
-            source_updates.push(("repository.security_advisories".to_string(), source));
-        }
+        self.merge_field(
+            "repository.security_advisories",
+            &mut target.security_advisories,
+            &override_settings.security_advisories,
+            &base_settings.security_advisories,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge vulnerability_reporting
-        if let Some(override_value) = &override_settings.vulnerability_reporting {
-            if let Some(base_value) = &base_settings.vulnerability_reporting {
-                self.validate_override(
-                    "repository.vulnerability_reporting",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.vulnerability_reporting = Some(override_value.clone());
-            source_updates.push(("repository.vulnerability_reporting".to_string(), source));
-        }
+        self.merge_field(
+            "repository.vulnerability_reporting",
+            &mut target.vulnerability_reporting,
+            &override_settings.vulnerability_reporting,
+            &base_settings.vulnerability_reporting,
+            source,
+            &mut source_updates,
+        )?;
 
-        // Merge auto_close_issues
-        if let Some(override_value) = &override_settings.auto_close_issues {
-            if let Some(base_value) = &base_settings.auto_close_issues {
-                self.validate_override(
-                    "repository.auto_close_issues",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.auto_close_issues = Some(override_value.clone());
-            source_updates.push(("repository.auto_close_issues".to_string(), source));
-        }
+        self.merge_field(
+            "repository.auto_close_issues",
+            &mut target.auto_close_issues,
+            &override_settings.auto_close_issues,
+            &base_settings.auto_close_issues,
+            source,
+            &mut source_updates,
+        )?;
 
         Ok(source_updates)
     }
@@ -796,111 +899,77 @@ impl ConfigurationMerger {
     ) -> ConfigurationResult<Vec<(String, ConfigurationSource)>> {
         let mut source_updates = Vec::new();
 
-        // Merge each field with validation
-        if let Some(override_value) = &override_settings.allow_auto_merge {
-            if let Some(base_value) = &base_settings.allow_auto_merge {
-                self.validate_override(
-                    "pull_requests.allow_auto_merge",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.allow_auto_merge = Some(override_value.clone());
-            source_updates.push(("pull_requests.allow_auto_merge".to_string(), source));
-        }
+        self.merge_field(
+            "pull_requests.allow_auto_merge",
+            &mut target.allow_auto_merge,
+            &override_settings.allow_auto_merge,
+            &base_settings.allow_auto_merge,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.allow_merge_commit {
-            if let Some(base_value) = &base_settings.allow_merge_commit {
-                self.validate_override(
-                    "pull_requests.allow_merge_commit",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.allow_merge_commit = Some(override_value.clone());
-            source_updates.push(("pull_requests.allow_merge_commit".to_string(), source));
-        }
+        self.merge_field(
+            "pull_requests.allow_merge_commit",
+            &mut target.allow_merge_commit,
+            &override_settings.allow_merge_commit,
+            &base_settings.allow_merge_commit,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.allow_rebase_merge {
-            if let Some(base_value) = &base_settings.allow_rebase_merge {
-                self.validate_override(
-                    "pull_requests.allow_rebase_merge",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.allow_rebase_merge = Some(override_value.clone());
-            source_updates.push(("pull_requests.allow_rebase_merge".to_string(), source));
-        }
+        self.merge_field(
+            "pull_requests.allow_rebase_merge",
+            &mut target.allow_rebase_merge,
+            &override_settings.allow_rebase_merge,
+            &base_settings.allow_rebase_merge,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.allow_squash_merge {
-            if let Some(base_value) = &base_settings.allow_squash_merge {
-                self.validate_override(
-                    "pull_requests.allow_squash_merge",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.allow_squash_merge = Some(override_value.clone());
-            source_updates.push(("pull_requests.allow_squash_merge".to_string(), source));
-        }
+        self.merge_field(
+            "pull_requests.allow_squash_merge",
+            &mut target.allow_squash_merge,
+            &override_settings.allow_squash_merge,
+            &base_settings.allow_squash_merge,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.delete_branch_on_merge {
-            if let Some(base_value) = &base_settings.delete_branch_on_merge {
-                self.validate_override(
-                    "pull_requests.delete_branch_on_merge",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.delete_branch_on_merge = Some(override_value.clone());
-            source_updates.push(("pull_requests.delete_branch_on_merge".to_string(), source));
-        }
+        self.merge_field(
+            "pull_requests.delete_branch_on_merge",
+            &mut target.delete_branch_on_merge,
+            &override_settings.delete_branch_on_merge,
+            &base_settings.delete_branch_on_merge,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.required_approving_review_count {
-            if let Some(base_value) = &base_settings.required_approving_review_count {
-                self.validate_override(
-                    "pull_requests.required_approving_review_count",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.required_approving_review_count = Some(override_value.clone());
-            source_updates.push((
-                "pull_requests.required_approving_review_count".to_string(),
-                source,
-            ));
-        }
+        self.merge_field(
+            "pull_requests.required_approving_review_count",
+            &mut target.required_approving_review_count,
+            &override_settings.required_approving_review_count,
+            &base_settings.required_approving_review_count,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.require_code_owner_reviews {
-            if let Some(base_value) = &base_settings.require_code_owner_reviews {
-                self.validate_override(
-                    "pull_requests.require_code_owner_reviews",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.require_code_owner_reviews = Some(override_value.clone());
-            source_updates.push((
-                "pull_requests.require_code_owner_reviews".to_string(),
-                source,
-            ));
-        }
+        self.merge_field(
+            "pull_requests.require_code_owner_reviews",
+            &mut target.require_code_owner_reviews,
+            &override_settings.require_code_owner_reviews,
+            &base_settings.require_code_owner_reviews,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.require_conversation_resolution {
-            if let Some(base_value) = &base_settings.require_conversation_resolution {
-                self.validate_override(
-                    "pull_requests.require_conversation_resolution",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.require_conversation_resolution = Some(override_value.clone());
-            source_updates.push((
-                "pull_requests.require_conversation_resolution".to_string(),
-                source,
-            ));
-        }
+        self.merge_field(
+            "pull_requests.require_conversation_resolution",
+            &mut target.require_conversation_resolution,
+            &override_settings.require_conversation_resolution,
+            &base_settings.require_conversation_resolution,
+            source,
+            &mut source_updates,
+        )?;
 
         Ok(source_updates)
     }
@@ -915,66 +984,156 @@ impl ConfigurationMerger {
     ) -> ConfigurationResult<Vec<(String, ConfigurationSource)>> {
         let mut source_updates = Vec::new();
 
-        if let Some(override_value) = &override_settings.default_branch {
-            if let Some(base_value) = &base_settings.default_branch {
-                self.validate_override(
-                    "branch_protection.default_branch",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.default_branch = Some(override_value.clone());
-            source_updates.push(("branch_protection.default_branch".to_string(), source));
-        }
+        self.merge_field(
+            "branch_protection.default_branch",
+            &mut target.default_branch,
+            &override_settings.default_branch,
+            &base_settings.default_branch,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.require_pull_request_reviews {
-            if let Some(base_value) = &base_settings.require_pull_request_reviews {
-                self.validate_override(
-                    "branch_protection.require_pull_request_reviews",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.require_pull_request_reviews = Some(override_value.clone());
-            source_updates.push((
-                "branch_protection.require_pull_request_reviews".to_string(),
-                source,
-            ));
-        }
+        self.merge_field(
+            "branch_protection.require_pull_request_reviews",
+            &mut target.require_pull_request_reviews,
+            &override_settings.require_pull_request_reviews,
+            &base_settings.require_pull_request_reviews,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.require_status_checks {
-            if let Some(base_value) = &base_settings.require_status_checks {
-                self.validate_override(
-                    "branch_protection.require_status_checks",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.require_status_checks = Some(override_value.clone());
-            source_updates.push((
-                "branch_protection.require_status_checks".to_string(),
-                source,
-            ));
-        }
+        self.merge_field(
+            "branch_protection.require_status_checks",
+            &mut target.require_status_checks,
+            &override_settings.require_status_checks,
+            &base_settings.require_status_checks,
+            source,
+            &mut source_updates,
+        )?;
 
-        if let Some(override_value) = &override_settings.restrict_pushes {
-            if let Some(base_value) = &base_settings.restrict_pushes {
-                self.validate_override(
-                    "branch_protection.restrict_pushes",
-                    base_value,
-                    &override_value.value,
-                )?;
-            }
-            target.restrict_pushes = Some(override_value.clone());
-            source_updates.push(("branch_protection.restrict_pushes".to_string(), source));
+        self.merge_field_with(
+            "branch_protection.required_status_checks",
+            &mut target.required_status_checks,
+            &override_settings.required_status_checks,
+            &base_settings.required_status_checks,
+            source,
+            &mut source_updates,
+            |contexts| contexts.join(", "),
+        )?;
+
+        self.merge_field(
+            "branch_protection.restrict_pushes",
+            &mut target.restrict_pushes,
+            &override_settings.restrict_pushes,
+            &base_settings.restrict_pushes,
+            source,
+            &mut source_updates,
+        )?;
+
+        Ok(source_updates)
+    }
+
+    /// Merges push settings with override policy validation.
+    fn merge_push_settings(
+        &self,
+        target: &mut PushSettings,
+        override_settings: &PushSettings,
+        base_settings: &PushSettings,
+        source: ConfigurationSource,
+    ) -> ConfigurationResult<Vec<(String, ConfigurationSource)>> {
+        let mut source_updates = Vec::new();
+
+        self.merge_field(
+            "push.allow_force_pushes",
+            &mut target.allow_force_pushes,
+            &override_settings.allow_force_pushes,
+            &base_settings.allow_force_pushes,
+            source,
+            &mut source_updates,
+        )?;
+
+        self.merge_field(
+            "push.require_signed_commits",
+            &mut target.require_signed_commits,
+            &override_settings.require_signed_commits,
+            &base_settings.require_signed_commits,
+            source,
+            &mut source_updates,
+        )?;
+
+        Ok(source_updates)
+    }
+
+    /// Merges GitHub Actions settings with override policy validation.
+    ///
+    /// `patterns_allowed` is not overridable; a team list replaces the
+    /// global one.
+    fn merge_action_settings(
+        &self,
+        target: &mut ActionSettings,
+        override_settings: &ActionSettings,
+        base_settings: &ActionSettings,
+        source: ConfigurationSource,
+    ) -> ConfigurationResult<Vec<(String, ConfigurationSource)>> {
+        let mut source_updates = Vec::new();
+
+        self.merge_field(
+            "actions.enabled",
+            &mut target.enabled,
+            &override_settings.enabled,
+            &base_settings.enabled,
+            source,
+            &mut source_updates,
+        )?;
+
+        self.merge_field(
+            "actions.allowed_actions",
+            &mut target.allowed_actions,
+            &override_settings.allowed_actions,
+            &base_settings.allowed_actions,
+            source,
+            &mut source_updates,
+        )?;
+
+        self.merge_field(
+            "actions.github_owned_allowed",
+            &mut target.github_owned_allowed,
+            &override_settings.github_owned_allowed,
+            &base_settings.github_owned_allowed,
+            source,
+            &mut source_updates,
+        )?;
+
+        self.merge_field(
+            "actions.verified_allowed",
+            &mut target.verified_allowed,
+            &override_settings.verified_allowed,
+            &base_settings.verified_allowed,
+            source,
+            &mut source_updates,
+        )?;
+
+        self.merge_field(
+            "actions.default_workflow_permissions",
+            &mut target.default_workflow_permissions,
+            &override_settings.default_workflow_permissions,
+            &base_settings.default_workflow_permissions,
+            source,
+            &mut source_updates,
+        )?;
+
+        if let Some(patterns) = &override_settings.patterns_allowed {
+            target.patterns_allowed = Some(patterns.clone());
+            source_updates.push(("actions.patterns_allowed".to_string(), source));
         }
 
         Ok(source_updates)
     }
 
-    /// Merges webhook collections additively.
+    /// Merges webhook collections additively, deduplicating by URL.
     ///
-    /// All webhooks from all sources are combined.
+    /// A webhook whose URL is already present replaces the existing entry, so
+    /// the higher-precedence layer's events and settings win.
     fn merge_webhooks(
         &self,
         target: &mut Vec<WebhookConfig>,
@@ -984,8 +1143,28 @@ impl ConfigurationMerger {
         let mut source_updates = Vec::new();
 
         for webhook in webhooks {
+            target.retain(|existing| existing.url != webhook.url);
             target.push(webhook.clone());
             source_updates.push(("webhooks".to_string(), source));
+            source_updates.push((format!("webhooks.{}", webhook.url), source));
+        }
+
+        source_updates
+    }
+
+    /// Merges labels by name; a higher-precedence layer replaces an existing
+    /// label with the same name.
+    fn merge_labels(
+        &self,
+        target: &mut HashMap<String, LabelConfig>,
+        labels: &[LabelConfig],
+        source: ConfigurationSource,
+    ) -> Vec<(String, ConfigurationSource)> {
+        let mut source_updates = Vec::new();
+
+        for label in labels {
+            target.insert(label.name.clone(), label.clone());
+            source_updates.push((format!("labels.{}", label.name), source));
         }
 
         source_updates
@@ -1010,9 +1189,11 @@ impl ConfigurationMerger {
         source_updates
     }
 
-    /// Merges GitHub App collections additively.
+    /// Merges GitHub App collections additively, deduplicating by app ID.
     ///
-    /// All GitHub Apps from all sources are combined.
+    /// An app that is already present is replaced by the higher-precedence
+    /// layer's entry, except that a `required` flag fixed by a lower layer
+    /// (`override_allowed = false`) is kept.
     fn merge_github_apps(
         &self,
         target: &mut Vec<GitHubAppConfig>,
@@ -1022,16 +1203,23 @@ impl ConfigurationMerger {
         let mut source_updates = Vec::new();
 
         for app in apps {
-            target.push(app.clone());
+            let mut app = app.clone();
+            if let Some(position) = target.iter().position(|e| e.app_id == app.app_id) {
+                let existing = target.remove(position);
+                if let Some(required) = existing.required.filter(|r| !r.can_override()) {
+                    app.required = Some(required);
+                }
+            }
             source_updates.push(("github_apps".to_string(), source));
+            source_updates.push((format!("github_apps.{}", app.app_id), source));
+            target.push(app);
         }
 
         source_updates
     }
 
-    /// Merges custom property collections additively.
-    ///
-    /// All custom properties from all sources are combined.
+    /// Merges custom properties by property name; a higher-precedence layer
+    /// replaces an existing property with the same name.
     fn merge_custom_properties(
         &self,
         target: &mut Vec<CustomProperty>,
@@ -1041,8 +1229,13 @@ impl ConfigurationMerger {
         let mut source_updates = Vec::new();
 
         for property in properties {
+            target.retain(|existing| existing.property_name != property.property_name);
             target.push(property.clone());
             source_updates.push(("custom_properties".to_string(), source));
+            source_updates.push((
+                format!("custom_properties.{}", property.property_name),
+                source,
+            ));
         }
 
         source_updates
@@ -1098,35 +1291,120 @@ impl ConfigurationMerger {
         source_updates
     }
 
-    /// Validates that an override is allowed.
+    /// Merges Actions variables, replacing existing variables with the same name.
+    fn merge_actions_variables(
+        &self,
+        target: &mut Vec<ActionsVariableConfig>,
+        variables: &[ActionsVariableConfig],
+        source: ConfigurationSource,
+    ) -> Vec<(String, ConfigurationSource)> {
+        let mut source_updates = Vec::new();
+
+        for variable in variables {
+            target.retain(|existing| existing.name != variable.name);
+            target.push(variable.clone());
+            source_updates.push(("actions_variables".to_string(), source));
+        }
+
+        source_updates
+    }
+
+    /// Merges Actions secrets, replacing existing secrets with the same name.
+    fn merge_actions_secrets(
+        &self,
+        target: &mut Vec<ActionsSecretConfig>,
+        secrets: &[ActionsSecretConfig],
+        source: ConfigurationSource,
+    ) -> Vec<(String, ConfigurationSource)> {
+        let mut source_updates = Vec::new();
+
+        for secret in secrets {
+            target.retain(|existing| existing.name != secret.name);
+            target.push(secret.clone());
+            source_updates.push(("actions_secrets".to_string(), source));
+        }
+
+        source_updates
+    }
+
+    /// Merges one overridable setting from a higher-precedence layer.
     ///
-    /// Checks if a setting can be overridden based on its `override_allowed` flag.
+    /// The override policy comes from the global value (`base_value`), see
+    /// [`OverridableValue::merge_with`]. When the layer does not set the field
+    /// the target is left untouched.
     ///
     /// # Arguments
     ///
     /// * `field_path` - Dot-separated path to the field (e.g., "repository.issues")
-    /// * `base_value` - The base value with override policy
-    /// * `override_value` - The value attempting to override
+    /// * `target` - The merged field to update
+    /// * `override_value` - The value the layer attempts to set
+    /// * `base_value` - The global value with override policy
+    /// * `source` - The layer's configuration source
+    /// * `source_updates` - Audit trail entries to extend
     ///
     /// # Errors
     ///
-    /// Returns `ConfigurationError::OverrideNotAllowed` if the base value prohibits
+    /// Returns `ConfigurationError::OverrideNotPermitted` if the base value prohibits
     /// overrides and the override value differs from the base.
-    fn validate_override<T: PartialEq + std::fmt::Display>(
+    fn merge_field<T: Clone + PartialEq + std::fmt::Display>(
         &self,
         field_path: &str,
-        base_value: &OverridableValue<T>,
-        override_value: &T,
+        target: &mut Option<OverridableValue<T>>,
+        override_value: &Option<OverridableValue<T>>,
+        base_value: &Option<OverridableValue<T>>,
+        source: ConfigurationSource,
+        source_updates: &mut Vec<(String, ConfigurationSource)>,
     ) -> ConfigurationResult<()> {
-        if !base_value.override_allowed && &base_value.value != override_value {
+        self.merge_field_with(
+            field_path,
+            target,
+            override_value,
+            base_value,
+            source,
+            source_updates,
+            T::to_string,
+        )
+    }
+
+    /// Like [`Self::merge_field`], for values without a `Display`
+    /// implementation; `describe` renders the rejected value in the error.
+    #[allow(clippy::too_many_arguments)]
+    fn merge_field_with<T: Clone + PartialEq>(
+        &self,
+        field_path: &str,
+        target: &mut Option<OverridableValue<T>>,
+        override_value: &Option<OverridableValue<T>>,
+        base_value: &Option<OverridableValue<T>>,
+        source: ConfigurationSource,
+        source_updates: &mut Vec<(String, ConfigurationSource)>,
+        describe: impl Fn(&T) -> String,
+    ) -> ConfigurationResult<()> {
+        let Some(override_value) = override_value else {
+            return Ok(());
+        };
+
+        let (merged, winner) = match base_value {
+            Some(base_value) => base_value.merge_with(
+                ConfigurationSource::Global,
+                Some(override_value.clone()),
+                source,
+            ),
+            None => (override_value.clone(), source),
+        };
+
+        if winner != source {
             return Err(ConfigurationError::OverrideNotPermitted {
                 setting: field_path.to_string(),
                 reason: format!(
                     "Cannot override '{}' with value '{}' - override not allowed by policy",
-                    field_path, override_value
+                    field_path,
+                    describe(&override_value.value)
                 ),
             });
         }
+
+        *target = Some(merged);
+        source_updates.push((field_path.to_string(), source));
         Ok(())
     }
 
@@ -1227,11 +1505,59 @@ impl ConfigurationMerger {
                 source,
             ));
         }
+        if settings.required_status_checks.is_some() {
+            source_updates.push((
+                "branch_protection.required_status_checks".to_string(),
+                source,
+            ));
+        }
         if settings.restrict_pushes.is_some() {
             source_updates.push(("branch_protection.restrict_pushes".to_string(), source));
         }
     }
 
+    /// Tracks source for all non-None push settings fields.
+    fn track_push_settings_sources(
+        &self,
+        settings: &PushSettings,
+        source_updates: &mut Vec<(String, ConfigurationSource)>,
+        source: ConfigurationSource,
+    ) {
+        if settings.allow_force_pushes.is_some() {
+            source_updates.push(("push.allow_force_pushes".to_string(), source));
+        }
+        if settings.require_signed_commits.is_some() {
+            source_updates.push(("push.require_signed_commits".to_string(), source));
+        }
+    }
+
+    /// Tracks source for all non-None Actions settings fields.
+    fn track_action_settings_sources(
+        &self,
+        settings: &ActionSettings,
+        source_updates: &mut Vec<(String, ConfigurationSource)>,
+        source: ConfigurationSource,
+    ) {
+        if settings.enabled.is_some() {
+            source_updates.push(("actions.enabled".to_string(), source));
+        }
+        if settings.allowed_actions.is_some() {
+            source_updates.push(("actions.allowed_actions".to_string(), source));
+        }
+        if settings.github_owned_allowed.is_some() {
+            source_updates.push(("actions.github_owned_allowed".to_string(), source));
+        }
+        if settings.verified_allowed.is_some() {
+            source_updates.push(("actions.verified_allowed".to_string(), source));
+        }
+        if settings.patterns_allowed.is_some() {
+            source_updates.push(("actions.patterns_allowed".to_string(), source));
+        }
+        if settings.default_workflow_permissions.is_some() {
+            source_updates.push(("actions.default_workflow_permissions".to_string(), source));
+        }
+    }
+
     /// Merges notification configurations additively.
     ///
     /// All outbound webhook endpoints from all sources are combined.
@@ -14,6 +14,9 @@ fn create_test_template() -> NewTemplateConfig {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -191,6 +194,9 @@ fn test_merge_template_rulesets() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         rulesets: Some(vec![RulesetConfig {
             name: "template-rule".to_string(),
@@ -297,6 +303,9 @@ fn test_merge_all_levels_rulesets() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         rulesets: Some(vec![RulesetConfig {
             name: "template-security".to_string(),
@@ -376,6 +385,9 @@ fn test_merge_empty_rulesets() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         rulesets: None,
         repository: None,
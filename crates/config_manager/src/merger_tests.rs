@@ -35,6 +35,9 @@ fn create_test_template() -> NewTemplateConfig {
             description: "Test template".to_string(),
             author: "Test Author".to_string(),
             tags: vec!["test".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: Some(RepositorySettings {
             issues: Some(OverridableValue::allowed(true)),
@@ -68,6 +71,9 @@ fn create_template_with_repository(repo_settings: RepositorySettings) -> NewTemp
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: Some(repo_settings),
         repository_type: None,
@@ -154,6 +160,34 @@ fn test_merge_global_and_template_only() {
     );
 }
 
+/// Verify that template code owners make code owner reviews mandatory.
+#[test]
+fn test_template_required_code_owners_require_code_owner_reviews() {
+    let merger = ConfigurationMerger::new();
+    let global = GlobalDefaults {
+        branch_protection: Some(BranchProtectionSettings {
+            require_code_owner_reviews: Some(OverridableValue::allowed(false)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut template = create_test_template();
+    template.template.required_code_owners = vec!["security".to_string()];
+
+    let merged = merger
+        .merge_configurations(&global, None, None, &template)
+        .expect("Merging should succeed");
+
+    assert_eq!(
+        merged.branch_protection.require_code_owner_reviews,
+        Some(OverridableValue::fixed(true))
+    );
+    assert_eq!(
+        merged.get_source("branch_protection.require_code_owner_reviews"),
+        Some(ConfigurationSource::Template)
+    );
+}
+
 /// Verify four-level hierarchy: Global → Repository Type → Team → Template.
 ///
 /// Each level should override the previous level according to precedence.
@@ -196,6 +230,9 @@ fn test_merge_four_level_hierarchy() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: Some(RepositorySettings {
             issues: Some(OverridableValue::allowed(false)), // Override global
@@ -303,6 +340,136 @@ fn test_team_cannot_override_fixed_global_setting() {
     }
 }
 
+/// Verify that team push settings override global ones and record their source.
+#[test]
+fn test_team_overrides_global_push_settings() {
+    let merger = ConfigurationMerger::new();
+
+    let global = GlobalDefaults {
+        push: Some(PushSettings {
+            allow_force_pushes: Some(OverridableValue::allowed(true)),
+            require_signed_commits: Some(OverridableValue::allowed(false)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        push: Some(PushSettings {
+            require_signed_commits: Some(OverridableValue::allowed(true)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let template = create_test_template();
+
+    let merged = merger
+        .merge_configurations(&global, None, Some(&team), &template)
+        .expect("Merge should succeed");
+
+    assert_eq!(
+        merged.push.allow_force_pushes.as_ref().map(|v| v.value),
+        Some(true)
+    );
+    assert_eq!(
+        merged.push.require_signed_commits.as_ref().map(|v| v.value),
+        Some(true)
+    );
+    assert_eq!(
+        merged.get_source("push.require_signed_commits"),
+        Some(ConfigurationSource::Team)
+    );
+    assert_eq!(
+        merged.get_source("push.allow_force_pushes"),
+        Some(ConfigurationSource::Global)
+    );
+}
+
+/// Verify that team Actions settings override global ones and record their source.
+#[test]
+fn test_team_overrides_global_action_settings() {
+    use crate::settings::WorkflowPermission;
+
+    let merger = ConfigurationMerger::new();
+
+    let global = GlobalDefaults {
+        actions: Some(ActionSettings {
+            enabled: Some(OverridableValue::fixed(true)),
+            default_workflow_permissions: Some(OverridableValue::allowed(WorkflowPermission::Read)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        actions: Some(ActionSettings {
+            default_workflow_permissions: Some(OverridableValue::allowed(
+                WorkflowPermission::Write,
+            )),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let template = create_test_template();
+
+    let merged = merger
+        .merge_configurations(&global, None, Some(&team), &template)
+        .expect("Merge should succeed");
+
+    assert_eq!(merged.actions.enabled.as_ref().map(|v| v.value), Some(true));
+    assert_eq!(
+        merged
+            .actions
+            .default_workflow_permissions
+            .as_ref()
+            .map(|v| v.value),
+        Some(WorkflowPermission::Write)
+    );
+    assert_eq!(
+        merged.get_source("actions.default_workflow_permissions"),
+        Some(ConfigurationSource::Team)
+    );
+    assert_eq!(
+        merged.get_source("actions.enabled"),
+        Some(ConfigurationSource::Global)
+    );
+}
+
+/// Verify that team cannot relax a fixed global signed-commit policy.
+#[test]
+fn test_team_cannot_override_fixed_signed_commit_policy() {
+    let merger = ConfigurationMerger::new();
+
+    let global = GlobalDefaults {
+        push: Some(PushSettings {
+            require_signed_commits: Some(OverridableValue::fixed(true)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        push: Some(PushSettings {
+            require_signed_commits: Some(OverridableValue::allowed(false)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let template = create_test_template();
+
+    let result = merger.merge_configurations(&global, None, Some(&team), &template);
+
+    match result {
+        Err(ConfigurationError::OverrideNotPermitted { setting, .. }) => {
+            assert_eq!(setting, "push.require_signed_commits");
+        }
+        other => panic!("Expected OverrideNotPermitted error, got {:?}", other),
+    }
+}
+
 /// Verify that template cannot override a non-overridable global setting.
 #[test]
 fn test_template_cannot_override_fixed_global_setting() {
@@ -470,6 +637,7 @@ fn test_github_apps_merge_additively() {
         github_apps: Some(vec![GitHubAppConfig {
             app_id: 12345,
             permissions: std::collections::HashMap::new(),
+            required: None,
         }]),
         ..Default::default()
     };
@@ -478,6 +646,7 @@ fn test_github_apps_merge_additively() {
         github_apps: Some(vec![GitHubAppConfig {
             app_id: 67890,
             permissions: std::collections::HashMap::new(),
+            required: None,
         }]),
         ..Default::default()
     };
@@ -502,6 +671,170 @@ fn test_github_apps_merge_additively() {
     assert!(app_ids.contains(&67890), "Team app should be present");
 }
 
+/// Verify that webhooks with the same URL are deduplicated, with the
+/// higher-precedence layer's version surviving.
+#[test]
+fn test_duplicate_webhook_urls_are_deduplicated() {
+    let merger = ConfigurationMerger::new();
+    let url = "https://hooks.example.com/ci";
+
+    let global = GlobalDefaults {
+        webhooks: Some(vec![WebhookConfig {
+            url: url.to_string(),
+            content_type: "json".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            secret: None,
+        }]),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        webhooks: Some(vec![WebhookConfig {
+            url: url.to_string(),
+            content_type: "json".to_string(),
+            events: vec!["push".to_string(), "pull_request".to_string()],
+            active: true,
+            secret: None,
+        }]),
+        ..Default::default()
+    };
+
+    let merged = merger
+        .merge_configurations(&global, None, Some(&team), &create_test_template())
+        .expect("Webhook merging should succeed");
+
+    assert_eq!(merged.webhooks.len(), 1, "Duplicate URL should be merged");
+    assert_eq!(
+        merged.webhooks[0].events,
+        vec!["push".to_string(), "pull_request".to_string()],
+        "Team webhook should replace the global one"
+    );
+    assert_eq!(
+        merged.get_source(&format!("webhooks.{}", url)),
+        Some(ConfigurationSource::Team)
+    );
+}
+
+/// Verify that labels with the same name are deduplicated, with the
+/// higher-precedence layer's version surviving.
+#[test]
+fn test_duplicate_label_names_higher_precedence_wins() {
+    let merger = ConfigurationMerger::new();
+
+    let repo_type = RepositoryTypeConfig {
+        labels: Some(vec![
+            LabelConfig {
+                name: "bug".to_string(),
+                color: "d73a4a".to_string(),
+                description: "Something isn't working".to_string(),
+            },
+            LabelConfig {
+                name: "service".to_string(),
+                color: "0e8a16".to_string(),
+                description: String::new(),
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let mut template = create_test_template();
+    template.labels = Some(vec![LabelConfig {
+        name: "bug".to_string(),
+        color: "b60205".to_string(),
+        description: "Defect".to_string(),
+    }]);
+
+    let merged = merger
+        .merge_configurations(
+            &GlobalDefaults::default(),
+            Some(&repo_type),
+            None,
+            &template,
+        )
+        .expect("Label merging should succeed");
+
+    assert_eq!(merged.labels.len(), 2);
+    assert_eq!(merged.labels["bug"].color, "b60205");
+    assert_eq!(
+        merged.get_source("labels.bug"),
+        Some(ConfigurationSource::Template)
+    );
+    assert_eq!(
+        merged.get_source("labels.service"),
+        Some(ConfigurationSource::RepositoryType)
+    );
+}
+
+/// Verify that GitHub Apps with the same app ID are deduplicated.
+#[test]
+fn test_duplicate_github_apps_are_deduplicated() {
+    let merger = ConfigurationMerger::new();
+
+    let global = GlobalDefaults {
+        github_apps: Some(vec![GitHubAppConfig {
+            app_id: 12345,
+            permissions: std::collections::HashMap::new(),
+            required: None,
+        }]),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        github_apps: Some(vec![GitHubAppConfig {
+            app_id: 12345,
+            permissions: std::collections::HashMap::from([(
+                "contents".to_string(),
+                "read".to_string(),
+            )]),
+            required: None,
+        }]),
+        ..Default::default()
+    };
+
+    let merged = merger
+        .merge_configurations(&global, None, Some(&team), &create_test_template())
+        .expect("GitHub App merging should succeed");
+
+    assert_eq!(merged.github_apps.len(), 1);
+    assert_eq!(merged.github_apps[0].permissions.len(), 1);
+    assert_eq!(
+        merged.get_source("github_apps.12345"),
+        Some(ConfigurationSource::Team)
+    );
+}
+
+/// Verify that a fixed `required` flag survives a higher-precedence app entry.
+#[test]
+fn test_fixed_github_app_required_flag_is_preserved() {
+    let merger = ConfigurationMerger::new();
+
+    let global = GlobalDefaults {
+        github_apps: Some(vec![GitHubAppConfig {
+            app_id: 12345,
+            permissions: std::collections::HashMap::new(),
+            required: Some(OverridableValue::fixed(true)),
+        }]),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        github_apps: Some(vec![GitHubAppConfig {
+            app_id: 12345,
+            permissions: std::collections::HashMap::new(),
+            required: Some(OverridableValue::allowed(false)),
+        }]),
+        ..Default::default()
+    };
+
+    let merged = merger
+        .merge_configurations(&global, None, Some(&team), &create_test_template())
+        .expect("GitHub App merging should succeed");
+
+    assert_eq!(merged.github_apps.len(), 1);
+    assert!(merged.github_apps[0].is_required());
+}
+
 // ============================================================================
 // Source Tracking Tests (Task 4.1)
 // ============================================================================
@@ -577,6 +910,9 @@ fn test_merge_with_empty_configurations() {
             description: "minimal".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -702,6 +1038,9 @@ fn test_complex_merge_scenario() {
             description: "Complex template".to_string(),
             author: "Test".to_string(),
             tags: vec!["complex".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: Some(RepositorySettings {
             issues: Some(OverridableValue::allowed(false)), // Override global
@@ -899,6 +1238,71 @@ fn test_custom_properties_merge_additively() {
         prop_names.contains(&"tech_stack"),
         "Repository type property should be present"
     );
+    assert_eq!(
+        merged.get_source("custom_properties.department"),
+        Some(ConfigurationSource::Global)
+    );
+    assert_eq!(
+        merged.get_source("custom_properties.tech_stack"),
+        Some(ConfigurationSource::RepositoryType)
+    );
+}
+
+/// Verify that a higher-precedence layer replaces a custom property with the
+/// same name.
+#[test]
+fn test_custom_property_overridden_by_team() {
+    let merger = ConfigurationMerger::new();
+
+    let repo_type = RepositoryTypeConfig {
+        custom_properties: Some(vec![
+            CustomProperty {
+                property_name: "tier".to_string(),
+                value: CustomPropertyValue::SingleSelect("silver".to_string()),
+            },
+            CustomProperty {
+                property_name: "tech_stack".to_string(),
+                value: CustomPropertyValue::String("rust".to_string()),
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let team = TeamConfig {
+        custom_properties: Some(vec![CustomProperty {
+            property_name: "tier".to_string(),
+            value: CustomPropertyValue::SingleSelect("gold".to_string()),
+        }]),
+        ..Default::default()
+    };
+
+    let merged = merger
+        .merge_configurations(
+            &GlobalDefaults::default(),
+            Some(&repo_type),
+            Some(&team),
+            &create_test_template(),
+        )
+        .expect("Custom property merging should succeed");
+
+    assert_eq!(merged.custom_properties.len(), 2);
+    let tier = merged
+        .custom_properties
+        .iter()
+        .find(|p| p.property_name == "tier")
+        .expect("tier should be present");
+    assert_eq!(
+        tier.value,
+        CustomPropertyValue::SingleSelect("gold".to_string())
+    );
+    assert_eq!(
+        merged.get_source("custom_properties.tier"),
+        Some(ConfigurationSource::Team)
+    );
+    assert_eq!(
+        merged.get_source("custom_properties.tech_stack"),
+        Some(ConfigurationSource::RepositoryType)
+    );
 }
 
 /// Test merging when repository type provides settings but global doesn't.
@@ -932,6 +1336,9 @@ fn test_repository_type_settings_without_global_base() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -1041,6 +1448,9 @@ fn test_template_settings_without_base() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: Some(RepositorySettings {
             wiki: Some(OverridableValue::allowed(false)),
@@ -1138,6 +1548,9 @@ fn test_all_repository_settings_fields() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -1277,6 +1690,7 @@ fn test_all_branch_protection_settings_fields() {
             dismiss_stale_reviews: None,
             require_code_owner_reviews: None,
             required_status_checks_list: None,
+            required_status_checks: None,
             strict_required_status_checks: None,
             allow_force_pushes: None,
             allow_deletions: None,
@@ -1349,6 +1763,9 @@ fn test_naming_rules_merge_additively_from_repository_type_and_template() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         naming_rules: Some(vec![RepositoryNamingRulesConfig {
             description: Some("Template rule: must end with -service".to_string()),
@@ -1417,6 +1834,7 @@ fn test_naming_rules_accumulated_from_three_levels() {
             allowed_pattern: Some("^[a-z0-9-]+$".to_string()),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
 
@@ -1435,6 +1853,9 @@ fn test_naming_rules_accumulated_from_three_levels() {
             description: "test".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         naming_rules: Some(vec![RepositoryNamingRulesConfig {
             description: Some("Template: must end with '-api'".to_string()),
@@ -1473,8 +1894,8 @@ fn test_naming_rules_accumulated_from_three_levels() {
 
 /// Verify that when no level provides labels the merged map is empty.
 ///
-/// Labels in `MergedConfiguration` are populated by `OrganizationSettingsManager`,
-/// not by `ConfigurationMerger`. The merger always returns an empty label map.
+/// Standard (global) labels are added by `OrganizationSettingsManager`, so the
+/// merger only produces labels defined by the repository type or template.
 #[test]
 fn test_no_labels_across_all_levels_yields_empty_map() {
     let merger = ConfigurationMerger::new();
@@ -1490,3 +1911,56 @@ fn test_no_labels_across_all_levels_yields_empty_map() {
         "No labels provided means empty merged map"
     );
 }
+
+/// Actions variables and secrets from global and template levels are combined,
+/// with the template replacing entries of the same name.
+#[test]
+fn test_actions_variables_and_secrets_merge_by_name() {
+    use crate::settings::{ActionsSecretConfig, ActionsVariableConfig};
+
+    let variable = |name: &str, value: &str| ActionsVariableConfig {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+    let secret = |name: &str, secret_ref: &str| ActionsSecretConfig {
+        name: name.to_string(),
+        secret_ref: secret_ref.to_string(),
+    };
+
+    let merger = ConfigurationMerger::new();
+    let global = GlobalDefaults {
+        actions_variables: Some(vec![
+            variable("RUST_VERSION", "1.79"),
+            variable("REGISTRY", "ghcr.io"),
+        ]),
+        actions_secrets: Some(vec![secret("DEPLOY_TOKEN", "ORG_DEPLOY_TOKEN")]),
+        ..Default::default()
+    };
+
+    let mut template = create_test_template();
+    template.actions_variables = Some(vec![variable("RUST_VERSION", "1.80")]);
+    template.actions_secrets = Some(vec![secret("CRATES_IO_TOKEN", "CRATES_IO_TOKEN")]);
+
+    let merged = merger
+        .merge_configurations(&global, None, None, &template)
+        .expect("Merge should succeed");
+
+    assert_eq!(
+        merged.actions_variables,
+        vec![
+            variable("REGISTRY", "ghcr.io"),
+            variable("RUST_VERSION", "1.80")
+        ]
+    );
+    assert_eq!(
+        merged.actions_secrets,
+        vec![
+            secret("DEPLOY_TOKEN", "ORG_DEPLOY_TOKEN"),
+            secret("CRATES_IO_TOKEN", "CRATES_IO_TOKEN")
+        ]
+    );
+    assert_eq!(
+        merged.get_source("actions_variables"),
+        Some(ConfigurationSource::Template)
+    );
+}
@@ -50,6 +50,7 @@ mod tests;
 /// Organizations can discover their metadata repository through:
 /// - Configuration-based: Explicitly named in application configuration
 /// - Topic-based: Discovered by searching for a specific GitHub topic
+/// - Combined file: Configuration read from one file such as `reporoller.toml`
 ///
 /// # Examples
 ///
@@ -73,6 +74,13 @@ pub enum DiscoveryMethod {
 
     /// Repository discovered by searching for a specific GitHub topic.
     TopicBased { topic: String },
+
+    /// Repository whose configuration lives in a single combined file rather
+    /// than the `global/`, `teams/` and `types/` layout.
+    CombinedFile {
+        repository_name: String,
+        file_path: String,
+    },
 }
 
 /// Metadata about the discovered organization configuration repository.
@@ -358,6 +366,34 @@ pub trait MetadataRepositoryProvider: Send + Sync {
         repo: &MetadataRepository,
     ) -> ConfigurationResult<Vec<WebhookConfig>>;
 
+    /// Prefetch the configuration files needed to resolve a configuration.
+    ///
+    /// Gives providers the chance to fetch the global, repository type and team
+    /// configuration files in bulk before they are loaded one by one through the
+    /// other `load_*` methods. The parsed results of those methods must be the
+    /// same whether or not a prefetch happened.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - Metadata repository information
+    /// * `repository_type` - Repository type whose configuration will be loaded, if any
+    /// * `teams` - Teams whose configurations will be loaded
+    ///
+    /// # Errors
+    ///
+    /// Implementations should treat prefetch failures as non-fatal and fall back
+    /// to loading files individually.
+    async fn prefetch_configuration_files(
+        &self,
+        _repo: &MetadataRepository,
+        _repository_type: Option<&str>,
+        _teams: &[String],
+    ) -> ConfigurationResult<()> {
+        Ok(())
+    }
+
     /// List all available repository types.
     ///
     /// Scans the `types/` directory to find all repository type configurations
@@ -252,6 +252,17 @@ mod mock_provider {
                 default_teams: None,
                 default_collaborators: None,
                 naming_rules: None,
+                allow_template_post_generation: None,
+                allowed_templates: None,
+                denied_templates: None,
+                actions_variables: None,
+                actions_secrets: None,
+                signed_initial_commit: None,
+                generate_codeowners: None,
+                replace_default_labels: None,
+                auto_init: None,
+                gitignore_template: None,
+                license_template: None,
             })
         }
 
@@ -47,8 +47,8 @@
 
 use crate::{
     basic_validator::BasicConfigurationValidator, errors::ConfigurationResult,
-    merger::ConfigurationMerger, metadata_provider::MetadataRepositoryProvider,
-    validator::ConfigurationValidator,
+    merged_config::ConfigurationSource, merger::ConfigurationMerger,
+    metadata_provider::MetadataRepositoryProvider, validator::ConfigurationValidator,
 };
 use std::sync::Arc;
 use tracing::{debug, info, instrument, warn};
@@ -172,6 +172,9 @@ impl OrganizationSettingsManager {
     /// Returns `ConfigurationError` if:
     /// - Metadata repository cannot be discovered
     /// - Configuration files cannot be loaded or parsed
+    /// - The organization does not allow the requested template
+    ///   (`ConfigurationError::TemplateNotAllowed`)
+    /// - Several teams set conflicting override values (see [`crate::TeamConfig::combine`])
     /// - Override policies are violated during merging
     ///
     /// # Examples
@@ -204,7 +207,7 @@ impl OrganizationSettingsManager {
         fields(
             organization = %context.organization(),
             template = %context.template(),
-            team = ?context.team(),
+            teams = ?context.teams(),
             repository_type = ?context.repository_type()
         )
     )]
@@ -230,6 +233,15 @@ impl OrganizationSettingsManager {
             metadata_repo.repository_name, metadata_repo.discovery_method
         );
 
+        // Step 1.5: Fetch the configuration files in bulk where the provider supports it
+        self.metadata_provider
+            .prefetch_configuration_files(
+                &metadata_repo,
+                context.repository_type(),
+                context.teams(),
+            )
+            .await?;
+
         // Step 2: Load global defaults
         debug!("Loading global defaults");
         let global_defaults = self
@@ -243,6 +255,21 @@ impl OrganizationSettingsManager {
 
         debug!("Global defaults loaded successfully");
 
+        // Step 2.1: Reject templates the organization does not permit
+        if !context.template().is_empty()
+            && !global_defaults.is_template_allowed(context.template())
+        {
+            warn!(
+                "Template '{}' is not allowed in organization '{}'",
+                context.template(),
+                context.organization()
+            );
+            return Err(crate::errors::ConfigurationError::TemplateNotAllowed {
+                org: context.organization().to_string(),
+                template: context.template().to_string(),
+            });
+        }
+
         // Step 2.5: Load standard labels from global configuration
         debug!("Loading standard labels");
         let standard_labels = self
@@ -304,8 +331,9 @@ impl OrganizationSettingsManager {
             None
         };
 
-        // Step 4: Load team configuration (if specified)
-        let team_config = if let Some(team) = context.team() {
+        // Step 4: Load team configuration(s) (if specified)
+        let mut team_configs = Vec::new();
+        for team in context.teams() {
             debug!("Loading team configuration: {}", team);
             let config = self
                 .metadata_provider
@@ -316,16 +344,30 @@ impl OrganizationSettingsManager {
                     e
                 })?;
 
-            if config.is_some() {
+            if let Some(config) = config {
                 info!("Team configuration loaded: {}", team);
+                team_configs.push((team.clone(), config));
             } else {
                 debug!("No team configuration found for: {}", team);
             }
+        }
 
-            config
-        } else {
-            debug!("No team specified");
-            None
+        // Step 4.5: Combine configurations of multiple owning teams in request order
+        let team_config = match team_configs.len() {
+            0 => {
+                debug!("No team configuration to apply");
+                None
+            }
+            1 => team_configs.pop().map(|(_, config)| config),
+            count => {
+                debug!("Combining {} team configurations", count);
+                Some(
+                    crate::team_config::TeamConfig::combine(&team_configs).map_err(|e| {
+                        warn!("Failed to combine team configurations: {}", e);
+                        e
+                    })?,
+                )
+            }
         };
 
         // Step 5: Load template configuration from template repository
@@ -341,6 +383,9 @@ impl OrganizationSettingsManager {
                     description: String::new(),
                     author: String::new(),
                     tags: vec![],
+                    default_description: None,
+                    default_topics: vec![],
+                    required_code_owners: vec![],
                 },
                 repository_type: None,
                 variables: None,
@@ -359,6 +404,10 @@ impl OrganizationSettingsManager {
                 teams: None,
                 collaborators: None,
                 naming_rules: None,
+                actions_variables: None,
+                actions_secrets: None,
+                variable_groups: None,
+                base_template: None,
             }
         } else {
             match self
@@ -401,12 +450,17 @@ impl OrganizationSettingsManager {
             })?;
 
         // Step 6.5: Merge standard labels into configuration
-        // Standard labels act as the baseline, and labels from other sources
-        // (repository type, team, template) override them by name
+        // Standard labels act as the baseline; repository type and template
+        // labels were already merged by name and take precedence over them
         debug!("Merging standard labels into configuration");
         for (label_name, label_config) in standard_labels {
-            // Only add if not already present (higher precedence sources override)
-            merged.labels.entry(label_name).or_insert(label_config);
+            if !merged.labels.contains_key(&label_name) {
+                merged.record_source(
+                    &format!("labels.{}", label_name),
+                    ConfigurationSource::Global,
+                );
+                merged.labels.insert(label_name, label_config);
+            }
         }
 
         // Step 6.5b: Merge global webhooks into configuration
@@ -416,24 +470,14 @@ impl OrganizationSettingsManager {
             // Check if webhook with same URL already exists (to avoid duplicates)
             let webhook_exists = merged.webhooks.iter().any(|w| w.url == webhook_config.url);
             if !webhook_exists {
+                merged.record_source(
+                    &format!("webhooks.{}", webhook_config.url),
+                    ConfigurationSource::Global,
+                );
                 merged.webhooks.push(webhook_config);
             }
         }
 
-        // Step 6.6: Merge template-specific labels into configuration
-        // Template labels are added on top of standard labels
-        if let Some(template_labels) = &template_config.labels {
-            debug!(
-                "Merging {} template-specific labels into configuration",
-                template_labels.len()
-            );
-            for label_config in template_labels {
-                merged
-                    .labels
-                    .insert(label_config.name.clone(), label_config.clone());
-            }
-        }
-
         if !merged.labels.is_empty() {
             info!(
                 "Configuration has {} labels after merging",
@@ -587,6 +631,37 @@ impl OrganizationSettingsManager {
                 debug!("Org max collaborator access level: {}", max_collab);
             }
         }
+        if let Some(allowed) = global_defaults.allow_template_post_generation {
+            merged.allow_template_post_generation = allowed;
+            debug!(
+                "Org allows template post-generation transforms: {}",
+                allowed
+            );
+        }
+        if let Some(signed) = global_defaults.signed_initial_commit {
+            merged.signed_initial_commit = signed;
+            debug!("Org signed initial commit: {}", signed);
+        }
+        if let Some(generate) = global_defaults.generate_codeowners {
+            merged.generate_codeowners = generate;
+            debug!("Org generates CODEOWNERS: {}", generate);
+        }
+        if let Some(replace) = global_defaults.replace_default_labels {
+            merged.replace_default_labels = replace;
+            debug!("Org replaces default labels: {}", replace);
+        }
+        if let Some(auto_init) = global_defaults.auto_init {
+            merged.auto_init = auto_init;
+            debug!("Org auto-initializes repositories: {}", auto_init);
+        }
+        if let Some(gitignore) = &global_defaults.gitignore_template {
+            merged.gitignore_template = Some(gitignore.clone());
+            debug!("Org gitignore template: {}", gitignore);
+        }
+        if let Some(license) = &global_defaults.license_template {
+            merged.license_template = Some(license.clone());
+            debug!("Org license template: {}", license);
+        }
 
         // Step 7: Validate merged configuration
         debug!("Validating merged configuration");
@@ -30,6 +30,9 @@ impl TemplateRepository for MockTemplateRepository {
                 description: format!("Test template: {}", template_name),
                 author: "Test Author".to_string(),
                 tags: vec![],
+                default_description: None,
+                default_topics: vec![],
+                required_code_owners: vec![],
             },
             repository_type: None,
             variables: None,
@@ -48,6 +51,10 @@ impl TemplateRepository for MockTemplateRepository {
             teams: None,
             collaborators: None,
             naming_rules: None,
+            actions_variables: None,
+            actions_secrets: None,
+            variable_groups: None,
+            base_template: None,
         })
     }
 
@@ -488,6 +495,9 @@ impl TemplateRepository for EmptySensitiveTemplateRepository {
                 description: format!("Test template: {}", template_name),
                 author: "Test Author".to_string(),
                 tags: vec![],
+                default_description: None,
+                default_topics: vec![],
+                required_code_owners: vec![],
             },
             repository_type: None,
             variables: None,
@@ -506,6 +516,10 @@ impl TemplateRepository for EmptySensitiveTemplateRepository {
             teams: None,
             collaborators: None,
             naming_rules: None,
+            actions_variables: None,
+            actions_secrets: None,
+            variable_groups: None,
+            base_template: None,
         })
     }
 
@@ -638,6 +652,10 @@ async fn test_resolve_configuration_with_empty_template_preserves_standard_label
         merged.labels.contains_key("bug"),
         "Standard labels should be preserved when no template is provided"
     );
+    assert_eq!(
+        merged.get_source("labels.bug"),
+        Some(crate::ConfigurationSource::Global)
+    );
 }
 // ============================================================================
 // Permission Protection Tests
@@ -775,6 +793,9 @@ impl TemplateRepository for PermissionTestTemplateRepository {
                 description: "Permission test template".to_string(),
                 author: "test".to_string(),
                 tags: vec![],
+                default_description: None,
+                default_topics: vec![],
+                required_code_owners: vec![],
             },
             repository_type: None,
             variables: None,
@@ -793,6 +814,10 @@ impl TemplateRepository for PermissionTestTemplateRepository {
             teams: self.teams.clone(),
             collaborators: self.collaborators.clone(),
             naming_rules: None,
+            actions_variables: None,
+            actions_secrets: None,
+            variable_groups: None,
+            base_template: None,
         })
     }
 
@@ -1144,3 +1169,168 @@ async fn test_resolve_configuration_max_access_levels_absent_when_not_configured
         "max_collaborator_access_level should be None when not configured"
     );
 }
+
+// --- Template post-generation policy tests ---
+
+/// Verify that the org can disable template post-generation transforms and
+/// that they are allowed when the setting is absent.
+#[tokio::test]
+async fn test_resolve_configuration_allow_template_post_generation() {
+    let context = crate::ConfigurationContext::new("test-org", "");
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        GlobalDefaults::default(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(merged.allow_template_post_generation);
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(GlobalDefaults {
+        allow_template_post_generation: Some(false),
+        ..Default::default()
+    }));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(!merged.allow_template_post_generation);
+}
+
+/// Verify that signed initial commits are off by default and can be enabled
+/// by the organization.
+#[tokio::test]
+async fn test_resolve_configuration_signed_initial_commit() {
+    let context = crate::ConfigurationContext::new("test-org", "");
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        GlobalDefaults::default(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(!merged.signed_initial_commit);
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(GlobalDefaults {
+        signed_initial_commit: Some(true),
+        ..Default::default()
+    }));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(merged.signed_initial_commit);
+}
+
+/// Verify that CODEOWNERS generation is off by default and can be enabled
+/// by the organization.
+#[tokio::test]
+async fn test_resolve_configuration_generate_codeowners() {
+    let context = crate::ConfigurationContext::new("test-org", "");
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        GlobalDefaults::default(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(!merged.generate_codeowners);
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(GlobalDefaults {
+        generate_codeowners: Some(true),
+        ..Default::default()
+    }));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(merged.generate_codeowners);
+}
+
+/// Verify that replacing GitHub's default labels is off by default and can be
+/// enabled by the organization.
+#[tokio::test]
+async fn test_resolve_configuration_replace_default_labels() {
+    let context = crate::ConfigurationContext::new("test-org", "");
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        GlobalDefaults::default(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(!merged.replace_default_labels);
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(GlobalDefaults {
+        replace_default_labels: Some(true),
+        ..Default::default()
+    }));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(merged.replace_default_labels);
+}
+
+/// Verify that the organization's repository initialization defaults reach the
+/// merged configuration.
+#[tokio::test]
+async fn test_resolve_configuration_repository_initialization_defaults() {
+    let context = crate::ConfigurationContext::new("test-org", "");
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        GlobalDefaults::default(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(!merged.auto_init);
+    assert_eq!(merged.gitignore_template, None);
+    assert_eq!(merged.license_template, None);
+
+    let provider = Arc::new(PermissionTestMetadataProvider::new(GlobalDefaults {
+        auto_init: Some(true),
+        gitignore_template: Some("Rust".to_string()),
+        license_template: Some("mit".to_string()),
+        ..Default::default()
+    }));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+    let merged = manager.resolve_configuration(&context).await.unwrap();
+    assert!(merged.auto_init);
+    assert_eq!(merged.gitignore_template.as_deref(), Some("Rust"));
+    assert_eq!(merged.license_template.as_deref(), Some("mit"));
+}
+
+// --- Template allow/deny policy tests ---
+
+fn template_policy_defaults() -> GlobalDefaults {
+    GlobalDefaults {
+        allowed_templates: Some(vec!["rust-*".to_string()]),
+        denied_templates: Some(vec!["rust-internal-*".to_string()]),
+        ..Default::default()
+    }
+}
+
+/// Verify that a template matching the org allowlist resolves normally.
+#[tokio::test]
+async fn test_resolve_configuration_allows_template_matching_allowlist() {
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        template_policy_defaults(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+
+    let context = crate::ConfigurationContext::new("test-org", "rust-service");
+    assert!(manager.resolve_configuration(&context).await.is_ok());
+
+    // Requests without a template are not subject to the template policy.
+    let context = crate::ConfigurationContext::new("test-org", "");
+    assert!(manager.resolve_configuration(&context).await.is_ok());
+}
+
+/// Verify that denied templates and templates outside the allowlist are rejected.
+#[tokio::test]
+async fn test_resolve_configuration_rejects_disallowed_templates() {
+    let provider = Arc::new(PermissionTestMetadataProvider::new(
+        template_policy_defaults(),
+    ));
+    let manager = OrganizationSettingsManager::new(provider, create_test_template_loader());
+
+    for template in ["rust-internal-tools", "python-service"] {
+        let context = crate::ConfigurationContext::new("test-org", template);
+        let result = manager.resolve_configuration(&context).await;
+        assert_eq!(
+            result.unwrap_err(),
+            crate::ConfigurationError::TemplateNotAllowed {
+                org: "test-org".to_string(),
+                template: template.to_string(),
+            }
+        );
+    }
+}
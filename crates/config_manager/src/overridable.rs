@@ -6,6 +6,7 @@
 //!
 //! See: specs/design/organization-repository-settings.md
 
+use crate::merged_config::ConfigurationSource;
 use serde::{Deserialize, Deserializer, Serialize};
 
 /// A value that can optionally be overridden by higher-precedence configuration levels.
@@ -152,6 +153,44 @@ impl<T> OverridableValue<T> {
     }
 }
 
+impl<T: Clone + PartialEq> OverridableValue<T> {
+    /// Merge a higher-precedence value over this one and report which layer won.
+    ///
+    /// The higher value wins when it is present and either this value allows
+    /// overrides or both values are equal (restating a fixed policy is not a
+    /// violation). Otherwise this value is kept. A denied override is detected
+    /// by the returned source being `self_source` while `higher` was present
+    /// with a different value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use config_manager::{ConfigurationSource, OverridableValue};
+    ///
+    /// let policy = OverridableValue::fixed(false);
+    /// let (merged, source) = policy.merge_with(
+    ///     ConfigurationSource::Global,
+    ///     Some(OverridableValue::allowed(true)),
+    ///     ConfigurationSource::Team,
+    /// );
+    /// assert!(!merged.value);
+    /// assert_eq!(source, ConfigurationSource::Global);
+    /// ```
+    pub fn merge_with(
+        &self,
+        self_source: ConfigurationSource,
+        higher: Option<OverridableValue<T>>,
+        higher_source: ConfigurationSource,
+    ) -> (OverridableValue<T>, ConfigurationSource) {
+        match higher {
+            Some(higher) if self.override_allowed || higher.value == self.value => {
+                (higher, higher_source)
+            }
+            _ => (self.clone(), self_source),
+        }
+    }
+}
+
 // Custom deserialization to support both explicit and simple formats
 impl<'de, T> Deserialize<'de> for OverridableValue<T>
 where
@@ -374,3 +374,59 @@ fn test_mixed_formats_in_same_config() {
     assert!(!config.team.projects.value);
     assert!(config.team.projects.override_allowed);
 }
+
+#[test]
+fn test_merge_with_override_allowed_takes_higher_value() {
+    let base = OverridableValue::allowed(1);
+
+    let (merged, source) = base.merge_with(
+        ConfigurationSource::Global,
+        Some(OverridableValue::allowed(3)),
+        ConfigurationSource::Team,
+    );
+
+    assert_eq!(merged.value, 3);
+    assert_eq!(source, ConfigurationSource::Team);
+}
+
+#[test]
+fn test_merge_with_override_denied_keeps_base_value() {
+    let base = OverridableValue::fixed(false);
+
+    let (merged, source) = base.merge_with(
+        ConfigurationSource::Global,
+        Some(OverridableValue::allowed(true)),
+        ConfigurationSource::Template,
+    );
+
+    assert_eq!(merged, OverridableValue::fixed(false));
+    assert_eq!(source, ConfigurationSource::Global);
+}
+
+#[test]
+fn test_merge_with_fixed_policy_accepts_same_value() {
+    let base = OverridableValue::fixed(true);
+
+    let (merged, source) = base.merge_with(
+        ConfigurationSource::Global,
+        Some(OverridableValue::allowed(true)),
+        ConfigurationSource::Team,
+    );
+
+    assert!(merged.value);
+    assert_eq!(source, ConfigurationSource::Team);
+}
+
+#[test]
+fn test_merge_with_higher_absent_keeps_base_value() {
+    let base = OverridableValue::allowed("main".to_string());
+
+    let (merged, source) = base.merge_with(
+        ConfigurationSource::RepositoryType,
+        None,
+        ConfigurationSource::Team,
+    );
+
+    assert_eq!(merged, base);
+    assert_eq!(source, ConfigurationSource::RepositoryType);
+}
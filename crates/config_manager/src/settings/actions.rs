@@ -1,6 +1,7 @@
 //! GitHub Actions settings.
 //!
-//! Controls GitHub Actions permissions and behavior.
+//! Controls GitHub Actions permissions and behavior, and the Actions variables
+//! and secrets provisioned on new repositories.
 
 use crate::OverridableValue;
 use serde::{Deserialize, Serialize};
@@ -22,6 +23,88 @@ pub struct ActionSettings {
 
     /// List of allowed action patterns
     pub patterns_allowed: Option<Vec<String>>,
+
+    /// Default permissions of the `GITHUB_TOKEN` given to workflows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_workflow_permissions: Option<OverridableValue<WorkflowPermission>>,
+}
+
+/// Default permissions of the `GITHUB_TOKEN` given to workflows.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [actions]
+/// default_workflow_permissions = "read"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkflowPermission {
+    /// Read access to repository contents and packages
+    Read,
+    /// Read and write access to all scopes
+    Write,
+}
+
+impl WorkflowPermission {
+    /// Returns the value GitHub uses for this permission.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+}
+
+impl std::fmt::Display for WorkflowPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A GitHub Actions variable created on new repositories.
+///
+/// Variables are stored in plain text and are intended for non-sensitive
+/// defaults. Use [`ActionsSecretConfig`] for sensitive values.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [[actions_variables]]
+/// name = "RUST_VERSION"
+/// value = "1.80"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionsVariableConfig {
+    /// Variable name
+    pub name: String,
+
+    /// Variable value
+    pub value: String,
+}
+
+/// A GitHub Actions secret created on new repositories.
+///
+/// Secret values are never stored in configuration. `secret_ref` identifies the
+/// value in the external secret store (for the default resolver, the name of
+/// an environment variable); it is resolved when the repository is created.
+/// Unknown keys such as `value` are rejected to prevent plaintext secrets.
+///
+/// # TOML Format
+///
+/// ```toml
+/// [[actions_secrets]]
+/// name = "DEPLOY_TOKEN"
+/// secret_ref = "DEPLOY_TOKEN_PROD"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActionsSecretConfig {
+    /// Secret name
+    pub name: String,
+
+    /// Reference to the secret value in the external secret store
+    pub secret_ref: String,
 }
 
 #[cfg(test)]
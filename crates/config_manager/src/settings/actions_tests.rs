@@ -5,3 +5,42 @@ fn test_default_creates_empty() {
     let settings = ActionSettings::default();
     assert!(settings.enabled.is_none());
 }
+
+#[test]
+fn test_workflow_permission_maps_to_github_values() {
+    assert_eq!(WorkflowPermission::Read.as_str(), "read");
+    assert_eq!(WorkflowPermission::Write.as_str(), "write");
+}
+
+#[test]
+fn test_default_workflow_permissions_deserializes_from_toml() {
+    let settings: ActionSettings =
+        toml::from_str("enabled = true\ndefault_workflow_permissions = \"read\"").unwrap();
+    assert_eq!(
+        settings.default_workflow_permissions.map(|p| p.value),
+        Some(WorkflowPermission::Read)
+    );
+}
+
+#[test]
+fn test_actions_variable_deserializes_from_toml() {
+    let variable: ActionsVariableConfig =
+        toml::from_str("name = \"RUST_VERSION\"\nvalue = \"1.80\"").unwrap();
+    assert_eq!(variable.name, "RUST_VERSION");
+    assert_eq!(variable.value, "1.80");
+}
+
+#[test]
+fn test_actions_secret_deserializes_reference() {
+    let secret: ActionsSecretConfig =
+        toml::from_str("name = \"DEPLOY_TOKEN\"\nsecret_ref = \"DEPLOY_TOKEN_PROD\"").unwrap();
+    assert_eq!(secret.name, "DEPLOY_TOKEN");
+    assert_eq!(secret.secret_ref, "DEPLOY_TOKEN_PROD");
+}
+
+#[test]
+fn test_actions_secret_rejects_plaintext_value() {
+    let result: Result<ActionsSecretConfig, _> =
+        toml::from_str("name = \"DEPLOY_TOKEN\"\nvalue = \"hunter2\"");
+    assert!(result.is_err());
+}
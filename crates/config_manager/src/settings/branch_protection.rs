@@ -31,6 +31,10 @@ pub struct BranchProtectionSettings {
     /// Required status checks (list of check names)
     pub required_status_checks_list: Option<Vec<String>>,
 
+    /// Status check contexts that must pass before merging into the default
+    /// branch. An empty list disables required status checks.
+    pub required_status_checks: Option<OverridableValue<Vec<String>>>,
+
     /// Require branches to be up to date before merging
     pub strict_required_status_checks: Option<OverridableValue<bool>>,
 
@@ -5,3 +5,15 @@ fn test_default_creates_empty() {
     let settings = BranchProtectionSettings::default();
     assert!(settings.default_branch.is_none());
 }
+
+#[test]
+fn test_required_status_checks_deserialize() {
+    let settings: BranchProtectionSettings = toml::from_str(
+        r#"required_status_checks = { value = ["ci/build", "ci/test"], override_allowed = false }"#,
+    )
+    .expect("Failed to parse");
+
+    let checks = settings.required_status_checks.expect("checks should be set");
+    assert_eq!(checks.value, vec!["ci/build", "ci/test"]);
+    assert!(!checks.override_allowed);
+}
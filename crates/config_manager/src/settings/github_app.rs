@@ -1,11 +1,23 @@
 //! GitHub App configuration.
 
+use crate::OverridableValue;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// GitHub App configuration.
 ///
 /// Defines a GitHub App that should be installed on the repository.
+///
+/// Apps marked `required` must already be installed in the organization;
+/// repository creation fails if one is missing. Other apps are best-effort and
+/// only produce a warning when they are not installed.
+///
+/// ```toml
+/// [[github_apps]]
+/// app_id = 12345
+/// permissions = { checks = "write" }
+/// required = { value = true, override_allowed = false }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GitHubAppConfig {
     /// GitHub App ID
@@ -13,6 +25,17 @@ pub struct GitHubAppConfig {
 
     /// Permissions required for this app
     pub permissions: HashMap<String, String>,
+
+    /// Whether the app must be installed for repository creation to succeed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<OverridableValue<bool>>,
+}
+
+impl GitHubAppConfig {
+    /// Returns `true` if a missing installation of this app is fatal.
+    pub fn is_required(&self) -> bool {
+        self.required.as_ref().is_some_and(|r| r.value)
+    }
 }
 
 #[cfg(test)]
@@ -6,6 +6,31 @@ fn test_github_app_creation() {
     let app = GitHubAppConfig {
         app_id: 12345,
         permissions: HashMap::new(),
+        required: None,
     };
     assert_eq!(app.app_id, 12345);
 }
+
+#[test]
+fn test_github_app_required_flag_parses_from_toml() {
+    let toml = r#"
+        app_id = 12345
+        permissions = { checks = "write" }
+        required = { value = true, override_allowed = false }
+    "#;
+
+    let app: GitHubAppConfig = toml::from_str(toml).expect("Failed to parse");
+    assert!(app.is_required());
+    assert_eq!(app.required, Some(OverridableValue::fixed(true)));
+}
+
+#[test]
+fn test_github_app_is_optional_by_default() {
+    let toml = r#"
+        app_id = 12345
+        permissions = { checks = "write" }
+    "#;
+
+    let app: GitHubAppConfig = toml::from_str(toml).expect("Failed to parse");
+    assert!(!app.is_required());
+}
@@ -21,7 +21,7 @@ pub mod ruleset;
 pub mod webhook;
 
 // Re-export all types for convenient access
-pub use actions::ActionSettings;
+pub use actions::{ActionSettings, ActionsSecretConfig, ActionsVariableConfig, WorkflowPermission};
 pub use branch_protection::BranchProtectionSettings;
 pub use custom_property::CustomProperty;
 pub use environment::EnvironmentConfig;
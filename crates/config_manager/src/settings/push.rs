@@ -11,6 +11,12 @@ pub struct PushSettings {
 
     /// Maximum number of tags that can be pushed at once
     pub max_tags_per_push: Option<OverridableValue<i32>>,
+
+    /// Allow force pushes to the default branch
+    pub allow_force_pushes: Option<OverridableValue<bool>>,
+
+    /// Require signed commits on the default branch
+    pub require_signed_commits: Option<OverridableValue<bool>>,
 }
 
 #[cfg(test)]
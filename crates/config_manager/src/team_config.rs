@@ -17,6 +17,16 @@
 //! - Use simple TOML format (values auto-wrap with `override_allowed = true`)
 //! - Support additive merging for collections (webhooks, apps, environments)
 //!
+//! # Multiple Teams
+//!
+//! A repository owned by several teams resolves all of their configurations.
+//! [`TeamConfig::combine`] folds them into a single team-level configuration in
+//! request order before the normal merge:
+//! - Additive collections are unioned; identical entries are kept once.
+//! - An override setting defined by only one team is taken from that team.
+//! - An override setting defined by several teams must have the same value in
+//!   each; otherwise combining fails with a validation error.
+//!
 //! # TOML Format
 //!
 //! Team configurations use simple value format:
@@ -49,7 +59,10 @@ use crate::settings::{
     NotificationsConfig, PullRequestSettings, PushSettings, RepositorySettings, RulesetConfig,
     WebhookConfig,
 };
-use serde::{Deserialize, Serialize};
+use crate::{ConfigurationError, ConfigurationResult, ValidationError, ValidationErrorType};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
 /// Team-specific configuration that overrides global defaults.
 ///
@@ -113,7 +126,8 @@ pub struct TeamConfig {
     /// Team-specific webhooks (additive).
     ///
     /// Webhooks defined here are added to global webhooks, not replacing them.
-    /// This allows teams to add their own notification endpoints.
+    /// This allows teams to add their own notification endpoints. A team webhook
+    /// with the same URL as a global or repository type webhook replaces it.
     pub webhooks: Option<Vec<WebhookConfig>>,
 
     /// Team-specific custom properties (additive).
@@ -161,6 +175,179 @@ pub struct TeamConfig {
     pub naming_rules: Option<Vec<crate::settings::RepositoryNamingRulesConfig>>,
 }
 
+impl TeamConfig {
+    /// Combines the configurations of several teams that own the same repository.
+    ///
+    /// Teams are processed in the given order. Additive collections are
+    /// unioned, and each override setting is taken from the team that defines
+    /// it. If two teams define the same override setting with different values
+    /// the conflict is reported rather than silently resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `teams` - Team names paired with their configuration, in request order
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigurationError::TeamConfigurationConflict` listing every
+    /// conflicting override setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use config_manager::TeamConfig;
+    ///
+    /// let backend: TeamConfig = toml::from_str("[repository]\nwiki = false").unwrap();
+    /// let platform: TeamConfig = toml::from_str("[repository]\nwiki = true").unwrap();
+    ///
+    /// let result = TeamConfig::combine(&[
+    ///     ("backend".to_string(), backend),
+    ///     ("platform".to_string(), platform),
+    /// ]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn combine(teams: &[(String, TeamConfig)]) -> ConfigurationResult<TeamConfig> {
+        let mut combined = TeamConfig::default();
+        let mut owners: HashMap<String, &str> = HashMap::new();
+        let mut errors = Vec::new();
+
+        for (team, config) in teams {
+            let mut overrides = OverrideCombiner {
+                team,
+                owners: &mut owners,
+                errors: &mut errors,
+            };
+            combined.repository =
+                overrides.combine("repository", combined.repository.take(), &config.repository)?;
+            combined.pull_requests = overrides.combine(
+                "pull_requests",
+                combined.pull_requests.take(),
+                &config.pull_requests,
+            )?;
+            combined.branch_protection = overrides.combine(
+                "branch_protection",
+                combined.branch_protection.take(),
+                &config.branch_protection,
+            )?;
+            combined.actions =
+                overrides.combine("actions", combined.actions.take(), &config.actions)?;
+            combined.push = overrides.combine("push", combined.push.take(), &config.push)?;
+
+            union(&mut combined.webhooks, &config.webhooks);
+            union(&mut combined.custom_properties, &config.custom_properties);
+            union(&mut combined.environments, &config.environments);
+            union(&mut combined.github_apps, &config.github_apps);
+            union(&mut combined.rulesets, &config.rulesets);
+            union(&mut combined.naming_rules, &config.naming_rules);
+
+            if let Some(notifications) = &config.notifications {
+                let target = combined.notifications.get_or_insert_with(Default::default);
+                for endpoint in &notifications.outbound_webhooks {
+                    if !target.outbound_webhooks.contains(endpoint) {
+                        target.outbound_webhooks.push(endpoint.clone());
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ConfigurationError::TeamConfigurationConflict { errors });
+        }
+
+        Ok(combined)
+    }
+}
+
+/// Adds the items of `source` to `target`, skipping items already present.
+fn union<T: Clone + PartialEq>(target: &mut Option<Vec<T>>, source: &Option<Vec<T>>) {
+    if let Some(items) = source {
+        let target = target.get_or_insert_with(Vec::new);
+        for item in items {
+            if !target.contains(item) {
+                target.push(item.clone());
+            }
+        }
+    }
+}
+
+/// Combines one team's override settings into the settings of earlier teams.
+struct OverrideCombiner<'a, 'b> {
+    team: &'a str,
+    owners: &'b mut HashMap<String, &'a str>,
+    errors: &'b mut Vec<ValidationError>,
+}
+
+impl<'a> OverrideCombiner<'a, '_> {
+    /// Combines a settings section field by field.
+    ///
+    /// Settings sections are flat structs of optional fields, so each field of
+    /// the serialized section is one override setting.
+    fn combine<T: Serialize + DeserializeOwned>(
+        &mut self,
+        section: &str,
+        current: Option<T>,
+        incoming: &Option<T>,
+    ) -> ConfigurationResult<Option<T>> {
+        let Some(incoming) = incoming else {
+            return Ok(current);
+        };
+
+        let mut fields = match current {
+            Some(current) => to_object(section, &current)?,
+            None => Map::new(),
+        };
+
+        for (field, value) in to_object(section, incoming)? {
+            if value.is_null() {
+                continue;
+            }
+
+            let field_path = format!("{}.{}", section, field);
+            match fields.get(&field) {
+                Some(existing) if existing.is_null() => {}
+                Some(existing) if *existing == value => continue,
+                Some(_) => {
+                    let owner = self.owners.get(&field_path).copied().unwrap_or_default();
+                    self.errors.push(ValidationError {
+                        error_type: ValidationErrorType::BusinessRuleViolation,
+                        message: format!(
+                            "Teams '{}' and '{}' set conflicting values for '{}'",
+                            owner, self.team, field_path
+                        ),
+                        field_path,
+                        suggestion: Some(
+                            "Define the setting in only one team's configuration or use the same value"
+                                .to_string(),
+                        ),
+                    });
+                    continue;
+                }
+                None => {}
+            }
+
+            self.owners.insert(field_path, self.team);
+            fields.insert(field, value);
+        }
+
+        serde_json::from_value(Value::Object(fields))
+            .map(Some)
+            .map_err(|e| ConfigurationError::ParseError {
+                reason: format!("Failed to combine team '{}' settings: {}", section, e),
+            })
+    }
+}
+
+/// Serializes a settings section into a JSON object.
+fn to_object<T: Serialize>(section: &str, settings: &T) -> ConfigurationResult<Map<String, Value>> {
+    match serde_json::to_value(settings) {
+        Ok(Value::Object(fields)) => Ok(fields),
+        Ok(_) => Ok(Map::new()),
+        Err(e) => Err(ConfigurationError::ParseError {
+            reason: format!("Failed to combine team '{}' settings: {}", section, e),
+        }),
+    }
+}
+
 #[cfg(test)]
 #[path = "team_config_tests.rs"]
 mod tests;
@@ -330,3 +330,129 @@ fn test_values_auto_wrapped_with_override_allowed() {
         "Team config values should auto-wrap with override_allowed = true"
     );
 }
+
+fn team(name: &str, toml: &str) -> (String, TeamConfig) {
+    (
+        name.to_string(),
+        toml::from_str(toml).expect("Failed to parse"),
+    )
+}
+
+#[test]
+fn test_combine_unions_additive_collections() {
+    let backend = team(
+        "backend",
+        r#"
+        [[webhooks]]
+        url = "https://backend.example.com/webhook"
+        content_type = "json"
+        events = ["push"]
+        active = true
+
+        [[webhooks]]
+        url = "https://shared.example.com/webhook"
+        content_type = "json"
+        events = ["push"]
+        active = true
+
+        [[github_apps]]
+        app_id = 11111
+        permissions = { contents = "read" }
+    "#,
+    );
+    let platform = team(
+        "platform",
+        r#"
+        [[webhooks]]
+        url = "https://shared.example.com/webhook"
+        content_type = "json"
+        events = ["push"]
+        active = true
+
+        [[webhooks]]
+        url = "https://platform.example.com/webhook"
+        content_type = "json"
+        events = ["issues"]
+        active = true
+
+        [[github_apps]]
+        app_id = 22222
+        permissions = { issues = "write" }
+    "#,
+    );
+
+    let combined = TeamConfig::combine(&[backend, platform]).expect("Teams should combine");
+
+    let urls: Vec<&str> = combined
+        .webhooks
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|w| w.url.as_str())
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://backend.example.com/webhook",
+            "https://shared.example.com/webhook",
+            "https://platform.example.com/webhook",
+        ]
+    );
+
+    let app_ids: Vec<u64> = combined
+        .github_apps
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|a| a.app_id)
+        .collect();
+    assert_eq!(app_ids, vec![11111, 22222]);
+}
+
+#[test]
+fn test_combine_takes_overrides_from_each_team() {
+    let backend = team("backend", "[repository]\nwiki = false\nissues = true");
+    let platform = team(
+        "platform",
+        "[repository]\nissues = true\nprojects = false\n\n[pull_requests]\nrequired_approving_review_count = 2",
+    );
+
+    let combined = TeamConfig::combine(&[backend, platform]).expect("Teams should combine");
+
+    let repository = combined.repository.unwrap();
+    assert_eq!(repository.wiki.map(|v| v.value), Some(false));
+    assert_eq!(repository.issues.map(|v| v.value), Some(true));
+    assert_eq!(repository.projects.map(|v| v.value), Some(false));
+    assert_eq!(
+        combined
+            .pull_requests
+            .unwrap()
+            .required_approving_review_count
+            .map(|v| v.value),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_combine_rejects_conflicting_overrides() {
+    let backend = team("backend", "[repository]\nwiki = false");
+    let platform = team("platform", "[repository]\nwiki = true");
+
+    let result = TeamConfig::combine(&[backend, platform]);
+
+    match result {
+        Err(ConfigurationError::TeamConfigurationConflict { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].field_path, "repository.wiki");
+            assert_eq!(
+                errors[0].error_type,
+                ValidationErrorType::BusinessRuleViolation
+            );
+            assert_eq!(
+                errors[0].message,
+                "Teams 'backend' and 'platform' set conflicting values for 'repository.wiki'"
+            );
+        }
+        other => panic!("Expected TeamConfigurationConflict, got {:?}", other),
+    }
+}
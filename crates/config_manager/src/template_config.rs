@@ -116,6 +116,41 @@ pub struct TemplateConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variables: Option<HashMap<String, TemplateVariable>>,
 
+    /// Groups that order the template variables for interactive prompting
+    /// (optional).
+    ///
+    /// Purely presentational: validation does not depend on the groups.
+    /// Variables that are not in any group are prompted last, in the
+    /// [`DEFAULT_VARIABLE_GROUP`].
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// [[variable_groups]]
+    /// name = "Service"
+    /// variables = ["service_name", "service_port"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variable_groups: Option<Vec<VariableGroup>>,
+
+    /// Template whose files this template is layered on (optional).
+    ///
+    /// Names a template repository in the same organization. Its files are
+    /// fetched first and this template's files are copied over them, so this
+    /// template wins when both contain the same path. A base template may
+    /// itself name a base template; cycles are rejected.
+    ///
+    /// Only files are composed. Configuration such as labels or repository
+    /// settings is not inherited from the base template.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// base_template = "company-standard"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_template: Option<String>,
+
     /// Repository feature settings (optional).
     ///
     /// Template-specific repository settings that override team and global defaults.
@@ -346,6 +381,107 @@ pub struct TemplateConfig {
     /// ```
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub naming_rules: Option<Vec<crate::settings::RepositoryNamingRulesConfig>>,
+
+    /// Template-specific GitHub Actions variables (additive).
+    ///
+    /// Added to the organization's variables; a variable with the same name
+    /// as an organization variable replaces its value.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// [[actions_variables]]
+    /// name = "RUST_VERSION"
+    /// value = "1.80"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions_variables: Option<Vec<crate::settings::ActionsVariableConfig>>,
+
+    /// Template-specific GitHub Actions secrets (additive).
+    ///
+    /// Secrets are declared by reference to the external secret store, never
+    /// by value. A secret with the same name as an organization secret
+    /// replaces its reference.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// [[actions_secrets]]
+    /// name = "CRATES_IO_TOKEN"
+    /// secret_ref = "CRATES_IO_TOKEN"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions_secrets: Option<Vec<crate::settings::ActionsSecretConfig>>,
+}
+
+impl TemplateConfig {
+    /// Parses a template configuration file (`.reporoller/template.toml`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigurationError::ParseError` if the TOML is invalid or does
+    /// not match the template configuration schema.
+    pub fn parse(content: &str) -> crate::ConfigurationResult<Self> {
+        crate::interpolation::parse_toml(content, ".reporoller/template.toml", None)
+    }
+
+    /// Returns the template variables arranged for prompting, group by group.
+    ///
+    /// Declared groups come first, in declaration order, with their variables
+    /// in the declared order. Names that are not defined variables, or that
+    /// already appeared in an earlier group, are skipped and empty groups are
+    /// dropped. The remaining variables follow in the
+    /// [`DEFAULT_VARIABLE_GROUP`], sorted by name.
+    pub fn variable_prompt_groups(&self) -> Vec<VariableGroup> {
+        let Some(variables) = &self.variables else {
+            return Vec::new();
+        };
+
+        let mut placed = std::collections::HashSet::new();
+        let mut groups: Vec<VariableGroup> = self
+            .variable_groups
+            .iter()
+            .flatten()
+            .map(|group| VariableGroup {
+                name: group.name.clone(),
+                variables: group
+                    .variables
+                    .iter()
+                    .filter(|name| variables.contains_key(*name) && placed.insert(*name))
+                    .cloned()
+                    .collect(),
+            })
+            .filter(|group| !group.variables.is_empty())
+            .collect();
+
+        let mut ungrouped: Vec<String> = variables
+            .keys()
+            .filter(|name| !placed.contains(name))
+            .cloned()
+            .collect();
+        if !ungrouped.is_empty() {
+            ungrouped.sort();
+            groups.push(VariableGroup {
+                name: DEFAULT_VARIABLE_GROUP.to_string(),
+                variables: ungrouped,
+            });
+        }
+
+        groups
+    }
+}
+
+/// Name of the group holding the variables that no declared group lists.
+pub const DEFAULT_VARIABLE_GROUP: &str = "General";
+
+/// A named, ordered set of template variables shown together when prompting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariableGroup {
+    /// Heading shown above the group's prompts.
+    pub name: String,
+
+    /// Variable names in the order they are prompted.
+    pub variables: Vec<String>,
 }
 
 /// Template metadata providing information about the template.
@@ -362,6 +498,28 @@ pub struct TemplateMetadata {
 
     /// Tags for template categorization and discovery.
     pub tags: Vec<String>,
+
+    /// Description given to repositories created from this template when the
+    /// creation request does not provide one.
+    ///
+    /// Rendered as a template, so it may reference template variables such as
+    /// `{{project_name}}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_description: Option<String>,
+
+    /// Topics applied to repositories created from this template when the
+    /// creation request does not provide any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_topics: Vec<String>,
+
+    /// Teams that must review every change to repositories created from this
+    /// template.
+    ///
+    /// The teams are written to `.github/CODEOWNERS` and branch protection on
+    /// the default branch requires code owner reviews. Neither can be
+    /// overridden by the creation request.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_code_owners: Vec<String>,
 }
 
 /// Repository type specification for the template.
@@ -426,12 +584,36 @@ pub struct TemplateVariable {
     pub max_length: Option<usize>,
 
     /// List of allowed values (optional).
+    ///
+    /// Each entry is either a plain string or a `{ value, label }` table whose
+    /// label is shown to users in place of the value.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<Vec<String>>,
+    pub options: Option<Vec<template_engine::VariableOption>>,
 
     /// Default value if not provided by user (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<String>,
+
+    /// Conditional requirement as `(variable_name, value)` (optional).
+    ///
+    /// The variable is required only when the named variable has the given
+    /// value, e.g. `required_if = ["database", "postgres"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_if: Option<(String, String)>,
+
+    /// Whether the value is sensitive (optional, defaults to false).
+    ///
+    /// Secret values are still used for rendering but are redacted from log
+    /// output and excluded from configuration audit records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<bool>,
+
+    /// Built-in transforms applied, in order, to the value before rendering
+    /// (optional), e.g. `transforms = ["trim", "lower_case", "kebab_case"]`.
+    ///
+    /// See [`template_engine::VariableTransform`] for the available names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transforms: Option<Vec<String>>,
 }
 
 #[cfg(test)]
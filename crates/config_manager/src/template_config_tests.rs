@@ -9,6 +9,9 @@ fn test_template_metadata_creation() {
         description: "Rust library template".to_string(),
         author: "Platform Team".to_string(),
         tags: vec!["rust".to_string(), "library".to_string()],
+        default_description: None,
+        default_topics: vec![],
+        required_code_owners: vec![],
     };
 
     assert_eq!(metadata.name, "rust-library");
@@ -84,8 +87,11 @@ fn test_template_variable_with_all_fields() {
         pattern: Some("^[a-z][a-z0-9-]*$".to_string()),
         min_length: Some(3),
         max_length: Some(50),
-        options: Some(vec!["service-a".to_string(), "service-b".to_string()]),
+        options: Some(vec!["service-a".into(), "service-b".into()]),
         default: Some("my-service".to_string()),
+        required_if: None,
+        secret: None,
+        transforms: None,
     };
 
     assert_eq!(var.description, "Service name");
@@ -98,6 +104,40 @@ fn test_template_variable_with_all_fields() {
     assert_eq!(var.default, Some("my-service".to_string()));
 }
 
+#[test]
+fn test_template_variable_options_plain_strings() {
+    let toml = r#"
+        description = "License"
+        options = ["MIT", "Apache-2.0"]
+    "#;
+
+    let var: TemplateVariable = toml::from_str(toml).expect("Failed to parse");
+
+    let options = var.options.expect("options should be present");
+    assert_eq!(options, vec!["MIT".into(), "Apache-2.0".into()]);
+    assert_eq!(options[0].value(), "MIT");
+    assert_eq!(options[0].label(), "MIT");
+}
+
+#[test]
+fn test_template_variable_options_with_labels() {
+    let toml = r#"
+        description = "License"
+        options = [
+            { value = "MIT", label = "MIT License" },
+            "Apache-2.0",
+        ]
+    "#;
+
+    let var: TemplateVariable = toml::from_str(toml).expect("Failed to parse");
+
+    let options = var.options.expect("options should be present");
+    assert_eq!(options[0].value(), "MIT");
+    assert_eq!(options[0].label(), "MIT License");
+    assert_eq!(options[1].value(), "Apache-2.0");
+    assert_eq!(options[1].label(), "Apache-2.0");
+}
+
 #[test]
 fn test_template_variable_minimal() {
     let var = TemplateVariable {
@@ -109,6 +149,9 @@ fn test_template_variable_minimal() {
         max_length: None,
         options: None,
         default: None,
+        required_if: None,
+        secret: None,
+        transforms: None,
     };
 
     assert_eq!(var.description, "Port number");
@@ -447,6 +490,9 @@ fn test_serialize_round_trip() {
             description: "Test template".to_string(),
             author: "Author".to_string(),
             tags: vec!["test".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: Some(RepositoryTypeSpec {
             repository_type: "library".to_string(),
@@ -468,6 +514,10 @@ fn test_serialize_round_trip() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let toml = toml::to_string(&config).expect("Failed to serialize");
@@ -484,6 +534,9 @@ fn test_clone_creates_independent_copy() {
             description: "Test".to_string(),
             author: "Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -502,6 +555,10 @@ fn test_clone_creates_independent_copy() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let cloned = config.clone();
@@ -516,6 +573,9 @@ fn test_debug_format() {
             description: "Test".to_string(),
             author: "Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -534,6 +594,10 @@ fn test_debug_format() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let debug_str = format!("{:?}", config);
@@ -665,6 +729,9 @@ fn test_template_config_visibility_serialization() {
             description: "Test template".to_string(),
             author: "Test Author".to_string(),
             tags: vec!["test".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -683,6 +750,10 @@ fn test_template_config_visibility_serialization() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let toml_str = toml::to_string(&config).expect("Failed to serialize");
@@ -706,6 +777,9 @@ fn test_template_config_none_visibility_not_serialized() {
             description: "Template without visibility".to_string(),
             author: "Test Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -724,6 +798,10 @@ fn test_template_config_none_visibility_not_serialized() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let toml_str = toml::to_string(&config).expect("Failed to serialize");
@@ -807,6 +885,50 @@ fn test_template_config_with_templating_section() {
     assert_eq!(templating.exclude_patterns[0], "target/**");
     assert_eq!(templating.exclude_patterns[1], "*.log");
     assert_eq!(templating.exclude_patterns[2], "tmp/**");
+    assert!(templating.post_generation.is_empty());
+}
+
+#[test]
+fn test_template_config_with_post_generation_transforms() {
+    use template_engine::PostGenerationTransform;
+
+    let toml = r#"
+        [template]
+        name = "rust-service"
+        description = "Rust microservice template"
+        author = "Platform Team"
+        tags = []
+
+        [templating]
+        post_generation = ["sort-gitignore", "format-toml"]
+    "#;
+
+    let config: TemplateConfig = toml::from_str(toml).expect("Failed to parse");
+    let templating = config.templating.expect("templating section");
+    assert_eq!(
+        templating.post_generation,
+        vec![
+            PostGenerationTransform::SortGitignore,
+            PostGenerationTransform::FormatToml
+        ]
+    );
+}
+
+#[test]
+fn test_template_config_rejects_unknown_post_generation_transform() {
+    let toml = r#"
+        [template]
+        name = "rust-service"
+        description = "Rust microservice template"
+        author = "Platform Team"
+        tags = []
+
+        [templating]
+        post_generation = ["cargo fmt"]
+    "#;
+
+    let result: Result<TemplateConfig, _> = toml::from_str(toml);
+    assert!(result.is_err(), "Unknown transforms must be rejected");
 }
 
 #[test]
@@ -946,6 +1068,9 @@ fn test_template_config_serialization_with_templating() {
             description: "Test template".to_string(),
             author: "Test Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -961,12 +1086,21 @@ fn test_template_config_serialization_with_templating() {
         templating: Some(TemplatingConfig {
             include_patterns: vec!["**/*.rs".to_string()],
             exclude_patterns: vec!["target/**".to_string()],
+            post_generation: vec![],
+            case_insensitive: false,
+            glob_recursive: false,
+            strict_variables: false,
+            max_path_length: None,
         }),
         notifications: None,
         permissions: None,
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let toml = toml::to_string(&config).expect("Failed to serialize");
@@ -1117,3 +1251,121 @@ fn test_template_config_with_teams_and_other_sections() {
     assert_eq!(teams.len(), 1);
     assert_eq!(teams[0].slug, "dev-team");
 }
+
+#[test]
+fn test_template_variable_required_if_deserialization() {
+    let toml_str = r#"
+        description = "Database connection string"
+        required_if = ["database", "postgres"]
+    "#;
+
+    let var: TemplateVariable = toml::from_str(toml_str).expect("Failed to parse");
+
+    assert_eq!(
+        var.required_if,
+        Some(("database".to_string(), "postgres".to_string()))
+    );
+    assert_eq!(var.required, None);
+}
+
+#[test]
+fn test_variable_prompt_groups_follow_declared_order() {
+    let toml = r#"
+        [template]
+        name = "service"
+        description = "Service template"
+        author = "Platform Team"
+        tags = []
+
+        [variables.service_name]
+        description = "Service name"
+
+        [variables.service_port]
+        description = "Port"
+
+        [variables.owner_email]
+        description = "Owner email"
+
+        [variables.license]
+        description = "License"
+
+        [[variable_groups]]
+        name = "Service"
+        variables = ["service_port", "service_name", "unknown"]
+
+        [[variable_groups]]
+        name = "Ownership"
+        variables = ["owner_email", "service_name"]
+
+        [[variable_groups]]
+        name = "Empty"
+        variables = ["unknown"]
+    "#;
+
+    let config: TemplateConfig = toml::from_str(toml).expect("Failed to parse");
+
+    assert_eq!(
+        config.variable_prompt_groups(),
+        vec![
+            VariableGroup {
+                name: "Service".to_string(),
+                variables: vec!["service_port".to_string(), "service_name".to_string()],
+            },
+            VariableGroup {
+                name: "Ownership".to_string(),
+                variables: vec!["owner_email".to_string()],
+            },
+            VariableGroup {
+                name: DEFAULT_VARIABLE_GROUP.to_string(),
+                variables: vec!["license".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_variable_prompt_groups_without_declared_groups_uses_default_group() {
+    let toml = r#"
+        [template]
+        name = "service"
+        description = "Service template"
+        author = "Platform Team"
+        tags = []
+
+        [variables.b]
+        description = "B"
+
+        [variables.a]
+        description = "A"
+    "#;
+
+    let config: TemplateConfig = toml::from_str(toml).expect("Failed to parse");
+
+    assert_eq!(config.variable_groups, None);
+    assert_eq!(
+        config.variable_prompt_groups(),
+        vec![VariableGroup {
+            name: DEFAULT_VARIABLE_GROUP.to_string(),
+            variables: vec!["a".to_string(), "b".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn test_template_config_parses_base_template() {
+    let config = TemplateConfig::parse(
+        r#"
+        base_template = "company-standard"
+
+        [template]
+        name = "rust-service"
+        description = "Rust service template"
+        author = "Platform Team"
+        tags = []
+    "#,
+    )
+    .expect("Failed to parse");
+
+    assert_eq!(config.base_template.as_deref(), Some("company-standard"));
+    assert!(TemplateConfig::parse("base_template = 1").is_err());
+}
@@ -70,6 +70,9 @@ fn create_test_template_config(name: &str) -> TemplateConfig {
             description: format!("Test template: {}", name),
             author: "Test Author".to_string(),
             tags: vec!["test".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -88,6 +91,10 @@ fn create_test_template_config(name: &str) -> TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
@@ -45,6 +45,17 @@ impl MockMetadataProvider {
             default_teams: None,
             default_collaborators: None,
             naming_rules: None,
+            allow_template_post_generation: None,
+            allowed_templates: None,
+            denied_templates: None,
+            actions_variables: None,
+            actions_secrets: None,
+            signed_initial_commit: None,
+            generate_codeowners: None,
+            replace_default_labels: None,
+            auto_init: None,
+            gitignore_template: None,
+            license_template: None,
         };
 
         Self {
@@ -84,6 +95,17 @@ impl MockMetadataProvider {
             default_teams: None,
             default_collaborators: None,
             naming_rules: None,
+            allow_template_post_generation: None,
+            allowed_templates: None,
+            denied_templates: None,
+            actions_variables: None,
+            actions_secrets: None,
+            signed_initial_commit: None,
+            generate_codeowners: None,
+            replace_default_labels: None,
+            auto_init: None,
+            gitignore_template: None,
+            license_template: None,
         };
 
         self.config_data = Some(defaults);
@@ -0,0 +1,70 @@
+//! GitHub Actions secret encryption.
+//!
+//! GitHub only accepts Actions secrets encrypted with the repository's public
+//! key using a libsodium sealed box. This module contains the public key type
+//! returned by the API and the sealing function used before uploading a secret.
+
+use crate::Error;
+use base64::Engine;
+use crypto_box::{aead::OsRng, PublicKey};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "actions_secret_tests.rs"]
+mod tests;
+
+/// The public key used to encrypt Actions secrets for a repository.
+///
+/// # Examples
+///
+/// ```
+/// use github_client::ActionsPublicKey;
+///
+/// let key = ActionsPublicKey {
+///     key_id: "568250167242549743".to_string(),
+///     key: "2Sg8iYjAxxmI2LvUXpJjkYrMxURPc8r+dB7TJyvv1234".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionsPublicKey {
+    /// Identifier of the key, sent back to GitHub with each encrypted secret
+    pub key_id: String,
+
+    /// Base64-encoded Curve25519 public key
+    pub key: String,
+}
+
+/// Encrypts a secret value for the given repository public key.
+///
+/// The value is sealed with a libsodium sealed box (`crypto_box_seal`) and the
+/// ciphertext is returned base64-encoded, ready to be sent as `encrypted_value`.
+///
+/// # Arguments
+///
+/// * `public_key` - Base64-encoded public key from [`ActionsPublicKey::key`]
+/// * `value` - The plaintext secret value
+///
+/// # Errors
+///
+/// Returns `Error::SecretEncryption` if the public key is not a base64-encoded
+/// 32-byte key or the value cannot be sealed. The error never contains the
+/// secret value.
+pub fn seal_secret(public_key: &str, value: &str) -> Result<String, Error> {
+    let engine = base64::engine::general_purpose::STANDARD;
+
+    let key_bytes = engine
+        .decode(public_key)
+        .map_err(|e| Error::SecretEncryption(format!("public key is not valid base64: {}", e)))?;
+    let key_bytes: [u8; 32] = key_bytes.try_into().map_err(|bytes: Vec<u8>| {
+        Error::SecretEncryption(format!(
+            "public key must be 32 bytes, got {} bytes",
+            bytes.len()
+        ))
+    })?;
+
+    let sealed = PublicKey::from(key_bytes)
+        .seal(&mut OsRng, value.as_bytes())
+        .map_err(|_| Error::SecretEncryption("failed to seal secret value".to_string()))?;
+
+    Ok(engine.encode(sealed))
+}
@@ -0,0 +1,51 @@
+use super::*;
+use crypto_box::SecretKey;
+
+/// Returns a fixed key pair and the base64-encoded public key.
+fn known_key_pair() -> (SecretKey, String) {
+    let secret_key = SecretKey::from([7u8; 32]);
+    let public_key = base64::engine::general_purpose::STANDARD.encode(secret_key.public_key());
+    (secret_key, public_key)
+}
+
+#[test]
+fn test_seal_secret_round_trips_with_known_key() {
+    let (secret_key, public_key) = known_key_pair();
+
+    let encrypted = seal_secret(&public_key, "super-secret-token").unwrap();
+
+    let ciphertext = base64::engine::general_purpose::STANDARD
+        .decode(&encrypted)
+        .unwrap();
+    let plaintext = secret_key.unseal(&ciphertext).unwrap();
+    assert_eq!(plaintext, b"super-secret-token");
+}
+
+#[test]
+fn test_seal_secret_uses_fresh_ephemeral_key() {
+    let (_, public_key) = known_key_pair();
+
+    let first = seal_secret(&public_key, "value").unwrap();
+    let second = seal_secret(&public_key, "value").unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_seal_secret_rejects_invalid_base64_key() {
+    let result = seal_secret("not base64!", "value");
+    assert!(matches!(result, Err(Error::SecretEncryption(_))));
+}
+
+#[test]
+fn test_seal_secret_rejects_wrong_key_length() {
+    let short_key = base64::engine::general_purpose::STANDARD.encode([1u8; 16]);
+
+    match seal_secret(&short_key, "value") {
+        Err(Error::SecretEncryption(message)) => {
+            assert!(message.contains("16 bytes"));
+            assert!(!message.contains("value"));
+        }
+        other => panic!("Expected SecretEncryption error, got {other:?}"),
+    }
+}
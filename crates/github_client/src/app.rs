@@ -0,0 +1,64 @@
+//! GitHub App metadata domain types.
+//!
+//! This module contains types describing the authenticated GitHub App itself,
+//! as returned by the `GET /app` endpoint.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "app_tests.rs"]
+mod tests;
+
+/// Owner of a GitHub App (user or organization).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AppOwner {
+    /// The login name of the owner
+    pub login: String,
+    /// The type of account (User or Organization)
+    #[serde(rename = "type", default)]
+    pub owner_type: Option<String>,
+}
+
+/// Metadata describing the authenticated GitHub App.
+///
+/// Returned by `GitHubClient::get_app_metadata`. Useful when diagnosing
+/// installation problems, as it shows which app the configured credentials
+/// belong to and which permissions and events it has been granted.
+///
+/// # Examples
+///
+/// ```rust
+/// use github_client::AppMetadata;
+///
+/// let json = r#"{ "id": 1, "slug": "repo-roller", "name": "RepoRoller" }"#;
+/// let app: AppMetadata = serde_json::from_str(json).unwrap();
+/// assert_eq!(app.slug, "repo-roller");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AppMetadata {
+    /// The unique ID of the app
+    pub id: u64,
+    /// The URL-friendly name of the app
+    pub slug: String,
+    /// The display name of the app
+    pub name: String,
+    /// The account that owns the app
+    #[serde(default)]
+    pub owner: Option<AppOwner>,
+    /// The app's description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The app's public GitHub page
+    #[serde(default)]
+    pub html_url: Option<String>,
+    /// Permissions granted to the app, keyed by permission name (e.g. `contents` -> `write`)
+    #[serde(default)]
+    pub permissions: HashMap<String, String>,
+    /// Webhook events the app is subscribed to
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Number of installations of the app, if reported
+    #[serde(default)]
+    pub installations_count: Option<u64>,
+}
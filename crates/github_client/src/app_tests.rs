@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn test_app_metadata_deserialization() {
+    let json = r#"{
+        "id": 42,
+        "slug": "repo-roller",
+        "name": "RepoRoller",
+        "owner": { "login": "my-org", "type": "Organization" },
+        "description": "Creates repositories from templates",
+        "html_url": "https://github.com/apps/repo-roller",
+        "permissions": { "administration": "write", "contents": "write" },
+        "events": ["repository"],
+        "installations_count": 3
+    }"#;
+
+    let app: AppMetadata = serde_json::from_str(json).expect("Failed to deserialize AppMetadata");
+
+    assert_eq!(app.id, 42);
+    assert_eq!(app.slug, "repo-roller");
+    assert_eq!(app.name, "RepoRoller");
+    assert_eq!(
+        app.owner,
+        Some(AppOwner {
+            login: "my-org".to_string(),
+            owner_type: Some("Organization".to_string()),
+        })
+    );
+    assert_eq!(
+        app.permissions.get("administration"),
+        Some(&"write".to_string())
+    );
+    assert_eq!(app.events, vec!["repository".to_string()]);
+    assert_eq!(app.installations_count, Some(3));
+}
+
+#[test]
+fn test_app_metadata_deserialization_minimal() {
+    let json = r#"{ "id": 7, "slug": "minimal-app", "name": "Minimal" }"#;
+
+    let app: AppMetadata = serde_json::from_str(json).expect("Failed to deserialize AppMetadata");
+
+    assert_eq!(app.id, 7);
+    assert!(app.owner.is_none());
+    assert!(app.permissions.is_empty());
+    assert!(app.events.is_empty());
+    assert!(app.installations_count.is_none());
+}
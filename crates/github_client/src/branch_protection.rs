@@ -33,3 +33,71 @@ pub struct BranchProtection {
     /// Whether stale reviews are dismissed when new commits are pushed
     pub dismiss_stale_reviews: Option<bool>,
 }
+
+/// Push and review rules to enforce on a protected branch.
+///
+/// Applied with [`crate::GitHubClient::update_branch_push_protection`].
+///
+/// # Examples
+///
+/// ```rust
+/// use github_client::BranchPushProtection;
+///
+/// let rules = BranchPushProtection {
+///     allow_force_pushes: false,
+///     required_signatures: true,
+///     require_code_owner_reviews: false,
+///     required_status_checks: vec!["ci/build".to_string()],
+///     strict_required_status_checks: true,
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchPushProtection {
+    /// Whether force pushes to the branch are permitted
+    pub allow_force_pushes: bool,
+    /// Whether commits pushed to the branch must be signed
+    pub required_signatures: bool,
+    /// Whether pull requests need an approving review from a code owner
+    #[serde(default)]
+    pub require_code_owner_reviews: bool,
+    /// Status check contexts that must pass before merging; empty disables
+    /// required status checks
+    #[serde(default)]
+    pub required_status_checks: Vec<String>,
+    /// Whether the branch must be up to date before merging; only applies
+    /// when status checks are required
+    #[serde(default)]
+    pub strict_required_status_checks: bool,
+}
+
+/// Request body for `PUT /repos/{owner}/{repo}/branches/{branch}/protection`.
+///
+/// GitHub requires the review, status check, admin and restriction fields to
+/// be present; `null` leaves them disabled.
+#[derive(Debug, Serialize)]
+pub(crate) struct BranchProtectionPayload {
+    pub required_status_checks: Option<serde_json::Value>,
+    pub enforce_admins: Option<bool>,
+    pub required_pull_request_reviews: Option<serde_json::Value>,
+    pub restrictions: Option<serde_json::Value>,
+    pub allow_force_pushes: bool,
+}
+
+impl From<&BranchPushProtection> for BranchProtectionPayload {
+    fn from(rules: &BranchPushProtection) -> Self {
+        Self {
+            required_status_checks: (!rules.required_status_checks.is_empty()).then(|| {
+                serde_json::json!({
+                    "strict": rules.strict_required_status_checks,
+                    "contexts": rules.required_status_checks,
+                })
+            }),
+            enforce_admins: None,
+            required_pull_request_reviews: rules
+                .require_code_owner_reviews
+                .then(|| serde_json::json!({ "require_code_owner_reviews": true })),
+            restrictions: None,
+            allow_force_pushes: rules.allow_force_pushes,
+        }
+    }
+}
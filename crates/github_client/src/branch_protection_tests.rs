@@ -48,3 +48,56 @@ fn test_branch_protection_with_null_values() {
     assert_eq!(protection.require_code_owner_reviews, None);
     assert_eq!(protection.dismiss_stale_reviews, None);
 }
+
+#[test]
+fn test_branch_protection_payload_sends_nulls_for_unmanaged_rules() {
+    let rules = BranchPushProtection {
+        allow_force_pushes: true,
+        required_signatures: true,
+        require_code_owner_reviews: false,
+        required_status_checks: vec![],
+        strict_required_status_checks: true,
+    };
+
+    let payload = serde_json::to_value(BranchProtectionPayload::from(&rules)).unwrap();
+
+    assert_eq!(payload["allow_force_pushes"], true);
+    assert!(payload["required_status_checks"].is_null());
+    assert!(payload["enforce_admins"].is_null());
+    assert!(payload["required_pull_request_reviews"].is_null());
+    assert!(payload["restrictions"].is_null());
+}
+
+#[test]
+fn test_branch_protection_payload_requires_code_owner_reviews() {
+    let rules = BranchPushProtection {
+        allow_force_pushes: false,
+        required_signatures: false,
+        require_code_owner_reviews: true,
+        required_status_checks: vec![],
+        strict_required_status_checks: false,
+    };
+
+    let payload = serde_json::to_value(BranchProtectionPayload::from(&rules)).unwrap();
+
+    assert_eq!(
+        payload["required_pull_request_reviews"]["require_code_owner_reviews"],
+        true
+    );
+}
+
+#[test]
+fn test_branch_protection_payload_requires_status_checks() {
+    let rules = BranchPushProtection {
+        required_status_checks: vec!["ci/build".to_string(), "ci/test".to_string()],
+        strict_required_status_checks: true,
+        ..Default::default()
+    };
+
+    let payload = serde_json::to_value(BranchProtectionPayload::from(&rules)).unwrap();
+
+    assert_eq!(
+        payload["required_status_checks"],
+        serde_json::json!({ "strict": true, "contexts": ["ci/build", "ci/test"] })
+    );
+}
@@ -94,3 +94,43 @@ pub enum EntryType {
     /// Git submodule reference
     Submodule,
 }
+
+/// A file to include in a commit created through the GitHub Git Data API.
+///
+/// Used by [`RepositoryClient::create_initial_commit`](crate::RepositoryClient::create_initial_commit)
+/// to describe the full tree of the commit.
+///
+/// # Examples
+///
+/// ```rust
+/// use github_client::CommitFile;
+///
+/// let file = CommitFile {
+///     path: "scripts/build.sh".to_string(),
+///     content: b"#!/bin/sh\ncargo build\n".to_vec(),
+///     executable: true,
+/// };
+/// assert_eq!(file.git_mode(), "100755");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitFile {
+    /// Path of the file relative to the repository root, using `/` separators
+    pub path: String,
+
+    /// Raw file content
+    pub content: Vec<u8>,
+
+    /// Whether the file should be marked executable in the git tree
+    pub executable: bool,
+}
+
+impl CommitFile {
+    /// Returns the git file mode for this file (`100755` or `100644`).
+    pub fn git_mode(&self) -> &'static str {
+        if self.executable {
+            "100755"
+        } else {
+            "100644"
+        }
+    }
+}
@@ -86,3 +86,16 @@ fn test_tree_entry_directory_no_download_url() {
     assert_eq!(entry.size, 0);
     assert_eq!(entry.download_url, None);
 }
+
+#[test]
+fn test_commit_file_git_mode() {
+    let mut file = CommitFile {
+        path: "README.md".to_string(),
+        content: b"# Hello".to_vec(),
+        executable: false,
+    };
+    assert_eq!(file.git_mode(), "100644");
+
+    file.executable = true;
+    assert_eq!(file.git_mode(), "100755");
+}
@@ -37,6 +37,48 @@ pub enum Error {
     #[error("API request failed")]
     ApiError(),
 
+    /// GitHub rejected a request with an unexpected status code.
+    ///
+    /// Used for statuses that have no more specific variant, most commonly
+    /// server errors (5xx). These may succeed when retried.
+    #[error("GitHub API request failed with status {status}: {message}")]
+    Api {
+        /// HTTP status code of the response
+        status: u16,
+        /// Message returned by GitHub
+        message: String,
+    },
+
+    /// GitHub denied access to a resource (HTTP 403).
+    ///
+    /// The GitHub App or token lacks a permission needed for the request, or
+    /// an organization policy forbids it. Rate limit responses are reported
+    /// as [`Error::RateLimitExceeded`] instead.
+    #[error("GitHub denied access (status 403): {message}")]
+    Forbidden {
+        /// Message returned by GitHub
+        message: String,
+    },
+
+    /// GitHub rejected the request content (HTTP 400 or 422).
+    ///
+    /// The request was well-formed HTTP but GitHub refused its values, for
+    /// example a repository name that already exists or an invalid setting.
+    #[error("GitHub rejected the request (status {status}): {message}")]
+    Validation {
+        /// HTTP status code of the response
+        status: u16,
+        /// Message returned by GitHub
+        message: String,
+    },
+
+    /// A GitHub response could not be decoded.
+    ///
+    /// The request succeeded, but the body was not valid UTF-8 or did not
+    /// match the expected structure.
+    #[error("Failed to parse GitHub response: {0}")]
+    Parse(String),
+
     /// Authentication or GitHub client initialization failure.
     ///
     /// This error occurs when:
@@ -82,6 +124,16 @@ pub enum Error {
     #[error("Failed to find installation for repository: {0}/{1} with ID: {2}")]
     FailedToFindAppInstallation(String, String, u64),
 
+    /// The GitHub App is not installed on an organization.
+    ///
+    /// This error occurs when none of the app's installations belongs to the
+    /// organization. An organization owner must install the app before
+    /// RepoRoller can act on the organization.
+    ///
+    /// Parameter: the organization name
+    #[error("GitHub App not installed on organization '{0}'")]
+    InstallationNotFound(String),
+
     /// The GitHub API returned a response in an unexpected format.
     ///
     /// This error indicates that the API response structure doesn't match
@@ -89,6 +141,9 @@ pub enum Error {
     /// - GitHub API changes or deprecations
     /// - Client library being out of date
     /// - Malformed API responses
+    ///
+    /// It is also used for requests that failed without a response from
+    /// GitHub, such as network errors.
     #[error("Invalid response format")]
     InvalidResponse,
 
@@ -97,7 +152,7 @@ pub enum Error {
     /// This error occurs when a GitHub API request returns a 404 status code,
     /// indicating that the requested resource (repository, file, directory, etc.)
     /// does not exist or is not accessible with the current authentication.
-    #[error("Resource not found")]
+    #[error("Resource not found (status 404)")]
     NotFound,
 
     /// GitHub API rate limit has been exceeded.
@@ -108,4 +163,50 @@ pub enum Error {
     /// in the response to determine when to retry.
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
+
+    /// A GitHub API operation did not complete within the configured timeout.
+    ///
+    /// This error occurs when GitHub (or the network path to it) stops responding
+    /// and the operation exceeds the timeout configured on the client via
+    /// `GitHubClient::with_timeout`. The in-flight request is cancelled. The
+    /// operation may be retried, but non-idempotent operations (such as
+    /// repository creation) may already have taken effect on GitHub.
+    #[error("GitHub API operation '{operation}' timed out after {timeout:?}")]
+    Timeout {
+        /// Name of the client operation that timed out
+        operation: String,
+        /// The timeout that was exceeded
+        timeout: std::time::Duration,
+    },
+
+    /// The GitHub App private key file could not be read.
+    ///
+    /// This error occurs when the configured key file does not exist, is not
+    /// readable by the current user, or is not valid UTF-8 text.
+    #[error("Failed to read GitHub App private key from '{path}': {reason}")]
+    PrivateKeyFile {
+        /// Path of the key file
+        path: String,
+        /// Why the file could not be read
+        reason: String,
+    },
+
+    /// The GitHub App private key is not a valid PEM-encoded RSA key.
+    ///
+    /// The key downloaded from the GitHub App settings page is accepted as is,
+    /// in either PKCS#1 (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`)
+    /// form.
+    #[error("Invalid GitHub App private key: {0}")]
+    InvalidPrivateKey(String),
+
+    /// A required environment variable is not set.
+    #[error("Environment variable '{0}' is not set")]
+    MissingEnvironmentVariable(String),
+
+    /// A GitHub Actions secret could not be encrypted for upload.
+    ///
+    /// This error occurs when the repository public key returned by GitHub is
+    /// malformed. The message never contains the secret value.
+    #[error("Failed to encrypt Actions secret: {0}")]
+    SecretEncryption(String),
 }
@@ -54,3 +54,73 @@ fn test_error_is_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Error>();
 }
+
+#[test]
+fn test_timeout_error_display() {
+    let error = Error::Timeout {
+        operation: "create_org_repository".to_string(),
+        timeout: std::time::Duration::from_secs(30),
+    };
+
+    // Test error message
+    assert_eq!(
+        error.to_string(),
+        "GitHub API operation 'create_org_repository' timed out after 30s"
+    );
+
+    // Test error source
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_private_key_file_error_display() {
+    let error = Error::PrivateKeyFile {
+        path: "/keys/app.pem".to_string(),
+        reason: "file does not exist".to_string(),
+    };
+
+    // Test error message
+    assert_eq!(
+        error.to_string(),
+        "Failed to read GitHub App private key from '/keys/app.pem': file does not exist"
+    );
+
+    // Test error source
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_status_error_display() {
+    let forbidden = Error::Forbidden {
+        message: "Resource not accessible by integration".to_string(),
+    };
+    assert_eq!(
+        forbidden.to_string(),
+        "GitHub denied access (status 403): Resource not accessible by integration"
+    );
+
+    let api = Error::Api {
+        status: 502,
+        message: "Bad Gateway".to_string(),
+    };
+    assert_eq!(
+        api.to_string(),
+        "GitHub API request failed with status 502: Bad Gateway"
+    );
+
+    assert_eq!(
+        Error::NotFound.to_string(),
+        "Resource not found (status 404)"
+    );
+}
+
+#[test]
+fn test_parse_error_display() {
+    let error = Error::Parse("missing field `resources`".to_string());
+
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse GitHub response: missing field `resources`"
+    );
+    assert!(error.source().is_none());
+}
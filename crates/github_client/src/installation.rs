@@ -31,6 +31,9 @@ pub struct Account {
 pub struct Installation {
     /// The unique ID of the installation
     pub id: u64,
+    /// The ID of the GitHub App this installation belongs to
+    #[serde(default)]
+    pub app_id: Option<u64>,
     /// The account (user or organization) where the app is installed
     pub account: Account,
     /// Optional repository selection details
@@ -44,6 +47,7 @@ impl From<octocrab::models::Installation> for Installation {
         let account_node_id = value.account.node_id.clone();
         Self {
             id: *value.id,
+            app_id: value.app_id.map(|id| *id),
             account: Account {
                 id: *value.account.id,
                 login: value.account.login,
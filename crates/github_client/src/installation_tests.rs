@@ -40,6 +40,7 @@ fn test_account_deserialization() {
 fn test_installation_serialization() {
     let installation = Installation {
         id: 98765,
+        app_id: Some(4242),
         account: Account {
             id: 12345,
             login: "test-org".to_string(),
@@ -54,6 +55,7 @@ fn test_installation_serialization() {
     let parsed: serde_json::Value = serde_json::from_str(&json_str).expect("Failed to parse JSON");
 
     assert_eq!(parsed["id"], 98765);
+    assert_eq!(parsed["app_id"], 4242);
     assert_eq!(parsed["account"]["id"], 12345);
     assert_eq!(parsed["account"]["login"], "test-org");
     assert_eq!(parsed["repository_selection"], "selected");
@@ -78,6 +80,7 @@ fn test_installation_deserialization() {
         from_str(json_str).expect("Failed to deserialize Installation");
 
     assert_eq!(installation.id, 11111);
+    assert_eq!(installation.app_id, None);
     assert_eq!(installation.account.id, 22222);
     assert_eq!(installation.account.login, "another-org");
     assert_eq!(installation.repository_selection, None);
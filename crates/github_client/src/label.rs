@@ -16,6 +16,8 @@ mod tests;
 /// # Fields
 ///
 /// * `name` - The name of the label
+/// * `color` - The label color as a hex code without the leading `#`
+/// * `description` - The label description, if any
 ///
 /// # Examples
 ///
@@ -24,10 +26,20 @@ mod tests;
 ///
 /// let label = Label {
 ///     name: "bug".to_string(),
+///     color: "d73a4a".to_string(),
+///     description: Some("Something isn't working".to_string()),
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Label {
     /// The name of the label
     pub name: String,
+
+    /// The label color as a hex code without the leading `#`
+    #[serde(default)]
+    pub color: String,
+
+    /// The label description, if any
+    #[serde(default)]
+    pub description: Option<String>,
 }
@@ -18,6 +18,8 @@ fn test_label_serialization() {
     // Create a label
     let label = Label {
         name: "bug".to_string(),
+        color: "d73a4a".to_string(),
+        description: None,
     };
 
     // Serialize to JSON
@@ -44,10 +44,29 @@ seOmBxWp9ZSiyaDAaJckPop1Y0kqwZNaAe9LXKpYp15PRyD4ehRLNbJLxx6UFHYf
 JC29NBAKJUVTF8zCccVfB2E=
 -----END PRIVATE KEY-----";
 
-fn create_test_pem() -> &'static str {
+pub(crate) fn create_test_pem() -> &'static str {
     TEST_RSA_PRIVATE_KEY_PEM
 }
 
+#[tokio::test]
+async fn test_create_app_client_rejects_short_key_without_panicking() {
+    let result = create_app_client(TEST_APP_ID, "short").await;
+
+    match result {
+        Err(Error::AuthError(message)) => {
+            assert!(message.contains("malformed or empty"), "{message}");
+        }
+        other => panic!("Expected AuthError, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn test_create_app_client_rejects_empty_key() {
+    let result = create_app_client(TEST_APP_ID, "").await;
+
+    assert!(matches!(result, Err(Error::AuthError(m)) if m.contains("malformed or empty")));
+}
+
 #[tokio::test]
 async fn test_create_org_repository_success() {
     let mock_server = MockServer::start().await;
@@ -76,7 +95,7 @@ async fn test_create_org_repository_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.create_org_repository(org_name, &payload).await;
 
@@ -113,7 +132,7 @@ async fn test_create_user_repository_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.create_user_repository(&payload).await;
 
@@ -168,7 +187,7 @@ async fn test_get_repository_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_repository(owner, repo).await;
 
@@ -206,7 +225,7 @@ async fn test_update_repository_settings_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .update_repository_settings(owner, repo, &settings)
@@ -288,7 +307,7 @@ async fn test_list_installations_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_installations().await;
 
@@ -325,7 +344,7 @@ async fn test_list_installations_empty() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_installations().await;
 
@@ -334,6 +353,33 @@ async fn test_list_installations_empty() {
     assert_eq!(installations.len(), 0);
 }
 
+#[tokio::test]
+async fn test_get_installation_token_for_org_not_installed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/app/installations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    let client = GitHubClient::new(octocrab);
+
+    let result = client.get_installation_token_for_org("test-org").await;
+
+    match result {
+        Err(Error::InstallationNotFound(org)) => assert_eq!(org, "test-org"),
+        other => panic!("Expected InstallationNotFound, got {:?}", other),
+    }
+}
+
 #[tokio::test]
 #[ignore = "Integration test - requires real GitHub App setup. octocrab models need complex mock data structure"]
 async fn test_get_installation_token_for_org_success() {
@@ -394,7 +440,7 @@ async fn test_get_installation_token_for_org_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_installation_token_for_org(org_name).await;
 
@@ -454,7 +500,7 @@ async fn test_get_installation_token_for_org_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_installation_token_for_org(org_name).await;
 
@@ -521,7 +567,7 @@ async fn test_get_installation_token_case_insensitive() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     // Test that uppercase org name finds lowercase match
     let result = client.get_installation_token_for_org(org_name_upper).await;
@@ -572,7 +618,7 @@ async fn test_set_repository_custom_properties_success() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_repository_custom_properties(owner, repo, &payload)
@@ -620,7 +666,7 @@ async fn test_set_repository_custom_properties_api_error() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_repository_custom_properties(owner, repo, &payload)
@@ -654,7 +700,7 @@ async fn test_set_repository_custom_properties_empty() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_repository_custom_properties(owner, repo, &payload)
@@ -695,7 +741,7 @@ async fn test_set_repository_custom_properties_permission_denied() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_repository_custom_properties(owner, repo, &payload)
@@ -736,7 +782,7 @@ async fn test_set_repository_custom_properties_repo_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_repository_custom_properties(owner, repo, &payload)
@@ -794,7 +840,7 @@ async fn test_list_directory_contents_empty_directory() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .list_directory_contents(owner, repo, dir_path, branch)
@@ -833,7 +879,7 @@ async fn test_list_directory_contents_path_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .list_directory_contents(owner, repo, dir_path, branch)
@@ -849,7 +895,7 @@ async fn test_list_directory_contents_path_not_found() {
 /// Test listing a path that is a file, not a directory.
 ///
 /// Verifies that when the path points to a file (GitHub returns an object instead
-/// of an array), we return Error::InvalidResponse.
+/// of an array), we return Error::Parse.
 #[tokio::test]
 async fn test_list_directory_contents_path_is_file() {
     let mock_server = MockServer::start().await;
@@ -880,7 +926,7 @@ async fn test_list_directory_contents_path_is_file() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .list_directory_contents(owner, repo, file_path, branch)
@@ -888,8 +934,8 @@ async fn test_list_directory_contents_path_is_file() {
 
     assert!(result.is_err(), "Should return error when path is a file");
     assert!(
-        matches!(result.unwrap_err(), Error::InvalidResponse),
-        "Should return InvalidResponse when path is file, not directory"
+        matches!(result.unwrap_err(), Error::Parse(_)),
+        "Should return Parse when path is file, not directory"
     );
 }
 
@@ -921,7 +967,7 @@ async fn test_list_directory_contents_auth_error() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .list_directory_contents(owner, repo, dir_path, branch)
@@ -936,7 +982,8 @@ async fn test_list_directory_contents_auth_error() {
 
 /// Test rate limit exceeded error.
 ///
-/// Verifies that rate limit errors are detected and mapped to Error::RateLimitExceeded.
+/// Verifies that rate limit errors are detected and mapped to Error::RateLimitExceeded,
+/// and that the remaining quota is looked up so it can be logged.
 #[tokio::test]
 async fn test_list_directory_contents_rate_limit() {
     let mock_server = MockServer::start().await;
@@ -960,6 +1007,18 @@ async fn test_list_directory_contents_rate_limit() {
         .mount(&mock_server)
         .await;
 
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resources": {
+                "core": { "limit": 5000, "used": 5000, "remaining": 0, "reset": 1234567890 },
+                "search": { "limit": 30, "used": 0, "remaining": 30, "reset": 1234567890 }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
     let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
     let octocrab = octocrab::Octocrab::builder()
         .base_uri(mock_server.uri())
@@ -967,7 +1026,7 @@ async fn test_list_directory_contents_rate_limit() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .list_directory_contents(owner, repo, dir_path, branch)
@@ -1019,7 +1078,7 @@ async fn test_list_organization_teams_returns_all_teams() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_organization_teams(org).await;
 
@@ -1076,7 +1135,7 @@ async fn test_list_organization_teams_paginates_multiple_pages() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_organization_teams(org).await;
 
@@ -1109,7 +1168,7 @@ async fn test_list_organization_teams_returns_empty_when_no_teams() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_organization_teams(org).await;
 
@@ -1138,13 +1197,77 @@ async fn test_list_organization_teams_returns_error_on_api_failure() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_organization_teams(org).await;
 
     assert!(result.is_err(), "Expected error on API failure");
 }
 
+/// Test that get_team parses the team returned for a slug.
+#[tokio::test]
+async fn test_get_team_returns_team() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org/teams/backend"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "slug": "backend",
+            "name": "Backend",
+            "description": "The backend team",
+            "privacy": "closed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    let client = GitHubClient::new(octocrab);
+
+    let team = client
+        .get_team("test-org", "backend")
+        .await
+        .expect("Expected Ok result")
+        .expect("Expected the team to exist");
+
+    assert_eq!(team.id, 1);
+    assert_eq!(team.name, "Backend");
+}
+
+/// Test that get_team returns None when the team does not exist.
+#[tokio::test]
+async fn test_get_team_returns_none_when_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org/teams/ghosts"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    let client = GitHubClient::new(octocrab);
+
+    let result = client.get_team("test-org", "ghosts").await;
+
+    assert!(matches!(result, Ok(None)), "got {result:?}");
+}
+
 /// Test listing team members with a single page of results.
 #[tokio::test]
 async fn test_get_team_members_returns_all_members() {
@@ -1176,7 +1299,7 @@ async fn test_get_team_members_returns_all_members() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_team_members(org, team_slug).await;
 
@@ -1232,7 +1355,7 @@ async fn test_get_team_members_paginates_multiple_pages() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_team_members(org, team_slug).await;
 
@@ -1267,7 +1390,7 @@ async fn test_get_team_members_returns_empty_when_no_members() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_team_members(org, team_slug).await;
 
@@ -1298,7 +1421,7 @@ async fn test_get_team_members_returns_error_on_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.get_team_members(org, team_slug).await;
 
@@ -1330,7 +1453,7 @@ async fn test_add_team_to_repository_succeeds() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .add_team_to_repository(org, team_slug, repo, permission)
@@ -1369,7 +1492,7 @@ async fn test_add_team_to_repository_returns_error_on_api_failure() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .add_team_to_repository(org, team_slug, repo, permission)
@@ -1404,7 +1527,7 @@ async fn test_set_team_repository_permission_succeeds() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_team_repository_permission(org, team_slug, repo_owner, repo, permission)
@@ -1446,7 +1569,7 @@ async fn test_set_team_repository_permission_returns_error_on_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_team_repository_permission(org, team_slug, repo_owner, repo, permission)
@@ -1488,7 +1611,7 @@ async fn test_get_team_repository_permission_returns_role_when_access_exists() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_team_repository_permission(org, team_slug, repo_owner, repo)
@@ -1531,7 +1654,7 @@ async fn test_get_team_repository_permission_returns_none_when_no_access() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_team_repository_permission(org, team_slug, repo_owner, repo)
@@ -1573,7 +1696,7 @@ async fn test_get_team_repository_permission_returns_none_on_empty_body() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_team_repository_permission(org, team_slug, repo_owner, repo)
@@ -1616,7 +1739,7 @@ async fn test_get_team_repository_permission_returns_error_on_api_failure() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_team_repository_permission(org, team_slug, repo_owner, repo)
@@ -1665,7 +1788,7 @@ async fn test_get_repository_team_permission_returns_role_when_team_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_repository_team_permission(owner, repo, team_slug)
@@ -1704,7 +1827,7 @@ async fn test_get_repository_team_permission_normalises_pull_and_push() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     // `pull` normalised to `read`
     let r1 = client
@@ -1786,7 +1909,7 @@ async fn test_get_repository_team_permission_returns_none_when_team_not_found()
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_repository_team_permission(owner, repo, team_slug)
@@ -1820,7 +1943,7 @@ async fn test_get_repository_team_permission_returns_none_on_empty_list() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_repository_team_permission(owner, repo, team_slug)
@@ -1853,7 +1976,7 @@ async fn test_get_repository_team_permission_returns_error_on_api_failure() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_repository_team_permission(owner, repo, team_slug)
@@ -1891,7 +2014,7 @@ async fn test_get_collaborator_permission_returns_role_for_existing_collaborator
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_collaborator_permission(owner, repo, username)
@@ -1929,7 +2052,7 @@ async fn test_get_collaborator_permission_returns_not_found_for_non_collaborator
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .get_collaborator_permission(owner, repo, username)
@@ -1974,7 +2097,7 @@ async fn test_list_repository_collaborators_returns_all_collaborators() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_repository_collaborators(owner, repo).await;
 
@@ -2019,7 +2142,7 @@ async fn test_list_repository_collaborators_paginates_multiple_pages() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_repository_collaborators(owner, repo).await;
 
@@ -2048,7 +2171,7 @@ async fn test_list_repository_collaborators_returns_empty_when_none() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_repository_collaborators(owner, repo).await;
 
@@ -2077,7 +2200,7 @@ async fn test_list_repository_collaborators_returns_error_on_api_failure() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client.list_repository_collaborators(owner, repo).await;
 
@@ -2109,7 +2232,7 @@ async fn test_add_repository_collaborator_succeeds() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .add_repository_collaborator(owner, repo, username, permission)
@@ -2148,7 +2271,7 @@ async fn test_add_repository_collaborator_returns_not_found_for_missing_repo() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .add_repository_collaborator(owner, repo, username, permission)
@@ -2188,7 +2311,7 @@ async fn test_set_collaborator_permission_succeeds() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .set_collaborator_permission(owner, repo, username, permission)
@@ -2227,7 +2350,7 @@ async fn test_remove_repository_collaborator_succeeds() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .remove_repository_collaborator(owner, repo, username)
@@ -2268,7 +2391,7 @@ async fn test_remove_repository_collaborator_returns_not_found() {
         .app(TEST_APP_ID.into(), key)
         .build()
         .unwrap();
-    let client = GitHubClient { client: octocrab };
+    let client = GitHubClient::new(octocrab);
 
     let result = client
         .remove_repository_collaborator(owner, repo, username)
@@ -2283,3 +2406,1398 @@ async fn test_remove_repository_collaborator_returns_not_found() {
         "Expected NotFound error"
     );
 }
+
+#[tokio::test]
+async fn test_update_branch_push_protection_requires_signatures() {
+    let mock_server = MockServer::start().await;
+    let route = "/repos/test-org/my-service/branches/main/protection";
+
+    Mock::given(method("PUT"))
+        .and(path(route))
+        .and(wiremock::matchers::body_partial_json(
+            json!({ "allow_force_pushes": false }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{route}/required_signatures")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "enabled": true })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    let client = GitHubClient::new(octocrab);
+
+    let rules = BranchPushProtection {
+        allow_force_pushes: false,
+        required_signatures: true,
+        require_code_owner_reviews: false,
+        required_status_checks: vec![],
+        strict_required_status_checks: false,
+    };
+    let result = client
+        .update_branch_push_protection("test-org", "my-service", "main", &rules)
+        .await;
+
+    assert!(result.is_ok(), "Expected Ok, got {result:?}");
+    mock_server.verify().await;
+}
+
+/// Verify that run_with_timeout returns Error::Timeout when the operation is too slow.
+#[tokio::test]
+async fn test_run_with_timeout_fires_for_slow_operation() {
+    let slow_operation = async {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok::<_, Error>(())
+    };
+
+    let result =
+        run_with_timeout("slow_operation", Duration::from_millis(20), slow_operation).await;
+
+    match result {
+        Err(Error::Timeout { operation, timeout }) => {
+            assert_eq!(operation, "slow_operation");
+            assert_eq!(timeout, Duration::from_millis(20));
+        }
+        other => panic!("Expected Error::Timeout, got {other:?}"),
+    }
+}
+
+/// Verify that run_with_timeout passes through the result of a fast operation.
+#[tokio::test]
+async fn test_run_with_timeout_returns_result_of_fast_operation() {
+    let result = run_with_timeout("fast_operation", Duration::from_secs(5), async {
+        Ok::<_, Error>(42)
+    })
+    .await;
+
+    assert_eq!(result.unwrap(), 42);
+
+    let result = run_with_timeout("failing_operation", Duration::from_secs(5), async {
+        Err::<(), _>(Error::NotFound)
+    })
+    .await;
+
+    assert!(matches!(result, Err(Error::NotFound)));
+}
+
+/// Request `/rate_limit` from a server answering with `status` and `body`.
+async fn rate_limit_error(status: u16, body: serde_json::Value) -> Error {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+
+    create_mock_app_client(&mock_server)
+        .get_rate_limit()
+        .await
+        .expect_err("request should fail")
+}
+
+/// Verify that failed GitHub responses keep their status and message.
+#[tokio::test]
+async fn test_octocrab_errors_map_to_specific_variants() {
+    let forbidden = rate_limit_error(
+        403,
+        json!({ "message": "Resource not accessible by integration" }),
+    )
+    .await;
+    match forbidden {
+        Error::Forbidden { message } => {
+            assert_eq!(message, "Resource not accessible by integration")
+        }
+        other => panic!("Expected Forbidden, got {other:?}"),
+    }
+
+    let rate_limited = rate_limit_error(
+        403,
+        json!({ "message": "API rate limit exceeded for installation ID 1." }),
+    )
+    .await;
+    assert!(
+        matches!(rate_limited, Error::RateLimitExceeded),
+        "got {rate_limited:?}"
+    );
+
+    let validation = rate_limit_error(422, json!({ "message": "Validation Failed" })).await;
+    assert!(
+        matches!(validation, Error::Validation { status: 422, .. }),
+        "got {validation:?}"
+    );
+    assert_eq!(
+        validation.to_string(),
+        "GitHub rejected the request (status 422): Validation Failed"
+    );
+
+    let unparsable = rate_limit_error(200, json!({ "resources": "unexpected" })).await;
+    assert!(matches!(unparsable, Error::Parse(_)), "got {unparsable:?}");
+}
+
+/// Verify that the client timeout applies to API calls that hang.
+#[tokio::test]
+async fn test_get_repository_times_out_when_github_does_not_respond() {
+    let mock_server = MockServer::start().await;
+    let owner = "test-owner";
+    let repo = "test-repo";
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{owner}/{repo}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "id": 1, "name": repo }))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    let client = GitHubClient::new(octocrab).with_timeout(Duration::from_millis(100));
+
+    let result = client.get_repository(owner, repo).await;
+
+    match result {
+        Err(Error::Timeout { operation, .. }) => assert_eq!(operation, "get_repository"),
+        Err(e) => panic!("Expected Error::Timeout, got {e:?}"),
+        Ok(_) => panic!("Expected Error::Timeout, got a repository"),
+    }
+}
+
+/// Verify that a new client uses the default operation timeout.
+#[tokio::test]
+async fn test_new_client_uses_default_timeout() {
+    let octocrab = octocrab::Octocrab::builder().build().unwrap();
+    let client = GitHubClient::new(octocrab);
+
+    assert_eq!(client.timeout(), DEFAULT_OPERATION_TIMEOUT);
+    assert_eq!(
+        client.with_timeout(Duration::from_secs(5)).timeout(),
+        Duration::from_secs(5)
+    );
+}
+
+fn create_mock_app_client(mock_server: &MockServer) -> GitHubClient {
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(create_test_pem().as_bytes()).unwrap();
+    let octocrab = octocrab::Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .app(TEST_APP_ID.into(), key)
+        .build()
+        .unwrap();
+    GitHubClient::new(octocrab)
+}
+
+/// Verify that get_app_metadata parses the /app response.
+#[tokio::test]
+async fn test_get_app_metadata_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/app"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": TEST_APP_ID,
+            "slug": "repo-roller",
+            "name": "RepoRoller",
+            "owner": { "login": "test-org", "type": "Organization" },
+            "permissions": { "administration": "write" },
+            "events": ["repository"]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let app = client
+        .get_app_metadata()
+        .await
+        .expect("Expected app metadata");
+
+    assert_eq!(app.id, TEST_APP_ID);
+    assert_eq!(app.slug, "repo-roller");
+    assert_eq!(app.name, "RepoRoller");
+    assert_eq!(app.owner.unwrap().login, "test-org");
+    assert_eq!(
+        app.permissions.get("administration"),
+        Some(&"write".to_string())
+    );
+}
+
+/// Verify that get_rate_limit parses the /rate_limit response.
+#[tokio::test]
+async fn test_get_rate_limit_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resources": {
+                "core": { "limit": 5000, "used": 250, "remaining": 4750, "reset": 1691591363 },
+                "search": { "limit": 30, "used": 2, "remaining": 28, "reset": 1691591091 },
+                "graphql": { "limit": 5000, "used": 0, "remaining": 5000, "reset": 1691593228 }
+            },
+            "rate": { "limit": 5000, "used": 250, "remaining": 4750, "reset": 1691591363 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let status = client
+        .get_rate_limit()
+        .await
+        .expect("Expected rate limit status");
+
+    assert_eq!(status.core.limit, 5000);
+    assert_eq!(status.core.remaining, 4750);
+    assert_eq!(status.core.reset, 1691591363);
+    assert_eq!(status.search.remaining, 28);
+    assert_eq!(status.graphql.map(|g| g.remaining), Some(5000));
+}
+
+/// Verify that get_rate_limit reports a missing endpoint as not found.
+#[tokio::test]
+async fn test_get_rate_limit_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.get_rate_limit().await;
+
+    assert!(matches!(result, Err(Error::NotFound)));
+}
+
+/// Verify that get_app_metadata reports rejected credentials as an auth error.
+#[tokio::test]
+async fn test_get_app_metadata_unauthorized() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/app"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "message": "A JSON web token could not be decoded",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.get_app_metadata().await;
+
+    assert!(
+        matches!(result, Err(Error::AuthError(_))),
+        "Expected AuthError, got {result:?}"
+    );
+}
+
+/// Verify that list_installation_repositories follows pagination.
+#[tokio::test]
+async fn test_list_installation_repositories_paginates() {
+    let mock_server = MockServer::start().await;
+
+    let repo_json = |name: &str| {
+        json!({
+            "name": name,
+            "full_name": format!("test-org/{name}"),
+            "node_id": format!("node-{name}"),
+            "private": false
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/installation/repositories"))
+        .and(wiremock::matchers::query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 3,
+            "repositories": [repo_json("repo-a"), repo_json("repo-b")]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/installation/repositories"))
+        .and(wiremock::matchers::query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 3,
+            "repositories": [repo_json("repo-c")]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let repositories = client
+        .list_installation_repositories()
+        .await
+        .expect("Expected repositories");
+
+    let names: Vec<&str> = repositories.iter().map(|r| r.name()).collect();
+    assert_eq!(names, vec!["repo-a", "repo-b", "repo-c"]);
+}
+
+/// Verify that list_accessible_repositories reports a missing installation clearly.
+#[tokio::test]
+async fn test_list_accessible_repositories_app_not_installed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/app/installations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.list_accessible_repositories("missing-org").await;
+
+    match result {
+        Err(Error::AuthError(message)) => {
+            assert!(message.contains("not installed on organization 'missing-org'"));
+        }
+        Err(e) => panic!("Expected AuthError, got {e:?}"),
+        Ok(_) => panic!("Expected AuthError, got repositories"),
+    }
+}
+
+/// Verify that get_files_content_at_ref fetches every file in a single GraphQL request.
+#[tokio::test]
+async fn test_get_files_content_at_ref_uses_single_graphql_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(wiremock::matchers::body_partial_json(json!({
+            "variables": {
+                "owner": "test-org",
+                "name": ".reporoller",
+                "e0": "config-staging:global/defaults.toml",
+                "e1": "config-staging:teams/missing/config.toml"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": {
+                    "f0": { "text": "[repository]\nwiki = false\n", "isBinary": false },
+                    "f1": null
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let files = client
+        .get_files_content_at_ref(
+            "test-org",
+            ".reporoller",
+            &["global/defaults.toml", "teams/missing/config.toml"],
+            Some("config-staging"),
+        )
+        .await
+        .expect("Expected file contents");
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(
+        files["global/defaults.toml"].as_deref(),
+        Some("[repository]\nwiki = false\n")
+    );
+    assert_eq!(files["teams/missing/config.toml"], None);
+}
+
+/// Verify that get_files_content_at_ref reports a missing repository as NotFound.
+#[tokio::test]
+async fn test_get_files_content_at_ref_missing_repository() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": null },
+            "errors": [{ "type": "NOT_FOUND", "message": "Could not resolve to a Repository" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .get_files_content_at_ref("test-org", "missing", &["global/defaults.toml"], None)
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound)), "got {result:?}");
+}
+
+/// Verify that get_file_content_at_ref requests the file at the given ref.
+#[tokio::test]
+async fn test_get_file_content_at_ref_passes_ref() {
+    use base64::Engine as _;
+
+    let mock_server = MockServer::start().await;
+    let api_url = "https://api.github.com/repos/test-org/.reporoller/contents/global/defaults.toml";
+    let html_url = "https://github.com/test-org/.reporoller/blob/v1/global/defaults.toml";
+    let content = "[repository]\nwiki = false\n";
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .and(wiremock::matchers::query_param("ref", "v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "defaults.toml",
+            "path": "global/defaults.toml",
+            "sha": "abc123def456",
+            "size": content.len(),
+            "type": "file",
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+            "encoding": "base64",
+            "url": api_url,
+            "html_url": html_url,
+            "git_url": null,
+            "download_url": null,
+            "_links": { "self": api_url, "git": null, "html": html_url }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .get_file_content_at_ref(
+            "test-org",
+            ".reporoller",
+            "global/defaults.toml",
+            Some("v1"),
+        )
+        .await
+        .expect("Expected file content at ref");
+    assert_eq!(result, content);
+
+    // Without the ref the mock does not match, so the request fails.
+    let without_ref = client
+        .get_file_content("test-org", ".reporoller", "global/defaults.toml")
+        .await;
+    assert!(without_ref.is_err());
+}
+
+/// Verify that transfer_repository sends the transfer request and polls the new owner.
+#[tokio::test]
+async fn test_transfer_repository_polls_until_repository_exists_under_new_owner() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/staging-org/test-repo/transfer"))
+        .and(body_json(json!({
+            "new_owner": "final-org",
+            "team_ids": [12, 34]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "id": 123456,
+            "name": "test-repo",
+            "url": "https://api.github.com/repos/staging-org/test-repo"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The transfer has not completed on the first check.
+    Mock::given(method("GET"))
+        .and(path("/repos/final-org/test-repo"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/final-org/test-repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123456,
+            "name": "test-repo",
+            "url": "https://api.github.com/repos/final-org/test-repo"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let repository = client
+        .transfer_repository("staging-org", "test-repo", "final-org", Some(vec![12, 34]))
+        .await
+        .expect("Expected transfer to succeed");
+
+    assert_eq!(repository.name(), "test-repo");
+}
+
+/// Verify that transfer_repository times out when the repository never appears.
+#[tokio::test]
+async fn test_transfer_repository_times_out_when_transfer_does_not_complete() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/staging-org/test-repo/transfer"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "id": 123456,
+            "name": "test-repo",
+            "url": "https://api.github.com/repos/staging-org/test-repo"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/final-org/test-repo"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server).with_timeout(Duration::from_millis(500));
+
+    let result = client
+        .transfer_repository("staging-org", "test-repo", "final-org", None)
+        .await;
+
+    match result {
+        Err(Error::Timeout { operation, .. }) => assert_eq!(operation, "transfer_repository"),
+        Err(e) => panic!("Expected Error::Timeout, got {e:?}"),
+        Ok(_) => panic!("Expected Error::Timeout, got a repository"),
+    }
+}
+
+/// Verify that set_actions_variable creates the variable with the expected body.
+#[tokio::test]
+async fn test_set_actions_variable_creates_variable() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/actions/variables"))
+        .and(body_json(
+            json!({ "name": "RUST_VERSION", "value": "1.80" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .set_actions_variable("test-owner", "test-repo", "RUST_VERSION", "1.80")
+        .await
+        .expect("Expected variable to be created");
+}
+
+/// Verify that set_actions_variable updates a variable that already exists.
+#[tokio::test]
+async fn test_set_actions_variable_updates_existing_variable() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/actions/variables"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "message": "Already exists - Variable already exists",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/repos/test-owner/test-repo/actions/variables/RUST_VERSION",
+        ))
+        .and(body_json(
+            json!({ "name": "RUST_VERSION", "value": "1.81" }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .set_actions_variable("test-owner", "test-repo", "RUST_VERSION", "1.81")
+        .await
+        .expect("Expected variable to be updated");
+}
+
+/// Verify that set_actions_permissions enables Actions and sets the default
+/// workflow permissions.
+#[tokio::test]
+async fn test_set_actions_permissions_sets_workflow_permissions() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/repos/test-owner/test-repo/actions/permissions"))
+        .and(body_json(json!({ "enabled": true })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-owner/test-repo/actions/permissions/workflow",
+        ))
+        .and(body_json(json!({ "default_workflow_permissions": "read" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .set_actions_permissions("test-owner", "test-repo", true, Some("read"))
+        .await
+        .expect("Expected Actions permissions to be set");
+}
+
+/// Verify that disabling Actions does not try to set workflow permissions.
+#[tokio::test]
+async fn test_set_actions_permissions_disabled_skips_workflow_permissions() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/repos/test-owner/test-repo/actions/permissions"))
+        .and(body_json(json!({ "enabled": false })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-owner/test-repo/actions/permissions/workflow",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .set_actions_permissions("test-owner", "test-repo", false, Some("write"))
+        .await
+        .expect("Expected Actions to be disabled");
+}
+
+/// Verify that set_actions_secret seals the value with the repository public key.
+#[tokio::test]
+async fn test_set_actions_secret_uploads_value_sealed_with_repository_key() {
+    use base64::Engine;
+    use crypto_box::SecretKey;
+
+    let mock_server = MockServer::start().await;
+
+    let secret_key = SecretKey::from([42u8; 32]);
+    let public_key = base64::engine::general_purpose::STANDARD.encode(secret_key.public_key());
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-owner/test-repo/actions/secrets/public-key",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "key_id": "568250167242549743",
+            "key": public_key
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-owner/test-repo/actions/secrets/DEPLOY_TOKEN",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .set_actions_secret("test-owner", "test-repo", "DEPLOY_TOKEN", "s3cr3t")
+        .await
+        .expect("Expected secret to be set");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let put_request = requests
+        .iter()
+        .find(|r| r.method.as_str() == "PUT")
+        .expect("Expected a PUT request");
+    let body: serde_json::Value = serde_json::from_slice(&put_request.body).unwrap();
+
+    assert_eq!(body["key_id"], "568250167242549743");
+    let ciphertext = base64::engine::general_purpose::STANDARD
+        .decode(body["encrypted_value"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(secret_key.unseal(&ciphertext).unwrap(), b"s3cr3t");
+    assert!(!String::from_utf8_lossy(&put_request.body).contains("s3cr3t"));
+}
+
+/// Verify that create_initial_commit builds a parentless commit containing
+/// exactly the supplied files and points the branch at it.
+#[tokio::test]
+async fn test_create_initial_commit_uses_git_data_api() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-owner/test-repo/contents/.reporoller-bootstrap",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/blobs"))
+        .and(body_json(
+            json!({ "content": "IyBIZWxsbw==", "encoding": "base64" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "sha": "blob-readme" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/blobs"))
+        .and(body_json(
+            json!({ "content": "ZWNobyBoaQ==", "encoding": "base64" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "sha": "blob-script" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/trees"))
+        .and(body_json(json!({
+            "tree": [
+                { "path": "README.md", "mode": "100644", "type": "blob", "sha": "blob-readme" },
+                { "path": "bin/run.sh", "mode": "100755", "type": "blob", "sha": "blob-script" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "sha": "tree-sha" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/commits"))
+        .and(body_json(json!({
+            "message": "Initial commit",
+            "tree": "tree-sha",
+            "parents": []
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "sha": "commit-sha" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-owner/test-repo/git/refs/heads/main"))
+        .and(body_json(json!({ "sha": "commit-sha", "force": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+    let files = vec![
+        CommitFile {
+            path: "README.md".to_string(),
+            content: b"# Hello".to_vec(),
+            executable: false,
+        },
+        CommitFile {
+            path: "bin/run.sh".to_string(),
+            content: b"echo hi".to_vec(),
+            executable: true,
+        },
+    ];
+
+    let sha = client
+        .create_initial_commit("test-owner", "test-repo", "main", "Initial commit", &files)
+        .await
+        .expect("Expected initial commit to be created");
+
+    assert_eq!(sha, "commit-sha");
+}
+
+/// Verify that create_initial_commit creates the branch ref when it does not exist.
+#[tokio::test]
+async fn test_create_initial_commit_creates_missing_branch() {
+    use wiremock::matchers::body_json;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-owner/test-repo/contents/.reporoller-bootstrap",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/commits"))
+        .and(body_json(json!({
+            "message": "Initial commit",
+            "tree": "4b825dc642cb6eb9a060e54bf8d69288fbee4904",
+            "parents": []
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "sha": "commit-sha" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-owner/test-repo/git/refs/heads/develop"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Reference does not exist",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/git/refs"))
+        .and(body_json(
+            json!({ "ref": "refs/heads/develop", "sha": "commit-sha" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let sha = client
+        .create_initial_commit("test-owner", "test-repo", "develop", "Initial commit", &[])
+        .await
+        .expect("Expected initial commit to be created");
+
+    assert_eq!(sha, "commit-sha");
+}
+
+/// Verify that repository_exists returns true when GitHub finds the repository.
+#[tokio::test]
+async fn test_repository_exists_returns_true_for_existing_repository() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123456,
+            "name": "test-repo",
+            "url": "https://api.github.com/repos/test-owner/test-repo"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let exists = client
+        .repository_exists("test-owner", "test-repo")
+        .await
+        .expect("Expected existence check to succeed");
+
+    assert!(exists);
+}
+
+/// Verify that repository_exists treats a 404 as a missing repository.
+#[tokio::test]
+async fn test_repository_exists_returns_false_for_missing_repository() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/new-repo"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let exists = client
+        .repository_exists("test-owner", "new-repo")
+        .await
+        .expect("Expected a 404 to be reported as not existing");
+
+    assert!(!exists);
+}
+
+/// Verify that repository_exists propagates API errors other than 404.
+#[tokio::test]
+async fn test_repository_exists_propagates_api_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "message": "Internal Server Error"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.repository_exists("test-owner", "test-repo").await;
+
+    assert!(
+        result.is_err(),
+        "Expected the API error to be propagated, got {result:?}"
+    );
+}
+
+/// Verify that replace_topics sends the complete topic list.
+#[tokio::test]
+async fn test_replace_topics_sends_names() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/repos/test-org/test-repo/topics"))
+        .and(wiremock::matchers::body_json(
+            json!({ "names": ["rust", "service"] }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "names": ["rust", "service"]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .replace_topics(
+            "test-org",
+            "test-repo",
+            &["rust".to_string(), "service".to_string()],
+        )
+        .await
+        .expect("Expected topics to be replaced");
+}
+
+/// Verify that replace_topics reports a missing repository as NotFound.
+#[tokio::test]
+async fn test_replace_topics_missing_repository() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/repos/test-org/missing/topics"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .replace_topics("test-org", "missing", &["rust".to_string()])
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound)), "got {result:?}");
+}
+
+/// Verify that a forbidden organization lookup is reported as an authorization error.
+#[tokio::test]
+async fn test_get_organization_default_branch_forbidden() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "message": "Resource not accessible by integration",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.get_organization_default_branch("test-org").await;
+
+    assert!(matches!(result, Err(Error::AuthError(_))), "got {result:?}");
+}
+
+/// Verify that a server error is not mistaken for a permission problem.
+#[tokio::test]
+async fn test_get_organization_default_branch_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(502).set_body_json(json!({
+            "message": "Bad Gateway"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client.get_organization_default_branch("test-org").await;
+
+    assert!(
+        matches!(result, Err(Error::Api { status: 502, .. })),
+        "got {result:?}"
+    );
+}
+
+/// Verify that generate_from_template posts the payload to the template's generate endpoint.
+#[tokio::test]
+async fn test_generate_from_template_posts_payload() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/rust-template/generate"))
+        .and(wiremock::matchers::body_json(json!({
+            "owner": "test-org",
+            "name": "new-repo",
+            "private": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 123456,
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "url": "https://api.github.com/repos/test-org/new-repo",
+            "default_branch": "main"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+    let payload = RepositoryGeneratePayload {
+        owner: "test-org".to_string(),
+        name: "new-repo".to_string(),
+        private: Some(true),
+        ..Default::default()
+    };
+
+    let repo = client
+        .generate_from_template("test-org", "rust-template", &payload)
+        .await
+        .expect("Expected repository to be generated");
+
+    assert_eq!(repo.name(), "new-repo");
+    assert_eq!(repo.default_branch(), Some("main"));
+}
+
+/// Verify that generate_from_template reports a missing template as NotFound.
+#[tokio::test]
+async fn test_generate_from_template_missing_template() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/missing/generate"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+    let payload = RepositoryGeneratePayload {
+        owner: "test-org".to_string(),
+        name: "new-repo".to_string(),
+        ..Default::default()
+    };
+
+    let result = client
+        .generate_from_template("test-org", "missing", &payload)
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound)), "got {result:?}");
+}
+
+/// Verify that create_pull_request posts head, base, title and body.
+#[tokio::test]
+async fn test_create_pull_request_posts_branches_and_description() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/new-repo/pulls"))
+        .and(wiremock::matchers::body_json(json!({
+            "title": "Initial repository content",
+            "head": "template-init",
+            "base": "main",
+            "body": "Scaffold for review"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 1,
+            "number": 1,
+            "html_url": "https://github.com/test-org/new-repo/pull/1",
+            "title": "Initial repository content",
+            "state": "open"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let pull_request = client
+        .create_pull_request(
+            "test-org",
+            "new-repo",
+            "template-init",
+            "main",
+            "Initial repository content",
+            "Scaffold for review",
+        )
+        .await
+        .expect("Expected pull request to be created");
+
+    assert_eq!(pull_request.number, 1);
+    assert_eq!(
+        pull_request.html_url,
+        "https://github.com/test-org/new-repo/pull/1"
+    );
+}
+
+/// Verify that a rejected pull request is reported as a validation error.
+#[tokio::test]
+async fn test_create_pull_request_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/new-repo/pulls"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Validation Failed",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .create_pull_request("test-org", "new-repo", "template-init", "main", "t", "b")
+        .await;
+
+    match result {
+        Err(Error::Validation { status, message }) => {
+            assert_eq!(status, 422);
+            assert_eq!(message, "Validation Failed");
+        }
+        other => panic!("Expected Validation error, got {other:?}"),
+    }
+}
+
+/// Verify that list_labels returns the color and description of each label.
+#[tokio::test]
+async fn test_list_labels_returns_label_details() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "id": 1,
+                "name": "bug",
+                "color": "d73a4a",
+                "description": "Something isn't working",
+                "default": true
+            },
+            {
+                "id": 2,
+                "name": "triage",
+                "color": "ededed",
+                "description": null,
+                "default": false
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let labels = client.list_labels("test-org", "test-repo").await.unwrap();
+
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[0].name, "bug");
+    assert_eq!(labels[0].color, "d73a4a");
+    assert_eq!(
+        labels[0].description.as_deref(),
+        Some("Something isn't working")
+    );
+    assert_eq!(labels[1].description, None);
+
+    let names = client
+        .list_repository_labels("test-org", "test-repo")
+        .await
+        .unwrap();
+    assert_eq!(names, vec!["bug".to_string(), "triage".to_string()]);
+}
+
+/// Verify that delete_label succeeds on 204 and percent-encodes the label name.
+#[tokio::test]
+async fn test_delete_label_success_encodes_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/repos/test-org/test-repo/labels/good%20first%20issue",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .delete_label("test-org", "test-repo", "good first issue")
+        .await
+        .expect("delete_label should succeed on 204");
+}
+
+/// Verify that delete_label maps a 404 to Error::NotFound.
+#[tokio::test]
+async fn test_delete_label_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/repos/test-org/test-repo/labels/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .delete_label("test-org", "test-repo", "missing")
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound)), "got {result:?}");
+}
+
+/// Verify that update_label sends the new color and description and accepts
+/// the label GitHub returns.
+#[tokio::test]
+async fn test_update_label_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/bug"))
+        .and(wiremock::matchers::body_json(json!({
+            "new_name": "bug",
+            "color": "b60205",
+            "description": "Something is broken"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "name": "bug",
+            "color": "b60205",
+            "description": "Something is broken",
+            "default": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    client
+        .update_label(
+            "test-org",
+            "test-repo",
+            "bug",
+            "bug",
+            "b60205",
+            "Something is broken",
+        )
+        .await
+        .expect("update_label should succeed");
+}
+
+/// Verify that update_label maps a 404 to Error::NotFound.
+#[tokio::test]
+async fn test_update_label_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_mock_app_client(&mock_server);
+
+    let result = client
+        .update_label("test-org", "test-repo", "missing", "missing", "ffffff", "")
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound)), "got {result:?}");
+}
+
+/// Verify that label routes percent-encode reserved characters in the name.
+#[test]
+fn test_label_route_encodes_reserved_characters() {
+    assert_eq!(
+        label_route("org", "repo", "type: bug/ui"),
+        "repos/org/repo/labels/type%3A%20bug%2Fui"
+    );
+    assert_eq!(
+        label_route("org", "repo", "needs-triage_v2.0~"),
+        "repos/org/repo/labels/needs-triage_v2.0~"
+    );
+}
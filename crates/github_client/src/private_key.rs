@@ -0,0 +1,131 @@
+//! GitHub App private key handling.
+//!
+//! Callers previously read the PEM file themselves and passed the key around as
+//! a plain `String`, which made it easy to log by accident. [`AppPrivateKey`]
+//! loads the key from a string, a file, or the file named by the
+//! [`PRIVATE_KEY_PATH_ENV_VAR`] environment variable, validates it, and keeps
+//! it in a [`SecretString`] that is zeroed on drop and redacted in `Debug`.
+
+use crate::Error;
+use jsonwebtoken::EncodingKey;
+use secrecy::{ExposeSecret, SecretString};
+use std::path::Path;
+
+#[cfg(test)]
+#[path = "private_key_tests.rs"]
+mod tests;
+
+/// Environment variable naming the file that holds the GitHub App private key.
+pub const PRIVATE_KEY_PATH_ENV_VAR: &str = "GITHUB_APP_PRIVATE_KEY_PATH";
+
+/// PEM label of a PKCS#1 RSA private key, as exported by GitHub.
+const PKCS1_LABEL: &str = "RSA PRIVATE KEY";
+
+/// PEM label of an unencrypted PKCS#8 private key, as produced by
+/// `openssl pkcs8 -topk8 -nocrypt` and many key management tools.
+const PKCS8_LABEL: &str = "PRIVATE KEY";
+
+/// Returns the label of the first PEM block in `pem`, e.g. `RSA PRIVATE KEY`.
+pub(crate) fn pem_label(pem: &str) -> Option<&str> {
+    let start = pem.find("-----BEGIN ")? + "-----BEGIN ".len();
+    let len = pem[start..].find("-----")?;
+    Some(&pem[start..start + len])
+}
+
+/// Parses a GitHub App private key in PKCS#1 or PKCS#8 PEM format.
+///
+/// GitHub App keys are RSA keys; other key types and encrypted keys are
+/// rejected with a message naming the format that was found.
+pub(crate) fn parse_encoding_key(pem: &str) -> Result<EncodingKey, String> {
+    match pem_label(pem) {
+        Some(PKCS1_LABEL) | Some(PKCS8_LABEL) => {
+            EncodingKey::from_rsa_pem(pem.as_bytes()).map_err(|e| e.to_string())
+        }
+        Some(label) => Err(format!(
+            "unsupported key format '{label}', expected an unencrypted RSA key \
+             ('{PKCS1_LABEL}' or '{PKCS8_LABEL}')"
+        )),
+        None => Err("key is not PEM-encoded, no '-----BEGIN' header found".to_string()),
+    }
+}
+
+/// A validated, PEM-encoded GitHub App private key.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use github_client::{create_app_client_from_key, AppPrivateKey, Error};
+///
+/// # async fn example() -> Result<(), Error> {
+/// let key = AppPrivateKey::from_file("app-key.pem")?;
+/// let client = create_app_client_from_key(123456, &key).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AppPrivateKey {
+    pem: SecretString,
+}
+
+impl AppPrivateKey {
+    /// Creates a key from PEM text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrivateKey`] if the text is not a PEM-encoded RSA key
+    /// in PKCS#1 (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`) format.
+    pub fn from_pem(pem: impl Into<String>) -> Result<Self, Error> {
+        let pem = SecretString::from(pem.into());
+        parse_encoding_key(pem.expose_secret()).map_err(Error::InvalidPrivateKey)?;
+        Ok(Self { pem })
+    }
+
+    /// Reads and validates the key stored in the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PrivateKeyFile`] if the file is missing or unreadable, and
+    /// [`Error::InvalidPrivateKey`] if its contents are not a PEM-encoded RSA key.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let pem = std::fs::read_to_string(path).map_err(|e| Error::PrivateKeyFile {
+            path: path.display().to_string(),
+            reason: match e.kind() {
+                std::io::ErrorKind::NotFound => "file does not exist".to_string(),
+                _ => e.to_string(),
+            },
+        })?;
+        Self::from_pem(pem)
+    }
+
+    /// Reads the key from the file named by [`PRIVATE_KEY_PATH_ENV_VAR`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingEnvironmentVariable`] if the variable is not set,
+    /// otherwise the errors of [`AppPrivateKey::from_file`].
+    pub fn from_env() -> Result<Self, Error> {
+        let path = std::env::var(PRIVATE_KEY_PATH_ENV_VAR)
+            .map_err(|_| Error::MissingEnvironmentVariable(PRIVATE_KEY_PATH_ENV_VAR.to_string()))?;
+        Self::from_file(path)
+    }
+
+    /// Returns the PEM text of the key. Never log the returned value.
+    pub fn expose_pem(&self) -> &str {
+        self.pem.expose_secret()
+    }
+}
+
+impl From<AppPrivateKey> for SecretString {
+    fn from(key: AppPrivateKey) -> Self {
+        key.pem
+    }
+}
+
+impl std::fmt::Debug for AppPrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppPrivateKey")
+            .field("pem", &"<REDACTED>")
+            .finish()
+    }
+}
@@ -0,0 +1,40 @@
+//! Pull request domain types.
+//!
+//! This module contains types representing GitHub pull requests.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "pull_request_tests.rs"]
+mod tests;
+
+/// A pull request, as returned by `POST /repos/{owner}/{repo}/pulls`.
+///
+/// Only the fields RepoRoller needs are captured; the rest of GitHub's
+/// response is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use github_client::PullRequest;
+///
+/// let json = r#"{
+///     "number": 1,
+///     "html_url": "https://github.com/my-org/my-repo/pull/1",
+///     "title": "Initial repository content",
+///     "state": "open"
+/// }"#;
+/// let pull_request: PullRequest = serde_json::from_str(json).unwrap();
+/// assert_eq!(pull_request.number, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequest {
+    /// The pull request number within the repository
+    pub number: u64,
+    /// The web URL of the pull request
+    pub html_url: String,
+    /// The pull request title
+    pub title: String,
+    /// The pull request state (`open` or `closed`)
+    pub state: String,
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn test_pull_request_deserialization_ignores_unknown_fields() {
+    let json_str = r#"{
+        "id": 1296269,
+        "number": 42,
+        "html_url": "https://github.com/octocat/hello-world/pull/42",
+        "title": "Initial repository content",
+        "state": "open",
+        "head": { "ref": "template-init" },
+        "base": { "ref": "main" }
+    }"#;
+
+    let pull_request: PullRequest =
+        serde_json::from_str(json_str).expect("Failed to deserialize PullRequest");
+
+    assert_eq!(pull_request.number, 42);
+    assert_eq!(
+        pull_request.html_url,
+        "https://github.com/octocat/hello-world/pull/42"
+    );
+    assert_eq!(pull_request.title, "Initial repository content");
+    assert_eq!(pull_request.state, "open");
+}
@@ -0,0 +1,60 @@
+//! GitHub API rate-limit domain types.
+//!
+//! This module contains types describing the remaining API quota of the
+//! authenticated identity, as returned by the `GET /rate_limit` endpoint.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "rate_limit_tests.rs"]
+mod tests;
+
+/// Quota of a single GitHub rate-limit resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RateLimitResource {
+    /// Maximum number of requests allowed in the current window
+    pub limit: u64,
+    /// Number of requests remaining in the current window
+    pub remaining: u64,
+    /// Number of requests made in the current window
+    #[serde(default)]
+    pub used: u64,
+    /// Time at which the current window resets, in seconds since the Unix epoch
+    pub reset: u64,
+}
+
+/// Remaining GitHub API quota of the authenticated identity.
+///
+/// Returned by `GitHubClient::get_rate_limit`. Querying the rate limit does
+/// not itself count against the quota.
+///
+/// # Examples
+///
+/// ```rust
+/// use github_client::RateLimitStatus;
+///
+/// let json = r#"{
+///     "core": { "limit": 5000, "remaining": 4999, "used": 1, "reset": 1700000000 },
+///     "search": { "limit": 30, "remaining": 30, "used": 0, "reset": 1700000060 }
+/// }"#;
+/// let status: RateLimitStatus = serde_json::from_str(json).unwrap();
+/// assert_eq!(status.core.remaining, 4999);
+/// assert!(status.graphql.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RateLimitStatus {
+    /// Quota for the REST API
+    pub core: RateLimitResource,
+    /// Quota for the search API
+    pub search: RateLimitResource,
+    /// Quota for the GraphQL API, if reported
+    #[serde(default)]
+    pub graphql: Option<RateLimitResource>,
+}
+
+/// Response body of `GET /rate_limit`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RateLimitResponse {
+    /// Quota per resource
+    pub resources: RateLimitStatus,
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_rate_limit_response_deserialization() {
+    // Abridged from the GitHub documentation for GET /rate_limit.
+    let json = r#"{
+        "resources": {
+            "core": { "limit": 5000, "used": 1, "remaining": 4999, "reset": 1691591363 },
+            "search": { "limit": 30, "used": 12, "remaining": 18, "reset": 1691591091 },
+            "graphql": { "limit": 5000, "used": 7, "remaining": 4993, "reset": 1691593228 },
+            "integration_manifest": { "limit": 5000, "used": 1, "remaining": 4999, "reset": 1691594631 }
+        },
+        "rate": { "limit": 5000, "used": 1, "remaining": 4999, "reset": 1372700873 }
+    }"#;
+
+    let response: RateLimitResponse =
+        serde_json::from_str(json).expect("Failed to deserialize rate limit response");
+
+    assert_eq!(
+        response.resources,
+        RateLimitStatus {
+            core: RateLimitResource {
+                limit: 5000,
+                remaining: 4999,
+                used: 1,
+                reset: 1691591363,
+            },
+            search: RateLimitResource {
+                limit: 30,
+                remaining: 18,
+                used: 12,
+                reset: 1691591091,
+            },
+            graphql: Some(RateLimitResource {
+                limit: 5000,
+                remaining: 4993,
+                used: 7,
+                reset: 1691593228,
+            }),
+        }
+    );
+}
+
+#[test]
+fn test_rate_limit_status_without_graphql() {
+    let json = r#"{
+        "core": { "limit": 60, "remaining": 0, "reset": 1691591363 },
+        "search": { "limit": 10, "remaining": 10, "reset": 1691591091 }
+    }"#;
+
+    let status: RateLimitStatus = serde_json::from_str(json).expect("Failed to deserialize");
+
+    assert_eq!(status.core.remaining, 0);
+    assert_eq!(status.core.used, 0);
+    assert!(status.graphql.is_none());
+}
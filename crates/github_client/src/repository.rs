@@ -61,6 +61,8 @@ pub struct Repository {
     node_id: String,
     /// Whether the repository is private
     private: bool,
+    /// The repository visibility: `public`, `private`, or `internal`
+    visibility: Option<String>,
     /// Whether issues are enabled for this repository
     has_issues: Option<bool>,
     /// Whether the wiki is enabled for this repository
@@ -71,6 +73,14 @@ pub struct Repository {
     has_discussions: Option<bool>,
     /// Whether auto-merge is enabled for this repository
     allow_auto_merge: Option<bool>,
+    /// Whether the repository is marked as a template repository
+    is_template: Option<bool>,
+    /// The name of the repository's default branch
+    default_branch: Option<String>,
+    /// The repository description
+    description: Option<String>,
+    /// The topics assigned to the repository
+    topics: Option<Vec<String>>,
 }
 
 impl Repository {
@@ -83,6 +93,21 @@ impl Repository {
         self.private
     }
 
+    /// Returns the repository visibility.
+    ///
+    /// # Returns
+    ///
+    /// `public`, `private`, or `internal`. When GitHub did not report the
+    /// visibility it is derived from the private flag, so `internal` is only
+    /// returned when GitHub reported it.
+    pub fn visibility(&self) -> &str {
+        match self.visibility.as_deref() {
+            Some(visibility) => visibility,
+            None if self.private => "private",
+            None => "public",
+        }
+    }
+
     /// Returns the name of the repository.
     ///
     /// # Returns
@@ -110,11 +135,16 @@ impl Repository {
             name,
             node_id,
             private,
+            visibility: None,
             has_issues: None,
             has_wiki: None,
             has_projects: None,
             has_discussions: None,
             allow_auto_merge: None,
+            is_template: None,
+            default_branch: None,
+            description: None,
+            topics: None,
         }
     }
 
@@ -166,6 +196,44 @@ impl Repository {
         self.allow_auto_merge
     }
 
+    /// Returns whether the repository is marked as a template repository.
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if the repository can be used with GitHub's "generate from
+    /// template" API, `Some(false)` if not, or `None` if unknown.
+    pub fn is_template(&self) -> Option<bool> {
+        self.is_template
+    }
+
+    /// Returns the name of the repository's default branch.
+    ///
+    /// # Returns
+    ///
+    /// The default branch name, or `None` if unknown (e.g. for an empty repository).
+    pub fn default_branch(&self) -> Option<&str> {
+        self.default_branch.as_deref()
+    }
+
+    /// Returns the repository description.
+    ///
+    /// # Returns
+    ///
+    /// The description, or `None` if the repository has none or it is unknown.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the topics assigned to the repository.
+    ///
+    /// # Returns
+    ///
+    /// The topic names, or `None` if unknown. A repository without topics
+    /// reports an empty list.
+    pub fn topics(&self) -> Option<&[String]> {
+        self.topics.as_deref()
+    }
+
     /// Returns the GraphQL node ID of the repository.
     ///
     /// # Returns
@@ -198,6 +266,7 @@ impl From<octocrab::models::Repository> for Repository {
             full_name: value.full_name.unwrap_or(value.name.clone()),
             node_id: value.node_id.unwrap_or_default(),
             private: value.private.unwrap_or(false),
+            visibility: value.visibility,
             has_issues: value.has_issues,
             has_wiki: value.has_wiki,
             has_projects: value.has_projects,
@@ -205,6 +274,10 @@ impl From<octocrab::models::Repository> for Repository {
             // This field may need to be fetched separately via the GitHub API
             has_discussions: None,
             allow_auto_merge: value.allow_auto_merge,
+            is_template: value.is_template,
+            default_branch: value.default_branch,
+            description: value.description,
+            topics: value.topics,
         }
     }
 }
@@ -42,6 +42,28 @@ fn test_repository_deserialization() {
     assert!(!repo.is_private());
 }
 
+#[test]
+fn test_repository_visibility() {
+    let json_str = r#"{
+        "name": "internal-repo",
+        "full_name": "org/internal-repo",
+        "node_id": "node123",
+        "private": true,
+        "visibility": "internal"
+    }"#;
+    let repo: Repository = from_str(json_str).expect("Failed to deserialize Repository");
+    assert_eq!(repo.visibility(), "internal");
+
+    // Without a reported visibility it is derived from the private flag
+    let repo = Repository::new(
+        "repo".to_string(),
+        "org/repo".to_string(),
+        "node".to_string(),
+        true,
+    );
+    assert_eq!(repo.visibility(), "private");
+}
+
 #[test]
 fn test_repository_deserialization_with_features() {
     let json_str = r#"{
@@ -63,6 +85,25 @@ fn test_repository_deserialization_with_features() {
     assert_eq!(repo.has_wiki(), Some(false));
     assert_eq!(repo.has_projects(), Some(true));
     assert_eq!(repo.has_discussions(), Some(false));
+    assert_eq!(repo.is_template(), None);
+    assert_eq!(repo.default_branch(), None);
+}
+
+#[test]
+fn test_repository_deserialization_with_template_flag() {
+    let json_str = r#"{
+        "name": "rust-template",
+        "full_name": "org/rust-template",
+        "node_id": "node456",
+        "private": false,
+        "is_template": true,
+        "default_branch": "main"
+    }"#;
+
+    let repo: Repository = from_str(json_str).expect("Failed to deserialize Repository");
+
+    assert_eq!(repo.is_template(), Some(true));
+    assert_eq!(repo.default_branch(), Some("main"));
 }
 
 #[test]
@@ -97,3 +138,37 @@ fn test_repository_private_flag() {
     assert!(!public_repo.is_private());
     assert!(private_repo.is_private());
 }
+
+#[test]
+fn test_repository_settings_from_github_response() {
+    let github_repo: octocrab::models::Repository = serde_json::from_value(serde_json::json!({
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "my-service",
+        "full_name": "my-org/my-service",
+        "url": "https://api.github.com/repos/my-org/my-service",
+        "private": false,
+        "visibility": "internal",
+        "description": "Order processing service",
+        "topics": ["rust", "service"],
+        "has_issues": true,
+        "has_projects": false,
+        "has_wiki": false,
+        "default_branch": "develop"
+    }))
+    .expect("Failed to deserialize GitHub repository");
+
+    let repo = Repository::from(github_repo);
+
+    assert_eq!(repo.full_name, "my-org/my-service");
+    assert_eq!(repo.visibility(), "internal");
+    assert_eq!(repo.description(), Some("Order processing service"));
+    assert_eq!(
+        repo.topics(),
+        Some(&["rust".to_string(), "service".to_string()][..])
+    );
+    assert_eq!(repo.has_issues(), Some(true));
+    assert_eq!(repo.has_projects(), Some(false));
+    assert_eq!(repo.has_wiki(), Some(false));
+    assert_eq!(repo.default_branch(), Some("develop"));
+}
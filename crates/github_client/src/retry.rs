@@ -0,0 +1,79 @@
+//! Retry policy for GitHub API calls that hit rate limits.
+//!
+//! GitHub answers rate-limited requests with `429 Too Many Requests`, or with
+//! `403 Forbidden` and a message mentioning the rate limit. Both are transient,
+//! so callers that issue many requests in a row (for example when paging through
+//! an organization's repositories) can back off and retry the same request.
+
+use std::time::Duration;
+
+#[cfg(test)]
+#[path = "retry_tests.rs"]
+mod tests;
+
+/// Exponential backoff policy for retrying rate-limited requests.
+///
+/// The delay before retry `n` (starting at zero) is `initial_backoff * 2^n`,
+/// capped at `max_backoff`.
+///
+/// # Examples
+///
+/// ```rust
+/// use github_client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::default();
+/// assert_eq!(policy.backoff_for(0), Duration::from_secs(1));
+/// assert_eq!(policy.backoff_for(1), Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between retries
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait before the given retry (zero-based).
+    pub fn backoff_for(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Returns `true` if a response status and message indicate a rate limit.
+///
+/// A `429` is always a rate limit. A `403` is only treated as one when the
+/// message mentions the rate limit, because GitHub also uses `403` for
+/// permission failures, which retrying will not fix.
+pub fn is_rate_limited(status: http::StatusCode, message: &str) -> bool {
+    status == http::StatusCode::TOO_MANY_REQUESTS
+        || (status == http::StatusCode::FORBIDDEN && message.to_lowercase().contains("rate limit"))
+}
+
+/// Returns `true` if an octocrab error is a GitHub rate-limit response.
+///
+/// See [`is_rate_limited`] for the classification rules.
+pub fn is_rate_limit_error(error: &octocrab::Error) -> bool {
+    match error {
+        octocrab::Error::GitHub { source, .. } => {
+            is_rate_limited(source.status_code, &source.message)
+        }
+        _ => false,
+    }
+}
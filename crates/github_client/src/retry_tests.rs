@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_backoff_doubles_per_retry() {
+    let policy = RetryPolicy {
+        max_retries: 5,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_secs(60),
+    };
+
+    assert_eq!(policy.backoff_for(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff_for(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff_for(3), Duration::from_millis(800));
+}
+
+#[test]
+fn test_backoff_is_capped_at_max() {
+    let policy = RetryPolicy {
+        max_retries: 50,
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(30),
+    };
+
+    assert_eq!(policy.backoff_for(5), Duration::from_secs(30));
+    assert_eq!(policy.backoff_for(40), Duration::from_secs(30));
+}
+
+#[test]
+fn test_too_many_requests_is_rate_limited() {
+    assert!(is_rate_limited(http::StatusCode::TOO_MANY_REQUESTS, ""));
+}
+
+#[test]
+fn test_forbidden_is_rate_limited_only_with_rate_limit_message() {
+    assert!(is_rate_limited(
+        http::StatusCode::FORBIDDEN,
+        "API rate limit exceeded for installation ID 1234."
+    ));
+    assert!(is_rate_limited(
+        http::StatusCode::FORBIDDEN,
+        "You have exceeded a secondary rate limit."
+    ));
+    assert!(!is_rate_limited(
+        http::StatusCode::FORBIDDEN,
+        "Resource not accessible by integration"
+    ));
+}
+
+#[test]
+fn test_other_statuses_are_not_rate_limited() {
+    assert!(!is_rate_limited(http::StatusCode::NOT_FOUND, "rate limit"));
+    assert!(!is_rate_limited(
+        http::StatusCode::INTERNAL_SERVER_ERROR,
+        ""
+    ));
+}
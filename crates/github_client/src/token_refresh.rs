@@ -0,0 +1,130 @@
+//! Installation token refresh for long-running clients.
+//!
+//! Installation access tokens expire one hour after they are issued. A
+//! [`GitHubClient`] configured with [`GitHubClient::with_token_refresher`]
+//! obtains a new token when GitHub rejects a request with `401 Unauthorized`
+//! and retries the rejected operation once. GitHub rejects the request before
+//! acting on it, so retrying is safe for non-idempotent operations as well.
+
+use async_trait::async_trait;
+use octocrab::Octocrab;
+use std::cell::Cell;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::{create_octocrab_client, Error, GitHubClient};
+
+#[cfg(test)]
+#[path = "token_refresh_tests.rs"]
+mod tests;
+
+tokio::task_local! {
+    /// Set when GitHub answers a request of the current operation with 401.
+    static AUTH_REJECTED: Cell<bool>;
+}
+
+/// Source of freshly authenticated Octocrab clients.
+///
+/// Implementations are called by [`GitHubClient`] after GitHub rejects its
+/// current token.
+#[async_trait]
+pub trait TokenRefresher: Send + Sync + std::fmt::Debug {
+    /// Returns an Octocrab client authenticated with a new token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new token cannot be obtained.
+    async fn refresh(&self) -> Result<Octocrab, Error>;
+}
+
+#[async_trait]
+impl<T: TokenRefresher + ?Sized> TokenRefresher for Arc<T> {
+    async fn refresh(&self) -> Result<Octocrab, Error> {
+        self.as_ref().refresh().await
+    }
+}
+
+/// Refreshes an installation token through the GitHub App client.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use github_client::{create_app_client, create_github_client, GitHubClient, InstallationTokenRefresher};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let app_client = GitHubClient::new(create_app_client(123456, "...").await?);
+/// let token = app_client.get_installation_token_for_org("my-org").await?;
+///
+/// let client = create_github_client(&token, None)?
+///     .with_token_refresher(InstallationTokenRefresher::new(app_client, "my-org"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct InstallationTokenRefresher {
+    /// Client authenticated as the GitHub App
+    app_client: GitHubClient,
+    /// Organization whose installation issues the token
+    organization: String,
+    /// GitHub API base URL for the refreshed client, if not the default
+    base_url: Option<String>,
+}
+
+impl InstallationTokenRefresher {
+    /// Creates a refresher for the app installation on `organization`.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_client` - Client authenticated as the GitHub App (JWT)
+    /// * `organization` - Organization the app is installed on
+    pub fn new(app_client: GitHubClient, organization: impl Into<String>) -> Self {
+        Self {
+            app_client,
+            organization: organization.into(),
+            base_url: None,
+        }
+    }
+
+    /// Builds refreshed clients against a different GitHub API base URL.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+}
+
+#[async_trait]
+impl TokenRefresher for InstallationTokenRefresher {
+    async fn refresh(&self) -> Result<Octocrab, Error> {
+        let token = self
+            .app_client
+            .get_installation_token_for_org(&self.organization)
+            .await?;
+        let client = create_octocrab_client(&token, self.base_url.as_deref())?;
+        Ok(client.as_ref().clone())
+    }
+}
+
+/// Records that an Octocrab error was a `401 Unauthorized` response.
+///
+/// Has no effect outside [`track_auth_rejection`].
+pub(crate) fn record_octocrab_error(error: &octocrab::Error) {
+    if let octocrab::Error::GitHub { source, .. } = error {
+        if source.status_code == http::StatusCode::UNAUTHORIZED {
+            let _ = AUTH_REJECTED.try_with(|rejected| rejected.set(true));
+        }
+    }
+}
+
+/// Runs `future`, also returning whether GitHub answered any of its
+/// requests with `401 Unauthorized`.
+///
+/// Rejections inside a nested call are only reported to the innermost
+/// enclosing call.
+pub(crate) async fn track_auth_rejection<F: Future>(future: F) -> (F::Output, bool) {
+    AUTH_REJECTED
+        .scope(Cell::new(false), async move {
+            let output = future.await;
+            (output, AUTH_REJECTED.with(Cell::get))
+        })
+        .await
+}
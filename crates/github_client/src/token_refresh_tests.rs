@@ -0,0 +1,156 @@
+use super::*;
+use crate::create_github_client;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const PUBLIC_KEY_PATH: &str = "/repos/test-owner/test-repo/actions/secrets/public-key";
+
+/// Refresher returning clients for a fixed new token, counting its calls.
+#[derive(Debug)]
+struct CountingRefresher {
+    base_url: String,
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl TokenRefresher for CountingRefresher {
+    async fn refresh(&self) -> Result<Octocrab, Error> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let client = create_octocrab_client("refreshed-token", Some(&self.base_url))?;
+        Ok(client.as_ref().clone())
+    }
+}
+
+fn unauthorized() -> ResponseTemplate {
+    ResponseTemplate::new(401).set_body_json(json!({
+        "message": "Bad credentials",
+        "documentation_url": "https://docs.github.com/rest"
+    }))
+}
+
+fn public_key() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "key_id": "012345678912345678",
+        "key": "2Sg8iYjAxxmI2LvUXpJjkYrMxURPc8r+dB7TJyvv1234"
+    }))
+}
+
+fn client_with_refresher(mock_server: &MockServer, calls: &Arc<AtomicUsize>) -> GitHubClient {
+    create_github_client("expired-token", Some(&mock_server.uri()))
+        .unwrap()
+        .with_token_refresher(CountingRefresher {
+            base_url: mock_server.uri(),
+            calls: calls.clone(),
+        })
+}
+
+/// Verify that a 401 refreshes the token and the retried call succeeds.
+#[tokio::test]
+async fn test_unauthorized_response_refreshes_token_and_retries() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(PUBLIC_KEY_PATH))
+        .respond_with(unauthorized())
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PUBLIC_KEY_PATH))
+        .respond_with(public_key())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let client = client_with_refresher(&mock_server, &calls);
+
+    let key = client
+        .get_actions_public_key("test-owner", "test-repo")
+        .await
+        .expect("Expected the retried call to succeed");
+
+    assert_eq!(key.key_id, "012345678912345678");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let retry_auth = requests[1]
+        .headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    assert!(retry_auth.contains("refreshed-token"));
+}
+
+/// Verify that the token is refreshed only once when GitHub keeps rejecting it.
+#[tokio::test]
+async fn test_unauthorized_retry_is_attempted_once() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(PUBLIC_KEY_PATH))
+        .respond_with(unauthorized())
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let client = client_with_refresher(&mock_server, &calls);
+
+    let result = client
+        .get_actions_public_key("test-owner", "test-repo")
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+/// Verify that a client without a refresher does not retry a 401.
+#[tokio::test]
+async fn test_unauthorized_without_refresher_is_not_retried() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(PUBLIC_KEY_PATH))
+        .respond_with(unauthorized())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_github_client("expired-token", Some(&mock_server.uri())).unwrap();
+
+    let result = client
+        .get_actions_public_key("test-owner", "test-repo")
+        .await;
+
+    assert!(result.is_err());
+}
+
+/// Verify that other errors do not trigger a refresh.
+#[tokio::test]
+async fn test_other_errors_do_not_refresh_token() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(PUBLIC_KEY_PATH))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "message": "Resource not accessible by integration"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let client = client_with_refresher(&mock_server, &calls);
+
+    let result = client
+        .get_actions_public_key("test-owner", "test-repo")
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
@@ -418,6 +418,7 @@ impl IntegrationTestRunner {
             visibility_policy_provider,
             environment_detector,
             event_context,
+            repo_roller_core::CreationDependencies::default(),
         )
         .await;
 
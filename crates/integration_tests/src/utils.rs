@@ -98,8 +98,11 @@ impl TestRepository {
 }
 
 /// Initialize logging for integration tests.
+///
+/// Safe to call from every test: if a subscriber is already installed the call
+/// does nothing.
 pub fn init_logging() {
-    tracing_subscriber::fmt()
+    let _ = tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
@@ -108,7 +111,7 @@ pub fn init_logging() {
         .with_thread_ids(true)
         .with_file(true)
         .with_line_number(true)
-        .init();
+        .try_init();
 }
 
 /// Validate that required environment variables are set for testing.
@@ -73,6 +73,7 @@ async fn test_override_protection_prevents_template_override() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -171,6 +172,7 @@ async fn test_fixed_value_cannot_be_overridden() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -263,6 +265,7 @@ async fn test_null_and_empty_value_handling() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -346,6 +349,7 @@ async fn test_partial_field_overrides() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -446,6 +450,7 @@ async fn test_label_collection_merging() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -544,6 +549,7 @@ async fn test_webhook_collection_accumulation() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -640,6 +646,7 @@ async fn test_invalid_repository_type_combination() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -720,6 +727,7 @@ async fn test_complete_four_level_hierarchy() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -802,6 +810,7 @@ async fn test_hierarchy_with_missing_levels() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -890,6 +899,7 @@ async fn test_conflicting_collection_items() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -79,6 +79,7 @@ async fn test_basic_repository_creation() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -181,6 +182,7 @@ async fn test_variable_substitution() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -284,6 +286,7 @@ async fn test_file_filtering() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -370,6 +373,7 @@ async fn test_error_handling_invalid_template() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -430,6 +434,7 @@ async fn test_organization_settings() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -522,6 +527,7 @@ async fn test_team_configuration() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -604,6 +610,7 @@ async fn test_repository_type_configuration() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -689,6 +696,7 @@ async fn test_configuration_hierarchy() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -2,7 +2,7 @@
 //!
 //! These tests verify the functionality added in Task 6.0-6.8 for creating
 //! repositories without using template content, including:
-//! - Empty repositories (no files)
+//! - Empty repositories (placeholder README.md only)
 //! - Custom initialization (README.md and/or .gitignore only)
 //! - Settings application without templates
 //!
@@ -35,7 +35,7 @@ fn init_test_logging() {
 ///
 /// Verifies that:
 /// - Repository is created successfully
-/// - No files are present in the repository
+/// - Only a placeholder README.md is present
 /// - Organization default settings are applied
 #[tokio::test]
 async fn test_empty_repository_without_template() -> Result<()> {
@@ -82,6 +82,7 @@ async fn test_empty_repository_without_template() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -98,15 +99,14 @@ async fn test_empty_repository_without_template() -> Result<()> {
 
     assert_eq!(repo.name(), repo_name, "Repository name should match");
 
-    // Verify repository is empty by trying to get README.md
-    // Empty repository should not have any files
-    let readme_result = verification_client
+    // Empty repository should have a placeholder README.md headed by its name
+    let readme = verification_client
         .get_file_content(&config.test_org, &repo_name, "README.md")
-        .await;
+        .await?;
 
     assert!(
-        readme_result.is_err(),
-        "Empty repository should not have README.md"
+        readme.starts_with(&format!("# {}", repo_name)),
+        "Placeholder README.md should be headed by the repository name"
     );
 
     info!("✓ Empty repository without template test passed");
@@ -117,7 +117,7 @@ async fn test_empty_repository_without_template() -> Result<()> {
 ///
 /// Verifies that:
 /// - Repository is created successfully
-/// - No files are present (despite template providing them)
+/// - Only a placeholder README.md is present (no template files)
 /// - Template settings are applied (not org defaults)
 #[tokio::test]
 async fn test_empty_repository_with_template_settings() -> Result<()> {
@@ -165,6 +165,7 @@ async fn test_empty_repository_with_template_settings() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -184,14 +185,14 @@ async fn test_empty_repository_with_template_settings() -> Result<()> {
 
     assert_eq!(repo.name(), repo_name, "Repository name should match");
 
-    // Verify repository is empty despite having template
-    let readme_result = verification_client
+    // Verify template files were not copied despite having a template
+    let readme = verification_client
         .get_file_content(&config.test_org, &repo_name, "README.md")
-        .await;
+        .await?;
 
     assert!(
-        readme_result.is_err(),
-        "Empty repository should not have README.md even with template"
+        readme.starts_with(&format!("# {}", repo_name)),
+        "README.md should be the placeholder, not the template's"
     );
 
     // Note: template settings (labels, webhooks, rulesets) are applied during repository
@@ -200,6 +201,71 @@ async fn test_empty_repository_with_template_settings() -> Result<()> {
     Ok(())
 }
 
+/// Test that an empty repository still receives its configured labels.
+///
+/// Verifies that:
+/// - Repository is created without a template
+/// - Labels from the organization configuration are applied
+#[tokio::test]
+async fn test_empty_repository_receives_configured_labels() -> Result<()> {
+    init_test_logging();
+    info!("Testing empty repository label application");
+
+    let config = TestConfig::from_env()?;
+    let repo_name = generate_test_repo_name("test", "empty-labels");
+    let _test_repo = TestRepository::new(repo_name.clone(), config.test_org.clone());
+
+    let auth_service = auth_handler::GitHubAuthService::new(
+        config.github_app_id,
+        config.github_app_private_key.clone(),
+    );
+    let installation_token = auth_service
+        .get_installation_token_for_org(&config.test_org)
+        .await?;
+    let providers = create_visibility_providers(&installation_token, ".reporoller").await?;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new(&repo_name)?,
+        OrganizationName::new(&config.test_org)?,
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+
+    let event_providers = integration_tests::create_event_notification_providers();
+    create_repository(
+        request,
+        providers.metadata_provider.as_ref(),
+        &auth_service,
+        ".reporoller",
+        providers.visibility_policy_provider,
+        providers.environment_detector,
+        repo_roller_core::EventNotificationContext::new(
+            "integration-test",
+            event_providers.secret_resolver.clone(),
+            event_providers.metrics.clone(),
+        ),
+        repo_roller_core::CreationDependencies::default(),
+    )
+    .await?;
+
+    let verification_client = github_client::create_token_client(&installation_token)?;
+    let verification_client = github_client::GitHubClient::new(verification_client);
+
+    // The metadata repository's global configuration defines standard labels
+    let labels = verification_client
+        .list_repository_labels(&config.test_org, &repo_name)
+        .await?;
+
+    assert!(
+        labels.iter().any(|l| l.eq_ignore_ascii_case("bug")),
+        "Empty repository should have the configured labels, got: {:?}",
+        labels
+    );
+
+    info!("✓ Empty repository label application test passed");
+    Ok(())
+}
+
 /// Test creating a repository with custom initialization (README only).
 ///
 /// Verifies that:
@@ -254,6 +320,7 @@ async fn test_custom_init_readme_only() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -359,6 +426,7 @@ async fn test_custom_init_gitignore_only() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -464,6 +532,7 @@ async fn test_custom_init_both_files() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -566,6 +635,7 @@ async fn test_custom_init_with_template_settings() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -71,6 +71,7 @@ async fn test_metadata_repository_not_found() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -160,6 +161,7 @@ async fn test_template_repository_not_found() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -242,6 +244,7 @@ async fn test_malformed_template_toml() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
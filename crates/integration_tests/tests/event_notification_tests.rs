@@ -82,6 +82,7 @@ async fn test_repository_creation_succeeds_with_event_notifications_context() ->
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -142,6 +143,7 @@ async fn test_repository_creation_succeeds_with_noop_event_metrics() -> Result<(
         providers.visibility_policy_provider,
         providers.environment_detector,
         EventNotificationContext::new("integration-test-noop", secret_resolver, metrics),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -197,6 +199,7 @@ async fn test_notification_failure_does_not_block_repository_creation() -> Resul
         providers.visibility_policy_provider,
         providers.environment_detector,
         EventNotificationContext::new("integration-test-no-block", secret_resolver, metrics),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -251,6 +254,7 @@ async fn test_created_by_identifier_is_accepted() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
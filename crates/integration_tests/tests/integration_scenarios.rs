@@ -68,6 +68,7 @@ async fn test_basic_repository_creation() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -147,6 +148,7 @@ async fn test_variable_substitution() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -238,6 +240,7 @@ async fn test_file_filtering() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -113,6 +113,7 @@ async fn test_labels_created_from_global_config() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -64,6 +64,7 @@ async fn test_missing_metadata_repository_fallback() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -158,6 +159,7 @@ async fn test_malformed_global_toml_error() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -242,6 +244,7 @@ async fn test_missing_global_toml_file() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -319,6 +322,7 @@ async fn test_conflicting_team_configuration() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -399,6 +403,7 @@ async fn test_nonexistent_repository_type() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -483,6 +488,7 @@ async fn test_malformed_team_toml() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -561,6 +567,7 @@ async fn test_missing_team_file() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -644,6 +651,7 @@ async fn test_inconsistent_metadata_structure() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -720,6 +728,7 @@ async fn test_duplicate_label_definitions() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -28,6 +28,9 @@ fn minimal_template(name: &str) -> TemplateConfig {
             description: "Integration test template".to_string(),
             author: "test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -46,6 +49,10 @@ fn minimal_template(name: &str) -> TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
@@ -105,6 +112,7 @@ async fn test_global_prefix_rule_is_enforced() -> Result<()> {
             required_prefix: Some("acme-".to_string()),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
     let template = minimal_template("t");
@@ -137,6 +145,7 @@ async fn test_global_max_length_rule_is_enforced() -> Result<()> {
             max_length: Some(20),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
     let template = minimal_template("t");
@@ -173,6 +182,8 @@ async fn test_template_reserved_word_rule_is_enforced() -> Result<()> {
             reserved_words: vec!["demo".to_string()],
             ..Default::default()
         }]),
+        actions_variables: None,
+        actions_secrets: None,
         ..minimal_template("t")
     };
 
@@ -210,6 +221,8 @@ async fn test_template_forbidden_pattern_rule_is_enforced() -> Result<()> {
             forbidden_patterns: vec![".*--.*".to_string()],
             ..Default::default()
         }]),
+        actions_variables: None,
+        actions_secrets: None,
         ..minimal_template("t")
     };
 
@@ -246,6 +259,7 @@ async fn test_global_and_template_rules_are_both_enforced() -> Result<()> {
             required_prefix: Some("acme-".to_string()),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
 
@@ -255,6 +269,8 @@ async fn test_global_and_template_rules_are_both_enforced() -> Result<()> {
             required_suffix: Some("-svc".to_string()),
             ..Default::default()
         }]),
+        actions_variables: None,
+        actions_secrets: None,
         ..minimal_template("t")
     };
 
@@ -287,6 +303,7 @@ async fn test_all_config_levels_contribute_naming_rules() -> Result<()> {
             min_length: Some(5),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
 
@@ -314,6 +331,8 @@ async fn test_all_config_levels_contribute_naming_rules() -> Result<()> {
             required_suffix: Some("-v1".to_string()),
             ..Default::default()
         }]),
+        actions_variables: None,
+        actions_secrets: None,
         ..minimal_template("t")
     };
 
@@ -379,6 +398,7 @@ async fn test_allowed_pattern_from_config_hierarchy() -> Result<()> {
             allowed_pattern: Some(r"[a-z][a-z0-9-]*".to_string()),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
     let template = minimal_template("t");
@@ -416,6 +436,7 @@ async fn test_first_violated_rule_is_reported() -> Result<()> {
             min_length: Some(5),
             ..Default::default()
         }]),
+        allow_template_post_generation: None,
         ..Default::default()
     };
 
@@ -425,6 +446,8 @@ async fn test_first_violated_rule_is_reported() -> Result<()> {
             required_prefix: Some("acme-".to_string()),
             ..Default::default()
         }]),
+        actions_variables: None,
+        actions_secrets: None,
         ..minimal_template("t")
     };
 
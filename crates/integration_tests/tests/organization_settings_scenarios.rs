@@ -81,6 +81,7 @@ async fn test_organization_settings_with_global_defaults() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -182,6 +183,7 @@ async fn test_team_configuration_overrides() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -280,6 +282,7 @@ async fn test_repository_type_configuration() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -380,6 +383,7 @@ async fn test_configuration_hierarchy_merging() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -500,6 +504,7 @@ async fn test_complete_organization_settings_workflow() -> Result<()> {
                 event_providers.secret_resolver.clone(),
                 event_providers.metrics.clone(),
             ),
+            repo_roller_core::CreationDependencies::default(),
         )
         .await;
 
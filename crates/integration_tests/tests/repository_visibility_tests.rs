@@ -108,6 +108,7 @@ async fn test_create_private_repository_explicit() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -181,6 +182,7 @@ async fn test_create_public_repository_explicit() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -254,6 +256,7 @@ async fn test_create_repository_default_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -346,6 +349,7 @@ async fn test_concurrent_visibility_resolution() -> Result<()> {
                 event_providers.secret_resolver.clone(),
                 event_providers.metrics.clone(),
             ),
+            repo_roller_core::CreationDependencies::default(),
         ),
         create_repository(
             request_2,
@@ -359,6 +363,7 @@ async fn test_concurrent_visibility_resolution() -> Result<()> {
                 event_providers.secret_resolver.clone(),
                 event_providers.metrics.clone(),
             ),
+            repo_roller_core::CreationDependencies::default(),
         ),
         create_repository(
             request_3,
@@ -372,6 +377,7 @@ async fn test_concurrent_visibility_resolution() -> Result<()> {
                 event_providers.secret_resolver.clone(),
                 event_providers.metrics.clone(),
             ),
+            repo_roller_core::CreationDependencies::default(),
         ),
     )?;
 
@@ -240,6 +240,7 @@ async fn test_template_variable_injection_protection() -> Result<()> {
             built_in_variables: HashMap::new(),
             variable_configs: HashMap::new(),
             templating_config: None,
+            strict_variables: false,
         };
 
         let result = processor.process_template(&files, &request, Path::new("."));
@@ -294,6 +295,7 @@ async fn test_template_variable_injection_protection() -> Result<()> {
             built_in_variables: HashMap::new(),
             variable_configs: HashMap::new(),
             templating_config: None,
+            strict_variables: false,
         };
 
         let result = processor.process_template(&files, &request, Path::new("."));
@@ -355,6 +357,7 @@ async fn test_template_variable_injection_protection() -> Result<()> {
             built_in_variables: HashMap::new(),
             variable_configs: HashMap::new(),
             templating_config: None,
+            strict_variables: false,
         };
 
         let result = processor.process_template(&files, &request, Path::new("."));
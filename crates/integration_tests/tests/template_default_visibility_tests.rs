@@ -106,6 +106,7 @@ async fn test_template_default_private_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -194,6 +195,7 @@ async fn test_template_default_public_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -268,6 +270,7 @@ async fn test_user_preference_overrides_template_default() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -340,6 +343,7 @@ async fn test_no_template_default_uses_system_default() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
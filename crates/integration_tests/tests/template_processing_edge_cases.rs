@@ -74,6 +74,7 @@ async fn test_large_file_processing() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -162,6 +163,7 @@ async fn test_binary_file_preservation() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -245,6 +247,7 @@ async fn test_deep_directory_nesting() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -333,6 +336,7 @@ async fn test_many_files_template() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     let elapsed = start_time.elapsed();
@@ -420,6 +424,7 @@ async fn test_unicode_filenames() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -495,6 +500,7 @@ async fn test_symlink_handling() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -570,6 +576,7 @@ async fn test_executable_permissions_preserved() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -644,6 +651,7 @@ async fn test_hidden_files_processing() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -719,6 +727,7 @@ async fn test_empty_directory_handling() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -793,6 +802,7 @@ async fn test_files_without_extensions() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
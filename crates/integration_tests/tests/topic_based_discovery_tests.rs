@@ -268,6 +268,7 @@ async fn test_end_to_end_creation_with_topic_discovery() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
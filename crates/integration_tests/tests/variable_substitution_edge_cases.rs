@@ -98,6 +98,7 @@ async fn test_nested_variable_substitution() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(result.is_ok(), "Repository creation should succeed");
@@ -181,6 +182,7 @@ async fn test_missing_required_variable_error() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -272,6 +274,7 @@ async fn test_very_long_variable_values() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     let elapsed = start_time.elapsed();
@@ -376,6 +379,7 @@ async fn test_handlebars_syntax_in_variables() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(
@@ -482,6 +486,7 @@ async fn test_special_characters_in_variables() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(
@@ -569,6 +574,7 @@ async fn test_variable_substitution_in_filenames() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
     assert!(
@@ -109,6 +109,7 @@ async fn test_required_policy_enforces_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -168,6 +169,7 @@ async fn test_required_policy_allows_compliant_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -242,6 +244,7 @@ async fn test_restricted_policy_blocks_prohibited_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await;
 
@@ -301,6 +304,7 @@ async fn test_restricted_policy_allows_permitted_visibility() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -375,6 +379,7 @@ async fn test_unrestricted_policy_allows_all_visibilities() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -401,6 +406,7 @@ async fn test_unrestricted_policy_allows_all_visibilities() -> Result<()> {
             event_providers.secret_resolver.clone(),
             event_providers.metrics.clone(),
         ),
+        repo_roller_core::CreationDependencies::default(),
     )
     .await?;
 
@@ -11,6 +11,7 @@
 //! HTTP boundary and never exposes internal implementation details.
 
 use axum::{
+    extract::rejection::JsonRejection,
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
@@ -81,6 +82,58 @@ impl ApiError {
     pub fn internal(message: impl Into<String>) -> Self {
         ApiError(anyhow::anyhow!("internal error: {}", message.into()))
     }
+
+    /// Create an error for an organization the GitHub App is not installed on
+    ///
+    /// Results in a 403 Forbidden telling the caller to install the app. When
+    /// the app slug is known, the response includes the app's install URL.
+    pub fn app_not_installed(org: impl Into<String>, app_slug: Option<&str>) -> Self {
+        ApiError(anyhow::Error::new(AppNotInstalled {
+            org: org.into(),
+            install_url: app_slug.map(app_install_url),
+        }))
+    }
+}
+
+/// The GitHub App is not installed on the organization a request targets.
+#[derive(Debug, thiserror::Error)]
+#[error("GitHub App not installed on organization '{org}'")]
+struct AppNotInstalled {
+    org: String,
+    install_url: Option<String>,
+}
+
+/// Build the URL an organization owner visits to install a GitHub App.
+fn app_install_url(app_slug: &str) -> String {
+    format!("https://github.com/apps/{}/installations/new", app_slug)
+}
+
+/// A JSON request body that could not be deserialized into its request model.
+///
+/// Produced from axum's [`JsonRejection`]; request models use
+/// `deny_unknown_fields`, so this includes bodies with unexpected fields.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid request body: {reason}")]
+struct InvalidRequestBody {
+    status: StatusCode,
+    reason: String,
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        // Malformed and mistyped bodies are client errors (400); axum reports
+        // deserialization failures as 422 by default.
+        let status = match rejection {
+            JsonRejection::JsonDataError(_) | JsonRejection::JsonSyntaxError(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            _ => rejection.status(),
+        };
+        ApiError(anyhow::Error::new(InvalidRequestBody {
+            status,
+            reason: rejection.body_text(),
+        }))
+    }
 }
 
 impl From<RepoRollerError> for ApiError {
@@ -108,6 +161,10 @@ impl IntoResponse for ApiError {
         let (status, error_response) =
             if let Some(repo_error) = self.0.downcast_ref::<RepoRollerError>() {
                 convert_reporoller_error(repo_error)
+            } else if let Some(body_error) = self.0.downcast_ref::<InvalidRequestBody>() {
+                convert_invalid_request_body(body_error)
+            } else if let Some(not_installed) = self.0.downcast_ref::<AppNotInstalled>() {
+                convert_app_not_installed(&not_installed.org, not_installed.install_url.as_deref())
             } else {
                 // Fallback for errors that are not wrapped in RepoRollerError.
                 // This handles anyhow errors injected directly via `ApiError::from(anyhow::Error)`
@@ -165,6 +222,69 @@ fn convert_error(error: &anyhow::Error) -> (StatusCode, ErrorResponse) {
     )
 }
 
+/// Convert a rejected request body to an HTTP error response.
+///
+/// When the body contains a field the request model does not define, the
+/// field is named in `details.field`.
+fn convert_invalid_request_body(error: &InvalidRequestBody) -> (StatusCode, ErrorResponse) {
+    let details = unknown_field_name(&error.reason).map(|field| json!({ "field": field }));
+
+    (
+        error.status,
+        ErrorResponse {
+            error: ErrorDetails {
+                code: "ValidationError".to_string(),
+                message: error.to_string(),
+                details,
+            },
+        },
+    )
+}
+
+/// Convert a missing GitHub App installation to a 403 that tells the caller
+/// how to resolve it.
+///
+/// The organization, and the install URL when known, are included in
+/// `details`.
+fn convert_app_not_installed(org: &str, install_url: Option<&str>) -> (StatusCode, ErrorResponse) {
+    let (message, details) = match install_url {
+        Some(url) => (
+            format!(
+                "The RepoRoller GitHub App is not installed on organization '{}'. \
+                 An organization owner must install it from {} before repositories can be created there.",
+                org, url
+            ),
+            json!({ "organization": org, "installUrl": url }),
+        ),
+        None => (
+            format!(
+                "The RepoRoller GitHub App is not installed on organization '{}'. \
+                 An organization owner must install it before repositories can be created there.",
+                org
+            ),
+            json!({ "organization": org }),
+        ),
+    };
+
+    (
+        StatusCode::FORBIDDEN,
+        ErrorResponse {
+            error: ErrorDetails {
+                code: "GitHubAppNotInstalled".to_string(),
+                message,
+                details: Some(details),
+            },
+        },
+    )
+}
+
+/// Extract the field name from serde's "unknown field `name`" message.
+fn unknown_field_name(reason: &str) -> Option<&str> {
+    let start = reason.find("unknown field `")? + "unknown field `".len();
+    let len = reason[start..].find('`')?;
+    Some(&reason[start..start + len])
+}
+
 /// Log error with appropriate level based on HTTP status
 fn log_error(error: &anyhow::Error, status: StatusCode) {
     match status {
@@ -257,10 +377,22 @@ pub fn convert_authentication_error(error: AuthenticationError) -> (StatusCode,
     )
 }
 
+/// Get the HTTP status code a domain error is reported with.
+///
+/// Uses the same mapping as the responses produced from [`ApiError`], so
+/// callers that need the status ahead of building a response stay consistent
+/// with it.
+pub fn status_code_for(error: &RepoRollerError) -> StatusCode {
+    convert_reporoller_error(error).0
+}
+
 /// Convert RepoRollerError to HTTP status code and error response.
 ///
-/// Delegates to specific error type converters based on the error variant.
-fn convert_reporoller_error(error: &RepoRollerError) -> (StatusCode, ErrorResponse) {
+/// This is the single mapping from domain errors to HTTP responses; it
+/// delegates to specific error type converters based on the error variant.
+/// Client errors (validation, missing resources, policy) map to 4xx, GitHub
+/// failures to 502, and system failures to 500.
+pub fn convert_reporoller_error(error: &RepoRollerError) -> (StatusCode, ErrorResponse) {
     match error {
         RepoRollerError::Authentication(e) => convert_authentication_error(e.clone()),
         RepoRollerError::Validation(e) => convert_validation_error(e),
@@ -284,7 +416,8 @@ fn convert_reporoller_error(error: &RepoRollerError) -> (StatusCode, ErrorRespon
 
 /// Convert ValidationError to HTTP error response.
 ///
-/// Validation errors result in 400 Bad Request with details about what failed validation.
+/// Validation errors result in 400 Bad Request with details about what failed validation,
+/// except a taken repository name, which is reported as 409 Conflict.
 fn convert_validation_error(
     error: &repo_roller_core::ValidationError,
 ) -> (StatusCode, ErrorResponse) {
@@ -317,6 +450,21 @@ fn convert_validation_error(
             format!("Field '{}' has invalid format: {}", field, reason),
             Some(json!({ "field": field, "reason": reason })),
         ),
+        ValidationError::InvalidName {
+            field,
+            reason,
+            suggestion,
+        } => (
+            "ValidationError",
+            match suggestion {
+                Some(suggestion) => format!(
+                    "Field '{}' has invalid format: {}. Did you mean '{}'?",
+                    field, reason, suggestion
+                ),
+                None => format!("Field '{}' has invalid format: {}", field, reason),
+            },
+            Some(json!({ "field": field, "reason": reason, "suggestion": suggestion })),
+        ),
         ValidationError::InvalidRepositoryName { reason } => (
             "ValidationError",
             format!("Invalid repository name: {}", reason),
@@ -374,10 +522,32 @@ fn convert_validation_error(
             ),
             Some(json!({ "field": field, "options": options, "value": value })),
         ),
+        ValidationError::NameTaken { org, name } => (
+            "RepositoryNameTaken",
+            format!(
+                "Repository name '{}' is already taken in organization '{}'",
+                name, org
+            ),
+            Some(json!({ "organization": org, "name": name })),
+        ),
+        ValidationError::UnknownReferences {
+            org,
+            teams,
+            app_ids,
+        } => (
+            "UnknownReferences",
+            error.to_string(),
+            Some(json!({ "organization": org, "teams": teams, "appIds": app_ids })),
+        ),
+    };
+
+    let status = match error {
+        ValidationError::NameTaken { .. } => StatusCode::CONFLICT,
+        _ => StatusCode::BAD_REQUEST,
     };
 
     (
-        StatusCode::BAD_REQUEST,
+        status,
         ErrorResponse {
             error: ErrorDetails {
                 code: code.to_string(),
@@ -549,6 +719,26 @@ fn convert_configuration_error(
                 identifier, context
             ),
         ),
+        ConfigurationError::TeamConfigurationConflict { errors } => (
+            StatusCode::BAD_REQUEST,
+            "TeamConfigurationConflict",
+            format!(
+                "Owning teams have conflicting configuration: {}",
+                errors
+                    .iter()
+                    .map(|e| e.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        ),
+        ConfigurationError::TemplateNotAllowed { org, template } => (
+            StatusCode::FORBIDDEN,
+            "TemplateNotAllowed",
+            format!(
+                "Template '{}' is not allowed in organization '{}'",
+                template, org
+            ),
+        ),
     };
 
     (
@@ -622,6 +812,12 @@ fn convert_template_error(error: &repo_roller_core::TemplateError) -> (StatusCod
             format!("Path traversal attempt detected: {}", path),
             Some(json!({ "path": path })),
         ),
+        TemplateError::BaseTemplateCycle { template, chain } => (
+            StatusCode::BAD_REQUEST,
+            "BaseTemplateCycle",
+            error.to_string(),
+            Some(json!({ "template": template, "chain": chain })),
+        ),
     };
 
     (
@@ -677,11 +873,7 @@ fn convert_github_error(error: &repo_roller_core::GitHubError) -> (StatusCode, E
             "GitHubInvalidResponse",
             format!("Invalid response from GitHub API: {}", reason),
         ),
-        GitHubError::AppNotInstalled { org } => (
-            StatusCode::FORBIDDEN,
-            "GitHubAppNotInstalled",
-            format!("GitHub App not installed on organization '{}'", org),
-        ),
+        GitHubError::AppNotInstalled { org } => return convert_app_not_installed(org, None),
     };
 
     (
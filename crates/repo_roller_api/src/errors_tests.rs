@@ -202,6 +202,18 @@ fn test_validation_error_invalid_repository_name_returns_400() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+#[test]
+fn test_validation_error_name_taken_returns_409() {
+    use repo_roller_core::ValidationError;
+    let error = RepoRollerError::Validation(ValidationError::NameTaken {
+        org: "my-org".to_string(),
+        name: "my-repo".to_string(),
+    });
+    let api_error = ApiError::from(error);
+    let response = api_error.into_response();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
 #[test]
 fn test_repository_error_already_exists_returns_409() {
     use repo_roller_core::RepositoryError;
@@ -328,3 +340,105 @@ fn test_system_error_filesystem_returns_500() {
     let response = api_error.into_response();
     assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
 }
+
+/// Test that the unexpected field is extracted from serde's error message
+#[test]
+fn test_unknown_field_name_extracted_from_reason() {
+    let reason = "Failed to deserialize the JSON body into the target type: \
+                  unknown field `templat`, expected one of `name`, `organization` at line 1 column 42";
+    assert_eq!(unknown_field_name(reason), Some("templat"));
+    assert_eq!(unknown_field_name("missing field `name`"), None);
+}
+
+/// Each error category maps to its documented status, and `ApiError`
+/// responses use the same mapping.
+#[test]
+fn test_status_code_for_each_error_category() {
+    use repo_roller_core::{
+        AccessLevel, GitHubError, PermissionError, PermissionType, RepositoryError, SystemError,
+        TemplateError, ValidationError,
+    };
+
+    let cases: Vec<(RepoRollerError, StatusCode)> = vec![
+        (
+            ValidationError::empty_field("name").into(),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            ValidationError::NameTaken {
+                org: "org".to_string(),
+                name: "repo".to_string(),
+            }
+            .into(),
+            StatusCode::CONFLICT,
+        ),
+        (
+            AuthenticationError::InvalidToken.into(),
+            StatusCode::UNAUTHORIZED,
+        ),
+        (
+            AuthenticationError::InsufficientPermissions {
+                operation: "create".to_string(),
+                required: "admin".to_string(),
+            }
+            .into(),
+            StatusCode::FORBIDDEN,
+        ),
+        (
+            RepositoryError::NotFound {
+                org: "org".to_string(),
+                name: "repo".to_string(),
+            }
+            .into(),
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            config_manager::ConfigurationError::InvalidConfiguration {
+                field: "field".to_string(),
+                reason: "bad".to_string(),
+            }
+            .into(),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            TemplateError::TemplateNotFound {
+                name: "missing".to_string(),
+            }
+            .into(),
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            GitHubError::NetworkError {
+                reason: "connection reset".to_string(),
+            }
+            .into(),
+            StatusCode::BAD_GATEWAY,
+        ),
+        (
+            SystemError::Internal {
+                reason: "unexpected".to_string(),
+            }
+            .into(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ),
+        (
+            PermissionError::ExceedsOrganizationLimits {
+                permission_type: PermissionType::Push,
+                level: AccessLevel::Admin,
+                maximum_allowed: AccessLevel::Write,
+            }
+            .into(),
+            StatusCode::FORBIDDEN,
+        ),
+    ];
+
+    for (error, expected) in cases {
+        let description = error.to_string();
+        assert_eq!(status_code_for(&error), expected, "{description}");
+        assert_eq!(
+            ApiError::from(error).into_response().status(),
+            expected,
+            "{description}"
+        );
+    }
+}
@@ -17,7 +17,7 @@
 
 use async_trait::async_trait;
 use axum::{
-    extract::{Path, State},
+    extract::{FromRequest, Path, State},
     Extension, Json,
 };
 use serde::{Deserialize, Serialize};
@@ -35,8 +35,18 @@ use config_manager::{
     ConfigurationContext, GitHubMetadataProvider, MetadataProviderConfig,
     MetadataRepositoryProvider, OrganizationSettingsManager,
 };
-use github_client::GitHubClient;
-use repo_roller_core::{RepoRollerError, RepositoryNamingValidator};
+use github_client::{GitHubClient, RepositoryClient};
+use repo_roller_core::{RepoRollerError, RepositoryNamingValidator, TemplateError};
+
+/// JSON body extractor that rejects malformed bodies with an [`ApiError`].
+///
+/// Unlike [`Json`], a body that fails to deserialize (including one with an
+/// unknown field) produces a `400` with a standard
+/// [`ErrorResponse`](crate::errors::ErrorResponse) rather than a plain-text
+/// `422`.
+#[derive(FromRequest)]
+#[from_request(via(Json), rejection(ApiError))]
+pub struct ApiJson<T>(pub T);
 
 /// Thin `UserAuthenticationService` adapter that returns a pre-minted token.
 ///
@@ -137,7 +147,7 @@ fn is_valid_repository_name(name: &str) -> bool {
 pub async fn create_repository(
     State(state): State<AppState>,
     Extension(auth): Extension<AuthContext>,
-    Json(request): Json<CreateRepositoryRequest>,
+    ApiJson(request): ApiJson<CreateRepositoryRequest>,
 ) -> Result<(axum::http::StatusCode, Json<CreateRepositoryResponse>), ApiError> {
     use crate::translation::{
         domain_repository_creation_result_to_http, http_create_repository_request_to_domain,
@@ -174,12 +184,23 @@ pub async fn create_repository(
     // sharing here — github_client and environment_detector each hold their own
     // independent Octocrab instance (with separate connection pools), the same
     // as they did before this refactor.
-    let github_client = github_client::GitHubClient::new(github_octocrab.as_ref().clone());
+    let mut github_client = github_client::GitHubClient::new(github_octocrab.as_ref().clone());
+    // The client is shared by the whole creation, so let it obtain a new
+    // installation token if GitHub rejects the one minted above.
+    use auth_handler::UserAuthenticationService as _;
+    if let Some(refresher) = state
+        .auth_service
+        .installation_token_refresher(&request.organization, state.github_api_base_url.as_deref())
+        .await
+    {
+        github_client = github_client.with_token_refresher(refresher);
+    }
 
     // Create metadata provider for template discovery and loading
+    let metadata_provider_config = state.metadata_provider_config();
     let metadata_provider = std::sync::Arc::new(config_manager::GitHubMetadataProvider::new(
-        github_client,
-        config_manager::MetadataProviderConfig::explicit(&state.metadata_repository_name),
+        github_client.clone(),
+        metadata_provider_config.clone(),
     ));
 
     // Authentication service wraps the already-minted token so the domain
@@ -204,6 +225,16 @@ pub async fn create_repository(
     let metrics = state.event_metrics.clone();
     let event_context =
         repo_roller_core::EventNotificationContext::new(&actor_login, secret_resolver, metrics);
+    // The creation reuses the handler's GitHub client (clones of a
+    // GitHubClient share one Octocrab instance) and reads its configuration
+    // from the same GitHub API and metadata git ref as the rest of the
+    // handler.
+    let dependencies = repo_roller_core::CreationDependencies {
+        github_api_base_url: state.github_api_base_url.clone(),
+        repository_client: Some(std::sync::Arc::new(github_client)),
+        metadata_provider_config: Some(metadata_provider_config),
+        ..Default::default()
+    };
 
     // Call domain service to create repository
     let result = repo_roller_core::create_repository(
@@ -214,6 +245,7 @@ pub async fn create_repository(
         visibility_policy_provider,
         environment_detector,
         event_context,
+        dependencies,
     )
     .await?; // ApiError::from(RepoRollerError) converts automatically
 
@@ -280,6 +312,47 @@ pub(crate) async fn check_org_naming_rules(
     }
 }
 
+/// Remove templates the organisation does not permit from `template_names`.
+///
+/// Discovers the metadata repository for `org`, loads its global defaults and
+/// keeps only the templates allowed by `allowed_templates`/`denied_templates`.
+/// Failures to reach the metadata repository or load defaults leave the list
+/// unfiltered, matching the fallback used when creating repositories.
+pub(crate) async fn filter_allowed_templates(
+    template_names: Vec<String>,
+    org: &str,
+    provider: &dyn MetadataRepositoryProvider,
+) -> Vec<String> {
+    let metadata_repo = match provider.discover_metadata_repository(org).await {
+        Ok(repo) => repo,
+        Err(e) => {
+            tracing::warn!(
+                org = org,
+                error = %e,
+                "Failed to discover metadata repository; skipping template policy"
+            );
+            return template_names;
+        }
+    };
+
+    let global_defaults = match provider.load_global_defaults(&metadata_repo).await {
+        Ok(defaults) => defaults,
+        Err(e) => {
+            tracing::warn!(
+                org = org,
+                error = %e,
+                "Failed to load global defaults; skipping template policy"
+            );
+            return template_names;
+        }
+    };
+
+    template_names
+        .into_iter()
+        .filter(|name| global_defaults.is_template_allowed(name))
+        .collect()
+}
+
 /// Check whether a repository name is available in the given organisation by
 /// querying the GitHub API.
 ///
@@ -306,14 +379,14 @@ pub(crate) async fn check_repository_availability(
     org: &str,
     name: &str,
 ) -> (bool, Option<String>) {
-    match client.get_repository(org, name).await {
-        Ok(_) => (
+    match client.repository_exists(org, name).await {
+        Ok(true) => (
             false,
             Some(format!(
                 "Repository '{name}' already exists in organisation '{org}'."
             )),
         ),
-        Err(github_client::Error::NotFound) => (true, None),
+        Ok(false) => (true, None),
         Err(e) => {
             tracing::warn!(
                 org = org,
@@ -357,7 +430,7 @@ pub(crate) async fn check_repository_availability(
 pub async fn validate_repository_name(
     State(state): State<AppState>,
     Extension(_auth): Extension<AuthContext>,
-    Json(request): Json<ValidateRepositoryNameRequest>,
+    ApiJson(request): ApiJson<ValidateRepositoryNameRequest>,
 ) -> Result<Json<ValidateRepositoryNameResponse>, ApiError> {
     let mut messages = Vec::new();
     let mut valid = true;
@@ -485,15 +558,21 @@ pub async fn validate_repository_name(
 
 /// POST /api/v1/repositories/validate
 ///
-/// Validate a complete repository creation request.
+/// Validate a complete repository creation request without creating anything.
+///
+/// Once the request is structurally valid and names a template, the merged
+/// configuration is resolved, the variables are validated and the template is
+/// dry-rendered. Every problem found is returned in the response rather than
+/// as an HTTP error, together with the paths of the files the template
+/// renders.
 ///
 /// See: specs/interfaces/api-request-types.md#validaterepositoryrequestrequest
 pub async fn validate_repository_request(
-    State(_state): State<AppState>,
-    Json(request): Json<ValidateRepositoryRequestRequest>,
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    ApiJson(request): ApiJson<ValidateRepositoryRequestRequest>,
 ) -> Result<Json<ValidateRepositoryRequestResponse>, ApiError> {
     let mut errors = Vec::new();
-    let warnings = Vec::new();
 
     // Validate repository name
     if request.name.is_empty() {
@@ -527,10 +606,6 @@ pub async fn validate_repository_request(
                 message: "Template name cannot be empty".to_string(),
                 severity: ValidationSeverity::Error,
             });
-        } else {
-            // Note: This endpoint performs structural format validation only.
-            // Template existence is validated during actual repository creation via
-            // OrganizationSettingsManager, which has GitHub API access.
         }
     }
 
@@ -545,22 +620,270 @@ pub async fn validate_repository_request(
         }
     }
 
-    // Note: Variable content validation (required fields, patterns) requires the
-    // template configuration to be loaded from GitHub, so it is deferred to the
-    // creation step via the template engine.
-
-    // Note: Team existence validation requires GitHub API calls and is deferred
-    // to the creation step.
-
-    let response = ValidateRepositoryRequestResponse {
-        valid: errors.is_empty(),
-        warnings,
+    let mut response = ValidateRepositoryRequestResponse {
+        valid: false,
         errors,
+        warnings: vec![],
+        rendered_paths: vec![],
     };
 
+    // Configuration and template checks need a structurally valid request
+    if response.errors.is_empty() && request.template.is_some() {
+        let actor_login = auth
+            .user_login
+            .as_deref()
+            .unwrap_or("reporoller-api")
+            .to_string();
+        dry_validate_repository_request(&state, request, actor_login, &mut response).await?;
+    }
+
+    response.valid = response.errors.is_empty();
     Ok(Json(response))
 }
 
+/// Resolves the configuration for a request, validates its variables and
+/// dry-renders its template, recording every problem in `response`.
+///
+/// A configuration that fails to resolve is reported and the remaining checks
+/// run against the default configuration, so that configuration and variable
+/// problems are reported together. Nothing is created on GitHub.
+async fn dry_validate_repository_request(
+    state: &AppState,
+    request: ValidateRepositoryRequestRequest,
+    actor_login: String,
+    response: &mut ValidateRepositoryRequestResponse,
+) -> Result<(), ApiError> {
+    let org = request.organization.clone();
+    let Some(template_name) = request.template.clone() else {
+        return Ok(());
+    };
+
+    let (manager, provider) = create_settings_manager(&org, state).await?;
+
+    let template = match provider
+        .load_template_configuration(&org, &template_name)
+        .await
+    {
+        Ok(template) => template,
+        Err(e) => {
+            tracing::warn!(
+                "Template validation failed for '{}/{}': {:?}",
+                org,
+                template_name,
+                e
+            );
+            response.errors.push(ValidationResult {
+                field: "template".to_string(),
+                message: format!("Template '{}' could not be loaded: {}", template_name, e),
+                severity: ValidationSeverity::Error,
+            });
+            return Ok(());
+        }
+    };
+
+    let context = configuration_context(
+        &org,
+        &template_name,
+        request.team.as_deref(),
+        request.repository_type.as_deref(),
+    );
+    let merged = match manager.resolve_configuration(&context).await {
+        Ok(merged) => {
+            let warnings = repo_roller_core::collect_configuration_warnings(&merged).await;
+            response
+                .warnings
+                .extend(warnings.into_iter().map(|warning| ValidationResult {
+                    field: warning.field_path,
+                    message: warning.message,
+                    severity: ValidationSeverity::Warning,
+                }));
+            merged
+        }
+        Err(e) => {
+            response.errors.extend(configuration_validation_errors(&e));
+            config_manager::MergedConfiguration::new()
+        }
+    };
+
+    let domain_request =
+        crate::translation::http_create_repository_request_to_domain(request, actor_login)?;
+    response.warnings.extend(
+        repo_roller_core::collect_template_warnings(&template, &domain_request.variables)
+            .into_iter()
+            .map(|warning| ValidationResult {
+                field: warning.field_path,
+                message: warning.message,
+                severity: ValidationSeverity::Warning,
+            }),
+    );
+
+    let files = match state.fetch_template_files(&org, &template_name).await {
+        Ok(files) => files,
+        Err(e) => {
+            response.errors.push(ValidationResult {
+                field: "template".to_string(),
+                message: format!("Failed to fetch template files: {}", e),
+                severity: ValidationSeverity::Error,
+            });
+            return Ok(());
+        }
+    };
+
+    match repo_roller_core::dry_run_template(&files, &domain_request, &template, &merged) {
+        Ok(dry_run) => {
+            response
+                .errors
+                .extend(
+                    dry_run
+                        .render_failures
+                        .into_iter()
+                        .map(|failure| ValidationResult {
+                            field: "template".to_string(),
+                            message: format!(
+                                "File '{}' failed to render: {}",
+                                failure.path, failure.reason
+                            ),
+                            severity: ValidationSeverity::Error,
+                        }),
+                );
+            response.rendered_paths = dry_run.rendered_paths;
+            response.rendered_paths.sort();
+        }
+        Err(RepoRollerError::Template(TemplateError::RequiredVariableMissing { variable })) => {
+            response.errors.push(ValidationResult {
+                field: format!("variables.{}", variable),
+                message: format!("Required template variable '{}' is missing", variable),
+                severity: ValidationSeverity::Error,
+            });
+        }
+        Err(RepoRollerError::Template(TemplateError::SubstitutionFailed { variable, reason })) => {
+            response.errors.push(ValidationResult {
+                field: format!("variables.{}", variable),
+                message: reason,
+                severity: ValidationSeverity::Error,
+            });
+        }
+        Err(e) => return Err(ApiError::from(e)),
+    }
+
+    Ok(())
+}
+
+/// POST /api/v1/repositories/preview-content
+///
+/// Render the files of a repository's initial commit without creating it.
+///
+/// The merged configuration is resolved and the template is rendered exactly
+/// as for repository creation, but nothing is written to GitHub. Text files
+/// are returned with their rendered content; binary files only with their
+/// size and SHA-256 digest.
+pub async fn preview_repository_content(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    ApiJson(request): ApiJson<PreviewContentRequest>,
+) -> Result<Json<PreviewContentResponse>, ApiError> {
+    let org = request.organization.clone();
+    let template_name = request
+        .template
+        .clone()
+        .filter(|template| !template.is_empty())
+        .ok_or_else(|| {
+            ApiError::validation_error("template", "A template is required to preview content")
+        })?;
+
+    let (manager, provider) = create_settings_manager(&org, &state).await?;
+    let template = provider
+        .load_template_configuration(&org, &template_name)
+        .await
+        .map_err(|e| ApiError::from(RepoRollerError::Configuration(e)))?;
+
+    let context = configuration_context(
+        &org,
+        &template_name,
+        request.team.as_deref(),
+        request.repository_type.as_deref(),
+    );
+    let merged = manager
+        .resolve_configuration(&context)
+        .await
+        .map_err(|e| ApiError::from(RepoRollerError::Configuration(e)))?;
+
+    let actor_login = auth
+        .user_login
+        .as_deref()
+        .unwrap_or("reporoller-api")
+        .to_string();
+    let domain_request =
+        crate::translation::http_create_repository_request_to_domain(request, actor_login)?;
+
+    let files = state
+        .fetch_template_files(&org, &template_name)
+        .await
+        .map_err(|e| {
+            ApiError::from(RepoRollerError::Template(TemplateError::FetchFailed {
+                reason: format!("Failed to fetch template files: {}", e),
+            }))
+        })?;
+
+    let preview =
+        repo_roller_core::preview_template_content(&files, &domain_request, &template, &merged)?;
+
+    Ok(Json(content_preview_response(preview)))
+}
+
+/// Converts rendered file previews into the API response format.
+fn content_preview_response(
+    preview: Vec<repo_roller_core::RenderedFilePreview>,
+) -> PreviewContentResponse {
+    let files = preview
+        .into_iter()
+        .map(|file| RenderedFileInfo {
+            path: file.path,
+            size: file.size,
+            kind: match file.kind {
+                template_engine::TemplateFileKind::Text => "text".to_string(),
+                template_engine::TemplateFileKind::Binary => "binary".to_string(),
+            },
+            sha256: file.sha256,
+            content: file.content,
+        })
+        .collect();
+
+    PreviewContentResponse { files }
+}
+
+/// Converts a configuration resolution error into validation results.
+///
+/// Errors that carry several problems, such as conflicting team overrides,
+/// produce one result per problem.
+fn configuration_validation_errors(
+    error: &config_manager::ConfigurationError,
+) -> Vec<ValidationResult> {
+    use config_manager::ConfigurationError;
+
+    match error {
+        ConfigurationError::OverrideNotPermitted { setting, .. } => vec![ValidationResult {
+            field: setting.clone(),
+            message: error.to_string(),
+            severity: ValidationSeverity::Error,
+        }],
+        ConfigurationError::ValidationFailed { errors, .. }
+        | ConfigurationError::TeamConfigurationConflict { errors } => errors
+            .iter()
+            .map(|e| ValidationResult {
+                field: e.field_path.clone(),
+                message: e.message.clone(),
+                severity: ValidationSeverity::Error,
+            })
+            .collect(),
+        _ => vec![ValidationResult {
+            field: "configuration".to_string(),
+            message: error.to_string(),
+            severity: ValidationSeverity::Error,
+        }],
+    }
+}
+
 /// GET /api/v1/orgs/:org/templates
 ///
 /// List available templates for an organization.
@@ -584,6 +907,10 @@ pub async fn list_templates(
         ApiError::from(anyhow::anyhow!("Failed to list templates: {}", e))
     })?;
 
+    // Hide templates the organization does not permit
+    let template_names =
+        filter_allowed_templates(template_names, &params.org, provider.as_ref()).await;
+
     // Load template configurations to get details
     let mut templates = Vec::new();
     for template_name in template_names {
@@ -663,20 +990,7 @@ pub async fn get_template_details(
         })?;
 
     // Convert template variables to API response format
-    let mut variables = std::collections::HashMap::new();
-    if let Some(template_vars) = config.variables {
-        for (name, var_config) in template_vars {
-            variables.insert(
-                name,
-                VariableDefinition {
-                    description: var_config.description,
-                    required: var_config.required.unwrap_or(false),
-                    default: var_config.default,
-                    pattern: None, // TemplateVariable doesn't have pattern field
-                },
-            );
-        }
-    }
+    let variables = to_variable_definitions(config.variables.unwrap_or_default());
 
     // Build configuration from repository settings
     let configuration = serde_json::json!({
@@ -696,6 +1010,98 @@ pub async fn get_template_details(
     Ok(Json(response))
 }
 
+/// GET /api/v1/orgs/:org/templates/:template/files
+///
+/// List a template's files and declared variables without creating a
+/// repository. Files are fetched but not rendered.
+pub async fn list_template_files(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthContext>,
+    Path(params): Path<ListTemplateFilesParams>,
+) -> Result<Json<TemplateFilesResponse>, ApiError> {
+    let (_manager, provider) = create_settings_manager(&params.org, &state).await?;
+
+    let config = provider
+        .load_template_configuration(&params.org, &params.template)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to load template configuration for '{}/{}': {:?}",
+                params.org,
+                params.template,
+                e
+            );
+            match e {
+                config_manager::ConfigurationError::FileNotFound { .. } => {
+                    ApiError::from(anyhow::anyhow!(
+                        "Template '{}' not found in organization '{}'",
+                        params.template,
+                        params.org
+                    ))
+                }
+                _ => ApiError::from(anyhow::anyhow!(
+                    "Failed to load template configuration: {}",
+                    e
+                )),
+            }
+        })?;
+
+    let fetcher = template_engine::GitHubTemplateFetcher::new();
+    let inspection = repo_roller_core::inspect_template(
+        &format!("{}/{}", params.org, params.template),
+        &config,
+        &fetcher,
+    )
+    .await?;
+
+    Ok(Json(template_files_response(params.template, inspection)))
+}
+
+/// Converts a template inspection into the API response format.
+fn template_files_response(
+    name: String,
+    inspection: repo_roller_core::TemplateInspection,
+) -> TemplateFilesResponse {
+    let files = inspection
+        .files
+        .into_iter()
+        .map(|file| TemplateFileInfo {
+            path: file.path,
+            size: file.size,
+            kind: match file.kind {
+                template_engine::TemplateFileKind::Text => "text".to_string(),
+                template_engine::TemplateFileKind::Binary => "binary".to_string(),
+            },
+        })
+        .collect();
+
+    TemplateFilesResponse {
+        name,
+        files,
+        variables: to_variable_definitions(inspection.variables),
+    }
+}
+
+/// Converts template variables to the API variable definition format.
+fn to_variable_definitions(
+    template_vars: std::collections::HashMap<String, config_manager::TemplateVariable>,
+) -> std::collections::HashMap<String, VariableDefinition> {
+    template_vars
+        .into_iter()
+        .map(|(name, var_config)| {
+            (
+                name,
+                VariableDefinition {
+                    description: var_config.description,
+                    required: var_config.required.unwrap_or(false),
+                    default: var_config.default,
+                    pattern: None, // TemplateVariable doesn't have pattern field
+                },
+            )
+        })
+        .collect()
+}
+
 /// POST /api/v1/orgs/:org/templates/:template/validate
 ///
 /// Validate a template structure.
@@ -720,6 +1126,7 @@ pub async fn validate_template(
                 valid: true,
                 errors: vec![],
                 warnings: vec![],
+                rendered_paths: vec![],
             }))
         }
         Err(e) => {
@@ -748,6 +1155,7 @@ pub async fn validate_template(
                             severity: ValidationSeverity::Error,
                         }],
                         warnings: vec![],
+                        rendered_paths: vec![],
                     }))
                 }
                 _ => Ok(Json(ValidateTemplateResponse {
@@ -758,6 +1166,7 @@ pub async fn validate_template(
                         severity: ValidationSeverity::Error,
                     }],
                     warnings: vec![],
+                    rendered_paths: vec![],
                 })),
             }
         }
@@ -891,9 +1300,9 @@ pub async fn get_global_defaults(
 /// See: specs/interfaces/api-request-types.md#previewconfigurationrequest
 pub async fn preview_configuration(
     State(state): State<AppState>,
-    Extension(_auth): Extension<AuthContext>,
+    Extension(auth): Extension<AuthContext>,
     Path(org): Path<String>,
-    Json(request): Json<PreviewConfigurationRequest>,
+    ApiJson(request): ApiJson<PreviewConfigurationRequest>,
 ) -> Result<Json<PreviewConfigurationResponse>, ApiError> {
     // Create settings manager
     let (manager, provider) = create_settings_manager(&org, &state).await?;
@@ -921,16 +1330,12 @@ pub async fn preview_configuration(
         }
     }
 
-    // Create configuration context
-    let mut context = ConfigurationContext::new(&org, &request.template);
-
-    if let Some(ref team) = request.team {
-        context = context.with_team(team);
-    }
-
-    if let Some(ref repo_type) = request.repository_type {
-        context = context.with_repository_type(repo_type);
-    }
+    let context = configuration_context(
+        &org,
+        &request.template,
+        request.team.as_deref(),
+        request.repository_type.as_deref(),
+    );
 
     // Resolve merged configuration
     let merged = manager
@@ -940,18 +1345,25 @@ pub async fn preview_configuration(
 
     // Extract source attribution before serialising. source_trace is excluded
     // from JSON serialisation via #[serde(skip)], so we read it here while
-    // the domain value is still available.
+    // the domain value is still available. Settings no layer configured are
+    // reported as GitHub defaults.
     let sources: std::collections::HashMap<String, String> = merged
         .source_trace
         .configured_fields()
         .into_iter()
+        .chain(
+            config_manager::MergedConfiguration::setting_field_paths()
+                .iter()
+                .map(String::as_str),
+        )
         .filter_map(|field| {
-            merged.source_trace.get_source(field).map(|src| {
+            merged.resolved_source(field).map(|src| {
                 let level = match src {
                     config_manager::ConfigurationSource::Global => "global",
                     config_manager::ConfigurationSource::RepositoryType => "repository_type",
                     config_manager::ConfigurationSource::Team => "team",
                     config_manager::ConfigurationSource::Template => "template",
+                    config_manager::ConfigurationSource::GitHubDefault => "github_default",
                 };
                 (field.to_string(), level.to_string())
             })
@@ -967,24 +1379,129 @@ pub async fn preview_configuration(
         ))
     })?;
 
-    // TODO: populate from a dedicated validation pass if/when soft warnings are introduced.
     // Hard validation failures propagate as HTTP errors from resolve_configuration, so this
-    // field carries only advisory information when the merge succeeds.
+    // field carries only advisory warnings when the merge succeeds. These are the same
+    // warnings a subsequent repository creation would report, apart from those about
+    // request variables, which a preview does not have.
+    let mut warnings = repo_roller_core::collect_configuration_warnings(&merged).await;
+    match provider
+        .load_template_configuration(&org, &request.template)
+        .await
+    {
+        Ok(template) => warnings.extend(repo_roller_core::collect_template_warnings(
+            &template,
+            &std::collections::HashMap::new(),
+        )),
+        Err(e) => tracing::warn!(
+            "Could not load template '{}' for template warnings: {}",
+            request.template,
+            e
+        ),
+    }
+    let warnings = warnings
+        .iter()
+        .map(crate::translation::format_validation_warning)
+        .collect();
     let validation = crate::models::response::ConfigurationPreviewValidation {
         valid: true,
-        warnings: vec![],
+        warnings,
         errors: vec![],
     };
 
+    let notifications = match request.repository_name {
+        Some(repository_name) => {
+            preview_creation_notifications(&org, &request.template, repository_name, &auth, &merged)
+                .await?
+        }
+        None => Vec::new(),
+    };
+
     let response = PreviewConfigurationResponse {
         merged: merged_json,
         sources,
         validation,
+        notifications,
     };
 
     Ok(Json(response))
 }
 
+/// Builds the context used to resolve the merged configuration for a template.
+fn configuration_context(
+    org: &str,
+    template: &str,
+    team: Option<&str>,
+    repository_type: Option<&str>,
+) -> ConfigurationContext {
+    let mut context = ConfigurationContext::new(org, template);
+
+    if let Some(team) = team {
+        context = context.with_team(team);
+    }
+
+    if let Some(repo_type) = repository_type {
+        context = context.with_repository_type(repo_type);
+    }
+
+    context
+}
+
+/// Computes the notifications that creating `repository_name` from `template`
+/// would send, without creating anything or contacting the endpoints.
+async fn preview_creation_notifications(
+    org: &str,
+    template: &str,
+    repository_name: String,
+    auth: &AuthContext,
+    merged: &config_manager::MergedConfiguration,
+) -> Result<Vec<NotificationPreviewResponse>, ApiError> {
+    let name = repo_roller_core::RepositoryName::new(repository_name).map_err(|e| {
+        ApiError::validation_error("repositoryName", format!("Invalid repository name: {}", e))
+    })?;
+    let owner = repo_roller_core::OrganizationName::new(org).map_err(|e| {
+        ApiError::validation_error("organization", format!("Invalid organization name: {}", e))
+    })?;
+    let template = repo_roller_core::TemplateName::new(template).map_err(|e| {
+        ApiError::validation_error("template", format!("Invalid template name: {}", e))
+    })?;
+    let actor_login = auth.user_login.as_deref().unwrap_or("reporoller-api");
+
+    // The would-be result has no GitHub node ID yet, so a placeholder is used.
+    let would_be_result = repo_roller_core::RepositoryCreationResult {
+        repository_url: format!("https://github.com/{}/{}", owner.as_ref(), name.as_ref()),
+        repository_id: "preview".to_string(),
+        created_at: repo_roller_core::Timestamp::now(),
+        default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
+    };
+    let would_be_request = repo_roller_core::RepositoryCreationRequestBuilder::new(name, owner)
+        .template(template)
+        .build();
+    let event = repo_roller_core::RepositoryCreatedEvent::from_result_and_request(
+        &would_be_result,
+        &would_be_request,
+        merged,
+        actor_login,
+    );
+
+    let secret_resolver = repo_roller_core::event_secrets::EnvironmentSecretResolver::new();
+    let previews =
+        repo_roller_core::preview_repository_created(&event, merged, &secret_resolver).await;
+
+    Ok(previews
+        .into_iter()
+        .map(|preview| NotificationPreviewResponse {
+            endpoint_url: preview.endpoint_url,
+            payload: preview.payload,
+            signature: preview.signature,
+            error: preview.error_message,
+        })
+        .collect())
+}
+
 /// POST /api/v1/orgs/:org/validate
 ///
 /// Validate organization settings and configuration.
@@ -1015,6 +1532,7 @@ pub async fn validate_organization(
                 valid: false,
                 errors,
                 warnings,
+                rendered_paths: vec![],
             }));
         }
     };
@@ -1063,6 +1581,7 @@ pub async fn validate_organization(
         valid,
         errors,
         warnings,
+        rendered_paths: vec![],
     }))
 }
 
@@ -202,129 +202,295 @@ async fn test_validate_repository_name_empty() {
     assert!(!response_json["messages"].as_array().unwrap().is_empty());
 }
 
-/// Test validate_repository_request endpoint with valid complete request
-///
-/// Verifies that a valid complete repository creation request returns
-/// 200 OK with valid=true and no errors.
-#[tokio::test]
-async fn test_validate_repository_request_valid() {
-    let app = create_router_without_auth(test_app_state());
-
-    let request_body = json!({
-        "organization": "testorg",
-        "name": "test-repo",
-        "template": "rust-library",
-        "visibility": "private",
-        "team": "platform",
-        "repositoryType": "library",
-        "variables": {
-            "projectName": "Test Project",
-            "author": "Test Author"
-        }
-    });
+/// Router for the validate endpoint tests, with an authenticated caller.
+fn validate_request_app(state: AppState) -> axum::Router {
+    create_router_without_auth(state).layer(middleware::from_fn(
+        |mut req: axum::extract::Request, next: axum::middleware::Next| async move {
+            req.extensions_mut()
+                .insert(crate::middleware::AuthContext::new());
+            next.run(req).await
+        },
+    ))
+}
 
+/// Send `request_body` to the validate endpoint and return the JSON response.
+async fn post_validate_request(
+    state: AppState,
+    request_body: serde_json::Value,
+) -> serde_json::Value {
     let request = Request::builder()
         .method("POST")
         .uri("/api/v1/repositories/validate")
         .header("content-type", "application/json")
-        .header("authorization", "Bearer test-token-123")
         .body(Body::from(serde_json::to_string(&request_body).unwrap()))
         .unwrap();
 
-    let response = app.oneshot(request).await.unwrap();
-
-    assert_eq!(response.status(), StatusCode::OK);
-
+    let response = validate_request_app(state).oneshot(request).await.unwrap();
+    let status = response.status();
     let body = axum::body::to_bytes(response.into_body(), usize::MAX)
         .await
         .unwrap();
-    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "Expected 200; body: {}",
+        String::from_utf8_lossy(&body)
+    );
+    serde_json::from_slice(&body).unwrap()
+}
 
-    assert_eq!(response_json["valid"], true);
+/// Test validate_repository_request endpoint with valid complete request
+///
+/// Verifies that a valid request resolves, renders, and returns valid=true
+/// with the manifest of rendered paths.
+#[tokio::test]
+async fn test_validate_repository_request_valid() {
+    let mock_server = MockServer::start().await;
+    mount_resolve_mocks(&mock_server, "testorg", "rust-library").await;
+
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x")
+        .with_mock_template_files(vec![
+            ("README.md".to_string(), b"# {{repo_name}}".to_vec()),
+            ("src/lib.rs".to_string(), b"// {{org_name}}".to_vec()),
+        ]);
+
+    let response_json = post_validate_request(
+        state,
+        json!({
+            "organization": "testorg",
+            "name": "test-repo",
+            "template": "rust-library",
+            "visibility": "private",
+            "variables": {
+                "projectName": "Test Project",
+                "author": "Test Author"
+            }
+        }),
+    )
+    .await;
+
+    assert_eq!(response_json["valid"], true, "response: {response_json}");
     // Errors field is omitted when empty due to skip_serializing_if
     if let Some(errors) = response_json.get("errors") {
         assert!(errors.as_array().unwrap().is_empty());
     }
+    assert_eq!(
+        response_json["renderedPaths"],
+        json!(["README.md", "src/lib.rs"])
+    );
 }
 
-/// Test validate_repository_request endpoint with missing template variables
+/// Test preview_repository_content renders an in-memory template
 ///
-/// Verifies that the validate endpoint performs only structural validation.
-/// Template variable completeness is deferred to the creation step where the
-/// template configuration is available.
+/// Text files are returned with their rendered content, while binary files
+/// are summarized by size and digest and their bytes are not inlined.
 #[tokio::test]
-async fn test_validate_repository_request_missing_variables() {
-    let app = create_router_without_auth(test_app_state());
+async fn test_preview_repository_content_summarizes_binary_files() {
+    let mock_server = MockServer::start().await;
+    mount_resolve_mocks(&mock_server, "testorg", "rust-library").await;
 
-    let request_body = json!({
-        "organization": "testorg",
-        "name": "test-repo",
-        "template": "rust-library",
-        "variables": {}  // No variables provided
-    });
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x")
+        .with_mock_template_files(vec![
+            ("README.md".to_string(), b"# {{repo_name}}".to_vec()),
+            (
+                "docs/logo.png".to_string(),
+                vec![0x89, b'P', b'N', b'G', 0x00],
+            ),
+        ]);
 
     let request = Request::builder()
         .method("POST")
-        .uri("/api/v1/repositories/validate")
+        .uri("/api/v1/repositories/preview-content")
         .header("content-type", "application/json")
-        .header("authorization", "Bearer test-token-123")
-        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+        .body(Body::from(
+            serde_json::to_string(&json!({
+                "organization": "testorg",
+                "name": "test-repo",
+                "template": "rust-library"
+            }))
+            .unwrap(),
+        ))
         .unwrap();
 
-    let response = app.oneshot(request).await.unwrap();
-
-    assert_eq!(response.status(), StatusCode::OK);
-
+    let response = validate_request_app(state).oneshot(request).await.unwrap();
+    let status = response.status();
     let body = axum::body::to_bytes(response.into_body(), usize::MAX)
         .await
         .unwrap();
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "Expected 200; body: {}",
+        String::from_utf8_lossy(&body)
+    );
     let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
-    // Structural validation passes: name, org, and template are all non-empty.
-    // Variable completeness is validated during creation, not during this pre-check.
-    assert_eq!(
-        response_json["valid"], true,
-        "validate endpoint should return valid=true for structurally correct request"
+    let files = response_json["files"].as_array().unwrap();
+    assert_eq!(files.len(), 2, "files: {files:?}");
+    assert_eq!(files[0]["path"], "README.md");
+    assert_eq!(files[0]["kind"], "text");
+    assert_eq!(files[0]["content"], "# test-repo");
+    assert_eq!(files[1]["path"], "docs/logo.png");
+    assert_eq!(files[1]["kind"], "binary");
+    assert_eq!(files[1]["size"], 5);
+    assert_eq!(files[1]["sha256"].as_str().unwrap().len(), 64);
+    assert!(
+        files[1].get("content").is_none(),
+        "binary content must not be inlined: {}",
+        files[1]
     );
 }
 
-/// Test validate_repository_request endpoint with a template name
+/// Test validate_repository_request endpoint with missing template variables
 ///
-/// Verifies that the validate endpoint performs only structural validation.
-/// Template existence is deferred to the creation step where GitHub API is available.
+/// Verifies that a required variable declared by the template is reported
+/// against its field.
+#[tokio::test]
+async fn test_validate_repository_request_missing_variables() {
+    let mock_server = MockServer::start().await;
+    mount_resolve_mocks_with_files(
+        &mock_server,
+        "testorg",
+        "rust-library",
+        "[repository]\nissues = true\n",
+        &template_toml_with_required_variable("rust-library", "service_name"),
+    )
+    .await;
+
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x")
+        .with_mock_template_files(vec![(
+            "README.md".to_string(),
+            b"# {{service_name}}".to_vec(),
+        )]);
+
+    let response_json = post_validate_request(
+        state,
+        json!({
+            "organization": "testorg",
+            "name": "test-repo",
+            "template": "rust-library",
+            "variables": {}
+        }),
+    )
+    .await;
+
+    assert_eq!(response_json["valid"], false);
+    let errors = response_json["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0]["field"], "variables.service_name");
+}
+
+/// Test validate_repository_request endpoint with a nonexistent template
+///
+/// Verifies that a template that cannot be loaded is reported in the response
+/// rather than as an HTTP error.
 #[tokio::test]
 async fn test_validate_repository_request_nonexistent_template() {
-    let app = create_router_without_auth(test_app_state());
+    let mock_server = MockServer::start().await;
 
-    let request_body = json!({
-        "organization": "testorg",
-        "name": "test-repo",
-        "template": "nonexistent-template",
-        "variables": {}
-    });
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x");
 
-    let request = Request::builder()
-        .method("POST")
-        .uri("/api/v1/repositories/validate")
-        .header("content-type", "application/json")
-        .header("authorization", "Bearer test-token-123")
-        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
-        .unwrap();
+    let response_json = post_validate_request(
+        state,
+        json!({
+            "organization": "testorg",
+            "name": "test-repo",
+            "template": "nonexistent-template",
+            "variables": {}
+        }),
+    )
+    .await;
 
-    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response_json["valid"], false);
+    let errors = response_json["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0]["field"], "template");
+}
 
-    assert_eq!(response.status(), StatusCode::OK);
+/// Test validate_repository_request endpoint reports every problem together
+///
+/// A team override of a fixed global setting and a variable that violates its
+/// pattern must both appear in one response.
+#[tokio::test]
+async fn test_validate_repository_request_reports_override_conflict_and_bad_variable() {
+    let mock_server = MockServer::start().await;
+    let template_toml = r#"
+[template]
+name = "rust-library"
+description = "Test template"
+author = "Test Author"
+tags = []
+
+[variables.service_name]
+description = "Service name"
+required = true
+pattern = "^[a-z-]+$"
+"#;
+    mount_resolve_mocks_with_files(
+        &mock_server,
+        "testorg",
+        "rust-library",
+        "[repository]\nwiki = { value = false, override_allowed = false }\n",
+        template_toml,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/testorg/.reporoller/contents/teams/platform/config.toml",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(file_content_response(
+                "testorg",
+                ".reporoller",
+                "teams/platform/config.toml",
+                "[repository]\nwiki = true\n",
+            )),
+        )
+        .mount(&mock_server)
+        .await;
 
-    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-        .await
-        .unwrap();
-    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x")
+        .with_mock_template_files(vec![(
+            "README.md".to_string(),
+            b"# {{service_name}}".to_vec(),
+        )]);
+
+    let response_json = post_validate_request(
+        state,
+        json!({
+            "organization": "testorg",
+            "name": "test-repo",
+            "template": "rust-library",
+            "team": "platform",
+            "variables": { "service_name": "Not Valid!" }
+        }),
+    )
+    .await;
 
-    // Template existence is not checked in structural validation (requires GitHub API).
-    // The request is structurally valid: non-empty org, name, and template name.
-    assert_eq!(
-        response_json["valid"], true,
-        "validate endpoint should return valid=true for structurally correct request"
+    assert_eq!(response_json["valid"], false);
+    let fields: Vec<&str> = response_json["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert!(
+        fields.iter().any(|f| f.contains("wiki")),
+        "override conflict missing from {fields:?}"
+    );
+    assert!(
+        fields.contains(&"variables.service_name"),
+        "bad variable missing from {fields:?}"
     );
 }
 
@@ -338,18 +504,36 @@ async fn test_validate_repository_request_nonexistent_template() {
 /// Minimal mock that lets tests configure what `load_global_defaults` returns.
 struct MockNamingRuleProvider {
     naming_rules: Option<Vec<config_manager::RepositoryNamingRulesConfig>>,
+    allowed_templates: Option<Vec<String>>,
+    denied_templates: Option<Vec<String>>,
 }
 
 impl MockNamingRuleProvider {
     /// Provider that returns no naming rules (empty org config).
     fn with_no_rules() -> Self {
-        Self { naming_rules: None }
+        Self {
+            naming_rules: None,
+            allowed_templates: None,
+            denied_templates: None,
+        }
     }
 
     /// Provider that returns the supplied naming rules.
     fn with_rules(rules: Vec<config_manager::RepositoryNamingRulesConfig>) -> Self {
         Self {
             naming_rules: Some(rules),
+            allowed_templates: None,
+            denied_templates: None,
+        }
+    }
+
+    /// Provider that returns the supplied template allow/deny patterns.
+    fn with_template_policy(allowed: Option<Vec<&str>>, denied: Option<Vec<&str>>) -> Self {
+        let to_strings = |patterns: Vec<&str>| patterns.into_iter().map(String::from).collect();
+        Self {
+            naming_rules: None,
+            allowed_templates: allowed.map(to_strings),
+            denied_templates: denied.map(to_strings),
         }
     }
 }
@@ -376,6 +560,9 @@ impl config_manager::MetadataRepositoryProvider for MockNamingRuleProvider {
     ) -> config_manager::ConfigurationResult<config_manager::GlobalDefaults> {
         Ok(config_manager::GlobalDefaults {
             naming_rules: self.naming_rules.clone(),
+            allow_template_post_generation: None,
+            allowed_templates: self.allowed_templates.clone(),
+            denied_templates: self.denied_templates.clone(),
             ..Default::default()
         })
     }
@@ -516,6 +703,43 @@ async fn test_check_org_naming_rules_reserved_word_returns_message() {
     );
 }
 
+// ============================================================================
+// filter_allowed_templates unit tests
+// ============================================================================
+
+fn template_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+/// Without a template policy every template is listed.
+#[tokio::test]
+async fn test_filter_allowed_templates_without_policy_keeps_all() {
+    let provider = MockNamingRuleProvider::with_no_rules();
+    let names = template_names(&["rust-service", "internal-tools"]);
+    let filtered = filter_allowed_templates(names.clone(), "test-org", &provider).await;
+    assert_eq!(filtered, names);
+}
+
+/// Only templates matching the allowlist globs are listed, minus denied ones.
+#[tokio::test]
+async fn test_filter_allowed_templates_applies_allow_and_deny_globs() {
+    let provider = MockNamingRuleProvider::with_template_policy(
+        Some(vec!["rust-*", "python-service"]),
+        Some(vec!["*-internal"]),
+    );
+    let names = template_names(&[
+        "rust-service",
+        "rust-internal",
+        "python-service",
+        "python-library",
+    ]);
+    let filtered = filter_allowed_templates(names, "test-org", &provider).await;
+    assert_eq!(
+        filtered,
+        template_names(&["rust-service", "python-service"])
+    );
+}
+
 // ============================================================================
 // check_repository_availability unit tests
 //
@@ -1088,10 +1312,39 @@ fn repo_json(org: &str, name: &str) -> serde_json::Value {
 /// that wiremock returns 404; the provider treats those as empty/absent and continues.
 async fn mount_resolve_mocks(server: &MockServer, org: &str, template: &str) {
     let minimal_defaults_toml = "[repository]\nissues = true\nwiki = false\n";
+    mount_resolve_mocks_with_defaults(server, org, template, minimal_defaults_toml).await;
+}
+
+/// Same as [`mount_resolve_mocks`], serving `defaults_toml` as the global defaults.
+async fn mount_resolve_mocks_with_defaults(
+    server: &MockServer,
+    org: &str,
+    template: &str,
+    defaults_toml: &str,
+) {
     let minimal_template_toml = format!(
         "[template]\nname = \"{template}\"\ndescription = \"Test template\"\nauthor = \"Test Author\"\ntags = []\n"
     );
+    mount_resolve_mocks_with_files(server, org, template, defaults_toml, &minimal_template_toml)
+        .await;
+}
 
+/// Template configuration declaring one required variable.
+fn template_toml_with_required_variable(template: &str, variable: &str) -> String {
+    format!(
+        "[template]\nname = \"{template}\"\ndescription = \"Test template\"\nauthor = \"Test Author\"\ntags = []\n\n[variables.{variable}]\ndescription = \"Required\"\nrequired = true\n"
+    )
+}
+
+/// Same as [`mount_resolve_mocks`], serving `defaults_toml` as the global
+/// defaults and `template_toml` as the template configuration.
+async fn mount_resolve_mocks_with_files(
+    server: &MockServer,
+    org: &str,
+    template: &str,
+    defaults_toml: &str,
+    template_toml: &str,
+) {
     // Metadata repository discovery: GET /repos/{org}/.reporoller
     Mock::given(method("GET"))
         .and(path(format!("/repos/{org}/.reporoller")))
@@ -1109,7 +1362,7 @@ async fn mount_resolve_mocks(server: &MockServer, org: &str, template: &str) {
                 org,
                 ".reporoller",
                 "global/defaults.toml",
-                minimal_defaults_toml,
+                defaults_toml,
             )),
         )
         .mount(server)
@@ -1132,7 +1385,7 @@ async fn mount_resolve_mocks(server: &MockServer, org: &str, template: &str) {
                 org,
                 template,
                 ".reporoller/template.toml",
-                &minimal_template_toml,
+                template_toml,
             )),
         )
         .mount(server)
@@ -1195,8 +1448,14 @@ async fn test_preview_configuration_returns_merged_config_with_sources() {
         !sources.is_empty(),
         "sources must contain at least one entry when global defaults define repository settings"
     );
-    // Every source value must be one of the four recognised levels.
-    let valid_levels = ["global", "repository_type", "team", "template"];
+    // Every source value must be one of the recognised levels.
+    let valid_levels = [
+        "global",
+        "repository_type",
+        "team",
+        "template",
+        "github_default",
+    ];
     for (key, val) in sources {
         let level = val.as_str().unwrap_or("");
         assert!(
@@ -1204,6 +1463,14 @@ async fn test_preview_configuration_returns_merged_config_with_sources() {
             "source '{key}' has unexpected level '{level}'"
         );
     }
+    // Settings no layer configures are reported as GitHub defaults.
+    for field in config_manager::MergedConfiguration::setting_field_paths() {
+        assert!(sources.contains_key(field), "missing source for '{field}'");
+    }
+    assert!(
+        sources.values().any(|level| level == "github_default"),
+        "unset settings must be reported as github_default"
+    );
     let validation = resp["validation"]
         .as_object()
         .expect("validation must be a JSON object");
@@ -1214,6 +1481,75 @@ async fn test_preview_configuration_returns_merged_config_with_sources() {
     );
 }
 
+/// Naming a repository adds a preview of the notifications its creation would send.
+///
+/// The endpoint's secret is deliberately unset, so the preview carries the
+/// payload and an error instead of a signature.
+#[tokio::test]
+async fn test_preview_configuration_includes_notification_preview() {
+    let mock_server = MockServer::start().await;
+    let defaults_toml = r#"
+[repository]
+issues = true
+
+[[notifications.outbound_webhooks]]
+url = "https://hooks.example.com/reporoller"
+secret = "REPOROLLER_PREVIEW_TEST_SECRET_NOT_SET"
+events = ["repository.created"]
+"#;
+    mount_resolve_mocks_with_defaults(&mock_server, "testorg", "rust-service", defaults_toml).await;
+
+    let state = AppState::default()
+        .with_github_api_base_url(mock_server.uri())
+        .with_mock_installation_token("x");
+    let app = create_router_without_auth(state).layer(middleware::from_fn(
+        |mut req: axum::extract::Request, next: axum::middleware::Next| async move {
+            req.extensions_mut()
+                .insert(crate::middleware::AuthContext::new());
+            next.run(req).await
+        },
+    ));
+
+    let request_body = json!({ "template": "rust-service", "repositoryName": "new-service" });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/orgs/testorg/configuration/preview")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "Expected 200; body: {}",
+        String::from_utf8_lossy(&body)
+    );
+    let resp: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let notifications = resp["notifications"]
+        .as_array()
+        .expect("notifications must be a JSON array");
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(
+        notifications[0]["endpointUrl"],
+        "https://hooks.example.com/reporoller"
+    );
+    assert!(notifications[0].get("signature").is_none());
+    assert!(notifications[0]["error"].is_string());
+
+    let payload: serde_json::Value =
+        serde_json::from_str(notifications[0]["payload"].as_str().unwrap()).unwrap();
+    assert_eq!(payload["event_type"], "repository.created");
+    assert_eq!(payload["organization"], "testorg");
+    assert_eq!(payload["repository_name"], "new-service");
+    assert_eq!(payload["template_name"], "rust-service");
+}
+
 /// Missing template: returns 404.
 ///
 /// When `GET /repos/{org}/{template}` returns 404 (template repository does not
@@ -1327,3 +1663,145 @@ async fn test_preview_configuration_unknown_repository_type_returns_400() {
         "Expected 400 when repository_type does not exist"
     );
 }
+
+// ============================================================================
+// Request Body Rejection Tests
+// ============================================================================
+
+/// Test that create_repository rejects a body with an unknown field.
+///
+/// A misspelled field must not be silently ignored: the response is a 400
+/// with a standard error body naming the unexpected field.
+#[tokio::test]
+async fn test_create_repository_unknown_field_returns_400() {
+    let app = create_router_without_auth(test_app_state()).layer(middleware::from_fn(
+        |mut req: axum::extract::Request, next: axum::middleware::Next| async move {
+            req.extensions_mut()
+                .insert(crate::middleware::AuthContext::new());
+            next.run(req).await
+        },
+    ));
+
+    let request_body = json!({
+        "organization": "testorg",
+        "name": "test-repo",
+        "templat": "rust-library"
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/repositories")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response_json["error"]["code"], "ValidationError");
+    assert!(response_json["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("unknown field `templat`"));
+    assert_eq!(response_json["error"]["details"]["field"], "templat");
+}
+
+/// Test that create_repository reports a missing GitHub App installation as
+/// a 403 telling the caller to install the app.
+#[tokio::test]
+async fn test_create_repository_app_not_installed_returns_403() {
+    let state = test_app_state()
+        .with_github_app_slug("reporoller")
+        .with_mock_app_not_installed();
+    let app = create_router_without_auth(state).layer(middleware::from_fn(
+        |mut req: axum::extract::Request, next: axum::middleware::Next| async move {
+            req.extensions_mut()
+                .insert(crate::middleware::AuthContext::new());
+            next.run(req).await
+        },
+    ));
+
+    let request_body = json!({
+        "organization": "testorg",
+        "name": "test-repo",
+        "template": "rust-library"
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/repositories")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let install_url = "https://github.com/apps/reporoller/installations/new";
+    assert_eq!(response_json["error"]["code"], "GitHubAppNotInstalled");
+    let message = response_json["error"]["message"].as_str().unwrap();
+    assert!(message.contains("not installed on organization 'testorg'"));
+    assert!(message.contains(install_url));
+    assert_eq!(response_json["error"]["details"]["organization"], "testorg");
+    assert_eq!(response_json["error"]["details"]["installUrl"], install_url);
+}
+
+// =============================================================================
+// list_template_files tests
+// =============================================================================
+
+/// The template inspection is converted into the file manifest response.
+#[test]
+fn test_template_files_response_lists_files_and_variables() {
+    let mut variables = std::collections::HashMap::new();
+    variables.insert(
+        "project_name".to_string(),
+        config_manager::TemplateVariable {
+            description: "Project name".to_string(),
+            example: None,
+            required: Some(true),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: None,
+            required_if: None,
+            secret: None,
+            transforms: None,
+        },
+    );
+    let inspection = repo_roller_core::TemplateInspection {
+        files: template_engine::build_file_manifest(&[
+            ("README.md".to_string(), b"# {{repo_name}}\n".to_vec()),
+            ("logo.png".to_string(), vec![0x89, b'P', 0x00]),
+        ]),
+        variables,
+    };
+
+    let response = template_files_response("rust-library".to_string(), inspection);
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "name": "rust-library",
+            "files": [
+                { "path": "README.md", "size": 16, "kind": "text" },
+                { "path": "logo.png", "size": 3, "kind": "binary" }
+            ],
+            "variables": {
+                "project_name": { "description": "Project name", "required": true }
+            }
+        })
+    );
+}
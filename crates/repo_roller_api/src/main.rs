@@ -8,6 +8,8 @@
 //! - `API_HOST`: Host to bind to (default: 0.0.0.0)
 //! - `RUST_LOG`: Log level (default: info)
 //! - `METADATA_REPOSITORY_NAME`: Name of metadata repository (default: .reporoller)
+//! - `METADATA_GIT_REF`: Branch, tag or commit to read the metadata repository at
+//!   (default: its default branch)
 //! - `GITHUB_APP_ID`: GitHub App ID (required)
 //! - `GITHUB_APP_PRIVATE_KEY`: GitHub App private key in PEM format (required)
 
@@ -44,6 +46,9 @@ pub const DEFAULT_PORT: u16 = 8080;
 pub struct AppState {
     /// Metadata repository name for organization settings
     pub metadata_repository_name: String,
+    /// Git ref the metadata repository is read at; `None` reads its default
+    /// branch.
+    pub(crate) metadata_git_ref: Option<String>,
     /// Shared event metrics, initialised once at startup.
     ///
     /// Cloned (Arc clone, not a new allocation) for each handler invocation so
@@ -54,6 +59,12 @@ pub struct AppState {
     /// When `None` the default `https://api.github.com` is used. Set this to
     /// target a GitHub Enterprise instance or (in tests) a wiremock server.
     pub(crate) github_api_base_url: Option<String>,
+    /// URL slug of the GitHub App, e.g. `reporoller` for
+    /// `https://github.com/apps/reporoller`.
+    ///
+    /// When set, errors for organizations without an installation include the
+    /// app's install URL.
+    pub(crate) github_app_slug: Option<String>,
     /// GitHub App authentication service used to mint installation tokens.
     ///
     /// Stored as an `Arc` so that cloned `AppState` values (one per request)
@@ -70,6 +81,17 @@ pub struct AppState {
     /// the real GitHub API. Always `None` in production.
     #[cfg(test)]
     pub(crate) mock_installation_token: Option<String>,
+    /// When `true`, `get_installation_token` fails as if the GitHub App were
+    /// not installed on the organization. Always `false` in production.
+    #[cfg(test)]
+    pub(crate) mock_app_not_installed: bool,
+    /// Template files injected in tests to bypass cloning the template
+    /// repository.
+    ///
+    /// When `Some`, `fetch_template_files` returns these files for every
+    /// template. Always `None` in production.
+    #[cfg(test)]
+    pub(crate) mock_template_files: Option<Vec<(String, Vec<u8>)>>,
 }
 
 impl AppState {
@@ -89,10 +111,12 @@ impl AppState {
         let registry = prometheus::Registry::new();
         Self {
             metadata_repository_name: metadata_repository_name.into(),
+            metadata_git_ref: None,
             event_metrics: std::sync::Arc::new(
                 repo_roller_core::event_metrics::PrometheusEventMetrics::new(&registry),
             ),
             github_api_base_url: None,
+            github_app_slug: None,
             auth_service: std::sync::Arc::new(auth_handler::GitHubAuthService::new(
                 github_app_id,
                 github_app_private_key,
@@ -100,6 +124,10 @@ impl AppState {
             jwt_secret: secrecy::SecretString::from(jwt_secret.into()),
             #[cfg(test)]
             mock_installation_token: None,
+            #[cfg(test)]
+            mock_app_not_installed: false,
+            #[cfg(test)]
+            mock_template_files: None,
         }
     }
 
@@ -112,6 +140,43 @@ impl AppState {
         self
     }
 
+    /// Read the metadata repository at the given branch, tag or commit.
+    ///
+    /// Lets a deployment use configuration staged on a branch before it is
+    /// merged.
+    pub fn with_metadata_git_ref(mut self, git_ref: impl Into<String>) -> Self {
+        self.metadata_git_ref = Some(git_ref.into());
+        self
+    }
+
+    /// Configuration for reading the metadata repository, shared by template
+    /// loading and organization configuration resolution.
+    pub(crate) fn metadata_provider_config(&self) -> config_manager::MetadataProviderConfig {
+        let config =
+            config_manager::MetadataProviderConfig::explicit(&self.metadata_repository_name);
+        match &self.metadata_git_ref {
+            Some(git_ref) => config.with_git_ref(git_ref),
+            None => config,
+        }
+    }
+
+    /// Set the URL slug of the GitHub App.
+    ///
+    /// Used to point callers at the app's install page when an organization
+    /// has no installation.
+    pub fn with_github_app_slug(mut self, slug: impl Into<String>) -> Self {
+        self.github_app_slug = Some(slug.into());
+        self
+    }
+
+    /// Make `get_installation_token` fail as if the GitHub App were not
+    /// installed on the organization.
+    #[cfg(test)]
+    pub fn with_mock_app_not_installed(mut self) -> Self {
+        self.mock_app_not_installed = true;
+        self
+    }
+
     /// Inject a pre-minted installation token used in unit tests.
     ///
     /// When set, `get_installation_token` returns this token without calling
@@ -123,6 +188,34 @@ impl AppState {
         self
     }
 
+    /// Inject the template files returned by `fetch_template_files` in unit
+    /// tests.
+    #[cfg(test)]
+    pub fn with_mock_template_files(mut self, files: Vec<(String, Vec<u8>)>) -> Self {
+        self.mock_template_files = Some(files);
+        self
+    }
+
+    /// Fetch the files of the template repository `org/template`.
+    ///
+    /// In production the repository is fetched with the `GitHubTemplateFetcher`.
+    /// In tests the files set by `with_mock_template_files` are returned
+    /// instead.
+    pub(crate) async fn fetch_template_files(
+        &self,
+        org: &str,
+        template: &str,
+    ) -> Result<Vec<(String, Vec<u8>)>, String> {
+        #[cfg(test)]
+        if let Some(ref files) = self.mock_template_files {
+            return Ok(files.clone());
+        }
+        use template_engine::TemplateFetcher as _;
+        template_engine::GitHubTemplateFetcher::new()
+            .fetch_template_files(&format!("https://github.com/{}/{}", org, template))
+            .await
+    }
+
     /// Mint a GitHub App installation token for `org`.
     ///
     /// In production this uses the stored `GitHubAuthService` (holding the App
@@ -133,6 +226,13 @@ impl AppState {
         &self,
         org: &str,
     ) -> Result<String, crate::errors::ApiError> {
+        #[cfg(test)]
+        if self.mock_app_not_installed {
+            return Err(crate::errors::ApiError::app_not_installed(
+                org,
+                self.github_app_slug.as_deref(),
+            ));
+        }
         #[cfg(test)]
         if let Some(ref token) = self.mock_installation_token {
             return Ok(token.clone());
@@ -141,11 +241,14 @@ impl AppState {
         self.auth_service
             .get_installation_token_for_org(org)
             .await
-            .map_err(|e| {
-                crate::errors::ApiError::internal(format!(
+            .map_err(|e| match e {
+                auth_handler::AuthError::AppNotInstalled(org) => {
+                    crate::errors::ApiError::app_not_installed(org, self.github_app_slug.as_deref())
+                }
+                e => crate::errors::ApiError::internal(format!(
                     "Failed to get GitHub App installation token for organisation '{}': {}",
                     org, e
-                ))
+                )),
             })
     }
 }
@@ -164,6 +267,7 @@ impl Default for AppState {
     fn default() -> Self {
         Self {
             metadata_repository_name: ".reporoller".to_string(),
+            metadata_git_ref: None,
             event_metrics: {
                 let registry = prometheus::Registry::new();
                 std::sync::Arc::new(
@@ -171,9 +275,12 @@ impl Default for AppState {
                 )
             },
             github_api_base_url: None,
+            github_app_slug: None,
             auth_service: std::sync::Arc::new(auth_handler::GitHubAuthService::new(0u64, "")),
             jwt_secret: secrecy::SecretString::from(TEST_JWT_SECRET.to_string()),
             mock_installation_token: None,
+            mock_app_not_installed: false,
+            mock_template_files: None,
         }
     }
 }
@@ -187,10 +294,7 @@ async fn main() -> anyhow::Result<()> {
         .install_default()
         .ok();
 
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string()))
-        .init();
+    init_logging();
 
     // Load configuration from environment
     let config = ApiConfig {
@@ -213,9 +317,21 @@ async fn main() -> anyhow::Result<()> {
     // Normalize literal \n sequences to real newlines so the key works whether
     // it was stored with escaped newlines (common in env files and CI secrets)
     // or with real newlines (common when read directly from a .pem file).
-    let github_app_private_key = env::var("GITHUB_APP_PRIVATE_KEY")
-        .expect("GITHUB_APP_PRIVATE_KEY environment variable is required")
-        .replace("\\n", "\n");
+    // When it is not set, the key is read from the file named by
+    // GITHUB_APP_PRIVATE_KEY_PATH instead.
+    let github_app_private_key = match env::var("GITHUB_APP_PRIVATE_KEY") {
+        Ok(key) => key.replace("\\n", "\n"),
+        Err(_) => github_client::AppPrivateKey::from_env()
+            .unwrap_or_else(|e| {
+                panic!(
+                    "GITHUB_APP_PRIVATE_KEY or {} environment variable is required: {}",
+                    github_client::PRIVATE_KEY_PATH_ENV_VAR,
+                    e
+                )
+            })
+            .expose_pem()
+            .to_string(),
+    };
 
     // JWT_SECRET signs backend-issued JWTs — never log its value.
     let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET environment variable is required");
@@ -225,12 +341,18 @@ async fn main() -> anyhow::Result<()> {
     );
 
     // Create app state and server
-    let state = AppState::new(
+    let mut state = AppState::new(
         metadata_repo.clone(),
         github_app_id,
         github_app_private_key,
         jwt_secret,
     );
+    if let Ok(slug) = env::var("GITHUB_APP_SLUG") {
+        state = state.with_github_app_slug(slug);
+    }
+    if let Ok(git_ref) = env::var("METADATA_GIT_REF") {
+        state = state.with_metadata_git_ref(git_ref);
+    }
     let server = ApiServer::new(config, state);
 
     tracing::info!("Starting RepoRoller API server");
@@ -242,6 +364,21 @@ async fn main() -> anyhow::Result<()> {
     server.serve().await
 }
 
+/// Installs the global tracing subscriber for the API server.
+///
+/// Library crates only emit `tracing` events; the subscriber is set up here.
+/// Calling this more than once, or after another subscriber has been
+/// installed, leaves the existing subscriber in place.
+fn init_logging() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string()))
+        .try_init();
+}
+
+#[cfg(test)]
+#[path = "main_tests.rs"]
+mod tests;
+
 #[cfg(test)]
 mod test_crypto_provider {
     #[ctor::ctor(unsafe)]
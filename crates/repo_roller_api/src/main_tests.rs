@@ -0,0 +1,24 @@
+//! Tests for the API server binary
+
+use super::*;
+
+/// Installing the subscriber twice must not panic.
+#[test]
+fn test_init_logging_is_idempotent() {
+    init_logging();
+    init_logging();
+}
+
+/// The metadata repository is read at its default branch unless a git ref is
+/// configured.
+#[test]
+fn test_metadata_provider_config_uses_configured_git_ref() {
+    let state = AppState::default();
+    assert_eq!(state.metadata_provider_config().git_ref(), None);
+
+    let state = state.with_metadata_git_ref("config-staging");
+    assert_eq!(
+        state.metadata_provider_config().git_ref(),
+        Some("config-staging")
+    );
+}
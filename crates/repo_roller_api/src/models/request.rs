@@ -88,6 +88,14 @@ pub struct CreateRepositoryRequest {
     /// Repository name (must follow GitHub naming rules)
     pub name: String,
 
+    /// Kind of account named by `organization`: `"organization"` (default)
+    /// or `"user"` for a personal account.
+    ///
+    /// Personal repositories cannot use teams, owning teams or internal
+    /// visibility.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_type: Option<String>,
+
     /// Template name to use for repository creation.
     ///
     /// Optional when using Empty or CustomInit content strategies.
@@ -148,6 +156,79 @@ pub struct CreateRepositoryRequest {
     /// ```
     #[serde(default)]
     pub collaborators: HashMap<String, String>,
+
+    /// Additional teams owning the repository whose team configuration applies.
+    ///
+    /// Combined after `team` in the order given. Additive settings from all
+    /// teams are unioned; conflicting override settings reject the request.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "team": "backend", "owningTeams": ["platform"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owning_teams: Vec<String>,
+
+    /// Create the initial commit through the GitHub API so it is signed and
+    /// verified (optional, defaults from organization configuration).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signed_initial_commit: Option<bool>,
+
+    /// Branch that receives the initial content, proposed to the default
+    /// branch in a pull request (optional).
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "reviewBranch": "template-init"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_branch: Option<String>,
+
+    /// License GitHub adds to the repository when creating it, as a license
+    /// key (optional).
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "licenseTemplate": "mit"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_template: Option<String>,
+
+    /// GitHub `.gitignore` template GitHub applies when creating the
+    /// repository (optional).
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "gitignoreTemplate": "Rust"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitignore_template: Option<String>,
+
+    /// Whether GitHub creates an initial commit with a README (optional).
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// "autoInit": true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+
+    /// Repository description (optional).
+    ///
+    /// Overrides the template's default description when provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Repository topics (optional).
+    ///
+    /// Replaces the template's default topics when non-empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
 }
 
 // Translation to domain types is implemented in the translation module
@@ -177,6 +258,13 @@ pub struct ValidateRepositoryNameRequest {
 /// See: specs/interfaces/api-request-types.md#validaterepositoryrequestrequest
 pub type ValidateRepositoryRequestRequest = CreateRepositoryRequest;
 
+/// Type alias for previewing the initial commit of a repository.
+///
+/// Uses the same structure as CreateRepositoryRequest so that the preview is
+/// rendered exactly as the repository would be created. A template is
+/// required.
+pub type PreviewContentRequest = CreateRepositoryRequest;
+
 /// Path parameters for listing templates.
 ///
 /// See: specs/interfaces/api-request-types.md#listtemplatesrequest
@@ -201,6 +289,9 @@ pub struct GetTemplateDetailsParams {
 /// Path parameters for validating a template.
 pub type ValidateTemplateParams = GetTemplateDetailsParams;
 
+/// Path parameters for listing a template's files.
+pub type ListTemplateFilesParams = GetTemplateDetailsParams;
+
 /// Path parameters for listing repository types.
 ///
 /// See: specs/interfaces/api-request-types.md#listrepositorytypesrequest
@@ -255,6 +346,13 @@ pub struct PreviewConfigurationRequest {
     /// Repository type (optional - template may specify)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repository_type: Option<String>,
+
+    /// Repository name for previewing outbound notifications (optional)
+    ///
+    /// When set, the response lists the payload and signature that creating a
+    /// repository with this name would send to each notification endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_name: Option<String>,
 }
 
 /// Path parameters for validating organization settings.
@@ -104,6 +104,18 @@ fn test_preview_configuration_request_minimal() {
     assert_eq!(req.repository_type, None);
 }
 
+/// Test PreviewConfigurationRequest with a repository name for notification preview
+#[test]
+fn test_preview_configuration_request_with_repository_name() {
+    let json = r#"{
+        "template": "rust-library",
+        "repositoryName": "my-service"
+    }"#;
+
+    let req: PreviewConfigurationRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.repository_name, Some("my-service".to_string()));
+}
+
 #[test]
 fn test_create_repository_request_deserialization() {
     let json = r#"{
@@ -35,7 +35,8 @@ use std::collections::HashMap;
 ///     "teamPermissions": {...},
 ///     "branchProtection": {...}
 ///   },
-///   "createdAt": "2025-11-12T10:30:00Z"
+///   "createdAt": "2025-11-12T10:30:00Z",
+///   "warnings": ["webhooks[0].secret: Webhook has no secret configured"]
 /// }
 /// ```
 ///
@@ -51,6 +52,10 @@ pub struct CreateRepositoryResponse {
 
     /// Timestamp when repository was created
     pub created_at: String, // ISO 8601 format
+
+    /// Non-blocking configuration warnings raised during creation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// Repository information included in responses.
@@ -149,6 +154,13 @@ pub struct ValidateRepositoryRequestResponse {
     /// Validation warnings (don't prevent creation)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<ValidationResult>,
+
+    /// Paths of the files the template renders, sorted
+    ///
+    /// Only populated by repository request validation, and only when the
+    /// template renders.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rendered_paths: Vec<String>,
 }
 
 /// Individual validation result for a field or constraint.
@@ -328,6 +340,111 @@ pub struct VariableDefinition {
     pub pattern: Option<String>,
 }
 
+/// HTTP response listing a template's files.
+///
+/// Lists the files a repository created from the template would start with,
+/// before any variables are substituted, together with the template's
+/// declared variables.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "name": "rust-library",
+///   "files": [
+///     { "path": "README.md", "size": 412, "kind": "text" },
+///     { "path": "docs/logo.png", "size": 10240, "kind": "binary" }
+///   ],
+///   "variables": {
+///     "project_name": {
+///       "description": "Human-readable project name",
+///       "required": true
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateFilesResponse {
+    /// Template name
+    pub name: String,
+
+    /// Template files, sorted by path
+    pub files: Vec<TemplateFileInfo>,
+
+    /// Variable definitions
+    pub variables: HashMap<String, VariableDefinition>,
+}
+
+/// A single file in a template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateFileInfo {
+    /// Path relative to the template root
+    pub path: String,
+
+    /// Size in bytes
+    pub size: u64,
+
+    /// `"text"` for files that have variables substituted, `"binary"` for
+    /// files copied unchanged
+    pub kind: String,
+}
+
+/// HTTP response for previewing the initial commit of a repository.
+///
+/// Text files carry their rendered content. Binary files are summarized by
+/// size and digest only.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "files": [
+///     {
+///       "path": "README.md",
+///       "size": 13,
+///       "kind": "text",
+///       "sha256": "5d41402abc4b2a76b9719d911017c592...",
+///       "content": "# my-new-repo"
+///     },
+///     {
+///       "path": "docs/logo.png",
+///       "size": 10240,
+///       "kind": "binary",
+///       "sha256": "9e107d9d372bb6826bd81d3542a419d6..."
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewContentResponse {
+    /// Rendered files, sorted by path
+    pub files: Vec<RenderedFileInfo>,
+}
+
+/// A single rendered file of a content preview.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedFileInfo {
+    /// Path relative to the repository root
+    pub path: String,
+
+    /// Size of the rendered content in bytes
+    pub size: u64,
+
+    /// `"text"` or `"binary"`
+    pub kind: String,
+
+    /// Hex-encoded SHA-256 digest of the rendered content
+    pub sha256: String,
+
+    /// Rendered content; omitted for binary files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
 /// HTTP response for template validation.
 ///
 /// See: specs/interfaces/api-response-types.md#validatetemplateresponse
@@ -422,11 +539,42 @@ pub struct PreviewConfigurationResponse {
     /// Merged configuration result
     pub merged: serde_json::Value,
 
-    /// Source of each configuration value (for traceability)
+    /// Source of each configuration value (for traceability); settings no
+    /// layer configures are reported as `github_default`
     pub sources: HashMap<String, String>, // key -> source (e.g., "visibility" -> "team")
 
     /// Validation summary for the merged configuration
     pub validation: ConfigurationPreviewValidation,
+
+    /// Notifications a creation would send; only present when the request
+    /// named a repository
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notifications: Vec<NotificationPreviewResponse>,
+}
+
+/// Request that creating a repository would send to one notification endpoint.
+///
+/// Nothing is sent when the preview is computed. `signature` is the value of
+/// the `X-RepoRoller-Signature-256` header and is absent when the endpoint's
+/// secret could not be resolved; `error` then explains why.
+///
+/// See: specs/interfaces/api-response-types.md#previewconfigurationresponse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreviewResponse {
+    /// Endpoint URL
+    pub endpoint_url: String,
+
+    /// Exact JSON request body
+    pub payload: String,
+
+    /// HMAC-SHA256 signature of the payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+
+    /// Why the request could not be fully prepared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// HTTP response for organization settings validation.
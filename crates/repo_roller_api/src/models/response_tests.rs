@@ -14,11 +14,13 @@ fn test_create_repository_response_serialization() {
         },
         applied_configuration: serde_json::json!({}),
         created_at: "2025-11-12T10:30:00Z".to_string(),
+        warnings: vec![],
     };
 
     let json = serde_json::to_string(&response).unwrap();
     assert!(json.contains("\"repository\""));
     assert!(json.contains("\"createdAt\""));
+    assert!(!json.contains("\"warnings\""));
 }
 
 #[test]
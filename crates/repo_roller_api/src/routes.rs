@@ -9,6 +9,7 @@
 //! - POST   /api/v1/repositories - Create repository
 //! - POST   /api/v1/repositories/validate-name - Validate name
 //! - POST   /api/v1/repositories/validate - Validate full request
+//! - POST   /api/v1/repositories/preview-content - Preview initial commit content
 //! - GET    /api/v1/orgs/:org/templates - List templates
 //! - GET    /api/v1/orgs/:org/templates/:template - Get template details
 //! - POST   /api/v1/orgs/:org/templates/:template/validate - Validate template
@@ -112,6 +113,10 @@ pub fn create_router(state: AppState) -> Router {
             "/repositories/validate",
             post(handlers::validate_repository_request),
         )
+        .route(
+            "/repositories/preview-content",
+            post(handlers::preview_repository_content),
+        )
         // Organization-specific routes
         .nest("/orgs/{org}", organization_routes())
         // Auth middleware reads jwt_secret from AppState; from_fn_with_state
@@ -144,6 +149,10 @@ fn organization_routes() -> Router<AppState> {
         // Template routes
         .route("/templates", get(handlers::list_templates))
         .route("/templates/{template}", get(handlers::get_template_details))
+        .route(
+            "/templates/{template}/files",
+            get(handlers::list_template_files),
+        )
         .route(
             "/templates/{template}/validate",
             post(handlers::validate_template),
@@ -231,6 +240,10 @@ pub fn create_router_without_auth(state: AppState) -> Router {
             "/repositories/validate",
             post(handlers::validate_repository_request),
         )
+        .route(
+            "/repositories/preview-content",
+            post(handlers::preview_repository_content),
+        )
         // Organization-specific routes (without org-specific auth)
         .nest("/orgs/{org}", organization_routes_without_auth())
         // Health check
@@ -253,6 +266,10 @@ fn organization_routes_without_auth() -> Router<AppState> {
         // Template routes
         .route("/templates", get(handlers::list_templates))
         .route("/templates/{template}", get(handlers::get_template_details))
+        .route(
+            "/templates/{template}/files",
+            get(handlers::list_template_files),
+        )
         .route(
             "/templates/{template}/validate",
             post(handlers::validate_template),
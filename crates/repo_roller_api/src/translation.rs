@@ -12,7 +12,7 @@ use crate::{
 use std::collections::HashMap;
 
 use repo_roller_core::{
-    permissions::AccessLevel, OrganizationName, RepositoryCreationRequest,
+    permissions::AccessLevel, OrganizationName, OwnerType, RepositoryCreationRequest,
     RepositoryCreationRequestBuilder, RepositoryCreationResult, RepositoryName, TemplateName,
 };
 
@@ -46,6 +46,21 @@ pub fn http_create_repository_request_to_domain(
         ApiError::validation_error("organization", format!("Invalid organization name: {}", e))
     })?;
 
+    // Owner type defaults to an organization
+    let owner_type = if let Some(owner_type_str) = http_req.owner_type {
+        owner_type_str.parse::<OwnerType>().map_err(|_| {
+            ApiError::validation_error(
+                "ownerType",
+                format!(
+                    "Invalid owner type '{}'. Must be 'organization' or 'user'",
+                    owner_type_str
+                ),
+            )
+        })?
+    } else {
+        OwnerType::Organization
+    };
+
     // Template is optional - only validate if provided
     let template = if let Some(template_str) = http_req.template {
         Some(TemplateName::new(template_str).map_err(|e| {
@@ -134,8 +149,10 @@ pub fn http_create_repository_request_to_domain(
         RepositoryCreationRequestBuilder::new(name, owner)
     };
 
-    // Add content strategy
-    builder = builder.content_strategy(http_req.content_strategy);
+    // Add content strategy and owner type
+    builder = builder
+        .content_strategy(http_req.content_strategy)
+        .owner_type(owner_type);
 
     // Add visibility if provided
     if let Some(vis) = visibility {
@@ -150,6 +167,43 @@ pub fn http_create_repository_request_to_domain(
     // Add teams and collaborators
     builder = builder.teams(teams).collaborators(collaborators);
 
+    // Owning teams for configuration: the primary team first, then any others
+    let owning_teams: Vec<String> = http_req
+        .team
+        .into_iter()
+        .chain(http_req.owning_teams)
+        .collect();
+    builder = builder.owning_teams(owning_teams);
+
+    // Per-request choice of commit path; otherwise the organization setting applies
+    if let Some(signed) = http_req.signed_initial_commit {
+        builder = builder.signed_initial_commit(signed);
+    }
+
+    // Land the content on a side branch and open a pull request for it
+    if let Some(branch) = http_req.review_branch {
+        builder = builder.review_branch(branch);
+    }
+
+    // GitHub creates the LICENSE file when it creates the repository
+    if let Some(license) = http_req.license_template {
+        builder = builder.license_template(license);
+    }
+
+    // GitHub scaffolds a .gitignore and/or README when it creates the repository
+    if let Some(gitignore) = http_req.gitignore_template {
+        builder = builder.gitignore_template(gitignore);
+    }
+    if let Some(auto_init) = http_req.auto_init {
+        builder = builder.auto_init(auto_init);
+    }
+
+    // Explicit description and topics override the template's defaults
+    if let Some(description) = http_req.description {
+        builder = builder.description(description);
+    }
+    builder = builder.topics(http_req.topics);
+
     // Set the actor identity via builder to keep all construction through one path
     builder = builder.actor(actor_login);
 
@@ -209,9 +263,19 @@ pub fn domain_repository_creation_result_to_http(
         repository: repository_info,
         applied_configuration,
         created_at: result.created_at.to_string(), // Uses Display trait which calls to_rfc3339()
+        warnings: result
+            .warnings
+            .iter()
+            .map(format_validation_warning)
+            .collect(),
     }
 }
 
+/// Format a configuration warning as `<field path>: <message>` for HTTP responses.
+pub fn format_validation_warning(warning: &repo_roller_core::ValidationWarning) -> String {
+    format!("{}: {}", warning.field_path, warning.message)
+}
+
 #[cfg(test)]
 #[path = "translation_tests.rs"]
 mod tests;
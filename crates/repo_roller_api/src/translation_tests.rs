@@ -22,6 +22,15 @@ fn test_http_to_domain_create_repository_request_valid() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -57,6 +66,15 @@ fn test_http_to_domain_invalid_repository_name() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -79,6 +97,15 @@ fn test_http_to_domain_invalid_organization_name() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -101,6 +128,15 @@ fn test_http_to_domain_invalid_template_name() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -117,6 +153,10 @@ fn test_domain_to_http_create_repository_response() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let http_req = CreateRepositoryRequest {
@@ -130,6 +170,15 @@ fn test_domain_to_http_create_repository_response() {
         content_strategy: repo_roller_core::ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let http_response = domain_repository_creation_result_to_http(domain_result, &http_req);
@@ -154,6 +203,10 @@ fn test_domain_to_http_default_visibility() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let http_req = CreateRepositoryRequest {
@@ -167,6 +220,15 @@ fn test_domain_to_http_default_visibility() {
         content_strategy: repo_roller_core::ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let http_response = domain_repository_creation_result_to_http(domain_result, &http_req);
@@ -174,6 +236,56 @@ fn test_domain_to_http_default_visibility() {
     assert_eq!(http_response.repository.visibility, "private"); // Defaults to private
 }
 
+/// Test configuration warnings are carried into the HTTP response
+#[test]
+fn test_domain_to_http_includes_warnings() {
+    use repo_roller_core::{Timestamp, ValidationWarning};
+
+    let domain_result = RepositoryCreationResult {
+        repository_url: "https://github.com/myorg/my-repo".to_string(),
+        repository_id: "R_kgDOABCDEF".to_string(),
+        created_at: Timestamp::now(),
+        default_branch: "main".to_string(),
+        warnings: vec![ValidationWarning {
+            field_path: "webhooks[0].secret".to_string(),
+            message: "Webhook has no secret configured".to_string(),
+            recommendation: None,
+        }],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
+    };
+
+    let http_req = CreateRepositoryRequest {
+        organization: "myorg".to_string(),
+        name: "my-repo".to_string(),
+        template: Some("rust-library".to_string()),
+        visibility: None,
+        team: None,
+        repository_type: None,
+        variables: HashMap::new(),
+        content_strategy: repo_roller_core::ContentStrategy::Template,
+        teams: HashMap::new(),
+        collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
+    };
+
+    let http_response = domain_repository_creation_result_to_http(domain_result, &http_req);
+
+    assert_eq!(
+        http_response.warnings,
+        vec!["webhooks[0].secret: Webhook has no secret configured".to_string()]
+    );
+}
+
 /// Test translation with empty variables map
 #[test]
 fn test_http_to_domain_empty_variables() {
@@ -190,6 +302,15 @@ fn test_http_to_domain_empty_variables() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -220,6 +341,15 @@ fn test_http_to_domain_multiple_variables() {
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -257,6 +387,15 @@ fn test_http_to_domain_empty_strategy_without_template() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -285,6 +424,15 @@ fn test_http_to_domain_empty_strategy_with_template() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -317,6 +465,15 @@ fn test_http_to_domain_custom_init_both_files() {
         },
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -352,6 +509,15 @@ fn test_http_to_domain_custom_init_with_template() {
         },
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -387,6 +553,15 @@ fn test_http_to_domain_template_strategy_requires_template() {
         content_strategy: ContentStrategy::Template, // Requires template
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     assert!(
@@ -411,6 +586,15 @@ fn test_http_to_domain_default_content_strategy_with_template() {
         content_strategy: ContentStrategy::Template, // Default
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -436,6 +620,15 @@ fn test_http_to_domain_visibility_public() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -461,6 +654,15 @@ fn test_http_to_domain_visibility_private() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -486,6 +688,15 @@ fn test_http_to_domain_visibility_none() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -515,6 +726,15 @@ fn test_http_to_domain_visibility_internal() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -547,6 +767,15 @@ fn test_http_to_domain_visibility_wrong_case_rejected() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -572,6 +801,15 @@ fn test_http_to_domain_visibility_invalid() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -603,6 +841,15 @@ fn test_http_to_domain_teams_with_valid_permissions() {
         content_strategy: ContentStrategy::Empty,
         teams,
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -634,6 +881,15 @@ fn test_http_to_domain_collaborators_with_valid_permissions() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators,
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -669,6 +925,15 @@ fn test_http_to_domain_teams_invalid_permission_returns_error() {
         content_strategy: ContentStrategy::Empty,
         teams,
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -697,6 +962,15 @@ fn test_http_to_domain_collaborators_invalid_permission_returns_error() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators,
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -722,6 +996,15 @@ fn test_http_to_domain_empty_teams_and_collaborators_yields_empty_maps() {
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -756,6 +1039,15 @@ fn test_http_to_domain_teams_all_valid_access_levels() {
         content_strategy: ContentStrategy::Empty,
         teams,
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
     };
 
     let result = http_create_repository_request_to_domain(http_req, "test-actor".to_string());
@@ -771,3 +1063,151 @@ fn test_http_to_domain_teams_all_valid_access_levels() {
     );
     assert_eq!(domain_req.teams.get("t-admin"), Some(&AccessLevel::Admin));
 }
+
+/// Test that the primary team and additional owning teams are passed on in order.
+#[test]
+fn test_http_to_domain_owning_teams_in_request_order() {
+    use repo_roller_core::ContentStrategy;
+
+    let http_req = CreateRepositoryRequest {
+        organization: "myorg".to_string(),
+        name: "my-repo".to_string(),
+        template: None,
+        visibility: None,
+        team: Some("backend".to_string()),
+        repository_type: None,
+        variables: HashMap::new(),
+        content_strategy: ContentStrategy::Empty,
+        teams: HashMap::new(),
+        collaborators: HashMap::new(),
+        owning_teams: vec!["platform".to_string(), "security".to_string()],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
+    };
+
+    let domain_req = http_create_repository_request_to_domain(http_req, "test-actor".to_string())
+        .expect("Translation should succeed");
+
+    assert_eq!(
+        domain_req.owning_teams,
+        vec!["backend", "platform", "security"]
+    );
+}
+
+/// Test that the signed initial commit choice is carried into the domain request
+#[test]
+fn test_http_to_domain_signed_initial_commit() {
+    use repo_roller_core::ContentStrategy;
+
+    let make_request = |signed_initial_commit| CreateRepositoryRequest {
+        organization: "myorg".to_string(),
+        name: "my-repo".to_string(),
+        template: None,
+        visibility: None,
+        team: None,
+        repository_type: None,
+        variables: HashMap::new(),
+        content_strategy: ContentStrategy::Empty,
+        teams: HashMap::new(),
+        collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
+    };
+
+    let domain_req =
+        http_create_repository_request_to_domain(make_request(Some(true)), "actor".to_string())
+            .unwrap();
+    assert_eq!(domain_req.signed_initial_commit, Some(true));
+
+    let domain_req =
+        http_create_repository_request_to_domain(make_request(None), "actor".to_string()).unwrap();
+    assert_eq!(domain_req.signed_initial_commit, None);
+}
+
+/// Test that an explicit description and topics are carried into the domain request
+#[test]
+fn test_http_to_domain_description_and_topics() {
+    use repo_roller_core::ContentStrategy;
+
+    let http_req = CreateRepositoryRequest {
+        organization: "myorg".to_string(),
+        name: "my-repo".to_string(),
+        template: None,
+        visibility: None,
+        team: None,
+        repository_type: None,
+        variables: HashMap::new(),
+        content_strategy: ContentStrategy::Empty,
+        teams: HashMap::new(),
+        collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: Some("Billing service".to_string()),
+        topics: vec!["rust".to_string(), "billing".to_string()],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: None,
+    };
+
+    let domain_req = http_create_repository_request_to_domain(http_req, "actor".to_string())
+        .expect("Translation should succeed");
+
+    assert_eq!(domain_req.description.as_deref(), Some("Billing service"));
+    assert_eq!(domain_req.topics, vec!["rust", "billing"]);
+}
+
+/// Test that the owner type is parsed, defaulting to an organization
+#[test]
+fn test_http_to_domain_owner_type() {
+    use repo_roller_core::ContentStrategy;
+
+    let make_request = |owner_type: Option<&str>| CreateRepositoryRequest {
+        organization: "octocat".to_string(),
+        name: "my-repo".to_string(),
+        template: None,
+        visibility: None,
+        team: None,
+        repository_type: None,
+        variables: HashMap::new(),
+        content_strategy: ContentStrategy::Empty,
+        teams: HashMap::new(),
+        collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
+        description: None,
+        topics: vec![],
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
+        owner_type: owner_type.map(str::to_string),
+    };
+
+    let domain_req =
+        http_create_repository_request_to_domain(make_request(Some("user")), "actor".to_string())
+            .unwrap();
+    assert_eq!(domain_req.owner_type, OwnerType::User);
+
+    let domain_req =
+        http_create_repository_request_to_domain(make_request(None), "actor".to_string()).unwrap();
+    assert_eq!(domain_req.owner_type, OwnerType::Organization);
+
+    let result =
+        http_create_repository_request_to_domain(make_request(Some("team")), "actor".to_string());
+    assert!(result.is_err());
+}
@@ -8,6 +8,8 @@
 //! - `create_cmd`: Repository creation commands from templates
 //! - `make_template_cmd`: Scaffold a Git repository as a RepoRoller template
 //! - `org_settings_cmd`: Organization settings inspection commands
+//! - `preview_cmd`: Print the files a template renders
+//! - `render_cmd`: Render a template into a local directory
 //! - `template_cmd`: Template inspection and validation commands
 
 pub mod auth_cmd;
@@ -15,4 +17,6 @@ pub mod config_cmd;
 pub mod create_cmd;
 pub mod make_template_cmd;
 pub mod org_settings_cmd;
+pub mod preview_cmd;
+pub mod render_cmd;
 pub mod template_cmd;
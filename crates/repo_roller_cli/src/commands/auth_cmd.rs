@@ -15,6 +15,7 @@
 //! in configuration files to maintain security.
 
 use clap::Subcommand;
+use github_client::GitHubClient;
 use keyring::Entry;
 use std::path::PathBuf;
 use tracing::{debug, error, info, instrument};
@@ -54,6 +55,20 @@ pub enum AuthCommands {
         #[arg(default_value = "token")]
         method: String,
     },
+
+    /// Show what the configured GitHub App can see.
+    ///
+    /// Prints the app's identity and granted permissions, the accounts it is
+    /// installed on and, when an organization is given, the repositories the
+    /// app can access in that organization and the installation's remaining
+    /// API rate limits. Use this to diagnose "app not installed", permission,
+    /// or rate-limit failures.
+    #[command(name = "status")]
+    Status {
+        /// Organization to list the app's accessible repositories and rate limits for.
+        #[arg(long)]
+        org: Option<String>,
+    },
 }
 
 /// Executes the specified authentication command.
@@ -81,7 +96,178 @@ pub enum AuthCommands {
 pub async fn execute(cmd: &AuthCommands) -> Result<(), Error> {
     match cmd {
         AuthCommands::GitHub { method } => auth_github(method).await,
+        AuthCommands::Status { org } => auth_status(org.as_deref()).await,
+    }
+}
+
+/// Prints diagnostics about what the configured GitHub App can access.
+///
+/// Loads the GitHub App credentials from the system keyring and reports the
+/// app's metadata, its installations and, if `org` is given, the repositories
+/// the app can access in that organization and the remaining API rate limits
+/// of the app's installation on it.
+///
+/// # Arguments
+///
+/// * `org` - Optional organization to list accessible repositories for
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - GitHub App credentials are not configured in the keyring
+/// - The private key file cannot be read
+/// - GitHub rejects the app credentials or an API call fails
+#[instrument]
+async fn auth_status(org: Option<&str>) -> Result<(), Error> {
+    let (app_id, private_key) = load_app_credentials()?;
+
+    let octocrab = github_client::create_app_client(app_id, &private_key)
+        .await
+        .map_err(|e| Error::Auth(format!("Failed to create GitHub App client: {}", e)))?;
+    let client = GitHubClient::new(octocrab);
+
+    let app = client
+        .get_app_metadata()
+        .await
+        .map_err(|e| Error::GitHub(format!("Failed to get GitHub App metadata: {}", e)))?;
+
+    println!("GitHub App");
+    println!("----------");
+    println!("  Name: {}", app.name);
+    println!("  Slug: {}", app.slug);
+    println!("  ID: {}", app.id);
+    if let Some(owner) = &app.owner {
+        println!("  Owner: {}", owner.login);
+    }
+
+    let mut permissions: Vec<_> = app.permissions.iter().collect();
+    permissions.sort();
+    println!("  Permissions:");
+    if permissions.is_empty() {
+        println!("    (none)");
+    }
+    for (permission, access) in permissions {
+        println!("    {}: {}", permission, access);
+    }
+
+    let installations = client
+        .list_installations()
+        .await
+        .map_err(|e| Error::GitHub(format!("Failed to list app installations: {}", e)))?;
+
+    println!();
+    println!("Installations ({})", installations.len());
+    println!("-------------");
+    for installation in &installations {
+        println!(
+            "  {} ({}, repositories: {})",
+            installation.account.login,
+            installation.account.account_type,
+            installation
+                .repository_selection
+                .as_deref()
+                .unwrap_or("unknown")
+        );
+    }
+
+    if let Some(org) = org {
+        let repositories = client
+            .list_accessible_repositories(org)
+            .await
+            .map_err(|e| {
+                Error::GitHub(format!(
+                    "Failed to list repositories accessible in '{}': {}",
+                    org, e
+                ))
+            })?;
+
+        println!();
+        println!(
+            "Repositories accessible in '{}' ({})",
+            org,
+            repositories.len()
+        );
+        println!("-----------------------");
+        for repository in &repositories {
+            println!("  {}", repository.name());
+        }
+
+        let token = client
+            .get_installation_token_for_org(org)
+            .await
+            .map_err(|e| {
+                Error::GitHub(format!(
+                    "Failed to get installation token for '{}': {}",
+                    org, e
+                ))
+            })?;
+        let installation_client = github_client::create_github_client(&token, None)
+            .map_err(|e| Error::GitHub(format!("Failed to create GitHub client: {}", e)))?;
+        let rate_limit = installation_client
+            .get_rate_limit()
+            .await
+            .map_err(|e| Error::GitHub(format!("Failed to get rate limit status: {}", e)))?;
+
+        println!();
+        println!("Rate limits for '{}'", org);
+        println!("---------------");
+        println!("  {}", format_rate_limit("Core", &rate_limit.core));
+        println!("  {}", format_rate_limit("Search", &rate_limit.search));
+        if let Some(graphql) = &rate_limit.graphql {
+            println!("  {}", format_rate_limit("GraphQL", graphql));
+        }
     }
+
+    Ok(())
+}
+
+/// Formats a rate-limit resource as a single line, e.g.
+/// `Core: 4750/5000 remaining, resets at 2023-08-09 14:29:23 UTC`.
+fn format_rate_limit(name: &str, resource: &github_client::RateLimitResource) -> String {
+    let reset = i64::try_from(resource.reset)
+        .ok()
+        .and_then(|reset| chrono::DateTime::from_timestamp(reset, 0))
+        .map(|reset| reset.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| resource.reset.to_string());
+    format!(
+        "{}: {}/{} remaining, resets at {}",
+        name, resource.remaining, resource.limit, reset
+    )
+}
+
+/// Loads the GitHub App ID and private key from the system keyring.
+///
+/// # Errors
+///
+/// Returns `Error::Auth` if the credentials are not configured or the private
+/// key file cannot be read.
+fn load_app_credentials() -> Result<(u64, String), Error> {
+    let app_id_entry = Entry::new(KEY_RING_SERVICE_NAME, KEY_RING_APP_ID)
+        .map_err(|e| Error::Auth(format!("Failed to access keyring for app ID: {}", e)))?;
+
+    let app_id_str = app_id_entry
+        .get_password()
+        .map_err(|e| Error::Auth(format!("Failed to get app ID from keyring: {}. Run 'repo-roller auth github app' to configure GitHub App credentials.", e)))?;
+
+    let app_id: u64 = app_id_str
+        .parse()
+        .map_err(|e| Error::Auth(format!("Invalid app ID format: {}", e)))?;
+
+    let key_path_entry = Entry::new(KEY_RING_SERVICE_NAME, KEY_RING_APP_PRIVATE_KEY_PATH)
+        .map_err(|e| Error::Auth(format!("Failed to access keyring for key path: {}", e)))?;
+
+    let key_path = key_path_entry
+        .get_password()
+        .map_err(|e| Error::Auth(format!("Failed to get key path from keyring: {}. Run 'repo-roller auth github app' to configure GitHub App credentials.", e)))?;
+
+    let private_key = std::fs::read_to_string(&key_path).map_err(|e| {
+        Error::Auth(format!(
+            "Failed to read private key from {}: {}",
+            key_path, e
+        ))
+    })?;
+
+    Ok((app_id, private_key))
 }
 
 /// Configures GitHub authentication based on the specified method.
@@ -4,13 +4,16 @@
 //! - Initializing new configuration files
 //! - Validating existing configuration syntax
 //! - Getting and setting configuration values
+//! - Comparing the effective organization configuration between two metadata commits
 //!
 //! Configuration files are stored in TOML format and contain settings for
 //! templates, authentication methods, and other application preferences.
 
 use clap::Subcommand;
+use config_manager::{ConfigurationContext, ConfigurationDiff};
 use tracing::{debug, error, info, instrument};
 
+use crate::commands::org_settings_cmd::create_settings_manager_at_ref;
 use crate::config::{get_config_path, AppConfig};
 use crate::errors::Error;
 
@@ -74,6 +77,39 @@ pub enum ConfigCommands {
         /// New value to assign to the specified key.
         value: String,
     },
+
+    /// Show how the effective configuration changes between two metadata commits.
+    ///
+    /// Resolves the merged configuration for the given scenario at two refs of
+    /// the organization's metadata repository and lists every setting whose
+    /// effective value or override policy differs.
+    Diff {
+        /// Organization name.
+        #[arg(long)]
+        org: String,
+
+        /// Template name to resolve the configuration for.
+        #[arg(long)]
+        template: String,
+
+        /// Team name (optional).
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Repository type (optional).
+        #[arg(long)]
+        repo_type: Option<String>,
+
+        /// Metadata repository ref (branch, tag or commit SHA) to compare from.
+        from: String,
+
+        /// Metadata repository ref (branch, tag or commit SHA) to compare to.
+        to: String,
+
+        /// Output format (json or pretty).
+        #[arg(long, default_value = "pretty")]
+        format: String,
+    },
 }
 
 /// Executes the specified configuration command.
@@ -103,7 +139,128 @@ pub async fn execute(cmd: &ConfigCommands) -> Result<(), Error> {
         ConfigCommands::Validate { path } => validate_config(path.as_deref()),
         ConfigCommands::Get { path, key } => get_config(path.as_deref(), key.as_deref()),
         ConfigCommands::Set { path, key, value } => set_config(path.as_deref(), key, value),
+        ConfigCommands::Diff {
+            org,
+            template,
+            team,
+            repo_type,
+            from,
+            to,
+            format,
+        } => {
+            diff_config(
+                org,
+                template,
+                team.as_deref(),
+                repo_type.as_deref(),
+                from,
+                to,
+                format,
+            )
+            .await
+        }
+    }
+}
+
+/// Compares the effective configuration resolved at two metadata repository refs.
+///
+/// # Arguments
+///
+/// * `org` - Organization name
+/// * `template` - Template name
+/// * `team` - Optional team name
+/// * `repo_type` - Optional repository type
+/// * `from` - Metadata repository ref to compare from
+/// * `to` - Metadata repository ref to compare to
+/// * `format` - Output format ("json" or "pretty")
+#[instrument]
+async fn diff_config(
+    org: &str,
+    template: &str,
+    team: Option<&str>,
+    repo_type: Option<&str>,
+    from: &str,
+    to: &str,
+    format: &str,
+) -> Result<(), Error> {
+    if format != "json" && format != "pretty" {
+        return Err(Error::InvalidArguments(format!(
+            "Invalid format '{}', must be 'json' or 'pretty'",
+            format
+        )));
+    }
+
+    let mut context = ConfigurationContext::new(org, template);
+    if let Some(t) = team {
+        context = context.with_team(t);
+    }
+    if let Some(rt) = repo_type {
+        context = context.with_repository_type(rt);
     }
+
+    let mut resolved = Vec::with_capacity(2);
+    for git_ref in [from, to] {
+        debug!(
+            message = "Resolving configuration",
+            org = org,
+            git_ref = git_ref
+        );
+        let manager = create_settings_manager_at_ref(Some(git_ref)).await?;
+        let merged = manager.resolve_configuration(&context).await.map_err(|e| {
+            Error::Config(format!(
+                "Failed to resolve configuration at '{}': {}",
+                git_ref, e
+            ))
+        })?;
+        resolved.push(merged);
+    }
+
+    let diff = config_manager::diff_configurations(&resolved[0], &resolved[1]);
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&diff)
+            .map_err(|e| Error::Config(format!("Failed to serialize to JSON: {}", e)))?;
+        println!("{}", json);
+    } else {
+        println!("{}", format_diff(&diff, from, to));
+    }
+
+    Ok(())
+}
+
+/// Formats a configuration diff as one line per changed setting.
+fn format_diff(diff: &ConfigurationDiff, from: &str, to: &str) -> String {
+    if diff.is_empty() {
+        return format!(
+            "No effective configuration changes between {} and {}",
+            from, to
+        );
+    }
+
+    let describe = |value: &Option<serde_json::Value>| match value {
+        Some(v) => v.to_string(),
+        None => "<unset>".to_string(),
+    };
+
+    let mut lines = vec![format!(
+        "{} setting(s) changed between {} and {}:",
+        diff.changes.len(),
+        from,
+        to
+    )];
+    for change in &diff.changes {
+        let mut line = format!(
+            "  {}: {} -> {}",
+            change.field_path,
+            describe(&change.old_value),
+            describe(&change.new_value)
+        );
+        if let Some(source) = change.new_source.or(change.old_source) {
+            line.push_str(&format!(" ({})", source));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
 }
 
 /// Creates a new configuration file with default values.
@@ -372,3 +529,7 @@ fn set_config_value(config: &mut AppConfig, key: &str, value: &str) -> Result<()
         ))),
     }
 }
+
+#[cfg(test)]
+#[path = "config_cmd_tests.rs"]
+mod tests;
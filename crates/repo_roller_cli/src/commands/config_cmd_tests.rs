@@ -0,0 +1,76 @@
+//! Tests for configuration commands.
+
+use super::*;
+use config_manager::{ConfigurationChange, ConfigurationSource};
+use serde_json::json;
+
+/// Verify Diff command can be constructed with required arguments.
+#[test]
+fn test_diff_command_construction() {
+    let cmd = ConfigCommands::Diff {
+        org: "test-org".to_string(),
+        template: "rust-lib".to_string(),
+        team: None,
+        repo_type: Some("library".to_string()),
+        from: "main".to_string(),
+        to: "abc123".to_string(),
+        format: "pretty".to_string(),
+    };
+
+    match cmd {
+        ConfigCommands::Diff {
+            from,
+            to,
+            repo_type,
+            ..
+        } => {
+            assert_eq!(from, "main");
+            assert_eq!(to, "abc123");
+            assert_eq!(repo_type.as_deref(), Some("library"));
+        }
+        _ => panic!("Expected Diff command"),
+    }
+}
+
+/// Verify an empty diff is reported as having no changes.
+#[test]
+fn test_format_diff_empty() {
+    let output = format_diff(&ConfigurationDiff::default(), "v1", "v2");
+    assert_eq!(
+        output,
+        "No effective configuration changes between v1 and v2"
+    );
+}
+
+/// Verify changed settings are listed with old value, new value and source.
+#[test]
+fn test_format_diff_lists_changes() {
+    let diff = ConfigurationDiff {
+        changes: vec![
+            ConfigurationChange {
+                field_path: "branch_protection.require_pull_request_reviews.override_allowed"
+                    .to_string(),
+                old_value: Some(json!(true)),
+                new_value: Some(json!(false)),
+                old_source: Some(ConfigurationSource::Global),
+                new_source: Some(ConfigurationSource::Global),
+            },
+            ConfigurationChange {
+                field_path: "repository.wiki.value".to_string(),
+                old_value: Some(json!(true)),
+                new_value: None,
+                old_source: None,
+                new_source: None,
+            },
+        ],
+    };
+
+    let output = format_diff(&diff, "v1", "v2");
+
+    assert_eq!(
+        output,
+        "2 setting(s) changed between v1 and v2:\n  \
+         branch_protection.require_pull_request_reviews.override_allowed: true -> false (Global)\n  \
+         repository.wiki.value: true -> <unset>"
+    );
+}
@@ -9,24 +9,27 @@
 //!
 //! - Configuration file support (TOML format)
 //! - Interactive prompting for missing required values
+//! - Interactive prompting for template variables, grouped as the template declares
+//! - Template variable values from `--var` flags and a JSON or TOML `--vars-file`
 //! - Organization-specific repository naming rules
 //! - GitHub App and Personal Access Token authentication
 //! - Template-based repository creation
 //!
 use crate::{
     commands::auth_cmd::{KEY_RING_APP_ID, KEY_RING_APP_PRIVATE_KEY_PATH, KEY_RING_SERVICE_NAME},
-    config::{get_config_path, AppConfig},
+    config::{get_config_path, parse_config_file, read_config_file, AppConfig},
     errors::Error,
 };
 use auth_handler::UserAuthenticationService;
 use clap::Args;
+use config_manager::{MetadataRepositoryProvider, TemplateConfig, TemplateVariable};
 use keyring::Entry;
 use repo_roller_core::{
-    permissions::AccessLevel, ContentStrategy, OrganizationName, RepoRollerResult,
+    permissions::AccessLevel, ContentStrategy, OrganizationName, OwnerType, RepoRollerResult,
     RepositoryCreationRequest, RepositoryCreationRequestBuilder, RepositoryCreationResult,
-    RepositoryName, TemplateName,
+    RepositoryName, TemplateName, ValidationError,
 };
-use std::{collections::HashMap, fs, future::Future};
+use std::{collections::HashMap, fs, future::Future, io::Write, path::Path};
 use tracing::{debug, error, info};
 
 #[cfg(test)]
@@ -54,34 +57,30 @@ mod create_cmd_tests;
 /// # Errors
 ///
 /// This function will return an error if:
-/// - The configuration file cannot be read
-/// - The configuration file contains invalid TOML
-/// - The TOML structure cannot be parsed
+/// - The configuration file does not exist (`Error::ConfigFileNotFound`)
+/// - The configuration file cannot be read (`Error::Config`)
+/// - The configuration file contains invalid TOML (`Error::ConfigParse`)
 fn load_cli_config(config_path: &str) -> Result<(String, String, String), Error> {
-    match fs::read_to_string(config_path) {
-        Ok(contents) => match toml::from_str::<toml::Table>(&contents) {
-            Ok(table) => {
-                let name = table
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                let owner = table
-                    .get("owner")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                let template = table
-                    .get("template")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                Ok((name, owner, template))
-            }
-            Err(e) => Err(Error::ParseTomlFile(e)),
-        },
-        Err(e) => Err(Error::LoadFile(e)),
-    }
+    let path = Path::new(config_path);
+    let contents = read_config_file(path)?;
+    let table: toml::Table = parse_config_file(path, &contents)?;
+
+    let name = table
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let owner = table
+        .get("owner")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let template = table
+        .get("template")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    Ok((name, owner, template))
 }
 
 /// Command-line arguments for the create command.
@@ -112,6 +111,14 @@ pub struct CreateArgs {
     #[arg(long)]
     pub owner: Option<String>,
 
+    /// Kind of account named by --owner: `org` or `user`.
+    ///
+    /// With `user`, the repository is created in the personal account the
+    /// GitHub App is installed on, and organization-only steps such as team
+    /// grants and custom properties are skipped.
+    #[arg(long, default_value = "org")]
+    pub owner_type: OwnerType,
+
     /// Template type to use for repository creation.
     ///
     /// Specifies which template should be used as the basis for the
@@ -163,17 +170,125 @@ pub struct CreateArgs {
     /// `--collaborator alice:write --collaborator bob:read`
     #[arg(long = "collaborator", value_name = "USERNAME:PERMISSION")]
     pub collaborators: Vec<String>,
+
+    /// Set a template variable (repeatable).
+    ///
+    /// Format: `NAME=VALUE`. Overrides the same variable from --vars-file.
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub variables: Vec<String>,
+
+    /// Path to a JSON or TOML file mapping template variable names to values.
+    ///
+    /// Files ending in `.json` are read as JSON, anything else as TOML.
+    /// Values must be strings, numbers or booleans.
+    #[arg(long, value_name = "PATH")]
+    pub vars_file: Option<String>,
+}
+
+/// Loads template variable values from a JSON or TOML file.
+///
+/// The file holds a single flat map of variable names to values. Numbers and
+/// booleans are converted to their text; the template's `var_type` decides
+/// whether that text is valid when the template is rendered.
+///
+/// # Errors
+///
+/// * `Error::ConfigFileNotFound` - the file does not exist
+/// * `Error::ConfigParse` - the file is not valid TOML
+/// * `Error::Config` - the file is not valid JSON, is not a map, or holds a
+///   value that is not a string, number or boolean
+pub(crate) fn load_variables_file(vars_path: &str) -> Result<HashMap<String, String>, Error> {
+    let path = Path::new(vars_path);
+    let contents = read_config_file(path)?;
+
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let entries: Vec<(String, Option<String>)> = if is_json {
+        let value: serde_json::Value = serde_json::from_str(&contents).map_err(|e| {
+            Error::Config(format!(
+                "Variables file '{}' is not valid JSON: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let serde_json::Value::Object(map) = value else {
+            return Err(Error::Config(format!(
+                "Variables file '{}' must contain a JSON object",
+                path.display()
+            )));
+        };
+        map.into_iter()
+            .map(|(name, value)| {
+                let text = match value {
+                    serde_json::Value::String(s) => Some(s),
+                    serde_json::Value::Number(n) => Some(n.to_string()),
+                    serde_json::Value::Bool(b) => Some(b.to_string()),
+                    _ => None,
+                };
+                (name, text)
+            })
+            .collect()
+    } else {
+        let table: toml::Table = parse_config_file(path, &contents)?;
+        table
+            .into_iter()
+            .map(|(name, value)| {
+                let text = match value {
+                    toml::Value::String(s) => Some(s),
+                    toml::Value::Array(_) | toml::Value::Table(_) => None,
+                    other => Some(other.to_string()),
+                };
+                (name, text)
+            })
+            .collect()
+    };
+
+    entries
+        .into_iter()
+        .map(|(name, text)| match text {
+            Some(text) => Ok((name, text)),
+            None => Err(Error::Config(format!(
+                "Variable '{}' in '{}' must be a string, number or boolean",
+                name,
+                path.display()
+            ))),
+        })
+        .collect()
+}
+
+/// Parses `--var NAME=VALUE` entries.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidArguments` for an entry without `=` or with an
+/// empty name.
+fn parse_variable_args(entries: &[String]) -> Result<HashMap<String, String>, Error> {
+    entries
+        .iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                Ok((name.trim().to_string(), value.to_string()))
+            }
+            _ => Err(Error::InvalidArguments(format!(
+                "Invalid --var format '{}'. Expected NAME=VALUE (e.g. service_name=billing)",
+                entry
+            ))),
+        })
+        .collect()
 }
 
 /// Creates a repository using the default application configuration and authentication.
 ///
 /// This function loads the application configuration from the default path,
-/// retrieves authentication credentials from the system keyring, and delegates
-/// to the core repository creation logic.
+/// retrieves authentication credentials from the system keyring, prompts for
+/// the template variables the request does not set, and delegates to the core
+/// repository creation logic.
 ///
 /// # Arguments
 ///
 /// * `request` - The repository creation request containing name, owner, and template
+/// * `ask_user_for_value` - Function to prompt the user for template variable values
 ///
 /// # Returns
 ///
@@ -185,9 +300,13 @@ pub struct CreateArgs {
 /// - The application configuration cannot be loaded
 /// - Authentication credentials cannot be retrieved from the keyring
 /// - The core repository creation process fails
-pub async fn create_repository(
-    request: RepositoryCreationRequest,
-) -> RepoRollerResult<RepositoryCreationResult> {
+pub async fn create_repository<AskFn>(
+    mut request: RepositoryCreationRequest,
+    ask_user_for_value: AskFn,
+) -> RepoRollerResult<RepositoryCreationResult>
+where
+    AskFn: Fn(&str) -> Result<String, Error> + Send + Sync,
+{
     let path = get_config_path(None);
     let config = match AppConfig::load(&path) {
         Ok(c) => c,
@@ -244,6 +363,32 @@ pub async fn create_repository(
         ),
     ));
 
+    // Prompt for template variables. A template that cannot be loaded is
+    // reported by the core creation logic.
+    if let Some(template_name) = &request.template {
+        match metadata_provider
+            .load_template_configuration(request.owner.as_ref(), template_name.as_ref())
+            .await
+        {
+            Ok(template_config) => {
+                request.variables = prompt_for_template_variables(
+                    &template_config,
+                    &request.variables,
+                    &mut std::io::stdout(),
+                    &ask_user_for_value,
+                )
+                .map_err(|e| {
+                    repo_roller_core::RepoRollerError::System(
+                        repo_roller_core::SystemError::Internal {
+                            reason: format!("Failed to read template variables: {}", e),
+                        },
+                    )
+                })?;
+            }
+            Err(e) => debug!("Template configuration not available for prompting: {}", e),
+        }
+    }
+
     // Create visibility providers
     let visibility_policy_provider = std::sync::Arc::new(
         config_manager::ConfigBasedPolicyProvider::new(metadata_provider.clone()),
@@ -274,6 +419,7 @@ pub async fn create_repository(
         visibility_policy_provider,
         environment_detector,
         event_context,
+        repo_roller_core::CreationDependencies::default(),
     )
     .await
 }
@@ -356,6 +502,92 @@ async fn get_authentication_tokens(config: &AppConfig) -> Result<(u64, String),
     Ok(provider)
 }
 
+/// Prompts for the template variables that have no value yet, group by group.
+///
+/// Groups come from [`TemplateConfig::variable_prompt_groups`]; each one is
+/// introduced by a header written to `out`. Variables already present in
+/// `provided` are not prompted, and a group with nothing left to prompt gets
+/// no header. An empty answer leaves the variable unset so that its default
+/// applies, except for a required variable without a default, which is asked
+/// again.
+///
+/// # Returns
+///
+/// The provided values together with the answers.
+///
+/// # Errors
+///
+/// Returns `Error::StdOutFlushFailed` if a header cannot be written, or the
+/// error returned by `ask_user_for_value`.
+pub fn prompt_for_template_variables<AskFn>(
+    template: &TemplateConfig,
+    provided: &HashMap<String, String>,
+    out: &mut dyn Write,
+    ask_user_for_value: AskFn,
+) -> Result<HashMap<String, String>, Error>
+where
+    AskFn: Fn(&str) -> Result<String, Error>,
+{
+    let mut values = provided.clone();
+    let Some(definitions) = &template.variables else {
+        return Ok(values);
+    };
+
+    for group in template.variable_prompt_groups() {
+        let pending: Vec<&String> = group
+            .variables
+            .iter()
+            .filter(|name| !values.contains_key(*name))
+            .collect();
+        if pending.is_empty() {
+            continue;
+        }
+
+        writeln!(out, "\n{}\n{}", group.name, "-".repeat(group.name.len()))
+            .map_err(|_| Error::StdOutFlushFailed)?;
+        for name in pending {
+            let variable = &definitions[name];
+            let prompt = variable_prompt(name, variable);
+            loop {
+                let answer = ask_user_for_value(&prompt)?;
+                if !answer.is_empty() {
+                    values.insert(name.clone(), answer);
+                    break;
+                }
+                if variable.default.is_some() || !variable.required.unwrap_or(false) {
+                    break;
+                }
+                println!("  Error: {} is required.", name);
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Builds the prompt for a template variable, e.g.
+/// `service_port (Port to listen on, default: 8080): `.
+fn variable_prompt(name: &str, variable: &TemplateVariable) -> String {
+    let detail = match (&variable.default, variable.required.unwrap_or(false)) {
+        (Some(default), _) => format!(", default: {}", default),
+        (None, true) => ", required".to_string(),
+        (None, false) => ", optional".to_string(),
+    };
+    format!("{} ({}{}): ", name, variable.description, detail)
+}
+
+/// Formats a name validation error, adding the suggested name when the
+/// rejected value is close to a valid one.
+fn describe_name_error(kind: &str, value: &str, error: &ValidationError) -> String {
+    match error.suggestion() {
+        Some(suggestion) => format!(
+            "Invalid {} '{}': {}. Did you mean '{}'?",
+            kind, value, error, suggestion
+        ),
+        None => format!("Invalid {} '{}': {}", kind, value, error),
+    }
+}
+
 /// Handles the complete repository creation workflow.
 ///
 /// This function orchestrates the entire repository creation process by:
@@ -461,7 +693,7 @@ where
 
     // Build request with validated branded types
     let name = RepositoryName::new(&final_name).map_err(|e| {
-        Error::InvalidArguments(format!("Invalid repository name '{}': {}", final_name, e))
+        Error::InvalidArguments(describe_name_error("repository name", &final_name, &e))
     })?;
 
     let owner = OrganizationName::new(&final_owner).map_err(|e| {
@@ -472,12 +704,13 @@ where
     })?;
 
     // Build request based on flags
-    let mut builder = RepositoryCreationRequestBuilder::new(name, owner);
+    let mut builder =
+        RepositoryCreationRequestBuilder::new(name, owner).owner_type(options.owner_type);
 
     // Add template if provided
     if !final_template.is_empty() {
         let template = TemplateName::new(&final_template).map_err(|e| {
-            Error::InvalidArguments(format!("Invalid template name '{}': {}", final_template, e))
+            Error::InvalidArguments(describe_name_error("template name", &final_template, &e))
         })?;
         builder = builder.template(template);
     }
@@ -551,6 +784,16 @@ where
 
     builder = builder.teams(teams).collaborators(collaborators);
 
+    // Template variables: values from --vars-file, overridden by --var flags.
+    let mut variables = match options.vars_file {
+        Some(vars_path) => load_variables_file(vars_path)?,
+        None => HashMap::new(),
+    };
+    variables.extend(parse_variable_args(options.variables)?);
+    if !variables.is_empty() {
+        builder = builder.variables(variables);
+    }
+
     // CLI acts as the "reporoller-cli" service actor. Determining the
     // specific human user behind an installation token requires an additional
     // GitHub API call (/user) which is not yet wired for the CLI path.
@@ -588,6 +831,12 @@ pub struct CreateCommandOptions<'a> {
     pub teams: &'a [String],
     /// Username → permission string pairs provided via `--collaborator USERNAME:PERMISSION` flags.
     pub collaborators: &'a [String],
+    /// Whether the owner is an organization or a personal account.
+    pub owner_type: OwnerType,
+    /// Path to a JSON or TOML file with template variable values.
+    pub vars_file: &'a Option<String>,
+    /// `NAME=VALUE` template variables provided via `--var` flags.
+    pub variables: &'a [String],
 }
 
 impl<'a> CreateCommandOptions<'a> {
@@ -614,6 +863,28 @@ impl<'a> CreateCommandOptions<'a> {
             init_gitignore,
             teams,
             collaborators,
+            owner_type: OwnerType::Organization,
+            vars_file: &None,
+            variables: &[],
         }
     }
+
+    /// Sets whether the owner is an organization or a personal account.
+    pub fn with_owner_type(mut self, owner_type: OwnerType) -> Self {
+        self.owner_type = owner_type;
+        self
+    }
+
+    /// Sets the template variables from `--vars-file` and `--var` flags.
+    ///
+    /// `--var` values override values for the same variable from the file.
+    pub fn with_variables(
+        mut self,
+        vars_file: &'a Option<String>,
+        variables: &'a [String],
+    ) -> Self {
+        self.vars_file = vars_file;
+        self.variables = variables;
+        self
+    }
 }
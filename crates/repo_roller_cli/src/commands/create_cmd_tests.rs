@@ -53,6 +53,10 @@ fn make_logged_create_repo_success(
                 repository_id: "test-id-123".to_string(),
                 created_at: Timestamp::now(),
                 default_branch: "main".to_string(),
+                warnings: vec![],
+                verification: None,
+                configuration_audit: None,
+                template_commit_sha: None,
             })
         })
     }
@@ -100,7 +104,7 @@ async fn test_cli_config_invalid_toml() {
     let options =
         CreateCommandOptions::new(&path, &None, &None, &None, false, false, false, &[], &[]);
     let result = handle_create_command(options, ask, create_repository).await;
-    assert!(matches!(result, Err(Error::ParseTomlFile(_))));
+    assert!(matches!(result, Err(Error::ConfigParse { line: 1, .. })));
 }
 
 #[tokio::test]
@@ -121,7 +125,9 @@ async fn test_cli_config_missing() {
         &[],
     );
     let result = handle_create_command(options, ask, create_repository).await;
-    assert!(matches!(result, Err(Error::LoadFile(_))));
+    assert!(
+        matches!(result, Err(Error::ConfigFileNotFound { ref path }) if path == "nonexistent.toml")
+    );
 }
 
 #[tokio::test]
@@ -262,8 +268,18 @@ async fn test_load_cli_config_invalid_file() {
     let result = load_cli_config(path);
 
     assert!(result.is_err());
-    // The error should be a ParseTomlFile error from TOML parsing
-    assert!(matches!(result.unwrap_err(), Error::ParseTomlFile(_)));
+    // The error should name the file and the location of the syntax error
+    match result.unwrap_err() {
+        Error::ConfigParse {
+            path: error_path,
+            line,
+            ..
+        } => {
+            assert_eq!(error_path, path);
+            assert_eq!(line, 1);
+        }
+        other => panic!("Expected ConfigParse error, got {:?}", other),
+    }
 }
 
 #[tokio::test]
@@ -271,8 +287,10 @@ async fn test_load_cli_config_missing_file() {
     let result = load_cli_config("nonexistent_file.toml");
 
     assert!(result.is_err());
-    // The error should be a LoadFile error
-    assert!(matches!(result.unwrap_err(), Error::LoadFile(_)));
+    // The error should be a ConfigFileNotFound error naming the file
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::ConfigFileNotFound { .. }));
+    assert!(err.to_string().contains("nonexistent_file.toml"));
 }
 
 #[tokio::test]
@@ -1015,3 +1033,284 @@ async fn test_team_slug_with_colon_splits_on_first_colon() {
         "Expected Err for permission 'write:extra', got Ok"
     );
 }
+
+/// Test that --owner-type user is carried into the creation request.
+#[tokio::test]
+async fn test_owner_type_user_passes_to_request() {
+    let ask = make_ask_user_for_value;
+    let log = Arc::new(Mutex::new(CallLog::new()));
+    let create_repo = make_logged_create_repo_success(log.clone());
+
+    let repo_name = Some("my-repo".to_string());
+    let owner = Some("octocat".to_string());
+
+    let options = CreateCommandOptions::new(
+        &None,
+        &repo_name,
+        &owner,
+        &None,
+        true,
+        false,
+        false,
+        &[],
+        &[],
+    )
+    .with_owner_type(OwnerType::User);
+
+    let result = handle_create_command(options, ask, create_repo).await;
+
+    assert!(result.is_ok(), "Expected Ok but got: {:?}", result.err());
+    let log = log.lock().unwrap();
+    assert_eq!(log.create_repository_args[0].owner_type, OwnerType::User);
+}
+
+#[test]
+fn test_describe_name_error_includes_suggestion() {
+    let error = RepositoryName::new("My Repo").unwrap_err();
+
+    let message = describe_name_error("repository name", "My Repo", &error);
+
+    assert!(message.starts_with("Invalid repository name 'My Repo': "));
+    assert!(message.ends_with("Did you mean 'my-repo'?"), "{message}");
+}
+
+#[test]
+fn test_describe_name_error_without_suggestion() {
+    let error = RepositoryName::new("my@repo").unwrap_err();
+
+    let message = describe_name_error("repository name", "my@repo", &error);
+
+    assert!(!message.contains("Did you mean"), "{message}");
+}
+
+// =============================================================================
+// Template Variable Prompting Tests
+// =============================================================================
+
+/// Collects everything written to it, one entry per non-empty line.
+struct TranscriptWriter<'a>(&'a std::cell::RefCell<Vec<String>>);
+
+impl Write for TranscriptWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let text = String::from_utf8_lossy(buf);
+        self.0.borrow_mut().extend(
+            text.lines()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Verify that grouped variables are prompted in declared order under their
+/// headers, followed by the ungrouped variables in the default group.
+#[test]
+fn test_prompt_for_template_variables_prompts_group_by_group() {
+    let template: TemplateConfig = toml::from_str(
+        r#"
+        [template]
+        name = "service"
+        description = "Service template"
+        author = "Platform Team"
+        tags = []
+
+        [variables.service_name]
+        description = "Service name"
+        required = true
+
+        [variables.service_port]
+        description = "Port"
+        default = "8080"
+
+        [variables.owner_email]
+        description = "Owner email"
+
+        [variables.team]
+        description = "Team"
+
+        [[variable_groups]]
+        name = "Service"
+        variables = ["service_port", "service_name"]
+
+        [[variable_groups]]
+        name = "Ownership"
+        variables = ["owner_email"]
+        "#,
+    )
+    .expect("template should parse");
+    let provided = HashMap::from([("team".to_string(), "platform".to_string())]);
+
+    let transcript = std::cell::RefCell::new(Vec::new());
+    let mut out = TranscriptWriter(&transcript);
+    let ask = |prompt: &str| {
+        transcript.borrow_mut().push(prompt.to_string());
+        Ok::<_, Error>(match prompt.split(' ').next() {
+            Some("service_name") => "orders".to_string(),
+            _ => String::new(),
+        })
+    };
+
+    let values = prompt_for_template_variables(&template, &provided, &mut out, ask)
+        .expect("prompting should succeed");
+
+    assert_eq!(
+        transcript.into_inner(),
+        vec![
+            "Service",
+            "-------",
+            "service_port (Port, default: 8080): ",
+            "service_name (Service name, required): ",
+            "Ownership",
+            "---------",
+            "owner_email (Owner email, optional): ",
+        ]
+    );
+    assert_eq!(
+        values,
+        HashMap::from([
+            ("team".to_string(), "platform".to_string()),
+            ("service_name".to_string(), "orders".to_string()),
+        ])
+    );
+}
+
+// =============================================================================
+// Template Variable Tests
+// =============================================================================
+
+/// Writes `contents` to a temporary file ending in `suffix`.
+fn write_vars_file(suffix: &str, contents: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new()
+        .suffix(suffix)
+        .tempfile()
+        .expect("temp file should be created");
+    file.write_all(contents.as_bytes())
+        .expect("temp file should be writable");
+    file
+}
+
+/// Test that --var overrides a value loaded from --vars-file.
+#[tokio::test]
+async fn test_var_flag_overrides_vars_file_value() {
+    let log = Arc::new(Mutex::new(CallLog::new()));
+    let create_repo = make_logged_create_repo_success(log.clone());
+    let vars_file = write_vars_file(
+        ".toml",
+        "service_name = \"billing\"\nservice_port = 8080\nenabled = true\n",
+    );
+
+    let repo_name = Some("my-repo".to_string());
+    let org_name = Some("my-org".to_string());
+    let template = Some("rust-service".to_string());
+    let vars_path = Some(vars_file.path().to_str().unwrap().to_string());
+    let variables = vec!["service_name=orders".to_string()];
+
+    let options = CreateCommandOptions::new(
+        &None,
+        &repo_name,
+        &org_name,
+        &template,
+        false,
+        false,
+        false,
+        &[],
+        &[],
+    )
+    .with_variables(&vars_path, &variables);
+
+    let result = handle_create_command(options, make_ask_user_for_value, create_repo).await;
+
+    assert!(result.is_ok(), "Expected Ok but got: {:?}", result.err());
+    let log = log.lock().unwrap();
+    assert_eq!(
+        log.create_repository_args[0].variables,
+        HashMap::from([
+            ("service_name".to_string(), "orders".to_string()),
+            ("service_port".to_string(), "8080".to_string()),
+            ("enabled".to_string(), "true".to_string()),
+        ])
+    );
+}
+
+/// Test that a JSON variables file is read as JSON.
+#[test]
+fn test_load_variables_file_reads_json() {
+    let vars_file = write_vars_file(".json", r#"{"service_name": "billing", "replicas": 3}"#);
+
+    let variables = load_variables_file(vars_file.path().to_str().unwrap())
+        .expect("JSON variables should load");
+
+    assert_eq!(
+        variables,
+        HashMap::from([
+            ("service_name".to_string(), "billing".to_string()),
+            ("replicas".to_string(), "3".to_string()),
+        ])
+    );
+}
+
+/// Test that variables files with values that are not single values are rejected.
+#[test]
+fn test_load_variables_file_rejects_nested_values() {
+    let toml_file = write_vars_file(".toml", "ports = [80, 443]\n");
+    let json_file = write_vars_file(".json", r#"{"owner": {"team": "platform"}}"#);
+
+    for (file, name) in [(&toml_file, "ports"), (&json_file, "owner")] {
+        let result = load_variables_file(file.path().to_str().unwrap());
+        assert!(
+            matches!(&result, Err(Error::Config(msg)) if msg.contains(name)),
+            "got {:?}",
+            result
+        );
+    }
+}
+
+/// Test that an unparsable variables file is reported.
+#[test]
+fn test_load_variables_file_rejects_invalid_content() {
+    let toml_file = write_vars_file(".toml", "service_name = \n");
+    let json_file = write_vars_file(".json", "[1, 2]");
+
+    assert!(matches!(
+        load_variables_file(toml_file.path().to_str().unwrap()),
+        Err(Error::ConfigParse { .. })
+    ));
+    assert!(matches!(
+        load_variables_file(json_file.path().to_str().unwrap()),
+        Err(Error::Config(_))
+    ));
+}
+
+/// Test that a malformed --var entry is rejected.
+#[tokio::test]
+async fn test_var_flag_invalid_format_returns_error() {
+    let log = Arc::new(Mutex::new(CallLog::new()));
+    let create_repo = make_logged_create_repo_success(log.clone());
+
+    let repo_name = Some("my-repo".to_string());
+    let org_name = Some("my-org".to_string());
+    let template = Some("rust-service".to_string());
+    let variables = vec!["service_name".to_string()];
+
+    let options = CreateCommandOptions::new(
+        &None,
+        &repo_name,
+        &org_name,
+        &template,
+        false,
+        false,
+        false,
+        &[],
+        &[],
+    )
+    .with_variables(&None, &variables);
+
+    let result = handle_create_command(options, make_ask_user_for_value, create_repo).await;
+
+    assert!(matches!(result, Err(Error::InvalidArguments(msg)) if msg.contains("--var")));
+    assert!(log.lock().unwrap().create_repository_args.is_empty());
+}
@@ -301,7 +301,10 @@ async fn create_metadata_provider() -> Result<Arc<dyn MetadataRepositoryProvider
         &app_config.organization.metadata_repository_name
     };
 
-    let config = MetadataProviderConfig::explicit(metadata_repo_name);
+    let mut config = MetadataProviderConfig::explicit(metadata_repo_name);
+    if let Some(branch) = &app_config.organization.metadata_branch {
+        config = config.with_git_ref(branch);
+    }
 
     let provider = GitHubMetadataProvider::new(github_client, config);
 
@@ -313,6 +316,16 @@ async fn create_metadata_provider() -> Result<Arc<dyn MetadataRepositoryProvider
 /// This helper function creates both the metadata provider and template loader,
 /// then initializes the OrganizationSettingsManager with both dependencies.
 async fn create_settings_manager() -> Result<OrganizationSettingsManager, Error> {
+    create_settings_manager_at_ref(None).await
+}
+
+/// Create an organization settings manager that reads the metadata repository at a git ref.
+///
+/// When `git_ref` is `None` the configured `metadata_branch` is used, or the
+/// metadata repository's default branch when none is configured.
+pub(crate) async fn create_settings_manager_at_ref(
+    git_ref: Option<&str>,
+) -> Result<OrganizationSettingsManager, Error> {
     // Load application config to get metadata repository name
     let config_path = get_config_path(None);
     let app_config = AppConfig::load(&config_path).unwrap_or_else(|_| AppConfig::default());
@@ -354,7 +367,11 @@ async fn create_settings_manager() -> Result<OrganizationSettingsManager, Error>
     };
 
     let github_client = GitHubClient::new(octocrab.clone());
-    let provider_config = MetadataProviderConfig::explicit(metadata_repo_name);
+    let mut provider_config = MetadataProviderConfig::explicit(metadata_repo_name);
+    // An explicit ref replaces the configured metadata branch
+    if let Some(git_ref) = git_ref.or(app_config.organization.metadata_branch.as_deref()) {
+        provider_config = provider_config.with_git_ref(git_ref);
+    }
     let metadata_provider = GitHubMetadataProvider::new(github_client, provider_config);
     let provider_arc = Arc::new(metadata_provider) as Arc<dyn MetadataRepositoryProvider>;
 
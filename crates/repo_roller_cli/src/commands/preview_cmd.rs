@@ -0,0 +1,90 @@
+//! `preview` command: prints the files a template renders for inspection.
+//!
+//! This module implements `repo-roller preview`, which renders a template the
+//! same way as `render` but prints the files of the initial commit instead of
+//! writing them. Text files are shown with their rendered content; binary
+//! files only with their size and SHA-256 digest. Nothing is written to disk
+//! or created on GitHub.
+//!
+//! The `--template` and `--config` arguments are the same as for `render`.
+//!
+//! # Usage
+//!
+//! ```bash
+//! repo-roller preview --template ./rust-service-template --config vars.toml
+//! repo-roller preview --template my-org/rust-service --config vars.toml
+//! ```
+
+use clap::Args;
+use repo_roller_core::RenderedFilePreview;
+use std::path::Path;
+use template_engine::TemplateFileKind;
+
+use crate::commands::render_cmd::{load_render_request, resolve_template};
+use crate::errors::Error;
+
+#[cfg(test)]
+#[path = "preview_cmd_tests.rs"]
+mod tests;
+
+/// Arguments for `repo-roller preview`.
+#[derive(Args, Debug, Clone)]
+pub struct PreviewArgs {
+    /// Template to preview: a local template directory, or `owner/repo` on GitHub.
+    #[arg(long)]
+    pub template: String,
+
+    /// Path to a TOML file with the repository name, owner and `[variables]`.
+    #[arg(long)]
+    pub config: String,
+}
+
+/// Executes the `preview` command.
+///
+/// # Returns
+///
+/// The rendered files, sorted by path.
+///
+/// # Errors
+///
+/// * `Error::ConfigFileNotFound` / `Error::ConfigParse` / `Error::Config` - the
+///   variables file cannot be read or is invalid
+/// * `Error::InvalidArguments` - `--template` is neither a directory nor `owner/repo`
+/// * `Error::Auth` - GitHub credentials are needed but not available
+/// * `Error::GitHub` - the template cannot be fetched
+/// * `Error::Config` - the template fails to render
+pub async fn execute(args: &PreviewArgs) -> Result<Vec<RenderedFilePreview>, Error> {
+    let (fetcher, source, template) = resolve_template(&args.template).await?;
+    let request = load_render_request(Path::new(&args.config), &template.template.name)?;
+
+    let files = fetcher
+        .fetch_template_files(&source)
+        .await
+        .map_err(|e| Error::GitHub(format!("Failed to fetch template files: {}", e)))?;
+
+    repo_roller_core::preview_local_template(&files, &template, &request)
+        .map_err(|e| Error::Config(format!("Failed to render template: {}", e)))
+}
+
+/// Formats the rendered files for printing.
+pub(crate) fn format_preview(files: &[RenderedFilePreview]) -> String {
+    let mut output = format!("Initial commit: {} file(s)\n", files.len());
+    for file in files {
+        match (&file.kind, &file.content) {
+            (TemplateFileKind::Text, Some(content)) => {
+                output.push_str(&format!("\n=== {} ({} bytes) ===\n", file.path, file.size));
+                output.push_str(content);
+                if !content.ends_with('\n') {
+                    output.push('\n');
+                }
+            }
+            _ => {
+                output.push_str(&format!(
+                    "\n=== {} (binary, {} bytes, sha256 {}) ===\n",
+                    file.path, file.size, file.sha256
+                ));
+            }
+        }
+    }
+    output
+}
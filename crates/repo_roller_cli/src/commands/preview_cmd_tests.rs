@@ -0,0 +1,67 @@
+//! Tests for the `preview` command.
+
+use super::*;
+use tempfile::TempDir;
+
+/// Test that a local template is previewed without writing any files.
+#[tokio::test]
+async fn test_preview_local_template_returns_rendered_files() {
+    let tmp = TempDir::new().unwrap();
+    let template_dir = tmp.path().join("template");
+    std::fs::create_dir_all(template_dir.join(".reporoller")).unwrap();
+    std::fs::write(
+        template_dir.join(".reporoller/template.toml"),
+        "[template]\nname = \"service-template\"\ndescription = \"A service\"\nauthor = \"Platform Team\"\ntags = []\n",
+    )
+    .unwrap();
+    std::fs::write(template_dir.join("README.md"), "# {{repo_name}}").unwrap();
+    std::fs::write(
+        template_dir.join("logo.png"),
+        [0x89, b'P', b'N', b'G', 0x00],
+    )
+    .unwrap();
+    let config = tmp.path().join("vars.toml");
+    std::fs::write(&config, "name = \"payments\"\nowner = \"my-org\"\n").unwrap();
+
+    let files = execute(&PreviewArgs {
+        template: template_dir.to_string_lossy().to_string(),
+        config: config.to_string_lossy().to_string(),
+    })
+    .await
+    .expect("preview should succeed");
+
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["README.md", "logo.png"]);
+    assert_eq!(files[0].content.as_deref(), Some("# payments"));
+    assert_eq!(files[1].content, None);
+}
+
+/// Test that binary files are summarized rather than printed.
+#[test]
+fn test_format_preview_summarizes_binary_files() {
+    let files = vec![
+        RenderedFilePreview {
+            path: "README.md".to_string(),
+            size: 10,
+            kind: TemplateFileKind::Text,
+            sha256: "aa".repeat(32),
+            content: Some("# payments".to_string()),
+        },
+        RenderedFilePreview {
+            path: "logo.png".to_string(),
+            size: 5,
+            kind: TemplateFileKind::Binary,
+            sha256: "bb".repeat(32),
+            content: None,
+        },
+    ];
+
+    let output = format_preview(&files);
+
+    assert!(output.starts_with("Initial commit: 2 file(s)"));
+    assert!(output.contains("=== README.md (10 bytes) ===\n# payments\n"));
+    assert!(output.contains(&format!(
+        "=== logo.png (binary, 5 bytes, sha256 {}) ===",
+        "bb".repeat(32)
+    )));
+}
@@ -0,0 +1,225 @@
+//! `render` command: renders a template into a local directory for inspection.
+//!
+//! This module implements `repo-roller render`, which fetches a template,
+//! renders it with variables read from a TOML file and writes the generated
+//! files to an output directory. Nothing is created on GitHub.
+//!
+//! The template is either a local template directory (rendered as it is on
+//! disk, including uncommitted changes) or a GitHub repository given as
+//! `owner/repo`.
+//!
+//! The variables file uses the same `name` and `owner` keys as the `create`
+//! configuration file, plus a `[variables]` table:
+//!
+//! ```toml
+//! name = "payments-service"
+//! owner = "my-org"
+//!
+//! [variables]
+//! service_name = "payments"
+//! ```
+//!
+//! # Usage
+//!
+//! ```bash
+//! repo-roller render --template ./rust-service-template --config vars.toml --out ./preview
+//! repo-roller render --template my-org/rust-service --config vars.toml --out ./preview
+//! ```
+
+use clap::Args;
+use config_manager::TemplateConfig;
+use repo_roller_core::LocalRenderRequest;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use template_engine::{
+    GitHubTemplateFetcher, LocalTemplateFetcher, TemplateFetcher, TemplateFileEntry,
+    TemplateFileKind,
+};
+use tracing::debug;
+
+use crate::commands::template_cmd::{create_metadata_provider, load_template_config_from_path};
+use crate::config::{parse_config_file, read_config_file};
+use crate::errors::Error;
+
+#[cfg(test)]
+#[path = "render_cmd_tests.rs"]
+mod tests;
+
+/// Arguments for `repo-roller render`.
+#[derive(Args, Debug, Clone)]
+pub struct RenderArgs {
+    /// Template to render: a local template directory, or `owner/repo` on GitHub.
+    #[arg(long)]
+    pub template: String,
+
+    /// Path to a TOML file with the repository name, owner and `[variables]`.
+    #[arg(long)]
+    pub config: String,
+
+    /// Directory to write the rendered files to (created if missing).
+    #[arg(long)]
+    pub out: PathBuf,
+}
+
+/// Executes the `render` command.
+///
+/// # Returns
+///
+/// The manifest of the rendered files, sorted by path.
+///
+/// # Errors
+///
+/// * `Error::ConfigFileNotFound` / `Error::ConfigParse` / `Error::Config` - the
+///   variables file cannot be read or is invalid
+/// * `Error::InvalidArguments` - `--template` is neither a directory nor `owner/repo`
+/// * `Error::Auth` - GitHub credentials are needed but not available
+/// * `Error::GitHub` - the template cannot be fetched
+/// * `Error::Config` - the template fails to render or a file cannot be written
+pub async fn execute(args: &RenderArgs) -> Result<Vec<TemplateFileEntry>, Error> {
+    let (fetcher, source, template) = resolve_template(&args.template).await?;
+    render_template(
+        fetcher.as_ref(),
+        &source,
+        &template,
+        Path::new(&args.config),
+        &args.out,
+    )
+    .await
+}
+
+/// Resolves a `--template` argument to a fetcher, the source passed to it and
+/// the template configuration.
+///
+/// # Errors
+///
+/// * `Error::InvalidArguments` - `template` is neither a directory nor `owner/repo`
+/// * `Error::Auth` - GitHub credentials are needed but not available
+/// * `Error::Config` - the template configuration cannot be loaded
+pub(crate) async fn resolve_template(
+    template: &str,
+) -> Result<(Box<dyn TemplateFetcher>, String, TemplateConfig), Error> {
+    let template_dir = Path::new(template);
+    if template_dir.is_dir() {
+        debug!("Using local template {}", template);
+        let config = load_template_config_from_path(template_dir)?;
+        return Ok((Box::new(LocalTemplateFetcher), template.to_string(), config));
+    }
+
+    let (owner, repo) = template
+        .split_once('/')
+        .filter(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+        .ok_or_else(|| {
+            Error::InvalidArguments(format!(
+                "Template '{}' is neither a local directory nor 'owner/repo'",
+                template
+            ))
+        })?;
+
+    debug!("Using GitHub template {}/{}", owner, repo);
+    let provider = create_metadata_provider().await?;
+    let config = provider
+        .load_template_configuration(owner, repo)
+        .await
+        .map_err(|e| Error::Config(format!("Failed to load template configuration: {}", e)))?;
+
+    Ok((
+        Box::new(GitHubTemplateFetcher::new()),
+        format!("https://github.com/{}/{}", owner, repo),
+        config,
+    ))
+}
+
+/// Fetches a template and renders it into `out_dir`.
+///
+/// # Errors
+///
+/// * `Error::GitHub` - the template files cannot be fetched
+/// * `Error::Config` - the variables file is invalid or rendering fails
+pub(crate) async fn render_template(
+    fetcher: &dyn TemplateFetcher,
+    source: &str,
+    template: &TemplateConfig,
+    config_path: &Path,
+    out_dir: &Path,
+) -> Result<Vec<TemplateFileEntry>, Error> {
+    let request = load_render_request(config_path, &template.template.name)?;
+
+    let files = fetcher
+        .fetch_template_files(source)
+        .await
+        .map_err(|e| Error::GitHub(format!("Failed to fetch template files: {}", e)))?;
+
+    repo_roller_core::render_template_to_directory(&files, template, &request, out_dir)
+        .map_err(|e| Error::Config(format!("Failed to render template: {}", e)))
+}
+
+/// Reads the repository name, owner and variable values from a TOML file.
+///
+/// The repository name defaults to the template name when the file has no
+/// `name` key. Non-string variable values are converted to their TOML text.
+///
+/// # Errors
+///
+/// * `Error::ConfigFileNotFound` - the file does not exist
+/// * `Error::ConfigParse` - the file is not valid TOML
+/// * `Error::Config` - `[variables]` is not a table or holds an array or table
+pub(crate) fn load_render_request(
+    path: &Path,
+    template_name: &str,
+) -> Result<LocalRenderRequest, Error> {
+    let contents = read_config_file(path)?;
+    let table: toml::Table = parse_config_file(path, &contents)?;
+
+    let text = |key: &str| table.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+    let mut variables = HashMap::new();
+    if let Some(value) = table.get("variables") {
+        let entries = value.as_table().ok_or_else(|| {
+            Error::Config(format!(
+                "'variables' in '{}' must be a table",
+                path.display()
+            ))
+        })?;
+        for (name, value) in entries {
+            let value = match value {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Array(_) | toml::Value::Table(_) => {
+                    return Err(Error::Config(format!(
+                        "Variable '{}' in '{}' must be a single value",
+                        name,
+                        path.display()
+                    )))
+                }
+                other => other.to_string(),
+            };
+            variables.insert(name.clone(), value);
+        }
+    }
+
+    Ok(LocalRenderRequest {
+        repo_name: text("name").unwrap_or_else(|| template_name.to_string()),
+        org_name: text("owner").unwrap_or_default(),
+        template_name: template_name.to_string(),
+        variables,
+    })
+}
+
+/// Formats the summary printed after a successful render.
+pub(crate) fn format_render_summary(out_dir: &Path, manifest: &[TemplateFileEntry]) -> String {
+    let mut output = format!(
+        "Rendered {} file(s) to {}\n",
+        manifest.len(),
+        out_dir.display()
+    );
+    for entry in manifest {
+        let kind = match entry.kind {
+            TemplateFileKind::Text => "text",
+            TemplateFileKind::Binary => "binary",
+        };
+        output.push_str(&format!(
+            "  {:<50} {:>10} bytes  {}\n",
+            entry.path, entry.size, kind
+        ));
+    }
+    output
+}
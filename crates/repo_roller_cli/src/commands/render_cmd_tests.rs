@@ -0,0 +1,129 @@
+//! Tests for the `render` command.
+
+use super::*;
+use tempfile::TempDir;
+
+/// Creates a local template directory with a template configuration and two files.
+fn create_local_template(dir: &Path) {
+    std::fs::create_dir_all(dir.join(".reporoller")).unwrap();
+    std::fs::write(
+        dir.join(".reporoller/template.toml"),
+        "[template]\nname = \"service-template\"\ndescription = \"A service\"\nauthor = \"Platform Team\"\ntags = []\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("README.md"), "# {{repo_name}} for {{org_name}}").unwrap();
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(
+        dir.join("src/{{service_name}}.rs"),
+        "pub const PORT: u16 = {{port}};",
+    )
+    .unwrap();
+}
+
+fn write_vars(dir: &Path, content: &str) -> PathBuf {
+    let path = dir.join("vars.toml");
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+/// Test that a local template is rendered into the output directory.
+#[tokio::test]
+async fn test_render_local_template_writes_files() {
+    let tmp = TempDir::new().unwrap();
+    let template_dir = tmp.path().join("template");
+    create_local_template(&template_dir);
+    let config = write_vars(
+        tmp.path(),
+        "name = \"payments\"\nowner = \"my-org\"\n\n[variables]\nservice_name = \"api\"\nport = 8080\n",
+    );
+    let out = tmp.path().join("preview");
+
+    let manifest = execute(&RenderArgs {
+        template: template_dir.to_string_lossy().to_string(),
+        config: config.to_string_lossy().to_string(),
+        out: out.clone(),
+    })
+    .await
+    .expect("render should succeed");
+
+    assert_eq!(
+        std::fs::read_to_string(out.join("README.md")).unwrap(),
+        "# payments for my-org"
+    );
+    assert_eq!(
+        std::fs::read_to_string(out.join("src/api.rs")).unwrap(),
+        "pub const PORT: u16 = 8080;"
+    );
+    assert!(
+        !out.join(".reporoller").exists(),
+        "template configuration must not be rendered"
+    );
+
+    let paths: Vec<&str> = manifest.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, vec!["README.md", "src/api.rs"]);
+}
+
+/// Test that a template argument that is neither a directory nor owner/repo is rejected.
+#[tokio::test]
+async fn test_render_rejects_unknown_template_source() {
+    let tmp = TempDir::new().unwrap();
+    let config = write_vars(tmp.path(), "");
+
+    let result = execute(&RenderArgs {
+        template: "not-a-directory-or-repo".to_string(),
+        config: config.to_string_lossy().to_string(),
+        out: tmp.path().join("preview"),
+    })
+    .await;
+
+    assert!(matches!(result, Err(Error::InvalidArguments(_))));
+}
+
+/// Test that the repository name defaults to the template name.
+#[test]
+fn test_load_render_request_defaults_repo_name_to_template() {
+    let tmp = TempDir::new().unwrap();
+    let config = write_vars(tmp.path(), "[variables]\nenabled = true\n");
+
+    let request = load_render_request(&config, "service-template").unwrap();
+
+    assert_eq!(request.repo_name, "service-template");
+    assert_eq!(request.org_name, "");
+    assert_eq!(request.variables.get("enabled"), Some(&"true".to_string()));
+}
+
+/// Test that array variable values are rejected.
+#[test]
+fn test_load_render_request_rejects_array_values() {
+    let tmp = TempDir::new().unwrap();
+    let config = write_vars(tmp.path(), "[variables]\nitems = [\"a\", \"b\"]\n");
+
+    let result = load_render_request(&config, "service-template");
+
+    assert!(matches!(result, Err(Error::Config(msg)) if msg.contains("items")));
+}
+
+/// Test that a missing variables file is reported.
+#[test]
+fn test_load_render_request_missing_file() {
+    let tmp = TempDir::new().unwrap();
+
+    let result = load_render_request(&tmp.path().join("missing.toml"), "t");
+
+    assert!(matches!(result, Err(Error::ConfigFileNotFound { .. })));
+}
+
+/// Test that the summary lists every rendered file.
+#[test]
+fn test_format_render_summary_lists_files() {
+    let manifest = template_engine::build_file_manifest(&[
+        ("README.md".to_string(), b"# hi".to_vec()),
+        ("logo.png".to_string(), vec![0x89, 0x50, 0x4e, 0x47, 0x00]),
+    ]);
+
+    let summary = format_render_summary(Path::new("preview"), &manifest);
+
+    assert!(summary.starts_with("Rendered 2 file(s) to preview"));
+    assert!(summary.contains("README.md"));
+    assert!(summary.contains("binary"));
+}
@@ -100,6 +100,10 @@ pub struct TemplateVariableInfo {
     /// Example value (if any).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example: Option<String>,
+
+    /// Allowed values, with display labels where the template defines them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<template_engine::VariableOption>,
 }
 
 /// Result of template validation.
@@ -247,7 +251,8 @@ pub async fn execute(cmd: &TemplateCommands) -> Result<(), Error> {
 ///
 /// Loads GitHub App credentials from the system keyring and creates
 /// an authenticated GitHubMetadataProvider instance.
-async fn create_metadata_provider() -> Result<Arc<dyn MetadataRepositoryProvider>, Error> {
+pub(crate) async fn create_metadata_provider() -> Result<Arc<dyn MetadataRepositoryProvider>, Error>
+{
     // Load application config to get metadata repository name
     let config_path = get_config_path(None);
     let app_config = AppConfig::load(&config_path).unwrap_or_else(|_| AppConfig::default());
@@ -294,7 +299,10 @@ async fn create_metadata_provider() -> Result<Arc<dyn MetadataRepositoryProvider
         &app_config.organization.metadata_repository_name
     };
 
-    let config = MetadataProviderConfig::explicit(metadata_repo_name);
+    let mut config = MetadataProviderConfig::explicit(metadata_repo_name);
+    if let Some(branch) = &app_config.organization.metadata_branch {
+        config = config.with_git_ref(branch);
+    }
     let provider = GitHubMetadataProvider::new(github_client, config);
 
     Ok(Arc::new(provider))
@@ -377,6 +385,7 @@ fn template_variable_to_info(name: String, var: TemplateVariable) -> TemplateVar
         required: var.required.unwrap_or(false),
         default_value: var.default,
         example: var.example,
+        options: var.options.unwrap_or_default(),
     }
 }
 
@@ -564,6 +573,21 @@ pub async fn validate_template(
         .await
     {
         Ok(cfg) => cfg,
+        Err(ConfigurationError::ValidationFailed { errors, .. }) => {
+            // Loaded but rejected (e.g. bad variable names) - report each error
+            issues.extend(errors.into_iter().map(|err| ValidationIssue {
+                severity: "error".to_string(),
+                location: err.field_path,
+                message: err.message,
+            }));
+
+            return Ok(TemplateValidationResult {
+                template_name: template_name.to_string(),
+                valid: false,
+                issues,
+                warnings: vec![],
+            });
+        }
         Err(e) => {
             // Template loading failed - return validation result with error
             let issue = match e {
@@ -674,13 +698,22 @@ async fn validate_loaded_template(
         }
 
         for (var_name, var_def) in variables {
-            // Variable names: alphanumeric + underscore only
-            if !var_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            // Variable names: Handlebars identifiers that don't shadow built-ins
+            if !template_engine::is_valid_variable_name(var_name) {
                 issues.push(ValidationIssue {
                     severity: "error".to_string(),
                     location: format!("variables.{}", var_name),
                     message: format!(
-                        "Variable name '{}' contains invalid characters. Only alphanumeric and underscore allowed.",
+                        "Variable name '{}' contains invalid characters. Only letters, digits and underscore allowed, starting with a letter or underscore.",
+                        var_name
+                    ),
+                });
+            } else if template_engine::is_built_in_variable_name(var_name) {
+                issues.push(ValidationIssue {
+                    severity: "error".to_string(),
+                    location: format!("variables.{}", var_name),
+                    message: format!(
+                        "Variable name '{}' is reserved for a built-in variable",
                         var_name
                     ),
                 });
@@ -837,6 +870,8 @@ pub(crate) const TEMPLATE_CONFIG_KNOWN_KEYS: &[&str] = &[
     "teams",
     "collaborators",
     "naming_rules",
+    "actions_variables",
+    "actions_secrets",
 ];
 
 /// Return `ValidationWarning`s for any top-level key in `.reporoller/template.toml` that is
@@ -1101,6 +1136,21 @@ fn format_template_info_pretty(info: &TemplateInfo) -> String {
                 output.push_str(&format!("    {}: {}\n", "Example".dimmed(), example));
             }
 
+            if !var.options.is_empty() {
+                output.push_str(&format!("    {}:\n", "Options".dimmed()));
+                for option in &var.options {
+                    if option.label() == option.value() {
+                        output.push_str(&format!("      - {}\n", option.value()));
+                    } else {
+                        output.push_str(&format!(
+                            "      - {} ({})\n",
+                            option.label(),
+                            option.value()
+                        ));
+                    }
+                }
+            }
+
             output.push('\n');
         }
     }
@@ -154,6 +154,9 @@ fn create_minimal_template_config(name: &str) -> TemplateConfig {
             description: format!("{} template", name),
             author: "Test Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -171,6 +174,10 @@ fn create_minimal_template_config(name: &str) -> TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
@@ -182,11 +189,14 @@ fn create_full_template_config(name: &str) -> TemplateConfig {
             description: "Project name".to_string(),
             required: Some(true),
             default: None,
+            required_if: None,
             example: Some("my-project".to_string()),
             pattern: None,
             min_length: None,
             max_length: None,
             options: None,
+            secret: None,
+            transforms: None,
         },
     );
     variables.insert(
@@ -195,11 +205,14 @@ fn create_full_template_config(name: &str) -> TemplateConfig {
             description: "Service port".to_string(),
             required: Some(false),
             default: Some("8080".to_string()),
+            required_if: None,
             example: Some("3000".to_string()),
             pattern: None,
             min_length: None,
             max_length: None,
             options: None,
+            secret: None,
+            transforms: None,
         },
     );
 
@@ -210,6 +223,9 @@ fn create_full_template_config(name: &str) -> TemplateConfig {
             description: format!("{} template with full config", name),
             author: "Platform Team".to_string(),
             tags: vec!["rust".to_string(), "service".to_string()],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: Some(config_manager::RepositoryTypeSpec {
             repository_type: "service".to_string(),
@@ -230,6 +246,10 @@ fn create_full_template_config(name: &str) -> TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
@@ -631,11 +651,14 @@ async fn test_validate_template_invalid_variable_name() {
             description: "Invalid variable name".to_string(),
             required: Some(true),
             default: None,
+            required_if: None,
             example: None,
             pattern: None,
             min_length: None,
             max_length: None,
             options: None,
+            secret: None,
+            transforms: None,
         },
     );
     config.variables = Some(variables);
@@ -658,6 +681,43 @@ async fn test_validate_template_invalid_variable_name() {
             && i.message.contains("characters")));
 }
 
+#[tokio::test]
+async fn test_validate_template_built_in_variable_name() {
+    let mut config = create_minimal_template_config("shadowing-vars");
+    let mut variables = HashMap::new();
+    variables.insert(
+        "repo_name".to_string(), // Collides with a built-in variable
+        TemplateVariable {
+            description: "Repository name".to_string(),
+            required: Some(true),
+            default: None,
+            required_if: None,
+            example: None,
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            secret: None,
+            transforms: None,
+        },
+    );
+    config.variables = Some(variables);
+
+    let provider = Arc::new(
+        MockMetadataProvider::new().with_template_config("shadowing-vars".to_string(), config),
+    );
+
+    let validation = validate_template("test-org", "shadowing-vars", provider)
+        .await
+        .unwrap();
+
+    assert!(!validation.valid);
+    assert!(validation
+        .issues
+        .iter()
+        .any(|i| i.location == "variables.repo_name" && i.message.contains("reserved")));
+}
+
 #[tokio::test]
 async fn test_validate_template_required_variable_with_default() {
     let mut config = create_minimal_template_config("contradictory-vars");
@@ -669,10 +729,13 @@ async fn test_validate_template_required_variable_with_default() {
             required: Some(true),
             default: Some("default-value".to_string()), // Contradiction!
             example: None,
+            required_if: None,
             pattern: None,
             min_length: None,
             max_length: None,
             options: None,
+            secret: None,
+            transforms: None,
         },
     );
     config.variables = Some(variables);
@@ -703,11 +766,14 @@ async fn test_validate_template_required_variable_without_example_warning() {
             description: "Service name".to_string(),
             required: Some(true),
             default: None,
+            required_if: None,
             example: None, // No example - warning
             pattern: None,
             min_length: None,
             max_length: None,
             options: None,
+            secret: None,
+            transforms: None,
         },
     );
     config.variables = Some(variables);
@@ -814,6 +880,7 @@ fn test_format_template_info_json() {
             required: true,
             default_value: None,
             example: Some("my-lib".to_string()),
+            options: vec![],
         }],
         configuration_sections: 3,
     };
@@ -847,6 +914,7 @@ fn test_format_template_info_pretty() {
             required: true,
             default_value: None,
             example: Some("my-lib".to_string()),
+            options: vec![],
         }],
         configuration_sections: 3,
     };
@@ -864,6 +932,35 @@ fn test_format_template_info_pretty() {
     assert!(output.contains("project_name"));
 }
 
+/// Test that pretty output shows option labels alongside their values.
+#[test]
+fn test_format_template_info_pretty_shows_option_labels() {
+    let info = TemplateInfo {
+        name: "rust-library".to_string(),
+        description: "A Rust library template".to_string(),
+        author: "Platform Team".to_string(),
+        tags: vec![],
+        repository_type: None,
+        variables: vec![TemplateVariableInfo {
+            name: "license".to_string(),
+            description: Some("License".to_string()),
+            required: true,
+            default_value: None,
+            example: None,
+            options: vec![
+                template_engine::VariableOption::labeled("MIT", "MIT License"),
+                "Apache-2.0".into(),
+            ],
+        }],
+        configuration_sections: 0,
+    };
+
+    let output = format_template_info(&info, "pretty").unwrap();
+
+    assert!(output.contains("MIT License (MIT)"));
+    assert!(output.contains("- Apache-2.0"));
+}
+
 /// Test formatting ValidationResult as JSON.
 #[test]
 fn test_format_validation_result_json() {
@@ -1576,8 +1673,9 @@ fn test_known_keys_sync_with_template_config() {
     // without updating TEMPLATE_CONFIG_KNOWN_KEYS, the serialised output will
     // contain a key not present in TEMPLATE_CONFIG_KNOWN_KEYS and this test fails.
     use config_manager::settings::{
-        DefaultCollaboratorConfig, DefaultTeamConfig, EnvironmentConfig, GitHubAppConfig,
-        NotificationsConfig, RulesetConfig, TemplatePermissionsConfig,
+        ActionsSecretConfig, ActionsVariableConfig, DefaultCollaboratorConfig, DefaultTeamConfig,
+        EnvironmentConfig, GitHubAppConfig, NotificationsConfig, RulesetConfig,
+        TemplatePermissionsConfig,
     };
     use config_manager::{
         RepositoryNamingRulesConfig, RepositoryTypePolicy, RepositoryTypeSpec, RepositoryVisibility,
@@ -1590,6 +1688,9 @@ fn test_known_keys_sync_with_template_config() {
             description: String::new(),
             author: "a".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: Some(RepositoryTypeSpec {
             repository_type: "svc".to_string(),
@@ -1606,6 +1707,9 @@ fn test_known_keys_sync_with_template_config() {
                 max_length: None,
                 options: None,
                 default: None,
+                required_if: None,
+                secret: None,
+                transforms: None,
             },
         )])),
         repository: Some(RepositorySettings::default()),
@@ -1631,6 +1735,7 @@ fn test_known_keys_sync_with_template_config() {
         github_apps: Some(vec![GitHubAppConfig {
             app_id: 1,
             permissions: HashMap::from([("contents".to_string(), "read".to_string())]),
+            required: None,
         }]),
         rulesets: Some(vec![RulesetConfig {
             name: "main".to_string(),
@@ -1644,6 +1749,11 @@ fn test_known_keys_sync_with_template_config() {
         templating: Some(TemplatingConfig {
             include_patterns: vec![],
             exclude_patterns: vec![],
+            post_generation: vec![],
+            case_insensitive: false,
+            glob_recursive: false,
+            strict_variables: false,
+            max_path_length: None,
         }),
         notifications: Some(NotificationsConfig::default()),
         permissions: Some(TemplatePermissionsConfig::default()),
@@ -1658,6 +1768,16 @@ fn test_known_keys_sync_with_template_config() {
             locked: false,
         }]),
         naming_rules: Some(vec![RepositoryNamingRulesConfig::default()]),
+        actions_variables: Some(vec![ActionsVariableConfig {
+            name: "VAR".to_string(),
+            value: "value".to_string(),
+        }]),
+        actions_secrets: Some(vec![ActionsSecretConfig {
+            name: "SECRET".to_string(),
+            secret_ref: "SECRET_REF".to_string(),
+        }]),
+        variable_groups: None,
+        base_template: None,
     };
 
     let serialized =
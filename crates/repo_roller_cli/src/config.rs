@@ -9,11 +9,11 @@
 //! specified file path or from the default location in the current directory.
 
 use std::{
-    fs,
+    fs, io,
     path::{Path, PathBuf},
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::{debug, info};
 
 use crate::errors::Error;
@@ -77,15 +77,16 @@ impl AppConfig {
     ///
     /// Returns a `Result` containing:
     /// - `Ok(AppConfig)` - Successfully loaded and parsed configuration
-    /// - `Err(Error::Config)` - If the file doesn't exist, can't be read, or contains invalid TOML
+    /// - `Err(Error)` - If the file doesn't exist, can't be read, or contains invalid TOML
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The specified file does not exist
-    /// - The file cannot be read due to permissions or I/O issues
-    /// - The file contains invalid TOML syntax
-    /// - The TOML structure doesn't match the expected configuration schema
+    /// - The specified file does not exist (`Error::ConfigFileNotFound`)
+    /// - The file cannot be read due to permissions or I/O issues (`Error::Config`)
+    /// - The file contains invalid TOML syntax (`Error::ConfigParse`)
+    /// - A required key is missing (`Error::ConfigMissingField`)
+    /// - The TOML structure doesn't match the expected configuration schema (`Error::ConfigParse`)
     ///
     /// # Example
     ///
@@ -102,20 +103,8 @@ impl AppConfig {
     pub fn load(path: &Path) -> Result<Self, Error> {
         debug!("Loading configuration from {:?}", path);
 
-        if !path.exists() {
-            return Err(Error::Config(format!(
-                "Configuration file not found: {:?}",
-                path
-            )));
-        }
-
-        let content = fs::read_to_string(path)
-            .map_err(|e| Error::Config(format!("Failed to read configuration file: {}", e)))?;
-
-        let config: AppConfig = toml::from_str(&content)
-            .map_err(|e| Error::Config(format!("Failed to parse configuration file: {}", e)))?;
-
-        Ok(config)
+        let content = read_config_file(path)?;
+        parse_config_file(path, &content)
     }
 
     /// Saves the configuration to a TOML file at the specified path.
@@ -245,6 +234,7 @@ impl Default for AuthenticationConfig {
 /// # Fields
 ///
 /// * `metadata_repository_name` - Name of the repository containing organization configuration
+/// * `metadata_branch` - Optional branch of the metadata repository to read configuration from
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OrganizationConfig {
     /// Name of the metadata repository for organization configuration.
@@ -253,6 +243,14 @@ pub struct OrganizationConfig {
     /// including global defaults, repository type configurations, and team settings.
     #[serde(default = "OrganizationConfig::default_metadata_repository_name")]
     pub metadata_repository_name: String,
+
+    /// Branch of the metadata repository to read configuration from.
+    ///
+    /// When not set, the metadata repository's default branch is used. Pointing
+    /// this at a staging branch allows configuration changes to be previewed
+    /// before they are merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_branch: Option<String>,
 }
 
 impl OrganizationConfig {
@@ -273,6 +271,7 @@ impl OrganizationConfig {
     pub fn new() -> Self {
         OrganizationConfig {
             metadata_repository_name: Self::default_metadata_repository_name(),
+            metadata_branch: None,
         }
     }
 }
@@ -281,10 +280,78 @@ impl Default for OrganizationConfig {
     fn default() -> Self {
         Self {
             metadata_repository_name: OrganizationConfig::default_metadata_repository_name(),
+            metadata_branch: None,
         }
     }
 }
 
+/// Reads the raw contents of a configuration file.
+///
+/// # Errors
+///
+/// Returns `Error::ConfigFileNotFound` if no file exists at `path`, or
+/// `Error::Config` if the file exists but cannot be read.
+pub(crate) fn read_config_file(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::ConfigFileNotFound {
+            path: path.display().to_string(),
+        },
+        _ => Error::Config(format!(
+            "Failed to read configuration file '{}': {}",
+            path.display(),
+            e
+        )),
+    })
+}
+
+/// Parses the contents of a configuration file into `T`.
+///
+/// TOML deserialization errors are translated into errors that name the file
+/// and, where possible, the offending key or location, so that users can fix
+/// the file without having to interpret the raw parser output.
+///
+/// # Errors
+///
+/// Returns `Error::ConfigMissingField` if a required key is absent, or
+/// `Error::ConfigParse` for any other syntax or schema error.
+pub(crate) fn parse_config_file<T: DeserializeOwned>(
+    path: &Path,
+    content: &str,
+) -> Result<T, Error> {
+    toml::from_str(content).map_err(|e| {
+        let message = e.message().trim().to_string();
+        if let Some(field) = message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            return Error::ConfigMissingField {
+                path: path.display().to_string(),
+                field: field.to_string(),
+            };
+        }
+
+        let (line, column) = e
+            .span()
+            .map(|span| line_and_column(content, span.start))
+            .unwrap_or((1, 1));
+        Error::ConfigParse {
+            path: path.display().to_string(),
+            line,
+            column,
+            message,
+        }
+    })
+}
+
+/// Converts a byte offset into a 1-based line and column pair.
+fn line_and_column(content: &str, offset: usize) -> (usize, usize) {
+    let prefix = content.get(..offset).unwrap_or(content);
+    let line = prefix.matches('\n').count() + 1;
+    let line_start = prefix.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = prefix[line_start..].chars().count() + 1;
+    (line, column)
+}
+
 /// Resolves the path to the configuration file.
 ///
 /// This function determines the configuration file path based on the provided
@@ -24,24 +24,100 @@ fn test_app_config_load_invalid_toml() {
     let result = AppConfig::load(&config_path);
 
     assert!(result.is_err());
-    if let Err(Error::Config(msg)) = result {
-        assert!(msg.contains("Failed to parse configuration file"));
+    if let Err(Error::ConfigParse {
+        path, line, column, ..
+    }) = result
+    {
+        assert_eq!(path, config_path.display().to_string());
+        assert_eq!(line, 1);
+        assert!(column > 1);
     } else {
-        panic!("Expected Config error");
+        panic!("Expected ConfigParse error");
     }
 }
 
+#[test]
+fn test_app_config_load_broken_toml_reports_location() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let config_path = temp_dir.path().join("broken_config.toml");
+
+    // Unterminated table header on the third line
+    fs::write(
+        &config_path,
+        "[authentication]\nauth_method = \"token\"\n[organization\n",
+    )
+    .expect("Failed to write broken TOML");
+
+    let error = AppConfig::load(&config_path).unwrap_err();
+
+    match &error {
+        Error::ConfigParse { line, .. } => assert_eq!(*line, 3),
+        other => panic!("Expected ConfigParse error, got {:?}", other),
+    }
+    let message = error.to_string();
+    assert!(message.contains(&config_path.display().to_string()));
+    assert!(message.contains("line 3"));
+}
+
+#[test]
+fn test_app_config_load_wrong_value_type_reports_parse_error() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let config_path = temp_dir.path().join("wrong_type_config.toml");
+
+    fs::write(&config_path, "[authentication]\nauth_method = 42\n")
+        .expect("Failed to write config");
+
+    let result = AppConfig::load(&config_path);
+
+    assert!(matches!(result, Err(Error::ConfigParse { line: 2, .. })));
+}
+
 #[test]
 fn test_app_config_load_nonexistent_file() {
     let nonexistent_path = PathBuf::from("nonexistent_config.toml");
     let result = AppConfig::load(&nonexistent_path);
 
     assert!(result.is_err());
-    if let Err(Error::Config(msg)) = result {
-        assert!(msg.contains("Configuration file not found"));
+    if let Err(Error::ConfigFileNotFound { path }) = result {
+        assert_eq!(path, "nonexistent_config.toml");
     } else {
-        panic!("Expected Config error");
+        panic!("Expected ConfigFileNotFound error");
+    }
+}
+
+#[test]
+fn test_parse_config_file_missing_required_field() {
+    #[derive(Debug, Deserialize)]
+    struct RequiresTemplate {
+        #[allow(dead_code)]
+        name: String,
+        #[allow(dead_code)]
+        template: String,
     }
+
+    let path = Path::new("create.toml");
+    let result = parse_config_file::<RequiresTemplate>(path, "name = \"my-repo\"\n");
+
+    match result {
+        Err(ref error @ Error::ConfigMissingField { ref field, .. }) => {
+            assert_eq!(field, "template");
+            assert_eq!(
+                error.to_string(),
+                "Configuration file 'create.toml' is missing the required key 'template'"
+            );
+        }
+        other => panic!("Expected ConfigMissingField error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_line_and_column() {
+    let content = "first\nsecond\nthird";
+
+    assert_eq!(line_and_column(content, 0), (1, 1));
+    assert_eq!(line_and_column(content, 3), (1, 4));
+    assert_eq!(line_and_column(content, 6), (2, 1));
+    assert_eq!(line_and_column(content, 15), (3, 3));
 }
 
 #[test]
@@ -131,6 +207,7 @@ fn test_organization_config_default() {
         org_config.metadata_repository_name,
         DEFAULT_METADATA_REPOSITORY_NAME
     );
+    assert_eq!(org_config.metadata_branch, None);
 }
 
 #[test]
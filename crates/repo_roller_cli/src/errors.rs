@@ -27,6 +27,52 @@ pub enum Error {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// The configuration file does not exist at the given path.
+    ///
+    /// This error is returned when a configuration file was requested, either
+    /// explicitly via `--config` or implicitly via the default location, but
+    /// no file exists at that path.
+    #[error("Configuration file not found: '{path}'")]
+    ConfigFileNotFound {
+        /// Path of the configuration file that could not be found.
+        path: String,
+    },
+
+    /// The configuration file is missing a required key.
+    ///
+    /// This error is returned when the configuration file is valid TOML but
+    /// does not contain a key that the configuration schema requires.
+    #[error("Configuration file '{path}' is missing the required key '{field}'")]
+    ConfigMissingField {
+        /// Path of the configuration file.
+        path: String,
+
+        /// Name of the missing key.
+        field: String,
+    },
+
+    /// The configuration file could not be parsed.
+    ///
+    /// This error is returned when the configuration file contains invalid TOML
+    /// syntax or a value that does not match the configuration schema. The
+    /// location points at the offending part of the file, counting from 1.
+    #[error(
+        "Failed to parse configuration file '{path}' at line {line}, column {column}: {message}"
+    )]
+    ConfigParse {
+        /// Path of the configuration file.
+        path: String,
+
+        /// Line of the offending content.
+        line: usize,
+
+        /// Column of the offending content.
+        column: usize,
+
+        /// Description of the parse failure.
+        message: String,
+    },
+
     /// Invalid command-line arguments were provided.
     ///
     /// This error is returned when the user provides invalid or incompatible
@@ -16,6 +16,43 @@ fn test_config_error_display() {
     );
 }
 
+#[test]
+fn test_config_file_not_found_error_display() {
+    let error = Error::ConfigFileNotFound {
+        path: "./config.toml".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Configuration file not found: './config.toml'"
+    );
+}
+
+#[test]
+fn test_config_missing_field_error_display() {
+    let error = Error::ConfigMissingField {
+        path: "./create.toml".to_string(),
+        field: "template".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Configuration file './create.toml' is missing the required key 'template'"
+    );
+}
+
+#[test]
+fn test_config_parse_error_display() {
+    let error = Error::ConfigParse {
+        path: "./config.toml".to_string(),
+        line: 3,
+        column: 14,
+        message: "invalid table header".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse configuration file './config.toml' at line 3, column 14: invalid table header"
+    );
+}
+
 #[test]
 fn test_error_debug_format() {
     let error = Error::Auth("test".to_string());
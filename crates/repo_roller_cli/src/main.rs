@@ -33,7 +33,7 @@ use errors::Error;
 use crate::commands::{
     auth_cmd::AuthCommands, config_cmd::ConfigCommands, create_cmd::CreateArgs,
     make_template_cmd::MakeTemplateArgs, org_settings_cmd::OrgSettingsCommands,
-    template_cmd::TemplateCommands,
+    preview_cmd::PreviewArgs, render_cmd::RenderArgs, template_cmd::TemplateCommands,
 };
 
 #[cfg(test)]
@@ -77,6 +77,12 @@ enum Commands {
     #[command(subcommand)]
     OrgSettings(OrgSettingsCommands),
 
+    /// Print the files of the initial commit a template renders, without creating anything
+    Preview(PreviewArgs),
+
+    /// Render a template into a local directory without creating a repository
+    Render(RenderArgs),
+
     /// Template inspection and validation commands
     #[command(subcommand)]
     Template(TemplateCommands),
@@ -102,12 +108,21 @@ pub fn parse_key_val(s: &str) -> Result<(String, String), String> {
     Ok((s[..pos].to_string(), s[pos + 1..].to_string()))
 }
 
-#[tokio::main]
-async fn main() {
-    tracing_subscriber::registry()
+/// Installs the global tracing subscriber for the CLI.
+///
+/// Library crates only emit `tracing` events; the subscriber is set up here.
+/// Calling this more than once, or after another subscriber has been
+/// installed, leaves the existing subscriber in place.
+fn init_logging() {
+    let _ = tracing_subscriber::registry()
         .with(fmt::layer().pretty())
         .with(EnvFilter::from_env("REPO_ROLLER_LOG"))
-        .init();
+        .try_init();
+}
+
+#[tokio::main]
+async fn main() {
+    init_logging();
 
     let cli = Cli::parse();
     match &cli.command {
@@ -135,9 +150,13 @@ async fn main() {
                 args.init_gitignore,
                 &args.teams,
                 &args.collaborators,
-            );
-            let result =
-                handle_create_command(options, &ask_user_for_value, create_repository).await;
+            )
+            .with_owner_type(args.owner_type)
+            .with_variables(&args.vars_file, &args.variables);
+            let result = handle_create_command(options, &ask_user_for_value, |request| {
+                create_repository(request, ask_user_for_value)
+            })
+            .await;
 
             match result {
                 Ok(creation_result) => {
@@ -146,6 +165,15 @@ async fn main() {
                     println!("  ID: {}", creation_result.repository_id);
                     println!("  Default branch: {}", creation_result.default_branch);
                     println!("  Created at: {}", creation_result.created_at);
+                    if !creation_result.warnings.is_empty() {
+                        println!("Warnings:");
+                        for warning in &creation_result.warnings {
+                            println!("  {}: {}", warning.field_path, warning.message);
+                            if let Some(recommendation) = &warning.recommendation {
+                                println!("    Recommendation: {}", recommendation);
+                            }
+                        }
+                    }
                     std::process::exit(0);
                 }
                 Err(e) => {
@@ -189,6 +217,21 @@ async fn main() {
                 "timestamp_unix"
             );
             println!();
+            println!("CI variables (only available when running in CI):\n");
+            println!(
+                "  {:<20} Commit SHA that triggered the run",
+                "ci_commit_sha"
+            );
+            println!(
+                "  {:<20} Login of the user that triggered the run",
+                "ci_actor"
+            );
+            println!("  {:<20} Unique ID of the workflow run", "ci_run_id");
+            println!(
+                "  {:<20} Triggering ref (pr<number>, main, or branch name)",
+                "ci_workflow_context"
+            );
+            println!();
             println!("These variables are injected by the template engine before user-defined");
             println!("variables. Template-specific variables are listed with:");
             println!("  repo-roller template info --org <ORG> --template <TEMPLATE>");
@@ -200,6 +243,29 @@ async fn main() {
                 std::process::exit(1);
             }
         }
+        Commands::Preview(args) => match crate::commands::preview_cmd::execute(args).await {
+            Ok(files) => {
+                print!("{}", crate::commands::preview_cmd::format_preview(&files));
+                std::process::exit(0);
+            }
+            Err(e) => {
+                error!("Error: {e}");
+                std::process::exit(1);
+            }
+        },
+        Commands::Render(args) => match crate::commands::render_cmd::execute(args).await {
+            Ok(manifest) => {
+                print!(
+                    "{}",
+                    crate::commands::render_cmd::format_render_summary(&args.out, &manifest)
+                );
+                std::process::exit(0);
+            }
+            Err(e) => {
+                error!("Error: {e}");
+                std::process::exit(1);
+            }
+        },
         Commands::Template(cmd) => {
             if let Err(e) = crate::commands::template_cmd::execute(cmd).await {
                 error!("Error: {e}");
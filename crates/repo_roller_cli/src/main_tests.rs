@@ -1,4 +1,4 @@
-use super::Cli;
+use super::{init_logging, Cli};
 use clap::CommandFactory;
 use std::collections::HashSet;
 use template_engine::{BuiltInVariablesParams, TemplateProcessor};
@@ -60,3 +60,10 @@ fn list_variables_matches_template_engine_built_ins() {
         missing_from_engine
     );
 }
+
+/// Installing the subscriber twice must not panic.
+#[test]
+fn init_logging_is_idempotent() {
+    init_logging();
+    init_logging();
+}
@@ -0,0 +1,193 @@
+//! Batch repository creation with an optional progress ledger.
+//!
+//! [`create_repositories`] creates repositories one request at a time. When a
+//! [`BatchLedger`] is supplied, the outcome of every request is recorded as
+//! soon as it is known. Running the same batch again with the same ledger,
+//! for example after the process crashed part-way through, skips the requests
+//! that already succeeded and retries only the failed or unattempted ones, so
+//! no repository creation is attempted twice after it succeeded.
+//!
+//! Requests are identified in the ledger by their `owner/name`.
+
+use crate::errors::RepoRollerResult;
+use crate::request::{RepositoryCreationRequest, RepositoryCreationResult};
+use crate::{CreationDependencies, RepoRollerError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+#[cfg(test)]
+#[path = "batch_tests.rs"]
+mod tests;
+
+/// Recorded outcome of one request in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerOutcome {
+    /// The repository was created.
+    Succeeded {
+        /// URL of the created repository
+        repository_url: String,
+    },
+    /// Creating the repository failed; the request is retried on the next run.
+    Failed {
+        /// Description of the failure
+        error: String,
+    },
+}
+
+/// Store recording the outcome of each request in a batch.
+///
+/// Implementations decide where the ledger lives, e.g. a file or a database,
+/// and must persist a recorded outcome before `record` returns for a batch to
+/// be safely resumable.
+#[async_trait]
+pub trait BatchLedger: Send + Sync {
+    /// Returns the recorded outcome for `key`, or `None` if it was never attempted.
+    async fn outcome(&self, key: &str) -> RepoRollerResult<Option<LedgerOutcome>>;
+
+    /// Records the outcome for `key`, replacing any earlier record.
+    async fn record(&self, key: &str, outcome: &LedgerOutcome) -> RepoRollerResult<()>;
+}
+
+/// Ledger that keeps its records in memory.
+///
+/// Records are lost when the process exits, so this is mainly useful for
+/// tests and for batches that only need to survive retries within one run.
+#[derive(Debug, Default)]
+pub struct InMemoryBatchLedger {
+    entries: Mutex<HashMap<String, LedgerOutcome>>,
+}
+
+impl InMemoryBatchLedger {
+    /// Creates an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of every recorded outcome, keyed by `owner/name`.
+    pub fn entries(&self) -> HashMap<String, LedgerOutcome> {
+        self.entries.lock().expect("ledger lock poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl BatchLedger for InMemoryBatchLedger {
+    async fn outcome(&self, key: &str) -> RepoRollerResult<Option<LedgerOutcome>> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("ledger lock poisoned")
+            .get(key)
+            .cloned())
+    }
+
+    async fn record(&self, key: &str, outcome: &LedgerOutcome) -> RepoRollerResult<()> {
+        self.entries
+            .lock()
+            .expect("ledger lock poisoned")
+            .insert(key.to_string(), outcome.clone());
+        Ok(())
+    }
+}
+
+/// Result of one request in a batch.
+#[derive(Debug)]
+pub enum BatchItemStatus {
+    /// The repository was created in this run.
+    Created(Box<RepositoryCreationResult>),
+    /// The ledger shows the repository was created by an earlier run, so it
+    /// was not attempted again.
+    AlreadyCreated {
+        /// URL of the repository, as recorded in the ledger
+        repository_url: String,
+    },
+    /// Creating the repository failed.
+    Failed(RepoRollerError),
+}
+
+/// Result of one request in a batch, identified by its `owner/name`.
+#[derive(Debug)]
+pub struct BatchItemResult {
+    /// `owner/name` of the requested repository
+    pub key: String,
+    /// What happened to the request
+    pub status: BatchItemStatus,
+}
+
+/// Returns the key identifying a request in a [`BatchLedger`].
+pub fn ledger_key(request: &RepositoryCreationRequest) -> String {
+    format!("{}/{}", request.owner, request.name)
+}
+
+/// Creates repositories for a batch of requests, in order.
+///
+/// `create` performs a single creation, usually by calling
+/// [`create_repository`](crate::create_repository) with the caller's
+/// providers and the [`CreationDependencies`] it is given. Every creation gets
+/// a clone of `dependencies`, so a repository client or template fetcher
+/// configured on it is shared by the whole batch. A failed request does not
+/// stop the batch.
+///
+/// When `ledger` is given, requests it records as succeeded are skipped and
+/// every attempted request's outcome is recorded before the next request
+/// starts.
+///
+/// # Errors
+///
+/// Returns the ledger's error if an outcome cannot be read or recorded. The
+/// batch stops at that point, since its progress could no longer be resumed
+/// safely.
+pub async fn create_repositories<F, Fut>(
+    requests: Vec<RepositoryCreationRequest>,
+    ledger: Option<&dyn BatchLedger>,
+    dependencies: &CreationDependencies,
+    mut create: F,
+) -> RepoRollerResult<Vec<BatchItemResult>>
+where
+    F: FnMut(RepositoryCreationRequest, CreationDependencies) -> Fut,
+    Fut: Future<Output = RepoRollerResult<RepositoryCreationResult>>,
+{
+    let mut results = Vec::with_capacity(requests.len());
+
+    for request in requests {
+        let key = ledger_key(&request);
+
+        if let Some(ledger) = ledger {
+            if let Some(LedgerOutcome::Succeeded { repository_url }) = ledger.outcome(&key).await? {
+                info!("Skipping '{}': already created by an earlier run", key);
+                results.push(BatchItemResult {
+                    key,
+                    status: BatchItemStatus::AlreadyCreated { repository_url },
+                });
+                continue;
+            }
+        }
+
+        let (outcome, status) = match create(request, dependencies.clone()).await {
+            Ok(result) => (
+                LedgerOutcome::Succeeded {
+                    repository_url: result.repository_url.clone(),
+                },
+                BatchItemStatus::Created(Box::new(result)),
+            ),
+            Err(e) => {
+                warn!("Failed to create '{}': {}", key, e);
+                (
+                    LedgerOutcome::Failed {
+                        error: e.to_string(),
+                    },
+                    BatchItemStatus::Failed(e),
+                )
+            }
+        };
+
+        if let Some(ledger) = ledger {
+            ledger.record(&key, &outcome).await?;
+        }
+        results.push(BatchItemResult { key, status });
+    }
+
+    Ok(results)
+}
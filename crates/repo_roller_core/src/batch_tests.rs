@@ -0,0 +1,129 @@
+//! Tests for the batch module.
+
+use super::*;
+use crate::errors::RepositoryError;
+use crate::{OrganizationName, RepositoryCreationRequestBuilder, RepositoryName, Timestamp};
+use std::time::Duration;
+
+fn request(name: &str) -> RepositoryCreationRequest {
+    RepositoryCreationRequestBuilder::new(
+        RepositoryName::new(name).unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .build()
+}
+
+fn requests() -> Vec<RepositoryCreationRequest> {
+    ["repo-a", "repo-b", "repo-c", "repo-d"]
+        .into_iter()
+        .map(request)
+        .collect()
+}
+
+fn created(request: &RepositoryCreationRequest) -> RepositoryCreationResult {
+    RepositoryCreationResult {
+        repository_url: format!("https://github.com/{}", ledger_key(request)),
+        repository_id: "R_kgDOABCDEF".to_string(),
+        created_at: Timestamp::now(),
+        default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
+    }
+}
+
+/// Test that a failed request is recorded and does not stop the batch.
+#[tokio::test]
+async fn test_create_repositories_records_every_outcome() {
+    let ledger = InMemoryBatchLedger::new();
+
+    let dependencies = CreationDependencies::new();
+
+    let results = create_repositories(
+        requests(),
+        Some(&ledger),
+        &dependencies,
+        |req, _| async move {
+            match req.name.as_ref() {
+                "repo-b" => Err(RepositoryError::CreationFailed {
+                    reason: "boom".to_string(),
+                }
+                .into()),
+                _ => Ok(created(&req)),
+            }
+        },
+    )
+    .await
+    .expect("ledger should not fail");
+
+    assert_eq!(results.len(), 4);
+    assert!(matches!(results[1].status, BatchItemStatus::Failed(_)));
+    let entries = ledger.entries();
+    assert_eq!(
+        entries["test-org/repo-a"],
+        LedgerOutcome::Succeeded {
+            repository_url: "https://github.com/test-org/repo-a".to_string()
+        }
+    );
+    assert!(matches!(
+        entries["test-org/repo-b"],
+        LedgerOutcome::Failed { .. }
+    ));
+}
+
+/// Test that resuming after a crash skips completed requests and retries
+/// failed and unattempted ones.
+#[tokio::test]
+async fn test_resumed_batch_skips_completed_requests() {
+    let ledger = InMemoryBatchLedger::new();
+    let dependencies = CreationDependencies::new();
+
+    // First run: repo-a succeeds, repo-b fails, and the process "crashes"
+    // while creating repo-c.
+    let crashed_run = create_repositories(
+        requests(),
+        Some(&ledger),
+        &dependencies,
+        |req, _| async move {
+            match req.name.as_ref() {
+                "repo-a" => Ok(created(&req)),
+                "repo-b" => Err(RepositoryError::CreationFailed {
+                    reason: "transient".to_string(),
+                }
+                .into()),
+                _ => std::future::pending().await,
+            }
+        },
+    );
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), crashed_run)
+            .await
+            .is_err(),
+        "first run should be interrupted"
+    );
+
+    // Resumed run with the same ledger.
+    let attempted = Mutex::new(Vec::new());
+    let results = create_repositories(requests(), Some(&ledger), &dependencies, |req, _| {
+        attempted.lock().unwrap().push(req.name.to_string());
+        async move { Ok(created(&req)) }
+    })
+    .await
+    .expect("ledger should not fail");
+
+    assert_eq!(
+        attempted.into_inner().unwrap(),
+        vec!["repo-b", "repo-c", "repo-d"]
+    );
+    match &results[0].status {
+        BatchItemStatus::AlreadyCreated { repository_url } => {
+            assert_eq!(repository_url, "https://github.com/test-org/repo-a");
+        }
+        other => panic!("Expected repo-a to be skipped, got {:?}", other),
+    }
+    assert!(results[1..]
+        .iter()
+        .all(|r| matches!(r.status, BatchItemStatus::Created(_))));
+    assert_eq!(ledger.entries().len(), 4);
+}
@@ -0,0 +1,144 @@
+//! Generated CODEOWNERS files.
+//!
+//! When the organization enables `generate_codeowners`, the teams that own a
+//! new repository are recorded as the default code owners of every file in it.
+//! Teams a template lists in `required_code_owners` are always recorded,
+//! whatever the organization setting.
+//!
+//! # Merging with template content
+//!
+//! A CODEOWNERS file provided by the template is never replaced. The generated
+//! `*` rule is inserted at the top of the template's file so that any rule the
+//! template defines, being later in the file, still takes precedence for the
+//! paths it matches. The file GitHub would read is updated, checked in GitHub's
+//! lookup order (`.github/`, repository root, `docs/`); when the template has
+//! no CODEOWNERS file, `.github/CODEOWNERS` is created.
+
+use std::path::Path;
+
+use tracing::{debug, info};
+
+use crate::errors::{RepoRollerError, RepoRollerResult, SystemError};
+
+#[cfg(test)]
+#[path = "codeowners_tests.rs"]
+mod tests;
+
+/// Locations GitHub reads a CODEOWNERS file from, in lookup order.
+const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Comment line placed above the generated rule.
+const GENERATED_HEADER: &str =
+    "# Default owners generated by RepoRoller from the required and owning teams.";
+
+/// Combines the template's required code owners with the owning teams.
+///
+/// Required owners come first. Owning teams already listed as required owners
+/// are not repeated.
+pub(crate) fn codeowner_teams(required_owners: &[String], owning_teams: &[String]) -> Vec<String> {
+    let mut teams = required_owners.to_vec();
+    for team in owning_teams {
+        if !teams.contains(team) {
+            teams.push(team.clone());
+        }
+    }
+    teams
+}
+
+/// Builds the CODEOWNERS rule assigning every file to the owning teams.
+///
+/// Team names are written as `@{org}/{team}`. Names that already include an
+/// organization (`org/team` or `@org/team`) are used as given.
+///
+/// Returns `None` when there are no owning teams.
+pub(crate) fn generate_owner_rule(org: &str, owning_teams: &[String]) -> Option<String> {
+    let owners: Vec<String> = owning_teams
+        .iter()
+        .map(|team| team.trim().trim_start_matches('@'))
+        .filter(|team| !team.is_empty())
+        .map(|team| {
+            if team.contains('/') {
+                format!("@{}", team)
+            } else {
+                format!("@{}/{}", org, team)
+            }
+        })
+        .collect();
+
+    if owners.is_empty() {
+        return None;
+    }
+
+    Some(format!("* {}", owners.join(" ")))
+}
+
+/// Merges the generated owner rule into an existing CODEOWNERS file.
+///
+/// Without an existing file the result contains only the generated rule. An
+/// existing file is kept intact below the generated rule; if it already
+/// contains the rule it is returned unchanged.
+pub(crate) fn merge_codeowners(existing: Option<&str>, rule: &str) -> String {
+    let generated = format!("{}\n{}\n", GENERATED_HEADER, rule);
+    match existing {
+        None => generated,
+        Some(content) if content.lines().any(|line| line.trim() == rule) => content.to_string(),
+        Some(content) => format!("{}\n{}", generated, content),
+    }
+}
+
+/// Writes the generated CODEOWNERS rule into the local repository content.
+///
+/// Does nothing when there are no owning teams.
+///
+/// # Errors
+///
+/// Returns `SystemError::FileSystem` if the CODEOWNERS file cannot be read or
+/// written.
+pub(crate) fn write_codeowners(
+    local_repo_path: &Path,
+    org: &str,
+    owning_teams: &[String],
+) -> RepoRollerResult<()> {
+    let Some(rule) = generate_owner_rule(org, owning_teams) else {
+        debug!("No owning teams; skipping CODEOWNERS generation");
+        return Ok(());
+    };
+
+    let existing_location = CODEOWNERS_LOCATIONS
+        .iter()
+        .find(|location| local_repo_path.join(location).is_file());
+    let target = local_repo_path.join(existing_location.unwrap_or(&CODEOWNERS_LOCATIONS[0]));
+
+    let existing = match existing_location {
+        Some(_) => Some(std::fs::read_to_string(&target).map_err(|e| {
+            RepoRollerError::System(SystemError::FileSystem {
+                operation: format!("read {}", target.display()),
+                reason: e.to_string(),
+            })
+        })?),
+        None => None,
+    };
+
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            RepoRollerError::System(SystemError::FileSystem {
+                operation: format!("create directory {}", parent.display()),
+                reason: e.to_string(),
+            })
+        })?;
+    }
+
+    std::fs::write(&target, merge_codeowners(existing.as_deref(), &rule)).map_err(|e| {
+        RepoRollerError::System(SystemError::FileSystem {
+            operation: format!("write {}", target.display()),
+            reason: e.to_string(),
+        })
+    })?;
+
+    info!(
+        "Generated CODEOWNERS rule '{}' in {}",
+        rule,
+        existing_location.unwrap_or(&CODEOWNERS_LOCATIONS[0])
+    );
+    Ok(())
+}
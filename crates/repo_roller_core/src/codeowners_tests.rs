@@ -0,0 +1,116 @@
+//! Tests for CODEOWNERS generation.
+
+use super::*;
+use temp_dir::TempDir;
+
+#[test]
+fn test_codeowner_teams_puts_required_owners_first_without_duplicates() {
+    let required = vec!["security".to_string(), "platform".to_string()];
+    let owning = vec!["platform".to_string(), "payments".to_string()];
+
+    assert_eq!(
+        codeowner_teams(&required, &owning),
+        vec![
+            "security".to_string(),
+            "platform".to_string(),
+            "payments".to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_generate_owner_rule_single_team() {
+    let rule = generate_owner_rule("acme", &["platform".to_string()]);
+
+    assert_eq!(rule.as_deref(), Some("* @acme/platform"));
+}
+
+#[test]
+fn test_generate_owner_rule_multiple_teams_keeps_order_and_qualified_names() {
+    let rule = generate_owner_rule(
+        "acme",
+        &[
+            "platform".to_string(),
+            "@other-org/security".to_string(),
+            "acme/sre".to_string(),
+        ],
+    );
+
+    assert_eq!(
+        rule.as_deref(),
+        Some("* @acme/platform @other-org/security @acme/sre")
+    );
+}
+
+#[test]
+fn test_generate_owner_rule_without_teams_is_none() {
+    assert_eq!(generate_owner_rule("acme", &[]), None);
+    assert_eq!(generate_owner_rule("acme", &["  ".to_string()]), None);
+}
+
+#[test]
+fn test_merge_codeowners_without_existing_file() {
+    let merged = merge_codeowners(None, "* @acme/platform");
+
+    assert_eq!(merged, format!("{}\n* @acme/platform\n", GENERATED_HEADER));
+}
+
+#[test]
+fn test_merge_codeowners_keeps_template_rules_after_generated_rule() {
+    let existing = "/docs/ @acme/docs\n*.rs @acme/rust\n";
+
+    let merged = merge_codeowners(Some(existing), "* @acme/platform");
+
+    assert_eq!(
+        merged,
+        format!(
+            "{}\n* @acme/platform\n\n/docs/ @acme/docs\n*.rs @acme/rust\n",
+            GENERATED_HEADER
+        )
+    );
+}
+
+#[test]
+fn test_merge_codeowners_is_unchanged_when_rule_already_present() {
+    let existing = "* @acme/platform\n/docs/ @acme/docs\n";
+
+    assert_eq!(
+        merge_codeowners(Some(existing), "* @acme/platform"),
+        existing
+    );
+}
+
+#[test]
+fn test_write_codeowners_creates_github_codeowners_for_single_team() {
+    let temp_dir = TempDir::new().unwrap();
+
+    write_codeowners(temp_dir.path(), "acme", &["platform".to_string()]).unwrap();
+
+    let content = std::fs::read_to_string(temp_dir.path().join(".github/CODEOWNERS")).unwrap();
+    assert!(content.lines().any(|line| line == "* @acme/platform"));
+}
+
+#[test]
+fn test_write_codeowners_merges_with_template_file() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("CODEOWNERS"), "*.md @acme/docs\n").unwrap();
+
+    write_codeowners(temp_dir.path(), "acme", &["platform".to_string()]).unwrap();
+
+    // The template's file is updated in place; no second file is created.
+    assert!(!temp_dir.path().join(".github/CODEOWNERS").exists());
+    let content = std::fs::read_to_string(temp_dir.path().join("CODEOWNERS")).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    let generated = lines.iter().position(|l| *l == "* @acme/platform").unwrap();
+    let template = lines.iter().position(|l| *l == "*.md @acme/docs").unwrap();
+    assert!(generated < template);
+}
+
+#[test]
+fn test_write_codeowners_without_teams_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+
+    write_codeowners(temp_dir.path(), "acme", &[]).unwrap();
+
+    assert!(!temp_dir.path().join(".github/CODEOWNERS").exists());
+}
@@ -12,7 +12,8 @@
 //! ## Configuration Resolution
 //!
 //! The [`resolve_organization_configuration`] function:
-//! 1. Creates a metadata provider to access the organization's metadata repository
+//! 1. Creates a metadata provider for the organization's metadata repository from
+//!    the given `MetadataProviderConfig`, reading a combined file when one is named
 //! 2. Uses `OrganizationSettingsManager` to resolve configuration hierarchy
 //! 3. Merges organization, template, and global defaults
 //! 4. Falls back to global defaults if metadata repository is unavailable
@@ -24,9 +25,14 @@
 //! 2. Creates webhooks (future implementation)
 //! 3. Sets custom properties including repository type
 //!
+//! The [`apply_actions_configuration`] function provisions GitHub Actions
+//! variables and secrets; secrets are resolved from an external store by
+//! reference and encrypted before upload.
+//!
 //! ## Error Handling
 //!
 //! - Configuration resolution failures fall back to global defaults with warnings
+//! - Conflicting configuration between owning teams is a validation error
 //! - Application failures return errors to prevent incomplete repository setup
 //!
 //! ## Examples
@@ -37,7 +43,10 @@
 //!     installation_token,
 //!     "my-org",
 //!     "rust-service",
-//!     ".reporoller"
+//!     &[],
+//!     MetadataProviderConfig::explicit(".reporoller"),
+//!     None,
+//!     None,
 //! ).await?;
 //!
 //! // Apply configuration to repository
@@ -49,9 +58,10 @@
 //! ).await?;
 //! ```
 
-use crate::errors::{GitHubError, RepoRollerError, RepoRollerResult, SystemError};
-use crate::{LabelManager, RulesetManager, WebhookManager};
-use github_client::{GitHubClient, RepositoryClient};
+use crate::errors::{GitHubError, RepoRollerError, RepoRollerResult, SystemError, ValidationError};
+use crate::{GitHubAppManager, LabelManager, RulesetManager, WebhookManager};
+use github_client::RepositoryClient;
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 #[cfg(test)]
@@ -73,7 +83,9 @@ mod tests;
 ///
 /// ## Metadata Repository
 ///
-/// The metadata repository (typically named `.reporoller` or similar) contains:
+/// The metadata repository (typically named `.reporoller` or similar) is
+/// discovered and read as `metadata_provider_config` describes, including the
+/// git ref, placeholder values, request timeout and fetch concurrency. It contains:
 /// - Organization-wide default settings
 /// - Template-specific configuration overrides
 /// - Label definitions
@@ -87,12 +99,19 @@ mod tests;
 /// - Falls back to global defaults
 /// - Allows repository creation to proceed with minimal configuration
 ///
+/// Conflicting override settings between owning teams never fall back; they
+/// are returned as a validation error.
+///
 /// ## Parameters
 ///
 /// * `installation_token` - GitHub App installation token for authentication
 /// * `organization` - Organization name where the repository will be created
 /// * `template_name` - Name of the template being used
-/// * `metadata_repository_name` - Name of the metadata repository (e.g., ".reporoller")
+/// * `teams` - Teams owning the repository, in precedence order
+/// * `metadata_provider_config` - How the metadata repository is discovered and read
+/// * `combined_metadata_file` - Path of a combined configuration file; `None` reads the
+///   `global/`, `teams/` and `types/` layout
+/// * `github_api_base_url` - GitHub API base URL; `None` uses `https://api.github.com`
 ///
 /// ## Returns
 ///
@@ -103,6 +122,7 @@ mod tests;
 /// Returns `RepoRollerError` if:
 /// - GitHub client creation fails
 /// - Configuration structure is invalid
+/// - Owning teams set conflicting override values
 /// - Internal errors occur during resolution
 ///
 /// Note: Metadata repository access failures result in fallback, not errors.
@@ -114,7 +134,10 @@ mod tests;
 ///     installation_token,
 ///     "acme-corp",
 ///     "rust-service",
-///     ".reporoller"
+///     &["backend".to_string()],
+///     MetadataProviderConfig::explicit(".reporoller").with_git_ref("config-staging"),
+///     None,
+///     None,
 /// ).await?;
 ///
 /// println!("Resolved {} labels", config.labels.len());
@@ -124,39 +147,59 @@ pub(crate) async fn resolve_organization_configuration(
     installation_token: &str,
     organization: &str,
     template_name: &str,
-    metadata_repository_name: &str,
+    teams: &[String],
+    metadata_provider_config: config_manager::MetadataProviderConfig,
+    combined_metadata_file: Option<&str>,
+    github_api_base_url: Option<&str>,
 ) -> RepoRollerResult<config_manager::MergedConfiguration> {
     use config_manager::{
-        ConfigurationContext, GitHubMetadataProvider, MetadataProviderConfig,
-        OrganizationSettingsManager,
+        CombinedFileMetadataProvider, ConfigurationContext, GitHubMetadataProvider,
+        MetadataRepositoryProvider, OrganizationSettingsManager,
     };
-    use std::sync::Arc;
 
     info!("Resolving organization configuration");
 
     info!("Creating metadata provider for repository discovery");
-    info!("Metadata repository name: {}", metadata_repository_name);
+    info!(
+        "Metadata repository git ref: {}",
+        metadata_provider_config
+            .git_ref()
+            .unwrap_or("default branch")
+    );
 
     // Create a separate client for the metadata provider
-    let metadata_client = github_client::create_token_client(installation_token).map_err(|e| {
-        error!("Failed to create metadata provider client: {}", e);
-        RepoRollerError::System(SystemError::Internal {
-            reason: format!("Failed to create metadata provider client: {}", e),
-        })
-    })?;
-    let metadata_repo_client = GitHubClient::new(metadata_client.clone());
-
-    let metadata_provider_config = MetadataProviderConfig::explicit(metadata_repository_name);
-    let metadata_provider = Arc::new(GitHubMetadataProvider::new(
-        metadata_repo_client,
-        metadata_provider_config,
-    ));
+    let metadata_repo_client =
+        github_client::create_github_client(installation_token, github_api_base_url).map_err(
+            |e| {
+                error!("Failed to create metadata provider client: {}", e);
+                RepoRollerError::System(SystemError::Internal {
+                    reason: format!("Failed to create metadata provider client: {}", e),
+                })
+            },
+        )?;
+
+    let metadata_provider: Arc<dyn MetadataRepositoryProvider> = match combined_metadata_file {
+        Some(file_path) => {
+            info!("Reading combined configuration file: {}", file_path);
+            Arc::new(
+                CombinedFileMetadataProvider::new(
+                    metadata_repo_client.clone(),
+                    metadata_provider_config,
+                )
+                .with_file_path(file_path),
+            )
+        }
+        None => Arc::new(GitHubMetadataProvider::new(
+            metadata_repo_client.clone(),
+            metadata_provider_config,
+        )),
+    };
 
     info!("Metadata provider created successfully");
 
     // Create template loader for template configuration resolution
-    // Template loader needs Arc<GitHubClient> so create a separate client instance
-    let template_client = GitHubClient::new(metadata_client);
+    // Template loader needs Arc<GitHubClient> so it gets its own client instance
+    let template_client = metadata_repo_client;
     let template_repo = Arc::new(config_manager::GitHubTemplateRepository::new(Arc::new(
         template_client,
     )));
@@ -164,15 +207,39 @@ pub(crate) async fn resolve_organization_configuration(
 
     let settings_manager = OrganizationSettingsManager::new(metadata_provider, template_loader);
 
-    let config_context = ConfigurationContext::new(organization, template_name);
+    let config_context =
+        ConfigurationContext::new(organization, template_name).with_teams(teams.iter().cloned());
 
-    info!("Calling settings_manager.resolve_configuration with context: org={}, template={}, team={:?}, repo_type={:?}",
-           organization, template_name, config_context.team(), config_context.repository_type());
+    info!("Calling settings_manager.resolve_configuration with context: org={}, template={}, teams={:?}, repo_type={:?}",
+           organization, template_name, config_context.teams(), config_context.repository_type());
 
     let merged_config = settings_manager
         .resolve_configuration(&config_context)
         .await
-        .or_else(|e: config_manager::ConfigurationError| -> config_manager::ConfigurationResult<config_manager::MergedConfiguration> {
+        .or_else(|e: config_manager::ConfigurationError| -> RepoRollerResult<config_manager::MergedConfiguration> {
+            // Conflicts between owning teams are a problem with the request, not a
+            // missing metadata repository, so they must not fall back to defaults.
+            if let config_manager::ConfigurationError::TeamConfigurationConflict { errors } = &e {
+                let reason = errors
+                    .iter()
+                    .map(|err| err.message.clone())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                error!("Owning team configurations conflict: {}", reason);
+                return Err(RepoRollerError::Validation(ValidationError::invalid_format(
+                    "owning_teams",
+                    reason,
+                )));
+            }
+            // A template the organization does not permit must never be used,
+            // even when the rest of the configuration is unavailable.
+            if let config_manager::ConfigurationError::TemplateNotAllowed { .. } = &e {
+                error!("Template rejected by organization policy: {}", e);
+                return Err(RepoRollerError::Validation(ValidationError::invalid_format(
+                    "template",
+                    e.to_string(),
+                )));
+            }
             error!(
                 "Failed to resolve organization configuration: {}. Using global defaults.",
                 e
@@ -196,6 +263,65 @@ pub(crate) async fn resolve_organization_configuration(
     Ok(merged_config)
 }
 
+/// Remove settings that only exist for organization-owned repositories.
+///
+/// Personal accounts have no teams and no custom properties, so default team
+/// grants and custom properties resolved from the metadata repository would
+/// fail when applied. They are dropped with a warning instead.
+pub(crate) fn remove_organization_only_settings(
+    merged_config: &mut config_manager::MergedConfiguration,
+) {
+    if !merged_config.teams.is_empty() {
+        warn!(
+            "Skipping {} team grant(s) for a repository owned by a personal account",
+            merged_config.teams.len()
+        );
+        merged_config.teams.clear();
+        merged_config.locked_teams.clear();
+    }
+
+    if !merged_config.custom_properties.is_empty() {
+        warn!(
+            "Skipping {} custom properties for a repository owned by a personal account",
+            merged_config.custom_properties.len()
+        );
+        merged_config.custom_properties.clear();
+    }
+}
+
+/// Collect non-blocking validation warnings for a resolved configuration.
+///
+/// Runs the [`config_manager::BasicConfigurationValidator`] against the merged
+/// configuration (which already includes the template's settings) and returns
+/// only its warnings, such as webhooks delivered over HTTP or without a secret.
+///
+/// Warnings never block repository creation. Validation errors are not reported
+/// here because hard failures are surfaced by configuration resolution itself.
+pub async fn collect_configuration_warnings(
+    merged_config: &config_manager::MergedConfiguration,
+) -> Vec<config_manager::ValidationWarning> {
+    use config_manager::ConfigurationValidator;
+
+    match config_manager::BasicConfigurationValidator::new()
+        .validate_merged_config(merged_config)
+        .await
+    {
+        Ok(result) => {
+            for warning in &result.warnings {
+                warn!(
+                    field = warning.field_path.as_str(),
+                    "Configuration warning: {}", warning.message
+                );
+            }
+            result.warnings
+        }
+        Err(e) => {
+            warn!("Failed to collect configuration warnings: {}", e);
+            Vec::new()
+        }
+    }
+}
+
 /// Apply merged configuration to a newly created repository.
 ///
 /// This function applies the resolved organization configuration to a repository,
@@ -208,21 +334,32 @@ pub(crate) async fn resolve_organization_configuration(
 ///
 /// ### Labels
 /// - Creates repository labels with specified colors and descriptions
+/// - Deletes all other labels first when `replace_default_labels` is set
 /// - Uses LabelManager for orchestration (idempotent, handles partial failures)
 ///
 /// ### Webhooks
 /// - Configures repository webhooks for events
 /// - Uses WebhookManager for orchestration (validates, deduplicates, secure)
 ///
+/// ### GitHub Apps
+/// - Checks that configured apps are installed in the organization
+/// - Uses GitHubAppManager (required apps missing are fatal, optional ones warn)
+///
 /// ### Custom Properties
 /// - Sets custom repository properties including repository type
 /// - Uses GitHub's custom properties API directly
 ///
+/// ### Default Branch
+/// - Makes the configured `branch_protection.default_branch` the repository's
+///   default branch when it differs from `pushed_branch`
+/// - Skipped with a warning when the configured branch does not exist
+///
 /// ## Parameters
 ///
 /// * `installation_repo_client` - Authenticated GitHub client for repository operations
 /// * `owner` - Repository owner (organization or user)
 /// * `repo_name` - Name of the repository
+/// * `pushed_branch` - Branch the initial content was pushed to
 /// * `merged_config` - Resolved configuration from `resolve_organization_configuration`
 ///
 /// ## Returns
@@ -233,7 +370,9 @@ pub(crate) async fn resolve_organization_configuration(
 ///
 /// Returns `RepoRollerError` if:
 /// - Label/webhook operations fail
+/// - A required GitHub App is not installed
 /// - Custom properties API call fails
+/// - The default branch cannot be checked or changed
 /// - Network errors occur
 /// - Authentication is insufficient
 ///
@@ -246,6 +385,7 @@ pub(crate) async fn resolve_organization_configuration(
 ///     &client,
 ///     "acme-corp",
 ///     "new-service",
+///     "main",
 ///     &merged_config
 /// ).await?;
 ///
@@ -258,14 +398,19 @@ pub(crate) async fn resolve_organization_configuration(
 /// - Webhooks: Applied via WebhookManager (validates, deduplicates, secure)
 /// - Rulesets: Applied via RulesetManager (idempotent, conflict detection)
 /// - Custom Properties: Applied via GitHub API (including repository type)
+/// - Default Branch: Changed via GitHub API once the branch exists
+///
+/// Push settings are applied to the default branch separately by
+/// [`apply_branch_push_protection`], which needs the branch name.
 ///
 /// ## Future Enhancements
 ///
 /// - Branch protection rules application
 pub(crate) async fn apply_repository_configuration(
-    installation_repo_client: &GitHubClient,
+    installation_repo_client: &Arc<dyn RepositoryClient>,
     owner: &str,
     repo_name: &str,
+    pushed_branch: &str,
     merged_config: &config_manager::MergedConfiguration,
 ) -> RepoRollerResult<()> {
     info!(
@@ -280,19 +425,29 @@ pub(crate) async fn apply_repository_configuration(
         merged_config.labels.is_empty()
     );
 
-    if !merged_config.labels.is_empty() {
+    if merged_config.replace_default_labels || !merged_config.labels.is_empty() {
         info!(
             "Labels to apply: {:?}",
             merged_config.labels.keys().collect::<Vec<_>>()
         );
         let label_manager = LabelManager::new(installation_repo_client.clone());
-        let label_result = label_manager
-            .apply_labels(owner, repo_name, &merged_config.labels)
-            .await?;
+        let label_result = if merged_config.replace_default_labels {
+            label_manager
+                .replace_labels(owner, repo_name, &merged_config.labels)
+                .await?
+        } else {
+            label_manager
+                .apply_labels(owner, repo_name, &merged_config.labels)
+                .await?
+        };
 
         info!(
-            "Label application complete: created={}, updated={}, failed={}, skipped={}",
-            label_result.created, label_result.updated, label_result.failed, label_result.skipped
+            "Label application complete: created={}, updated={}, deleted={}, failed={}, skipped={}",
+            label_result.created,
+            label_result.updated,
+            label_result.deleted,
+            label_result.failed,
+            label_result.skipped
         );
 
         if label_result.failed > 0 {
@@ -326,6 +481,26 @@ pub(crate) async fn apply_repository_configuration(
         }
     }
 
+    // Check GitHub Apps using GitHubAppManager
+    if !merged_config.github_apps.is_empty() {
+        let app_manager = GitHubAppManager::new(installation_repo_client.clone());
+        let app_result = app_manager
+            .apply_github_apps(owner, repo_name, &merged_config.github_apps)
+            .await?;
+
+        for outcome in &app_result.outcomes {
+            info!(
+                "GitHub App {}: status={:?}, required={}, permissions={:?}",
+                outcome.app_id, outcome.status, outcome.required, outcome.permissions
+            );
+        }
+
+        let missing: Vec<u64> = app_result.missing_optional().map(|o| o.app_id).collect();
+        if !missing.is_empty() {
+            warn!("Optional GitHub App(s) not installed: {:?}", missing);
+        }
+    }
+
     // Apply rulesets using RulesetManager
     if !merged_config.rulesets.is_empty() {
         info!(
@@ -363,6 +538,27 @@ pub(crate) async fn apply_repository_configuration(
         }
     }
 
+    // Apply GitHub Actions permissions
+    if let Some((enabled, default_workflow_permissions)) = actions_permissions(merged_config) {
+        installation_repo_client
+            .set_actions_permissions(owner, repo_name, enabled, default_workflow_permissions)
+            .await
+            .map_err(|e| {
+                error!("Failed to set Actions permissions on repository: {}", e);
+                RepoRollerError::GitHub(GitHubError::NetworkError {
+                    reason: format!(
+                        "Failed to set Actions permissions on {}/{}: {}",
+                        owner, repo_name, e
+                    ),
+                })
+            })?;
+
+        info!(
+            "Actions permissions applied: enabled={}, default_workflow_permissions={:?}",
+            enabled, default_workflow_permissions
+        );
+    }
+
     // Apply custom properties (including repository type)
     if !merged_config.custom_properties.is_empty() {
         debug!(
@@ -415,5 +611,273 @@ pub(crate) async fn apply_repository_configuration(
         );
     }
 
+    // Switch the default branch when the configuration names another branch
+    if let Some(default_branch) = merged_config
+        .branch_protection
+        .default_branch
+        .as_ref()
+        .map(|v| v.value.as_str())
+        .filter(|branch| *branch != pushed_branch)
+    {
+        apply_default_branch(installation_repo_client, owner, repo_name, default_branch).await?;
+    }
+
+    Ok(())
+}
+
+/// Make `branch` the default branch of the repository once it exists.
+///
+/// A branch that does not exist is skipped with a warning, since GitHub
+/// rejects a default branch that has not been pushed.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if the branch cannot be looked up or
+/// the default branch cannot be changed.
+pub(crate) async fn apply_default_branch(
+    installation_repo_client: &Arc<dyn RepositoryClient>,
+    owner: &str,
+    repo_name: &str,
+    branch: &str,
+) -> RepoRollerResult<()> {
+    let to_error = |e: github_client::Error| {
+        error!("Failed to set default branch on repository: {}", e);
+        RepoRollerError::GitHub(GitHubError::NetworkError {
+            reason: format!(
+                "Failed to set default branch '{}' on {}/{}: {}",
+                branch, owner, repo_name, e
+            ),
+        })
+    };
+
+    if !installation_repo_client
+        .branch_exists(owner, repo_name, branch)
+        .await
+        .map_err(to_error)?
+    {
+        warn!(
+            "Configured default branch '{}' does not exist in {}/{}; keeping the current default",
+            branch, owner, repo_name
+        );
+        return Ok(());
+    }
+
+    installation_repo_client
+        .set_default_branch(owner, repo_name, branch)
+        .await
+        .map_err(to_error)?;
+
+    info!(
+        "Default branch of {}/{} set to '{}'",
+        owner, repo_name, branch
+    );
+    Ok(())
+}
+
+/// Derive the GitHub Actions permissions to apply to the repository.
+///
+/// Returns whether Actions is enabled and the default workflow permissions
+/// (`"read"` or `"write"`), or `None` when the merged Actions settings set
+/// neither. Actions stays enabled when only the workflow permissions are set,
+/// matching GitHub's default.
+pub(crate) fn actions_permissions(
+    merged_config: &config_manager::MergedConfiguration,
+) -> Option<(bool, Option<&'static str>)> {
+    let actions = &merged_config.actions;
+    if actions.enabled.is_none() && actions.default_workflow_permissions.is_none() {
+        return None;
+    }
+
+    let enabled = actions.enabled.as_ref().is_none_or(|v| v.value);
+    let default_workflow_permissions = actions
+        .default_workflow_permissions
+        .as_ref()
+        .map(|v| v.value.as_str());
+
+    Some((enabled, default_workflow_permissions))
+}
+
+/// Derive the push and review rules to enforce on the default branch.
+///
+/// Returns `None` when the merged push settings set neither force pushes nor
+/// signed commits, code owner reviews are not required, for example by a
+/// template's `required_code_owners`, and no status checks are required. An
+/// empty `required_status_checks` list leaves required status checks
+/// disabled. These rules are branch protection sub-settings, so when no branch protection is configured they are skipped
+/// with a warning rather than protecting the branch implicitly. A push-level `allow_force_pushes` takes
+/// precedence over the branch protection one.
+pub(crate) fn branch_push_protection(
+    merged_config: &config_manager::MergedConfiguration,
+) -> Option<github_client::BranchPushProtection> {
+    let push = &merged_config.push;
+    let protection = &merged_config.branch_protection;
+    let require_code_owner_reviews = protection
+        .require_code_owner_reviews
+        .as_ref()
+        .is_some_and(|v| v.value);
+    let required_status_checks = protection
+        .required_status_checks
+        .as_ref()
+        .map(|v| v.value.clone())
+        .unwrap_or_default();
+    if push.allow_force_pushes.is_none()
+        && push.require_signed_commits.is_none()
+        && !require_code_owner_reviews
+        && required_status_checks.is_empty()
+    {
+        return None;
+    }
+
+    if *protection == config_manager::settings::BranchProtectionSettings::default() {
+        warn!(
+            "Push settings (allow_force_pushes, require_signed_commits) require branch protection, \
+             which is not configured; skipping"
+        );
+        return None;
+    }
+
+    let allow_force_pushes = push
+        .allow_force_pushes
+        .as_ref()
+        .or(protection.allow_force_pushes.as_ref())
+        .is_some_and(|v| v.value);
+    let required_signatures = push
+        .require_signed_commits
+        .as_ref()
+        .is_some_and(|v| v.value);
+
+    Some(github_client::BranchPushProtection {
+        allow_force_pushes,
+        required_signatures,
+        require_code_owner_reviews,
+        required_status_checks,
+        strict_required_status_checks: protection
+            .strict_required_status_checks
+            .as_ref()
+            .is_some_and(|v| v.value),
+    })
+}
+
+/// Apply the merged push and review rules to the repository's default branch.
+///
+/// See [`branch_push_protection`] for when nothing is applied.
+///
+/// ## Errors
+///
+/// Returns `RepoRollerError::GitHub` if GitHub rejects the protection update.
+pub(crate) async fn apply_branch_push_protection(
+    installation_repo_client: &dyn RepositoryClient,
+    owner: &str,
+    repo_name: &str,
+    default_branch: &str,
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<()> {
+    let Some(rules) = branch_push_protection(merged_config) else {
+        return Ok(());
+    };
+
+    installation_repo_client
+        .update_branch_push_protection(owner, repo_name, default_branch, &rules)
+        .await
+        .map_err(|e| {
+            error!("Failed to apply push settings to branch protection: {}", e);
+            RepoRollerError::GitHub(GitHubError::NetworkError {
+                reason: format!(
+                    "Failed to protect branch '{}' on {}/{}: {}",
+                    default_branch, owner, repo_name, e
+                ),
+            })
+        })?;
+
+    info!(
+        "Applied push settings to branch '{}': allow_force_pushes={}, required_signatures={}",
+        default_branch, rules.allow_force_pushes, rules.required_signatures
+    );
     Ok(())
 }
+
+/// Provision GitHub Actions variables and secrets on a newly created repository.
+///
+/// Variables are written verbatim. Secrets are declared in configuration only by
+/// reference; each `secret_ref` is resolved through `secret_resolver` at
+/// provisioning time and the plaintext value is sealed against the repository's
+/// Actions public key before upload. Secret values are never logged.
+///
+/// ## Parameters
+///
+/// * `repo_client` - Authenticated GitHub client for repository operations
+/// * `owner` - Repository owner (organization or user)
+/// * `repo_name` - Name of the repository
+/// * `merged_config` - Resolved configuration from `resolve_organization_configuration`
+/// * `secret_resolver` - Resolves secret references to their values
+///
+/// ## Error Handling
+///
+/// Individual failures (including unresolvable secret references) are logged as
+/// warnings and do not abort provisioning, matching label and webhook handling.
+pub(crate) async fn apply_actions_configuration(
+    repo_client: &dyn RepositoryClient,
+    owner: &str,
+    repo_name: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    secret_resolver: &dyn crate::event_secrets::SecretResolver,
+) {
+    let mut failed: Vec<String> = Vec::new();
+
+    for variable in &merged_config.actions_variables {
+        if let Err(e) = repo_client
+            .set_actions_variable(owner, repo_name, &variable.name, &variable.value)
+            .await
+        {
+            warn!(
+                name = variable.name.as_str(),
+                error = ?e,
+                "Failed to set Actions variable"
+            );
+            failed.push(variable.name.clone());
+        }
+    }
+
+    for secret in &merged_config.actions_secrets {
+        let value = match secret_resolver.resolve_secret(&secret.secret_ref).await {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(
+                    name = secret.name.as_str(),
+                    secret_ref = secret.secret_ref.as_str(),
+                    error = %e,
+                    "Failed to resolve Actions secret reference"
+                );
+                failed.push(secret.name.clone());
+                continue;
+            }
+        };
+
+        if let Err(e) = repo_client
+            .set_actions_secret(owner, repo_name, &secret.name, &value)
+            .await
+        {
+            warn!(
+                name = secret.name.as_str(),
+                error = ?e,
+                "Failed to set Actions secret"
+            );
+            failed.push(secret.name.clone());
+        }
+    }
+
+    info!(
+        "Actions provisioning complete: variables={}, secrets={}, failed={}",
+        merged_config.actions_variables.len(),
+        merged_config.actions_secrets.len(),
+        failed.len()
+    );
+
+    if !failed.is_empty() {
+        warn!(
+            "Failed to provision {} Actions variable(s)/secret(s): {:?}",
+            failed.len(),
+            failed
+        );
+    }
+}
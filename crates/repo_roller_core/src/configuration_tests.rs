@@ -12,6 +12,15 @@
 // Unit tests here focus on ensuring the module compiles and exports
 // the expected functions.
 
+use super::*;
+use config_manager::settings::{LabelConfig, WebhookConfig};
+use config_manager::{MergedConfiguration, OverridableValue};
+use github_client::GitHubClient;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
 /// Verify that the configuration module compiles and exports expected functions.
 ///
 /// This test ensures the module's public interface is available.
@@ -31,3 +40,424 @@ fn test_configuration_module_compiles() {
 // - MetadataProvider for configuration access
 // - Full configuration resolution workflow
 // - Configuration application with custom properties
+
+/// Creates a [`GitHubClient`] pointed at a wiremock server for testing.
+fn create_test_github_client(server_uri: &str) -> GitHubClient {
+    let octocrab = Octocrab::builder()
+        .base_uri(server_uri)
+        .expect("valid URI from wiremock")
+        .personal_token("test-token".to_string())
+        .build()
+        .expect("octocrab builder succeeds with valid base_uri");
+    GitHubClient::new(octocrab)
+}
+
+/// Builds an active JSON webhook configuration for `push` events.
+fn webhook(url: &str) -> WebhookConfig {
+    WebhookConfig {
+        url: url.to_string(),
+        content_type: "json".to_string(),
+        secret: None,
+        active: true,
+        events: vec!["push".to_string()],
+    }
+}
+
+/// Verify that re-applying configuration after a partial failure leaves
+/// the labels that already exist alone and only creates the missing webhook.
+#[tokio::test]
+async fn test_reapply_skips_existing_labels_and_creates_missing_webhook() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "name": "bug", "color": "d73a4a", "description": "Something isn't working" },
+            { "id": 2, "name": "triage", "color": "ededed", "description": "Needs triage" }
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/bug"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/triage"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let existing_hook = json!({
+        "id": 11,
+        "url": "https://api.github.com/repos/test-org/test-repo/hooks/11",
+        "active": true,
+        "events": ["push"],
+        "config": {
+            "url": "https://ci.example.com/hook",
+            "content_type": "json",
+            "insecure_ssl": "0"
+        },
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    });
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/hooks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([existing_hook])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/test-repo/hooks"))
+        .and(wiremock::matchers::body_partial_json(json!({
+            "config": { "url": "https://chat.example.com/hook" }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 12,
+            "url": "https://api.github.com/repos/test-org/test-repo/hooks/12",
+            "active": true,
+            "events": ["push"],
+            "config": {
+                "url": "https://chat.example.com/hook",
+                "content_type": "json",
+                "insecure_ssl": "0"
+            },
+            "created_at": "2024-01-02T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/hooks/11"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut config = MergedConfiguration::new();
+    for (name, color, description) in [
+        ("bug", "d73a4a", "Something isn't working"),
+        ("triage", "#EDEDED", "Needs triage"),
+    ] {
+        config.labels.insert(
+            name.to_string(),
+            LabelConfig {
+                name: name.to_string(),
+                color: color.to_string(),
+                description: description.to_string(),
+            },
+        );
+    }
+    config.webhooks = vec![
+        webhook("https://ci.example.com/hook"),
+        webhook("https://chat.example.com/hook"),
+    ];
+
+    let client: Arc<dyn RepositoryClient> = create_test_github_client(&server.uri()).into();
+    apply_repository_configuration(&client, "test-org", "test-repo", "main", &config)
+        .await
+        .expect("configuration should be applied");
+}
+
+/// Verify that re-applying configuration converges a label whose color
+/// changed by updating it in place.
+#[tokio::test]
+async fn test_reapply_updates_label_with_changed_color() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "name": "bug", "color": "d73a4a", "description": "Something isn't working" }
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/bug"))
+        .and(wiremock::matchers::body_partial_json(json!({
+            "new_name": "bug",
+            "color": "b60205"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1, "name": "bug", "color": "b60205", "description": "Something isn't working"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut config = MergedConfiguration::new();
+    config.labels.insert(
+        "bug".to_string(),
+        LabelConfig {
+            name: "bug".to_string(),
+            color: "b60205".to_string(),
+            description: "Something isn't working".to_string(),
+        },
+    );
+
+    let client = create_test_github_client(&server.uri());
+    let result = LabelManager::new(client)
+        .apply_labels("test-org", "test-repo", &config.labels)
+        .await
+        .expect("labels should be applied");
+
+    assert_eq!(result.updated, 1);
+    assert_eq!(result.failed, 0);
+}
+
+/// Verify that with `replace_default_labels` set, GitHub's stock labels are
+/// deleted and only the configured labels remain.
+#[tokio::test]
+async fn test_replace_default_labels_removes_stock_labels() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "name": "bug", "color": "d73a4a", "description": "Something isn't working" },
+            { "id": 2, "name": "documentation", "color": "0075ca", "description": "Improvements or additions to documentation" },
+            { "id": 3, "name": "enhancement", "color": "a2eeef", "description": "New feature or request" }
+        ])))
+        .mount(&server)
+        .await;
+    for (name, expected) in [("bug", 0), ("documentation", 1), ("enhancement", 1)] {
+        Mock::given(method("DELETE"))
+            .and(path(format!("/repos/test-org/test-repo/labels/{name}")))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(expected)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo/labels/bug"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut config = MergedConfiguration::new();
+    config.replace_default_labels = true;
+    config.labels.insert(
+        "bug".to_string(),
+        LabelConfig {
+            name: "bug".to_string(),
+            color: "d73a4a".to_string(),
+            description: "Something isn't working".to_string(),
+        },
+    );
+
+    let client: Arc<dyn RepositoryClient> = create_test_github_client(&server.uri()).into();
+    apply_repository_configuration(&client, "test-org", "test-repo", "main", &config)
+        .await
+        .expect("configuration should be applied");
+}
+
+/// Verify that team grants and custom properties are dropped for personal
+/// repositories while repository-level settings are kept.
+#[test]
+fn test_remove_organization_only_settings() {
+    use config_manager::settings::custom_property::{CustomProperty, CustomPropertyValue};
+
+    let mut config = MergedConfiguration::new();
+    config
+        .teams
+        .insert("platform".to_string(), "write".to_string());
+    config.locked_teams.insert("platform".to_string());
+    config
+        .collaborators
+        .insert("alice".to_string(), "read".to_string());
+    config.custom_properties.push(CustomProperty {
+        property_name: "repo_type".to_string(),
+        value: CustomPropertyValue::String("service".to_string()),
+    });
+    config.labels.insert(
+        "bug".to_string(),
+        LabelConfig {
+            name: "bug".to_string(),
+            color: "d73a4a".to_string(),
+            description: String::new(),
+        },
+    );
+
+    remove_organization_only_settings(&mut config);
+
+    assert!(config.teams.is_empty());
+    assert!(config.locked_teams.is_empty());
+    assert!(config.custom_properties.is_empty());
+    assert_eq!(config.collaborators.len(), 1);
+    assert_eq!(config.labels.len(), 1);
+}
+
+fn push_config(require_signed_commits: bool) -> MergedConfiguration {
+    let mut config = MergedConfiguration::new();
+    config.branch_protection.require_pull_request_reviews = Some(OverridableValue::allowed(true));
+    config.push.require_signed_commits = Some(OverridableValue::allowed(require_signed_commits));
+    config
+}
+
+/// `require_signed_commits` maps to `required_signatures` on the default branch.
+#[test]
+fn test_branch_push_protection_requires_signatures() {
+    let rules = branch_push_protection(&push_config(true)).expect("rules should be applied");
+
+    assert!(rules.required_signatures);
+    assert!(!rules.allow_force_pushes);
+}
+
+/// Push settings are skipped when no branch protection is configured.
+#[test]
+fn test_branch_push_protection_skipped_without_branch_protection() {
+    let mut config = push_config(true);
+    config.branch_protection = config_manager::settings::BranchProtectionSettings::default();
+
+    assert!(branch_push_protection(&config).is_none());
+}
+
+/// Workflow permissions are mapped to GitHub's values and keep Actions enabled.
+#[test]
+fn test_actions_permissions_maps_workflow_permission() {
+    use config_manager::settings::WorkflowPermission;
+
+    let mut config = MergedConfiguration::new();
+    config.actions.default_workflow_permissions =
+        Some(OverridableValue::allowed(WorkflowPermission::Read));
+
+    assert_eq!(actions_permissions(&config), Some((true, Some("read"))));
+}
+
+/// Nothing is applied when the Actions settings are unset.
+#[test]
+fn test_actions_permissions_none_without_action_settings() {
+    assert_eq!(actions_permissions(&MergedConfiguration::new()), None);
+}
+
+/// A disabled Actions setting disables Actions on the repository.
+#[tokio::test]
+async fn test_apply_repository_configuration_disables_actions() {
+    use config_manager::settings::WorkflowPermission;
+    use wiremock::matchers::body_json;
+
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/test-org/test-repo/actions/permissions"))
+        .and(body_json(json!({ "enabled": false })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(
+            "/repos/test-org/test-repo/actions/permissions/workflow",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut config = MergedConfiguration::new();
+    config.actions.enabled = Some(OverridableValue::fixed(false));
+    config.actions.default_workflow_permissions =
+        Some(OverridableValue::allowed(WorkflowPermission::Write));
+
+    let client: Arc<dyn RepositoryClient> = create_test_github_client(&server.uri()).into();
+    apply_repository_configuration(&client, "test-org", "test-repo", "main", &config)
+        .await
+        .expect("Actions should be disabled");
+}
+
+/// A configured default branch other than the pushed one is made the default
+/// once the branch exists.
+#[tokio::test]
+async fn test_apply_repository_configuration_sets_configured_default_branch() {
+    use wiremock::matchers::body_json;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/branches/develop"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "name": "develop" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/test-repo"))
+        .and(body_json(json!({ "default_branch": "develop" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut config = MergedConfiguration::new();
+    config.branch_protection.default_branch =
+        Some(OverridableValue::allowed("develop".to_string()));
+
+    let client: Arc<dyn RepositoryClient> = create_test_github_client(&server.uri()).into();
+    apply_repository_configuration(&client, "test-org", "test-repo", "main", &config)
+        .await
+        .expect("default branch should be set");
+}
+
+/// Required code owner reviews are applied even without push settings.
+#[test]
+fn test_branch_push_protection_requires_code_owner_reviews() {
+    let mut config = MergedConfiguration::new();
+    config.branch_protection.require_code_owner_reviews = Some(OverridableValue::fixed(true));
+
+    let rules = branch_push_protection(&config).expect("rules should be applied");
+
+    assert!(rules.require_code_owner_reviews);
+    assert!(!rules.required_signatures);
+    assert!(!rules.allow_force_pushes);
+}
+
+/// The push-level force push setting takes precedence over branch protection.
+#[test]
+fn test_branch_push_protection_prefers_push_force_push_setting() {
+    let mut config = push_config(false);
+    config.branch_protection.allow_force_pushes = Some(OverridableValue::allowed(false));
+    config.push.allow_force_pushes = Some(OverridableValue::allowed(true));
+
+    let rules = branch_push_protection(&config).expect("rules should be applied");
+
+    assert!(rules.allow_force_pushes);
+    assert!(!rules.required_signatures);
+}
+
+/// Nothing is applied when the push settings leave both rules unset.
+#[test]
+fn test_branch_push_protection_none_without_push_rules() {
+    let mut config = MergedConfiguration::new();
+    config.branch_protection.require_pull_request_reviews = Some(OverridableValue::allowed(true));
+
+    assert!(branch_push_protection(&config).is_none());
+}
+
+/// Configured status check contexts are required on the default branch.
+#[test]
+fn test_branch_push_protection_requires_status_checks() {
+    let mut config = MergedConfiguration::new();
+    config.branch_protection.required_status_checks = Some(OverridableValue::allowed(vec![
+        "ci/build".to_string(),
+        "ci/test".to_string(),
+    ]));
+    config.branch_protection.strict_required_status_checks = Some(OverridableValue::fixed(true));
+
+    let rules = branch_push_protection(&config).expect("rules should be applied");
+
+    assert_eq!(rules.required_status_checks, vec!["ci/build", "ci/test"]);
+    assert!(rules.strict_required_status_checks);
+}
+
+/// An empty list of status checks leaves required status checks disabled.
+#[test]
+fn test_branch_push_protection_empty_status_checks_disable_checks() {
+    let mut config = push_config(true);
+    config.branch_protection.required_status_checks = Some(OverridableValue::allowed(vec![]));
+
+    let rules = branch_push_protection(&config).expect("rules should be applied");
+    assert!(rules.required_status_checks.is_empty());
+
+    config.push = Default::default();
+    assert!(branch_push_protection(&config).is_none());
+}
@@ -13,7 +13,9 @@
 //!
 //! - [`TemplateBasedContentProvider`]: Fetches and processes template files (current behavior)
 //! - [`ZeroContentProvider`]: Creates no files (empty repository)
+//! - [`PlaceholderContentProvider`]: Creates a minimal README.md only
 //! - [`CustomInitContentProvider`]: Creates selected initialization files
+//! - [`CopyFromRepositoryContentProvider`]: Copies a snapshot of an existing repository
 //!
 //! # Examples
 //!
@@ -70,7 +72,9 @@ use tracing::{error, info};
 ///
 /// - [`TemplateBasedContentProvider`]: Fetches and processes template files (current behavior)
 /// - [`ZeroContentProvider`]: Creates no files (empty repository)
+/// - [`PlaceholderContentProvider`]: Creates a minimal README.md only
 /// - [`CustomInitContentProvider`]: Creates selected initialization files (README, .gitignore)
+/// - [`CopyFromRepositoryContentProvider`]: Copies a snapshot of an existing repository
 ///
 /// # Examples
 ///
@@ -130,6 +134,15 @@ pub trait ContentProvider: Send + Sync {
         template_source: &str,
         merged_config: &config_manager::MergedConfiguration,
     ) -> RepoRollerResult<TempDir>;
+
+    /// Commit SHA of the template content provided by the last call to
+    /// [`provide_content`](Self::provide_content).
+    ///
+    /// Returns `None` for providers that do not render a template, or when
+    /// the commit is not known.
+    fn template_commit_sha(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Content provider that fetches and processes template files.
@@ -178,6 +191,10 @@ pub trait ContentProvider: Send + Sync {
 pub struct TemplateBasedContentProvider<'a> {
     /// Template fetcher for retrieving files from source repository
     fetcher: &'a dyn template_engine::TemplateFetcher,
+    /// Clock used for built-in timestamp variables
+    clock: std::sync::Arc<dyn template_engine::Clock>,
+    /// Commit SHA of the template rendered by the last `provide_content` call
+    template_commit_sha: std::sync::Mutex<Option<String>>,
 }
 
 impl<'a> TemplateBasedContentProvider<'a> {
@@ -199,7 +216,19 @@ impl<'a> TemplateBasedContentProvider<'a> {
     /// # }
     /// ```
     pub fn new(fetcher: &'a dyn template_engine::TemplateFetcher) -> Self {
-        Self { fetcher }
+        Self {
+            fetcher,
+            clock: std::sync::Arc::new(template_engine::SystemClock),
+            template_commit_sha: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Use the given clock for built-in timestamp variables.
+    ///
+    /// Defaults to [`template_engine::SystemClock`].
+    pub fn with_clock(mut self, clock: std::sync::Arc<dyn template_engine::Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 }
 
@@ -233,14 +262,28 @@ impl<'a> ContentProvider for TemplateBasedContentProvider<'a> {
         // 3. Copy files
         // 4. Replace variables
         // 5. Create additional files
-        crate::template_processing::prepare_local_repository(
+        let (temp_dir, commit_sha) = crate::template_processing::prepare_local_repository(
             request,
             template,
             template_source,
             self.fetcher,
             merged_config,
+            &self.clock,
         )
-        .await
+        .await?;
+
+        *self
+            .template_commit_sha
+            .lock()
+            .expect("template commit lock poisoned") = commit_sha;
+        Ok(temp_dir)
+    }
+
+    fn template_commit_sha(&self) -> Option<String> {
+        self.template_commit_sha
+            .lock()
+            .expect("template commit lock poisoned")
+            .clone()
     }
 }
 
@@ -331,6 +374,86 @@ impl ContentProvider for ZeroContentProvider {
     }
 }
 
+/// Content provider for placeholder repositories created with
+/// [`ContentStrategy::Empty`](crate::ContentStrategy::Empty).
+///
+/// Creates only a minimal README.md containing the repository name and, when
+/// one is known, its description. No template is required; the template
+/// configuration, if any, is ignored. The README gives the repository an
+/// initial commit on the default branch so that branch protection and other
+/// configuration can be applied to it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use repo_roller_core::{PlaceholderContentProvider, ContentProvider};
+///
+/// # async fn example(
+/// #     request: &repo_roller_core::RepositoryCreationRequest,
+/// #     merged_config: &config_manager::MergedConfiguration,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider =
+///     PlaceholderContentProvider::new(Some("Payment processing service".to_string()));
+/// let temp_dir = provider.provide_content(request, None, "", merged_config).await?;
+/// // temp_dir contains only README.md
+/// # Ok(())
+/// # }
+/// ```
+pub struct PlaceholderContentProvider {
+    /// Description written below the README heading
+    description: Option<String>,
+}
+
+impl PlaceholderContentProvider {
+    /// Create a new placeholder content provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `description` - Resolved repository description. When `None`, the
+    ///   request's description is used, if any.
+    pub fn new(description: Option<String>) -> Self {
+        Self { description }
+    }
+}
+
+#[async_trait::async_trait]
+impl ContentProvider for PlaceholderContentProvider {
+    async fn provide_content(
+        &self,
+        request: &RepositoryCreationRequest,
+        _template_config: Option<&config_manager::TemplateConfig>,
+        _template_source: &str,
+        _merged_config: &config_manager::MergedConfiguration,
+    ) -> RepoRollerResult<TempDir> {
+        info!("Creating placeholder repository content (README.md only)");
+
+        let local_repo_path = TempDir::new().map_err(|e| {
+            error!("Failed to create temporary directory: {}", e);
+            RepoRollerError::System(SystemError::Internal {
+                reason: format!("Failed to create temporary directory: {}", e),
+            })
+        })?;
+
+        let description = self.description.as_ref().or(request.description.as_ref());
+        let readme_content = match description {
+            Some(description) => format!("# {}\n\n{}\n", request.name.as_ref(), description),
+            None => format!("# {}\n", request.name.as_ref()),
+        };
+
+        let readme_path = local_repo_path.path().join("README.md");
+        std::fs::write(&readme_path, readme_content).map_err(|e| {
+            error!("Failed to create README.md: {}", e);
+            RepoRollerError::System(SystemError::FileSystem {
+                operation: "create README.md".to_string(),
+                reason: e.to_string(),
+            })
+        })?;
+
+        info!("README.md created at: {:?}", readme_path);
+        Ok(local_repo_path)
+    }
+}
+
 /// Content provider that creates custom initialization files.
 ///
 /// This provider creates a minimal set of initialization files based on
@@ -476,6 +599,90 @@ impl ContentProvider for CustomInitContentProvider {
     }
 }
 
+/// Content provider that copies a snapshot of an existing repository.
+///
+/// The files on the source repository's default branch are copied unchanged;
+/// no variables are substituted. Committed like any other content, they give
+/// the new repository a single initial commit without the source's history.
+///
+/// Copies that keep every branch, tag and commit are made with
+/// `copy_full_history` on
+/// [`ContentStrategy::CopyFromRepository`](crate::ContentStrategy::CopyFromRepository),
+/// which mirrors the source repository instead of using a content provider.
+///
+/// # Examples
+///
+/// ```no_run
+/// use repo_roller_core::{ContentProvider, CopyFromRepositoryContentProvider};
+///
+/// # async fn example(
+/// #     request: &repo_roller_core::RepositoryCreationRequest,
+/// #     merged_config: &config_manager::MergedConfiguration,
+/// #     fetcher: &dyn template_engine::TemplateFetcher,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = CopyFromRepositoryContentProvider::new(fetcher, "my-org/existing-repo");
+/// let temp_dir = provider.provide_content(request, None, "", merged_config).await?;
+/// // temp_dir contains the files of my-org/existing-repo
+/// # Ok(())
+/// # }
+/// ```
+pub struct CopyFromRepositoryContentProvider<'a> {
+    /// Fetcher used to read the source repository's files
+    fetcher: &'a dyn template_engine::TemplateFetcher,
+    /// Source repository as `owner/name`
+    source: String,
+}
+
+impl<'a> CopyFromRepositoryContentProvider<'a> {
+    /// Create a new copy content provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `fetcher` - Fetcher used to read the source repository's files
+    /// * `source` - Source repository as `owner/name`
+    pub fn new(fetcher: &'a dyn template_engine::TemplateFetcher, source: &str) -> Self {
+        Self {
+            fetcher,
+            source: source.to_string(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ContentProvider for CopyFromRepositoryContentProvider<'a> {
+    async fn provide_content(
+        &self,
+        _request: &RepositoryCreationRequest,
+        _template_config: Option<&config_manager::TemplateConfig>,
+        _template_source: &str,
+        _merged_config: &config_manager::MergedConfiguration,
+    ) -> RepoRollerResult<TempDir> {
+        info!("Copying a snapshot of repository '{}'", self.source);
+
+        let files = self
+            .fetcher
+            .fetch_template_files(&self.source)
+            .await
+            .map_err(|e| {
+                error!("Failed to fetch files from '{}': {}", self.source, e);
+                RepoRollerError::System(SystemError::Internal {
+                    reason: format!("Failed to fetch files from '{}': {}", self.source, e),
+                })
+            })?;
+
+        let local_repo_path = TempDir::new().map_err(|e| {
+            error!("Failed to create temporary directory: {}", e);
+            RepoRollerError::System(SystemError::Internal {
+                reason: format!("Failed to create temporary directory: {}", e),
+            })
+        })?;
+        crate::template_processing::copy_template_files(&files, &local_repo_path)?;
+
+        info!("Copied {} files from '{}'", files.len(), self.source);
+        Ok(local_repo_path)
+    }
+}
+
 /// Create a README.md file with repository information.
 ///
 /// Generates a basic README with repository name, owner, and optional
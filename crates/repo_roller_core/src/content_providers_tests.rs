@@ -2,7 +2,7 @@
 // See specs/interfaces/content-providers.md for complete specification
 
 use super::*;
-use crate::{ContentStrategy, OrganizationName, RepositoryName, TemplateName};
+use crate::{ContentStrategy, OrganizationName, OwnerType, RepositoryName, TemplateName};
 use std::collections::HashMap;
 
 // Mock TemplateFetcher for testing
@@ -26,13 +26,26 @@ fn create_test_request() -> RepositoryCreationRequest {
     RepositoryCreationRequest {
         name: RepositoryName::new("test-repo").unwrap(),
         owner: OrganizationName::new("test-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("test-template").unwrap()),
         variables: HashMap::new(),
         visibility: None,
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     }
 }
 fn create_test_template_config() -> config_manager::TemplateConfig {
@@ -42,6 +55,9 @@ fn create_test_template_config() -> config_manager::TemplateConfig {
             description: "Test template".to_string(),
             author: "Test Author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository: None,
         repository_type: None,
@@ -60,6 +76,10 @@ fn create_test_template_config() -> config_manager::TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
@@ -216,6 +236,63 @@ async fn test_zero_provider_default() {
     assert!(result.is_ok());
 }
 
+// ============================================================================
+// PlaceholderContentProvider Tests
+// ============================================================================
+
+/// Test that PlaceholderContentProvider creates only a README.md.
+///
+/// Assertion: README.md is the only file and contains the name and description.
+#[tokio::test]
+async fn test_placeholder_provider_creates_readme_only() {
+    let provider = PlaceholderContentProvider::new(Some("Payment processing".to_string()));
+
+    let mut request = create_test_request();
+    request.template = None;
+    request.content_strategy = ContentStrategy::Empty;
+    let merged_config = create_test_merged_config();
+
+    let temp_dir = provider
+        .provide_content(&request, None, "", &merged_config)
+        .await
+        .expect("Placeholder content should not require a template");
+
+    let entries: Vec<_> = std::fs::read_dir(temp_dir.path())
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 1, "Expected only README.md");
+
+    let readme = std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+    assert_eq!(readme, "# test-repo\n\nPayment processing\n");
+}
+
+/// Test that PlaceholderContentProvider falls back to the request description.
+///
+/// Assertion: Request description is used when none was resolved; otherwise
+/// the README has only a heading.
+#[tokio::test]
+async fn test_placeholder_provider_description_fallback() {
+    let provider = PlaceholderContentProvider::new(None);
+    let merged_config = create_test_merged_config();
+
+    let mut request = create_test_request();
+    let temp_dir = provider
+        .provide_content(&request, None, "", &merged_config)
+        .await
+        .unwrap();
+    let readme = std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+    assert_eq!(readme, "# test-repo\n");
+
+    request.description = Some("From the request".to_string());
+    let temp_dir = provider
+        .provide_content(&request, None, "", &merged_config)
+        .await
+        .unwrap();
+    let readme = std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+    assert_eq!(readme, "# test-repo\n\nFrom the request\n");
+}
+
 // ============================================================================
 // CustomInitContentProvider Tests
 // ============================================================================
@@ -426,3 +503,66 @@ async fn test_readme_content_without_template() {
     assert!(content.contains("test-org"));
     assert!(content.contains("RepoRoller"));
 }
+
+/// Test that CopyFromRepositoryContentProvider copies the source files unchanged.
+///
+/// Assertion: Files are fetched from the source repository and written without
+/// variable substitution.
+#[tokio::test]
+async fn test_copy_from_repository_copies_source_files() {
+    struct SourceFetcher;
+
+    #[async_trait::async_trait]
+    impl template_engine::TemplateFetcher for SourceFetcher {
+        async fn fetch_template_files(
+            &self,
+            source: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, String> {
+            assert_eq!(source, "test-org/existing-repo");
+            Ok(vec![
+                ("README.md".to_string(), b"# {{repo_name}}".to_vec()),
+                ("src/main.rs".to_string(), b"fn main() {}".to_vec()),
+            ])
+        }
+    }
+
+    let fetcher = SourceFetcher;
+    let provider = CopyFromRepositoryContentProvider::new(&fetcher, "test-org/existing-repo");
+
+    let request = create_test_request();
+    let merged_config = create_test_merged_config();
+
+    let temp_dir = provider
+        .provide_content(&request, None, "", &merged_config)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap(),
+        "# {{repo_name}}"
+    );
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+}
+
+/// Test that CopyFromRepositoryContentProvider reports fetch failures.
+///
+/// Assertion: A source that cannot be read fails the content generation.
+#[tokio::test]
+async fn test_copy_from_repository_fetch_failure() {
+    let fetcher = MockTemplateFetcher {
+        should_succeed: false,
+    };
+    let provider = CopyFromRepositoryContentProvider::new(&fetcher, "test-org/existing-repo");
+
+    let request = create_test_request();
+    let merged_config = create_test_merged_config();
+
+    let result = provider
+        .provide_content(&request, None, "", &merged_config)
+        .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,154 @@
+//! Services repository creation uses to reach Git and GitHub.
+//!
+//! [`CreationDependencies`] is passed to [`crate::create_repository`]
+//! alongside the request. The defaults create a GitHub client and template
+//! fetcher for each creation, talk to `https://api.github.com` and run Git
+//! through [`crate::Git2Operations`]; tests, batches and alternative
+//! deployments replace them through the `with_*` methods.
+
+use std::sync::Arc;
+
+use config_manager::MetadataProviderConfig;
+use github_client::RepositoryClient;
+use template_engine::TemplateFetcher;
+
+use crate::GitOperations;
+
+/// Git and GitHub services used while creating a repository, and how the
+/// local content is handled when the creation fails.
+///
+/// Keeps the collaborators that do the work of a creation separate from the
+/// [`crate::EventNotificationContext`], which only describes how the outcome
+/// is reported. Cloning is cheap, so one instance can be shared by several
+/// creations.
+///
+/// # Examples
+///
+/// ```
+/// use repo_roller_core::{CreationDependencies, Git2Operations};
+/// use std::sync::Arc;
+///
+/// let dependencies = CreationDependencies::new()
+///     .with_git(Arc::new(Git2Operations))
+///     .with_github_api_base_url("https://github.example.com/api/v3");
+/// ```
+#[derive(Clone)]
+pub struct CreationDependencies {
+    /// Local Git operations used to commit and push the initial content.
+    pub git: Arc<dyn GitOperations>,
+    /// GitHub API base URL used by the installation clients; `None` uses
+    /// `https://api.github.com`.
+    pub github_api_base_url: Option<String>,
+    /// GitHub API client used for the creation; `None` creates one from the
+    /// installation token.
+    pub repository_client: Option<Arc<dyn RepositoryClient>>,
+    /// Fetcher for template content; `None` uses a new
+    /// [`template_engine::GitHubTemplateFetcher`].
+    pub template_fetcher: Option<Arc<dyn TemplateFetcher>>,
+    /// How the organization configuration is read from the metadata
+    /// repository; `None` reads the repository named in the request at its
+    /// default branch.
+    pub metadata_provider_config: Option<MetadataProviderConfig>,
+    /// Path of the combined configuration file in the metadata repository;
+    /// `None` reads the `global/`, `teams/` and `types/` layout.
+    pub combined_metadata_file: Option<String>,
+    /// Whether to keep a copy of the rendered local repository when a later
+    /// step fails, for debugging.
+    pub keep_failed_workdir: bool,
+}
+
+impl CreationDependencies {
+    /// Create dependencies that use [`crate::Git2Operations`] and the public
+    /// GitHub API.
+    pub fn new() -> Self {
+        Self {
+            git: Arc::new(crate::Git2Operations),
+            github_api_base_url: None,
+            repository_client: None,
+            template_fetcher: None,
+            metadata_provider_config: None,
+            combined_metadata_file: None,
+            keep_failed_workdir: false,
+        }
+    }
+
+    /// Use the given Git operations for the initial commit and push.
+    ///
+    /// Defaults to [`crate::Git2Operations`]. Tests pass a mock so that
+    /// repository creation runs without touching a real Git remote.
+    pub fn with_git(mut self, git: Arc<dyn GitOperations>) -> Self {
+        self.git = git;
+        self
+    }
+
+    /// Send GitHub API requests to the given base URL.
+    ///
+    /// Use this for GitHub Enterprise Server or a mock server in tests.
+    pub fn with_github_api_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.github_api_base_url = Some(base_url.into());
+        self
+    }
+
+    /// Use the given GitHub API client instead of creating one for the
+    /// creation.
+    ///
+    /// The client must be authorized for the request's owner. Sharing one
+    /// client across creations, e.g. in a batch, reuses its connections; the
+    /// caller controls its lifetime. A client shared by a long-running batch
+    /// should carry a token refresher, e.g. from
+    /// [`auth_handler::UserAuthenticationService::installation_token_refresher`],
+    /// since installation tokens expire after one hour. The installation token
+    /// is still obtained for pushing the initial content and reading the
+    /// configuration.
+    pub fn with_repository_client(mut self, client: Arc<dyn RepositoryClient>) -> Self {
+        self.repository_client = Some(client);
+        self
+    }
+
+    /// Use the given fetcher for template content instead of a new
+    /// [`template_engine::GitHubTemplateFetcher`].
+    pub fn with_template_fetcher(mut self, fetcher: Arc<dyn TemplateFetcher>) -> Self {
+        self.template_fetcher = Some(fetcher);
+        self
+    }
+
+    /// Read the organization configuration with the given metadata provider
+    /// configuration.
+    ///
+    /// Its discovery method replaces the metadata repository name passed to
+    /// [`crate::create_repository`]. Use it to preview configuration staged on
+    /// a branch ([`MetadataProviderConfig::with_git_ref`]), to fill `${KEY}`
+    /// placeholders, or to tune request timeouts and concurrent fetches.
+    /// Templates are still loaded through the metadata provider passed to
+    /// [`crate::create_repository`].
+    pub fn with_metadata_provider_config(mut self, config: MetadataProviderConfig) -> Self {
+        self.metadata_provider_config = Some(config);
+        self
+    }
+
+    /// Read the organization configuration from one combined file in the
+    /// metadata repository, e.g. [`config_manager::DEFAULT_COMBINED_CONFIG_PATH`].
+    ///
+    /// See [`config_manager::CombinedFileMetadataProvider`] for the file layout.
+    pub fn with_combined_metadata_file(mut self, file_path: impl Into<String>) -> Self {
+        self.combined_metadata_file = Some(file_path.into());
+        self
+    }
+
+    /// Keep a copy of the rendered local repository when creation fails after
+    /// the content was generated.
+    ///
+    /// The copy is written to
+    /// `<temp dir>/repo-roller-failed/<owner>-<name>-<correlation id>` and its
+    /// location is logged. Successful creations leave nothing behind.
+    pub fn with_keep_failed_workdir(mut self, keep: bool) -> Self {
+        self.keep_failed_workdir = keep;
+        self
+    }
+}
+
+impl Default for CreationDependencies {
+    fn default() -> Self {
+        Self::new()
+    }
+}
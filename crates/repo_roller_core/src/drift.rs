@@ -0,0 +1,169 @@
+//! Drift detection for existing repositories.
+//!
+//! Repositories change after they are created: administrators toggle
+//! features, delete labels, or relax branch protection. [`detect_drift`]
+//! compares a repository's current state with the [`MergedConfiguration`] it
+//! should have, using the same readers as post-creation verification, and
+//! produces a [`DriftReport`] with the intended and actual value of every
+//! setting that differs, ranked by [`DriftSeverity`].
+//!
+//! In addition to the settings checked by
+//! [`verify_repository_configuration`](crate::verify_repository_configuration),
+//! the repository visibility is compared when an intended visibility is given.
+//!
+//! [`MergedConfiguration`]: config_manager::MergedConfiguration
+
+use crate::errors::{GitHubError, RepoRollerError, RepoRollerResult};
+use crate::verification::compare_with_configuration;
+use config_manager::RepositoryVisibility;
+use github_client::RepositoryClient;
+use tracing::{info, warn};
+
+#[cfg(test)]
+#[path = "drift_tests.rs"]
+mod tests;
+
+/// Setting path used for the repository visibility.
+const VISIBILITY_SETTING: &str = "repository.visibility";
+
+/// How serious a drifted setting is.
+///
+/// Variants are ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DriftSeverity {
+    /// Cosmetic or convenience settings, such as labels and features
+    Low,
+    /// Settings that affect integrations, such as webhooks
+    Medium,
+    /// Settings that affect access or review policy, such as visibility and
+    /// branch protection
+    High,
+}
+
+impl DriftSeverity {
+    /// Classifies a setting path from a verification comparison.
+    fn for_setting(setting: &str) -> Self {
+        if setting == VISIBILITY_SETTING || setting.starts_with("branch_protection.") {
+            Self::High
+        } else if setting.starts_with("webhooks.") {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+}
+
+/// A setting whose current value differs from the intended configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingDrift {
+    /// Path of the setting, e.g. `repository.visibility` or `labels.bug`
+    pub setting: String,
+
+    /// The value the configuration asks for
+    pub intended: String,
+
+    /// The value found on GitHub, or `"missing"` when it is absent
+    pub actual: String,
+
+    /// How serious the difference is
+    pub severity: DriftSeverity,
+}
+
+/// Outcome of comparing an existing repository with its intended configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Number of settings that were compared
+    pub checked: usize,
+
+    /// Settings that have drifted, most severe first
+    pub drifts: Vec<SettingDrift>,
+}
+
+impl DriftReport {
+    /// Returns `true` when any checked setting has drifted.
+    pub fn has_drift(&self) -> bool {
+        !self.drifts.is_empty()
+    }
+
+    /// Returns the severity of the most serious drift, if any.
+    pub fn max_severity(&self) -> Option<DriftSeverity> {
+        self.drifts.iter().map(|d| d.severity).max()
+    }
+}
+
+/// Reads an existing repository from GitHub and reports how it differs from
+/// its intended configuration.
+///
+/// # Arguments
+///
+/// * `client` - GitHub client for reading the repository
+/// * `owner` - Repository owner
+/// * `repo` - Repository name
+/// * `merged_config` - The configuration the repository should have
+/// * `visibility` - The visibility the repository should have; not compared
+///   when `None`, since visibility is not part of the merged configuration
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if any configured setting cannot be
+/// read. Drift is reported in the report, not as an error.
+pub async fn detect_drift(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    visibility: Option<RepositoryVisibility>,
+) -> RepoRollerResult<DriftReport> {
+    info!(owner = owner, repo = repo, "Detecting repository drift");
+
+    let mut comparison = compare_with_configuration(client, owner, repo, merged_config).await?;
+
+    if let Some(intended) = visibility {
+        let actual = client
+            .get_repository_settings(owner, repo)
+            .await
+            .map_err(|e| {
+                RepoRollerError::GitHub(GitHubError::NetworkError {
+                    reason: format!("Failed to read repository for drift detection: {}", e),
+                })
+            })?;
+        comparison.compare(
+            VISIBILITY_SETTING,
+            intended.as_str(),
+            Some(actual.visibility()),
+        );
+    }
+
+    let mut drifts: Vec<SettingDrift> = comparison
+        .mismatches
+        .into_iter()
+        .map(|mismatch| SettingDrift {
+            severity: DriftSeverity::for_setting(&mismatch.setting),
+            setting: mismatch.setting,
+            intended: mismatch.expected,
+            actual: mismatch.actual,
+        })
+        .collect();
+    // Stable sort keeps the reading order within a severity
+    drifts.sort_by(|a, b| b.severity.cmp(&a.severity));
+
+    for drift in &drifts {
+        warn!(
+            setting = %drift.setting,
+            intended = %drift.intended,
+            actual = %drift.actual,
+            severity = ?drift.severity,
+            "Repository setting has drifted from its configuration"
+        );
+    }
+    info!(
+        checked = comparison.checked,
+        drifted = drifts.len(),
+        "Drift detection complete"
+    );
+
+    Ok(DriftReport {
+        checked: comparison.checked,
+        drifts,
+    })
+}
@@ -0,0 +1,158 @@
+//! Tests for the drift module.
+
+use super::*;
+use config_manager::settings::LabelConfig;
+use config_manager::{MergedConfiguration, OverridableValue};
+use github_client::GitHubClient;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Creates a [`GitHubClient`] pointed at a wiremock server for testing.
+fn create_test_github_client(server_uri: &str) -> GitHubClient {
+    let octocrab = Octocrab::builder()
+        .base_uri(server_uri)
+        .expect("valid URI from wiremock")
+        .personal_token("test-token".to_string())
+        .build()
+        .expect("octocrab builder succeeds with valid base_uri");
+    GitHubClient::new(octocrab)
+}
+
+/// Mounts a `GET /repos/test-org/test-repo` response with the given visibility.
+async fn mount_repository(server: &MockServer, visibility: &str) {
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123456789,
+            "node_id": "R_kgDOTest",
+            "name": "test-repo",
+            "full_name": "test-org/test-repo",
+            "private": visibility != "public",
+            "visibility": visibility,
+            "url": "https://api.github.com/repos/test-org/test-repo",
+            "has_issues": true,
+            "has_wiki": false,
+            "has_projects": true
+        })))
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn test_severity_classification() {
+    assert_eq!(
+        DriftSeverity::for_setting("repository.visibility"),
+        DriftSeverity::High
+    );
+    assert_eq!(
+        DriftSeverity::for_setting("branch_protection.main.dismiss_stale_reviews"),
+        DriftSeverity::High
+    );
+    assert_eq!(
+        DriftSeverity::for_setting("webhooks.https://example.com/hook.active"),
+        DriftSeverity::Medium
+    );
+    assert_eq!(DriftSeverity::for_setting("labels.bug"), DriftSeverity::Low);
+}
+
+#[tokio::test]
+async fn test_manually_changed_visibility_is_reported_as_drift() {
+    let server = MockServer::start().await;
+    // The repository should be private, but someone made it public
+    mount_repository(&server, "public").await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(OverridableValue::allowed(true));
+
+    let report = detect_drift(
+        &client,
+        "test-org",
+        "test-repo",
+        &config,
+        Some(RepositoryVisibility::Private),
+    )
+    .await
+    .expect("Drift detection should succeed");
+
+    assert!(report.has_drift());
+    assert_eq!(report.checked, 2);
+    assert_eq!(
+        report.drifts,
+        vec![SettingDrift {
+            setting: "repository.visibility".to_string(),
+            intended: "private".to_string(),
+            actual: "public".to_string(),
+            severity: DriftSeverity::High,
+        }]
+    );
+    assert_eq!(report.max_severity(), Some(DriftSeverity::High));
+}
+
+#[tokio::test]
+async fn test_drifts_are_ordered_by_severity() {
+    let server = MockServer::start().await;
+    mount_repository(&server, "internal").await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.labels.insert(
+        "bug".to_string(),
+        LabelConfig {
+            name: "bug".to_string(),
+            color: "d73a4a".to_string(),
+            description: String::new(),
+        },
+    );
+
+    let report = detect_drift(
+        &client,
+        "test-org",
+        "test-repo",
+        &config,
+        Some(RepositoryVisibility::Private),
+    )
+    .await
+    .expect("Drift detection should succeed");
+
+    let settings: Vec<(&str, DriftSeverity)> = report
+        .drifts
+        .iter()
+        .map(|d| (d.setting.as_str(), d.severity))
+        .collect();
+    assert_eq!(
+        settings,
+        vec![
+            ("repository.visibility", DriftSeverity::High),
+            ("labels.bug", DriftSeverity::Low),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_matching_repository_has_no_drift() {
+    let server = MockServer::start().await;
+    mount_repository(&server, "private").await;
+    let client = create_test_github_client(&server.uri());
+
+    let report = detect_drift(
+        &client,
+        "test-org",
+        "test-repo",
+        &MergedConfiguration::new(),
+        Some(RepositoryVisibility::Private),
+    )
+    .await
+    .expect("Drift detection should succeed");
+
+    assert!(!report.has_drift());
+    assert_eq!(report.checked, 1);
+    assert_eq!(report.max_severity(), None);
+}
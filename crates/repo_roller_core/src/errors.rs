@@ -32,6 +32,17 @@ pub enum ValidationError {
     #[error("Field '{field}' has invalid format: {reason}")]
     InvalidFormat { field: String, reason: String },
 
+    /// A repository or template name with an invalid format.
+    ///
+    /// `suggestion` holds a normalized name (lowercase, spaces replaced by
+    /// hyphens) when that normalized name would be valid.
+    #[error("Field '{field}' has invalid format: {reason}")]
+    InvalidName {
+        field: String,
+        reason: String,
+        suggestion: Option<String>,
+    },
+
     #[error("Invalid repository name: {reason}")]
     InvalidRepositoryName { reason: String },
 
@@ -65,6 +76,20 @@ pub enum ValidationError {
         options: Vec<String>,
         value: String,
     },
+
+    #[error("Repository name '{name}' is already taken in organization '{org}'")]
+    NameTaken { org: String, name: String },
+
+    /// Teams or required GitHub Apps referenced for a repository that do not
+    /// exist in the organization.
+    #[error(
+        "Unknown references in organization '{org}': teams {teams:?}, GitHub Apps {app_ids:?}"
+    )]
+    UnknownReferences {
+        org: String,
+        teams: Vec<String>,
+        app_ids: Vec<u64>,
+    },
 }
 
 impl ValidationError {
@@ -96,6 +121,26 @@ impl ValidationError {
             reason: reason.into(),
         }
     }
+
+    pub fn invalid_name(
+        field: impl Into<String>,
+        reason: impl Into<String>,
+        suggestion: Option<String>,
+    ) -> Self {
+        Self::InvalidName {
+            field: field.into(),
+            reason: reason.into(),
+            suggestion,
+        }
+    }
+
+    /// Get the suggested replacement value, if the error carries one.
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::InvalidName { suggestion, .. } => suggestion.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 /// Repository operation errors.
@@ -164,6 +209,12 @@ pub enum TemplateError {
 
     #[error("Path traversal attempt detected: {path}")]
     PathTraversalAttempt { path: String },
+
+    #[error("Template '{template}' is its own base template: {}", chain.join(" -> "))]
+    BaseTemplateCycle {
+        template: String,
+        chain: Vec<String>,
+    },
 }
 
 pub type TemplateResult<T> = Result<T, TemplateError>;
@@ -297,4 +348,23 @@ pub enum RepoRollerError {
     Permission(#[from] crate::permissions::PermissionError),
 }
 
+impl RepoRollerError {
+    /// Get a stable, machine-readable name for the error's domain.
+    ///
+    /// Used where errors are reported to external systems (e.g. outbound
+    /// event notifications) that should not depend on message wording.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Validation(_) => "validation",
+            Self::Repository(_) => "repository",
+            Self::Configuration(_) => "configuration",
+            Self::Template(_) => "template",
+            Self::Authentication(_) => "authentication",
+            Self::GitHub(_) => "github",
+            Self::System(_) => "system",
+            Self::Permission(_) => "permission",
+        }
+    }
+}
+
 pub type RepoRollerResult<T> = Result<T, RepoRollerError>;
@@ -490,6 +490,19 @@ fn test_validation_error_invalid_option() {
     assert!(err.to_string().contains("internal"));
 }
 
+#[test]
+fn test_validation_error_name_taken() {
+    let err = ValidationError::NameTaken {
+        org: "my-org".to_string(),
+        name: "my-repo".to_string(),
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "Repository name 'my-repo' is already taken in organization 'my-org'"
+    );
+}
+
 // ============================================================================
 // RepositoryError Tests
 // ============================================================================
@@ -1066,3 +1079,51 @@ fn test_debug_format_all_error_types() {
         assert!(!debug_output.is_empty());
     }
 }
+
+#[test]
+fn test_reporoller_error_category() {
+    let cases: Vec<(RepoRollerError, &str)> = vec![
+        (ValidationError::empty_field("test").into(), "validation"),
+        (
+            RepositoryError::NotFound {
+                org: "test".to_string(),
+                name: "test".to_string(),
+            }
+            .into(),
+            "repository",
+        ),
+        (
+            ConfigurationError::FileNotFound {
+                path: "test".to_string(),
+            }
+            .into(),
+            "configuration",
+        ),
+        (
+            TemplateError::TemplateNotFound {
+                name: "test".to_string(),
+            }
+            .into(),
+            "template",
+        ),
+        (AuthenticationError::InvalidToken.into(), "authentication"),
+        (
+            GitHubError::ResourceNotFound {
+                resource: "test".to_string(),
+            }
+            .into(),
+            "github",
+        ),
+        (
+            SystemError::Internal {
+                reason: "test".to_string(),
+            }
+            .into(),
+            "system",
+        ),
+    ];
+
+    for (err, expected) in cases {
+        assert_eq!(err.category(), expected, "unexpected category for {err}");
+    }
+}
@@ -6,7 +6,7 @@ use config_manager::{NotificationEndpoint, NotificationsConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::{RepositoryCreationRequest, RepositoryCreationResult};
+use crate::{RepoRollerError, RepositoryCreationRequest, RepositoryCreationResult};
 
 /// Configuration context for event notification delivery after repository creation.
 ///
@@ -34,6 +34,15 @@ pub struct EventNotificationContext {
     pub secret_resolver: std::sync::Arc<dyn crate::event_secrets::SecretResolver>,
     /// Metrics collector for tracking event delivery outcomes.
     pub metrics: std::sync::Arc<dyn crate::event_metrics::EventMetrics>,
+    /// Clock used for generated timestamps (built-in template variables and
+    /// `RepositoryCreationResult::created_at`).
+    pub clock: std::sync::Arc<dyn template_engine::Clock>,
+    /// Identifier tying the notifications of one creation attempt to the
+    /// caller's own records (e.g. an API request ID).
+    pub correlation_id: String,
+    /// Receiver of an event for each completed creation step; `None`
+    /// records nothing.
+    pub step_sink: Option<std::sync::Arc<dyn crate::step_events::CreationStepSink>>,
 }
 
 impl EventNotificationContext {
@@ -53,6 +62,54 @@ impl EventNotificationContext {
             created_by: created_by.into(),
             secret_resolver,
             metrics,
+            clock: std::sync::Arc::new(template_engine::SystemClock),
+            correlation_id: uuid::Uuid::new_v4().to_string(),
+            step_sink: None,
+        }
+    }
+
+    /// Use the given correlation ID instead of a generated one.
+    ///
+    /// Defaults to a new UUID v4 for each context.
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = correlation_id.into();
+        self
+    }
+
+    /// Use the given clock for timestamps generated during repository creation.
+    ///
+    /// Defaults to [`template_engine::SystemClock`]. Pass a
+    /// [`template_engine::FixedClock`] to make timestamps deterministic.
+    pub fn with_clock(mut self, clock: std::sync::Arc<dyn template_engine::Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Report each completed creation step to the given sink.
+    ///
+    /// Use [`crate::step_events::JsonlStepSink`] to write the events as JSON
+    /// lines.
+    pub fn with_step_sink(
+        mut self,
+        sink: std::sync::Arc<dyn crate::step_events::CreationStepSink>,
+    ) -> Self {
+        self.step_sink = Some(sink);
+        self
+    }
+
+    /// Records a completed creation step with the configured sink, if any.
+    pub(crate) fn record_step(
+        &self,
+        step: crate::step_events::CreationStep,
+        request: &RepositoryCreationRequest,
+    ) {
+        if let Some(sink) = &self.step_sink {
+            sink.record(&crate::step_events::CreationStepEvent {
+                step,
+                timestamp: crate::Timestamp::from_clock(self.clock.as_ref()),
+                correlation_id: self.correlation_id.clone(),
+                repository: format!("{}/{}", request.owner, request.name),
+            });
         }
     }
 }
@@ -142,6 +199,7 @@ impl RepositoryCreatedEvent {
             crate::ContentStrategy::Template => "template",
             crate::ContentStrategy::Empty => "empty",
             crate::ContentStrategy::CustomInit { .. } => "custom_init",
+            crate::ContentStrategy::CopyFromRepository { .. } => "copy_from_repository",
         }
         .to_string();
 
@@ -244,6 +302,67 @@ impl RepositoryCreatedEvent {
     }
 }
 
+/// Event published when repository creation fails after the organization's
+/// configuration has been resolved.
+///
+/// See docs/spec/interfaces/event-publisher.md#repositorycreationfailedevent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryCreationFailedEvent {
+    /// Event type identifier (always "repository.creation_failed")
+    pub event_type: String,
+
+    /// Unique identifier for this event (UUID v4)
+    pub event_id: String,
+
+    /// Timestamp when event occurred (ISO 8601 UTC)
+    pub timestamp: DateTime<Utc>,
+
+    /// Organization the repository was requested in
+    pub organization: String,
+
+    /// Requested repository name
+    pub repository_name: String,
+
+    /// Requested template (null if empty/custom-init)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_name: Option<String>,
+
+    /// User who requested repository creation
+    pub created_by: String,
+
+    /// Category of the failure (see [`RepoRollerError::category`])
+    pub error_category: String,
+
+    /// Human-readable failure description
+    pub error_message: String,
+
+    /// Identifier of the creation attempt, shared with the caller's records
+    pub correlation_id: String,
+}
+
+impl RepositoryCreationFailedEvent {
+    /// Creates a new event from the failed request and its error.
+    pub fn from_error_and_request(
+        error: &RepoRollerError,
+        request: &RepositoryCreationRequest,
+        created_by: &str,
+        correlation_id: &str,
+    ) -> Self {
+        Self {
+            event_type: "repository.creation_failed".to_string(),
+            event_id: uuid::Uuid::new_v4().to_string(),
+            timestamp: chrono::Utc::now(),
+            organization: request.owner.as_ref().to_string(),
+            repository_name: request.name.as_ref().to_string(),
+            template_name: request.template.as_ref().map(|t| t.as_ref().to_string()),
+            created_by: created_by.to_string(),
+            error_category: error.category().to_string(),
+            error_message: error.to_string(),
+            correlation_id: correlation_id.to_string(),
+        }
+    }
+}
+
 /// Repository settings that were applied during creation.
 ///
 /// See docs/spec/interfaces/event-publisher.md#appliedsettings
@@ -267,6 +386,20 @@ pub struct DeliveryResult {
     pub error_message: Option<String>,
 }
 
+/// Request that would be sent to one endpoint, computed without sending it.
+///
+/// See docs/spec/interfaces/event-publisher.md#notificationpreview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationPreview {
+    pub endpoint_url: String,
+    /// Exact JSON request body
+    pub payload: String,
+    /// Value of the `X-RepoRoller-Signature-256` header, or `None` if the
+    /// endpoint's secret could not be resolved
+    pub signature: Option<String>,
+    pub error_message: Option<String>,
+}
+
 /// Publishes a repository creation event to all configured endpoints.
 ///
 /// This function is called after successful repository creation to notify
@@ -300,7 +433,7 @@ pub async fn publish_repository_created(
     secret_resolver: &dyn crate::event_secrets::SecretResolver,
     metrics: &dyn crate::event_metrics::EventMetrics,
 ) -> Vec<DeliveryResult> {
-    use tracing::{error, info, warn};
+    use tracing::{error, info};
 
     // Track this background task in the active-task gauge for the duration of the call.
     metrics.increment_active_tasks();
@@ -322,7 +455,6 @@ pub async fn publish_repository_created(
             return Vec::new();
         }
     };
-    let payload_bytes = payload_json.as_bytes();
 
     info!(
         event_id = %event.event_id,
@@ -332,10 +464,155 @@ pub async fn publish_repository_created(
         "Publishing repository creation event"
     );
 
-    // Step 3: Collect matching endpoints from merged config.
-    // Deduplicate on (url, sorted_events) so an endpoint appearing at multiple
-    // configuration levels (org + team) is only delivered to once.
-    let event_type = "repository.created";
+    // Steps 3–4: Deliver to the endpoints subscribed to the event.
+    let results = deliver_event(
+        &event.event_id,
+        &event.event_type,
+        payload_json.as_bytes(),
+        merged_config,
+        secret_resolver,
+        metrics,
+    )
+    .await;
+
+    metrics.decrement_active_tasks();
+    results
+}
+
+/// Publishes a repository creation failure event to all configured endpoints.
+///
+/// Called when repository creation fails after the organization's
+/// configuration (and so its notification endpoints) has been resolved, which
+/// happens right after authentication. Failures before that point (request
+/// validation, authentication and the configuration resolution itself) have
+/// no endpoints to notify. Delivery is
+/// best-effort: failures are logged and reported in the returned results, and
+/// never replace the creation error.
+///
+/// # Arguments
+/// * `event` - Failure event, see [`RepositoryCreationFailedEvent::from_error_and_request`]
+/// * `merged_config` - Merged configuration with notification settings
+/// * `secret_resolver` - Secret resolution service
+/// * `metrics` - Metrics collection service
+///
+/// # Returns
+/// Vector of delivery results (one per endpoint)
+///
+/// See docs/spec/interfaces/event-publisher.md#publish_repository_creation_failed
+pub async fn publish_repository_creation_failed(
+    event: &RepositoryCreationFailedEvent,
+    merged_config: &config_manager::MergedConfiguration,
+    secret_resolver: &dyn crate::event_secrets::SecretResolver,
+    metrics: &dyn crate::event_metrics::EventMetrics,
+) -> Vec<DeliveryResult> {
+    use tracing::{error, info};
+
+    metrics.increment_active_tasks();
+
+    let payload_json = match serde_json::to_string(event) {
+        Ok(json) => json,
+        Err(e) => {
+            error!(
+                event_id = %event.event_id,
+                error = %e,
+                "Failed to serialize event to JSON"
+            );
+            metrics.decrement_active_tasks();
+            return Vec::new();
+        }
+    };
+
+    info!(
+        event_id = %event.event_id,
+        event_type = %event.event_type,
+        organization = %event.organization,
+        repository = %event.repository_name,
+        error_category = %event.error_category,
+        correlation_id = %event.correlation_id,
+        "Publishing repository creation failure event"
+    );
+
+    let results = deliver_event(
+        &event.event_id,
+        &event.event_type,
+        payload_json.as_bytes(),
+        merged_config,
+        secret_resolver,
+        metrics,
+    )
+    .await;
+
+    metrics.decrement_active_tasks();
+    results
+}
+
+/// Computes the requests a repository creation event would send, without
+/// sending them.
+///
+/// Lets operators check their receivers before enabling notifications. The
+/// payload and signature of each preview are exactly what
+/// [`publish_repository_created`] would send for the same event. Unlike a real
+/// delivery, an endpoint whose secret cannot be resolved is still listed, with
+/// `signature` unset and the failure in `error_message`.
+///
+/// # Arguments
+/// * `event` - Event for a would-be creation, see [`RepositoryCreatedEvent::from_result_and_request`]
+/// * `merged_config` - Merged configuration with notification settings
+/// * `secret_resolver` - Secret resolution service
+///
+/// # Returns
+/// One preview per subscribed endpoint; empty if the event cannot be serialized
+///
+/// See docs/spec/interfaces/event-publisher.md#preview_repository_created
+pub async fn preview_repository_created(
+    event: &RepositoryCreatedEvent,
+    merged_config: &config_manager::MergedConfiguration,
+    secret_resolver: &dyn crate::event_secrets::SecretResolver,
+) -> Vec<NotificationPreview> {
+    use tracing::error;
+
+    let payload_json = match serde_json::to_string(event) {
+        Ok(json) => json,
+        Err(e) => {
+            error!(
+                event_id = %event.event_id,
+                error = %e,
+                "Failed to serialize event to JSON"
+            );
+            return Vec::new();
+        }
+    };
+
+    let mut previews = Vec::new();
+    for endpoint in subscribed_endpoints(&event.event_type, merged_config) {
+        let (signature, error_message) =
+            match secret_resolver.resolve_secret(&endpoint.secret).await {
+                Ok(secret) => (
+                    Some(compute_hmac_sha256(payload_json.as_bytes(), &secret)),
+                    None,
+                ),
+                Err(e) => (None, Some(format!("Secret resolution failed: {}", e))),
+            };
+
+        previews.push(NotificationPreview {
+            endpoint_url: endpoint.url.clone(),
+            payload: payload_json.clone(),
+            signature,
+            error_message,
+        });
+    }
+
+    previews
+}
+
+/// Returns the active endpoints subscribed to `event_type`.
+///
+/// Endpoints are deduplicated on (url, sorted_events) so an endpoint appearing
+/// at multiple configuration levels (org + team) is only delivered to once.
+fn subscribed_endpoints<'a>(
+    event_type: &str,
+    merged_config: &'a config_manager::MergedConfiguration,
+) -> Vec<&'a NotificationEndpoint> {
     let mut seen: std::collections::HashSet<(String, Vec<String>)> =
         std::collections::HashSet::new();
     let mut matching_endpoints: Vec<&NotificationEndpoint> = Vec::new();
@@ -356,22 +633,38 @@ pub async fn publish_repository_created(
         }
     }
 
+    matching_endpoints
+}
+
+/// Delivers a serialized event to every active endpoint subscribed to
+/// `event_type`, signing each request with the endpoint's secret.
+async fn deliver_event(
+    event_id: &str,
+    event_type: &str,
+    payload_bytes: &[u8],
+    merged_config: &config_manager::MergedConfiguration,
+    secret_resolver: &dyn crate::event_secrets::SecretResolver,
+    metrics: &dyn crate::event_metrics::EventMetrics,
+) -> Vec<DeliveryResult> {
+    use tracing::{info, warn};
+
+    let matching_endpoints = subscribed_endpoints(event_type, merged_config);
+
     info!(
-        event_id = %event.event_id,
+        event_id = %event_id,
         endpoint_count = matching_endpoints.len(),
         "Collected notification endpoints"
     );
 
     if matching_endpoints.is_empty() {
         info!(
-            event_id = %event.event_id,
+            event_id = %event_id,
             "No matching notification endpoints configured"
         );
-        metrics.decrement_active_tasks();
         return Vec::new();
     }
 
-    // Step 4: Deliver to each endpoint sequentially
+    // Deliver to each endpoint sequentially
     let mut results = Vec::new();
     // Reuse a shared client across all publish calls to allow connection-pool reuse.
     let client = shared_http_client();
@@ -384,7 +677,7 @@ pub async fn publish_repository_created(
             Ok(s) => s,
             Err(e) => {
                 warn!(
-                    event_id = %event.event_id,
+                    event_id = %event_id,
                     endpoint_url = %endpoint.url,
                     error = %e,
                     "Secret resolution failed, skipping endpoint"
@@ -418,7 +711,7 @@ pub async fn publish_repository_created(
 
                 if response.status().is_success() {
                     info!(
-                        event_id = %event.event_id,
+                        event_id = %event_id,
                         endpoint_url = %endpoint.url,
                         status_code = status_code,
                         response_time_ms = duration_ms,
@@ -435,7 +728,7 @@ pub async fn publish_repository_created(
                     });
                 } else {
                     warn!(
-                        event_id = %event.event_id,
+                        event_id = %event_id,
                         endpoint_url = %endpoint.url,
                         status_code = status_code,
                         response_time_ms = duration_ms,
@@ -463,7 +756,7 @@ pub async fn publish_repository_created(
                 };
 
                 warn!(
-                    event_id = %event.event_id,
+                    event_id = %event_id,
                     endpoint_url = %endpoint.url,
                     error = %error_msg,
                     response_time_ms = duration_ms,
@@ -483,14 +776,13 @@ pub async fn publish_repository_created(
     }
 
     info!(
-        event_id = %event.event_id,
+        event_id = %event_id,
         success_count = results.iter().filter(|r| r.success).count(),
         failure_count = results.iter().filter(|r| !r.success).count(),
         total_endpoints = results.len(),
         "Event delivery complete"
     );
 
-    metrics.decrement_active_tasks();
     results
 }
 
@@ -3,8 +3,8 @@
 
 use super::*;
 use crate::{
-    ContentStrategy, OrganizationName, RepositoryCreationRequest, RepositoryCreationResult,
-    RepositoryName, RepositoryVisibility, TemplateName, Timestamp,
+    ContentStrategy, OrganizationName, OwnerType, RepositoryCreationRequest,
+    RepositoryCreationResult, RepositoryName, RepositoryVisibility, TemplateName, Timestamp,
 };
 use chrono::Utc;
 use std::collections::HashMap;
@@ -243,11 +243,16 @@ mod event_construction_tests {
             repository_id: "R_kgDOH9876543".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("my-repo").unwrap(),
             owner: OrganizationName::new("my-org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: Some(TemplateName::new("rust-service").unwrap()),
             variables: {
                 let mut vars = HashMap::new();
@@ -258,7 +263,19 @@ mod event_construction_tests {
             content_strategy: ContentStrategy::Template,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         let merged_config = config_manager::MergedConfiguration::new();
@@ -301,18 +318,35 @@ mod event_construction_tests {
             repository_id: "R_kgDOH1111111".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("empty-repo").unwrap(),
             owner: OrganizationName::new("test-org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: Some(RepositoryVisibility::Public),
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         let merged_config = config_manager::MergedConfiguration::new();
@@ -349,11 +383,16 @@ mod event_construction_tests {
             repository_id: "R_kgDOH2222222".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("custom-repo").unwrap(),
             owner: OrganizationName::new("org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: Some(RepositoryVisibility::Internal),
@@ -363,7 +402,19 @@ mod event_construction_tests {
             },
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         let merged_config = config_manager::MergedConfiguration::new();
@@ -404,18 +455,35 @@ mod event_construction_tests {
             repository_id: "R_test".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("repo").unwrap(),
             owner: OrganizationName::new("org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         // Act
@@ -462,18 +530,35 @@ mod event_construction_tests {
             repository_id: "R_test".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("repo").unwrap(),
             owner: OrganizationName::new("org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         // Act
@@ -503,18 +588,35 @@ mod event_construction_tests {
             repository_id: "R_test".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("repo").unwrap(),
             owner: OrganizationName::new("org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         let merged_config = config_manager::MergedConfiguration::new();
@@ -566,18 +668,35 @@ mod event_construction_tests {
             repository_id: "R_test".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         };
 
         let request = RepositoryCreationRequest {
             name: RepositoryName::new("repo").unwrap(),
             owner: OrganizationName::new("org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         };
 
         let merged_config = config_manager::MergedConfiguration::new();
@@ -1290,13 +1409,26 @@ mod publish_workflow_tests {
         RepositoryCreationRequest {
             name: RepositoryName::new("test-repo").unwrap(),
             owner: OrganizationName::new("test-org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: Some(TemplateName::new("test-template").unwrap()),
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Template,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         }
     }
 
@@ -1306,6 +1438,10 @@ mod publish_workflow_tests {
             repository_id: "R_kgDOABCDEF".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         }
     }
 
@@ -1540,13 +1676,26 @@ mod http_delivery_tests {
         RepositoryCreationRequest {
             name: RepositoryName::new("test-repo").unwrap(),
             owner: OrganizationName::new("test-org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: Some(TemplateName::new("test-template").unwrap()),
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Template,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         }
     }
 
@@ -1556,6 +1705,10 @@ mod http_delivery_tests {
             repository_id: "R_kgDOABCDEF".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         }
     }
 
@@ -2314,6 +2467,180 @@ mod http_delivery_tests {
         assert_eq!(metrics.decrement_call_count(), 1);
     }
 
+    // ── Creation Failure Tests ────────────────────────────────────────────────
+
+    fn forced_failure_event() -> RepositoryCreationFailedEvent {
+        let error = crate::RepoRollerError::GitHub(crate::GitHubError::ApiRequestFailed {
+            status: 500,
+            message: "forced failure".to_string(),
+        });
+        RepositoryCreationFailedEvent::from_error_and_request(
+            &error,
+            &test_request(),
+            "test-user",
+            "corr-123",
+        )
+    }
+
+    #[tokio::test]
+    async fn test_creation_failed_event_is_delivered_signed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let signing_secret = "failure-signing-secret";
+        let mut endpoint = make_endpoint(server.uri(), "SIG_SECRET");
+        endpoint.events = vec!["repository.creation_failed".to_string()];
+        let config = merged_config_with(vec![endpoint]);
+        let resolver = MockSecretResolver::with("SIG_SECRET", signing_secret);
+        let metrics = TrackingMetrics::new();
+
+        let results = publish_repository_creation_failed(
+            &forced_failure_event(),
+            &config,
+            &resolver,
+            &metrics,
+        )
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].success);
+
+        let received = server
+            .received_requests()
+            .await
+            .expect("Request recording should be enabled");
+        assert_eq!(received.len(), 1);
+
+        let body = &received[0].body;
+        let sig_header = received[0]
+            .headers
+            .get("x-reporoller-signature-256")
+            .and_then(|v| v.to_str().ok())
+            .expect("X-RepoRoller-Signature-256 header must be present");
+        assert_eq!(sig_header, compute_hmac_sha256(body, signing_secret));
+
+        let payload: serde_json::Value = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload["event_type"], "repository.creation_failed");
+        assert_eq!(payload["organization"], "test-org");
+        assert_eq!(payload["repository_name"], "test-repo");
+        assert_eq!(payload["template_name"], "test-template");
+        assert_eq!(payload["error_category"], "github");
+        assert_eq!(payload["correlation_id"], "corr-123");
+        assert_eq!(metrics.active_tasks.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_creation_failed_event_not_sent_to_created_only_endpoints() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let config = merged_config_with(vec![make_endpoint(server.uri(), "S")]);
+        let resolver = MockSecretResolver::with("S", "secret");
+        let metrics = TrackingMetrics::new();
+
+        let results = publish_repository_creation_failed(
+            &forced_failure_event(),
+            &config,
+            &resolver,
+            &metrics,
+        )
+        .await;
+
+        assert!(results.is_empty());
+        server.verify().await;
+    }
+
+    // ── Preview Tests ─────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_preview_signature_matches_real_delivery() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = merged_config_with(vec![make_endpoint(server.uri(), "SIG_SECRET")]);
+        let resolver = MockSecretResolver::with("SIG_SECRET", "preview-signing-secret");
+        let metrics = TrackingMetrics::new();
+        let event = RepositoryCreatedEvent::from_result_and_request(
+            &test_result(),
+            &test_request(),
+            &config,
+            "test-user",
+        );
+
+        let previews = preview_repository_created(&event, &config, &resolver).await;
+
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].endpoint_url, server.uri());
+        assert!(previews[0].error_message.is_none());
+        assert!(
+            server.received_requests().await.unwrap().is_empty(),
+            "Preview must not send anything"
+        );
+
+        // Send the same event for real and compare what the receiver saw.
+        deliver_event(
+            &event.event_id,
+            &event.event_type,
+            previews[0].payload.as_bytes(),
+            &config,
+            &resolver,
+            &metrics,
+        )
+        .await;
+
+        let received = server.received_requests().await.unwrap();
+        assert_eq!(received.len(), 1);
+        let sig_header = received[0]
+            .headers
+            .get("x-reporoller-signature-256")
+            .and_then(|v| v.to_str().ok())
+            .expect("X-RepoRoller-Signature-256 header must be present");
+        assert_eq!(previews[0].signature.as_deref(), Some(sig_header));
+        assert_eq!(received[0].body, previews[0].payload.as_bytes());
+        assert_eq!(
+            previews[0].payload,
+            serde_json::to_string(&event).unwrap(),
+            "Preview payload must be the serialized event"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preview_reports_unresolvable_secret() {
+        let config = merged_config_with(vec![make_endpoint(
+            "https://example.com/hook".to_string(),
+            "MISSING_SECRET",
+        )]);
+        let resolver = MockSecretResolver::with("OTHER_SECRET", "value");
+        let event = RepositoryCreatedEvent::from_result_and_request(
+            &test_result(),
+            &test_request(),
+            &config,
+            "test-user",
+        );
+
+        let previews = preview_repository_created(&event, &config, &resolver).await;
+
+        assert_eq!(previews.len(), 1);
+        assert!(previews[0].signature.is_none());
+        assert!(previews[0]
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("Secret resolution failed"));
+    }
+
     // ── Deduplication Tests ───────────────────────────────────────────────────
 
     /// Regression: endpoint configured at both org and team level must only
@@ -2424,13 +2751,26 @@ mod logging_tests {
         RepositoryCreationRequest {
             name: RepositoryName::new("log-test-repo").unwrap(),
             owner: OrganizationName::new("test-org").unwrap(),
+            owner_type: OwnerType::Organization,
             template: None,
             variables: HashMap::new(),
             visibility: None,
             content_strategy: ContentStrategy::Empty,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: vec![],
+            signed_initial_commit: None,
             actor_login: "test-user".to_string(),
+            description: None,
+            topics: vec![],
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
+            template_ref: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
         }
     }
 
@@ -2440,6 +2780,10 @@ mod logging_tests {
             repository_id: "R_logtest".to_string(),
             created_at: Timestamp::now(),
             default_branch: "main".to_string(),
+            warnings: vec![],
+            verification: None,
+            configuration_audit: None,
+            template_commit_sha: None,
         }
     }
 
@@ -6,15 +6,122 @@
 //! - File staging and committing
 //! - Remote repository configuration
 //! - Push operations with authentication
+//! - Mirroring an existing repository with all of its branches and tags
 //!
 //! For GitHub API operations (creating repositories, managing settings), see the
 //! `github_client` crate.
+//!
+//! Repository creation reaches these operations through the [`GitOperations`]
+//! trait so that the workflow can run against a mock in tests. [`Git2Operations`]
+//! is the production implementation.
 
 use crate::errors::SystemError;
 use git2::{Repository, Signature};
 use temp_dir::TempDir;
 use tracing::{debug, error, info, warn};
 
+/// Local Git operations used while creating a repository.
+///
+/// Implementations must be thread-safe (`Send + Sync`) so one instance can be
+/// shared across concurrent repository creations.
+pub trait GitOperations: Send + Sync {
+    /// Initializes a Git repository with the given default branch.
+    ///
+    /// See [`init_local_git_repo`].
+    fn init(&self, local_repo_path: &TempDir, default_branch: &str) -> Result<(), SystemError>;
+
+    /// Stages and commits every file in the working directory.
+    ///
+    /// See [`commit_all_changes`].
+    fn commit_all(
+        &self,
+        local_repo_path: &TempDir,
+        commit_message: &str,
+        allow_empty: bool,
+    ) -> Result<(), SystemError>;
+
+    /// Moves the committed content from the default branch to a review branch.
+    ///
+    /// See [`move_content_to_review_branch`].
+    fn move_to_review_branch(
+        &self,
+        local_repo_path: &TempDir,
+        default_branch: &str,
+        review_branch: &str,
+    ) -> Result<(), SystemError>;
+
+    /// Pushes a branch to the remote repository.
+    ///
+    /// See [`push_to_origin`].
+    fn push(
+        &self,
+        local_repo_path: &TempDir,
+        repo_url: url::Url,
+        branch_name: &str,
+        access_token: &str,
+        force: bool,
+    ) -> Result<(), SystemError>;
+
+    /// Mirrors every branch and tag of the source repository, with their
+    /// history, to the destination repository.
+    ///
+    /// See [`mirror_repository`].
+    fn mirror(
+        &self,
+        source_url: url::Url,
+        destination_url: url::Url,
+        access_token: &str,
+    ) -> Result<(), SystemError>;
+}
+
+/// [`GitOperations`] implemented with `git2`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Git2Operations;
+
+impl GitOperations for Git2Operations {
+    fn init(&self, local_repo_path: &TempDir, default_branch: &str) -> Result<(), SystemError> {
+        init_local_git_repo(local_repo_path, default_branch)
+    }
+
+    fn commit_all(
+        &self,
+        local_repo_path: &TempDir,
+        commit_message: &str,
+        allow_empty: bool,
+    ) -> Result<(), SystemError> {
+        commit_all_changes(local_repo_path, commit_message, allow_empty)
+    }
+
+    fn move_to_review_branch(
+        &self,
+        local_repo_path: &TempDir,
+        default_branch: &str,
+        review_branch: &str,
+    ) -> Result<(), SystemError> {
+        move_content_to_review_branch(local_repo_path, default_branch, review_branch)
+    }
+
+    fn push(
+        &self,
+        local_repo_path: &TempDir,
+        repo_url: url::Url,
+        branch_name: &str,
+        access_token: &str,
+        force: bool,
+    ) -> Result<(), SystemError> {
+        push_to_origin(local_repo_path, repo_url, branch_name, access_token, force)
+    }
+
+    fn mirror(
+        &self,
+        source_url: url::Url,
+        destination_url: url::Url,
+        access_token: &str,
+    ) -> Result<(), SystemError> {
+        mirror_repository(source_url, destination_url, access_token)
+    }
+}
+
 /// Debug the current state of the repository including HEAD and commit history.
 ///
 /// Logs information about the repository's current state for diagnostic purposes.
@@ -550,6 +657,112 @@ pub fn commit_all_changes(
     Ok(())
 }
 
+/// Move the committed content onto a review branch.
+///
+/// After [`commit_all_changes`] the default branch holds a single commit with
+/// the repository content. This rewrites the local history so that:
+///
+/// * `default_branch` points at an empty root commit, and
+/// * `review_branch` points at a commit with the original content and message
+///   whose parent is that root commit.
+///
+/// The shared root commit gives both branches a common history, which GitHub
+/// requires before a pull request from `review_branch` into `default_branch`
+/// can be opened.
+///
+/// ## Parameters
+///
+/// * `local_repo_path` - Directory containing the committed local repository
+/// * `default_branch` - Branch currently holding the content commit
+/// * `review_branch` - Branch that should receive the content
+///
+/// ## Errors
+///
+/// Returns `SystemError::GitOperation` if the repository cannot be read or
+/// any commit or reference cannot be written.
+pub fn move_content_to_review_branch(
+    local_repo_path: &TempDir,
+    default_branch: &str,
+    review_branch: &str,
+) -> Result<(), SystemError> {
+    info!(
+        "Moving initial content from '{}' to review branch '{}'",
+        default_branch, review_branch
+    );
+
+    let git_error = |operation: &str| {
+        let operation = operation.to_string();
+        move |e: git2::Error| {
+            error!("Failed to {}: {}", operation, e);
+            SystemError::GitOperation {
+                operation,
+                reason: e.to_string(),
+            }
+        }
+    };
+
+    let repo = Repository::open(local_repo_path.path()).map_err(git_error("open repository"))?;
+    let default_ref = format!("refs/heads/{}", default_branch);
+    let content_commit = repo
+        .find_reference(&default_ref)
+        .and_then(|reference| reference.peel_to_commit())
+        .map_err(git_error("find content commit"))?;
+    let content_tree = content_commit
+        .tree()
+        .map_err(git_error("find content tree"))?;
+
+    let signature = Signature::now("RepoRoller", "repo-roller@example.com")
+        .map_err(git_error("create signature"))?;
+
+    let empty_tree_oid = repo
+        .treebuilder(None)
+        .and_then(|builder| builder.write())
+        .map_err(git_error("create empty tree"))?;
+    let empty_tree = repo
+        .find_tree(empty_tree_oid)
+        .map_err(git_error("find empty tree"))?;
+    let root_oid = repo
+        .commit(
+            None,
+            &signature,
+            &signature,
+            "Initialize repository",
+            &empty_tree,
+            &[],
+        )
+        .map_err(git_error("create root commit"))?;
+    let root_commit = repo
+        .find_commit(root_oid)
+        .map_err(git_error("find root commit"))?;
+
+    let review_oid = repo
+        .commit(
+            None,
+            &signature,
+            &signature,
+            content_commit.message().unwrap_or("Initial commit"),
+            &content_tree,
+            &[&root_commit],
+        )
+        .map_err(git_error("create review commit"))?;
+
+    repo.reference(
+        &format!("refs/heads/{}", review_branch),
+        review_oid,
+        true,
+        "Initial content for review",
+    )
+    .map_err(git_error("create review branch"))?;
+    repo.reference(&default_ref, root_oid, true, "Empty initial commit")
+        .map_err(git_error("reset default branch"))?;
+
+    debug!(
+        "Default branch '{}' at {}, review branch '{}' at {}",
+        default_branch, root_oid, review_branch, review_oid
+    );
+    Ok(())
+}
+
 /// Initialize a new Git repository with the specified default branch.
 ///
 /// Creates a new Git repository in the given directory and sets the default branch
@@ -622,6 +835,8 @@ pub fn init_local_git_repo(local_path: &TempDir, default_branch: &str) -> Result
 /// * `repo_url` - URL of the GitHub repository to push to
 /// * `branch_name` - Name of the branch to push (matches default branch)
 /// * `access_token` - GitHub App installation token for authentication
+/// * `force` - Overwrite the remote branch instead of requiring a fast-forward
+///   (used when GitHub already created a commit, such as a license, on it)
 ///
 /// ## Returns
 ///
@@ -654,7 +869,7 @@ pub fn init_local_git_repo(local_path: &TempDir, default_branch: &str) -> Result
 ///
 /// ```rust,ignore
 /// let repo_url = url::Url::parse("https://github.com/owner/repo")?;
-/// push_to_origin(&temp_dir, repo_url, "main", &installation_token)?;
+/// push_to_origin(&temp_dir, repo_url, "main", &installation_token, false)?;
 /// println!("Repository pushed successfully");
 /// ```
 pub fn push_to_origin(
@@ -662,10 +877,11 @@ pub fn push_to_origin(
     repo_url: url::Url,
     branch_name: &str,
     access_token: &str,
+    force: bool,
 ) -> Result<(), SystemError> {
     info!(
-        "Starting git push operation to origin: {} (branch: {})",
-        repo_url, branch_name
+        "Starting git push operation to origin: {} (branch: {}, force: {})",
+        repo_url, branch_name, force
     );
     debug!(
         "Using authentication token ({} characters)",
@@ -807,7 +1023,12 @@ pub fn push_to_origin(
     push_options.remote_callbacks(callbacks);
 
     // Push the branch
-    let refspec = format!("refs/heads/{}:refs/heads/{}", branch_name, branch_name);
+    let refspec = format!(
+        "{}refs/heads/{}:refs/heads/{}",
+        if force { "+" } else { "" },
+        branch_name,
+        branch_name
+    );
     info!("Attempting to push with refspec: {}", refspec);
 
     match remote.push(&[&refspec], Some(&mut push_options)) {
@@ -856,6 +1077,97 @@ pub fn push_to_origin(
     }
 }
 
+/// Mirror every branch and tag of one repository to another.
+///
+/// This is the equivalent of `git clone --mirror` followed by `git push --mirror`:
+/// the source is fetched into a temporary bare repository and every branch and
+/// tag is force-pushed to the destination, keeping the full history. The
+/// installation token authenticates both the fetch and the push.
+///
+/// # Errors
+///
+/// Returns `SystemError::GitOperation` if the source cannot be fetched or the
+/// destination rejects the push.
+pub fn mirror_repository(
+    source_url: url::Url,
+    destination_url: url::Url,
+    access_token: &str,
+) -> Result<(), SystemError> {
+    info!("Mirroring repository {} to {}", source_url, destination_url);
+
+    let git_error = |operation: &str| {
+        let operation = operation.to_string();
+        move |e: git2::Error| {
+            error!("Failed to {}: {}", operation, e);
+            SystemError::GitOperation {
+                operation,
+                reason: e.to_string(),
+            }
+        }
+    };
+    let callbacks = || {
+        let token = access_token.to_string();
+        let mut callbacks = git2::RemoteCallbacks::new();
+        callbacks.credentials(move |_url, _username_from_url, allowed_types| {
+            if allowed_types.contains(git2::CredentialType::USER_PASS_PLAINTEXT) {
+                git2::Cred::userpass_plaintext("x-access-token", &token)
+            } else {
+                Err(git2::Error::from_str(
+                    "No supported credential types for GitHub authentication",
+                ))
+            }
+        });
+        callbacks.push_update_reference(|refname, status| match status {
+            Some(msg) => Err(git2::Error::from_str(&format!(
+                "Push reference update failed for '{}': {}",
+                refname, msg
+            ))),
+            None => Ok(()),
+        });
+        callbacks
+    };
+
+    let mirror_dir = TempDir::new().map_err(|e| SystemError::GitOperation {
+        operation: "create mirror directory".to_string(),
+        reason: e.to_string(),
+    })?;
+    let repo =
+        Repository::init_bare(mirror_dir.path()).map_err(git_error("create mirror repository"))?;
+
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(callbacks());
+    repo.remote_anonymous(source_url.as_str())
+        .map_err(git_error("add source remote"))?
+        .fetch(
+            &["+refs/heads/*:refs/heads/*", "+refs/tags/*:refs/tags/*"],
+            Some(&mut fetch_options),
+            None,
+        )
+        .map_err(git_error("fetch source repository"))?;
+
+    let mut refspecs = Vec::new();
+    for reference in repo
+        .references()
+        .map_err(git_error("list mirrored references"))?
+    {
+        let reference = reference.map_err(git_error("read mirrored reference"))?;
+        if let Some(name) = reference.name() {
+            refspecs.push(format!("+{}:{}", name, name));
+        }
+    }
+    debug!("Mirroring {} references", refspecs.len());
+
+    let mut push_options = git2::PushOptions::new();
+    push_options.remote_callbacks(callbacks());
+    repo.remote_anonymous(destination_url.as_str())
+        .map_err(git_error("add destination remote"))?
+        .push(&refspecs, Some(&mut push_options))
+        .map_err(git_error("push mirror"))?;
+
+    info!("Repository mirrored to {}", destination_url);
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "git_tests.rs"]
 mod tests;
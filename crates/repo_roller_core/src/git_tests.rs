@@ -286,3 +286,78 @@ fn test_multiple_files_and_directories() {
     assert!(tree.get_name("src").is_some());
     assert!(tree.get_name("tests").is_some());
 }
+
+#[test]
+fn test_move_content_to_review_branch() {
+    let temp_dir = TempDir::new().unwrap();
+    init_local_git_repo(&temp_dir, "main").unwrap();
+    fs::write(temp_dir.path().join("README.md"), "# Test Project").unwrap();
+    commit_all_changes(&temp_dir, "Initial commit", false).unwrap();
+
+    move_content_to_review_branch(&temp_dir, "main", "template-init").unwrap();
+
+    let repo = Repository::open(temp_dir.path()).unwrap();
+    let main = repo
+        .find_reference("refs/heads/main")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+    let review = repo
+        .find_reference("refs/heads/template-init")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+
+    // The default branch holds only an empty root commit.
+    assert_eq!(main.parent_count(), 0);
+    assert_eq!(main.tree().unwrap().len(), 0);
+
+    // The review branch holds the content on top of the default branch.
+    assert_eq!(review.parent_id(0).unwrap(), main.id());
+    assert_eq!(review.message().unwrap(), "Initial commit");
+    assert!(review
+        .tree()
+        .unwrap()
+        .get_path(std::path::Path::new("README.md"))
+        .is_ok());
+}
+
+#[test]
+fn test_mirror_repository_copies_branches_tags_and_history() {
+    let source_dir = TempDir::new().unwrap();
+    init_local_git_repo(&source_dir, "main").unwrap();
+    fs::write(source_dir.path().join("README.md"), "# Test Project").unwrap();
+    commit_all_changes(&source_dir, "Initial commit", false).unwrap();
+    fs::write(source_dir.path().join("CHANGELOG.md"), "# Changes").unwrap();
+    commit_all_changes(&source_dir, "Add changelog", false).unwrap();
+
+    let source = Repository::open(source_dir.path()).unwrap();
+    let head = source.head().unwrap().peel_to_commit().unwrap();
+    source.branch("release", &head, false).unwrap();
+    source
+        .tag_lightweight("v1.0.0", head.as_object(), false)
+        .unwrap();
+
+    let destination_dir = TempDir::new().unwrap();
+    Repository::init_bare(destination_dir.path()).unwrap();
+
+    mirror_repository(
+        url::Url::from_directory_path(source_dir.path()).unwrap(),
+        url::Url::from_directory_path(destination_dir.path()).unwrap(),
+        "ghs_test_token",
+    )
+    .unwrap();
+
+    let destination = Repository::open_bare(destination_dir.path()).unwrap();
+    for reference in ["refs/heads/main", "refs/heads/release", "refs/tags/v1.0.0"] {
+        let commit = destination
+            .find_reference(reference)
+            .unwrap()
+            .peel_to_commit()
+            .unwrap();
+        assert_eq!(commit.id(), head.id(), "{reference}");
+    }
+    // The history comes along, not just the tip.
+    assert_eq!(head.parent_count(), 1);
+    assert!(destination.find_commit(head.parent_id(0).unwrap()).is_ok());
+}
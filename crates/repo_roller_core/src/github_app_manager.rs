@@ -0,0 +1,210 @@
+//! GitHub App checks for repositories.
+//!
+//! This module provides the [`GitHubAppManager`] component which checks that the
+//! GitHub Apps listed in configuration are installed in the organization.
+//! Apps are not installed by RepoRoller; installing a third-party app requires
+//! an organization owner. Required apps that are missing fail repository
+//! creation, optional apps that are missing only produce a warning.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use config_manager::settings::GitHubAppConfig;
+use github_client::RepositoryClient;
+use tracing::{info, warn};
+
+use crate::{RepoRollerResult, RepositoryError};
+
+/// Checks configured GitHub Apps against the organization's installations.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use github_client::GitHubClient;
+/// use repo_roller_core::GitHubAppManager;
+///
+/// # async fn example(github_client: GitHubClient) -> Result<(), Box<dyn std::error::Error>> {
+/// let manager = GitHubAppManager::new(github_client);
+///
+/// let result = manager.apply_github_apps("my-org", "my-repo", &[]).await?;
+/// println!("Missing optional apps: {}", result.missing_optional().count());
+/// # Ok(())
+/// # }
+/// ```
+pub struct GitHubAppManager {
+    /// GitHub client for API operations
+    github_client: Arc<dyn RepositoryClient>,
+}
+
+impl GitHubAppManager {
+    /// Creates a new GitHubAppManager.
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - GitHub client for API operations, either a `GitHubClient`
+    ///   or a client shared as `Arc<dyn RepositoryClient>`
+    pub fn new(github_client: impl Into<Arc<dyn RepositoryClient>>) -> Self {
+        Self {
+            github_client: github_client.into(),
+        }
+    }
+
+    /// Checks that the configured GitHub Apps are installed for a repository.
+    ///
+    /// The organization's app installations are listed once and every
+    /// configured app is recorded in the result with its outcome. If the
+    /// installations cannot be listed, every app is treated as missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `apps` - GitHub Apps from the merged configuration
+    ///
+    /// # Returns
+    ///
+    /// `Ok(ApplyGitHubAppsResult)` with one outcome per configured app
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepoRollerError::Repository` if a required app is not installed.
+    pub async fn apply_github_apps(
+        &self,
+        owner: &str,
+        repo: &str,
+        apps: &[GitHubAppConfig],
+    ) -> RepoRollerResult<ApplyGitHubAppsResult> {
+        if apps.is_empty() {
+            return Ok(ApplyGitHubAppsResult::new());
+        }
+
+        info!(
+            owner = owner,
+            repo = repo,
+            count = apps.len(),
+            "Checking GitHub App installations"
+        );
+
+        let installed: HashSet<u64> = match self
+            .github_client
+            .list_organization_installations(owner)
+            .await
+        {
+            Ok(installations) => installations.iter().filter_map(|i| i.app_id).collect(),
+            Err(e) => {
+                warn!(
+                    owner = owner,
+                    error = ?e,
+                    "Failed to list GitHub App installations; treating all apps as missing"
+                );
+                HashSet::new()
+            }
+        };
+
+        evaluate_github_apps(owner, apps, &installed)
+    }
+}
+
+/// Records the outcome for each app given the set of installed app IDs.
+///
+/// Returns an error for the first required app that is not installed. Missing
+/// optional apps are logged as warnings and recorded in the result.
+pub(crate) fn evaluate_github_apps(
+    owner: &str,
+    apps: &[GitHubAppConfig],
+    installed: &HashSet<u64>,
+) -> RepoRollerResult<ApplyGitHubAppsResult> {
+    let mut result = ApplyGitHubAppsResult::new();
+
+    for app in apps {
+        let required = app.is_required();
+        let status = if installed.contains(&app.app_id) {
+            GitHubAppStatus::Installed
+        } else if required {
+            return Err(RepositoryError::SettingsApplicationFailed {
+                setting: format!("github_apps.{}", app.app_id),
+                reason: format!(
+                    "Required GitHub App {} is not installed in '{}'",
+                    app.app_id, owner
+                ),
+            }
+            .into());
+        } else {
+            warn!(
+                owner = owner,
+                app_id = app.app_id,
+                "Optional GitHub App is not installed; skipping"
+            );
+            GitHubAppStatus::Missing
+        };
+
+        let mut permissions: Vec<(String, String)> = app
+            .permissions
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        permissions.sort();
+
+        result.outcomes.push(GitHubAppOutcome {
+            app_id: app.app_id,
+            required,
+            status,
+            permissions,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Installation state of a configured GitHub App.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitHubAppStatus {
+    /// The app is installed in the organization.
+    Installed,
+
+    /// The app is not installed; only possible for optional apps.
+    Missing,
+}
+
+/// Outcome of checking a single configured GitHub App.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubAppOutcome {
+    /// GitHub App ID
+    pub app_id: u64,
+
+    /// Whether the app was required by configuration
+    pub required: bool,
+
+    /// Whether the app was found to be installed
+    pub status: GitHubAppStatus,
+
+    /// Permissions the configuration requests for the app, sorted by name
+    pub permissions: Vec<(String, String)>,
+}
+
+/// Result of checking GitHub Apps for a repository.
+///
+/// Contains one outcome per configured app, in configuration order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplyGitHubAppsResult {
+    /// Outcome for each configured app
+    pub outcomes: Vec<GitHubAppOutcome>,
+}
+
+impl ApplyGitHubAppsResult {
+    /// Creates a new empty result.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the optional apps that were not installed.
+    pub fn missing_optional(&self) -> impl Iterator<Item = &GitHubAppOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|o| o.status == GitHubAppStatus::Missing)
+    }
+}
+
+#[cfg(test)]
+#[path = "github_app_manager_tests.rs"]
+mod tests;
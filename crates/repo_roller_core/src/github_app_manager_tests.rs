@@ -0,0 +1,70 @@
+//! Tests for github_app_manager module.
+
+use super::*;
+use crate::RepoRollerError;
+use config_manager::OverridableValue;
+use std::collections::HashMap;
+
+fn app(app_id: u64, required: Option<OverridableValue<bool>>) -> GitHubAppConfig {
+    GitHubAppConfig {
+        app_id,
+        permissions: HashMap::from([("checks".to_string(), "write".to_string())]),
+        required,
+    }
+}
+
+#[test]
+fn test_installed_apps_are_recorded() {
+    let apps = vec![app(1, Some(OverridableValue::fixed(true))), app(2, None)];
+    let installed = HashSet::from([1, 2]);
+
+    let result = evaluate_github_apps("my-org", &apps, &installed).unwrap();
+
+    assert_eq!(result.outcomes.len(), 2);
+    assert_eq!(result.outcomes[0].status, GitHubAppStatus::Installed);
+    assert!(result.outcomes[0].required);
+    assert_eq!(
+        result.outcomes[0].permissions,
+        vec![("checks".to_string(), "write".to_string())]
+    );
+    assert_eq!(result.outcomes[1].status, GitHubAppStatus::Installed);
+    assert!(!result.outcomes[1].required);
+    assert_eq!(result.missing_optional().count(), 0);
+}
+
+#[test]
+fn test_required_missing_app_is_fatal() {
+    let apps = vec![app(1, None), app(42, Some(OverridableValue::fixed(true)))];
+    let installed = HashSet::from([1]);
+
+    let err = evaluate_github_apps("my-org", &apps, &installed).unwrap_err();
+
+    match err {
+        RepoRollerError::Repository(RepositoryError::SettingsApplicationFailed {
+            setting,
+            reason,
+        }) => {
+            assert_eq!(setting, "github_apps.42");
+            assert!(reason.contains("my-org"));
+        }
+        other => panic!("Expected SettingsApplicationFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_optional_missing_app_is_a_warning() {
+    let apps = vec![app(7, None), app(8, Some(OverridableValue::allowed(false)))];
+    let installed = HashSet::new();
+
+    let result = evaluate_github_apps("my-org", &apps, &installed).unwrap();
+
+    assert_eq!(result.outcomes.len(), 2);
+    let missing: Vec<u64> = result.missing_optional().map(|o| o.app_id).collect();
+    assert_eq!(missing, vec![7, 8]);
+}
+
+#[test]
+fn test_empty_result() {
+    let result = ApplyGitHubAppsResult::new();
+    assert!(result.outcomes.is_empty());
+}
@@ -44,7 +44,7 @@
 //! ).await?;
 //!
 //! // Use token for git operations
-//! git::push_to_origin(&repo, url, "main", &token)?;
+//! git::push_to_origin(&repo, url, "main", &token, false)?;
 //!
 //! // Use client for GitHub API operations
 //! client.create_org_repository("my-org", &payload).await?;
@@ -94,7 +94,8 @@ mod tests;
 ///
 /// Returns `RepoRollerError` if:
 /// - App client creation fails (invalid credentials)
-/// - Installation token retrieval fails (app not installed, network issues)
+/// - The app is not installed on the organization (`GitHubError::AppNotInstalled`)
+/// - Installation token retrieval fails (network issues)
 /// - Token client creation fails (internal error)
 ///
 /// ## Example
@@ -149,12 +150,17 @@ pub(crate) async fn setup_github_authentication(
                 "Failed to get installation token for organization '{}': {}",
                 organization, e
             );
-            RepoRollerError::GitHub(GitHubError::AuthenticationFailed {
-                reason: format!(
-                    "Failed to get installation token for organization '{}': {}",
-                    organization, e
-                ),
-            })
+            match e {
+                github_client::Error::InstallationNotFound(org) => {
+                    RepoRollerError::GitHub(GitHubError::AppNotInstalled { org })
+                }
+                e => RepoRollerError::GitHub(GitHubError::AuthenticationFailed {
+                    reason: format!(
+                        "Failed to get installation token for organization '{}': {}",
+                        organization, e
+                    ),
+                }),
+            }
         })?;
 
     info!("Successfully retrieved installation token");
@@ -3,8 +3,9 @@
 //! This module provides the [`LabelManager`] component for orchestrating
 //! label operations with business logic, idempotency, and error handling.
 
-use github_client::{GitHubClient, RepositoryClient};
+use github_client::{Label, RepositoryClient};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, warn};
 
 use crate::{GitHubError, RepoRollerResult};
@@ -40,7 +41,7 @@ use crate::{GitHubError, RepoRollerResult};
 /// ```
 pub struct LabelManager {
     /// GitHub client for API operations
-    github_client: GitHubClient,
+    github_client: Arc<dyn RepositoryClient>,
 }
 
 impl LabelManager {
@@ -48,7 +49,8 @@ impl LabelManager {
     ///
     /// # Arguments
     ///
-    /// * `github_client` - GitHub client for API operations
+    /// * `github_client` - GitHub client for API operations, either a `GitHubClient`
+    ///   or a client shared as `Arc<dyn RepositoryClient>`
     ///
     /// # Examples
     ///
@@ -60,14 +62,19 @@ impl LabelManager {
     /// let manager = LabelManager::new(client);
     /// # }
     /// ```
-    pub fn new(github_client: GitHubClient) -> Self {
-        Self { github_client }
+    pub fn new(github_client: impl Into<Arc<dyn RepositoryClient>>) -> Self {
+        Self {
+            github_client: github_client.into(),
+        }
     }
 
     /// Applies labels to a repository, creating or updating as needed.
     ///
     /// This method ensures that all labels from the configuration are present
-    /// in the repository. It is idempotent and safe to call multiple times.
+    /// in the repository. It is idempotent and safe to call multiple times:
+    /// labels that already exist with the configured color and description are
+    /// left untouched, so re-running after a partial failure only applies what
+    /// is missing or changed.
     ///
     /// # Arguments
     ///
@@ -85,13 +92,16 @@ impl LabelManager {
     ///
     /// # Behavior
     ///
-    /// 1. Iterates through all labels in configuration
-    /// 2. Creates or updates each label (create_label is idempotent)
-    /// 3. Logs each operation (created/updated/skipped)
+    /// 1. Lists the labels currently defined in the repository
+    /// 2. Skips labels that already match the configuration
+    /// 3. Updates existing labels whose color or description differ, and
+    ///    creates missing labels
     /// 4. Returns summary of operations
     ///
     /// # Error Handling
     ///
+    /// - If the existing labels cannot be listed, every label is created
+    ///   (create_label updates labels that already exist)
     /// - Continues on individual label failures (logs warning)
     /// - Returns error only if all labels fail
     /// - Partial success is considered success
@@ -110,9 +120,63 @@ impl LabelManager {
 
         let mut result = ApplyLabelsResult::new();
 
+        // GitHub label names are case-insensitive
+        let existing_labels: HashMap<String, Label> =
+            match self.github_client.list_labels(owner, repo).await {
+                Ok(existing) => existing
+                    .into_iter()
+                    .map(|label| (label.name.to_lowercase(), label))
+                    .collect(),
+                Err(e) => {
+                    warn!(
+                        owner = owner,
+                        repo = repo,
+                        error = ?e,
+                        "Failed to list existing labels"
+                    );
+                    HashMap::new()
+                }
+            };
+
         for (label_name, label_config) in labels {
             info!(name = label_name, "Applying label");
 
+            if let Some(existing) = existing_labels.get(&label_config.name.to_lowercase()) {
+                if label_matches(existing, label_config) {
+                    info!(name = label_name, "Label already up to date, skipping");
+                    result.skipped += 1;
+                    continue;
+                }
+
+                match self
+                    .github_client
+                    .update_label(
+                        owner,
+                        repo,
+                        &existing.name,
+                        &label_config.name,
+                        &label_config.color,
+                        &label_config.description,
+                    )
+                    .await
+                {
+                    Ok(()) => {
+                        info!(name = label_name, "Label updated successfully");
+                        result.updated += 1;
+                    }
+                    Err(e) => {
+                        warn!(
+                            name = label_name,
+                            error = ?e,
+                            "Failed to update label"
+                        );
+                        result.failed += 1;
+                        result.failed_labels.push(label_name.clone());
+                    }
+                }
+                continue;
+            }
+
             match self
                 .github_client
                 .create_label(
@@ -142,6 +206,8 @@ impl LabelManager {
 
         info!(
             created = result.created,
+            updated = result.updated,
+            skipped = result.skipped,
             failed = result.failed,
             "Label application complete"
         );
@@ -149,6 +215,103 @@ impl LabelManager {
         Ok(result)
     }
 
+    /// Replaces a repository's labels with the configured set.
+    ///
+    /// Deletes every existing label whose name is not in `labels` and then
+    /// applies the configured labels with [`apply_labels`](Self::apply_labels).
+    /// On a newly created repository this removes the stock labels GitHub adds
+    /// automatically, so only the organization's label scheme remains.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `labels` - Map of label name to LabelConfig
+    ///
+    /// # Returns
+    ///
+    /// `Ok(ApplyLabelsResult)` with details of operations performed, including
+    /// the number of deleted labels
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepoRollerError::System` if label operations fail.
+    ///
+    /// # Error Handling
+    ///
+    /// - If the existing labels cannot be listed, no labels are deleted and
+    ///   the configured labels are still applied
+    /// - Labels that no longer exist when deleted are ignored
+    /// - Labels that fail to delete are counted as failures (logs warning)
+    pub async fn replace_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        labels: &HashMap<String, config_manager::settings::LabelConfig>,
+    ) -> RepoRollerResult<ApplyLabelsResult> {
+        info!(
+            owner = owner,
+            repo = repo,
+            label_count = labels.len(),
+            "Replacing repository labels"
+        );
+
+        let mut deleted = 0;
+        let mut failed_deletions = Vec::new();
+
+        match self.github_client.list_labels(owner, repo).await {
+            Ok(existing) => {
+                // GitHub label names are case-insensitive
+                let configured: std::collections::HashSet<String> = labels
+                    .values()
+                    .map(|label| label.name.to_lowercase())
+                    .collect();
+
+                for label in existing
+                    .into_iter()
+                    .filter(|label| !configured.contains(&label.name.to_lowercase()))
+                {
+                    match self
+                        .github_client
+                        .delete_label(owner, repo, &label.name)
+                        .await
+                    {
+                        Ok(()) => {
+                            info!(name = %label.name, "Label deleted");
+                            deleted += 1;
+                        }
+                        Err(github_client::Error::NotFound) => {
+                            info!(name = %label.name, "Label already removed");
+                        }
+                        Err(e) => {
+                            warn!(
+                                name = %label.name,
+                                error = ?e,
+                                "Failed to delete label"
+                            );
+                            failed_deletions.push(label.name);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(
+                    owner = owner,
+                    repo = repo,
+                    error = ?e,
+                    "Failed to list existing labels, no labels deleted"
+                );
+            }
+        }
+
+        let mut result = self.apply_labels(owner, repo, labels).await?;
+        result.deleted = deleted;
+        result.failed += failed_deletions.len();
+        result.failed_labels.extend(failed_deletions);
+
+        Ok(result)
+    }
+
     /// Lists all labels currently defined in a repository.
     ///
     /// # Arguments
@@ -177,6 +340,20 @@ impl LabelManager {
     }
 }
 
+/// Returns `true` if an existing label already has the configured name,
+/// color and description.
+///
+/// Colors are compared case-insensitively and without a leading `#`; a missing
+/// description matches an empty one.
+fn label_matches(existing: &Label, config: &config_manager::settings::LabelConfig) -> bool {
+    existing.name == config.name
+        && existing
+            .color
+            .trim_start_matches('#')
+            .eq_ignore_ascii_case(config.color.trim_start_matches('#'))
+        && existing.description.as_deref().unwrap_or("") == config.description
+}
+
 /// Result of applying labels to a repository.
 ///
 /// Contains counters for the different outcomes of label operations.
@@ -191,6 +368,9 @@ pub struct ApplyLabelsResult {
     /// Number of labels that already existed with correct configuration
     pub skipped: usize,
 
+    /// Number of labels deleted because they were not in the configured set
+    pub deleted: usize,
+
     /// Number of labels that failed to apply
     pub failed: usize,
 
@@ -205,6 +385,7 @@ impl ApplyLabelsResult {
             created: 0,
             updated: 0,
             skipped: 0,
+            deleted: 0,
             failed: 0,
             failed_labels: Vec::new(),
         }
@@ -215,9 +396,10 @@ impl ApplyLabelsResult {
         self.failed == 0
     }
 
-    /// Returns true if any labels were successfully applied (created or updated).
+    /// Returns true if any labels were successfully changed (created, updated
+    /// or deleted).
     pub fn has_changes(&self) -> bool {
-        self.created > 0 || self.updated > 0
+        self.created > 0 || self.updated > 0 || self.deleted > 0
     }
 }
 
@@ -11,6 +11,7 @@ fn test_apply_labels_result_new() {
     assert_eq!(result.created, 0);
     assert_eq!(result.updated, 0);
     assert_eq!(result.skipped, 0);
+    assert_eq!(result.deleted, 0);
     assert_eq!(result.failed, 0);
     assert!(result.failed_labels.is_empty());
 }
@@ -76,6 +77,17 @@ fn test_apply_labels_result_has_changes_when_updated() {
     );
 }
 
+#[test]
+fn test_apply_labels_result_has_changes_when_deleted() {
+    let mut result = ApplyLabelsResult::new();
+    result.deleted = 1;
+
+    assert!(
+        result.has_changes(),
+        "Result with deleted labels should have changes"
+    );
+}
+
 #[test]
 fn test_apply_labels_result_has_no_changes_when_only_skipped() {
     let mut result = ApplyLabelsResult::new();
@@ -134,3 +146,35 @@ fn test_label_manager_can_be_created() {
     // The LabelManager type exists and has the correct public API
     // (verified by successful compilation of this crate)
 }
+
+#[test]
+fn test_label_matches_ignores_color_case_and_hash() {
+    let existing = Label {
+        name: "bug".to_string(),
+        color: "d73a4a".to_string(),
+        description: None,
+    };
+    let config = config_manager::settings::LabelConfig {
+        name: "bug".to_string(),
+        color: "#D73A4A".to_string(),
+        description: String::new(),
+    };
+
+    assert!(label_matches(&existing, &config));
+}
+
+#[test]
+fn test_label_matches_detects_changed_description() {
+    let existing = Label {
+        name: "bug".to_string(),
+        color: "d73a4a".to_string(),
+        description: Some("Old description".to_string()),
+    };
+    let config = config_manager::settings::LabelConfig {
+        name: "bug".to_string(),
+        color: "d73a4a".to_string(),
+        description: "Something isn't working".to_string(),
+    };
+
+    assert!(!label_matches(&existing, &config));
+}
@@ -87,12 +87,16 @@
 //!     visibility_policy_provider,
 //!     environment_detector,
 //!     event_context,
+//!     repo_roller_core::CreationDependencies::default(),
 //! ).await {
 //!     Ok(result) => {
 //!         println!("Repository created successfully:");
 //!         println!("  URL: {}", result.repository_url);
 //!         println!("  ID: {}", result.repository_id);
 //!         println!("  Default branch: {}", result.default_branch);
+//!         for warning in &result.warnings {
+//!             println!("  Warning: {}: {}", warning.field_path, warning.message);
+//!         }
 //!     }
 //!     Err(e) => eprintln!("Repository creation failed: {}", e),
 //! }
@@ -105,7 +109,7 @@
 //! All operations return [`RepoRollerResult<T>`] which provides structured error
 //! information with domain-specific error types.
 
-use github_client::{GitHubClient, RepositoryClient, RepositoryCreatePayload};
+use github_client::{CommitFile, RepositoryClient, RepositoryCreatePayload};
 use temp_dir::TempDir;
 use tracing::{debug, error, info, warn};
 
@@ -113,6 +117,11 @@ mod errors;
 
 // Git operations module
 mod git;
+pub use git::{Git2Operations, GitOperations};
+
+// Git and GitHub services used by repository creation
+mod creation_dependencies;
+pub use creation_dependencies::CreationDependencies;
 
 // Configuration resolution and application module
 mod configuration;
@@ -121,19 +130,41 @@ mod configuration;
 mod template_processing;
 // Re-export for testing
 pub use template_processing::extract_config_variables;
+// Re-export so callers can list a template's files before using it
+pub use template_processing::{inspect_template, TemplateInspection};
+// Re-export so callers (e.g. the API validate endpoint) can check a request without creating anything
+pub use template_processing::{dry_run_template, TemplateDryRun};
+// Re-export so callers (e.g. the CLI render command) can preview a template locally
+pub use template_processing::{render_template_to_directory, LocalRenderRequest};
+// Re-export so callers (e.g. the API and CLI preview commands) can show the initial commit's files
+pub use template_processing::{
+    preview_local_template, preview_template_content, RenderedFilePreview,
+};
+// Re-export so callers (e.g. configuration preview) can report the same warnings
+pub use configuration::collect_configuration_warnings;
+pub use template_processing::collect_template_warnings;
 
 // Content providers for repository initialization
 mod content_providers;
 
+// Generated CODEOWNERS files
+mod codeowners;
+
 // Label management operations
 mod label_manager;
 
 // Webhook management operations
 mod webhook_manager;
 
+// GitHub App installation checks
+mod github_app_manager;
+
 // Ruleset management operations
 mod ruleset_manager;
 
+// Pre-flight checks that referenced teams and GitHub Apps exist
+mod references;
+
 // Permission types and domain model
 pub mod permissions;
 
@@ -162,6 +193,31 @@ pub mod event_secrets;
 // Event metrics collection
 pub mod event_metrics;
 
+// Machine-readable events for each creation step
+pub mod step_events;
+pub use step_events::{
+    CreationStep, CreationStepEvent, CreationStepSink, InMemoryStepSink, JsonlStepSink,
+};
+
+// Post-creation verification of applied configuration
+pub mod verification;
+pub use verification::{verify_repository_configuration, SettingMismatch, VerificationReport};
+
+// Git reference name rules for branch names derived from user input
+pub use template_engine::ref_name;
+pub use template_engine::{is_valid_ref_name, sanitize_ref_name};
+
+// Drift detection for existing repositories
+pub mod drift;
+pub use drift::{detect_drift, DriftReport, DriftSeverity, SettingDrift};
+
+// Resumable batch repository creation
+pub mod batch;
+pub use batch::{
+    create_repositories, BatchItemResult, BatchItemStatus, BatchLedger, InMemoryBatchLedger,
+    LedgerOutcome,
+};
+
 // Re-export error types for public API
 pub use errors::{
     AuthenticationError, AuthenticationResult, ConfigurationError, GitHubError, GitHubResult,
@@ -223,25 +279,30 @@ pub use authentication::{SessionId, UserId};
 pub use github::{GitHubToken, InstallationId};
 pub use repository::{OrganizationName, RepositoryName};
 pub use request::{
-    ContentStrategy, RepositoryCreationRequest, RepositoryCreationRequestBuilder,
+    ContentStrategy, OwnerType, RepositoryCreationRequest, RepositoryCreationRequestBuilder,
     RepositoryCreationResult,
 };
 pub use template::TemplateName;
 // Re-exported from visibility module - see module docs for examples
 pub use visibility::{
-    DecisionSource, GitHubEnvironmentDetector, PlanLimitations, PolicyConstraint,
-    RepositoryVisibility, VisibilityDecision, VisibilityError, VisibilityPolicy,
+    DecisionSource, GitHubEnvironmentDetector, PersonalAccountEnvironment, PlanLimitations,
+    PolicyConstraint, RepositoryVisibility, VisibilityDecision, VisibilityError, VisibilityPolicy,
     VisibilityPolicyProvider, VisibilityRequest, VisibilityResolver,
 };
 // Re-exported from content_providers module
 pub use content_providers::{
-    ContentProvider, CustomInitContentProvider, CustomInitOptions, TemplateBasedContentProvider,
+    ContentProvider, CopyFromRepositoryContentProvider, CustomInitContentProvider,
+    CustomInitOptions, PlaceholderContentProvider, TemplateBasedContentProvider,
     ZeroContentProvider,
 };
 // Re-exported from label_manager module
 pub use label_manager::{ApplyLabelsResult, LabelManager};
 // Re-exported from webhook_manager module
 pub use webhook_manager::{ApplyWebhooksResult, WebhookManager};
+// Re-exported from github_app_manager module
+pub use github_app_manager::{
+    ApplyGitHubAppsResult, GitHubAppManager, GitHubAppOutcome, GitHubAppStatus,
+};
 // Re-exported from ruleset_manager module
 pub use ruleset_manager::{ApplyRulesetsResult, RulesetManager};
 // Re-exported from permissions module
@@ -253,12 +314,13 @@ pub use permissions::{
 };
 // Re-exported from event_publisher module
 pub use event_publisher::{
-    collect_notification_endpoints, compute_hmac_sha256, publish_repository_created,
-    sign_webhook_request, AppliedSettings, DeliveryResult, EventNotificationContext,
-    RepositoryCreatedEvent,
+    collect_notification_endpoints, compute_hmac_sha256, preview_repository_created,
+    publish_repository_created, publish_repository_creation_failed, sign_webhook_request,
+    AppliedSettings, DeliveryResult, EventNotificationContext, NotificationPreview,
+    RepositoryCreatedEvent, RepositoryCreationFailedEvent,
 };
 // Re-exported from config_manager
-pub use config_manager::{NotificationEndpoint, NotificationsConfig};
+pub use config_manager::{NotificationEndpoint, NotificationsConfig, ValidationWarning};
 // Re-exported from event_secrets module
 pub use event_secrets::{
     EnvironmentSecretResolver, FilesystemSecretResolver, SecretResolutionError, SecretResolver,
@@ -283,6 +345,11 @@ impl Timestamp {
         Self(Utc::now())
     }
 
+    /// Create a timestamp for the current moment as reported by `clock`
+    pub fn from_clock(clock: &dyn template_engine::Clock) -> Self {
+        Self(clock.now())
+    }
+
     /// Create a timestamp from a `DateTime<Utc>`
     pub fn from_datetime(dt: DateTime<Utc>) -> Self {
         Self(dt)
@@ -319,79 +386,169 @@ mod tests;
 struct CreationClients {
     /// Raw installation token used for git push authentication.
     installation_token: String,
+    /// When `installation_token` was obtained.
+    installation_token_issued_at: std::time::Instant,
     /// GitHub API client authenticated as the installation.
-    installation_repo_client: GitHubClient,
-    /// Template content fetcher backed by the same installation token.
-    template_fetcher: template_engine::GitHubTemplateFetcher,
+    installation_repo_client: std::sync::Arc<dyn RepositoryClient>,
+    /// Template content fetcher.
+    template_fetcher: std::sync::Arc<dyn template_engine::TemplateFetcher>,
 }
 
 /// Authenticates as the GitHub App installation and creates the GitHub clients
 /// needed for the rest of the creation workflow.
 ///
+/// The repository client and template fetcher configured on `dependencies`
+/// are used when present; otherwise a client for the installation and a
+/// [`template_engine::GitHubTemplateFetcher`] are created. A created client
+/// refreshes its token through `auth_service` when GitHub rejects it. The
+/// `github_api_base_url` of `dependencies` overrides `https://api.github.com`
+/// for a created client.
+///
 /// # Errors
 ///
-/// Returns `AuthenticationFailed` when the installation token cannot be obtained,
-/// or `SystemError::Internal` when a client cannot be constructed.
+/// Returns `AppNotInstalled` when the GitHub App is not installed on `owner`,
+/// `AuthenticationFailed` when the installation token cannot otherwise be
+/// obtained, or `SystemError::Internal` when a client cannot be constructed.
 async fn setup_github_clients(
     auth_service: &dyn auth_handler::UserAuthenticationService,
     owner: &str,
+    dependencies: &CreationDependencies,
 ) -> RepoRollerResult<CreationClients> {
-    let installation_token = auth_service
-        .get_installation_token_for_org(owner)
-        .await
-        .map_err(|e| {
-            error!("Failed to authenticate: {}", e);
-            RepoRollerError::GitHub(GitHubError::AuthenticationFailed {
-                reason: format!("Failed to get installation token: {}", e),
-            })
-        })?;
-
-    let installation_client =
-        github_client::create_token_client(&installation_token).map_err(|e| {
-            error!("Failed to create installation token client: {}", e);
-            RepoRollerError::System(SystemError::Internal {
-                reason: format!("Failed to create installation token client: {}", e),
-            })
-        })?;
-    let installation_repo_client = GitHubClient::new(installation_client);
+    let installation_token = get_installation_token(auth_service, owner).await?;
+    let installation_token_issued_at = std::time::Instant::now();
+
+    let installation_repo_client: std::sync::Arc<dyn RepositoryClient> =
+        match &dependencies.repository_client {
+            Some(client) => client.clone(),
+            None => {
+                let base_url = dependencies.github_api_base_url.as_deref();
+                let mut client = github_client::create_github_client(&installation_token, base_url)
+                    .map_err(|e| {
+                        error!("Failed to create installation token client: {}", e);
+                        RepoRollerError::System(SystemError::Internal {
+                            reason: format!("Failed to create installation token client: {}", e),
+                        })
+                    })?;
+                if let Some(refresher) = auth_service
+                    .installation_token_refresher(owner, base_url)
+                    .await
+                {
+                    client = client.with_token_refresher(refresher);
+                }
+                std::sync::Arc::new(client)
+            }
+        };
 
-    let template_fetcher = template_engine::GitHubTemplateFetcher::new();
+    let template_fetcher = dependencies
+        .template_fetcher
+        .clone()
+        .unwrap_or_else(|| std::sync::Arc::new(template_engine::GitHubTemplateFetcher::new()));
 
     Ok(CreationClients {
         installation_token,
+        installation_token_issued_at,
         installation_repo_client,
         template_fetcher,
     })
 }
 
-/// Resolves the merged organization configuration and loads the template
-/// configuration from GitHub (when a template is specified).
+/// Obtains an installation token for `owner`.
+///
+/// # Errors
+///
+/// Returns `AppNotInstalled` when the GitHub App is not installed on `owner`
+/// and `AuthenticationFailed` when the token cannot otherwise be obtained.
+async fn get_installation_token(
+    auth_service: &dyn auth_handler::UserAuthenticationService,
+    owner: &str,
+) -> RepoRollerResult<String> {
+    auth_service
+        .get_installation_token_for_org(owner)
+        .await
+        .map_err(|e| {
+            error!("Failed to authenticate: {}", e);
+            match e {
+                auth_handler::AuthError::AppNotInstalled(org) => {
+                    RepoRollerError::GitHub(GitHubError::AppNotInstalled { org })
+                }
+                e => RepoRollerError::GitHub(GitHubError::AuthenticationFailed {
+                    reason: format!("Failed to get installation token: {}", e),
+                }),
+            }
+        })
+}
+
+/// Age after which the installation token is replaced before it is used in a
+/// Git remote URL. Installation tokens expire one hour after they are issued,
+/// and Git cannot refresh a rejected token the way the API client does.
+const PUSH_TOKEN_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(45 * 60);
+
+/// Returns the installation token to push with, obtaining a new one when the
+/// token from the start of the creation is older than `max_age`.
+///
+/// # Errors
+///
+/// Returns the errors of [`get_installation_token`] when a new token is needed.
+async fn push_token(
+    auth_service: &dyn auth_handler::UserAuthenticationService,
+    owner: &str,
+    clients: &CreationClients,
+    max_age: std::time::Duration,
+) -> RepoRollerResult<String> {
+    if clients.installation_token_issued_at.elapsed() < max_age {
+        return Ok(clients.installation_token.clone());
+    }
+    info!("Installation token is about to expire, obtaining a new one for the push");
+    get_installation_token(auth_service, owner).await
+}
+
+/// Resolves the merged organization configuration for the request.
 ///
-/// Returns `(merged_config, template)` where `template` is `None` for
-/// empty-repository or no-template creations.
+/// The organization configuration is read as `dependencies` describe, or from
+/// `metadata_repository_name` at its default branch when they name no metadata
+/// provider configuration.
 ///
 /// # Errors
 ///
-/// Returns `ConfigurationError` when the merged config cannot be resolved, or
-/// `TemplateError::TemplateNotFound` when the requested template does not exist.
-async fn load_creation_config(
+/// Returns `ConfigurationError` when the merged config cannot be resolved.
+async fn resolve_creation_config(
     installation_token: &str,
     request: &RepositoryCreationRequest,
-    metadata_provider: &dyn config_manager::MetadataRepositoryProvider,
     metadata_repository_name: &str,
-) -> RepoRollerResult<(
-    config_manager::MergedConfiguration,
-    Option<config_manager::TemplateConfig>,
-)> {
+    dependencies: &CreationDependencies,
+) -> RepoRollerResult<config_manager::MergedConfiguration> {
     let template_name_for_config = request.template.as_ref().map(|t| t.as_ref()).unwrap_or("");
+    let metadata_provider_config = dependencies
+        .metadata_provider_config
+        .clone()
+        .unwrap_or_else(|| {
+            config_manager::MetadataProviderConfig::explicit(metadata_repository_name)
+        });
     let merged_config = configuration::resolve_organization_configuration(
         installation_token,
         request.owner.as_ref(),
         template_name_for_config,
-        metadata_repository_name,
+        &request.owning_teams,
+        metadata_provider_config,
+        dependencies.combined_metadata_file.as_deref(),
+        dependencies.github_api_base_url.as_deref(),
     )
     .await?;
+    Ok(merged_config)
+}
 
+/// Loads the template configuration from GitHub when a template is specified.
+///
+/// Returns `None` for empty-repository or no-template creations.
+///
+/// # Errors
+///
+/// Returns `TemplateError::TemplateNotFound` when the requested template does
+/// not exist.
+async fn load_template_config(
+    request: &RepositoryCreationRequest,
+    metadata_provider: &dyn config_manager::MetadataRepositoryProvider,
+) -> RepoRollerResult<Option<config_manager::TemplateConfig>> {
     let template = if let Some(ref template_name) = request.template {
         debug!(
             "Loading template '{}' from organization '{}'",
@@ -413,7 +570,7 @@ async fn load_creation_config(
         None
     };
 
-    Ok((merged_config, template))
+    Ok(template)
 }
 
 /// Resolves the final repository visibility by evaluating organization policies,
@@ -511,7 +668,8 @@ async fn resolve_repository_visibility(
 /// Generates the local repository content by selecting a [`ContentProvider`]
 /// based on `request.content_strategy` and calling `provide_content`.
 ///
-/// Returns the temporary directory path containing the generated content.
+/// Returns the temporary directory containing the generated content and the
+/// commit SHA of the rendered template, when one was rendered.
 ///
 /// # Errors
 ///
@@ -520,52 +678,173 @@ async fn generate_repository_content(
     request: &RepositoryCreationRequest,
     template: Option<&config_manager::TemplateConfig>,
     merged_config: &config_manager::MergedConfiguration,
-    template_fetcher: &template_engine::GitHubTemplateFetcher,
-) -> RepoRollerResult<TempDir> {
+    description: Option<&str>,
+    template_fetcher: &dyn template_engine::TemplateFetcher,
+    clock: std::sync::Arc<dyn template_engine::Clock>,
+) -> RepoRollerResult<(TempDir, Option<String>)> {
     let template_source = request
         .template
         .as_ref()
         .map(|t| format!("{}/{}", request.owner.as_ref(), t.as_ref()))
         .unwrap_or_default();
 
-    let content_provider: Box<dyn crate::ContentProvider> = match request.content_strategy {
+    let content_provider: Box<dyn crate::ContentProvider> = match &request.content_strategy {
         crate::ContentStrategy::Template => {
-            Box::new(crate::TemplateBasedContentProvider::new(template_fetcher))
+            Box::new(crate::TemplateBasedContentProvider::new(template_fetcher).with_clock(clock))
         }
-        crate::ContentStrategy::Empty => Box::new(crate::ZeroContentProvider::new()),
+        crate::ContentStrategy::Empty => Box::new(crate::PlaceholderContentProvider::new(
+            description.map(str::to_string),
+        )),
         crate::ContentStrategy::CustomInit {
             include_readme,
             include_gitignore,
         } => Box::new(crate::CustomInitContentProvider::new(
             crate::CustomInitOptions {
-                include_readme,
-                include_gitignore,
+                include_readme: *include_readme,
+                include_gitignore: *include_gitignore,
             },
         )),
+        crate::ContentStrategy::CopyFromRepository { source, .. } => Box::new(
+            crate::CopyFromRepositoryContentProvider::new(template_fetcher, source),
+        ),
     };
 
-    content_provider
+    let temp_dir = content_provider
         .provide_content(request, template, &template_source, merged_config)
-        .await
+        .await?;
+    Ok((temp_dir, content_provider.template_commit_sha()))
+}
+
+/// Returns the URL Git reaches repositories under on the GitHub instance
+/// whose API is at `github_api_base_url`.
+///
+/// `https://api.github.com` (the default) maps to `https://github.com/` and a
+/// GitHub Enterprise Server API at `https://{host}/api/v3` to
+/// `https://{host}/`. Other URLs, such as a mock server, are used as they are.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` if the base URL cannot be parsed.
+fn git_base_url(github_api_base_url: Option<&str>) -> RepoRollerResult<url::Url> {
+    let api_url = github_api_base_url.unwrap_or("https://api.github.com");
+    let invalid = |e: url::ParseError| {
+        RepoRollerError::Validation(ValidationError::InvalidFormat {
+            field: "github_api_base_url".to_string(),
+            reason: format!("'{}' is not a valid URL: {}", api_url, e),
+        })
+    };
+
+    let mut url = url::Url::parse(api_url).map_err(invalid)?;
+    if let Some(host) = url.host_str().and_then(|h| h.strip_prefix("api.")) {
+        let host = host.to_string();
+        url.set_host(Some(&host)).map_err(invalid)?;
+    }
+    let path = url.path().trim_end_matches('/');
+    let path = format!("{}/", path.strip_suffix("/api/v3").unwrap_or(path));
+    url.set_path(&path);
+    url.set_query(None);
+    Ok(url)
+}
+
+/// Reads the default branch of the source of a full-history copy.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` if `source` (`owner/name`) does
+/// not exist or is not accessible, and `GitHubError::NetworkError` if it
+/// cannot be read.
+async fn source_default_branch(
+    repo_client: &dyn RepositoryClient,
+    source: &str,
+) -> RepoRollerResult<String> {
+    let (owner, name) = source.split_once('/').unwrap_or((source, ""));
+    match repo_client.get_repository(owner, name).await {
+        Ok(repo) => Ok(repo
+            .default_branch()
+            .unwrap_or(FALLBACK_DEFAULT_BRANCH)
+            .to_string()),
+        Err(github_client::Error::NotFound) => Err(RepoRollerError::Validation(
+            ValidationError::InvalidFormat {
+                field: "content_strategy.source".to_string(),
+                reason: format!("'{}' does not exist or is not accessible", source),
+            },
+        )),
+        Err(e) => {
+            error!("Failed to read source repository '{}': {}", source, e);
+            Err(RepoRollerError::GitHub(GitHubError::NetworkError {
+                reason: format!("Failed to read source repository '{}': {}", source, e),
+            }))
+        }
+    }
+}
+
+/// Mirrors the source of a full-history copy into the new repository.
+///
+/// Every branch and tag of `source` (`owner/name`) is pushed to
+/// `destination` (`owner/name`) with its history, replacing anything GitHub
+/// committed while creating it. Both are reached on the Git host at
+/// `git_base_url`.
+///
+/// # Errors
+///
+/// Returns `SystemError::Internal` if the mirror fails.
+fn mirror_source_repository(
+    git: &dyn GitOperations,
+    git_base_url: &url::Url,
+    source: &str,
+    destination: &str,
+    installation_token: &str,
+) -> RepoRollerResult<()> {
+    let source_url = git_base_url.join(&format!("{}.git", source)).map_err(|e| {
+        RepoRollerError::Validation(ValidationError::InvalidFormat {
+            field: "content_strategy.source".to_string(),
+            reason: format!("'{}' is not a valid repository: {}", source, e),
+        })
+    })?;
+    let repo_url = git_base_url
+        .join(&format!("{}.git", destination))
+        .map_err(|e| {
+            RepoRollerError::System(SystemError::Internal {
+                reason: format!("'{}' is not a valid repository: {}", destination, e),
+            })
+        })?;
+
+    info!(
+        "Mirroring '{}' with its full history to {}",
+        source, repo_url
+    );
+    git.mirror(source_url, repo_url, installation_token)
+        .map_err(|e| {
+            error!("Failed to mirror '{}': {}", source, e);
+            RepoRollerError::System(SystemError::Internal {
+                reason: format!("Failed to mirror '{}': {}", source, e),
+            })
+        })
 }
 
 /// Pushes the local repository to the newly created GitHub remote.
 ///
+/// When `force` is set the remote branch is overwritten, which is needed when
+/// GitHub already committed to it while creating the repository.
+///
 /// # Errors
 ///
 /// Returns `SystemError::Internal` if the push fails.
 fn push_repository_to_github(
+    git: &dyn GitOperations,
     local_repo_path: &TempDir,
     repo_url: url::Url,
     default_branch: &str,
     installation_token: &str,
+    force: bool,
 ) -> RepoRollerResult<()> {
     info!("Pushing local repository to remote: {}", repo_url);
-    git::push_to_origin(
+    git.push(
         local_repo_path,
         repo_url,
         default_branch,
         installation_token,
+        force,
     )
     .map_err(|e| {
         error!("Failed to push to origin: {}", e);
@@ -602,10 +881,12 @@ fn is_transient_push_error(error: &RepoRollerError) -> bool {
 /// Returns `SystemError::Internal` wrapping the last push error if all
 /// attempts are exhausted.
 async fn push_repository_to_github_with_retry(
+    git: &dyn GitOperations,
     local_repo_path: &TempDir,
     repo_url: url::Url,
     default_branch: &str,
     installation_token: &str,
+    force: bool,
 ) -> RepoRollerResult<()> {
     const MAX_ATTEMPTS: u32 = 3;
 
@@ -624,10 +905,12 @@ async fn push_repository_to_github_with_retry(
         }
 
         match push_repository_to_github(
+            git,
             local_repo_path,
             repo_url.clone(),
             default_branch,
             installation_token,
+            force,
         ) {
             Ok(()) => return Ok(()),
             Err(e) if is_transient_push_error(&e) => {
@@ -641,156 +924,753 @@ async fn push_repository_to_github_with_retry(
     Err(last_error.expect("loop always sets last_error before exhausting"))
 }
 
-/// Applies the merged configuration and repository permissions after the
-/// repository has been created and populated on GitHub.
+/// Rejects collaborator grants that a creation request is not allowed to make.
 ///
-/// Permission errors are treated as non-fatal warnings: configuration will
-/// still be applied and the repository is fully usable.
+/// Collaborators in the request come from user input (API body or CLI flags),
+/// so they may not be granted `admin` access. Administrative access for
+/// individuals can only be established through organization or template
+/// configuration.
 ///
 /// # Errors
 ///
-/// Returns errors from configuration application; permission errors are logged
-/// and suppressed.
-async fn apply_post_creation_settings(
-    installation_repo_client: &GitHubClient,
-    request: &RepositoryCreationRequest,
-    merged_config: &config_manager::MergedConfiguration,
-    template: Option<&config_manager::TemplateConfig>,
-    requestor: &str,
-) -> RepoRollerResult<()> {
-    configuration::apply_repository_configuration(
-        installation_repo_client,
-        request.owner.as_ref(),
-        request.name.as_ref(),
-        merged_config,
-    )
-    .await?;
-
-    // Permission errors are non-fatal: the repository already exists and is
-    // usable; log a warning rather than failing the entire creation.
-    {
-        let permission_manager = crate::permission_manager::PermissionManager::new(
-            installation_repo_client.clone(),
-            crate::policy_engine::PolicyEngine::new(),
-        );
-        let hierarchy = crate::permission_workflow::build_permission_hierarchy(template);
-        let perm_request = crate::permission_workflow::build_permission_request(
-            &request.owner,
-            &request.name,
-            requestor,
-        );
-        // NOTE: `hierarchy` carries empty `organization_policies` and
-        // `user_requested_permissions` because org-level policies are not yet
-        // threaded through `MergedConfiguration`. The `PolicyEngine::evaluate_permission_request`
-        // call below therefore evaluates an empty request against an empty policy and
-        // is effectively a no-op today. The real permission-merge enforcement lives in
-        // `merge_access_map_with_policy` (called immediately below). The PolicyEngine
-        // path is future infrastructure intended to replace that merge once org policies
-        // are properly wired up. Until then, do not add policy logic inside the engine.
-        // Teams and collaborators are merged from two sources:
-        //   1. Org/template config (merged_config.teams / .collaborators) – baseline
-        //   2. Explicit request fields (request.teams / .collaborators)    – highest precedence
-        //
-        // Policy rules applied during merge (request overrides are subject to):
-        //   • Locked entries (merged_config.locked_teams) cannot be altered by the request.
-        //   • Config-established entries cannot be demoted (only upgraded) by the request.
-        //   • No request entry may exceed merged_config.max_team_access_level (capped).
-        let teams: std::collections::HashMap<String, crate::permissions::AccessLevel> =
-            merge_access_map_with_policy(
-                &merged_config.teams,
-                &merged_config.locked_teams,
-                merged_config.max_team_access_level.as_deref(),
-                request
-                    .teams
-                    .iter()
-                    .map(|(k, v)| (k.as_str(), *v))
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                "team",
-            );
-        let collaborators: std::collections::HashMap<String, crate::permissions::AccessLevel> =
-            merge_access_map_with_policy(
-                &merged_config.collaborators,
-                &merged_config.locked_collaborators,
-                merged_config.max_collaborator_access_level.as_deref(),
-                request
-                    .collaborators
-                    .iter()
-                    .map(|(k, v)| (k.as_str(), *v))
-                    .collect::<Vec<_>>()
-                    .as_slice(),
-                "collaborator",
+/// Returns `ValidationError::InvalidOption` naming the first collaborator whose
+/// requested access level is not permitted.
+fn validate_requested_collaborators(request: &RepositoryCreationRequest) -> RepoRollerResult<()> {
+    let mut usernames: Vec<&String> = request.collaborators.keys().collect();
+    usernames.sort();
+    for username in usernames {
+        if request.collaborators[username] == crate::permissions::AccessLevel::Admin {
+            warn!(
+                "Rejecting admin access requested for collaborator '{}'",
+                username
             );
-
-        match permission_manager
-            .apply_repository_permissions(
-                request.owner.as_ref(),
-                request.name.as_ref(),
-                &perm_request,
-                &hierarchy,
-                &teams,
-                &collaborators,
-            )
-            .await
-        {
-            Ok(result) => {
-                info!(
-                    teams_applied = result.teams_applied,
-                    collaborators_applied = result.collaborators_applied,
-                    "Repository permissions applied successfully"
-                );
-            }
-            Err(e) => {
-                warn!(
-                    "Permission application failed (non-fatal); repository created \
-                     but permissions may not be fully configured: {}",
-                    e
-                );
-            }
+            return Err(RepoRollerError::Validation(
+                ValidationError::InvalidOption {
+                    field: format!("collaborators.{}", username),
+                    options: ["none", "read", "triage", "write", "maintain"]
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect(),
+                    value: "admin".to_string(),
+                },
+            ));
         }
     }
-
     Ok(())
 }
 
-/// Merges a config-derived access map with request-supplied overrides, enforcing
-/// three protection rules:
+/// Rejects organization-only request settings for a repository owned by a
+/// personal account.
 ///
-/// 1. **Locked entries** — identifiers in `locked` cannot be altered by any request entry.
-///    The config value is preserved and a warning is logged.
-/// 2. **No demotion** — request entries that would *reduce* the access level of a
-///    config-established identifier are silently skipped (with a warning).
-/// 3. **Max-level ceiling** — if `max_level_str` is `Some`, request entries that exceed
-///    that ceiling are capped at the ceiling value (with a warning).
+/// Personal accounts have no teams and cannot create internal repositories,
+/// so requests that ask for either cannot be satisfied.
 ///
-/// # Arguments
+/// # Errors
 ///
-/// * `config_entries`  – Config-derived identifiers (`HashMap<String, String>`).
-/// * `locked`          – Set of identifiers that must not be changed by requests.
-/// * `max_level_str`   – Optional ceiling level string; entries exceeding it are capped.
-/// * `request_entries` – `(&str, AccessLevel)` pairs from the creation request.
-/// * `kind`            – Human-readable label used in warning messages (`"team"` or
-///   `"collaborator"`).
+/// Returns `ValidationError::InvalidFormat` naming the first organization-only
+/// field that is set.
+fn validate_owner_type(request: &RepositoryCreationRequest) -> RepoRollerResult<()> {
+    if request.owner_type != OwnerType::User {
+        return Ok(());
+    }
+
+    let (field, reason) = if !request.teams.is_empty() {
+        (
+            "teams",
+            "teams cannot be granted access to a personal repository",
+        )
+    } else if !request.owning_teams.is_empty() {
+        (
+            "owning_teams",
+            "a personal repository cannot be owned by teams",
+        )
+    } else if request.visibility == Some(visibility::RepositoryVisibility::Internal) {
+        (
+            "visibility",
+            "internal visibility is only available to organizations",
+        )
+    } else {
+        return Ok(());
+    };
+
+    warn!(
+        "Rejecting '{}' for repository owned by user '{}'",
+        field, request.owner
+    );
+    Err(RepoRollerError::Validation(
+        ValidationError::InvalidFormat {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        },
+    ))
+}
+
+/// Checks the source of a copy-from-repository request.
 ///
-/// # Returns
+/// The source must name a repository as `owner/name`. A full-history copy
+/// mirrors the source's branches as they are, so it cannot also move the
+/// content to a review branch or create a signed initial commit.
 ///
-/// A `HashMap<String, AccessLevel>` with the fully resolved access map.
-pub fn merge_access_map_with_policy(
-    config_entries: &std::collections::HashMap<String, String>,
-    locked: &std::collections::HashSet<String>,
-    max_level_str: Option<&str>,
-    request_entries: &[(&str, crate::permissions::AccessLevel)],
-    kind: &str,
-) -> std::collections::HashMap<String, crate::permissions::AccessLevel> {
-    // Parse and collect config entries; skip entries with unrecognised level strings.
-    let mut map: std::collections::HashMap<String, crate::permissions::AccessLevel> =
-        config_entries
-            .iter()
-            .filter_map(|(id, level_str)| {
-                crate::permissions::AccessLevel::try_from(level_str.as_str())
-                    .map(|level| (id.clone(), level))
-                    .map_err(|e| {
-                        warn!(
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` naming the offending field.
+fn validate_copy_source(request: &RepositoryCreationRequest) -> RepoRollerResult<()> {
+    let ContentStrategy::CopyFromRepository {
+        source,
+        copy_full_history,
+    } = &request.content_strategy
+    else {
+        return Ok(());
+    };
+
+    let valid_source = source.split_once('/').is_some_and(|(owner, name)| {
+        OrganizationName::new(owner).is_ok() && RepositoryName::new(name).is_ok()
+    });
+    let (field, reason) = if !valid_source {
+        (
+            "content_strategy.source",
+            format!("'{}' must name a repository as owner/name", source),
+        )
+    } else if *copy_full_history && request.review_branch.is_some() {
+        (
+            "review_branch",
+            "cannot be combined with copy_full_history".to_string(),
+        )
+    } else if *copy_full_history && request.signed_initial_commit == Some(true) {
+        (
+            "signed_initial_commit",
+            "cannot be combined with copy_full_history".to_string(),
+        )
+    } else {
+        return Ok(());
+    };
+
+    Err(RepoRollerError::Validation(
+        ValidationError::InvalidFormat {
+            field: field.to_string(),
+            reason,
+        },
+    ))
+}
+
+/// Replaces the requested review branch with a valid git branch name.
+///
+/// The branch comes from user input, so it may contain characters git rejects
+/// (see [`sanitize_ref_name`]). A changed name is logged.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` for the `review_branch` field when
+/// no valid branch name can be derived from it.
+fn sanitize_review_branch(request: &mut RepositoryCreationRequest) -> RepoRollerResult<()> {
+    let Some(review_branch) = request.review_branch.as_deref() else {
+        return Ok(());
+    };
+    let sanitized = sanitize_ref_name(review_branch).ok_or_else(|| {
+        RepoRollerError::Validation(ValidationError::InvalidFormat {
+            field: "review_branch".to_string(),
+            reason: format!("'{}' is not a valid branch name", review_branch),
+        })
+    })?;
+
+    if sanitized != review_branch {
+        warn!(
+            "Review branch '{}' is not a valid git branch name, using '{}' instead",
+            review_branch, sanitized
+        );
+        request.review_branch = Some(sanitized);
+    }
+    Ok(())
+}
+
+/// Checks that the requested review branch can be used.
+///
+/// The review branch must differ from the default branch. Signed initial
+/// commits are created as a single root commit through the GitHub API, which
+/// cannot give the review branch the shared history a pull request needs, so
+/// the two options are mutually exclusive.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` for the `review_branch` field when
+/// the branch cannot be used.
+fn validate_review_branch(
+    review_branch: &str,
+    default_branch: &str,
+    use_api_commit: bool,
+) -> RepoRollerResult<()> {
+    let reason = if review_branch.trim().is_empty() {
+        "must not be empty".to_string()
+    } else if review_branch == default_branch {
+        format!("must differ from the default branch '{}'", default_branch)
+    } else if use_api_commit {
+        "cannot be combined with signed initial commits".to_string()
+    } else {
+        return Ok(());
+    };
+
+    Err(RepoRollerError::Validation(
+        ValidationError::InvalidFormat {
+            field: "review_branch".to_string(),
+            reason,
+        },
+    ))
+}
+
+/// Checks that the default branch set by `branch_protection.default_branch`
+/// is a valid git branch name.
+///
+/// The branch is applied after the repository was created, so an invalid
+/// name is rejected up front rather than failing part-way through.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFormat` for the
+/// `branch_protection.default_branch` field when the name is not valid.
+fn validate_configured_default_branch(
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<()> {
+    let Some(default_branch) = merged_config.branch_protection.default_branch.as_ref() else {
+        return Ok(());
+    };
+    if is_valid_ref_name(&default_branch.value) {
+        return Ok(());
+    }
+
+    let reason = match sanitize_ref_name(&default_branch.value) {
+        Some(suggestion) => format!(
+            "'{}' is not a valid branch name; use '{}' instead",
+            default_branch.value, suggestion
+        ),
+        None => format!("'{}' is not a valid branch name", default_branch.value),
+    };
+    Err(RepoRollerError::Validation(
+        ValidationError::InvalidFormat {
+            field: "branch_protection.default_branch".to_string(),
+            reason,
+        },
+    ))
+}
+
+/// Builds the title and body of the pull request that proposes the initial
+/// content.
+fn review_pull_request_content(
+    request: &RepositoryCreationRequest,
+    default_branch: &str,
+) -> (String, String) {
+    let source = match &request.template {
+        Some(template) => format!("from the `{}` template", template.as_ref()),
+        None => "by RepoRoller".to_string(),
+    };
+    let title = "Initial repository content".to_string();
+    let body = format!(
+        "This pull request adds the initial content of `{}/{}`, generated {}.\n\n\
+         Review and merge it to land the scaffold on `{}`.",
+        request.owner.as_ref(),
+        request.name.as_ref(),
+        source,
+        default_branch
+    );
+    (title, body)
+}
+
+/// Opens the pull request that proposes the content on the review branch for
+/// merging into the default branch.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if the pull request cannot be created.
+async fn open_review_pull_request(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    review_branch: &str,
+    default_branch: &str,
+) -> RepoRollerResult<()> {
+    let (title, body) = review_pull_request_content(request, default_branch);
+    let pull_request = repo_client
+        .create_pull_request(
+            request.owner.as_ref(),
+            request.name.as_ref(),
+            review_branch,
+            default_branch,
+            &title,
+            &body,
+        )
+        .await
+        .map_err(|e| {
+            error!("Failed to open pull request for initial content: {}", e);
+            RepoRollerError::GitHub(GitHubError::NetworkError {
+                reason: format!("Failed to open pull request for initial content: {}", e),
+            })
+        })?;
+    info!(
+        "Opened pull request #{} for the initial content: {}",
+        pull_request.number, pull_request.html_url
+    );
+    Ok(())
+}
+
+/// Fails fast when a repository with the requested name already exists.
+///
+/// Runs before configuration resolution and template fetching so that a name
+/// clash is reported before any expensive work is done. If availability cannot
+/// be determined the check is skipped with a warning; GitHub still rejects a
+/// duplicate name when the repository is created.
+///
+/// # Errors
+///
+/// Returns `ValidationError::NameTaken` if the repository already exists.
+async fn ensure_repository_name_available(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+) -> RepoRollerResult<()> {
+    match repo_client
+        .repository_exists(request.owner.as_ref(), request.name.as_ref())
+        .await
+    {
+        Ok(false) => Ok(()),
+        Ok(true) => {
+            warn!(
+                "Repository '{}/{}' already exists",
+                request.owner, request.name
+            );
+            Err(RepoRollerError::Validation(ValidationError::NameTaken {
+                org: request.owner.as_ref().to_string(),
+                name: request.name.as_ref().to_string(),
+            }))
+        }
+        Err(e) => {
+            warn!(
+                "Could not check availability of repository '{}/{}': {}. Continuing.",
+                request.owner, request.name, e
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Applies the resolved topics to a newly created repository.
+///
+/// Does nothing when there are no topics. Failures are logged and ignored: the
+/// repository already exists at this point and topics can be added later.
+async fn apply_repository_topics(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    topics: &[String],
+) {
+    if topics.is_empty() {
+        return;
+    }
+
+    info!("Applying repository topics: {:?}", topics);
+    if let Err(e) = repo_client
+        .replace_topics(request.owner.as_ref(), request.name.as_ref(), topics)
+        .await
+    {
+        warn!(
+            "Failed to apply topics to repository '{}/{}': {}. Continuing.",
+            request.owner, request.name, e
+        );
+    }
+}
+
+/// Verifies the created repository against its merged configuration.
+///
+/// The repository already exists at this point, so a failure to read it back
+/// is logged and reported as `None` rather than failing the creation.
+async fn verify_created_repository(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    merged_config: &config_manager::MergedConfiguration,
+) -> Option<VerificationReport> {
+    match verify_repository_configuration(
+        repo_client,
+        request.owner.as_ref(),
+        request.name.as_ref(),
+        merged_config,
+    )
+    .await
+    {
+        Ok(report) => Some(report),
+        Err(e) => {
+            warn!(
+                "Could not verify configuration of repository '{}/{}': {}",
+                request.owner, request.name, e
+            );
+            None
+        }
+    }
+}
+
+/// Returns where the local content of a failed creation is kept.
+///
+/// The path is unique per creation attempt:
+/// `<temp dir>/repo-roller-failed/<owner>-<name>-<correlation id>`.
+fn failed_workdir_path(
+    request: &RepositoryCreationRequest,
+    correlation_id: &str,
+) -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join("repo-roller-failed")
+        .join(format!(
+            "{}-{}-{}",
+            request.owner, request.name, correlation_id
+        ))
+}
+
+/// Copies the local repository of a failed creation, including `.git`, to
+/// `destination` and logs where it was kept.
+///
+/// The temporary directory itself is still removed when it is dropped. A
+/// failed copy is only logged, so that the creation's own error is reported.
+fn keep_failed_workdir(local_repo_path: &TempDir, destination: &std::path::Path) {
+    match copy_directory(local_repo_path.path(), destination) {
+        Ok(()) => error!(
+            "Creation failed; kept the local repository at '{}'",
+            destination.display()
+        ),
+        Err(e) => warn!(
+            "Creation failed and the local repository could not be kept at '{}': {}",
+            destination.display(),
+            e
+        ),
+    }
+}
+
+/// Recursively copies the contents of `source` into `destination`.
+fn copy_directory(source: &std::path::Path, destination: &std::path::Path) -> std::io::Result<()> {
+    for entry in walkdir::WalkDir::new(source) {
+        let entry = entry?;
+        let relative_path = entry
+            .path()
+            .strip_prefix(source)
+            .map_err(std::io::Error::other)?;
+        let target = destination.join(relative_path);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Collects the files of the prepared local repository for a commit created
+/// through the GitHub API, excluding the `.git` directory.
+///
+/// Paths use `/` separators and are sorted so the resulting tree is
+/// deterministic.
+///
+/// # Errors
+///
+/// Returns `SystemError::FileSystem` if the directory cannot be read.
+fn collect_commit_files(local_repo_path: &TempDir) -> RepoRollerResult<Vec<CommitFile>> {
+    let root = local_repo_path.path();
+    let mut files = Vec::new();
+
+    for entry in walkdir::WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+    {
+        let entry = entry.map_err(|e| SystemError::FileSystem {
+            operation: "read directory entry".to_string(),
+            reason: e.to_string(),
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative_path =
+            entry
+                .path()
+                .strip_prefix(root)
+                .map_err(|e| SystemError::FileSystem {
+                    operation: "get relative path".to_string(),
+                    reason: e.to_string(),
+                })?;
+        let path = relative_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let content = std::fs::read(entry.path()).map_err(|e| SystemError::FileSystem {
+            operation: "read file".to_string(),
+            reason: format!("{:?}: {}", entry.path(), e),
+        })?;
+
+        #[cfg(unix)]
+        let executable = {
+            use std::os::unix::fs::PermissionsExt;
+            entry
+                .metadata()
+                .map(|m| m.permissions().mode() & 0o111 != 0)
+                .unwrap_or(false)
+        };
+        #[cfg(not(unix))]
+        let executable = false;
+
+        files.push(CommitFile {
+            path,
+            content,
+            executable,
+        });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+/// Publishes the initial commit of a newly created repository.
+///
+/// When `use_api_commit` is set the commit is created through the GitHub API,
+/// which signs it so that it shows as verified; otherwise the local commit is
+/// pushed with git. Either way the default branch ends up with the same tree.
+///
+/// When the request names a review branch, the default branch is pushed first
+/// and the review branch after it.
+///
+/// Set `force` when GitHub already committed to the repository while creating
+/// it (a license, `.gitignore` or README); the local history, which contains
+/// those files, then replaces the remote commit rather than building on it.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if the API commit fails, or the push
+/// error from [`push_repository_to_github_with_retry`].
+#[allow(clippy::too_many_arguments)]
+async fn publish_initial_commit(
+    repo_client: &dyn RepositoryClient,
+    git: &dyn GitOperations,
+    request: &RepositoryCreationRequest,
+    local_repo_path: &TempDir,
+    repo_url: url::Url,
+    default_branch: &str,
+    installation_token: &str,
+    use_api_commit: bool,
+    force: bool,
+) -> RepoRollerResult<()> {
+    if !use_api_commit {
+        // Uses retry logic to handle GitHub's eventual consistency between the
+        // REST API and git servers (transient 404/503 immediately after creation).
+        for branch in std::iter::once(default_branch).chain(request.review_branch.as_deref()) {
+            push_repository_to_github_with_retry(
+                git,
+                local_repo_path,
+                repo_url.clone(),
+                branch,
+                installation_token,
+                force,
+            )
+            .await?;
+        }
+        return Ok(());
+    }
+
+    let files = collect_commit_files(local_repo_path)?;
+    info!(
+        "Creating signed initial commit through the GitHub API ({} files)",
+        files.len()
+    );
+    let sha = repo_client
+        .create_initial_commit(
+            request.owner.as_ref(),
+            request.name.as_ref(),
+            default_branch,
+            "Initial commit",
+            &files,
+        )
+        .await
+        .map_err(|e| {
+            error!("Failed to create initial commit through the API: {}", e);
+            RepoRollerError::GitHub(GitHubError::NetworkError {
+                reason: format!("Failed to create initial commit: {}", e),
+            })
+        })?;
+    info!("Initial commit {} created on '{}'", sha, default_branch);
+    Ok(())
+}
+
+/// Applies the merged configuration and repository permissions after the
+/// repository has been created and populated on GitHub.
+///
+/// Permission errors and Actions variable/secret failures are treated as
+/// non-fatal warnings: configuration will still be applied and the repository
+/// is fully usable.
+///
+/// # Errors
+///
+/// Returns errors from configuration application; permission errors are logged
+/// and suppressed.
+async fn apply_post_creation_settings(
+    installation_repo_client: &std::sync::Arc<dyn RepositoryClient>,
+    request: &RepositoryCreationRequest,
+    merged_config: &config_manager::MergedConfiguration,
+    template: Option<&config_manager::TemplateConfig>,
+    default_branch: &str,
+    requestor: &str,
+    secret_resolver: &dyn event_secrets::SecretResolver,
+) -> RepoRollerResult<()> {
+    configuration::apply_repository_configuration(
+        installation_repo_client,
+        request.owner.as_ref(),
+        request.name.as_ref(),
+        default_branch,
+        merged_config,
+    )
+    .await?;
+
+    // Push settings and required code owner reviews are branch protection
+    // rules on the default branch. They are applied after the initial content
+    // has been pushed.
+    configuration::apply_branch_push_protection(
+        installation_repo_client,
+        request.owner.as_ref(),
+        request.name.as_ref(),
+        default_branch,
+        merged_config,
+    )
+    .await?;
+
+    // Actions variables and secrets are non-fatal in the same way as labels
+    // and webhooks; failures are logged inside the helper.
+    if !merged_config.actions_variables.is_empty() || !merged_config.actions_secrets.is_empty() {
+        configuration::apply_actions_configuration(
+            installation_repo_client,
+            request.owner.as_ref(),
+            request.name.as_ref(),
+            merged_config,
+            secret_resolver,
+        )
+        .await;
+    }
+
+    // Permission errors are non-fatal: the repository already exists and is
+    // usable; log a warning rather than failing the entire creation.
+    {
+        let permission_manager = crate::permission_manager::PermissionManager::new(
+            installation_repo_client.clone(),
+            crate::policy_engine::PolicyEngine::new(),
+        );
+        let hierarchy = crate::permission_workflow::build_permission_hierarchy(template);
+        let perm_request = crate::permission_workflow::build_permission_request(
+            &request.owner,
+            &request.name,
+            requestor,
+        );
+        // NOTE: `hierarchy` carries empty `organization_policies` and
+        // `user_requested_permissions` because org-level policies are not yet
+        // threaded through `MergedConfiguration`. The `PolicyEngine::evaluate_permission_request`
+        // call below therefore evaluates an empty request against an empty policy and
+        // is effectively a no-op today. The real permission-merge enforcement lives in
+        // `merge_access_map_with_policy` (called immediately below). The PolicyEngine
+        // path is future infrastructure intended to replace that merge once org policies
+        // are properly wired up. Until then, do not add policy logic inside the engine.
+        // Teams and collaborators are merged from two sources:
+        //   1. Org/template config (merged_config.teams / .collaborators) – baseline
+        //   2. Explicit request fields (request.teams / .collaborators)    – highest precedence
+        //
+        // Policy rules applied during merge (request overrides are subject to):
+        //   • Locked entries (merged_config.locked_teams) cannot be altered by the request.
+        //   • Config-established entries cannot be demoted (only upgraded) by the request.
+        //   • No request entry may exceed merged_config.max_team_access_level (capped).
+        let teams: std::collections::HashMap<String, crate::permissions::AccessLevel> =
+            merge_access_map_with_policy(
+                &merged_config.teams,
+                &merged_config.locked_teams,
+                merged_config.max_team_access_level.as_deref(),
+                request
+                    .teams
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), *v))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                "team",
+            );
+        let collaborators: std::collections::HashMap<String, crate::permissions::AccessLevel> =
+            merge_access_map_with_policy(
+                &merged_config.collaborators,
+                &merged_config.locked_collaborators,
+                merged_config.max_collaborator_access_level.as_deref(),
+                request
+                    .collaborators
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), *v))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                "collaborator",
+            );
+
+        match permission_manager
+            .apply_repository_permissions(
+                request.owner.as_ref(),
+                request.name.as_ref(),
+                &perm_request,
+                &hierarchy,
+                &teams,
+                &collaborators,
+            )
+            .await
+        {
+            Ok(result) => {
+                info!(
+                    teams_applied = result.teams_applied,
+                    collaborators_applied = result.collaborators_applied,
+                    "Repository permissions applied successfully"
+                );
+            }
+            Err(e) => {
+                warn!(
+                    "Permission application failed (non-fatal); repository created \
+                     but permissions may not be fully configured: {}",
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Merges a config-derived access map with request-supplied overrides, enforcing
+/// three protection rules:
+///
+/// 1. **Locked entries** — identifiers in `locked` cannot be altered by any request entry.
+///    The config value is preserved and a warning is logged.
+/// 2. **No demotion** — request entries that would *reduce* the access level of a
+///    config-established identifier are silently skipped (with a warning).
+/// 3. **Max-level ceiling** — if `max_level_str` is `Some`, request entries that exceed
+///    that ceiling are capped at the ceiling value (with a warning).
+///
+/// # Arguments
+///
+/// * `config_entries`  – Config-derived identifiers (`HashMap<String, String>`).
+/// * `locked`          – Set of identifiers that must not be changed by requests.
+/// * `max_level_str`   – Optional ceiling level string; entries exceeding it are capped.
+/// * `request_entries` – `(&str, AccessLevel)` pairs from the creation request.
+/// * `kind`            – Human-readable label used in warning messages (`"team"` or
+///   `"collaborator"`).
+///
+/// # Returns
+///
+/// A `HashMap<String, AccessLevel>` with the fully resolved access map.
+pub fn merge_access_map_with_policy(
+    config_entries: &std::collections::HashMap<String, String>,
+    locked: &std::collections::HashSet<String>,
+    max_level_str: Option<&str>,
+    request_entries: &[(&str, crate::permissions::AccessLevel)],
+    kind: &str,
+) -> std::collections::HashMap<String, crate::permissions::AccessLevel> {
+    // Parse and collect config entries; skip entries with unrecognised level strings.
+    let mut map: std::collections::HashMap<String, crate::permissions::AccessLevel> =
+        config_entries
+            .iter()
+            .filter_map(|(id, level_str)| {
+                crate::permissions::AccessLevel::try_from(level_str.as_str())
+                    .map(|level| (id.clone(), level))
+                    .map_err(|e| {
+                        warn!(
                             kind,
                             identifier = id.as_str(),
                             level = level_str.as_str(),
@@ -898,130 +1778,585 @@ fn spawn_event_notification(
     tokio::spawn(async move {
         info!(
             repository = %request.name,
-            "Spawning background task for event notifications"
+            "Spawning background task for event notifications"
+        );
+
+        let delivery_results = publish_repository_created(
+            &result_clone,
+            &request,
+            &merged_config,
+            &created_by_str,
+            secret_resolver.as_ref(),
+            metrics.as_ref(),
+        )
+        .await;
+
+        let success_count = delivery_results.iter().filter(|r| r.success).count();
+        let failure_count = delivery_results.len() - success_count;
+
+        if failure_count > 0 {
+            warn!(
+                repository = %request.name,
+                success_count = success_count,
+                failure_count = failure_count,
+                "Event notification delivery completed with failures"
+            );
+        } else if success_count > 0 {
+            info!(
+                repository = %request.name,
+                success_count = success_count,
+                "Event notification delivery completed successfully"
+            );
+        }
+    });
+}
+
+/// Spawns a background task that delivers `RepositoryCreationFailedEvent`
+/// notifications to all configured webhook endpoints.
+///
+/// Uses the same fire-and-forget pattern as [`spawn_event_notification`]; the
+/// creation error is returned to the caller regardless of delivery outcome.
+fn spawn_failure_notification(
+    error: &RepoRollerError,
+    request: RepositoryCreationRequest,
+    merged_config: config_manager::MergedConfiguration,
+    event_context: event_publisher::EventNotificationContext,
+) {
+    // The error is not `Clone`, so the event is built before spawning.
+    let event = RepositoryCreationFailedEvent::from_error_and_request(
+        error,
+        &request,
+        &event_context.created_by,
+        &event_context.correlation_id,
+    );
+    let secret_resolver = event_context.secret_resolver;
+    let metrics = event_context.metrics;
+
+    tokio::spawn(async move {
+        info!(
+            repository = %request.name,
+            correlation_id = %event.correlation_id,
+            "Spawning background task for creation failure notifications"
         );
 
-        let delivery_results = publish_repository_created(
-            &result_clone,
-            &request,
+        let delivery_results = publish_repository_creation_failed(
+            &event,
             &merged_config,
-            &created_by_str,
             secret_resolver.as_ref(),
             metrics.as_ref(),
         )
         .await;
 
-        let success_count = delivery_results.iter().filter(|r| r.success).count();
-        let failure_count = delivery_results.len() - success_count;
-
+        let failure_count = delivery_results.iter().filter(|r| !r.success).count();
         if failure_count > 0 {
             warn!(
                 repository = %request.name,
-                success_count = success_count,
                 failure_count = failure_count,
-                "Event notification delivery completed with failures"
-            );
-        } else if success_count > 0 {
-            info!(
-                repository = %request.name,
-                success_count = success_count,
-                "Event notification delivery completed successfully"
+                "Creation failure notification delivery completed with failures"
             );
         }
     });
 }
 
-/// Initialize and commit local Git repository.
+/// Branch used when the organization's default branch cannot be read.
+const FALLBACK_DEFAULT_BRANCH: &str = "main";
+
+/// The default branch chosen for a new repository.
+#[derive(Debug, Clone, PartialEq)]
+struct DefaultBranchResolution {
+    /// Branch name to create the repository with
+    name: String,
+    /// Set when the organization setting could not be read and the fallback
+    /// branch was used instead
+    fallback_warning: Option<ValidationWarning>,
+}
+
+/// Resolves the default branch for a new repository from the organization setting.
 ///
-/// # Returns
+/// When the organization cannot be read because it is not visible or the
+/// GitHub App lacks permission, the branch falls back to `main` and a warning
+/// is returned so callers can tell that the organization default was not
+/// honored. Other failures are not masked. A setting that is not a valid git
+/// branch name is replaced with a valid one (see [`sanitize_ref_name`]), or
+/// with `main` when none can be derived.
 ///
-/// Returns the default branch name used.
-async fn initialize_git_repository(
-    local_repo_path: &TempDir,
-    installation_repo_client: &GitHubClient,
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` for failures other than a missing
+/// organization or insufficient permissions.
+async fn resolve_default_branch(
+    repo_client: &dyn RepositoryClient,
     organization: &str,
-    allow_empty_commit: bool,
-) -> RepoRollerResult<String> {
+) -> RepoRollerResult<DefaultBranchResolution> {
     info!(
         "Getting organization default branch setting for: {}",
         organization
     );
-    let default_branch = installation_repo_client
+    match repo_client
         .get_organization_default_branch(organization)
         .await
-        .unwrap_or_else(|e| {
+    {
+        Ok(name) => {
+            let sanitized =
+                sanitize_ref_name(&name).unwrap_or_else(|| FALLBACK_DEFAULT_BRANCH.to_string());
+            if sanitized != name {
+                warn!(
+                    "Default branch '{}' of organization '{}' is not a valid git branch name, \
+                     using '{}' instead",
+                    name, organization, sanitized
+                );
+            }
+            Ok(DefaultBranchResolution {
+                name: sanitized,
+                fallback_warning: None,
+            })
+        }
+        Err(e @ (github_client::Error::NotFound | github_client::Error::AuthError(_))) => {
             warn!(
-                "Failed to get default branch for organization '{}': {}. Using 'main' as default.",
+                "Cannot read default branch for organization '{}': {}. Using '{}' instead.",
+                organization, e, FALLBACK_DEFAULT_BRANCH
+            );
+            Ok(DefaultBranchResolution {
+                name: FALLBACK_DEFAULT_BRANCH.to_string(),
+                fallback_warning: Some(ValidationWarning {
+                    field_path: "default_branch".to_string(),
+                    message: format!(
+                        "The default branch of organization '{}' could not be read ({}); \
+                         '{}' was used instead",
+                        organization, e, FALLBACK_DEFAULT_BRANCH
+                    ),
+                    recommendation: Some(
+                        "Grant the GitHub App read access to the organization's settings \
+                         so its default branch is honored"
+                            .to_string(),
+                    ),
+                }),
+            })
+        }
+        Err(e) => {
+            error!(
+                "Failed to get default branch for organization '{}': {}",
                 organization, e
             );
-            "main".to_string()
-        });
+            Err(RepoRollerError::GitHub(GitHubError::NetworkError {
+                reason: format!(
+                    "Failed to get default branch for organization '{}': {}",
+                    organization, e
+                ),
+            }))
+        }
+    }
+}
 
+/// Initialize and commit local Git repository on the given default branch.
+fn initialize_git_repository(
+    git: &dyn GitOperations,
+    local_repo_path: &TempDir,
+    default_branch: &str,
+) -> RepoRollerResult<()> {
     info!("Using default branch: {}", default_branch);
 
     debug!(
         "Initializing local git repository with branch: {}",
         default_branch
     );
-    git::init_local_git_repo(local_repo_path, &default_branch).map_err(|e| {
+    git.init(local_repo_path, default_branch).map_err(|e| {
         error!("Failed to initialize local git repository: {}", e);
         RepoRollerError::System(SystemError::Internal {
             reason: format!("Failed to initialize local git repository: {}", e),
         })
     })?;
 
-    debug!(
-        "Committing initial changes (allow_empty: {})",
-        allow_empty_commit
-    );
-    git::commit_all_changes(local_repo_path, "Initial commit", allow_empty_commit).map_err(
-        |e| {
+    debug!("Committing initial changes");
+    git.commit_all(local_repo_path, "Initial commit", false)
+        .map_err(|e| {
             error!("Failed to commit changes: {}", e);
             RepoRollerError::System(SystemError::Internal {
                 reason: format!("Failed to commit changes: {}", e),
             })
-        },
-    )?;
+        })?;
+
+    Ok(())
+}
+
+/// Files GitHub scaffolds when it creates a repository.
+///
+/// Request values take precedence over the organization defaults in the
+/// merged configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct GitHubScaffolding {
+    /// Create an initial commit with a README
+    auto_init: bool,
+    /// `.gitignore` template name, e.g. `Rust`
+    gitignore_template: Option<String>,
+    /// License key, e.g. `mit`
+    license_template: Option<String>,
+}
 
-    Ok(default_branch)
+impl GitHubScaffolding {
+    fn resolve(
+        request: &RepositoryCreationRequest,
+        merged_config: &config_manager::MergedConfiguration,
+    ) -> Self {
+        Self {
+            auto_init: request.auto_init.unwrap_or(merged_config.auto_init),
+            gitignore_template: request
+                .gitignore_template
+                .clone()
+                .or_else(|| merged_config.gitignore_template.clone()),
+            license_template: request
+                .license_template
+                .clone()
+                .or_else(|| merged_config.license_template.clone()),
+        }
+    }
+
+    /// Returns `true` if GitHub commits to the default branch on creation.
+    fn creates_commit(&self) -> bool {
+        self.auto_init || self.gitignore_template.is_some() || self.license_template.is_some()
+    }
+}
+
+/// Builds the GitHub payload for creating the requested repository.
+///
+/// The visibility is sent through the `visibility` field rather than the
+/// `private` flag so that `Internal` is preserved instead of degrading to
+/// private.
+fn repository_create_payload(
+    request: &RepositoryCreationRequest,
+    merged_config: &config_manager::MergedConfiguration,
+    visibility: visibility::RepositoryVisibility,
+    description: Option<String>,
+) -> RepositoryCreatePayload {
+    let scaffolding = GitHubScaffolding::resolve(request, merged_config);
+    RepositoryCreatePayload {
+        name: request.name.as_ref().to_string(),
+        description,
+        visibility: Some(visibility.as_str().to_string()),
+        has_issues: merged_config.repository.issues.as_ref().map(|v| v.value),
+        has_projects: merged_config.repository.projects.as_ref().map(|v| v.value),
+        has_wiki: merged_config.repository.wiki.as_ref().map(|v| v.value),
+        auto_init: scaffolding.auto_init.then_some(true),
+        gitignore_template: scaffolding.gitignore_template,
+        license_template: scaffolding.license_template,
+        ..Default::default()
+    }
 }
 
 /// Create repository on GitHub with merged configuration settings.
 ///
+/// Organization-owned repositories are created in `request.owner`; repositories
+/// owned by a personal account are created for the account the client is
+/// authenticated as.
+///
 /// # Returns
 ///
 /// Returns the created GitHub repository.
 async fn create_github_repository(
     request: &RepositoryCreationRequest,
     merged_config: &config_manager::MergedConfiguration,
-    installation_repo_client: &GitHubClient,
+    repo_client: &dyn RepositoryClient,
+    visibility: visibility::RepositoryVisibility,
+    description: Option<String>,
+) -> RepoRollerResult<github_client::Repository> {
+    let payload = repository_create_payload(request, merged_config, visibility, description);
+
+    info!(
+        "Creating GitHub repository: name='{}', owner_type={}, visibility={:?}",
+        request.name,
+        request.owner_type.as_str(),
+        visibility
+    );
+    let created = match request.owner_type {
+        OwnerType::Organization => {
+            repo_client
+                .create_org_repository(request.owner.as_ref(), &payload)
+                .await
+        }
+        OwnerType::User => repo_client.create_user_repository(&payload).await,
+    };
+    let repo = created.map_err(|e| {
+        error!("Failed to create GitHub repository: {}", e);
+        RepoRollerError::GitHub(GitHubError::NetworkError {
+            reason: format!("Failed to create repository: {}", e),
+        })
+    })?;
+
+    info!(
+        "GitHub repository created successfully: url='{}'",
+        repo.url()
+    );
+    Ok(repo)
+}
+
+/// Backoff used while waiting for a new repository to become readable.
+const REPOSITORY_AVAILABILITY_RETRY: github_client::RetryPolicy = github_client::RetryPolicy {
+    max_retries: 5,
+    initial_backoff: std::time::Duration::from_millis(250),
+    max_backoff: std::time::Duration::from_secs(4),
+};
+
+/// Waits until a newly created repository can be read through the API.
+///
+/// GitHub sometimes returns 404 for a repository for a second or two after
+/// creating it, which would fail the calls that follow. Every creation path
+/// waits right after creating the repository, before its content is
+/// published or its configuration applied. Only 404s
+/// are retried; any other error is logged and left to the following calls
+/// to report.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if the repository is still not found
+/// after `policy.max_retries` retries.
+async fn wait_for_repository_availability(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    policy: &github_client::RetryPolicy,
+) -> RepoRollerResult<()> {
+    let owner = request.owner.as_ref();
+    let name = request.name.as_ref();
+
+    for retry in 0..=policy.max_retries {
+        if retry > 0 {
+            let delay = policy.backoff_for(retry - 1);
+            warn!(
+                "Repository '{}/{}' not yet available, retrying in {}ms (retry {}/{})",
+                owner,
+                name,
+                delay.as_millis(),
+                retry,
+                policy.max_retries
+            );
+            tokio::time::sleep(delay).await;
+        }
+
+        match repo_client.get_repository(owner, name).await {
+            Ok(_) => return Ok(()),
+            Err(github_client::Error::NotFound) => continue,
+            Err(e) => {
+                warn!(
+                    "Could not confirm repository '{}/{}' is available: {}",
+                    owner, name, e
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    error!(
+        "Repository '{}/{}' still not found after creation",
+        owner, name
+    );
+    Err(RepoRollerError::GitHub(GitHubError::NetworkError {
+        reason: format!(
+            "Repository '{}/{}' was created but is still not available after {} retries",
+            owner, name, policy.max_retries
+        ),
+    }))
+}
+
+/// Copies the files GitHub committed while creating the repository into the
+/// local content, so that the initial commit keeps them when it replaces
+/// GitHub's commit.
+///
+/// `LICENSE` and `.gitignore` files rendered from the template are
+/// overwritten, since they were explicitly asked for. GitHub's README is only
+/// used when the local content has none.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if a file cannot be read from the new
+/// repository, and `SystemError::FileSystem` if it cannot be written.
+async fn write_scaffolded_files(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    scaffolding: &GitHubScaffolding,
+    local_repo_path: &TempDir,
+) -> RepoRollerResult<()> {
+    let mut paths = Vec::new();
+    if scaffolding.license_template.is_some() {
+        paths.push("LICENSE");
+    }
+    if scaffolding.gitignore_template.is_some() {
+        paths.push(".gitignore");
+    }
+    if scaffolding.auto_init && !local_repo_path.path().join("README.md").exists() {
+        paths.push("README.md");
+    }
+
+    for path in paths {
+        let content = repo_client
+            .get_file_content(request.owner.as_ref(), request.name.as_ref(), path)
+            .await
+            .map_err(|e| {
+                error!("Failed to read the {} created by GitHub: {}", path, e);
+                RepoRollerError::GitHub(GitHubError::NetworkError {
+                    reason: format!("Failed to read {}: {}", path, e),
+                })
+            })?;
+
+        std::fs::write(local_repo_path.path().join(path), content).map_err(|e| {
+            RepoRollerError::System(SystemError::FileSystem {
+                operation: format!("write {}", path),
+                reason: e.to_string(),
+            })
+        })?;
+        debug!(
+            "Added the {} created by GitHub to the initial content",
+            path
+        );
+    }
+    Ok(())
+}
+
+/// How the initial content of a new repository is produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentCreationPath {
+    /// GitHub generates the repository from the template repository natively.
+    NativeTemplate,
+    /// Content is generated locally, committed, and pushed to a new repository.
+    Render,
+    /// Every branch and tag of the source repository is mirrored to a new
+    /// repository.
+    Mirror,
+}
+
+/// Returns whether the request can be satisfied without rendering template
+/// content locally.
+///
+/// This holds when the request uses the template content strategy, neither
+/// the template nor the request defines variables, the template has no
+/// `[templating]` section and no base template (GitHub would only copy the
+/// top layer), no template ref is pinned (GitHub always copies the
+/// template's default branch), no license is requested, and the visibility can be
+/// expressed by GitHub's generate API (which cannot create internal
+/// repositories or add a license). Organization scaffolding defaults are
+/// checked by the caller.
+fn can_generate_natively(
+    request: &RepositoryCreationRequest,
+    template: Option<&config_manager::TemplateConfig>,
+    visibility: visibility::RepositoryVisibility,
+) -> bool {
+    let Some(template) = template else {
+        return false;
+    };
+
+    matches!(request.content_strategy, ContentStrategy::Template)
+        && request.variables.is_empty()
+        && template.variables.as_ref().is_none_or(|v| v.is_empty())
+        && template.templating.is_none()
+        && template.base_template.is_none()
+        && request.template_ref.is_none()
+        && request.license_template.is_none()
+        && visibility != visibility::RepositoryVisibility::Internal
+}
+
+/// Chooses between native template generation and the render path.
+///
+/// The native path is used only when [`can_generate_natively`] holds and the
+/// template repository is marked as a template on GitHub. If the template
+/// repository cannot be read, the render path is used.
+async fn choose_content_creation_path(
+    repo_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    template: Option<&config_manager::TemplateConfig>,
+    visibility: visibility::RepositoryVisibility,
+) -> ContentCreationPath {
+    let Some(template_name) = request.template.as_ref() else {
+        return ContentCreationPath::Render;
+    };
+    if !can_generate_natively(request, template, visibility) {
+        return ContentCreationPath::Render;
+    }
+
+    match repo_client
+        .get_repository_settings(request.owner.as_ref(), template_name.as_ref())
+        .await
+    {
+        Ok(repo) if repo.is_template() == Some(true) => {
+            info!(
+                "Template repository '{}' is a GitHub template and needs no rendering; using native generation",
+                template_name
+            );
+            ContentCreationPath::NativeTemplate
+        }
+        Ok(_) => ContentCreationPath::Render,
+        Err(e) => {
+            warn!(
+                "Could not read template repository '{}' ({}); using the render path",
+                template_name, e
+            );
+            ContentCreationPath::Render
+        }
+    }
+}
+
+/// Create the repository on GitHub by generating it from the template
+/// repository, then apply the repository feature settings the generate API
+/// does not accept.
+///
+/// # Returns
+///
+/// Returns the generated repository.
+async fn generate_github_repository(
+    request: &RepositoryCreationRequest,
+    merged_config: &config_manager::MergedConfiguration,
+    repo_client: &dyn RepositoryClient,
     visibility: visibility::RepositoryVisibility,
+    description: Option<String>,
 ) -> RepoRollerResult<github_client::Repository> {
-    let payload = RepositoryCreatePayload {
+    let template_name = request.template.as_ref().ok_or_else(|| {
+        RepoRollerError::System(SystemError::Internal {
+            reason: "Native template generation requires a template".to_string(),
+        })
+    })?;
+
+    let payload = github_client::RepositoryGeneratePayload {
+        owner: request.owner.as_ref().to_string(),
         name: request.name.as_ref().to_string(),
-        private: Some(visibility.is_private()),
-        has_issues: merged_config.repository.issues.as_ref().map(|v| v.value),
-        has_projects: merged_config.repository.projects.as_ref().map(|v| v.value),
-        has_wiki: merged_config.repository.wiki.as_ref().map(|v| v.value),
-        ..Default::default()
+        description,
+        include_all_branches: None,
+        private: Some(visibility == visibility::RepositoryVisibility::Private),
     };
 
     info!(
-        "Creating GitHub repository: name='{}', visibility={:?}",
-        request.name, visibility
+        "Generating GitHub repository '{}' from template '{}', visibility={:?}",
+        request.name, template_name, visibility
     );
-    let repo = installation_repo_client
-        .create_org_repository(request.owner.as_ref(), &payload)
+    let repo = repo_client
+        .generate_from_template(request.owner.as_ref(), template_name.as_ref(), &payload)
         .await
         .map_err(|e| {
-            error!("Failed to create GitHub repository: {}", e);
+            error!("Failed to generate GitHub repository from template: {}", e);
             RepoRollerError::GitHub(GitHubError::NetworkError {
-                reason: format!("Failed to create repository: {}", e),
+                reason: format!("Failed to generate repository from template: {}", e),
             })
         })?;
 
+    let settings = github_client::RepositorySettingsUpdate {
+        has_issues: merged_config.repository.issues.as_ref().map(|v| v.value),
+        has_projects: merged_config.repository.projects.as_ref().map(|v| v.value),
+        has_wiki: merged_config.repository.wiki.as_ref().map(|v| v.value),
+        ..Default::default()
+    };
+    if settings.has_issues.is_some()
+        || settings.has_projects.is_some()
+        || settings.has_wiki.is_some()
+    {
+        repo_client
+            .update_repository_settings(request.owner.as_ref(), request.name.as_ref(), &settings)
+            .await
+            .map_err(|e| {
+                error!("Failed to update generated repository settings: {}", e);
+                RepoRollerError::GitHub(GitHubError::NetworkError {
+                    reason: format!("Failed to update repository settings: {}", e),
+                })
+            })?;
+    }
+
     info!(
-        "GitHub repository created successfully: url='{}'",
+        "GitHub repository generated successfully: url='{}'",
         repo.url()
     );
     Ok(repo)
@@ -1042,7 +2377,8 @@ async fn create_github_repository(
 /// * `request` - Type-safe repository creation request with branded types
 /// * `metadata_provider` - Provider for loading template configurations from GitHub
 /// * `auth_service` - Authentication service for GitHub operations
-/// * `metadata_repository_name` - Name of the repository containing organization configuration (e.g., ".reporoller")
+/// * `metadata_repository_name` - Name of the repository containing organization configuration (e.g., ".reporoller"); replaced by [`CreationDependencies::with_metadata_provider_config`]
+/// * `dependencies` - Git and GitHub services used for the creation; [`CreationDependencies::default`] uses `git2` and `https://api.github.com`
 ///
 /// # Returns
 ///
@@ -1057,6 +2393,10 @@ async fn create_github_repository(
 /// - `GitHubError` - GitHub API operations failed
 /// - `SystemError` - Git operations or file system errors
 ///
+/// Every failure after the organization configuration is resolved, including
+/// a taken repository name or a missing template, is also published as a
+/// `repository.creation_failed` event to the configured notification endpoints.
+///
 /// # Example
 ///
 /// ```no_run
@@ -1105,112 +2445,444 @@ async fn create_github_repository(
 ///     visibility_policy_provider,
 ///     environment_detector,
 ///     event_context,
+///     repo_roller_core::CreationDependencies::default(),
 /// ).await?;
 /// println!("Created repository: {}", result.repository_url);
 /// # Ok(())
 /// # }
 /// ```
 pub async fn create_repository(
-    request: RepositoryCreationRequest,
+    mut request: RepositoryCreationRequest,
     metadata_provider: &dyn config_manager::MetadataRepositoryProvider,
     auth_service: &dyn auth_handler::UserAuthenticationService,
     metadata_repository_name: &str,
     visibility_policy_provider: std::sync::Arc<dyn visibility::VisibilityPolicyProvider>,
     environment_detector: std::sync::Arc<dyn visibility::GitHubEnvironmentDetector>,
     event_context: event_publisher::EventNotificationContext,
+    dependencies: CreationDependencies,
 ) -> RepoRollerResult<RepositoryCreationResult> {
     info!(
         "Starting repository creation: name='{}', owner='{}', template={:?}, strategy={:?}",
         request.name, request.owner, request.template, request.content_strategy
     );
 
+    // Step 0: Reject collaborator grants user input may not make,
+    // organization-only settings for personal repositories, and invalid copy
+    // sources. Make the review branch a valid git branch name.
+    validate_requested_collaborators(&request)?;
+    validate_owner_type(&request)?;
+    validate_copy_source(&request)?;
+    sanitize_review_branch(&mut request)?;
+
     // Steps 1–2: Authenticate and create GitHub clients.
-    let clients = setup_github_clients(auth_service, request.owner.as_ref()).await?;
+    let clients = setup_github_clients(auth_service, request.owner.as_ref(), &dependencies).await?;
+    event_context.record_step(CreationStep::AuthDone, &request);
 
-    // Steps 3–4: Resolve merged configuration and load the template config.
-    let (merged_config, template) = load_creation_config(
+    // Step 3: Resolve the merged configuration. It defines the notification
+    // endpoints, so every later failure can be reported.
+    let mut merged_config = resolve_creation_config(
         &clients.installation_token,
         &request,
-        metadata_provider,
         metadata_repository_name,
+        &dependencies,
     )
     .await?;
 
-    // Step 4b: Validate the repository name against configured naming rules.
-    RepositoryNamingValidator::new()
-        .validate(request.name.as_str(), &merged_config.naming_rules)
-        .map_err(RepoRollerError::Validation)?;
+    // Personal accounts cannot use organization-only settings.
+    if request.owner_type == OwnerType::User {
+        configuration::remove_organization_only_settings(&mut merged_config);
+    }
 
-    // Step 5: Resolve repository visibility.
-    let visibility_decision = resolve_repository_visibility(
-        &request,
-        template.as_ref(),
-        visibility_policy_provider,
-        environment_detector,
-    )
-    .await?;
+    // Steps 3a–12 run in one block so a failure can be reported to the
+    // notification endpoints the merged configuration defines.
+    let outcome: RepoRollerResult<RepositoryCreationResult> = async {
+        // Step 3a: Fail fast if the repository name is already taken.
+        ensure_repository_name_available(&clients.installation_repo_client, &request).await?;
+
+        // Step 4: Load the template config.
+        let template = load_template_config(&request, metadata_provider).await?;
+        event_context.record_step(CreationStep::TemplateFetched, &request);
+
+        // Step 4a: Collect non-blocking configuration and template warnings
+        // for the caller.
+        let mut warnings = configuration::collect_configuration_warnings(&merged_config).await;
+        if let Some(template) = template.as_ref() {
+            warnings.extend(template_processing::collect_template_warnings(
+                template,
+                &request.variables,
+            ));
+        }
 
-    // Step 6: Generate local repository content.
-    let local_repo_path = generate_repository_content(
-        &request,
-        template.as_ref(),
-        &merged_config,
-        &clients.template_fetcher,
-    )
-    .await?;
+        // Step 4b: Validate the repository name against configured naming
+        // rules and the configured default branch name.
+        RepositoryNamingValidator::new()
+            .validate(request.name.as_str(), &merged_config.naming_rules)
+            .map_err(RepoRollerError::Validation)?;
+        validate_configured_default_branch(&merged_config)?;
+
+        // Step 4c: When requested, make sure every referenced team and
+        // required GitHub App exists before anything is written.
+        if request.validate_references && request.owner_type == OwnerType::Organization {
+            references::ensure_references_exist(
+                &clients.installation_repo_client,
+                &request,
+                &merged_config,
+            )
+            .await?;
+        }
 
-    // Step 7: Initialize the local Git repository and create the initial commit.
-    let allow_empty_commit = matches!(request.content_strategy, ContentStrategy::Empty);
-    let default_branch = initialize_git_repository(
-        &local_repo_path,
-        &clients.installation_repo_client,
-        request.owner.as_ref(),
-        allow_empty_commit,
-    )
-    .await?;
+        // Step 5: Resolve repository visibility. Personal accounts have no
+        // organization plan to inspect.
+        let environment_detector: std::sync::Arc<dyn visibility::GitHubEnvironmentDetector> =
+            match request.owner_type {
+                OwnerType::Organization => environment_detector,
+                OwnerType::User => std::sync::Arc::new(visibility::PersonalAccountEnvironment),
+            };
+        let visibility_decision = resolve_repository_visibility(
+            &request,
+            template.as_ref(),
+            visibility_policy_provider,
+            environment_detector,
+        )
+        .await?;
 
-    // Step 8: Create the repository on GitHub.
-    let repo = create_github_repository(
-        &request,
-        &merged_config,
-        &clients.installation_repo_client,
-        visibility_decision.visibility,
-    )
-    .await?;
+        // Step 5a: Resolve the description and topics, falling back to the
+        // template's defaults when the request does not provide them.
+        let description = template_processing::resolve_repository_description(
+            &request,
+            template.as_ref(),
+            &merged_config,
+            &event_context.clock,
+        )?;
+        let topics = template_processing::resolve_repository_topics(&request, template.as_ref());
+
+        // Step 5b: Let GitHub generate the repository natively when the template
+        // is a GitHub template repository and nothing needs to be rendered. A
+        // generated CODEOWNERS file has to be added locally and a review branch
+        // has to be pushed, so either forces the render path. The template's
+        // required code owners are written regardless of the organization's
+        // `generate_codeowners` setting. A full-history copy mirrors the source
+        // repository instead.
+        let required_code_owners = template
+            .as_ref()
+            .map(|t| t.template.required_code_owners.as_slice())
+            .unwrap_or_default();
+        let owning_teams: &[String] = if merged_config.generate_codeowners {
+            &request.owning_teams
+        } else {
+            &[]
+        };
+        let codeowner_teams = codeowners::codeowner_teams(required_code_owners, owning_teams);
+        let generate_codeowners = !codeowner_teams.is_empty();
+        // GitHub's generate API cannot scaffold a license, .gitignore or
+        // README either.
+        let scaffolding = GitHubScaffolding::resolve(&request, &merged_config);
+        let mirror_source = match &request.content_strategy {
+            ContentStrategy::CopyFromRepository {
+                source,
+                copy_full_history: true,
+            } => Some(source.as_str()),
+            _ => None,
+        };
+        let creation_path = if mirror_source.is_some() {
+            ContentCreationPath::Mirror
+        } else if generate_codeowners
+            || request.review_branch.is_some()
+            || scaffolding.creates_commit()
+        {
+            ContentCreationPath::Render
+        } else {
+            choose_content_creation_path(
+                &clients.installation_repo_client,
+                &request,
+                template.as_ref(),
+                visibility_decision.visibility,
+            )
+            .await
+        };
+
+        // Commit of the rendered template; unknown when GitHub copies it natively.
+        let mut template_commit_sha = None;
+        let (repo, default_branch) = if creation_path == ContentCreationPath::NativeTemplate {
+            // Steps 6–9 (native): GitHub copies the template content; the new
+            // repository keeps the template's default branch.
+            let repo = generate_github_repository(
+                &request,
+                &merged_config,
+                &clients.installation_repo_client,
+                visibility_decision.visibility,
+                description,
+            )
+            .await?;
+            wait_for_repository_availability(
+                &clients.installation_repo_client,
+                &request,
+                &REPOSITORY_AVAILABILITY_RETRY,
+            )
+            .await?;
+            let default_branch = repo
+                .default_branch()
+                .unwrap_or(FALLBACK_DEFAULT_BRANCH)
+                .to_string();
+            (repo, default_branch)
+        } else if let (ContentCreationPath::Mirror, Some(source)) = (creation_path, mirror_source) {
+            // Steps 6–9 (mirror): create the repository and mirror every branch
+            // and tag of the source into it. The source's files are kept as
+            // they are, so no CODEOWNERS file is added.
+            if generate_codeowners {
+                warn!(
+                    "CODEOWNERS is not generated for repositories copied with their full history"
+                );
+            }
+            // The mirror keeps the source's default branch, not the one GitHub
+            // picks for the new repository.
+            let git_base_url = git_base_url(dependencies.github_api_base_url.as_deref())?;
+            let default_branch =
+                source_default_branch(clients.installation_repo_client.as_ref(), source).await?;
+            let repo = create_github_repository(
+                &request,
+                &merged_config,
+                &clients.installation_repo_client,
+                visibility_decision.visibility,
+                description,
+            )
+            .await?;
+            wait_for_repository_availability(
+                &clients.installation_repo_client,
+                &request,
+                &REPOSITORY_AVAILABILITY_RETRY,
+            )
+            .await?;
+            let token = push_token(
+                auth_service,
+                request.owner.as_ref(),
+                &clients,
+                PUSH_TOKEN_MAX_AGE,
+            )
+            .await?;
+            mirror_source_repository(
+                dependencies.git.as_ref(),
+                &git_base_url,
+                source,
+                &format!("{}/{}", request.owner, request.name),
+                &token,
+            )?;
+            if repo.default_branch() != Some(default_branch.as_str()) {
+                configuration::apply_default_branch(
+                    &clients.installation_repo_client,
+                    request.owner.as_ref(),
+                    request.name.as_ref(),
+                    &default_branch,
+                )
+                .await?;
+            }
+            (repo, default_branch)
+        } else {
+            // Step 6: Generate local repository content.
+            let (local_repo_path, commit_sha) = generate_repository_content(
+                &request,
+                template.as_ref(),
+                &merged_config,
+                description.as_deref(),
+                clients.template_fetcher.as_ref(),
+                event_context.clock.clone(),
+            )
+            .await?;
+            template_commit_sha = commit_sha;
+
+            // Steps 6a–9b run in one block so the local content can be kept
+            // for debugging when one of them fails.
+            let published: RepoRollerResult<(github_client::Repository, String)> = async {
+                // Step 6a: Add the required code owners and, when enabled, the
+                // owning teams to CODEOWNERS.
+                if generate_codeowners {
+                    codeowners::write_codeowners(
+                        local_repo_path.path(),
+                        request.owner.as_ref(),
+                        &codeowner_teams,
+                    )?;
+                }
 
-    // Step 9: Push local content to the GitHub remote.
-    // Uses retry logic to handle GitHub's eventual consistency between the
-    // REST API and git servers (transient 404/503 immediately after creation).
-    push_repository_to_github_with_retry(
-        &local_repo_path,
-        repo.url(),
-        &default_branch,
-        &clients.installation_token,
-    )
-    .await?;
+                // Step 7: Resolve the default branch.
+                let branch_resolution = match request.owner_type {
+                    OwnerType::Organization => {
+                        resolve_default_branch(
+                            &clients.installation_repo_client,
+                            request.owner.as_ref(),
+                        )
+                        .await?
+                    }
+                    OwnerType::User => DefaultBranchResolution {
+                        name: FALLBACK_DEFAULT_BRANCH.to_string(),
+                        fallback_warning: None,
+                    },
+                };
+                warnings.extend(branch_resolution.fallback_warning);
+                let default_branch = branch_resolution.name;
+                let use_api_commit = request
+                    .signed_initial_commit
+                    .unwrap_or(merged_config.signed_initial_commit);
+                if let Some(review_branch) = &request.review_branch {
+                    validate_review_branch(review_branch, &default_branch, use_api_commit)?;
+                }
 
-    // Steps 10–11: Apply merged configuration and repository permissions.
-    apply_post_creation_settings(
-        &clients.installation_repo_client,
-        &request,
-        &merged_config,
-        template.as_ref(),
-        &event_context.created_by,
-    )
-    .await?;
+                // Step 8: Create the repository on GitHub. GitHub can report it as
+                // missing for a moment afterwards, so wait until it is readable
+                // before any further call uses it.
+                let repo = create_github_repository(
+                    &request,
+                    &merged_config,
+                    &clients.installation_repo_client,
+                    visibility_decision.visibility,
+                    description,
+                )
+                .await?;
+                wait_for_repository_availability(
+                    &clients.installation_repo_client,
+                    &request,
+                    &REPOSITORY_AVAILABILITY_RETRY,
+                )
+                .await?;
+
+                // Step 8a: Keep the files GitHub committed on creation. The initial
+                // commit replaces GitHub's commit, so they have to be part of it.
+                if scaffolding.creates_commit() {
+                    write_scaffolded_files(
+                        &clients.installation_repo_client,
+                        &request,
+                        &scaffolding,
+                        &local_repo_path,
+                    )
+                    .await?;
+                }
 
-    info!("Repository creation completed successfully");
+                // Step 8b: Initialize the local Git repository and create the initial
+                // commit.
+                initialize_git_repository(
+                    dependencies.git.as_ref(),
+                    &local_repo_path,
+                    &default_branch,
+                )?;
+
+                // Step 8c: Move the content to the review branch when requested.
+                if let Some(review_branch) = &request.review_branch {
+                    dependencies
+                        .git
+                        .move_to_review_branch(&local_repo_path, &default_branch, review_branch)
+                        .map_err(|e| {
+                            error!("Failed to prepare review branch: {}", e);
+                            RepoRollerError::System(SystemError::Internal {
+                                reason: format!("Failed to prepare review branch: {}", e),
+                            })
+                        })?;
+                }
 
-    // Step 12: Build the result.
-    let result = RepositoryCreationResult {
-        repository_url: repo.url().to_string(),
-        repository_id: repo.node_id().to_string(),
-        created_at: Timestamp::now(),
-        default_branch: default_branch.clone(),
-    };
+                // Step 9: Publish the initial commit, either by pushing the local content
+                // or, when signed commits are requested, through the GitHub API.
+                let token = push_token(
+                    auth_service,
+                    request.owner.as_ref(),
+                    &clients,
+                    PUSH_TOKEN_MAX_AGE,
+                )
+                .await?;
+                publish_initial_commit(
+                    &clients.installation_repo_client,
+                    dependencies.git.as_ref(),
+                    &request,
+                    &local_repo_path,
+                    repo.url(),
+                    &default_branch,
+                    &token,
+                    use_api_commit,
+                    scaffolding.creates_commit(),
+                )
+                .await?;
+
+                // Step 9b: Propose the content on the review branch for merging.
+                if let Some(review_branch) = &request.review_branch {
+                    open_review_pull_request(
+                        &clients.installation_repo_client,
+                        &request,
+                        review_branch,
+                        &default_branch,
+                    )
+                    .await?;
+                }
+
+                Ok((repo, default_branch))
+            }
+            .await;
+
+            match published {
+                Ok(published) => published,
+                Err(error) => {
+                    if dependencies.keep_failed_workdir {
+                        keep_failed_workdir(
+                            &local_repo_path,
+                            &failed_workdir_path(&request, &event_context.correlation_id),
+                        );
+                    }
+                    return Err(error);
+                }
+            }
+        };
+
+        event_context.record_step(CreationStep::RepoCreated, &request);
+
+        // Step 9a: Apply repository topics.
+        apply_repository_topics(&clients.installation_repo_client, &request, &topics).await;
 
-    // Step 13: Fire-and-forget event notification.
-    spawn_event_notification(&result, request, merged_config, event_context);
+        // Steps 10–11: Apply merged configuration and repository permissions.
+        apply_post_creation_settings(
+            &clients.installation_repo_client,
+            &request,
+            &merged_config,
+            template.as_ref(),
+            &default_branch,
+            &event_context.created_by,
+            event_context.secret_resolver.as_ref(),
+        )
+        .await?;
+        event_context.record_step(CreationStep::ConfigApplied, &request);
+
+        // Step 11a: Optionally confirm the applied settings match the intended configuration.
+        let verification = if request.verify_configuration {
+            verify_created_repository(&clients.installation_repo_client, &request, &merged_config)
+                .await
+        } else {
+            None
+        };
+
+        info!("Repository creation completed successfully");
+
+        // Step 12: Build the result.
+        Ok(RepositoryCreationResult {
+            repository_url: repo.url().to_string(),
+            repository_id: repo.node_id().to_string(),
+            created_at: Timestamp::from_clock(event_context.clock.as_ref()),
+            default_branch: default_branch.clone(),
+            warnings,
+            verification,
+            configuration_audit: request.include_configuration_audit.then(|| {
+                merged_config
+                    .audit_record()
+                    .with_template_variables(&request.variables, template.as_ref())
+            }),
+            template_commit_sha,
+        })
+    }
+    .await;
 
-    Ok(result)
+    // Step 13: Fire-and-forget event notification for the outcome.
+    match outcome {
+        Ok(result) => {
+            spawn_event_notification(&result, request, merged_config, event_context);
+            Ok(result)
+        }
+        Err(error) => {
+            spawn_failure_notification(&error, request, merged_config, event_context);
+            Err(error)
+        }
+    }
 }
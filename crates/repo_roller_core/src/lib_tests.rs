@@ -36,23 +36,182 @@ impl auth_handler::UserAuthenticationService for MockAuthService {
     }
 }
 
+/// Mock authentication service that always returns an installation token
+///
+/// Used together with a mock GitHub server for tests of the full workflow
+struct MockTokenAuthService;
+
+#[async_trait]
+impl auth_handler::UserAuthenticationService for MockTokenAuthService {
+    async fn get_installation_token_for_org(
+        &self,
+        _org_name: &str,
+    ) -> auth_handler::AuthResult<String> {
+        Ok("ghs_mock_token".to_string())
+    }
+}
+
+/// Mock authentication service that issues numbered installation tokens and
+/// refreshes them against a mock server
+///
+/// Used for testing how expired installation tokens are replaced
+struct MockRefreshingAuthService {
+    base_url: String,
+    tokens_issued: Arc<std::sync::atomic::AtomicUsize>,
+    refreshes: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl MockRefreshingAuthService {
+    fn new(base_url: String) -> Self {
+        Self {
+            base_url,
+            tokens_issued: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            refreshes: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        }
+    }
+
+    fn refresher(&self) -> MockTokenRefresher {
+        MockTokenRefresher {
+            base_url: self.base_url.clone(),
+            refreshes: self.refreshes.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl auth_handler::UserAuthenticationService for MockRefreshingAuthService {
+    async fn get_installation_token_for_org(
+        &self,
+        _org_name: &str,
+    ) -> auth_handler::AuthResult<String> {
+        let issued = self
+            .tokens_issued
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(format!("ghs_token_{}", issued + 1))
+    }
+
+    async fn installation_token_refresher(
+        &self,
+        _org_name: &str,
+        _base_url: Option<&str>,
+    ) -> Option<Arc<dyn github_client::TokenRefresher>> {
+        Some(Arc::new(self.refresher()))
+    }
+}
+
+/// Token refresher returning clients for `ghs_refreshed_token`, counting its calls
+#[derive(Debug)]
+struct MockTokenRefresher {
+    base_url: String,
+    refreshes: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[async_trait]
+impl github_client::TokenRefresher for MockTokenRefresher {
+    async fn refresh(&self) -> Result<octocrab::Octocrab, GitHubError> {
+        self.refreshes
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let client =
+            github_client::create_octocrab_client("ghs_refreshed_token", Some(&self.base_url))?;
+        Ok(client.as_ref().clone())
+    }
+}
+
+/// Mock Git operations that record each call instead of touching a repository
+#[derive(Default)]
+struct MockGitOperations {
+    calls: Mutex<Vec<String>>,
+    /// Makes every push fail with a non-transient error
+    fail_push: bool,
+}
+
+impl MockGitOperations {
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: String) -> Result<(), SystemError> {
+        self.calls.lock().unwrap().push(call);
+        Ok(())
+    }
+}
+
+impl GitOperations for MockGitOperations {
+    fn init(&self, _local_repo_path: &TempDir, default_branch: &str) -> Result<(), SystemError> {
+        self.record(format!("init {}", default_branch))
+    }
+
+    fn commit_all(
+        &self,
+        _local_repo_path: &TempDir,
+        commit_message: &str,
+        _allow_empty: bool,
+    ) -> Result<(), SystemError> {
+        self.record(format!("commit {}", commit_message))
+    }
+
+    fn move_to_review_branch(
+        &self,
+        _local_repo_path: &TempDir,
+        _default_branch: &str,
+        review_branch: &str,
+    ) -> Result<(), SystemError> {
+        self.record(format!("review {}", review_branch))
+    }
+
+    fn push(
+        &self,
+        _local_repo_path: &TempDir,
+        repo_url: url::Url,
+        branch_name: &str,
+        _access_token: &str,
+        force: bool,
+    ) -> Result<(), SystemError> {
+        if self.fail_push {
+            return Err(SystemError::Internal {
+                reason: "remote rejected the push".to_string(),
+            });
+        }
+        self.record(format!("push {} {} force={}", repo_url, branch_name, force))
+    }
+
+    fn mirror(
+        &self,
+        source_url: url::Url,
+        destination_url: url::Url,
+        _access_token: &str,
+    ) -> Result<(), SystemError> {
+        self.record(format!("mirror {} {}", source_url, destination_url))
+    }
+}
+
 /// Mock metadata provider for testing
 ///
 /// Returns a configured TemplateConfig for testing purposes
 struct MockMetadataProvider {
     template_config: Option<TemplateConfig>,
+    global_defaults: Option<config_manager::GlobalDefaults>,
 }
 
 impl MockMetadataProvider {
     fn with_template(template_config: TemplateConfig) -> Self {
         Self {
             template_config: Some(template_config),
+            global_defaults: None,
+        }
+    }
+
+    fn with_global_defaults(global_defaults: config_manager::GlobalDefaults) -> Self {
+        Self {
+            template_config: None,
+            global_defaults: Some(global_defaults),
         }
     }
 
     fn empty() -> Self {
         Self {
             template_config: None,
+            global_defaults: None,
         }
     }
 }
@@ -74,16 +233,27 @@ impl MetadataRepositoryProvider for MockMetadataProvider {
 
     async fn discover_metadata_repository(
         &self,
-        _org: &str,
+        org: &str,
     ) -> ConfigurationResult<MetadataRepository> {
-        unimplemented!("Not used in these tests")
+        assert!(self.global_defaults.is_some(), "Not used in these tests");
+        Ok(MetadataRepository {
+            organization: org.to_string(),
+            repository_name: ".reporoller".to_string(),
+            discovery_method: config_manager::DiscoveryMethod::ConfigurationBased {
+                repository_name: ".reporoller".to_string(),
+            },
+            last_updated: chrono::Utc::now(),
+        })
     }
 
     async fn load_global_defaults(
         &self,
         _repo: &MetadataRepository,
     ) -> ConfigurationResult<config_manager::GlobalDefaults> {
-        unimplemented!("Not used in these tests")
+        Ok(self
+            .global_defaults
+            .clone()
+            .expect("Not used in these tests"))
     }
 
     async fn load_team_configuration(
@@ -135,6 +305,21 @@ impl MetadataRepositoryProvider for MockMetadataProvider {
     }
 }
 
+/// Mock secret resolver
+///
+/// Resolves every secret reference to the same fixed value
+struct MockSecretResolver;
+
+#[async_trait]
+impl crate::event_secrets::SecretResolver for MockSecretResolver {
+    async fn resolve_secret(
+        &self,
+        _secret_ref: &str,
+    ) -> Result<String, crate::event_secrets::SecretResolutionError> {
+        Ok("test-secret".to_string())
+    }
+}
+
 /// Mock visibility policy provider
 ///
 /// Returns unrestricted policy for all organizations
@@ -203,19 +388,23 @@ impl ConfigurableMockRepoClient {
 impl RepositoryClient for ConfigurableMockRepoClient {
     async fn create_org_repository(
         &self,
-        _owner: &str,
+        owner: &str,
         payload: &github_client::RepositoryCreatePayload,
     ) -> Result<github_client::Repository, GitHubError> {
+        if let Some(tracker) = &self.config.repository_creation_tracker {
+            tracker.lock().unwrap().push(format!("org:{}", owner));
+        }
         Ok(self.create_successful_repository(payload))
     }
 
     async fn create_user_repository(
         &self,
-        _payload: &github_client::RepositoryCreatePayload,
+        payload: &github_client::RepositoryCreatePayload,
     ) -> Result<github_client::Repository, GitHubError> {
-        Err(GitHubError::AuthError(
-            "User repos not supported in test".to_string(),
-        ))
+        if let Some(tracker) = &self.config.repository_creation_tracker {
+            tracker.lock().unwrap().push("user".to_string());
+        }
+        Ok(self.create_successful_repository(payload))
     }
 
     async fn update_repository_settings(
@@ -254,7 +443,13 @@ impl RepositoryClient for ConfigurableMockRepoClient {
         &self,
         _org_name: &str,
     ) -> Result<String, GitHubError> {
-        Ok(self.config.default_branch.clone())
+        match &self.config.default_branch {
+            MockDefaultBranchBehavior::Branch(name) => Ok(name.clone()),
+            MockDefaultBranchBehavior::Forbidden => Err(GitHubError::AuthError(
+                "Access forbidden - insufficient permissions".to_string(),
+            )),
+            MockDefaultBranchBehavior::InvalidResponse => Err(GitHubError::InvalidResponse),
+        }
     }
 
     async fn set_repository_custom_properties(
@@ -285,6 +480,14 @@ impl RepositoryClient for ConfigurableMockRepoClient {
         Ok(vec![])
     }
 
+    async fn list_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<github_client::Label>, GitHubError> {
+        Ok(vec![])
+    }
+
     async fn create_label(
         &self,
         _owner: &str,
@@ -311,10 +514,20 @@ impl RepositoryClient for ConfigurableMockRepoClient {
         _owner: &str,
         _repo: &str,
     ) -> Result<github_client::Repository, GitHubError> {
-        // Not implemented in test mock - return error
-        Err(GitHubError::AuthError(
-            "Not implemented in test".to_string(),
-        ))
+        match self.config.repository_is_template {
+            Some(is_template) => Ok(serde_json::from_value(serde_json::json!({
+                "name": "test-template",
+                "full_name": "test-org/test-template",
+                "node_id": "R_kgDOTemplate",
+                "private": false,
+                "is_template": is_template
+            }))
+            .unwrap()),
+            // Not configured - return error
+            None => Err(GitHubError::AuthError(
+                "Not implemented in test".to_string(),
+            )),
+        }
     }
 
     async fn get_branch_protection(
@@ -425,6 +638,222 @@ impl RepositoryClient for ConfigurableMockRepoClient {
         // Not implemented in test mock - return Ok
         Ok(())
     }
+
+    async fn transfer_repository(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _new_owner: &str,
+        _team_ids: Option<Vec<u64>>,
+    ) -> Result<github_client::Repository, GitHubError> {
+        // Not implemented in test mock
+        Err(GitHubError::InvalidResponse)
+    }
+
+    async fn set_actions_variable(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _name: &str,
+        _value: &str,
+    ) -> Result<(), GitHubError> {
+        // Not implemented in test mock - return Ok
+        Ok(())
+    }
+
+    async fn set_actions_secret(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _name: &str,
+        _value: &str,
+    ) -> Result<(), GitHubError> {
+        // Not implemented in test mock - return Ok
+        Ok(())
+    }
+
+    async fn set_actions_permissions(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _enabled: bool,
+        _default_workflow_permissions: Option<&str>,
+    ) -> Result<(), GitHubError> {
+        // Not implemented in test mock - return Ok
+        Ok(())
+    }
+
+    async fn repository_exists(&self, _owner: &str, _repo: &str) -> Result<bool, GitHubError> {
+        Ok(self.config.repository_exists)
+    }
+
+    async fn replace_topics(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        topics: &[String],
+    ) -> Result<(), GitHubError> {
+        if let Some(tracker) = &self.config.topics_tracker {
+            tracker.lock().unwrap().push(topics.to_vec());
+        }
+        Ok(())
+    }
+
+    async fn create_initial_commit(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        branch: &str,
+        _message: &str,
+        files: &[github_client::CommitFile],
+    ) -> Result<String, GitHubError> {
+        if let Some(tracker) = &self.config.initial_commit_tracker {
+            let paths = files.iter().map(|f| f.path.clone()).collect();
+            tracker.lock().unwrap().push((branch.to_string(), paths));
+        }
+        Ok("mock-commit-sha".to_string())
+    }
+
+    async fn generate_from_template(
+        &self,
+        _template_owner: &str,
+        _template_repo: &str,
+        payload: &github_client::RepositoryGeneratePayload,
+    ) -> Result<github_client::Repository, GitHubError> {
+        Ok(github_client::Repository::new(
+            payload.name.clone(),
+            format!("{}/{}", payload.owner, payload.name),
+            "MDEwOlJlcG9zaXRvcnkx".to_string(),
+            payload.private.unwrap_or(false),
+        ))
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<github_client::PullRequest, GitHubError> {
+        if let Some(tracker) = &self.config.pull_request_tracker {
+            tracker.lock().unwrap().push((
+                head.to_string(),
+                base.to_string(),
+                title.to_string(),
+                body.to_string(),
+            ));
+        }
+        Ok(github_client::PullRequest {
+            number: 1,
+            html_url: format!("https://github.com/{}/{}/pull/1", owner, repo),
+            title: title.to_string(),
+            state: "open".to_string(),
+        })
+    }
+
+    async fn get_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<github_client::Repository, GitHubError> {
+        Ok(github_client::Repository::new(
+            repo.to_string(),
+            format!("{}/{}", owner, repo),
+            "MDEwOlJlcG9zaXRvcnkx".to_string(),
+            false,
+        ))
+    }
+
+    async fn get_file_content(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _path: &str,
+    ) -> Result<String, GitHubError> {
+        // Not implemented in test mock
+        Err(GitHubError::NotFound)
+    }
+
+    async fn list_organization_teams(
+        &self,
+        _org: &str,
+    ) -> Result<Vec<github_client::Team>, GitHubError> {
+        Ok(vec![])
+    }
+
+    async fn list_organization_installations(
+        &self,
+        _org: &str,
+    ) -> Result<Vec<github_client::Installation>, GitHubError> {
+        Ok(vec![])
+    }
+
+    async fn update_branch_push_protection(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+        _rules: &github_client::BranchPushProtection,
+    ) -> Result<(), GitHubError> {
+        Ok(())
+    }
+
+    async fn branch_exists(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<bool, GitHubError> {
+        Ok(true)
+    }
+
+    async fn set_default_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+    ) -> Result<(), GitHubError> {
+        Ok(())
+    }
+
+    async fn add_team_to_repository(
+        &self,
+        _org: &str,
+        _team_slug: &str,
+        _repo: &str,
+        _permission: &str,
+    ) -> Result<(), GitHubError> {
+        Ok(())
+    }
+
+    async fn list_repository_collaborators(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<github_client::Collaborator>, GitHubError> {
+        Ok(vec![])
+    }
+
+    async fn add_repository_collaborator(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _username: &str,
+        _permission: &str,
+    ) -> Result<(), GitHubError> {
+        Ok(())
+    }
+
+    async fn remove_repository_collaborator(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _username: &str,
+    ) -> Result<(), GitHubError> {
+        Ok(())
+    }
 }
 
 /// Configuration for mock repository client behavior
@@ -434,8 +863,21 @@ struct MockRepoClientConfig {
     token_behavior: MockTokenBehavior,
     /// Optional callback to track when get_installation_token_for_org is called
     token_call_tracker: Option<Arc<Mutex<bool>>>,
-    /// Default branch to return from get_organization_default_branch
-    default_branch: String,
+    /// Behavior of get_organization_default_branch
+    default_branch: MockDefaultBranchBehavior,
+    /// Optional tracker recording (branch, file paths) for each create_initial_commit call
+    initial_commit_tracker: Option<Arc<Mutex<Vec<(String, Vec<String>)>>>>,
+    /// Result returned from repository_exists
+    repository_exists: bool,
+    /// Optional tracker recording the topics passed to each replace_topics call
+    topics_tracker: Option<Arc<Mutex<Vec<Vec<String>>>>>,
+    /// `is_template` flag of the repository returned by get_repository_settings;
+    /// `None` makes get_repository_settings fail
+    repository_is_template: Option<bool>,
+    /// Optional tracker recording (head, base, title, body) for each create_pull_request call
+    pull_request_tracker: Option<Arc<Mutex<Vec<(String, String, String, String)>>>>,
+    /// Optional tracker recording `org:<owner>` or `user` for each repository creation call
+    repository_creation_tracker: Option<Arc<Mutex<Vec<String>>>>,
 }
 
 impl Default for MockRepoClientConfig {
@@ -443,7 +885,13 @@ impl Default for MockRepoClientConfig {
         Self {
             token_behavior: MockTokenBehavior::Success("ghs_mock_token".to_string()),
             token_call_tracker: None,
-            default_branch: "main".to_string(),
+            default_branch: MockDefaultBranchBehavior::Branch("main".to_string()),
+            initial_commit_tracker: None,
+            repository_exists: false,
+            topics_tracker: None,
+            repository_is_template: None,
+            pull_request_tracker: None,
+            repository_creation_tracker: None,
         }
     }
 }
@@ -455,13 +903,30 @@ impl MockRepoClientConfig {
         Self {
             token_behavior: MockTokenBehavior::Success(format!("ghs_mock_token_for_{}", org_name)),
             token_call_tracker: None,
-            default_branch: "main".to_string(),
+            default_branch: MockDefaultBranchBehavior::Branch("main".to_string()),
+            initial_commit_tracker: None,
+            repository_exists: false,
+            topics_tracker: None,
+            repository_is_template: None,
+            pull_request_tracker: None,
+            repository_creation_tracker: None,
         }
     }
 }
 
 // --- MOCK ENUMS (ALPHABETICALLY ORDERED) ---
 
+/// Behavior of the mock's get_organization_default_branch
+#[derive(Clone)]
+enum MockDefaultBranchBehavior {
+    /// Return the given branch name
+    Branch(String),
+    /// Return the AuthError produced when the organization cannot be read
+    Forbidden,
+    /// Return an InvalidResponse error, as for a transient failure
+    InvalidResponse,
+}
+
 /// Configuration for mock repository client behavior
 #[derive(Clone)]
 enum MockTokenBehavior {
@@ -545,7 +1010,7 @@ async fn test_push_to_origin_with_valid_token() {
 
     // This will fail because it's not a real repository, but it should fail with a network error
     // rather than an authentication error, proving our auth setup is correct
-    let result = crate::git::push_to_origin(&temp_dir, fake_url, "main", token);
+    let result = crate::git::push_to_origin(&temp_dir, fake_url, "main", token, false);
 
     // We expect this to fail with a network/repository error, not an auth error
     assert!(result.is_err());
@@ -582,6 +1047,9 @@ async fn test_create_repository_type_conversion() {
             description: "Test template".to_string(),
             author: "Test".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -600,6 +1068,10 @@ async fn test_create_repository_type_conversion() {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     };
 
     let metadata_provider = MockMetadataProvider::with_template(template_config);
@@ -620,6 +1092,7 @@ async fn test_create_repository_type_conversion() {
         visibility_policy_provider,
         environment_detector,
         EventNotificationContext::new("unit-test", secret_resolver, metrics),
+        CreationDependencies::default(),
     )
     .await;
 
@@ -666,6 +1139,7 @@ async fn test_create_repository_error_handling() {
         visibility_policy_provider,
         environment_detector,
         EventNotificationContext::new("unit-test", secret_resolver, metrics),
+        CreationDependencies::default(),
     )
     .await;
 
@@ -709,6 +1183,7 @@ async fn test_create_repository_preserves_variables() {
         visibility_policy_provider,
         environment_detector,
         EventNotificationContext::new("unit-test", secret_resolver, metrics),
+        CreationDependencies::default(),
     )
     .await;
 }
@@ -761,6 +1236,7 @@ async fn test_create_repository_result_type() {
         visibility_policy_provider,
         environment_detector,
         EventNotificationContext::new("unit-test", secret_resolver, metrics),
+        CreationDependencies::default(),
     )
     .await;
 
@@ -781,357 +1257,2844 @@ async fn test_create_repository_result_type() {
     }
 }
 
-// --- CONFIGURATION VARIABLE EXTRACTION TESTS ---
+/// Mounts `GET /repos/test-org/new-repo` answering 404 for the first
+/// `not_found_count` requests and returning the repository afterwards.
+///
+/// The first lookup is the name availability check; later ones wait for the
+/// created repository to become available.
+async fn mount_new_repository_lookup(server: &wiremock::MockServer, not_found_count: u64) {
+    mount_repository_lookup(server, "new-repo", not_found_count).await;
+}
 
-/// Verify that extract_config_variables returns empty map for default configuration.
-#[test]
-fn test_extract_config_variables_empty_config() {
-    let merged_config = config_manager::MergedConfiguration::new();
-    let variables = extract_config_variables(&merged_config);
+/// Mounts `GET /repos/test-org/{name}` answering 404 for the first
+/// `not_found_count` requests and returning the repository afterwards.
+async fn mount_repository_lookup(server: &wiremock::MockServer, name: &str, not_found_count: u64) {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let route = format!("/repos/test-org/{}", name);
+    Mock::given(method("GET"))
+        .and(path(route.as_str()))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .up_to_n_times(not_found_count)
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(route.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": name,
+            "full_name": format!("test-org/{}", name),
+            "private": true,
+            "url": format!("https://api.github.com/repos/test-org/{}", name)
+        })))
+        .mount(server)
+        .await;
+}
 
-    // Default config should have no explicitly set values, so we get default boolean values
-    assert_eq!(
-        variables.get("config_issues_enabled"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_wiki_enabled"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_projects_enabled"),
+/// Matches requests authorized with `token` while `active` is set.
+///
+/// Used to make GitHub reject a token part-way through a test.
+struct RejectedTokenMatcher {
+    token: &'static str,
+    active: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl wiremock::Match for RejectedTokenMatcher {
+    fn matches(&self, request: &wiremock::Request) -> bool {
+        self.active.load(std::sync::atomic::Ordering::SeqCst)
+            && request
+                .headers
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.contains(self.token))
+    }
+}
+
+fn bad_credentials() -> wiremock::ResponseTemplate {
+    wiremock::ResponseTemplate::new(401).set_body_json(serde_json::json!({
+        "message": "Bad credentials",
+        "documentation_url": "https://docs.github.com/rest"
+    }))
+}
+
+/// Verify the whole creation workflow against a mock GitHub server and mock git.
+///
+/// The metadata repository is missing on the mock server, so the organization
+/// defaults are used.
+#[tokio::test]
+async fn test_create_repository_full_flow_with_mock_git_and_github() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "trunk"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let git = Arc::new(MockGitOperations::default());
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+    let dependencies = CreationDependencies::new()
+        .with_git(git.clone())
+        .with_github_api_base_url(server.uri());
+
+    let result = create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await
+    .expect("Repository creation should succeed");
+
+    assert_eq!(result.repository_id, "R_kgDONewRepo");
+    assert_eq!(result.default_branch, "trunk");
+    assert_eq!(
+        git.calls(),
+        vec![
+            "init trunk".to_string(),
+            "commit Initial commit".to_string(),
+            "push https://github.com/test-org/new-repo.git trunk force=false".to_string(),
+        ]
+    );
+    server.verify().await;
+}
+
+/// Verify that a full-history copy mirrors the source repository instead of
+/// pushing a local commit, on the Git host of the configured API, and keeps
+/// the source's default branch.
+#[tokio::test]
+async fn test_create_repository_copy_full_history_mirrors_source() {
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/existing-repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 987654321,
+            "node_id": "R_kgDOSource",
+            "name": "existing-repo",
+            "full_name": "test-org/existing-repo",
+            "private": true,
+            "default_branch": "develop",
+            "url": "https://api.github.com/repos/test-org/existing-repo"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "default_branch": "main",
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/new-repo/branches/develop"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "name": "develop" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/new-repo"))
+        .and(body_json(
+            serde_json::json!({ "default_branch": "develop" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::CopyFromRepository {
+        source: "test-org/existing-repo".to_string(),
+        copy_full_history: true,
+    })
+    .build();
+    let git = Arc::new(MockGitOperations::default());
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+    let dependencies = CreationDependencies::new()
+        .with_git(git.clone())
+        .with_github_api_base_url(server.uri());
+
+    let result = create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await
+    .expect("Repository creation should succeed");
+
+    assert_eq!(result.default_branch, "develop");
+    assert_eq!(
+        git.calls(),
+        vec![format!(
+            "mirror {0}/test-org/existing-repo.git {0}/test-org/new-repo.git",
+            server.uri()
+        )]
+    );
+    server.verify().await;
+}
+
+/// Verify that a full-history copy of a missing source fails before the
+/// repository is created.
+#[tokio::test]
+async fn test_create_repository_copy_full_history_rejects_missing_source() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 1).await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::CopyFromRepository {
+        source: "test-org/missing-repo".to_string(),
+        copy_full_history: true,
+    })
+    .build();
+    let git = Arc::new(MockGitOperations::default());
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+    let dependencies = CreationDependencies::new()
+        .with_git(git.clone())
+        .with_github_api_base_url(server.uri());
+
+    let result = create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+            if field == "content_strategy.source"
+    ));
+    assert!(git.calls().is_empty());
+    server.verify().await;
+}
+
+/// Verify that Git URLs are derived from the GitHub API base URL.
+#[test]
+fn test_git_base_url() {
+    let cases = [
+        (None, "https://github.com/"),
+        (Some("https://api.github.com"), "https://github.com/"),
+        (Some("https://api.github.com/"), "https://github.com/"),
+        (
+            Some("https://github.example.com/api/v3"),
+            "https://github.example.com/",
+        ),
+        (
+            Some("https://github.example.com/api/v3/"),
+            "https://github.example.com/",
+        ),
+        (Some("https://api.acme.ghe.com"), "https://acme.ghe.com/"),
+        (Some("http://127.0.0.1:8080"), "http://127.0.0.1:8080/"),
+    ];
+    for (api_url, expected) in cases {
+        assert_eq!(
+            git_base_url(api_url).unwrap().as_str(),
+            expected,
+            "{api_url:?}"
+        );
+    }
+    assert!(git_base_url(Some("not a url")).is_err());
+}
+
+/// Verify that a successful creation reports every step to the step sink, in order.
+#[tokio::test]
+async fn test_create_repository_records_step_events_in_order() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let sink = Arc::new(InMemoryStepSink::new());
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    )
+    .with_correlation_id("corr-42")
+    .with_step_sink(sink.clone());
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri());
+
+    create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await
+    .expect("Repository creation should succeed");
+
+    let events = sink.events();
+    let steps: Vec<CreationStep> = events.iter().map(|e| e.step).collect();
+    assert_eq!(
+        steps,
+        vec![
+            CreationStep::AuthDone,
+            CreationStep::TemplateFetched,
+            CreationStep::RepoCreated,
+            CreationStep::ConfigApplied,
+        ]
+    );
+    assert!(events.iter().all(|e| e.correlation_id == "corr-42"));
+    assert!(events.iter().all(|e| e.repository == "test-org/new-repo"));
+    assert!(events.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+}
+
+/// Verify that creation waits for a repository GitHub briefly reports as
+/// missing, then applies the configuration.
+#[tokio::test]
+async fn test_create_repository_waits_for_repository_after_creation() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    // The name check and the first lookup after creation both see a 404.
+    mount_new_repository_lookup(&server, 2).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let sink = Arc::new(InMemoryStepSink::new());
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    )
+    .with_step_sink(sink.clone());
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri());
+
+    create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await
+    .expect("Repository creation should succeed once the repository is available");
+
+    let lookups = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.url.path() == "/repos/test-org/new-repo")
+        .count();
+    // The name check, one 404 after creation and the successful retry.
+    assert_eq!(lookups, 3);
+    assert_eq!(
+        sink.events().last().map(|e| e.step),
+        Some(CreationStep::ConfigApplied)
+    );
+    server.verify().await;
+}
+
+/// Verify that creation waits for the new repository before the first API
+/// call that uses it, here opening the review pull request.
+#[tokio::test]
+async fn test_create_repository_waits_for_repository_before_follow_up_calls() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 2).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/test-org/new-repo/pulls"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 1,
+            "number": 1,
+            "html_url": "https://github.com/test-org/new-repo/pull/1",
+            "title": "Initial repository content",
+            "state": "open"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .review_branch("template-init")
+    .build();
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri());
+
+    create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await
+    .expect("Repository creation should succeed once the repository is available");
+
+    let requests = server.received_requests().await.unwrap();
+    let positions = |method: &str, route: &str| -> Vec<usize> {
+        requests
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.method.as_str() == method && r.url.path() == route)
+            .map(|(i, _)| i)
+            .collect()
+    };
+    let created = positions("POST", "/orgs/test-org/repos")[0];
+    // The name check and one 404 after creation precede the successful lookup.
+    let available = positions("GET", "/repos/test-org/new-repo")[2];
+    let pull_request = positions("POST", "/repos/test-org/new-repo/pulls")[0];
+    assert!(created < available);
+    assert!(available < pull_request);
+    // Nothing but the availability lookups runs between creating the
+    // repository and finding it.
+    assert!(requests[created + 1..available]
+        .iter()
+        .all(|r| r.url.path() == "/repos/test-org/new-repo"));
+    server.verify().await;
+}
+
+/// Verify that a failed push keeps a copy of the local repository and logs its path.
+#[tracing_test::traced_test]
+#[tokio::test]
+async fn test_create_repository_keeps_workdir_when_push_fails() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    mount_new_repository_lookup(&server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let correlation_id = uuid::Uuid::new_v4().to_string();
+    let kept_path = failed_workdir_path(&request, &correlation_id);
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    )
+    .with_correlation_id(correlation_id);
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations {
+            fail_push: true,
+            ..Default::default()
+        }))
+        .with_github_api_base_url(server.uri())
+        .with_keep_failed_workdir(true);
+
+    let result = create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await;
+
+    assert!(result.is_err(), "A failed push should fail the creation");
+    assert!(kept_path.is_dir(), "{} should be kept", kept_path.display());
+    assert!(logs_contain(&kept_path.display().to_string()));
+    std::fs::remove_dir_all(&kept_path).unwrap();
+}
+
+/// Verify that the GitHub clients configured on the dependencies are used
+/// instead of creating new ones.
+#[tokio::test]
+async fn test_setup_github_clients_uses_injected_clients() {
+    let repo_client: Arc<dyn RepositoryClient> = Arc::new(ConfigurableMockRepoClient::new(
+        MockRepoClientConfig::default(),
+    ));
+    let template_fetcher: Arc<dyn template_engine::TemplateFetcher> =
+        Arc::new(template_engine::LocalTemplateFetcher);
+    let dependencies = CreationDependencies::new()
+        .with_repository_client(repo_client.clone())
+        .with_template_fetcher(template_fetcher.clone());
+
+    let clients = setup_github_clients(&MockTokenAuthService, "test-org", &dependencies)
+        .await
+        .expect("clients should be set up");
+
+    assert!(Arc::ptr_eq(&clients.installation_repo_client, &repo_client));
+    assert!(Arc::ptr_eq(&clients.template_fetcher, &template_fetcher));
+}
+
+/// Verify that a created client obtains a new installation token when GitHub
+/// rejects the one it was created with.
+#[tokio::test]
+async fn test_setup_github_clients_refreshes_rejected_token() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/new-repo"))
+        .and(RejectedTokenMatcher {
+            token: "ghs_token_1",
+            active: Arc::new(AtomicBool::new(true)),
+        })
+        .respond_with(bad_credentials())
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_new_repository_lookup(&server, 0).await;
+
+    let auth_service = MockRefreshingAuthService::new(server.uri());
+    let dependencies = CreationDependencies::new().with_github_api_base_url(server.uri());
+    let clients = setup_github_clients(&auth_service, "test-org", &dependencies)
+        .await
+        .expect("clients should be set up");
+
+    clients
+        .installation_repo_client
+        .get_repository("test-org", "new-repo")
+        .await
+        .expect("the call should succeed with the refreshed token");
+
+    assert_eq!(auth_service.refreshes.load(Ordering::SeqCst), 1);
+    server.verify().await;
+}
+
+/// Verify that the token used for pushing is only replaced once it is older
+/// than the maximum age.
+#[tokio::test]
+async fn test_push_token_replaces_old_token() {
+    let auth_service = MockRefreshingAuthService::new("http://127.0.0.1:1".to_string());
+    let clients = setup_github_clients(&auth_service, "test-org", &CreationDependencies::new())
+        .await
+        .expect("clients should be set up");
+
+    let token = push_token(&auth_service, "test-org", &clients, PUSH_TOKEN_MAX_AGE)
+        .await
+        .expect("token should be available");
+    assert_eq!(token, "ghs_token_1");
+
+    let token = push_token(
+        &auth_service,
+        "test-org",
+        &clients,
+        std::time::Duration::ZERO,
+    )
+    .await
+    .expect("a new token should be obtained");
+    assert_eq!(token, "ghs_token_2");
+}
+
+/// Verify that one injected repository client serves every creation of a batch.
+#[tokio::test]
+async fn test_create_repositories_share_injected_repository_client() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    // The mock server only serves the configuration lookups, which find no
+    // metadata repository and fall back to the defaults.
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let creations = Arc::new(Mutex::new(Vec::new()));
+    let repo_client: Arc<dyn RepositoryClient> =
+        Arc::new(ConfigurableMockRepoClient::new(MockRepoClientConfig {
+            repository_creation_tracker: Some(creations.clone()),
+            ..Default::default()
+        }));
+    let template_fetcher: Arc<dyn template_engine::TemplateFetcher> =
+        Arc::new(template_engine::LocalTemplateFetcher);
+    let test_registry = prometheus::Registry::new();
+    let metrics: Arc<dyn crate::event_metrics::EventMetrics> = Arc::new(
+        crate::event_metrics::PrometheusEventMetrics::new(&test_registry),
+    );
+    let metadata_provider = MockMetadataProvider::empty();
+    let metadata_provider = &metadata_provider;
+
+    let requests = ["first-repo", "second-repo"]
+        .into_iter()
+        .map(|name| {
+            RepositoryCreationRequestBuilder::new(
+                RepositoryName::new(name).unwrap(),
+                OrganizationName::new("test-org").unwrap(),
+            )
+            .content_strategy(ContentStrategy::Empty)
+            .build()
+        })
+        .collect();
+
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri())
+        .with_repository_client(repo_client)
+        .with_template_fetcher(template_fetcher);
+
+    let results = crate::batch::create_repositories(
+        requests,
+        None,
+        &dependencies,
+        |request, dependencies| {
+            let event_context = EventNotificationContext::new(
+                "unit-test",
+                Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+                metrics.clone(),
+            );
+
+            create_repository(
+                request,
+                metadata_provider,
+                &MockTokenAuthService,
+                ".reporoller",
+                Arc::new(MockVisibilityPolicyProvider),
+                Arc::new(MockEnvironmentDetector),
+                event_context,
+                dependencies,
+            )
+        },
+    )
+    .await
+    .expect("batch should run");
+
+    assert!(results
+        .iter()
+        .all(|r| matches!(r.status, crate::batch::BatchItemStatus::Created(_))));
+    assert_eq!(
+        *creations.lock().unwrap(),
+        vec!["org:test-org".to_string(), "org:test-org".to_string()]
+    );
+    server.verify().await;
+}
+
+/// Verify that a batch sharing one client recovers when GitHub starts
+/// rejecting the client's installation token part-way through.
+#[tokio::test]
+async fn test_create_repositories_recover_from_expired_token() {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    let expired = Arc::new(AtomicBool::new(false));
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .and(RejectedTokenMatcher {
+            token: "ghs_shared_token",
+            active: expired.clone(),
+        })
+        .respond_with(bad_credentials())
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_repository_lookup(&server, "first-repo", 1).await;
+    mount_repository_lookup(&server, "second-repo", 1).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let auth_service = MockRefreshingAuthService::new(server.uri());
+    let shared_client =
+        github_client::create_github_client("ghs_shared_token", Some(&server.uri()))
+            .unwrap()
+            .with_token_refresher(auth_service.refresher());
+    let test_registry = prometheus::Registry::new();
+    let metrics: Arc<dyn crate::event_metrics::EventMetrics> = Arc::new(
+        crate::event_metrics::PrometheusEventMetrics::new(&test_registry),
+    );
+    let metadata_provider = MockMetadataProvider::empty();
+    let metadata_provider = &metadata_provider;
+    let auth_service_ref = &auth_service;
+    let attempts = AtomicUsize::new(0);
+
+    let requests = ["first-repo", "second-repo"]
+        .into_iter()
+        .map(|name| {
+            RepositoryCreationRequestBuilder::new(
+                RepositoryName::new(name).unwrap(),
+                OrganizationName::new("test-org").unwrap(),
+            )
+            .content_strategy(ContentStrategy::Empty)
+            .build()
+        })
+        .collect();
+
+    let dependencies = CreationDependencies::new()
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri())
+        .with_repository_client(Arc::new(shared_client));
+
+    let results = crate::batch::create_repositories(
+        requests,
+        None,
+        &dependencies,
+        |request, dependencies| {
+            // The shared token expires after the first creation.
+            if attempts.fetch_add(1, Ordering::SeqCst) == 1 {
+                expired.store(true, Ordering::SeqCst);
+            }
+            let event_context = EventNotificationContext::new(
+                "unit-test",
+                Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+                metrics.clone(),
+            );
+
+            create_repository(
+                request,
+                metadata_provider,
+                auth_service_ref,
+                ".reporoller",
+                Arc::new(MockVisibilityPolicyProvider),
+                Arc::new(MockEnvironmentDetector),
+                event_context,
+                dependencies,
+            )
+        },
+    )
+    .await
+    .expect("batch should run");
+
+    assert!(results
+        .iter()
+        .all(|r| matches!(r.status, crate::batch::BatchItemStatus::Created(_))));
+    assert_eq!(auth_service.refreshes.load(Ordering::SeqCst), 1);
+    server.verify().await;
+}
+
+// --- METADATA PROVIDER CONFIGURATION TESTS ---
+
+/// `global/defaults.toml` setting the `cost_center` custom property to `platform`.
+const METADATA_DEFAULTS_BASE64: &str =
+    "W1tjdXN0b21fcHJvcGVydGllc11dCnByb3BlcnR5X25hbWUgPSAiY29zdF9jZW50ZXIiCnZhbHVlID0gInBsYXRmb3JtIgo=";
+
+/// Mounts the GitHub API calls of an empty-repository creation of
+/// `test-org/new-repo` whose custom properties are expected to be set to
+/// `cost_center = platform` `expected_updates` times.
+async fn mount_empty_repository_creation(server: &wiremock::MockServer, expected_updates: u64) {
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    mount_new_repository_lookup(server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/test-org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "test-org",
+            "default_repository_branch": "main"
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 123456789,
+            "node_id": "R_kgDONewRepo",
+            "name": "new-repo",
+            "full_name": "test-org/new-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/new-repo"
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-org/new-repo/custom-properties"))
+        .and(body_partial_json(serde_json::json!({
+            "properties": [{ "property_name": "cost_center", "value": "platform" }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(expected_updates)
+        .mount(server)
+        .await;
+}
+
+/// Mounts the `test-org/.reporoller` metadata repository. Bulk file fetches
+/// fail, so every metadata file is read individually.
+async fn mount_metadata_repository(server: &wiremock::MockServer) {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/.reporoller"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 987654321,
+            "node_id": "R_kgDOMetadata",
+            "name": ".reporoller",
+            "full_name": "test-org/.reporoller",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/.reporoller"
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(server)
+        .await;
+}
+
+/// Build a REST contents API response for a metadata repository file.
+fn metadata_file_response(file_path: &str, content_base64: &str) -> wiremock::ResponseTemplate {
+    let api_url = format!("https://api.github.com/repos/test-org/.reporoller/contents/{file_path}");
+    let html_url = format!("https://github.com/test-org/.reporoller/blob/main/{file_path}");
+    wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "name": file_path.rsplit('/').next().unwrap(),
+        "path": file_path,
+        "sha": "abc123def456",
+        "size": content_base64.len(),
+        "type": "file",
+        "content": content_base64,
+        "encoding": "base64",
+        "url": api_url,
+        "html_url": html_url,
+        "git_url": null,
+        "download_url": null,
+        "_links": { "self": api_url, "git": null, "html": html_url }
+    }))
+}
+
+/// Create `test-org/new-repo` as an empty repository against `server`.
+async fn create_empty_repository(
+    server: &wiremock::MockServer,
+    dependencies: CreationDependencies,
+) -> RepoRollerResult<RepositoryCreationResult> {
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(crate::event_secrets::EnvironmentSecretResolver::new()),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+
+    create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies
+            .with_git(Arc::new(MockGitOperations::default()))
+            .with_github_api_base_url(server.uri()),
+    )
+    .await
+}
+
+/// Verify that the organization configuration is read at the configured git
+/// ref, so configuration staged on a branch applies to the new repository.
+#[tokio::test]
+async fn test_create_repository_reads_metadata_at_configured_git_ref() {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::Mock;
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 1).await;
+    mount_metadata_repository(&server).await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .and(query_param("ref", "config-staging"))
+        .respond_with(metadata_file_response(
+            "global/defaults.toml",
+            METADATA_DEFAULTS_BASE64,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dependencies = CreationDependencies::new().with_metadata_provider_config(
+        config_manager::MetadataProviderConfig::explicit(".reporoller")
+            .with_git_ref("config-staging"),
+    );
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should succeed");
+
+    server.verify().await;
+}
+
+/// Verify that `${KEY}` placeholders in the organization configuration are
+/// filled from the configured interpolation variables.
+#[tokio::test]
+async fn test_create_repository_interpolates_metadata_variables() {
+    use wiremock::matchers::{method, path};
+    use wiremock::Mock;
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 1).await;
+    mount_metadata_repository(&server).await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        // `cost_center` set to `${COST_CENTER}`
+        .respond_with(metadata_file_response(
+            "global/defaults.toml",
+            "W1tjdXN0b21fcHJvcGVydGllc11dCnByb3BlcnR5X25hbWUgPSAiY29zdF9jZW50ZXIiCnZhbHVlID0gIiR7Q09TVF9DRU5URVJ9Igo=",
+        ))
+        .mount(&server)
+        .await;
+
+    let variables =
+        std::collections::HashMap::from([("COST_CENTER".to_string(), "platform".to_string())]);
+    let dependencies = CreationDependencies::new().with_metadata_provider_config(
+        config_manager::MetadataProviderConfig::explicit(".reporoller")
+            .with_interpolation_variables(variables),
+    );
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should succeed");
+
+    server.verify().await;
+}
+
+/// Verify that the organization configuration is read from a combined file
+/// when one is configured.
+#[tokio::test]
+async fn test_create_repository_reads_combined_metadata_file() {
+    use wiremock::matchers::{method, path};
+    use wiremock::Mock;
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 1).await;
+    mount_metadata_repository(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/.reporoller/contents/reporoller.toml"))
+        // `[[global.custom_properties]]` setting `cost_center` to `platform`
+        .respond_with(metadata_file_response(
+            "reporoller.toml",
+            "W1tnbG9iYWwuY3VzdG9tX3Byb3BlcnRpZXNdXQpwcm9wZXJ0eV9uYW1lID0gImNvc3RfY2VudGVyIgp2YWx1ZSA9ICJwbGF0Zm9ybSIK",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dependencies = CreationDependencies::new()
+        .with_combined_metadata_file(config_manager::DEFAULT_COMBINED_CONFIG_PATH);
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should succeed");
+
+    server.verify().await;
+}
+
+/// Verify that a metadata repository slower than the configured request
+/// timeout is abandoned and the organization defaults are used.
+#[tokio::test]
+async fn test_create_repository_applies_metadata_request_timeout() {
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 0).await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/.reporoller"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "id": 987654321,
+                    "name": ".reporoller",
+                    "full_name": "test-org/.reporoller",
+                    "url": "https://api.github.com/repos/test-org/.reporoller"
+                }))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(metadata_file_response(
+            "global/defaults.toml",
+            METADATA_DEFAULTS_BASE64,
+        ))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let dependencies = CreationDependencies::new().with_metadata_provider_config(
+        config_manager::MetadataProviderConfig::explicit(".reporoller")
+            .with_request_timeout(Duration::from_millis(100)),
+    );
+    let started = std::time::Instant::now();
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should fall back to the organization defaults");
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    server.verify().await;
+}
+
+/// Verify that metadata files are fetched one at a time when the configured
+/// concurrency limit is one.
+#[tokio::test]
+async fn test_create_repository_limits_concurrent_metadata_fetches() {
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 1).await;
+    mount_metadata_repository(&server).await;
+    let delay = Duration::from_millis(200);
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(
+            metadata_file_response("global/defaults.toml", METADATA_DEFAULTS_BASE64)
+                .set_delay(delay),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    for file_path in ["global/standard-labels.toml", "global/webhooks.toml"] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/repos/test-org/.reporoller/contents/{file_path}"
+            )))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "message": "Not Found" }))
+                    .set_delay(delay),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let dependencies = CreationDependencies::new().with_metadata_provider_config(
+        config_manager::MetadataProviderConfig::explicit(".reporoller")
+            .with_max_concurrent_fetches(1),
+    );
+    let started = std::time::Instant::now();
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should succeed");
+
+    // Each file is fetched once, so only sequential fetches take this long.
+    assert!(started.elapsed() >= delay * 3);
+    server.verify().await;
+}
+
+/// Verify that a throttled metadata file fetch is retried after the
+/// configured backoff.
+#[tokio::test]
+async fn test_create_repository_uses_metadata_fetch_backoff() {
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let server = wiremock::MockServer::start().await;
+    mount_empty_repository_creation(&server, 1).await;
+    mount_metadata_repository(&server).await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "message": "API rate limit exceeded",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        .respond_with(metadata_file_response(
+            "global/defaults.toml",
+            METADATA_DEFAULTS_BASE64,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Longer than the default backoff, so the configured value must be used.
+    let backoff = Duration::from_secs(1);
+    let dependencies = CreationDependencies::new().with_metadata_provider_config(
+        config_manager::MetadataProviderConfig::explicit(".reporoller").with_fetch_backoff(backoff),
+    );
+    let started = std::time::Instant::now();
+    create_empty_repository(&server, dependencies)
+        .await
+        .expect("Repository creation should succeed");
+
+    assert!(started.elapsed() >= backoff);
+    server.verify().await;
+}
+
+/// Verify that a taken repository name is reported to the notification
+/// endpoints the organization configuration defines.
+#[tokio::test]
+async fn test_create_repository_reports_taken_name_to_notification_endpoints() {
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let server = wiremock::MockServer::start().await;
+    mount_new_repository_lookup(&server, 0).await;
+    mount_metadata_repository(&server).await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/repos/test-org/.reporoller/contents/global/defaults.toml",
+        ))
+        // Webhook at `${HOOK_URL}` subscribed to `repository.creation_failed`
+        .respond_with(metadata_file_response(
+            "global/defaults.toml",
+            "W1tub3RpZmljYXRpb25zLm91dGJvdW5kX3dlYmhvb2tzXV0KdXJsID0gIiR7SE9PS19VUkx9IgpzZWNyZXQgPSAiaG9vay1zZWNyZXQiCmV2ZW50cyA9IFsicmVwb3NpdG9yeS5jcmVhdGlvbl9mYWlsZWQiXQo=",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hooks"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/test-org/repos"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let variables = std::collections::HashMap::from([(
+        "HOOK_URL".to_string(),
+        format!("{}/hooks", server.uri()),
+    )]);
+    let dependencies = CreationDependencies::new()
+        .with_metadata_provider_config(
+            config_manager::MetadataProviderConfig::explicit(".reporoller")
+                .with_interpolation_variables(variables),
+        )
+        .with_git(Arc::new(MockGitOperations::default()))
+        .with_github_api_base_url(server.uri());
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let test_registry = prometheus::Registry::new();
+    let event_context = EventNotificationContext::new(
+        "unit-test",
+        Arc::new(MockSecretResolver),
+        Arc::new(crate::event_metrics::PrometheusEventMetrics::new(
+            &test_registry,
+        )),
+    );
+
+    let result = create_repository(
+        request,
+        &MockMetadataProvider::empty(),
+        &MockTokenAuthService,
+        ".reporoller",
+        Arc::new(MockVisibilityPolicyProvider),
+        Arc::new(MockEnvironmentDetector),
+        event_context,
+        dependencies,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(RepoRollerError::Validation(
+            ValidationError::NameTaken { .. }
+        ))
+    ));
+
+    // Failure notifications are delivered in the background.
+    let mut hook_requests = Vec::new();
+    for _ in 0..50 {
+        hook_requests = server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|request| request.url.path() == "/hooks")
+            .collect();
+        if !hook_requests.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(hook_requests.len(), 1);
+    let payload: serde_json::Value =
+        serde_json::from_slice(&hook_requests[0].body).expect("Payload should be JSON");
+    assert_eq!(payload["event_type"], "repository.creation_failed");
+    server.verify().await;
+}
+
+// --- CONFIGURATION VARIABLE EXTRACTION TESTS ---
+
+/// Verify that extract_config_variables returns empty map for default configuration.
+#[test]
+fn test_extract_config_variables_empty_config() {
+    let merged_config = config_manager::MergedConfiguration::new();
+    let variables = extract_config_variables(&merged_config);
+
+    // Default config should have no explicitly set values, so we get default boolean values
+    assert_eq!(
+        variables.get("config_issues_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_wiki_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_projects_enabled"),
+        Some(&"false".to_string())
+    );
+}
+
+/// Verify that repository feature settings are correctly extracted as config variables.
+#[test]
+fn test_extract_config_variables_repository_features() {
+    use config_manager::{settings::RepositorySettings, MergedConfiguration, OverridableValue};
+
+    let mut merged_config = MergedConfiguration::new();
+    merged_config.repository = RepositorySettings {
+        issues: Some(OverridableValue::allowed(true)),
+        wiki: Some(OverridableValue::fixed(false)),
+        projects: Some(OverridableValue::allowed(true)),
+        discussions: Some(OverridableValue::allowed(false)),
+        pages: Some(OverridableValue::allowed(true)),
+        security_advisories: Some(OverridableValue::allowed(true)),
+        vulnerability_reporting: Some(OverridableValue::allowed(false)),
+        auto_close_issues: Some(OverridableValue::allowed(false)),
+    };
+
+    let variables = extract_config_variables(&merged_config);
+
+    assert_eq!(
+        variables.get("config_issues_enabled"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_wiki_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_projects_enabled"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_discussions_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_pages_enabled"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_security_advisories_enabled"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_vulnerability_reporting_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_auto_close_issues_enabled"),
+        Some(&"false".to_string())
+    );
+}
+
+/// Verify that pull request settings are correctly extracted as config variables.
+#[test]
+fn test_extract_config_variables_pull_request_settings() {
+    use config_manager::{settings::PullRequestSettings, MergedConfiguration, OverridableValue};
+
+    let mut merged_config = MergedConfiguration::new();
+    merged_config.pull_requests = PullRequestSettings {
+        required_approving_review_count: Some(OverridableValue::allowed(2)),
+        allow_merge_commit: Some(OverridableValue::allowed(true)),
+        allow_squash_merge: Some(OverridableValue::allowed(false)),
+        allow_rebase_merge: Some(OverridableValue::allowed(true)),
+        allow_auto_merge: Some(OverridableValue::allowed(false)),
+        delete_branch_on_merge: Some(OverridableValue::allowed(true)),
+        ..Default::default()
+    };
+
+    let variables = extract_config_variables(&merged_config);
+
+    assert_eq!(
+        variables.get("config_required_approving_review_count"),
+        Some(&"2".to_string())
+    );
+    assert_eq!(
+        variables.get("config_allow_merge_commit"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_allow_squash_merge"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_allow_rebase_merge"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(
+        variables.get("config_allow_auto_merge"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_delete_branch_on_merge"),
+        Some(&"true".to_string())
+    );
+}
+
+/// Verify that all config variables use the "config_" prefix to avoid naming conflicts.
+#[test]
+fn test_extract_config_variables_uses_prefix() {
+    use config_manager::{settings::RepositorySettings, MergedConfiguration, OverridableValue};
+
+    let mut merged_config = MergedConfiguration::new();
+    merged_config.repository = RepositorySettings {
+        issues: Some(OverridableValue::allowed(true)),
+        ..Default::default()
+    };
+
+    let variables = extract_config_variables(&merged_config);
+
+    // Verify all keys start with "config_"
+    for key in variables.keys() {
+        assert!(
+            key.starts_with("config_"),
+            "Variable '{}' should start with 'config_'",
+            key
+        );
+    }
+}
+
+/// Verify that None values in configuration are handled correctly.
+#[test]
+fn test_extract_config_variables_none_values() {
+    use config_manager::{settings::RepositorySettings, MergedConfiguration};
+
+    let mut merged_config = MergedConfiguration::new();
+    merged_config.repository = RepositorySettings {
+        issues: None, // Explicitly set to None
+        wiki: None,
+        projects: None,
+        discussions: None,
+        pages: None,
+        security_advisories: None,
+        vulnerability_reporting: None,
+        auto_close_issues: None,
+    };
+
+    let variables = extract_config_variables(&merged_config);
+
+    // None values should result in "false" as the default
+    assert_eq!(
+        variables.get("config_issues_enabled"),
+        Some(&"false".to_string())
+    );
+    assert_eq!(
+        variables.get("config_wiki_enabled"),
         Some(&"false".to_string())
     );
 }
 
-/// Verify that repository feature settings are correctly extracted as config variables.
-#[test]
-fn test_extract_config_variables_repository_features() {
-    use config_manager::{settings::RepositorySettings, MergedConfiguration, OverridableValue};
+// ─── merge_access_map_with_policy ─────────────────────────────────────────────
+
+/// Convenience helper: build a HashMap<String,String> from (&str, &str) pairs.
+fn str_map(pairs: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// Convenience helper: build a HashSet<String> from &[&str].
+fn str_set(items: &[&str]) -> std::collections::HashSet<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+/// Verify that config entries without any request overrides are included as-is.
+#[test]
+fn test_merge_policy_config_only_entries_preserved() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-a", "write"), ("team-b", "read")]);
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &[], "team");
+
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Write));
+    assert_eq!(result.get("team-b"), Some(&AccessLevel::Read));
+}
+
+/// Verify that a request can add a new entry not present in config.
+#[test]
+fn test_merge_policy_request_adds_new_entry() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-a", "read")]);
+    let request = [("new-team", AccessLevel::Triage)];
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+
+    assert_eq!(result.get("new-team"), Some(&AccessLevel::Triage));
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Read));
+}
+
+/// Verify that a request can upgrade (promote) an existing config entry.
+#[test]
+fn test_merge_policy_request_can_upgrade_config_entry() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-a", "read")]);
+    let request = [("team-a", AccessLevel::Write)];
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Write));
+}
+
+/// Verify that a request cannot demote an existing config entry (no-demotion rule).
+#[test]
+fn test_merge_policy_request_cannot_demote_config_entry() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-a", "maintain")]);
+    let request = [("team-a", AccessLevel::Read)];
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+
+    // Must keep config level, not the requested demotion.
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Maintain));
+}
+
+/// Verify that a locked entry is not altered by a request.
+#[test]
+fn test_merge_policy_locked_entry_not_altered_by_request() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("locked-team", "write")]);
+    let locked = str_set(&["locked-team"]);
+    let request = [("locked-team", AccessLevel::Admin)];
+    let result = merge_access_map_with_policy(&config, &locked, None, &request, "team");
+
+    // Must remain at config level despite the upgrade attempt.
+    assert_eq!(result.get("locked-team"), Some(&AccessLevel::Write));
+}
+
+/// Verify that a locked entry that does NOT exist in config is still ignored when
+/// a request tries to set it. In production this combination (locked but not in config)
+/// is unreachable because `locked` is only populated from entries that already appear
+/// in `config_entries`. The correct safe behaviour is to always ignore requests for
+/// locked identifiers regardless.
+#[test]
+fn test_merge_policy_locked_entry_absent_from_config_ignores_request() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[]);
+    let locked = str_set(&["ghost-team"]);
+    let request = [("ghost-team", AccessLevel::Triage)];
+    let result = merge_access_map_with_policy(&config, &locked, None, &request, "team");
+
+    // The request value must NOT be inserted for a locked identifier.
+    assert_eq!(result.get("ghost-team"), None);
+}
+
+/// Verify that a request entry exceeding the org ceiling is capped at the ceiling.
+#[test]
+fn test_merge_policy_request_capped_at_ceiling() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[]);
+    let request = [("new-team", AccessLevel::Admin)];
+    let result =
+        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
+
+    assert_eq!(result.get("new-team"), Some(&AccessLevel::Write));
+}
+
+/// Verify that a request entry at exactly the ceiling is accepted unchanged.
+#[test]
+fn test_merge_policy_request_at_ceiling_is_accepted() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[]);
+    let request = [("new-team", AccessLevel::Write)];
+    let result =
+        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
+
+    assert_eq!(result.get("new-team"), Some(&AccessLevel::Write));
+}
+
+/// Verify that ceiling does not affect config entries (ceiling applies to request entries only).
+#[test]
+fn test_merge_policy_ceiling_does_not_affect_config_entries() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-a", "admin")]);
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &[], "team");
+
+    // Config entry with admin must remain even though ceiling is "write".
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Admin));
+}
+
+/// Verify ceiling + no-demotion interact correctly: after cap, if capped level is
+/// still below config level the demotion rule fires and the config value wins.
+#[test]
+fn test_merge_policy_ceiling_then_no_demotion_preserves_higher_config_level() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    // Config has "maintain", ceiling is "write", request asks for "admin".
+    // After capping: "write". "write" < "maintain" → demotion rule → keep "maintain".
+    let config = str_map(&[("team-a", "maintain")]);
+    let request = [("team-a", AccessLevel::Admin)];
+    let result =
+        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
+
+    assert_eq!(result.get("team-a"), Some(&AccessLevel::Maintain));
+}
+
+/// Verify that an invalid config level string is skipped gracefully.
+#[test]
+fn test_merge_policy_invalid_config_level_is_skipped() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let config = str_map(&[("team-bad", "ultramax"), ("team-ok", "read")]);
+    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &[], "team");
+
+    // Invalid entry silently dropped; valid entry preserved.
+    assert!(!result.contains_key("team-bad"));
+    assert_eq!(result.get("team-ok"), Some(&AccessLevel::Read));
+}
+
+/// Verify that an invalid ceiling string is treated as no ceiling (warning, not error).
+#[test]
+fn test_merge_policy_invalid_ceiling_treated_as_no_ceiling() {
+    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+
+    let request = [("new-team", AccessLevel::Admin)];
+    let result = merge_access_map_with_policy(
+        &str_map(&[]),
+        &str_set(&[]),
+        Some("not_a_level"),
+        &request,
+        "team",
+    );
+
+    // Ceiling is invalid, so no ceiling applied; Admin request accepted as-is.
+    assert_eq!(result.get("new-team"), Some(&AccessLevel::Admin));
+}
+
+// --- CONFIGURATION WARNING TESTS ---
 
-    let mut merged_config = MergedConfiguration::new();
-    merged_config.repository = RepositorySettings {
-        issues: Some(OverridableValue::allowed(true)),
-        wiki: Some(OverridableValue::fixed(false)),
-        projects: Some(OverridableValue::allowed(true)),
-        discussions: Some(OverridableValue::allowed(false)),
-        pages: Some(OverridableValue::allowed(true)),
-        security_advisories: Some(OverridableValue::allowed(true)),
-        vulnerability_reporting: Some(OverridableValue::allowed(false)),
-        auto_close_issues: Some(OverridableValue::allowed(false)),
+/// A webhook without a secret produces a non-blocking warning that is carried
+/// in the creation result.
+#[tokio::test]
+async fn test_webhook_without_secret_produces_warning_in_result() {
+    let mut merged_config = config_manager::MergedConfiguration::default();
+    merged_config.webhooks.push(WebhookConfig {
+        url: "https://example.com/hook".to_string(),
+        content_type: "json".to_string(),
+        secret: None,
+        active: true,
+        events: vec!["push".to_string()],
+    });
+
+    let warnings = collect_configuration_warnings(&merged_config).await;
+    let result = RepositoryCreationResult {
+        repository_url: "https://github.com/test-org/test-repo".to_string(),
+        repository_id: "R_kgDOABCDEF".to_string(),
+        created_at: Timestamp::now(),
+        default_branch: "main".to_string(),
+        warnings,
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
-    let variables = extract_config_variables(&merged_config);
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].field_path, "webhooks[0].secret");
+    assert!(result.warnings[0].recommendation.is_some());
+}
 
+/// A fully configured webhook produces no warnings.
+#[tokio::test]
+async fn test_webhook_with_secret_produces_no_warnings() {
+    let mut merged_config = config_manager::MergedConfiguration::default();
+    merged_config.webhooks.push(WebhookConfig {
+        url: "https://example.com/hook".to_string(),
+        content_type: "json".to_string(),
+        secret: Some("webhook-secret".to_string()),
+        active: true,
+        events: vec!["push".to_string()],
+    });
+
+    let warnings = collect_configuration_warnings(&merged_config).await;
+    assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+}
+
+/// Builds a prepared local repository and a request for initial commit tests.
+fn initial_commit_fixture() -> (TempDir, RepositoryCreationRequest) {
+    let local_repo = TempDir::new().unwrap();
+    std::fs::create_dir_all(local_repo.path().join("src")).unwrap();
+    std::fs::create_dir_all(local_repo.path().join(".git")).unwrap();
+    std::fs::write(local_repo.path().join("README.md"), "# test-repo").unwrap();
+    std::fs::write(
+        local_repo.path().join("src").join("main.rs"),
+        "fn main() {}",
+    )
+    .unwrap();
+    std::fs::write(local_repo.path().join(".git").join("config"), "[core]").unwrap();
+
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("test-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+
+    (local_repo, request)
+}
+
+/// Verify that the initial commit is created through the API when signed
+/// commits are requested, with the full tree and no git push.
+#[tokio::test]
+async fn test_publish_initial_commit_uses_api_when_signed() {
+    let (local_repo, request) = initial_commit_fixture();
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        initial_commit_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+
+    let git = MockGitOperations::default();
+
+    publish_initial_commit(
+        &client,
+        &git,
+        &request,
+        &local_repo,
+        url::Url::parse("https://github.invalid/test-org/test-repo").unwrap(),
+        "main",
+        "ghs_mock_token",
+        true,
+        false,
+    )
+    .await
+    .expect("API commit should succeed without pushing");
+
+    assert!(git.calls().is_empty());
+
+    let calls = tracker.lock().unwrap();
     assert_eq!(
-        variables.get("config_issues_enabled"),
-        Some(&"true".to_string())
-    );
-    assert_eq!(
-        variables.get("config_wiki_enabled"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_projects_enabled"),
-        Some(&"true".to_string())
-    );
-    assert_eq!(
-        variables.get("config_discussions_enabled"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_pages_enabled"),
-        Some(&"true".to_string())
-    );
-    assert_eq!(
-        variables.get("config_security_advisories_enabled"),
-        Some(&"true".to_string())
+        *calls,
+        vec![(
+            "main".to_string(),
+            vec!["README.md".to_string(), "src/main.rs".to_string()]
+        )]
     );
+}
+
+/// Verify that the API commit path is not used unless signed commits are requested.
+#[tokio::test]
+async fn test_publish_initial_commit_pushes_when_not_signed() {
+    let (local_repo, request) = initial_commit_fixture();
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        initial_commit_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+
+    let git = MockGitOperations::default();
+
+    publish_initial_commit(
+        &client,
+        &git,
+        &request,
+        &local_repo,
+        url::Url::parse("https://github.invalid/test-org/test-repo").unwrap(),
+        "main",
+        "ghs_mock_token",
+        false,
+        false,
+    )
+    .await
+    .expect("push should succeed");
+
     assert_eq!(
-        variables.get("config_vulnerability_reporting_enabled"),
-        Some(&"false".to_string())
+        git.calls(),
+        vec!["push https://github.invalid/test-org/test-repo main force=false".to_string()]
     );
+    assert!(tracker.lock().unwrap().is_empty());
+}
+
+/// Verify that the pull request proposes the review branch for merging into
+/// the default branch and describes the generated content.
+#[tokio::test]
+async fn test_open_review_pull_request_targets_default_branch() {
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        pull_request_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("test-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .template(TemplateName::new("rust-library").unwrap())
+    .review_branch("template-init")
+    .build();
+
+    open_review_pull_request(&client, &request, "template-init", "main")
+        .await
+        .expect("pull request should be opened");
+
+    let calls = tracker.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    let (head, base, title, body) = &calls[0];
+    assert_eq!(head, "template-init");
+    assert_eq!(base, "main");
+    assert_eq!(title, "Initial repository content");
     assert_eq!(
-        variables.get("config_auto_close_issues_enabled"),
-        Some(&"false".to_string())
+        body,
+        "This pull request adds the initial content of `test-org/test-repo`, \
+         generated from the `rust-library` template.\n\n\
+         Review and merge it to land the scaffold on `main`."
     );
 }
 
-/// Verify that pull request settings are correctly extracted as config variables.
+/// Verify the rules for choosing a review branch.
 #[test]
-fn test_extract_config_variables_pull_request_settings() {
-    use config_manager::{settings::PullRequestSettings, MergedConfiguration, OverridableValue};
+fn test_validate_review_branch() {
+    assert!(validate_review_branch("template-init", "main", false).is_ok());
 
-    let mut merged_config = MergedConfiguration::new();
-    merged_config.pull_requests = PullRequestSettings {
-        required_approving_review_count: Some(OverridableValue::allowed(2)),
-        allow_merge_commit: Some(OverridableValue::allowed(true)),
-        allow_squash_merge: Some(OverridableValue::allowed(false)),
-        allow_rebase_merge: Some(OverridableValue::allowed(true)),
-        allow_auto_merge: Some(OverridableValue::allowed(false)),
-        delete_branch_on_merge: Some(OverridableValue::allowed(true)),
-        ..Default::default()
+    for (branch, use_api_commit) in [("main", false), ("", false), ("template-init", true)] {
+        let result = validate_review_branch(branch, "main", use_api_commit);
+        assert!(
+            matches!(
+                result,
+                Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+                    if field == "review_branch"
+            ),
+            "branch '{branch}' (signed: {use_api_commit}) got {result:?}"
+        );
+    }
+}
+
+/// Verify that the review branch is made a valid git branch name.
+#[test]
+fn test_sanitize_review_branch() {
+    let build = |branch: Option<&str>| {
+        let mut builder = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        );
+        if let Some(branch) = branch {
+            builder = builder.review_branch(branch);
+        }
+        builder.build()
     };
 
-    let variables = extract_config_variables(&merged_config);
+    let mut request = build(Some("template init/v1..2"));
+    sanitize_review_branch(&mut request).unwrap();
+    assert_eq!(request.review_branch.as_deref(), Some("template-init/v1.2"));
 
-    assert_eq!(
-        variables.get("config_required_approving_review_count"),
-        Some(&"2".to_string())
-    );
-    assert_eq!(
-        variables.get("config_allow_merge_commit"),
-        Some(&"true".to_string())
-    );
-    assert_eq!(
-        variables.get("config_allow_squash_merge"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_allow_rebase_merge"),
-        Some(&"true".to_string())
-    );
-    assert_eq!(
-        variables.get("config_allow_auto_merge"),
-        Some(&"false".to_string())
-    );
-    assert_eq!(
-        variables.get("config_delete_branch_on_merge"),
-        Some(&"true".to_string())
-    );
+    let mut request = build(None);
+    sanitize_review_branch(&mut request).unwrap();
+    assert_eq!(request.review_branch, None);
+
+    let mut request = build(Some(".."));
+    assert!(matches!(
+        sanitize_review_branch(&mut request),
+        Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+            if field == "review_branch"
+    ));
 }
 
-/// Verify that all config variables use the "config_" prefix to avoid naming conflicts.
+/// Verify that a configured default branch must be a valid git branch name.
 #[test]
-fn test_extract_config_variables_uses_prefix() {
-    use config_manager::{settings::RepositorySettings, MergedConfiguration, OverridableValue};
+fn test_validate_configured_default_branch() {
+    use config_manager::{MergedConfiguration, OverridableValue};
 
     let mut merged_config = MergedConfiguration::new();
-    merged_config.repository = RepositorySettings {
-        issues: Some(OverridableValue::allowed(true)),
-        ..Default::default()
-    };
+    assert!(validate_configured_default_branch(&merged_config).is_ok());
 
-    let variables = extract_config_variables(&merged_config);
+    merged_config.branch_protection.default_branch =
+        Some(OverridableValue::allowed("release/v1".to_string()));
+    assert!(validate_configured_default_branch(&merged_config).is_ok());
 
-    // Verify all keys start with "config_"
-    for key in variables.keys() {
+    for branch in ["release v1", "main.lock", "..", "-main"] {
+        merged_config.branch_protection.default_branch =
+            Some(OverridableValue::allowed(branch.to_string()));
+        let result = validate_configured_default_branch(&merged_config);
         assert!(
-            key.starts_with("config_"),
-            "Variable '{}' should start with 'config_'",
-            key
+            matches!(
+                result,
+                Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+                    if field == "branch_protection.default_branch"
+            ),
+            "branch '{branch}' got {result:?}"
         );
     }
 }
 
-/// Verify that None values in configuration are handled correctly.
+/// Verify that an existing repository name is rejected before any further work.
+#[tokio::test]
+async fn test_ensure_repository_name_available_rejects_taken_name() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_exists: true,
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("taken-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+
+    let result = ensure_repository_name_available(&client, &request).await;
+
+    match result {
+        Err(RepoRollerError::Validation(ValidationError::NameTaken { org, name })) => {
+            assert_eq!(org, "test-org");
+            assert_eq!(name, "taken-repo");
+        }
+        other => panic!("Expected NameTaken, got {:?}", other),
+    }
+}
+
+/// Verify that an unused repository name passes the availability check.
+#[tokio::test]
+async fn test_ensure_repository_name_available_accepts_free_name() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig::default());
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+
+    ensure_repository_name_available(&client, &request)
+        .await
+        .expect("Free name should be accepted");
+}
+
+/// Verify that resolved topics are applied to the new repository.
+#[tokio::test]
+async fn test_apply_repository_topics_replaces_topics() {
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        topics_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let topics = vec!["rust".to_string(), "service".to_string()];
+
+    apply_repository_topics(&client, &request, &topics).await;
+
+    assert_eq!(*tracker.lock().unwrap(), vec![topics]);
+}
+
+/// Verify that no topics call is made when there are no topics to apply.
+#[tokio::test]
+async fn test_apply_repository_topics_skips_empty_topics() {
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        topics_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+
+    apply_repository_topics(&client, &request, &[]).await;
+
+    assert!(tracker.lock().unwrap().is_empty());
+}
+
+/// Verify that internal visibility is sent as `visibility: "internal"` rather
+/// than degrading to the `private` flag.
 #[test]
-fn test_extract_config_variables_none_values() {
-    use config_manager::{settings::RepositorySettings, MergedConfiguration};
+fn test_repository_create_payload_serializes_internal_visibility() {
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
 
-    let mut merged_config = MergedConfiguration::new();
-    merged_config.repository = RepositorySettings {
-        issues: None, // Explicitly set to None
-        wiki: None,
-        projects: None,
-        discussions: None,
-        pages: None,
-        security_advisories: None,
-        vulnerability_reporting: None,
-        auto_close_issues: None,
+    let payload = repository_create_payload(
+        &request,
+        &config_manager::MergedConfiguration::new(),
+        visibility::RepositoryVisibility::Internal,
+        None,
+    );
+    let json = serde_json::to_value(&payload).unwrap();
+
+    assert_eq!(json["visibility"], "internal");
+    assert!(json.get("private").is_none());
+}
+
+/// Verify that public and private visibility map to the matching values.
+#[test]
+fn test_repository_create_payload_maps_public_and_private_visibility() {
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
+    let merged_config = config_manager::MergedConfiguration::new();
+
+    for (visibility, expected) in [
+        (visibility::RepositoryVisibility::Public, "public"),
+        (visibility::RepositoryVisibility::Private, "private"),
+    ] {
+        let payload = repository_create_payload(&request, &merged_config, visibility, None);
+        assert_eq!(payload.visibility.as_deref(), Some(expected));
+    }
+}
+
+/// Verify that the requested license key is sent so GitHub creates the
+/// LICENSE file, and is omitted when no license is requested.
+#[test]
+fn test_repository_create_payload_includes_license_template() {
+    let builder = || {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("new-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
     };
+    let merged_config = config_manager::MergedConfiguration::new();
 
-    let variables = extract_config_variables(&merged_config);
+    let payload = repository_create_payload(
+        &builder().license_template("apache-2.0").build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    let json = serde_json::to_value(&payload).unwrap();
+    assert_eq!(json["license_template"], "apache-2.0");
+
+    let payload = repository_create_payload(
+        &builder().build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    let json = serde_json::to_value(&payload).unwrap();
+    assert!(json.get("license_template").is_none());
+}
+
+/// Verify that the requested `.gitignore` template and auto-init reach the
+/// payload, and are omitted when not requested.
+#[test]
+fn test_repository_create_payload_includes_gitignore_template_and_auto_init() {
+    let builder = || {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("new-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+    };
+    let merged_config = config_manager::MergedConfiguration::new();
+
+    let payload = repository_create_payload(
+        &builder().gitignore_template("Rust").auto_init(true).build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    let json = serde_json::to_value(&payload).unwrap();
+    assert_eq!(json["gitignore_template"], "Rust");
+    assert_eq!(json["auto_init"], true);
+
+    let payload = repository_create_payload(
+        &builder().build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    let json = serde_json::to_value(&payload).unwrap();
+    assert!(json.get("gitignore_template").is_none());
+    assert!(json.get("auto_init").is_none());
+}
+
+/// Verify that the organization's scaffolding defaults are used unless the
+/// request overrides them.
+#[test]
+fn test_repository_create_payload_uses_org_scaffolding_defaults() {
+    let builder = || {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("new-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+    };
+    let mut merged_config = config_manager::MergedConfiguration::new();
+    merged_config.auto_init = true;
+    merged_config.gitignore_template = Some("Rust".to_string());
+    merged_config.license_template = Some("mit".to_string());
+
+    let payload = repository_create_payload(
+        &builder().build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    assert_eq!(payload.auto_init, Some(true));
+    assert_eq!(payload.gitignore_template.as_deref(), Some("Rust"));
+    assert_eq!(payload.license_template.as_deref(), Some("mit"));
+
+    let payload = repository_create_payload(
+        &builder()
+            .auto_init(false)
+            .gitignore_template("Node")
+            .license_template("apache-2.0")
+            .build(),
+        &merged_config,
+        visibility::RepositoryVisibility::Private,
+        None,
+    );
+    assert_eq!(payload.auto_init, None);
+    assert_eq!(payload.gitignore_template.as_deref(), Some("Node"));
+    assert_eq!(payload.license_template.as_deref(), Some("apache-2.0"));
+}
+
+/// Build a policy provider that reads the given `[repository_visibility]`
+/// settings from the organization's global defaults.
+fn global_visibility_policy(
+    policy: config_manager::VisibilityPolicyConfig,
+) -> Arc<dyn VisibilityPolicyProvider> {
+    Arc::new(config_manager::ConfigBasedPolicyProvider::new(Arc::new(
+        MockMetadataProvider::with_global_defaults(config_manager::GlobalDefaults {
+            repository_visibility: Some(policy),
+            ..Default::default()
+        }),
+    )))
+}
+
+/// Verify that the visibility requested on the request overrides the
+/// template default when the global policy allows it.
+#[tokio::test]
+async fn test_resolve_repository_visibility_requested_value_overrides_template() {
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .with_visibility(visibility::RepositoryVisibility::Private)
+    .build();
+    let mut template = static_template_config();
+    template.default_visibility = Some(visibility::RepositoryVisibility::Public);
+    let policy = global_visibility_policy(config_manager::VisibilityPolicyConfig {
+        enforcement_level: "restricted".to_string(),
+        required_visibility: None,
+        restricted_visibilities: Some(vec!["internal".to_string()]),
+    });
+
+    let decision = resolve_repository_visibility(
+        &request,
+        Some(&template),
+        policy,
+        Arc::new(MockEnvironmentDetector),
+    )
+    .await
+    .expect("Private is allowed by the global policy");
 
-    // None values should result in "false" as the default
-    assert_eq!(
-        variables.get("config_issues_enabled"),
-        Some(&"false".to_string())
-    );
     assert_eq!(
-        variables.get("config_wiki_enabled"),
-        Some(&"false".to_string())
+        decision.visibility,
+        visibility::RepositoryVisibility::Private
     );
+    assert_eq!(decision.source, visibility::DecisionSource::UserPreference);
 }
 
-// ─── merge_access_map_with_policy ─────────────────────────────────────────────
+/// Verify that requesting a visibility the global policy fixes to another
+/// value fails instead of being silently replaced.
+#[tokio::test]
+async fn test_resolve_repository_visibility_rejects_value_forbidden_by_global_policy() {
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .with_visibility(visibility::RepositoryVisibility::Public)
+    .build();
+    let policy = global_visibility_policy(config_manager::VisibilityPolicyConfig {
+        enforcement_level: "required".to_string(),
+        required_visibility: Some("private".to_string()),
+        restricted_visibilities: None,
+    });
 
-/// Convenience helper: build a HashMap<String,String> from (&str, &str) pairs.
-fn str_map(pairs: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
-    pairs
-        .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
-        .collect()
-}
+    let result =
+        resolve_repository_visibility(&request, None, policy, Arc::new(MockEnvironmentDetector))
+            .await;
 
-/// Convenience helper: build a HashSet<String> from &[&str].
-fn str_set(items: &[&str]) -> std::collections::HashSet<String> {
-    items.iter().map(|s| s.to_string()).collect()
+    match result {
+        Err(RepoRollerError::Configuration(ConfigurationError::InvalidConfiguration {
+            field,
+            reason,
+        })) => {
+            assert_eq!(field, "visibility");
+            assert!(reason.contains("violates organization policy"), "{reason}");
+        }
+        other => panic!("Expected a visibility policy violation, got {other:?}"),
+    }
 }
 
-/// Verify that config entries without any request overrides are included as-is.
-#[test]
-fn test_merge_policy_config_only_entries_preserved() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that repositories owned by a personal account are created through
+/// the user endpoint rather than the organization endpoint.
+#[tokio::test]
+async fn test_create_github_repository_uses_user_endpoint_for_user_owner() {
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_creation_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("octocat").unwrap(),
+    )
+    .owner_type(OwnerType::User)
+    .content_strategy(ContentStrategy::Empty)
+    .build();
 
-    let config = str_map(&[("team-a", "write"), ("team-b", "read")]);
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &[], "team");
+    let repo = create_github_repository(
+        &request,
+        &config_manager::MergedConfiguration::new(),
+        &client,
+        visibility::RepositoryVisibility::Private,
+        None,
+    )
+    .await
+    .expect("user repository should be created");
 
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Write));
-    assert_eq!(result.get("team-b"), Some(&AccessLevel::Read));
+    assert_eq!(repo.name(), "new-repo");
+    assert_eq!(*tracker.lock().unwrap(), vec!["user".to_string()]);
 }
 
-/// Verify that a request can add a new entry not present in config.
-#[test]
-fn test_merge_policy_request_adds_new_entry() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that organization-owned repositories are created in the owner
+/// organization.
+#[tokio::test]
+async fn test_create_github_repository_uses_org_endpoint_by_default() {
+    let tracker = Arc::new(Mutex::new(Vec::new()));
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_creation_tracker: Some(tracker.clone()),
+        ..Default::default()
+    });
+    let request = RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .build();
 
-    let config = str_map(&[("team-a", "read")]);
-    let request = [("new-team", AccessLevel::Triage)];
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+    create_github_repository(
+        &request,
+        &config_manager::MergedConfiguration::new(),
+        &client,
+        visibility::RepositoryVisibility::Private,
+        None,
+    )
+    .await
+    .expect("organization repository should be created");
 
-    assert_eq!(result.get("new-team"), Some(&AccessLevel::Triage));
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Read));
+    assert_eq!(*tracker.lock().unwrap(), vec!["org:test-org".to_string()]);
 }
 
-/// Verify that a request can upgrade (promote) an existing config entry.
+/// Verify that copy sources must name a repository and that full-history copies
+/// reject options that need a locally created commit.
 #[test]
-fn test_merge_policy_request_can_upgrade_config_entry() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
-
-    let config = str_map(&[("team-a", "read")]);
-    let request = [("team-a", AccessLevel::Write)];
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+fn test_validate_copy_source() {
+    let builder = |source: &str, copy_full_history| {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("new-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::CopyFromRepository {
+            source: source.to_string(),
+            copy_full_history,
+        })
+    };
 
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Write));
+    assert!(validate_copy_source(&builder("test-org/existing-repo", true).build()).is_ok());
+    assert!(validate_copy_source(
+        &builder("test-org/existing-repo", false)
+            .review_branch("template-init")
+            .build()
+    )
+    .is_ok());
+
+    let cases = [
+        (
+            "content_strategy.source",
+            builder("existing-repo", false).build(),
+        ),
+        (
+            "content_strategy.source",
+            builder("test-org/nested/repo", false).build(),
+        ),
+        (
+            "review_branch",
+            builder("test-org/existing-repo", true)
+                .review_branch("template-init")
+                .build(),
+        ),
+        (
+            "signed_initial_commit",
+            builder("test-org/existing-repo", true)
+                .signed_initial_commit(true)
+                .build(),
+        ),
+    ];
+    for (expected, request) in cases {
+        let result = validate_copy_source(&request);
+        assert!(
+            matches!(
+                result,
+                Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+                    if field == expected
+            ),
+            "expected {expected} to be rejected, got {result:?}"
+        );
+    }
 }
 
-/// Verify that a request cannot demote an existing config entry (no-demotion rule).
+/// Verify that organization-only request settings are rejected for personal
+/// repositories and accepted for organization repositories.
 #[test]
-fn test_merge_policy_request_cannot_demote_config_entry() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
-
-    let config = str_map(&[("team-a", "maintain")]);
-    let request = [("team-a", AccessLevel::Read)];
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &request, "team");
+fn test_validate_owner_type() {
+    let builder = |owner_type| {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("new-repo").unwrap(),
+            OrganizationName::new("octocat").unwrap(),
+        )
+        .owner_type(owner_type)
+        .content_strategy(ContentStrategy::Empty)
+    };
+    let mut teams = std::collections::HashMap::new();
+    teams.insert(
+        "platform".to_string(),
+        crate::permissions::AccessLevel::Write,
+    );
 
-    // Must keep config level, not the requested demotion.
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Maintain));
+    assert!(validate_owner_type(&builder(OwnerType::User).build()).is_ok());
+    assert!(validate_owner_type(
+        &builder(OwnerType::Organization)
+            .teams(teams.clone())
+            .build()
+    )
+    .is_ok());
+
+    let cases = [
+        ("teams", builder(OwnerType::User).teams(teams).build()),
+        (
+            "owning_teams",
+            builder(OwnerType::User)
+                .owning_teams(vec!["platform".to_string()])
+                .build(),
+        ),
+        (
+            "visibility",
+            builder(OwnerType::User)
+                .with_visibility(visibility::RepositoryVisibility::Internal)
+                .build(),
+        ),
+    ];
+    for (expected, request) in cases {
+        let result = validate_owner_type(&request);
+        assert!(
+            matches!(
+                result,
+                Err(RepoRollerError::Validation(ValidationError::InvalidFormat { ref field, .. }))
+                    if field == expected
+            ),
+            "expected {expected} to be rejected, got {result:?}"
+        );
+    }
 }
 
-/// Verify that a locked entry is not altered by a request.
-#[test]
-fn test_merge_policy_locked_entry_not_altered_by_request() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that the organization's default branch is used when it can be read.
+#[tokio::test]
+async fn test_resolve_default_branch_uses_organization_setting() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        default_branch: MockDefaultBranchBehavior::Branch("trunk".to_string()),
+        ..Default::default()
+    });
 
-    let config = str_map(&[("locked-team", "write")]);
-    let locked = str_set(&["locked-team"]);
-    let request = [("locked-team", AccessLevel::Admin)];
-    let result = merge_access_map_with_policy(&config, &locked, None, &request, "team");
+    let resolution = resolve_default_branch(&client, "test-org")
+        .await
+        .expect("Default branch should resolve");
 
-    // Must remain at config level despite the upgrade attempt.
-    assert_eq!(result.get("locked-team"), Some(&AccessLevel::Write));
+    assert_eq!(resolution.name, "trunk");
+    assert!(resolution.fallback_warning.is_none());
 }
 
-/// Verify that a locked entry that does NOT exist in config is still ignored when
-/// a request tries to set it. In production this combination (locked but not in config)
-/// is unreachable because `locked` is only populated from entries that already appear
-/// in `config_entries`. The correct safe behaviour is to always ignore requests for
-/// locked identifiers regardless.
-#[test]
-fn test_merge_policy_locked_entry_absent_from_config_ignores_request() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that an organization default branch that git would reject is made
+/// a valid branch name.
+#[tokio::test]
+async fn test_resolve_default_branch_sanitizes_invalid_name() {
+    for (setting, expected) in [("release v1..2", "release-v1.2"), ("..", "main")] {
+        let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+            default_branch: MockDefaultBranchBehavior::Branch(setting.to_string()),
+            ..Default::default()
+        });
+
+        let resolution = resolve_default_branch(&client, "test-org")
+            .await
+            .expect("Default branch should resolve");
+
+        assert_eq!(resolution.name, expected, "setting '{setting}'");
+    }
+}
 
-    let config = str_map(&[]);
-    let locked = str_set(&["ghost-team"]);
-    let request = [("ghost-team", AccessLevel::Triage)];
-    let result = merge_access_map_with_policy(&config, &locked, None, &request, "team");
+/// Verify that missing permission falls back to `main` and reports the fallback.
+#[tokio::test]
+async fn test_resolve_default_branch_falls_back_when_forbidden() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        default_branch: MockDefaultBranchBehavior::Forbidden,
+        ..Default::default()
+    });
+
+    let resolution = resolve_default_branch(&client, "test-org")
+        .await
+        .expect("Permission errors should fall back");
+
+    assert_eq!(resolution.name, "main");
+    let warning = resolution
+        .fallback_warning
+        .expect("Fallback should be reported");
+    assert_eq!(warning.field_path, "default_branch");
+    assert!(warning.message.contains("test-org"));
+}
 
-    // The request value must NOT be inserted for a locked identifier.
-    assert_eq!(result.get("ghost-team"), None);
+/// Verify that a transient failure is propagated rather than masked.
+#[tokio::test]
+async fn test_resolve_default_branch_propagates_transient_error() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        default_branch: MockDefaultBranchBehavior::InvalidResponse,
+        ..Default::default()
+    });
+
+    let result = resolve_default_branch(&client, "test-org").await;
+
+    assert!(
+        matches!(
+            result,
+            Err(RepoRollerError::GitHub(
+                crate::GitHubError::NetworkError { .. }
+            ))
+        ),
+        "got {result:?}"
+    );
 }
 
-/// Verify that a request entry exceeding the org ceiling is capped at the ceiling.
+fn collaborator_request(
+    collaborators: &[(&str, crate::permissions::AccessLevel)],
+) -> RepositoryCreationRequest {
+    RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .content_strategy(ContentStrategy::Empty)
+    .collaborators(
+        collaborators
+            .iter()
+            .map(|(username, level)| (username.to_string(), *level))
+            .collect(),
+    )
+    .build()
+}
+
+/// Verify that requested collaborators below admin access are accepted.
 #[test]
-fn test_merge_policy_request_capped_at_ceiling() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+fn test_validate_requested_collaborators_accepts_non_admin() {
+    use crate::permissions::AccessLevel;
 
-    let config = str_map(&[]);
-    let request = [("new-team", AccessLevel::Admin)];
-    let result =
-        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
+    let request = collaborator_request(&[
+        ("contractor", AccessLevel::Write),
+        ("reviewer", AccessLevel::Maintain),
+    ]);
 
-    assert_eq!(result.get("new-team"), Some(&AccessLevel::Write));
+    validate_requested_collaborators(&request).expect("Non-admin collaborators are allowed");
 }
 
-/// Verify that a request entry at exactly the ceiling is accepted unchanged.
+/// Verify that a request granting admin access to a collaborator is rejected.
 #[test]
-fn test_merge_policy_request_at_ceiling_is_accepted() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
-
-    let config = str_map(&[]);
-    let request = [("new-team", AccessLevel::Write)];
-    let result =
-        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
-
-    assert_eq!(result.get("new-team"), Some(&AccessLevel::Write));
+fn test_validate_requested_collaborators_rejects_admin() {
+    use crate::permissions::AccessLevel;
+
+    let request = collaborator_request(&[
+        ("contractor", AccessLevel::Write),
+        ("mallory", AccessLevel::Admin),
+    ]);
+
+    match validate_requested_collaborators(&request) {
+        Err(RepoRollerError::Validation(ValidationError::InvalidOption {
+            field, value, ..
+        })) => {
+            assert_eq!(field, "collaborators.mallory");
+            assert_eq!(value, "admin");
+        }
+        other => panic!("Expected InvalidOption, got {:?}", other),
+    }
 }
 
-/// Verify that ceiling does not affect config entries (ceiling applies to request entries only).
+/// Verify that timestamps taken from a fixed clock are deterministic.
 #[test]
-fn test_merge_policy_ceiling_does_not_affect_config_entries() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+fn test_timestamp_from_fixed_clock() {
+    use chrono::TimeZone;
 
-    let config = str_map(&[("team-a", "admin")]);
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &[], "team");
+    let instant = chrono::Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+    let clock = template_engine::FixedClock::new(instant);
 
-    // Config entry with admin must remain even though ceiling is "write".
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Admin));
+    let timestamp = Timestamp::from_clock(&clock);
+
+    assert_eq!(timestamp.as_datetime(), &instant);
+    assert_eq!(timestamp.to_string(), "2024-01-02T03:04:05+00:00");
 }
 
-/// Verify ceiling + no-demotion interact correctly: after cap, if capped level is
-/// still below config level the demotion rule fires and the config value wins.
-#[test]
-fn test_merge_policy_ceiling_then_no_demotion_preserves_higher_config_level() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Template configuration with no variables or templating section.
+fn static_template_config() -> TemplateConfig {
+    TemplateConfig {
+        template: TemplateMetadata {
+            name: "static".to_string(),
+            description: "Static template".to_string(),
+            author: "Test".to_string(),
+            tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
+        },
+        repository_type: None,
+        variables: None,
+        repository: None,
+        pull_requests: None,
+        branch_protection: None,
+        labels: None,
+        webhooks: None,
+        environments: None,
+        github_apps: None,
+        rulesets: None,
+        default_visibility: None,
+        templating: None,
+        notifications: None,
+        permissions: None,
+        teams: None,
+        collaborators: None,
+        naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
+    }
+}
 
-    // Config has "maintain", ceiling is "write", request asks for "admin".
-    // After capping: "write". "write" < "maintain" → demotion rule → keep "maintain".
-    let config = str_map(&[("team-a", "maintain")]);
-    let request = [("team-a", AccessLevel::Admin)];
-    let result =
-        merge_access_map_with_policy(&config, &str_set(&[]), Some("write"), &request, "team");
+fn static_template_request() -> RepositoryCreationRequest {
+    RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("new-repo").unwrap(),
+        OrganizationName::new("test-org").unwrap(),
+    )
+    .template(TemplateName::new("static").unwrap())
+    .build()
+}
 
-    assert_eq!(result.get("team-a"), Some(&AccessLevel::Maintain));
+/// Verify that a GitHub template repository needing no rendering is
+/// generated natively.
+#[tokio::test]
+async fn test_content_creation_path_is_native_when_no_templating_required() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(true),
+        ..Default::default()
+    });
+    let template = static_template_config();
+
+    let path = choose_content_creation_path(
+        &client,
+        &static_template_request(),
+        Some(&template),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
+
+    assert_eq!(path, ContentCreationPath::NativeTemplate);
 }
 
-/// Verify that an invalid config level string is skipped gracefully.
-#[test]
-fn test_merge_policy_invalid_config_level_is_skipped() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that requesting a license forces the render path, since GitHub's
+/// generate API cannot add one.
+#[tokio::test]
+async fn test_content_creation_path_is_render_when_license_requested() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(true),
+        ..Default::default()
+    });
+    let mut request = static_template_request();
+    request.license_template = Some("mit".to_string());
 
-    let config = str_map(&[("team-bad", "ultramax"), ("team-ok", "read")]);
-    let result = merge_access_map_with_policy(&config, &str_set(&[]), None, &[], "team");
+    let path = choose_content_creation_path(
+        &client,
+        &request,
+        Some(&static_template_config()),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
 
-    // Invalid entry silently dropped; valid entry preserved.
-    assert!(!result.contains_key("team-bad"));
-    assert_eq!(result.get("team-ok"), Some(&AccessLevel::Read));
+    assert_eq!(path, ContentCreationPath::Render);
 }
 
-/// Verify that an invalid ceiling string is treated as no ceiling (warning, not error).
-#[test]
-fn test_merge_policy_invalid_ceiling_treated_as_no_ceiling() {
-    use crate::{merge_access_map_with_policy, permissions::AccessLevel};
+/// Verify that a template composed from a base template always uses the
+/// render path, since native generation would only copy the top layer.
+#[tokio::test]
+async fn test_content_creation_path_is_render_when_template_has_base() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(true),
+        ..Default::default()
+    });
+    let mut template = static_template_config();
+    template.base_template = Some("base-layer".to_string());
+
+    let path = choose_content_creation_path(
+        &client,
+        &static_template_request(),
+        Some(&template),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
 
-    let request = [("new-team", AccessLevel::Admin)];
-    let result = merge_access_map_with_policy(
-        &str_map(&[]),
-        &str_set(&[]),
-        Some("not_a_level"),
+    assert_eq!(path, ContentCreationPath::Render);
+}
+
+/// Verify that variables, either declared by the template or supplied in
+/// the request, force the render path.
+#[tokio::test]
+async fn test_content_creation_path_is_render_when_variables_exist() {
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(true),
+        ..Default::default()
+    });
+
+    let mut template = static_template_config();
+    template.variables = Some(std::collections::HashMap::from([(
+        "service_name".to_string(),
+        config_manager::TemplateVariable {
+            description: "Service name".to_string(),
+            example: None,
+            required: Some(true),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: None,
+            required_if: None,
+            secret: None,
+            transforms: None,
+        },
+    )]));
+    let path = choose_content_creation_path(
+        &client,
+        &static_template_request(),
+        Some(&template),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
+    assert_eq!(path, ContentCreationPath::Render);
+
+    let mut request = static_template_request();
+    request
+        .variables
+        .insert("service_name".to_string(), "payments".to_string());
+    let path = choose_content_creation_path(
+        &client,
         &request,
-        "team",
-    );
+        Some(&static_template_config()),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
+    assert_eq!(path, ContentCreationPath::Render);
+}
 
-    // Ceiling is invalid, so no ceiling applied; Admin request accepted as-is.
-    assert_eq!(result.get("new-team"), Some(&AccessLevel::Admin));
+/// Verify that the render path is used when the template repository is not
+/// a GitHub template or requires internal visibility.
+#[tokio::test]
+async fn test_content_creation_path_is_render_for_non_template_repository() {
+    let template = static_template_config();
+
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(false),
+        ..Default::default()
+    });
+    let path = choose_content_creation_path(
+        &client,
+        &static_template_request(),
+        Some(&template),
+        visibility::RepositoryVisibility::Private,
+    )
+    .await;
+    assert_eq!(path, ContentCreationPath::Render);
+
+    let client = ConfigurableMockRepoClient::new(MockRepoClientConfig {
+        repository_is_template: Some(true),
+        ..Default::default()
+    });
+    let path = choose_content_creation_path(
+        &client,
+        &static_template_request(),
+        Some(&template),
+        visibility::RepositoryVisibility::Internal,
+    )
+    .await;
+    assert_eq!(path, ContentCreationPath::Render);
 }
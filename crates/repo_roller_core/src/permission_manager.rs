@@ -32,8 +32,9 @@
 //! See `docs/spec/design/multi-level-permissions.md` for the full specification.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use github_client::GitHubClient;
+use github_client::RepositoryClient;
 use tracing::{info, instrument, warn};
 
 use crate::permission_audit_logger::PermissionAuditLogger;
@@ -196,7 +197,7 @@ pub struct PermissionManager {
     /// Structured audit logger for recording permission decisions.
     audit_logger: PermissionAuditLogger,
     /// GitHub client for applying permissions via the GitHub API.
-    github_client: GitHubClient,
+    github_client: Arc<dyn RepositoryClient>,
     /// Policy engine for validating permission requests against the hierarchy.
     policy_engine: PolicyEngine,
 }
@@ -206,7 +207,8 @@ impl PermissionManager {
     ///
     /// # Arguments
     ///
-    /// * `github_client` - GitHub client for API operations.
+    /// * `github_client` - GitHub client for API operations, either a `GitHubClient`
+    ///   or a client shared as `Arc<dyn RepositoryClient>`.
     /// * `policy_engine` - Policy engine for permission evaluation.
     ///
     /// # Examples
@@ -220,10 +222,13 @@ impl PermissionManager {
     /// let manager = PermissionManager::new(client, PolicyEngine::new());
     /// # }
     /// ```
-    pub fn new(github_client: GitHubClient, policy_engine: PolicyEngine) -> Self {
+    pub fn new(
+        github_client: impl Into<Arc<dyn RepositoryClient>>,
+        policy_engine: PolicyEngine,
+    ) -> Self {
         Self {
             audit_logger: PermissionAuditLogger::new(),
-            github_client,
+            github_client: github_client.into(),
             policy_engine,
         }
     }
@@ -27,6 +27,9 @@ fn minimal_template() -> TemplateConfig {
             description: "A test template".to_string(),
             author: "test-author".to_string(),
             tags: vec![],
+            default_description: None,
+            default_topics: vec![],
+            required_code_owners: vec![],
         },
         repository_type: None,
         variables: None,
@@ -45,6 +48,10 @@ fn minimal_template() -> TemplateConfig {
         teams: None,
         collaborators: None,
         naming_rules: None,
+        actions_variables: None,
+        actions_secrets: None,
+        variable_groups: None,
+        base_template: None,
     }
 }
 
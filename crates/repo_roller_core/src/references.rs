@@ -0,0 +1,156 @@
+//! Pre-flight checks that referenced teams and GitHub Apps exist.
+//!
+//! A misspelled team slug or a GitHub App that is not installed otherwise
+//! only fails once permissions or apps are applied, after the repository
+//! already exists. [`ensure_references_exist`] looks both up before anything
+//! is created. The check costs two GitHub API calls and only runs when the
+//! request sets `validate_references`.
+
+use std::collections::{BTreeSet, HashSet};
+
+use config_manager::MergedConfiguration;
+use github_client::RepositoryClient;
+use tracing::{info, warn};
+
+use crate::{RepoRollerError, RepoRollerResult, RepositoryCreationRequest, ValidationError};
+
+#[cfg(test)]
+#[path = "references_tests.rs"]
+mod tests;
+
+/// Fails when a referenced team or required GitHub App does not exist.
+///
+/// Teams are taken from the merged configuration and from the request's team
+/// grants and owning teams. Only apps marked as required are checked, since
+/// missing optional apps are allowed. If the teams or installations cannot be
+/// listed, that part of the check is skipped with a warning.
+///
+/// # Errors
+///
+/// Returns `ValidationError::UnknownReferences` listing every unknown team
+/// and app.
+pub(crate) async fn ensure_references_exist(
+    github_client: &dyn RepositoryClient,
+    request: &RepositoryCreationRequest,
+    merged_config: &MergedConfiguration,
+) -> RepoRollerResult<()> {
+    let org = request.owner.as_ref();
+    let teams = referenced_teams(request, merged_config);
+    let app_ids = required_app_ids(merged_config);
+
+    let known_teams = if teams.is_empty() {
+        None
+    } else {
+        match github_client.list_organization_teams(org).await {
+            Ok(list) => Some(list.into_iter().map(|t| t.slug.to_lowercase()).collect()),
+            Err(e) => {
+                warn!(
+                    "Could not list teams of '{}': {}. Skipping team check.",
+                    org, e
+                );
+                None
+            }
+        }
+    };
+
+    let installed_apps = if app_ids.is_empty() {
+        None
+    } else {
+        match github_client.list_organization_installations(org).await {
+            Ok(list) => Some(list.iter().filter_map(|i| i.app_id).collect()),
+            Err(e) => {
+                warn!(
+                    "Could not list GitHub App installations of '{}': {}. Skipping app check.",
+                    org, e
+                );
+                None
+            }
+        }
+    };
+
+    match find_unknown_references(
+        org,
+        &teams,
+        known_teams.as_ref(),
+        &app_ids,
+        installed_apps.as_ref(),
+    ) {
+        Some(error) => {
+            warn!("{}", error);
+            Err(RepoRollerError::Validation(error))
+        }
+        None => {
+            info!(
+                "All {} referenced team(s) and {} required app(s) exist in '{}'",
+                teams.len(),
+                app_ids.len(),
+                org
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Team slugs referenced by the merged configuration and the request, lowercased.
+pub(crate) fn referenced_teams(
+    request: &RepositoryCreationRequest,
+    merged_config: &MergedConfiguration,
+) -> BTreeSet<String> {
+    merged_config
+        .teams
+        .keys()
+        .chain(request.teams.keys())
+        .chain(request.owning_teams.iter())
+        .map(|slug| slug.to_lowercase())
+        .collect()
+}
+
+/// IDs of the GitHub Apps the merged configuration requires.
+pub(crate) fn required_app_ids(merged_config: &MergedConfiguration) -> BTreeSet<u64> {
+    merged_config
+        .github_apps
+        .iter()
+        .filter(|app| app.is_required())
+        .map(|app| app.app_id)
+        .collect()
+}
+
+/// Compares the referenced teams and apps with those known to exist.
+///
+/// `None` for `known_teams` or `installed_apps` skips that comparison.
+/// Returns `None` when nothing is unknown.
+pub(crate) fn find_unknown_references(
+    org: &str,
+    teams: &BTreeSet<String>,
+    known_teams: Option<&HashSet<String>>,
+    app_ids: &BTreeSet<u64>,
+    installed_apps: Option<&HashSet<u64>>,
+) -> Option<ValidationError> {
+    let unknown_teams: Vec<String> = known_teams
+        .map(|known| {
+            teams
+                .iter()
+                .filter(|slug| !known.contains(*slug))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let unknown_apps: Vec<u64> = installed_apps
+        .map(|installed| {
+            app_ids
+                .iter()
+                .filter(|id| !installed.contains(id))
+                .copied()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if unknown_teams.is_empty() && unknown_apps.is_empty() {
+        return None;
+    }
+    Some(ValidationError::UnknownReferences {
+        org: org.to_string(),
+        teams: unknown_teams,
+        app_ids: unknown_apps,
+    })
+}
@@ -0,0 +1,93 @@
+//! Tests for the references module.
+
+use super::*;
+use crate::{AccessLevel, OrganizationName, RepositoryCreationRequestBuilder, RepositoryName};
+use config_manager::settings::GitHubAppConfig;
+use config_manager::OverridableValue;
+use std::collections::HashMap;
+
+fn request() -> RepositoryCreationRequest {
+    RepositoryCreationRequestBuilder::new(
+        RepositoryName::new("my-repo").unwrap(),
+        OrganizationName::new("my-org").unwrap(),
+    )
+    .teams(HashMap::from([("Backend".to_string(), AccessLevel::Write)]))
+    .owning_teams(vec!["platform".to_string()])
+    .build()
+}
+
+fn merged_with_apps(apps: Vec<GitHubAppConfig>) -> MergedConfiguration {
+    let mut merged = MergedConfiguration::new();
+    merged
+        .teams
+        .insert("security".to_string(), "read".to_string());
+    merged.github_apps = apps;
+    merged
+}
+
+fn app(app_id: u64, required: bool) -> GitHubAppConfig {
+    GitHubAppConfig {
+        app_id,
+        permissions: HashMap::new(),
+        required: Some(OverridableValue::fixed(required)),
+    }
+}
+
+#[test]
+fn test_referenced_teams_include_configuration_and_request() {
+    let teams = referenced_teams(&request(), &merged_with_apps(vec![]));
+
+    let teams: Vec<&str> = teams.iter().map(String::as_str).collect();
+    assert_eq!(teams, vec!["backend", "platform", "security"]);
+}
+
+#[test]
+fn test_unknown_team_is_reported() {
+    let teams = referenced_teams(&request(), &merged_with_apps(vec![]));
+    let known = HashSet::from(["backend".to_string(), "security".to_string()]);
+
+    let error = find_unknown_references("my-org", &teams, Some(&known), &BTreeSet::new(), None)
+        .expect("platform should be unknown");
+
+    match error {
+        ValidationError::UnknownReferences {
+            org,
+            teams,
+            app_ids,
+        } => {
+            assert_eq!(org, "my-org");
+            assert_eq!(teams, vec!["platform".to_string()]);
+            assert!(app_ids.is_empty());
+        }
+        other => panic!("Expected UnknownReferences, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_required_app_is_reported() {
+    let merged = merged_with_apps(vec![app(1, true), app(2, true), app(3, false)]);
+    let app_ids = required_app_ids(&merged);
+    let installed = HashSet::from([1]);
+
+    let error =
+        find_unknown_references("my-org", &BTreeSet::new(), None, &app_ids, Some(&installed))
+            .expect("app 2 should be unknown");
+
+    match error {
+        ValidationError::UnknownReferences { teams, app_ids, .. } => {
+            assert!(teams.is_empty());
+            assert_eq!(app_ids, vec![2]);
+        }
+        other => panic!("Expected UnknownReferences, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_known_references_pass() {
+    let teams = referenced_teams(&request(), &merged_with_apps(vec![]));
+    let known: HashSet<String> = teams.iter().cloned().collect();
+
+    assert!(
+        find_unknown_references("my-org", &teams, Some(&known), &BTreeSet::new(), None).is_none()
+    );
+}
@@ -44,9 +44,10 @@ impl RepositoryName {
         }
 
         if name.starts_with('.') || name.starts_with('-') {
-            return Err(ValidationError::invalid_format(
+            return Err(ValidationError::invalid_name(
                 "repository_name",
                 "must not start with '.' or '-'",
+                Self::suggestion_for(&name),
             ));
         }
 
@@ -54,21 +55,43 @@ impl RepositoryName {
             .chars()
             .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.')
         {
-            return Err(ValidationError::invalid_format(
+            return Err(ValidationError::invalid_name(
                 "repository_name",
                 "must contain only alphanumeric characters, hyphens, underscores, or periods",
+                Self::suggestion_for(&name),
             ));
         }
 
         Ok(Self(name))
     }
 
+    /// Suggest a valid kebab-case name close to `name`, if there is one.
+    fn suggestion_for(name: &str) -> Option<String> {
+        let candidate = normalize_name(name);
+        (candidate != name && Self::new(candidate.as_str()).is_ok()).then_some(candidate)
+    }
+
     /// Get the repository name as a string slice
     pub fn as_str(&self) -> &str {
         &self.0
     }
 }
 
+/// Normalize a name towards kebab-case.
+///
+/// Lowercases the name, replaces runs of whitespace with a single hyphen and
+/// strips leading hyphens and periods and trailing hyphens. The result is not
+/// guaranteed to be valid.
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+        .trim_start_matches(['-', '.'])
+        .trim_end_matches('-')
+        .to_string()
+}
+
 impl std::fmt::Display for RepositoryName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
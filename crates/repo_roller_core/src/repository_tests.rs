@@ -35,3 +35,19 @@ fn test_organization_name_invalid() {
     assert!(OrganizationName::new("a".repeat(40)).is_err());
     assert!(OrganizationName::new("invalid_underscore").is_err());
 }
+
+#[test]
+fn test_repository_name_suggests_kebab_case() {
+    let err = RepositoryName::new("My Repo").unwrap_err();
+    assert_eq!(err.suggestion(), Some("my-repo"));
+
+    let err = RepositoryName::new("-my-repo").unwrap_err();
+    assert_eq!(err.suggestion(), Some("my-repo"));
+}
+
+#[test]
+fn test_repository_name_unfixable_has_no_suggestion() {
+    let err = RepositoryName::new("my@repo").unwrap_err();
+    assert!(matches!(err, ValidationError::InvalidName { .. }));
+    assert_eq!(err.suggestion(), None);
+}
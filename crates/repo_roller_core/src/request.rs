@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use crate::{
     permissions::AccessLevel, OrganizationName, RepositoryName, RepositoryVisibility, TemplateName,
@@ -23,8 +24,10 @@ mod tests;
 /// # Variants
 ///
 /// - `Template`: Use template repository (current/default behavior)
-/// - `Empty`: Create no files (empty repository)
+/// - `Empty`: Create only a minimal README.md (placeholder repository)
 /// - `CustomInit`: Create selected initialization files only
+/// - `CopyFromRepository`: Copy the content of an existing repository, either
+///   as a single-commit snapshot or with its full history
 ///
 /// # Examples
 ///
@@ -42,6 +45,12 @@ mod tests;
 ///     include_readme: true,
 ///     include_gitignore: true,
 /// };
+///
+/// // Copy of an existing repository, keeping its branches, tags and history
+/// let strategy = ContentStrategy::CopyFromRepository {
+///     source: "my-org/existing-repo".to_string(),
+///     copy_full_history: true,
+/// };
 /// ```
 ///
 /// See specs/interfaces/repository-creation-modes.md#contentstrategy-enum
@@ -52,7 +61,7 @@ pub enum ContentStrategy {
     #[default]
     Template,
 
-    /// Create no files (empty repository)
+    /// Create only a minimal README.md from the repository name and description
     #[serde(rename = "empty")]
     Empty,
 
@@ -65,6 +74,71 @@ pub enum ContentStrategy {
         /// Create .gitignore file
         include_gitignore: bool,
     },
+
+    /// Copy the content of an existing repository
+    #[serde(rename = "copy_from_repository")]
+    CopyFromRepository {
+        /// Source repository as `owner/name`
+        source: String,
+
+        /// Mirror every branch and tag with its history instead of creating
+        /// a single-commit snapshot of the default branch
+        #[serde(default)]
+        copy_full_history: bool,
+    },
+}
+
+/// Kind of GitHub account that will own a new repository.
+///
+/// Organization-owned repositories are created through the organization
+/// endpoint and can use organization-only features such as teams, custom
+/// properties and internal visibility. Repositories owned by a personal
+/// account are created for the account the GitHub App is installed on, and
+/// those features are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use repo_roller_core::OwnerType;
+///
+/// assert_eq!("user".parse::<OwnerType>(), Ok(OwnerType::User));
+/// assert_eq!("org".parse::<OwnerType>(), Ok(OwnerType::Organization));
+/// assert_eq!(OwnerType::default(), OwnerType::Organization);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnerType {
+    /// The repository is owned by an organization
+    #[default]
+    Organization,
+
+    /// The repository is owned by a personal (user) account
+    User,
+}
+
+impl OwnerType {
+    /// Converts the OwnerType to a string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Organization => "organization",
+            Self::User => "user",
+        }
+    }
+}
+
+impl FromStr for OwnerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "org" | "organization" => Ok(Self::Organization),
+            "user" => Ok(Self::User),
+            _ => Err(format!(
+                "Unknown owner type '{}'. Expected 'org' or 'user'",
+                s
+            )),
+        }
+    }
 }
 
 /// Request for creating a new repository with validated types.
@@ -76,7 +150,7 @@ pub enum ContentStrategy {
 ///
 /// ```rust
 /// use repo_roller_core::{
-///     RepositoryCreationRequest, RepositoryName, OrganizationName,
+///     RepositoryCreationRequest, RepositoryName, OrganizationName, OwnerType,
 ///     TemplateName, RepositoryVisibility, ContentStrategy
 /// };
 /// use repo_roller_core::permissions::AccessLevel;
@@ -86,13 +160,26 @@ pub enum ContentStrategy {
 /// let request = RepositoryCreationRequest {
 ///     name: RepositoryName::new("my-new-repo").unwrap(),
 ///     owner: OrganizationName::new("my-org").unwrap(),
+///     owner_type: OwnerType::Organization,
 ///     template: Some(TemplateName::new("rust-library").unwrap()),
 ///     variables: HashMap::new(),
 ///     visibility: Some(RepositoryVisibility::Private),
 ///     content_strategy: ContentStrategy::Template,
 ///     teams: HashMap::new(),
 ///     collaborators: HashMap::new(),
+///     owning_teams: vec![],
+///     signed_initial_commit: None,
 ///     actor_login: "alice".to_string(),
+///     description: None,
+///     topics: vec![],
+///     verify_configuration: false,
+///     include_configuration_audit: false,
+///     validate_references: false,
+///     template_ref: None,
+///     review_branch: None,
+///     license_template: None,
+///     gitignore_template: None,
+///     auto_init: None,
 /// };
 ///
 /// // Empty repository with team permissions
@@ -101,13 +188,26 @@ pub enum ContentStrategy {
 /// let request = RepositoryCreationRequest {
 ///     name: RepositoryName::new("my-empty-repo").unwrap(),
 ///     owner: OrganizationName::new("my-org").unwrap(),
+///     owner_type: OwnerType::Organization,
 ///     template: Some(TemplateName::new("github-actions").unwrap()),
 ///     variables: HashMap::new(),
 ///     visibility: None,
 ///     content_strategy: ContentStrategy::Empty,
 ///     teams,
 ///     collaborators: HashMap::new(),
+///     owning_teams: vec![],
+///     signed_initial_commit: None,
 ///     actor_login: "bob".to_string(),
+///     description: None,
+///     topics: vec![],
+///     verify_configuration: false,
+///     include_configuration_audit: false,
+///     validate_references: false,
+///     template_ref: None,
+///     review_branch: None,
+///     license_template: None,
+///     gitignore_template: None,
+///     auto_init: None,
 /// };
 /// ```
 ///
@@ -120,6 +220,9 @@ pub struct RepositoryCreationRequest {
     /// The organization or user that will own the repository
     pub owner: OrganizationName,
 
+    /// Whether `owner` is an organization or a personal account.
+    pub owner_type: OwnerType,
+
     /// Optional template name for content and settings.
     ///
     /// When `Some`, template is loaded for:
@@ -132,6 +235,14 @@ pub struct RepositoryCreationRequest {
     /// - Only Empty or CustomInit strategies valid
     pub template: Option<TemplateName>,
 
+    /// Git ref of the template to render: a branch, a tag or a full commit SHA.
+    ///
+    /// `None` renders the tip of the template's default branch. Only the
+    /// template files are pinned; the template configuration is read from
+    /// the default branch. The commit actually rendered is reported in
+    /// [`RepositoryCreationResult::template_commit_sha`].
+    pub template_ref: Option<String>,
+
     /// Template variables for variable substitution during processing
     pub variables: HashMap<String, String>,
 
@@ -142,7 +253,7 @@ pub struct RepositoryCreationRequest {
     ///
     /// Determines how repository content is generated:
     /// - Template: Fetch and process template files (default)
-    /// - Empty: Create no files
+    /// - Empty: Create only a minimal README.md
     /// - CustomInit: Create selected initialization files
     ///
     /// See [`ContentStrategy`] for details.
@@ -161,6 +272,83 @@ pub struct RepositoryCreationRequest {
     /// Use [`AccessLevel::None`] to explicitly remove a user if present.
     pub collaborators: HashMap<String, AccessLevel>,
 
+    /// Teams that own the repository, in precedence order.
+    ///
+    /// The team configuration of every listed team is loaded from the metadata
+    /// repository and combined in this order: additive collections are unioned
+    /// and conflicting override settings fail the request with a validation
+    /// error. An empty list means no team configuration is applied.
+    pub owning_teams: Vec<String>,
+
+    /// Whether to create the initial commit through the GitHub API so that it
+    /// is signed and shown as verified.
+    ///
+    /// `None` uses the organization's `signed_initial_commit` setting; `Some`
+    /// overrides it for this request.
+    pub signed_initial_commit: Option<bool>,
+
+    /// Branch that receives the initial content instead of the default branch.
+    ///
+    /// When set, the default branch starts with an empty commit and a pull
+    /// request from this branch into the default branch is opened so that
+    /// reviewers can approve the scaffold. Cannot be combined with signed
+    /// initial commits.
+    pub review_branch: Option<String>,
+
+    /// License GitHub should add when it creates the repository, given as a
+    /// license key such as `mit` or `apache-2.0`.
+    ///
+    /// GitHub commits the `LICENSE` file when the repository is created; the
+    /// generated content is then published on top of it and the file is kept.
+    /// Requesting a license always uses the render path.
+    /// `None` uses the organization's `license_template`, if any.
+    pub license_template: Option<String>,
+
+    /// GitHub `.gitignore` template (for example `Rust`) to apply when GitHub
+    /// creates the repository.
+    ///
+    /// The generated `.gitignore` is kept in the initial commit, replacing one
+    /// from the template. `None` uses the organization's `gitignore_template`.
+    pub gitignore_template: Option<String>,
+
+    /// Whether GitHub creates an initial commit with a README.
+    ///
+    /// The initial commit replaces GitHub's; the README is kept only when the
+    /// generated content has none. `None` uses the organization's `auto_init`.
+    pub auto_init: Option<bool>,
+
+    /// Repository description.
+    ///
+    /// `None` uses the template's `default_description`, if any.
+    pub description: Option<String>,
+
+    /// Repository topics.
+    ///
+    /// An empty list uses the template's `default_topics`.
+    pub topics: Vec<String>,
+
+    /// Re-read the repository after creation and compare it with the applied
+    /// configuration.
+    ///
+    /// When `true`, the result carries a
+    /// [`VerificationReport`](crate::VerificationReport).
+    pub verify_configuration: bool,
+
+    /// Attach the resolved configuration and the source of each setting to
+    /// the result.
+    ///
+    /// When `true`, the result carries a
+    /// [`ConfigurationAuditRecord`](config_manager::ConfigurationAuditRecord).
+    pub include_configuration_audit: bool,
+
+    /// Check that every referenced team and required GitHub App exists in
+    /// the organization before anything is created.
+    ///
+    /// Costs extra GitHub API calls, so it is off by default. When `true`,
+    /// unknown references fail the request with
+    /// [`ValidationError::UnknownReferences`](crate::ValidationError::UnknownReferences).
+    pub validate_references: bool,
+
     /// The identity (login) of the actor initiating this request.
     ///
     /// Used for:
@@ -187,6 +375,10 @@ pub struct RepositoryCreationRequest {
 ///     repository_id: "R_kgDOABCDEF".to_string(),
 ///     created_at: Timestamp::now(),
 ///     default_branch: "main".to_string(),
+///     warnings: vec![],
+///     verification: None,
+///     configuration_audit: None,
+///     template_commit_sha: None,
 /// };
 /// ```
 ///
@@ -204,6 +396,26 @@ pub struct RepositoryCreationResult {
 
     /// The default branch name
     pub default_branch: String,
+
+    /// Non-blocking configuration warnings raised while creating the repository
+    pub warnings: Vec<config_manager::ValidationWarning>,
+
+    /// Comparison of the created repository with its intended configuration.
+    ///
+    /// `None` unless verification was requested and the repository could be
+    /// read back.
+    pub verification: Option<crate::VerificationReport>,
+
+    /// The resolved configuration and per-setting sources, for audit storage.
+    ///
+    /// `None` unless the request set `include_configuration_audit`.
+    pub configuration_audit: Option<config_manager::ConfigurationAuditRecord>,
+
+    /// Full SHA of the template commit the repository content was rendered from.
+    ///
+    /// `None` when no template files were rendered, for example for empty
+    /// repositories or repositories generated natively by GitHub.
+    pub template_commit_sha: Option<String>,
 }
 
 /// Builder for constructing RepositoryCreationRequest instances.
@@ -254,12 +466,25 @@ pub struct RepositoryCreationResult {
 pub struct RepositoryCreationRequestBuilder {
     name: Option<RepositoryName>,
     owner: Option<OrganizationName>,
+    owner_type: OwnerType,
     template: Option<TemplateName>,
+    template_ref: Option<String>,
     variables: Option<HashMap<String, String>>,
     visibility: Option<RepositoryVisibility>,
     content_strategy: Option<ContentStrategy>,
     teams: HashMap<String, AccessLevel>,
     collaborators: HashMap<String, AccessLevel>,
+    owning_teams: Vec<String>,
+    signed_initial_commit: Option<bool>,
+    review_branch: Option<String>,
+    license_template: Option<String>,
+    gitignore_template: Option<String>,
+    auto_init: Option<bool>,
+    description: Option<String>,
+    topics: Vec<String>,
+    verify_configuration: bool,
+    include_configuration_audit: bool,
+    validate_references: bool,
     actor_login: Option<String>,
 }
 
@@ -282,12 +507,25 @@ impl RepositoryCreationRequestBuilder {
         Self {
             name: Some(name),
             owner: Some(owner),
+            owner_type: OwnerType::Organization,
             template: None,
+            template_ref: None,
             variables: None,
             visibility: None,
             content_strategy: None,
             teams: HashMap::new(),
             collaborators: HashMap::new(),
+            owning_teams: Vec::new(),
+            signed_initial_commit: None,
+            review_branch: None,
+            license_template: None,
+            gitignore_template: None,
+            auto_init: None,
+            description: None,
+            topics: Vec::new(),
+            verify_configuration: false,
+            include_configuration_audit: false,
+            validate_references: false,
             actor_login: None,
         }
     }
@@ -316,6 +554,30 @@ impl RepositoryCreationRequestBuilder {
         self
     }
 
+    /// Set the kind of account that owns the new repository.
+    ///
+    /// Defaults to [`OwnerType::Organization`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("octocat")?,
+    /// )
+    /// .owner_type(OwnerType::User)
+    /// .build();
+    /// assert_eq!(request.owner_type, OwnerType::User);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn owner_type(mut self, owner_type: OwnerType) -> Self {
+        self.owner_type = owner_type;
+        self
+    }
+
     /// Set the teams to grant access to the new repository.
     ///
     /// Replaces any previously set teams map.
@@ -370,6 +632,239 @@ impl RepositoryCreationRequestBuilder {
         self
     }
 
+    /// Set the teams that own the new repository, in precedence order.
+    ///
+    /// Replaces any previously set owning teams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .owning_teams(vec!["backend".to_string(), "platform".to_string()])
+    /// .build();
+    /// assert_eq!(request.owning_teams, vec!["backend", "platform"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn owning_teams(mut self, teams: Vec<String>) -> Self {
+        self.owning_teams = teams;
+        self
+    }
+
+    /// Choose whether the initial commit is created through the GitHub API
+    /// (signed and verified), overriding the organization setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .signed_initial_commit(true)
+    /// .build();
+    /// assert_eq!(request.signed_initial_commit, Some(true));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signed_initial_commit(mut self, signed: bool) -> Self {
+        self.signed_initial_commit = Some(signed);
+        self
+    }
+
+    /// Land the initial content on a separate branch and open a pull request
+    /// into the default branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .review_branch("template-init")
+    /// .build();
+    /// assert_eq!(request.review_branch.as_deref(), Some("template-init"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn review_branch(mut self, branch: impl Into<String>) -> Self {
+        self.review_branch = Some(branch.into());
+        self
+    }
+
+    /// Have GitHub create the repository with the given license.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .license_template("mit")
+    /// .build();
+    /// assert_eq!(request.license_template.as_deref(), Some("mit"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn license_template(mut self, key: impl Into<String>) -> Self {
+        self.license_template = Some(key.into());
+        self
+    }
+
+    /// Have GitHub apply the named `.gitignore` template when it creates the
+    /// repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .gitignore_template("Rust")
+    /// .build();
+    /// assert_eq!(request.gitignore_template.as_deref(), Some("Rust"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gitignore_template(mut self, name: impl Into<String>) -> Self {
+        self.gitignore_template = Some(name.into());
+        self
+    }
+
+    /// Set whether GitHub creates an initial commit with a README, overriding
+    /// the organization default.
+    pub fn auto_init(mut self, auto_init: bool) -> Self {
+        self.auto_init = Some(auto_init);
+        self
+    }
+
+    /// Set the repository description, overriding the template default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .description("Payment processing service")
+    /// .build();
+    /// assert_eq!(request.description.as_deref(), Some("Payment processing service"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the repository topics, overriding the template defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .topics(vec!["rust".to_string(), "service".to_string()])
+    /// .build();
+    /// assert_eq!(request.topics, vec!["rust", "service"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn topics(mut self, topics: Vec<String>) -> Self {
+        self.topics = topics;
+        self
+    }
+
+    /// Verify the applied configuration once the repository has been created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .verify_configuration(true)
+    /// .build();
+    /// assert!(request.verify_configuration);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_configuration(mut self, verify: bool) -> Self {
+        self.verify_configuration = verify;
+        self
+    }
+
+    /// Attach a configuration audit record to the creation result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .include_configuration_audit(true)
+    /// .build();
+    /// assert!(request.include_configuration_audit);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn include_configuration_audit(mut self, include: bool) -> Self {
+        self.include_configuration_audit = include;
+        self
+    }
+
+    /// Check that referenced teams and required GitHub Apps exist before
+    /// creating the repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .validate_references(true)
+    /// .build();
+    /// assert!(request.validate_references);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_references(mut self, validate: bool) -> Self {
+        self.validate_references = validate;
+        self
+    }
+
     /// Set the template for content and settings.
     ///
     /// When provided, the template is loaded for file content (if using Template strategy)
@@ -394,6 +889,29 @@ impl RepositoryCreationRequestBuilder {
         self
     }
 
+    /// Render the template at a branch, tag or full commit SHA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use repo_roller_core::*;
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = RepositoryCreationRequestBuilder::new(
+    ///     RepositoryName::new("my-repo")?,
+    ///     OrganizationName::new("my-org")?,
+    /// )
+    /// .template(TemplateName::new("rust-service")?)
+    /// .template_ref("3f2c1e0b9a8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b")
+    /// .build();
+    /// assert!(request.template_ref.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn template_ref(mut self, git_ref: impl Into<String>) -> Self {
+        self.template_ref = Some(git_ref.into());
+        self
+    }
+
     /// Add a single template variable.
     ///
     /// If a variable with the same key already exists, it will be overwritten.
@@ -477,12 +995,25 @@ impl RepositoryCreationRequestBuilder {
         RepositoryCreationRequest {
             name,
             owner,
+            owner_type: self.owner_type,
             template: self.template,
+            template_ref: self.template_ref,
             variables: self.variables.unwrap_or_default(),
             visibility: self.visibility,
             content_strategy,
             teams: self.teams,
             collaborators: self.collaborators,
+            owning_teams: self.owning_teams,
+            signed_initial_commit: self.signed_initial_commit,
+            review_branch: self.review_branch,
+            license_template: self.license_template,
+            gitignore_template: self.gitignore_template,
+            auto_init: self.auto_init,
+            description: self.description,
+            topics: self.topics,
+            verify_configuration: self.verify_configuration,
+            include_configuration_audit: self.include_configuration_audit,
+            validate_references: self.validate_references,
             actor_login: self.actor_login.unwrap_or_else(|| "reporoller".to_string()),
         }
     }
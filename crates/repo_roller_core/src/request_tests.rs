@@ -19,13 +19,26 @@ fn test_repository_creation_request_creation() {
     let request = RepositoryCreationRequest {
         name: name.clone(),
         owner: owner.clone(),
+        owner_type: OwnerType::Organization,
         template: Some(template.clone()),
         variables: variables.clone(),
         visibility: None,
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     assert_eq!(request.name, name);
@@ -44,13 +57,26 @@ fn test_repository_creation_request_with_variables() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("my-repo").unwrap(),
         owner: OrganizationName::new("my-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("rust-library").unwrap()),
         variables: variables.clone(),
         visibility: None,
         content_strategy: ContentStrategy::Template,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     assert_eq!(request.variables.len(), 2);
@@ -64,13 +90,26 @@ fn test_repository_creation_request_clone() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("my-repo").unwrap(),
         owner: OrganizationName::new("my-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("rust-library").unwrap()),
         content_strategy: ContentStrategy::Template,
         variables: HashMap::new(),
         visibility: None,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     let cloned = request.clone();
@@ -88,13 +127,27 @@ fn test_repository_creation_request_debug() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("my-repo").unwrap(),
         owner: OrganizationName::new("my-org").unwrap(),
+        owner_type: OwnerType::Organization,
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("rust-library").unwrap()),
         content_strategy: ContentStrategy::Template,
         variables: HashMap::new(),
         visibility: None,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     let debug_output = format!("{:?}", request);
@@ -122,7 +175,19 @@ fn test_repository_creation_request_type_safety() {
         visibility: None,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     // Verify we can access the values
@@ -137,13 +202,26 @@ fn test_repository_creation_request_empty_variables() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("my-repo").unwrap(),
         owner: OrganizationName::new("my-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("rust-library").unwrap()),
         content_strategy: ContentStrategy::Template,
         variables: HashMap::new(),
         visibility: None,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     assert!(request.variables.is_empty());
@@ -165,13 +243,26 @@ fn test_repository_creation_request_validates_names() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("valid-repo").unwrap(),
         owner: OrganizationName::new("valid-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: Some(TemplateName::new("valid-template").unwrap()),
         content_strategy: ContentStrategy::Template,
         variables: HashMap::new(),
         visibility: None,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     assert_eq!(request.name.as_str(), "valid-repo");
@@ -358,6 +449,10 @@ fn test_repository_creation_result_creation() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     assert_eq!(result.repository_url, "https://github.com/my-org/my-repo");
@@ -374,6 +469,10 @@ fn test_repository_creation_result_timestamp() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: timestamp,
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     assert_eq!(result.created_at, timestamp);
@@ -387,6 +486,10 @@ fn test_repository_creation_result_clone() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let cloned = result.clone();
@@ -405,6 +508,10 @@ fn test_repository_creation_result_debug() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let debug_output = format!("{:?}", result);
@@ -422,6 +529,10 @@ fn test_repository_creation_result_different_branches() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let result_master = RepositoryCreationResult {
@@ -429,6 +540,10 @@ fn test_repository_creation_result_different_branches() {
         repository_id: "R_kgDOGHIJKL".to_string(),
         created_at: Timestamp::now(),
         default_branch: "master".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let result_custom = RepositoryCreationResult {
@@ -436,6 +551,10 @@ fn test_repository_creation_result_different_branches() {
         repository_id: "R_kgDOMNOPQR".to_string(),
         created_at: Timestamp::now(),
         default_branch: "develop".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     assert_eq!(result_main.default_branch, "main");
@@ -451,6 +570,10 @@ fn test_repository_creation_result_url_formats() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     let ssh_result = RepositoryCreationResult {
@@ -458,6 +581,10 @@ fn test_repository_creation_result_url_formats() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     assert!(https_result.repository_url.starts_with("https://"));
@@ -473,6 +600,10 @@ fn test_repository_creation_result_repository_id() {
         repository_id: "R_kgDOABCDEF".to_string(),
         created_at: Timestamp::now(),
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     assert!(result.repository_id.starts_with("R_"));
@@ -489,6 +620,10 @@ fn test_repository_creation_result_success_scenario() {
         repository_id: "R_kgDOHXjK7A".to_string(),
         created_at: timestamp,
         default_branch: "main".to_string(),
+        warnings: vec![],
+        verification: None,
+        configuration_audit: None,
+        template_commit_sha: None,
     };
 
     // Verify all fields are populated correctly
@@ -736,6 +871,32 @@ fn test_content_strategy_deserialize_custom_init() {
     );
 }
 
+/// Test ContentStrategy deserialization for CopyFromRepository.
+///
+/// A snapshot copy is the default when `copy_full_history` is omitted.
+#[test]
+fn test_content_strategy_deserialize_copy_from_repository() {
+    let json = r#"{"type":"copy_from_repository","source":"my-org/existing-repo"}"#;
+    let strategy: ContentStrategy = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        strategy,
+        ContentStrategy::CopyFromRepository {
+            source: "my-org/existing-repo".to_string(),
+            copy_full_history: false,
+        }
+    );
+
+    let json = r#"{"type":"copy_from_repository","source":"my-org/existing-repo","copy_full_history":true}"#;
+    let strategy: ContentStrategy = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        strategy,
+        ContentStrategy::CopyFromRepository {
+            source: "my-org/existing-repo".to_string(),
+            copy_full_history: true,
+        }
+    );
+}
+
 /// Test ContentStrategy Clone trait.
 #[test]
 fn test_content_strategy_clone() {
@@ -761,13 +922,26 @@ fn test_request_empty_strategy_validation() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("test-repo").unwrap(),
         owner: OrganizationName::new("test-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: None,
         variables: HashMap::new(),
         visibility: None,
         content_strategy: ContentStrategy::Empty,
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     // Should not panic or error - Empty strategy doesn't require template
@@ -780,6 +954,7 @@ fn test_request_custom_init_strategy_validation() {
     let request = RepositoryCreationRequest {
         name: RepositoryName::new("test-repo").unwrap(),
         owner: OrganizationName::new("test-org").unwrap(),
+        owner_type: OwnerType::Organization,
         template: None,
         variables: HashMap::new(),
         visibility: None,
@@ -789,7 +964,19 @@ fn test_request_custom_init_strategy_validation() {
         },
         teams: HashMap::new(),
         collaborators: HashMap::new(),
+        owning_teams: vec![],
+        signed_initial_commit: None,
         actor_login: "test-user".to_string(),
+        description: None,
+        topics: vec![],
+        verify_configuration: false,
+        include_configuration_audit: false,
+        validate_references: false,
+        template_ref: None,
+        review_branch: None,
+        license_template: None,
+        gitignore_template: None,
+        auto_init: None,
     };
 
     // Should not panic or error - CustomInit strategy doesn't require template
@@ -989,3 +1176,41 @@ fn test_request_with_teams_and_collaborators_is_cloneable() {
     assert_eq!(cloned.teams.len(), 1);
     assert_eq!(cloned.teams.get("platform"), Some(&AccessLevel::Write));
 }
+
+/// Verify that the configuration audit is opt-in on the builder.
+#[test]
+fn test_builder_include_configuration_audit() {
+    let builder = || {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("my-repo").unwrap(),
+            OrganizationName::new("my-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+    };
+
+    assert!(!builder().build().include_configuration_audit);
+    assert!(
+        builder()
+            .include_configuration_audit(true)
+            .build()
+            .include_configuration_audit
+    );
+}
+
+/// Verify that owner types parse from CLI-style strings and serialize in snake case.
+#[test]
+fn test_owner_type_parse_and_serialize() {
+    assert_eq!("org".parse::<OwnerType>(), Ok(OwnerType::Organization));
+    assert_eq!(
+        "Organization".parse::<OwnerType>(),
+        Ok(OwnerType::Organization)
+    );
+    assert_eq!("user".parse::<OwnerType>(), Ok(OwnerType::User));
+    assert!("team".parse::<OwnerType>().is_err());
+
+    assert_eq!(serde_json::to_value(OwnerType::User).unwrap(), "user");
+    assert_eq!(
+        serde_json::to_value(OwnerType::Organization).unwrap(),
+        "organization"
+    );
+}
@@ -3,8 +3,9 @@
 //! This module provides the [`RulesetManager`] component for orchestrating
 //! repository ruleset operations with business logic and idempotency.
 
-use github_client::{GitHubClient, RepositoryClient, RepositoryRuleset};
+use github_client::{RepositoryClient, RepositoryRuleset};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, warn};
 
 use crate::{GitHubError, RepoRollerResult};
@@ -45,7 +46,7 @@ use crate::{GitHubError, RepoRollerResult};
 /// ```
 pub struct RulesetManager {
     /// GitHub client for API operations
-    github_client: GitHubClient,
+    github_client: Arc<dyn RepositoryClient>,
 }
 
 impl RulesetManager {
@@ -53,7 +54,8 @@ impl RulesetManager {
     ///
     /// # Arguments
     ///
-    /// * `github_client` - GitHub client for API operations
+    /// * `github_client` - GitHub client for API operations, either a `GitHubClient`
+    ///   or a client shared as `Arc<dyn RepositoryClient>`
     ///
     /// # Examples
     ///
@@ -65,8 +67,10 @@ impl RulesetManager {
     /// let manager = RulesetManager::new(client);
     /// # }
     /// ```
-    pub fn new(github_client: GitHubClient) -> Self {
-        Self { github_client }
+    pub fn new(github_client: impl Into<Arc<dyn RepositoryClient>>) -> Self {
+        Self {
+            github_client: github_client.into(),
+        }
     }
 
     /// Applies rulesets to a repository, creating or updating as needed.
@@ -0,0 +1,134 @@
+//! Machine-readable events for the steps of a repository creation.
+//!
+//! [`create_repository`](crate::create_repository) reports each completed
+//! orchestration step to the [`CreationStepSink`] configured on its
+//! [`EventNotificationContext`](crate::event_publisher::EventNotificationContext).
+//! Each event carries the creation's correlation ID and a timestamp, so an
+//! observability pipeline can follow a creation without parsing tracing output.
+//! Tracing is unaffected.
+//!
+//! [`JsonlStepSink`] writes one JSON object per line; [`InMemoryStepSink`]
+//! keeps the events for inspection.
+
+use crate::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::warn;
+
+#[cfg(test)]
+#[path = "step_events_tests.rs"]
+mod tests;
+
+/// An orchestration step of a repository creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreationStep {
+    /// Authenticated with GitHub and created the installation clients.
+    AuthDone,
+    /// Resolved the merged configuration and loaded the template, if any.
+    TemplateFetched,
+    /// Created the repository on GitHub and published its initial content.
+    RepoCreated,
+    /// Applied the merged configuration and permissions to the repository.
+    ConfigApplied,
+}
+
+/// A completed step of one repository creation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreationStepEvent {
+    /// The step that completed
+    pub step: CreationStep,
+    /// When the step completed, according to the creation's clock
+    pub timestamp: Timestamp,
+    /// Correlation ID of the creation the step belongs to
+    pub correlation_id: String,
+    /// `owner/name` of the repository being created
+    pub repository: String,
+}
+
+/// Receiver of step events during repository creation.
+///
+/// Recording must not fail the creation, so implementations handle their own
+/// errors, for example by logging them.
+pub trait CreationStepSink: Send + Sync {
+    /// Records a completed step.
+    fn record(&self, event: &CreationStepEvent);
+}
+
+/// Sink writing each event as one line of JSON.
+pub struct JsonlStepSink<W: Write + Send> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonlStepSink<W> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner().expect("step sink lock poisoned")
+    }
+}
+
+impl JsonlStepSink<File> {
+    /// Creates a sink appending to the file at `path`, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened.
+    pub fn append_to(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<W: Write + Send> CreationStepSink for JsonlStepSink<W> {
+    fn record(&self, event: &CreationStepEvent) {
+        let line = match serde_json::to_string(event) {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to serialize creation step event: {}", e);
+                return;
+            }
+        };
+
+        let mut writer = self.writer.lock().expect("step sink lock poisoned");
+        if let Err(e) = writeln!(writer, "{}", line).and_then(|_| writer.flush()) {
+            warn!("Failed to write creation step event: {}", e);
+        }
+    }
+}
+
+/// Sink that keeps its events in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryStepSink {
+    events: Mutex<Vec<CreationStepEvent>>,
+}
+
+impl InMemoryStepSink {
+    /// Creates an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the recorded events, in order.
+    pub fn events(&self) -> Vec<CreationStepEvent> {
+        self.events.lock().expect("step sink lock poisoned").clone()
+    }
+}
+
+impl CreationStepSink for InMemoryStepSink {
+    fn record(&self, event: &CreationStepEvent) {
+        self.events
+            .lock()
+            .expect("step sink lock poisoned")
+            .push(event.clone());
+    }
+}
@@ -0,0 +1,49 @@
+//! Tests for the step_events module.
+
+use super::*;
+use chrono::TimeZone;
+
+fn event(step: CreationStep) -> CreationStepEvent {
+    CreationStepEvent {
+        step,
+        timestamp: Timestamp::from_datetime(
+            chrono::Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+        ),
+        correlation_id: "corr-1".to_string(),
+        repository: "my-org/my-repo".to_string(),
+    }
+}
+
+#[test]
+fn test_jsonl_sink_writes_one_line_per_event() {
+    let sink = JsonlStepSink::new(Vec::new());
+
+    sink.record(&event(CreationStep::AuthDone));
+    sink.record(&event(CreationStep::RepoCreated));
+
+    let output = String::from_utf8(sink.into_inner()).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["step"], "auth_done");
+    assert_eq!(lines[0]["correlation_id"], "corr-1");
+    assert_eq!(lines[0]["repository"], "my-org/my-repo");
+    assert_eq!(lines[0]["timestamp"], "2024-05-01T12:00:00Z");
+    assert_eq!(lines[1]["step"], "repo_created");
+}
+
+#[test]
+fn test_in_memory_sink_keeps_events_in_order() {
+    let sink = InMemoryStepSink::new();
+
+    sink.record(&event(CreationStep::TemplateFetched));
+    sink.record(&event(CreationStep::ConfigApplied));
+
+    let steps: Vec<CreationStep> = sink.events().iter().map(|e| e.step).collect();
+    assert_eq!(
+        steps,
+        vec![CreationStep::TemplateFetched, CreationStep::ConfigApplied]
+    );
+}
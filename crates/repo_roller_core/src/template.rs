@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::ValidationError;
+use crate::repository::normalize_name;
 
 #[cfg(test)]
 #[path = "template_tests.rs"]
@@ -40,9 +41,10 @@ impl TemplateName {
         }
 
         if name.starts_with('-') || name.ends_with('-') {
-            return Err(ValidationError::invalid_format(
+            return Err(ValidationError::invalid_name(
                 "template_name",
                 "must not start or end with hyphen",
+                Self::suggestion_for(&name),
             ));
         }
 
@@ -50,15 +52,22 @@ impl TemplateName {
             .chars()
             .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
         {
-            return Err(ValidationError::invalid_format(
+            return Err(ValidationError::invalid_name(
                 "template_name",
                 "must be kebab-case (lowercase alphanumeric + hyphens)",
+                Self::suggestion_for(&name),
             ));
         }
 
         Ok(Self(name))
     }
 
+    /// Suggest a valid kebab-case name close to `name`, if there is one.
+    fn suggestion_for(name: &str) -> Option<String> {
+        let candidate = normalize_name(name);
+        (candidate != name && Self::new(candidate.as_str()).is_ok()).then_some(candidate)
+    }
+
     /// Get the template name as a string slice
     pub fn as_str(&self) -> &str {
         &self.0
@@ -1,7 +1,7 @@
 //! Template processing operations for repository creation.
 //!
 //! This module contains all template-related operations including:
-//! - Fetching template files from source repositories
+//! - Fetching template files from source repositories, layered over any base templates
 //! - Copying template files to local directories
 //! - Processing template variables and substitution
 //! - Creating additional repository files (README, .gitignore)
@@ -17,11 +17,154 @@ use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::Path;
+use std::sync::Arc;
 use temp_dir::TempDir;
-use template_engine::{TemplateFetcher, TemplateProcessingRequest, TemplateProcessor};
-use tracing::{debug, error, info};
+use template_engine::{
+    Clock, FetchedTemplate, ProcessedTemplate, TemplateFetcher, TemplateFileEntry,
+    TemplateFileKind, TemplateProcessingRequest, TemplateProcessor,
+};
+use tracing::{debug, error, info, warn};
 use walkdir::WalkDir;
 
+/// Converts a template source in `owner/repo` form to the URL passed to the
+/// template fetcher. Sources that are already URLs are returned unchanged.
+fn template_fetch_url(template_source: &str) -> String {
+    if template_source.starts_with("http://") || template_source.starts_with("https://") {
+        template_source.to_string()
+    } else {
+        format!("https://github.com/{}", template_source)
+    }
+}
+
+/// Returns the source of a base template in the same organization as
+/// `template_source`, i.e. `template_source` with its last segment replaced.
+fn base_template_source(template_source: &str, base_template: &str) -> String {
+    match template_source.trim_end_matches('/').rsplit_once('/') {
+        Some((owner, _)) => format!("{}/{}", owner, base_template),
+        None => base_template.to_string(),
+    }
+}
+
+/// Returns the repository name at the end of a template source.
+fn template_source_name(template_source: &str) -> &str {
+    let source = template_source.trim_end_matches('/');
+    source.rsplit('/').next().unwrap_or(source)
+}
+
+/// Fetches the files of a template, layered over the files of its base templates.
+///
+/// The chain of base templates is followed through `base_template`, reading
+/// each base's `.reporoller/template.toml`. Files are overlaid from the
+/// outermost base template to `template`, so the more specific template wins
+/// when two contain the same path. Templates without a base template are
+/// fetched as they are.
+///
+/// `template` itself is fetched at `git_ref` when given; base templates are
+/// always fetched at their default branch. The returned commit SHA is the
+/// one `template` was read from.
+///
+/// # Errors
+///
+/// - `TemplateError::BaseTemplateCycle` - a template appears twice in the chain
+/// - `TemplateError::FetchFailed` - files or a base template's configuration
+///   cannot be fetched or parsed
+pub(crate) async fn fetch_composed_template_files(
+    template_source: &str,
+    git_ref: Option<&str>,
+    template: &config_manager::TemplateConfig,
+    template_fetcher: &dyn TemplateFetcher,
+) -> RepoRollerResult<FetchedTemplate> {
+    let fetch_failed = |reason: String| {
+        error!("{}", reason);
+        RepoRollerError::Template(TemplateError::FetchFailed { reason })
+    };
+
+    // Resolve the chain first, so a cycle is reported before any files are fetched.
+    let mut chain = vec![template_source_name(template_source).to_string()];
+    let mut sources = vec![template_source.to_string()];
+    let mut next_base = template.base_template.clone();
+    while let Some(base) = next_base {
+        if chain.contains(&base) {
+            chain.push(base);
+            return Err(RepoRollerError::Template(
+                TemplateError::BaseTemplateCycle {
+                    template: chain[0].clone(),
+                    chain,
+                },
+            ));
+        }
+
+        let source = base_template_source(template_source, &base);
+        let content = template_fetcher
+            .fetch_file(&template_fetch_url(&source), ".reporoller/template.toml")
+            .await
+            .map_err(|e| fetch_failed(format!("Failed to fetch base template '{}': {}", base, e)))?
+            .ok_or_else(|| {
+                fetch_failed(format!(
+                    "Base template '{}' has no .reporoller/template.toml",
+                    base
+                ))
+            })?;
+        let config = config_manager::TemplateConfig::parse(&String::from_utf8_lossy(&content))
+            .map_err(|e| {
+                fetch_failed(format!(
+                    "Invalid configuration in base template '{}': {}",
+                    base, e
+                ))
+            })?;
+
+        next_base = config.base_template;
+        chain.push(base);
+        sources.push(source);
+    }
+
+    if sources.len() > 1 {
+        info!("Composing template from: {}", chain.join(" <- "));
+    }
+
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut index_by_path: HashMap<String, usize> = HashMap::new();
+    let mut commit_sha = None;
+    for (depth, source) in sources.iter().enumerate().rev() {
+        let github_url = template_fetch_url(source);
+        let layer = if depth == 0 {
+            info!(
+                "Fetching template files from: {} at {}",
+                github_url,
+                git_ref.unwrap_or("the default branch")
+            );
+            let fetched = template_fetcher
+                .fetch_template_at(&github_url, git_ref)
+                .await
+                .map_err(|e| fetch_failed(format!("Failed to fetch template files: {}", e)))?;
+            commit_sha = fetched.commit_sha;
+            fetched.files
+        } else {
+            info!("Fetching base template files from: {}", github_url);
+            template_fetcher
+                .fetch_template_files(&github_url)
+                .await
+                .map_err(|e| fetch_failed(format!("Failed to fetch template files: {}", e)))?
+        };
+
+        for (path, content) in layer {
+            let key = path.replace('\\', "/");
+            match index_by_path.get(&key) {
+                Some(&index) => files[index] = (path, content),
+                None => {
+                    index_by_path.insert(key, files.len());
+                    files.push((path, content));
+                }
+            }
+        }
+    }
+
+    if let Some(sha) = &commit_sha {
+        info!("Rendering template commit {}", sha);
+    }
+    Ok(FetchedTemplate { files, commit_sha })
+}
+
 /// Validate that a file path is safe for use in template processing.
 ///
 /// This function ensures that template files cannot perform path traversal attacks
@@ -220,11 +363,23 @@ pub(crate) fn copy_template_files(
 ) -> Result<(), SystemError> {
     debug!("Copying {} template files to local repository", files.len());
 
+    write_files(files, local_repo_path.path())?;
+
+    info!("Template files copied successfully");
+    Ok(())
+}
+
+/// Write files below `target_dir`, rejecting paths that would escape it.
+///
+/// Every path is checked with [`validate_safe_path`] before it is written.
+/// Parent directories are created as needed and existing files are
+/// overwritten.
+fn write_files(files: &[(String, Vec<u8>)], target_dir: &Path) -> Result<(), SystemError> {
     for (file_path, content) in files {
         // Validate the path for security (prevent path traversal attacks)
-        validate_safe_path(file_path, local_repo_path.path())?;
+        validate_safe_path(file_path, target_dir)?;
 
-        let target_path = local_repo_path.path().join(file_path);
+        let target_path = target_dir.join(file_path);
 
         // Create parent directories if they don't exist
         if let Some(parent) = target_path.parent() {
@@ -254,10 +409,9 @@ pub(crate) fn copy_template_files(
             }
         })?;
 
-        debug!("Copied file: {}", file_path);
+        debug!("Wrote file: {}", file_path);
     }
 
-    info!("Template files copied successfully");
     Ok(())
 }
 
@@ -508,6 +662,256 @@ pub fn extract_config_variables(
     variables
 }
 
+/// Builds the template engine request used to render template content.
+///
+/// Combines the built-in variables, the `config_` variables derived from the
+/// merged organization configuration, the user-provided variables from the
+/// request, and the template's variable definitions.
+fn build_processing_request(
+    processor: &TemplateProcessor,
+    req: &RepositoryCreationRequest,
+    template: &config_manager::TemplateConfig,
+    merged_config: &config_manager::MergedConfiguration,
+) -> TemplateProcessingRequest {
+    // Generate built-in variables
+    // Note: Use .as_ref() to convert branded types to &str for template_engine
+    // (avoids circular dependency between crates)
+    let template_name_str = req.template.as_ref().map(|t| t.as_ref()).unwrap_or("none");
+    let built_in_params = template_engine::BuiltInVariablesParams {
+        repo_name: req.name.as_ref(),
+        org_name: req.owner.as_ref(),
+        template_name: template_name_str,
+        template_repo: "unknown", // We'd need to get this from template config
+        user_login: &req.actor_login,
+        user_name: &req.actor_login, // Use login as display name; dedicated display name not in request
+        default_branch: "main",
+    };
+    let mut built_in_variables = processor.generate_built_in_variables(&built_in_params);
+
+    // Details of the CI run creating the repository; empty outside CI
+    built_in_variables.extend(template_engine::generate_ci_variables());
+
+    // Extract configuration-driven variables from merged config
+    let config_variables = extract_config_variables(merged_config);
+
+    // Use user-provided variables from the request
+    let user_variables = req.variables.clone();
+
+    // Merge all variable sources: built-in variables + config variables
+    let mut all_built_in_variables = built_in_variables;
+    all_built_in_variables.extend(config_variables);
+
+    TemplateProcessingRequest {
+        variables: user_variables,
+        built_in_variables: all_built_in_variables,
+        variable_configs: engine_variable_configs(template),
+        templating_config: template.templating.clone(), // Use template's filtering configuration
+        strict_variables: strict_variables(template),
+    }
+}
+
+/// Whether the template asks for references to undeclared variables to fail.
+fn strict_variables(template: &config_manager::TemplateConfig) -> bool {
+    template
+        .templating
+        .as_ref()
+        .is_some_and(|templating| templating.strict_variables)
+}
+
+/// Applies the template's maximum output path length, if it sets one.
+fn with_max_path_length(
+    processor: TemplateProcessor,
+    template: &config_manager::TemplateConfig,
+) -> TemplateProcessor {
+    match template
+        .templating
+        .as_ref()
+        .and_then(|templating| templating.max_path_length)
+    {
+        Some(max_path_length) => processor.with_max_path_length(max_path_length),
+        None => processor,
+    }
+}
+
+/// Converts the template's variable definitions to template engine configs.
+fn engine_variable_configs(
+    template: &config_manager::TemplateConfig,
+) -> HashMap<String, template_engine::VariableConfig> {
+    let mut variable_configs = HashMap::new();
+    if let Some(ref template_vars) = template.variables {
+        for (name, var) in template_vars {
+            let engine_config = template_engine::VariableConfig {
+                description: var.description.clone(),
+                example: var.example.clone(),
+                required: var.required,
+                pattern: var.pattern.clone(),
+                min_length: var.min_length,
+                max_length: var.max_length,
+                options: var.options.clone(),
+                default: var.default.clone(),
+                required_if: var.required_if.clone(),
+                secret: var.secret,
+                transforms: var.transforms.clone().unwrap_or_default(),
+            };
+            variable_configs.insert(name.clone(), engine_config);
+        }
+    }
+    variable_configs
+}
+
+/// Dry-renders every file so that all broken files are reported at once.
+///
+/// Returns a `TemplateError::SyntaxError` listing every file that failed to
+/// render.
+fn ensure_files_render(
+    processor: &TemplateProcessor,
+    files: &[(String, Vec<u8>)],
+    request: &TemplateProcessingRequest,
+) -> RepoRollerResult<()> {
+    let render_failures = processor.validate_render(files, request).map_err(|e| {
+        error!("Template render validation failed: {}", e);
+        SystemError::Internal {
+            reason: format!("Template processing failed: {}", e),
+        }
+    })?;
+    if !render_failures.is_empty() {
+        for failure in &render_failures {
+            error!(
+                "Template file '{}' failed to render: {}",
+                failure.path, failure.reason
+            );
+        }
+        return Err(TemplateError::SyntaxError {
+            file: render_failures
+                .iter()
+                .map(|f| f.path.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            reason: format!(
+                "{} file(s) failed to render: {}",
+                render_failures.len(),
+                render_failures
+                    .iter()
+                    .map(|f| format!("{}: {}", f.path, f.reason))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Validates the request's variables against the template's declared variables.
+///
+/// The template configuration is known before any template file is fetched,
+/// so running this first rejects a request with missing or invalid variables
+/// without downloading the template.
+///
+/// ## Error Types
+///
+/// - `TemplateError::RequiredVariableMissing` - A required variable has no value
+/// - `TemplateError::SubstitutionFailed` - A variable violates its constraints
+pub(crate) fn validate_request_variables(
+    req: &RepositoryCreationRequest,
+    template: &config_manager::TemplateConfig,
+    merged_config: &config_manager::MergedConfiguration,
+    clock: &Arc<dyn Clock>,
+) -> RepoRollerResult<()> {
+    let processor = TemplateProcessor::new()
+        .map_err(|e| SystemError::Internal {
+            reason: format!("Failed to create template processor: {}", e),
+        })?
+        .with_clock(clock.clone());
+
+    let processing_request = build_processing_request(&processor, req, template, merged_config);
+
+    processor
+        .validate_variables(&processing_request)
+        .map_err(variable_validation_error)
+}
+
+/// Collect non-blocking warnings about a template's variables.
+///
+/// Reports required variables whose default makes the requirement a no-op,
+/// and `variables` supplied for the template that it does not declare.
+/// Warnings are sorted by variable name and never block repository creation.
+pub fn collect_template_warnings(
+    template: &config_manager::TemplateConfig,
+    variables: &HashMap<String, String>,
+) -> Vec<config_manager::ValidationWarning> {
+    let declared = template.variables.clone().unwrap_or_default();
+    let mut warnings = Vec::new();
+
+    let mut declared_names: Vec<&String> = declared.keys().collect();
+    declared_names.sort();
+    for name in declared_names {
+        let variable = &declared[name];
+        if variable.required == Some(true) && variable.default.is_some() {
+            warnings.push(config_manager::ValidationWarning {
+                field_path: format!("variables.{}", name),
+                message: format!(
+                    "Variable '{}' is required but has a default value, so it is never missing",
+                    name
+                ),
+                recommendation: Some(
+                    "Remove the default or mark the variable as optional".to_string(),
+                ),
+            });
+        }
+    }
+
+    let mut undeclared: Vec<&String> = variables
+        .keys()
+        .filter(|name| !declared.contains_key(*name))
+        .collect();
+    undeclared.sort();
+    for name in undeclared {
+        warnings.push(config_manager::ValidationWarning {
+            field_path: format!("variables.{}", name),
+            message: format!(
+                "Variable '{}' is not declared by template '{}'",
+                name, template.template.name
+            ),
+            recommendation: Some(
+                "Declare the variable in the template or remove it from the request".to_string(),
+            ),
+        });
+    }
+
+    for warning in &warnings {
+        warn!(
+            field = warning.field_path.as_str(),
+            "Template warning: {}", warning.message
+        );
+    }
+    warnings
+}
+
+/// Maps a template engine variable validation error to a `TemplateError`.
+fn variable_validation_error(e: template_engine::Error) -> RepoRollerError {
+    error!("Template variable validation failed: {}", e);
+    match e {
+        template_engine::Error::RequiredVariableMissing(variable) => {
+            TemplateError::RequiredVariableMissing { variable }
+        }
+        template_engine::Error::PatternValidationFailed { variable, pattern } => {
+            TemplateError::SubstitutionFailed {
+                variable,
+                reason: format!("Value does not match pattern: {}", pattern),
+            }
+        }
+        template_engine::Error::VariableValidation { variable, reason } => {
+            TemplateError::SubstitutionFailed { variable, reason }
+        }
+        other => TemplateError::SubstitutionFailed {
+            variable: "(multiple variables)".to_string(),
+            reason: other.to_string(),
+        },
+    }
+    .into()
+}
+
 /// Process template variables and substitute them in all template files.
 ///
 /// This function handles the variable substitution phase of repository creation,
@@ -528,6 +932,7 @@ pub fn extract_config_variables(
 /// * `req` - Repository creation request containing substitution values
 /// * `template` - Template configuration including variable definitions
 /// * `merged_config` - Merged organization configuration providing additional template variables
+/// * `clock` - Clock used for the built-in `timestamp` variables
 ///
 /// ## Returns
 ///
@@ -572,6 +977,10 @@ pub fn extract_config_variables(
 /// - Template engine processing failures
 /// - Path manipulation errors
 ///
+/// Every file is dry-rendered before any output is written; if one or more
+/// files fail to render, a `TemplateError::SyntaxError` listing all of them is
+/// returned.
+///
 /// ## Template Engine Integration
 ///
 /// Uses the `template_engine` crate for actual variable substitution:
@@ -584,64 +993,19 @@ pub(crate) fn replace_template_variables(
     req: &RepositoryCreationRequest,
     template: &config_manager::TemplateConfig,
     merged_config: &config_manager::MergedConfiguration,
-) -> Result<(), SystemError> {
+    clock: &Arc<dyn Clock>,
+) -> RepoRollerResult<()> {
     debug!("Processing template variables using TemplateProcessor");
 
     // Create template processor
-    let processor = TemplateProcessor::new().map_err(|e| SystemError::Internal {
-        reason: format!("Failed to create template processor: {}", e),
-    })?;
-
-    // Generate built-in variables
-    // Note: Use .as_ref() to convert branded types to &str for template_engine
-    // (avoids circular dependency between crates)
-    let template_name_str = req.template.as_ref().map(|t| t.as_ref()).unwrap_or("none");
-    let built_in_params = template_engine::BuiltInVariablesParams {
-        repo_name: req.name.as_ref(),
-        org_name: req.owner.as_ref(),
-        template_name: template_name_str,
-        template_repo: "unknown", // We'd need to get this from template config
-        user_login: &req.actor_login,
-        user_name: &req.actor_login, // Use login as display name; dedicated display name not in request
-        default_branch: "main",
-    };
-    let built_in_variables = processor.generate_built_in_variables(&built_in_params);
-
-    // Extract configuration-driven variables from merged config
-    let config_variables = extract_config_variables(merged_config);
-
-    // Use user-provided variables from the request
-    let user_variables = req.variables.clone();
-
-    // Convert config_manager::TemplateVariable to template_engine::VariableConfig
-    let mut variable_configs = HashMap::new();
-    if let Some(ref template_vars) = template.variables {
-        for (name, var) in template_vars {
-            let engine_config = template_engine::VariableConfig {
-                description: var.description.clone(),
-                example: var.example.clone(),
-                required: var.required,
-                pattern: var.pattern.clone(),
-                min_length: var.min_length,
-                max_length: var.max_length,
-                options: var.options.clone(),
-                default: var.default.clone(),
-            };
-            variable_configs.insert(name.clone(), engine_config);
-        }
-    }
-
-    // Merge all variable sources: built-in variables + config variables
-    let mut all_built_in_variables = built_in_variables;
-    all_built_in_variables.extend(config_variables);
+    let processor = TemplateProcessor::new()
+        .map_err(|e| SystemError::Internal {
+            reason: format!("Failed to create template processor: {}", e),
+        })?
+        .with_clock(clock.clone());
+    let processor = with_max_path_length(processor, template);
 
-    // Create processing request
-    let processing_request = TemplateProcessingRequest {
-        variables: user_variables,
-        built_in_variables: all_built_in_variables,
-        variable_configs,
-        templating_config: template.templating.clone(), // Use template's filtering configuration
-    };
+    let processing_request = build_processing_request(&processor, req, template, merged_config);
 
     // Read all files that were copied to the local repo
     let mut files_to_process = Vec::new();
@@ -678,8 +1042,12 @@ pub(crate) fn replace_template_variables(
         }
     }
 
+    // Dry-render every file first so that all broken files are reported at once,
+    // before anything is written or the repository is created on GitHub
+    ensure_files_render(&processor, &files_to_process, &processing_request)?;
+
     // Process the template files
-    let processed = processor
+    let mut processed = processor
         .process_template(
             &files_to_process,
             &processing_request,
@@ -692,6 +1060,8 @@ pub(crate) fn replace_template_variables(
             }
         })?;
 
+    apply_post_generation(&mut processed, template, merged_config);
+
     // Write the processed files back to the local repo
     // First, clear the directory (except .git)
     for entry in WalkDir::new(local_repo_path.path())
@@ -749,14 +1119,106 @@ pub(crate) fn replace_template_variables(
     Ok(())
 }
 
+/// Runs the template's opt-in post-generation transforms (in-process, built-in
+/// only) when the organization configuration allows them.
+fn apply_post_generation(
+    processed: &mut ProcessedTemplate,
+    template: &config_manager::TemplateConfig,
+    merged_config: &config_manager::MergedConfiguration,
+) {
+    if let Some(templating) = &template.templating {
+        if !templating.post_generation.is_empty() {
+            if merged_config.allow_template_post_generation {
+                info!(
+                    "Applying post-generation transforms: {:?}",
+                    templating.post_generation
+                );
+                template_engine::apply_post_generation_transforms(
+                    processed,
+                    &templating.post_generation,
+                );
+            } else {
+                warn!(
+                    "Template declares post-generation transforms but they are disabled \
+                     by organization configuration; skipping"
+                );
+            }
+        }
+    }
+}
+
+/// Resolve the description for a new repository.
+///
+/// A description supplied in the request always wins. Otherwise the template's
+/// `default_description` is rendered through the template engine with the same
+/// variables used for the template files, so it may reference values such as
+/// `{{project_name}}`.
+///
+/// ## Returns
+///
+/// * `Ok(Some(description))` - The resolved description
+/// * `Ok(None)` - Neither the request nor the template provides a description
+/// * `Err(Error)` - The template's default description failed to render
+pub(crate) fn resolve_repository_description(
+    req: &RepositoryCreationRequest,
+    template: Option<&config_manager::TemplateConfig>,
+    merged_config: &config_manager::MergedConfiguration,
+    clock: &Arc<dyn Clock>,
+) -> RepoRollerResult<Option<String>> {
+    if let Some(description) = &req.description {
+        return Ok(Some(description.clone()));
+    }
+
+    let Some(template) = template else {
+        return Ok(None);
+    };
+    let Some(default_description) = &template.template.default_description else {
+        return Ok(None);
+    };
+
+    let processor = TemplateProcessor::new()
+        .map_err(|e| SystemError::Internal {
+            reason: format!("Failed to create template processor: {}", e),
+        })?
+        .with_clock(clock.clone());
+    let processing_request = build_processing_request(&processor, req, template, merged_config);
+
+    let description = processor
+        .render_string(default_description, &processing_request)
+        .map_err(|e| TemplateError::SubstitutionFailed {
+            variable: "default_description".to_string(),
+            reason: e.to_string(),
+        })?;
+
+    Ok(Some(description))
+}
+
+/// Resolve the topics for a new repository.
+///
+/// Topics supplied in the request replace the template's `default_topics`
+/// entirely; the two lists are not merged.
+pub(crate) fn resolve_repository_topics(
+    req: &RepositoryCreationRequest,
+    template: Option<&config_manager::TemplateConfig>,
+) -> Vec<String> {
+    if !req.topics.is_empty() {
+        return req.topics.clone();
+    }
+
+    template
+        .map(|t| t.template.default_topics.clone())
+        .unwrap_or_default()
+}
+
 /// Prepare local repository with template files and processing.
 ///
 /// This function orchestrates the complete local repository preparation workflow:
-/// 1. Creates a temporary directory for the repository
-/// 2. Fetches template files from the source repository
-/// 3. Copies template files to the local directory
-/// 4. Processes template variables and performs substitutions
-/// 5. Creates additional standard files (README.md, .gitignore) if not provided by template
+/// 1. Validates the request variables against the template configuration
+/// 2. Creates a temporary directory for the repository
+/// 3. Fetches template files from the source repository, at `request.template_ref` if set
+/// 4. Copies template files to the local directory
+/// 5. Processes template variables and performs substitutions
+/// 6. Creates additional standard files (README.md, .gitignore) if not provided by template
 ///
 /// ## Parameters
 ///
@@ -764,17 +1226,21 @@ pub(crate) fn replace_template_variables(
 /// * `template` - Template configuration including source repository and variable definitions
 /// * `template_fetcher` - Trait object for fetching template files from source
 /// * `merged_config` - Merged organization configuration providing template variables
+/// * `clock` - Clock used for the built-in `timestamp` variables
 ///
 /// ## Returns
 ///
-/// * `Ok(TempDir)` - Temporary directory containing the prepared repository
+/// * `Ok((TempDir, Option<String>))` - Temporary directory containing the prepared
+///   repository, and the SHA of the template commit it was rendered from if known
 /// * `Err(RepoRollerError)` - If any step in the preparation fails
 ///
 /// ## Error Types
 ///
 /// - `SystemError::Internal` - Temporary directory creation or file operations failed
+/// - `TemplateError::RequiredVariableMissing` - A required variable has no value; reported before fetching
 /// - `TemplateError::FetchFailed` - Template file fetching failed
 /// - `TemplateError::SubstitutionFailed` - Variable substitution failed
+/// - `TemplateError::SyntaxError` - One or more template files failed to render
 ///
 /// ## Cleanup
 ///
@@ -786,7 +1252,12 @@ pub(crate) async fn prepare_local_repository(
     template_source: &str,
     template_fetcher: &dyn TemplateFetcher,
     merged_config: &config_manager::MergedConfiguration,
-) -> RepoRollerResult<TempDir> {
+    clock: &Arc<dyn Clock>,
+) -> RepoRollerResult<(TempDir, Option<String>)> {
+    // Fail fast on invalid variables before downloading the template
+    debug!("Validating template variables");
+    validate_request_variables(request, template, merged_config, clock)?;
+
     // Create temporary directory
     let local_repo_path = TempDir::new().map_err(|e| {
         error!("Failed to create temporary directory: {}", e);
@@ -795,25 +1266,14 @@ pub(crate) async fn prepare_local_repository(
         })
     })?;
 
-    // Fetch template files
-    // Convert owner/repo format to full GitHub URL
-    let github_url =
-        if template_source.starts_with("http://") || template_source.starts_with("https://") {
-            template_source.to_string()
-        } else {
-            format!("https://github.com/{}", template_source)
-        };
-
-    info!("Fetching template files from: {}", github_url);
-    let files = template_fetcher
-        .fetch_template_files(&github_url)
-        .await
-        .map_err(|e| {
-            error!("Failed to fetch template files: {}", e);
-            RepoRollerError::Template(TemplateError::FetchFailed {
-                reason: format!("Failed to fetch template files: {}", e),
-            })
-        })?;
+    // Fetch template files, layered over any base templates
+    let FetchedTemplate { files, commit_sha } = fetch_composed_template_files(
+        template_source,
+        request.template_ref.as_deref(),
+        template,
+        template_fetcher,
+    )
+    .await?;
 
     // Copy template files
     debug!("Copying template files to local repository");
@@ -826,13 +1286,16 @@ pub(crate) async fn prepare_local_repository(
 
     // Process template variables
     debug!("Processing template variables");
-    replace_template_variables(&local_repo_path, request, template, merged_config).map_err(
-        |e| {
-            error!("Failed to replace template variables: {}", e);
-            RepoRollerError::Template(TemplateError::SubstitutionFailed {
-                variable: "(multiple variables)".to_string(),
-                reason: format!("Batch variable replacement failed: {}", e),
-            })
+    replace_template_variables(&local_repo_path, request, template, merged_config, clock).map_err(
+        |e| match e {
+            RepoRollerError::Template(_) => e,
+            other => {
+                error!("Failed to replace template variables: {}", other);
+                RepoRollerError::Template(TemplateError::SubstitutionFailed {
+                    variable: "(multiple variables)".to_string(),
+                    reason: format!("Batch variable replacement failed: {}", other),
+                })
+            }
         },
     )?;
 
@@ -845,7 +1308,351 @@ pub(crate) async fn prepare_local_repository(
         })
     })?;
 
-    Ok(local_repo_path)
+    Ok((local_repo_path, commit_sha))
+}
+
+/// The files and declared variables of a template, as returned by
+/// [`inspect_template`].
+#[derive(Debug, Clone)]
+pub struct TemplateInspection {
+    /// Template files, sorted by path.
+    pub files: Vec<TemplateFileEntry>,
+
+    /// Variables declared in the template configuration.
+    pub variables: HashMap<String, config_manager::TemplateVariable>,
+}
+
+/// Lists a template's files and declared variables without rendering it.
+///
+/// The template files are fetched exactly as they would be for repository
+/// creation, but nothing is rendered, written to disk, or created on GitHub.
+///
+/// ## Parameters
+///
+/// * `template_source` - Template repository, as `owner/repo` or a URL
+/// * `template` - Template configuration providing the declared variables
+/// * `template_fetcher` - Trait object for fetching template files from source
+///
+/// ## Error Types
+///
+/// - `TemplateError::FetchFailed` - Template file fetching failed
+pub async fn inspect_template(
+    template_source: &str,
+    template: &config_manager::TemplateConfig,
+    template_fetcher: &dyn TemplateFetcher,
+) -> RepoRollerResult<TemplateInspection> {
+    let github_url = template_fetch_url(template_source);
+
+    debug!("Inspecting template files from: {}", github_url);
+    let files = template_fetcher
+        .fetch_template_files(&github_url)
+        .await
+        .map_err(|e| {
+            error!("Failed to fetch template files: {}", e);
+            RepoRollerError::Template(TemplateError::FetchFailed {
+                reason: format!("Failed to fetch template files: {}", e),
+            })
+        })?;
+
+    Ok(TemplateInspection {
+        files: template_engine::build_file_manifest(&files),
+        variables: template.variables.clone().unwrap_or_default(),
+    })
+}
+
+/// Names and variable values used to render a template with
+/// [`render_template_to_directory`].
+#[derive(Debug, Clone, Default)]
+pub struct LocalRenderRequest {
+    /// Value of the built-in `repo_name` variable.
+    pub repo_name: String,
+
+    /// Value of the built-in `org_name` variable.
+    pub org_name: String,
+
+    /// Value of the built-in `template_name` variable.
+    pub template_name: String,
+
+    /// User-provided variable values.
+    pub variables: HashMap<String, String>,
+}
+
+/// Renders template files into a local directory.
+///
+/// The files are rendered exactly as for repository creation, apart from the
+/// organization's `config_` variables and post-generation transforms, which
+/// need the merged organization configuration. Nothing is created on GitHub.
+/// `out_dir` is created if needed and existing files in it are overwritten.
+///
+/// ## Parameters
+///
+/// * `files` - Template files, e.g. from a [`TemplateFetcher`]
+/// * `template` - Template configuration providing the declared variables
+/// * `request` - Built-in names and user-provided variable values
+/// * `out_dir` - Directory the rendered files are written to
+///
+/// ## Returns
+///
+/// The manifest of the rendered files, sorted by path.
+///
+/// ## Error Types
+///
+/// - `TemplateError::SyntaxError` - One or more files failed to render
+/// - `SystemError::FileSystem` - A rendered path escapes `out_dir` or cannot be written
+/// - `SystemError::Internal` - Variable validation or rendering failed
+pub fn render_template_to_directory(
+    files: &[(String, Vec<u8>)],
+    template: &config_manager::TemplateConfig,
+    request: &LocalRenderRequest,
+    out_dir: &Path,
+) -> RepoRollerResult<Vec<TemplateFileEntry>> {
+    let processed = render_local_template(files, template, request, out_dir)?;
+
+    fs::create_dir_all(out_dir).map_err(|e| SystemError::FileSystem {
+        operation: "create directory".to_string(),
+        reason: format!("{:?}: {}", out_dir, e),
+    })?;
+    write_files(&processed.files, out_dir)?;
+
+    info!(
+        "Rendered {} template file(s) to {:?}",
+        processed.files.len(),
+        out_dir
+    );
+    Ok(template_engine::build_file_manifest(&processed.files))
+}
+
+/// Renders template files for a [`LocalRenderRequest`] without writing them.
+///
+/// `out_dir` is the directory the rendered paths are resolved against.
+fn render_local_template(
+    files: &[(String, Vec<u8>)],
+    template: &config_manager::TemplateConfig,
+    request: &LocalRenderRequest,
+    out_dir: &Path,
+) -> RepoRollerResult<ProcessedTemplate> {
+    let processor = TemplateProcessor::new().map_err(|e| SystemError::Internal {
+        reason: format!("Failed to create template processor: {}", e),
+    })?;
+    let processor = with_max_path_length(processor, template);
+
+    let mut built_in_variables =
+        processor.generate_built_in_variables(&template_engine::BuiltInVariablesParams {
+            repo_name: &request.repo_name,
+            org_name: &request.org_name,
+            template_name: &request.template_name,
+            template_repo: "unknown",
+            user_login: "",
+            user_name: "",
+            default_branch: "main",
+        });
+    built_in_variables.extend(template_engine::generate_ci_variables());
+
+    let processing_request = TemplateProcessingRequest {
+        variables: request.variables.clone(),
+        built_in_variables,
+        variable_configs: engine_variable_configs(template),
+        templating_config: template.templating.clone(),
+        strict_variables: strict_variables(template),
+    };
+
+    ensure_files_render(&processor, files, &processing_request)?;
+
+    let processed = processor
+        .process_template(files, &processing_request, out_dir)
+        .map_err(|e| {
+            error!("Template processing failed: {}", e);
+            SystemError::Internal {
+                reason: format!("Template processing failed: {}", e),
+            }
+        })?;
+    Ok(processed)
+}
+
+/// The outcome of [`dry_run_template`].
+#[derive(Debug, Clone, Default)]
+pub struct TemplateDryRun {
+    /// Paths the rendered files would be written to, in template order.
+    pub rendered_paths: Vec<String>,
+
+    /// Template files that failed to render.
+    pub render_failures: Vec<template_engine::RenderFailure>,
+}
+
+/// Validates a request's variables and dry-renders the template files.
+///
+/// Uses the same variables as repository creation, including the `config_`
+/// variables from the merged organization configuration, but nothing is
+/// written to disk or created on GitHub. Unlike repository creation, every
+/// file that fails to render is reported rather than the first.
+///
+/// ## Parameters
+///
+/// * `files` - Template files, e.g. from a [`TemplateFetcher`]
+/// * `request` - Repository creation request providing names and variable values
+/// * `template` - Template configuration providing the declared variables
+/// * `merged_config` - Merged organization configuration
+///
+/// ## Error Types
+///
+/// - `TemplateError::RequiredVariableMissing` - A required variable has no value
+/// - `TemplateError::SubstitutionFailed` - A variable violates its constraints
+/// - `SystemError::Internal` - The template processor could not be created
+pub fn dry_run_template(
+    files: &[(String, Vec<u8>)],
+    request: &RepositoryCreationRequest,
+    template: &config_manager::TemplateConfig,
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<TemplateDryRun> {
+    let processor = TemplateProcessor::new().map_err(|e| SystemError::Internal {
+        reason: format!("Failed to create template processor: {}", e),
+    })?;
+
+    let processing_request = build_processing_request(&processor, request, template, merged_config);
+    processor
+        .validate_variables(&processing_request)
+        .map_err(variable_validation_error)?;
+
+    let dry_render = processor
+        .dry_render(files, &processing_request)
+        .map_err(|e| {
+            error!("Template dry render failed: {}", e);
+            SystemError::Internal {
+                reason: format!("Template processing failed: {}", e),
+            }
+        })?;
+
+    Ok(TemplateDryRun {
+        rendered_paths: dry_render.output_paths,
+        render_failures: dry_render.failures,
+    })
+}
+
+/// A rendered file of a content preview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedFilePreview {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+
+    /// Size of the rendered content in bytes.
+    pub size: u64,
+
+    /// Whether the file was rendered as text or copied as binary content.
+    pub kind: TemplateFileKind,
+
+    /// Hex-encoded SHA-256 digest of the rendered content.
+    pub sha256: String,
+
+    /// Rendered content of a text file. Binary files are only summarized by
+    /// size and digest, so this is `None` for them.
+    pub content: Option<String>,
+}
+
+/// Renders a request's template files as they would be committed, without
+/// creating anything.
+///
+/// Uses the same variables and post-generation transforms as repository
+/// creation, but nothing is written to disk or created on GitHub. Additional
+/// files such as a generated README or `.gitignore` are not included.
+///
+/// ## Parameters
+///
+/// * `files` - Template files, e.g. from a [`TemplateFetcher`]
+/// * `request` - Repository creation request providing names and variable values
+/// * `template` - Template configuration providing the declared variables
+/// * `merged_config` - Merged organization configuration
+///
+/// ## Returns
+///
+/// The rendered files, sorted by path.
+///
+/// ## Error Types
+///
+/// - `TemplateError::RequiredVariableMissing` - A required variable has no value
+/// - `TemplateError::SubstitutionFailed` - A variable violates its constraints
+/// - `TemplateError::SyntaxError` - One or more files failed to render
+/// - `SystemError::Internal` - The template could not be processed
+pub fn preview_template_content(
+    files: &[(String, Vec<u8>)],
+    request: &RepositoryCreationRequest,
+    template: &config_manager::TemplateConfig,
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<Vec<RenderedFilePreview>> {
+    let processor = TemplateProcessor::new().map_err(|e| SystemError::Internal {
+        reason: format!("Failed to create template processor: {}", e),
+    })?;
+    let processor = with_max_path_length(processor, template);
+
+    let processing_request = build_processing_request(&processor, request, template, merged_config);
+    processor
+        .validate_variables(&processing_request)
+        .map_err(variable_validation_error)?;
+    ensure_files_render(&processor, files, &processing_request)?;
+
+    let mut processed = processor
+        .process_template(files, &processing_request, Path::new("."))
+        .map_err(|e| {
+            error!("Template processing failed: {}", e);
+            SystemError::Internal {
+                reason: format!("Template processing failed: {}", e),
+            }
+        })?;
+    apply_post_generation(&mut processed, template, merged_config);
+
+    Ok(preview_rendered_files(processed))
+}
+
+/// Renders template files for a [`LocalRenderRequest`] without writing them.
+///
+/// This is [`render_template_to_directory`] without the output directory:
+/// the organization's `config_` variables and post-generation transforms are
+/// not applied.
+///
+/// ## Returns
+///
+/// The rendered files, sorted by path.
+///
+/// ## Error Types
+///
+/// - `TemplateError::SyntaxError` - One or more files failed to render
+/// - `SystemError::Internal` - Variable validation or rendering failed
+pub fn preview_local_template(
+    files: &[(String, Vec<u8>)],
+    template: &config_manager::TemplateConfig,
+    request: &LocalRenderRequest,
+) -> RepoRollerResult<Vec<RenderedFilePreview>> {
+    let processed = render_local_template(files, template, request, Path::new("."))?;
+    Ok(preview_rendered_files(processed))
+}
+
+/// Summarizes processed files for a preview, sorted by path.
+fn preview_rendered_files(processed: ProcessedTemplate) -> Vec<RenderedFilePreview> {
+    use sha2::{Digest, Sha256};
+
+    let mut previews: Vec<RenderedFilePreview> = processed
+        .files
+        .into_iter()
+        .map(|(path, content)| {
+            let is_text = template_engine::is_text_content(&content);
+            RenderedFilePreview {
+                size: content.len() as u64,
+                kind: if is_text {
+                    TemplateFileKind::Text
+                } else {
+                    TemplateFileKind::Binary
+                },
+                sha256: hex::encode(Sha256::digest(&content)),
+                content: if is_text {
+                    String::from_utf8(content).ok()
+                } else {
+                    None
+                },
+                path,
+            }
+        })
+        .collect();
+    previews.sort_by(|a, b| a.path.cmp(&b.path));
+    previews
 }
 
 #[cfg(test)]
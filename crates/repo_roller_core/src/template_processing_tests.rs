@@ -368,9 +368,826 @@ mod copy_template_files_tests {
     }
 }
 
+/// Module for dry-render validation performed before template output is written
+mod render_validation_tests {
+    use super::*;
+    use crate::{
+        ContentStrategy, OrganizationName, RepositoryCreationRequestBuilder, RepositoryName,
+    };
+
+    pub(super) fn test_template_config() -> config_manager::TemplateConfig {
+        config_manager::TemplateConfig {
+            template: config_manager::TemplateMetadata {
+                name: "test-template".to_string(),
+                description: "Test template".to_string(),
+                author: "Test Author".to_string(),
+                tags: vec![],
+                default_description: None,
+                default_topics: vec![],
+                required_code_owners: vec![],
+            },
+            repository: None,
+            repository_type: None,
+            pull_requests: None,
+            branch_protection: None,
+            labels: None,
+            webhooks: None,
+            environments: None,
+            github_apps: None,
+            rulesets: None,
+            variables: None,
+            default_visibility: None,
+            templating: None,
+            notifications: None,
+            permissions: None,
+            teams: None,
+            collaborators: None,
+            naming_rules: None,
+            actions_variables: None,
+            actions_secrets: None,
+            variable_groups: None,
+            base_template: None,
+        }
+    }
+
+    /// Test that every file that fails to render is reported and no output is written.
+    #[test]
+    fn test_replace_template_variables_reports_all_render_failures() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let readme = "# {{repo_name}}";
+        std::fs::write(temp_dir.path().join("README.md"), readme).unwrap();
+        std::fs::write(temp_dir.path().join("a.md"), "{{#each items}}").unwrap();
+        std::fs::write(temp_dir.path().join("b.txt"), "{{#if repo_name}}").unwrap();
+
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+
+        let result = replace_template_variables(
+            &temp_dir,
+            &request,
+            &test_template_config(),
+            &config_manager::MergedConfiguration::new(),
+            &(std::sync::Arc::new(template_engine::SystemClock)
+                as std::sync::Arc<dyn template_engine::Clock>),
+        );
+
+        match result {
+            Err(RepoRollerError::Template(TemplateError::SyntaxError { file, reason })) => {
+                assert!(file.contains("a.md"), "Missing a.md in '{}'", file);
+                assert!(file.contains("b.txt"), "Missing b.txt in '{}'", file);
+                assert!(!file.contains("README.md"));
+                assert!(reason.contains("2 file(s)"));
+            }
+            other => panic!("Expected SyntaxError, got {:?}", other),
+        }
+
+        let readme_after = std::fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+        assert_eq!(readme_after, readme, "No output should be written");
+    }
+
+    /// Test that a dry run reports rendered paths alongside render failures.
+    #[test]
+    fn test_dry_run_template_reports_rendered_paths_and_failures() {
+        let files = vec![
+            (
+                "{{repo_name}}/README.md".to_string(),
+                b"# {{repo_name}}".to_vec(),
+            ),
+            ("broken.md".to_string(), b"{{#each items}}".to_vec()),
+        ];
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+
+        let dry_run = dry_run_template(
+            &files,
+            &request,
+            &test_template_config(),
+            &config_manager::MergedConfiguration::new(),
+        )
+        .expect("Variables should be valid");
+
+        assert_eq!(
+            dry_run.rendered_paths,
+            vec!["test-repo/README.md", "broken.md"]
+        );
+        assert_eq!(dry_run.render_failures.len(), 1);
+        assert_eq!(dry_run.render_failures[0].path, "broken.md");
+    }
+
+    /// Test that a content preview inlines rendered text and summarizes binary files.
+    #[test]
+    fn test_preview_template_content_summarizes_binary_files() {
+        let files = vec![
+            ("logo.png".to_string(), vec![0x89, b'P', 0x00]),
+            ("README.md".to_string(), b"# {{repo_name}}".to_vec()),
+        ];
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+
+        let preview = preview_template_content(
+            &files,
+            &request,
+            &test_template_config(),
+            &config_manager::MergedConfiguration::new(),
+        )
+        .expect("Preview should succeed");
+
+        let paths: Vec<&str> = preview.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["README.md", "logo.png"]);
+        assert_eq!(preview[0].content.as_deref(), Some("# test-repo"));
+        assert_eq!(preview[0].kind, TemplateFileKind::Text);
+        assert_eq!(preview[1].content, None);
+        assert_eq!(preview[1].kind, TemplateFileKind::Binary);
+        assert_eq!(preview[1].size, 3);
+        assert_eq!(preview[1].sha256.len(), 64);
+    }
+
+    /// Test that a template's `strict_variables` setting makes undeclared references fail.
+    #[test]
+    fn test_template_strict_variables_rejects_undeclared_reference() {
+        let files = vec![("README.md".to_string(), b"{{undeclared_key}}".to_vec())];
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+        let merged = config_manager::MergedConfiguration::new();
+
+        let mut template = test_template_config();
+        assert!(preview_template_content(&files, &request, &template, &merged).is_ok());
+
+        template.templating = Some(template_engine::TemplatingConfig {
+            include_patterns: vec![],
+            exclude_patterns: vec![],
+            post_generation: vec![],
+            case_insensitive: false,
+            glob_recursive: false,
+            strict_variables: true,
+            max_path_length: None,
+        });
+        assert!(preview_template_content(&files, &request, &template, &merged).is_err());
+    }
+
+    /// Test that a template's `max_path_length` setting rejects longer output paths.
+    #[test]
+    fn test_template_max_path_length_rejects_long_paths() {
+        let files = vec![(
+            "docs/architecture/decisions/README.md".to_string(),
+            b"# {{repo_name}}".to_vec(),
+        )];
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+        let merged = config_manager::MergedConfiguration::new();
+
+        let mut template = test_template_config();
+        assert!(preview_template_content(&files, &request, &template, &merged).is_ok());
+
+        template.templating = Some(template_engine::TemplatingConfig {
+            include_patterns: vec![],
+            exclude_patterns: vec![],
+            post_generation: vec![],
+            case_insensitive: false,
+            glob_recursive: false,
+            strict_variables: false,
+            max_path_length: Some(20),
+        });
+        let error = preview_template_content(&files, &request, &template, &merged)
+            .expect_err("Paths longer than the template's limit should be rejected");
+        assert!(error.to_string().contains("maximum path length"));
+    }
+
+    /// Test that no-op variable settings and undeclared variables are reported as warnings.
+    #[test]
+    fn test_collect_template_warnings_reports_variable_problems() {
+        let mut template = test_template_config();
+        template.variables = Some(HashMap::from([(
+            "service_name".to_string(),
+            config_manager::TemplateVariable {
+                description: "Service name".to_string(),
+                example: None,
+                required: Some(true),
+                pattern: None,
+                min_length: None,
+                max_length: None,
+                options: None,
+                default: Some("service".to_string()),
+                required_if: None,
+                secret: None,
+                transforms: None,
+            },
+        )]));
+        let variables = HashMap::from([
+            ("service_name".to_string(), "billing".to_string()),
+            ("team_name".to_string(), "payments".to_string()),
+        ]);
+
+        let warnings = collect_template_warnings(&template, &variables);
+
+        let fields: Vec<&str> = warnings.iter().map(|w| w.field_path.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["variables.service_name", "variables.team_name"]
+        );
+        assert!(warnings[0].message.contains("has a default value"));
+        assert!(warnings[1]
+            .message
+            .contains("not declared by template 'test-template'"));
+    }
+}
+
+/// Module for resolving repository description and topics from template metadata
+mod repository_metadata_tests {
+    use super::render_validation_tests::test_template_config;
+    use super::*;
+    use crate::{OrganizationName, RepositoryCreationRequestBuilder, RepositoryName};
+
+    fn clock() -> Arc<dyn Clock> {
+        Arc::new(template_engine::SystemClock)
+    }
+
+    fn request_builder() -> RepositoryCreationRequestBuilder {
+        RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("billing-api").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+    }
+
+    /// Test that the template's default description is rendered with user variables.
+    #[test]
+    fn test_default_description_renders_user_variables() {
+        let mut template = test_template_config();
+        template.template.default_description =
+            Some("{{project_name}} service owned by {{org_name}}".to_string());
+        let request = request_builder()
+            .variable("project_name", "Billing")
+            .build();
+
+        let description = resolve_repository_description(
+            &request,
+            Some(&template),
+            &config_manager::MergedConfiguration::new(),
+            &clock(),
+        )
+        .expect("Description should render");
+
+        assert_eq!(
+            description.as_deref(),
+            Some("Billing service owned by test-org")
+        );
+    }
+
+    /// Test that a description in the request overrides the template default.
+    #[test]
+    fn test_request_description_overrides_template_default() {
+        let mut template = test_template_config();
+        template.template.default_description = Some("{{project_name}} service".to_string());
+        let request = request_builder()
+            .description("Explicit description")
+            .build();
+
+        let description = resolve_repository_description(
+            &request,
+            Some(&template),
+            &config_manager::MergedConfiguration::new(),
+            &clock(),
+        )
+        .expect("Description should resolve");
+
+        assert_eq!(description.as_deref(), Some("Explicit description"));
+    }
+
+    /// Test that no description is resolved when neither source provides one.
+    #[test]
+    fn test_description_absent_without_request_or_template_default() {
+        let request = request_builder().build();
+
+        let description = resolve_repository_description(
+            &request,
+            Some(&test_template_config()),
+            &config_manager::MergedConfiguration::new(),
+            &clock(),
+        )
+        .expect("Description should resolve");
+
+        assert!(description.is_none());
+    }
+
+    /// Test that request topics replace the template's default topics.
+    #[test]
+    fn test_topics_resolution_prefers_request() {
+        let mut template = test_template_config();
+        template.template.default_topics = vec!["rust".to_string(), "service".to_string()];
+
+        let from_template = resolve_repository_topics(&request_builder().build(), Some(&template));
+        assert_eq!(from_template, vec!["rust", "service"]);
+
+        let request = request_builder()
+            .topics(vec!["billing".to_string()])
+            .build();
+        let from_request = resolve_repository_topics(&request, Some(&template));
+        assert_eq!(from_request, vec!["billing"]);
+
+        assert!(resolve_repository_topics(&request_builder().build(), None).is_empty());
+    }
+}
+
 #[test]
 fn test_template_processing_module_compiles() {
     // This test ensures the module compiles correctly.
     // Actual template processing functionality is tested via integration tests
     // in the integration_tests crate.
 }
+
+/// Module for listing template files without rendering
+mod inspect_template_tests {
+    use super::render_validation_tests::test_template_config;
+    use super::*;
+    use std::sync::Mutex;
+    use template_engine::TemplateFileKind;
+
+    /// Fetcher that serves a fixed, in-memory set of template files and records
+    /// the sources it was asked for.
+    pub(super) struct InMemoryTemplateFetcher {
+        pub(super) files: Vec<(String, Vec<u8>)>,
+        pub(super) requested_sources: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TemplateFetcher for InMemoryTemplateFetcher {
+        async fn fetch_template_files(
+            &self,
+            source: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, String> {
+            self.requested_sources
+                .lock()
+                .unwrap()
+                .push(source.to_string());
+            Ok(self.files.clone())
+        }
+    }
+
+    struct FailingTemplateFetcher;
+
+    #[async_trait::async_trait]
+    impl TemplateFetcher for FailingTemplateFetcher {
+        async fn fetch_template_files(
+            &self,
+            _source: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, String> {
+            Err("repository not found".to_string())
+        }
+    }
+
+    /// Test that the manifest lists every fixture file with its size and kind,
+    /// together with the template's declared variables.
+    #[tokio::test]
+    async fn test_inspect_template_returns_file_manifest() {
+        let fetcher = InMemoryTemplateFetcher {
+            files: vec![
+                ("src/lib.rs".to_string(), b"//! {{project_name}}\n".to_vec()),
+                ("README.md".to_string(), b"# {{repo_name}}\n".to_vec()),
+                ("docs/diagram.png".to_string(), vec![0x89, b'P', 0x00, 0xff]),
+            ],
+            requested_sources: Mutex::new(Vec::new()),
+        };
+        let mut template = test_template_config();
+        let mut variables = HashMap::new();
+        variables.insert(
+            "project_name".to_string(),
+            config_manager::TemplateVariable {
+                description: "Project name".to_string(),
+                example: None,
+                required: Some(true),
+                pattern: None,
+                min_length: None,
+                max_length: None,
+                options: None,
+                default: None,
+                required_if: None,
+                secret: None,
+                transforms: None,
+            },
+        );
+        template.variables = Some(variables);
+
+        let inspection = inspect_template("test-org/test-template", &template, &fetcher)
+            .await
+            .expect("inspection should succeed");
+
+        let paths: Vec<&str> = inspection.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["README.md", "docs/diagram.png", "src/lib.rs"]);
+        assert_eq!(inspection.files[0].size, 16);
+        assert_eq!(inspection.files[0].kind, TemplateFileKind::Text);
+        assert_eq!(inspection.files[1].size, 4);
+        assert_eq!(inspection.files[1].kind, TemplateFileKind::Binary);
+        assert_eq!(inspection.files[2].kind, TemplateFileKind::Text);
+
+        assert_eq!(inspection.variables.len(), 1);
+        assert!(inspection.variables.contains_key("project_name"));
+
+        assert_eq!(
+            *fetcher.requested_sources.lock().unwrap(),
+            vec!["https://github.com/test-org/test-template".to_string()]
+        );
+    }
+
+    /// Test that a fetch failure is reported as a template fetch error.
+    #[tokio::test]
+    async fn test_inspect_template_reports_fetch_failure() {
+        let result = inspect_template(
+            "test-org/missing",
+            &test_template_config(),
+            &FailingTemplateFetcher,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(RepoRollerError::Template(TemplateError::FetchFailed { .. }))
+        ));
+    }
+}
+
+/// Module for preparing the local repository from a template
+mod prepare_local_repository_tests {
+    use super::inspect_template_tests::InMemoryTemplateFetcher;
+    use super::render_validation_tests::test_template_config;
+    use super::*;
+    use crate::{
+        ContentStrategy, OrganizationName, RepositoryCreationRequestBuilder, RepositoryName,
+    };
+    use std::sync::Mutex;
+
+    /// Test that a missing required variable is reported before any template
+    /// file is fetched.
+    #[tokio::test]
+    async fn test_prepare_local_repository_validates_variables_before_fetching() {
+        let fetcher = InMemoryTemplateFetcher {
+            files: vec![("README.md".to_string(), b"# {{service}}".to_vec())],
+            requested_sources: Mutex::new(Vec::new()),
+        };
+        let mut template = test_template_config();
+        template.variables = Some(HashMap::from([(
+            "service".to_string(),
+            config_manager::TemplateVariable {
+                description: "Service name".to_string(),
+                example: None,
+                required: Some(true),
+                pattern: None,
+                min_length: None,
+                max_length: None,
+                options: None,
+                default: None,
+                required_if: None,
+                secret: None,
+                transforms: None,
+            },
+        )]));
+        let request = RepositoryCreationRequestBuilder::new(
+            RepositoryName::new("test-repo").unwrap(),
+            OrganizationName::new("test-org").unwrap(),
+        )
+        .content_strategy(ContentStrategy::Empty)
+        .build();
+        let clock: Arc<dyn Clock> = Arc::new(template_engine::SystemClock);
+
+        let result = prepare_local_repository(
+            &request,
+            &template,
+            "test-org/test-template",
+            &fetcher,
+            &config_manager::MergedConfiguration::new(),
+            &clock,
+        )
+        .await;
+
+        match result {
+            Err(RepoRollerError::Template(TemplateError::RequiredVariableMissing { variable })) => {
+                assert_eq!(variable, "service");
+            }
+            other => panic!("Expected RequiredVariableMissing, got {:?}", other.err()),
+        }
+        assert!(fetcher.requested_sources.lock().unwrap().is_empty());
+    }
+}
+
+/// Module for rendering templates into a local directory
+mod render_to_directory_tests {
+    use super::render_validation_tests::test_template_config;
+    use super::*;
+
+    fn render_request() -> LocalRenderRequest {
+        LocalRenderRequest {
+            repo_name: "preview-repo".to_string(),
+            org_name: "test-org".to_string(),
+            template_name: "test-template".to_string(),
+            variables: HashMap::from([("service".to_string(), "payments".to_string())]),
+        }
+    }
+
+    /// Test that rendered files are written below the output directory.
+    #[test]
+    fn test_render_template_to_directory_writes_rendered_files() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let out_dir = temp_dir.path().join("preview");
+        let files = vec![
+            (
+                "README.md".to_string(),
+                b"# {{repo_name}} ({{service}})".to_vec(),
+            ),
+            (
+                "src/{{service}}.rs".to_string(),
+                b"// {{org_name}}".to_vec(),
+            ),
+        ];
+
+        let manifest = render_template_to_directory(
+            &files,
+            &test_template_config(),
+            &render_request(),
+            &out_dir,
+        )
+        .expect("Rendering should succeed");
+
+        assert_eq!(
+            std::fs::read_to_string(out_dir.join("README.md")).unwrap(),
+            "# preview-repo (payments)"
+        );
+        assert_eq!(
+            std::fs::read_to_string(out_dir.join("src/payments.rs")).unwrap(),
+            "// test-org"
+        );
+        let paths: Vec<&str> = manifest.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["README.md", "src/payments.rs"]);
+    }
+
+    /// Test that a rendered path escaping the output directory is rejected.
+    #[test]
+    fn test_render_template_to_directory_blocks_path_traversal() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let out_dir = temp_dir.path().join("preview");
+        let files = vec![("{{service}}/../../escape.txt".to_string(), b"x".to_vec())];
+
+        let result = render_template_to_directory(
+            &files,
+            &test_template_config(),
+            &render_request(),
+            &out_dir,
+        );
+
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("escape.txt").exists());
+    }
+}
+
+/// Module for layering template files over base templates
+mod template_composition_tests {
+    use super::render_validation_tests::test_template_config;
+    use super::*;
+
+    /// Fetcher serving the files and configuration of several templates,
+    /// keyed by the template's repository name.
+    struct MultiTemplateFetcher {
+        templates: HashMap<&'static str, TemplateFixture>,
+    }
+
+    /// Files of a template and the content of its `.reporoller/template.toml`.
+    type TemplateFixture = (Vec<(String, Vec<u8>)>, Option<String>);
+
+    impl MultiTemplateFetcher {
+        fn template(&self, source: &str) -> Result<&TemplateFixture, String> {
+            let name = source.rsplit('/').next().unwrap_or(source);
+            self.templates
+                .get(name)
+                .ok_or_else(|| format!("unknown template '{}'", source))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TemplateFetcher for MultiTemplateFetcher {
+        async fn fetch_template_files(
+            &self,
+            source: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, String> {
+            Ok(self.template(source)?.0.clone())
+        }
+
+        async fn fetch_template_at(
+            &self,
+            source: &str,
+            git_ref: Option<&str>,
+        ) -> Result<template_engine::FetchedTemplate, String> {
+            let name = source.rsplit('/').next().unwrap_or(source);
+            Ok(template_engine::FetchedTemplate {
+                files: self.template(source)?.0.clone(),
+                commit_sha: Some(format!("{}@{}", name, git_ref.unwrap_or("HEAD"))),
+            })
+        }
+
+        async fn fetch_file(&self, source: &str, path: &str) -> Result<Option<Vec<u8>>, String> {
+            assert_eq!(path, ".reporoller/template.toml");
+            Ok(self
+                .template(source)?
+                .1
+                .as_ref()
+                .map(|config| config.as_bytes().to_vec()))
+        }
+    }
+
+    fn file(path: &str, content: &str) -> (String, Vec<u8>) {
+        (path.to_string(), content.as_bytes().to_vec())
+    }
+
+    fn template_toml(base_template: Option<&str>) -> String {
+        let base = base_template
+            .map(|b| format!("base_template = \"{}\"\n", b))
+            .unwrap_or_default();
+        format!(
+            "{}[template]\nname = \"t\"\ndescription = \"d\"\nauthor = \"a\"\ntags = []\n",
+            base
+        )
+    }
+
+    fn child_template(base_template: &str) -> config_manager::TemplateConfig {
+        let mut template = test_template_config();
+        template.base_template = Some(base_template.to_string());
+        template
+    }
+
+    /// Test that base files are overlaid by the child template, nearest template winning.
+    #[tokio::test]
+    async fn test_child_template_files_override_base_files() {
+        let fetcher = MultiTemplateFetcher {
+            templates: HashMap::from([
+                (
+                    "company-standard",
+                    (
+                        vec![
+                            file("LICENSE", "standard license"),
+                            file("README.md", "standard readme"),
+                            file(".editorconfig", "standard editorconfig"),
+                        ],
+                        Some(template_toml(None)),
+                    ),
+                ),
+                (
+                    "rust-base",
+                    (
+                        vec![
+                            file("README.md", "rust readme"),
+                            file(".editorconfig", "rust editorconfig"),
+                        ],
+                        Some(template_toml(Some("company-standard"))),
+                    ),
+                ),
+                (
+                    "rust-service",
+                    (
+                        vec![
+                            file("README.md", "service readme"),
+                            file("src/main.rs", "fn main() {}"),
+                        ],
+                        None,
+                    ),
+                ),
+            ]),
+        };
+
+        let files = fetch_composed_template_files(
+            "my-org/rust-service",
+            None,
+            &child_template("rust-base"),
+            &fetcher,
+        )
+        .await
+        .expect("composition should succeed");
+
+        let files: HashMap<String, String> = files
+            .files
+            .into_iter()
+            .map(|(path, content)| (path, String::from_utf8(content).unwrap()))
+            .collect();
+        assert_eq!(
+            files,
+            HashMap::from([
+                ("LICENSE".to_string(), "standard license".to_string()),
+                ("README.md".to_string(), "service readme".to_string()),
+                (".editorconfig".to_string(), "rust editorconfig".to_string()),
+                ("src/main.rs".to_string(), "fn main() {}".to_string()),
+            ])
+        );
+    }
+
+    /// Test that a chain of base templates leading back to a template is rejected.
+    #[tokio::test]
+    async fn test_base_template_cycle_is_detected() {
+        let fetcher = MultiTemplateFetcher {
+            templates: HashMap::from([
+                (
+                    "a",
+                    (vec![file("a.txt", "a")], Some(template_toml(Some("b")))),
+                ),
+                (
+                    "b",
+                    (
+                        vec![file("b.txt", "b")],
+                        Some(template_toml(Some("rust-service"))),
+                    ),
+                ),
+                ("rust-service", (vec![file("c.txt", "c")], None)),
+            ]),
+        };
+
+        let result = fetch_composed_template_files(
+            "my-org/rust-service",
+            None,
+            &child_template("a"),
+            &fetcher,
+        )
+        .await;
+
+        match result {
+            Err(RepoRollerError::Template(TemplateError::BaseTemplateCycle {
+                template,
+                chain,
+            })) => {
+                assert_eq!(template, "rust-service");
+                assert_eq!(chain, vec!["rust-service", "a", "b", "rust-service"]);
+            }
+            other => panic!(
+                "Expected BaseTemplateCycle, got {:?}",
+                other.map(|f| f.len())
+            ),
+        }
+    }
+
+    /// Test that a base template without a configuration file is reported.
+    #[tokio::test]
+    async fn test_missing_base_template_configuration_fails() {
+        let fetcher = MultiTemplateFetcher {
+            templates: HashMap::from([
+                ("company-standard", (vec![file("LICENSE", "x")], None)),
+                ("rust-service", (vec![file("README.md", "y")], None)),
+            ]),
+        };
+
+        let result = fetch_composed_template_files(
+            "my-org/rust-service",
+            None,
+            &child_template("company-standard"),
+            &fetcher,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(RepoRollerError::Template(TemplateError::FetchFailed { .. }))
+        ));
+    }
+
+    /// Test that only the requested template is fetched at the pinned ref and its commit is reported.
+    #[tokio::test]
+    async fn test_pinned_ref_applies_to_requested_template() {
+        let fetcher = MultiTemplateFetcher {
+            templates: HashMap::from([
+                (
+                    "rust-base",
+                    (
+                        vec![file("LICENSE", "base license")],
+                        Some(template_toml(None)),
+                    ),
+                ),
+                ("rust-service", (vec![file("README.md", "readme")], None)),
+            ]),
+        };
+
+        let fetched = fetch_composed_template_files(
+            "my-org/rust-service",
+            Some("v1.2.0"),
+            &child_template("rust-base"),
+            &fetcher,
+        )
+        .await
+        .expect("composition should succeed");
+
+        assert_eq!(fetched.commit_sha.as_deref(), Some("rust-service@v1.2.0"));
+        assert_eq!(fetched.files.len(), 2);
+    }
+}
@@ -18,3 +18,19 @@ fn test_template_name_invalid() {
     assert!(TemplateName::new("").is_err());
     assert!(TemplateName::new("a".repeat(51)).is_err());
 }
+
+#[test]
+fn test_template_name_suggests_kebab_case() {
+    let err = TemplateName::new("My Repo").unwrap_err();
+    assert_eq!(err.suggestion(), Some("my-repo"));
+
+    let err = TemplateName::new("RustLibrary").unwrap_err();
+    assert_eq!(err.suggestion(), Some("rustlibrary"));
+}
+
+#[test]
+fn test_template_name_unfixable_has_no_suggestion() {
+    let err = TemplateName::new("rust_library").unwrap_err();
+    assert!(matches!(err, ValidationError::InvalidName { .. }));
+    assert_eq!(err.suggestion(), None);
+}
@@ -0,0 +1,326 @@
+//! Post-creation verification of applied repository configuration.
+//!
+//! A successful API response shows that GitHub accepted a setting, not that the
+//! repository still has it. [`verify_repository_configuration`] re-reads the
+//! repository from GitHub and compares it with the [`MergedConfiguration`]
+//! that was applied, producing a [`VerificationReport`] that lists every
+//! setting whose actual value differs from the intended one.
+//!
+//! The following are verified:
+//! - Repository features: issues, projects, and wiki
+//! - Labels: every configured label exists (names compare case-insensitively,
+//!   as on GitHub)
+//! - Branch protection review rules on the configured default branch, when any
+//!   are configured
+//! - Webhooks: every configured webhook exists (matched by URL) with the
+//!   configured active state
+//!
+//! Settings that are not configured are not checked.
+//!
+//! [`MergedConfiguration`]: config_manager::MergedConfiguration
+
+use crate::errors::{GitHubError, RepoRollerError, RepoRollerResult};
+use config_manager::OverridableValue;
+use github_client::RepositoryClient;
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+#[cfg(test)]
+#[path = "verification_tests.rs"]
+mod tests;
+
+/// Branch checked for protection rules when the configuration names none.
+const DEFAULT_BRANCH: &str = "main";
+
+/// A setting whose actual value differs from the intended configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingMismatch {
+    /// Path of the setting, e.g. `repository.wiki` or `labels.bug`
+    pub setting: String,
+
+    /// The value the configuration asked for
+    pub expected: String,
+
+    /// The value found on GitHub, or `"missing"` when it is absent
+    pub actual: String,
+}
+
+/// Outcome of comparing a repository with its intended configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Number of settings that were compared
+    pub checked: usize,
+
+    /// Settings whose actual value differs from the intended value
+    pub mismatches: Vec<SettingMismatch>,
+}
+
+impl VerificationReport {
+    /// Returns `true` when every checked setting matches the configuration.
+    pub fn is_compliant(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Compares one setting and records a mismatch if the values differ.
+    pub(crate) fn compare<T: PartialEq + ToString>(
+        &mut self,
+        setting: &str,
+        expected: T,
+        actual: Option<T>,
+    ) {
+        self.checked += 1;
+        if actual.as_ref() != Some(&expected) {
+            self.mismatches.push(SettingMismatch {
+                setting: setting.to_string(),
+                expected: expected.to_string(),
+                actual: actual.map_or_else(|| "missing".to_string(), |a| a.to_string()),
+            });
+        }
+    }
+}
+
+/// Re-reads a repository from GitHub and diffs it against its configuration.
+///
+/// # Arguments
+///
+/// * `client` - GitHub client for reading the repository
+/// * `owner` - Repository owner
+/// * `repo` - Repository name
+/// * `merged_config` - The configuration that was applied to the repository
+///
+/// # Returns
+///
+/// A [`VerificationReport`] listing every setting that does not match.
+///
+/// # Errors
+///
+/// Returns `GitHubError::NetworkError` if the repository settings, labels, or
+/// branch protection cannot be read. Drift is reported in the report, not as
+/// an error.
+pub async fn verify_repository_configuration(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<VerificationReport> {
+    info!(
+        owner = owner,
+        repo = repo,
+        "Verifying applied repository configuration"
+    );
+
+    let report = compare_with_configuration(client, owner, repo, merged_config).await?;
+
+    if report.is_compliant() {
+        info!(
+            checked = report.checked,
+            "Repository configuration matches intended configuration"
+        );
+    } else {
+        for mismatch in &report.mismatches {
+            warn!(
+                setting = %mismatch.setting,
+                expected = %mismatch.expected,
+                actual = %mismatch.actual,
+                "Repository setting does not match intended configuration"
+            );
+        }
+    }
+
+    Ok(report)
+}
+
+/// Reads the configured settings from GitHub and compares them, without
+/// logging the outcome.
+///
+/// Shared by verification and drift detection.
+pub(crate) async fn compare_with_configuration(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+) -> RepoRollerResult<VerificationReport> {
+    let mut report = VerificationReport::default();
+
+    verify_repository_features(client, owner, repo, merged_config, &mut report).await?;
+    verify_labels(client, owner, repo, merged_config, &mut report).await?;
+    verify_branch_protection(client, owner, repo, merged_config, &mut report).await?;
+    verify_webhooks(client, owner, repo, merged_config, &mut report).await?;
+
+    Ok(report)
+}
+
+async fn verify_repository_features(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    report: &mut VerificationReport,
+) -> RepoRollerResult<()> {
+    let settings = &merged_config.repository;
+    let features = [
+        ("repository.issues", &settings.issues),
+        ("repository.projects", &settings.projects),
+        ("repository.wiki", &settings.wiki),
+    ];
+    if features.iter().all(|(_, value)| value.is_none()) {
+        return Ok(());
+    }
+
+    let actual = client
+        .get_repository_settings(owner, repo)
+        .await
+        .map_err(|e| read_failed("repository settings", e))?;
+
+    let actual_values = [
+        actual.has_issues(),
+        actual.has_projects(),
+        actual.has_wiki(),
+    ];
+    for ((setting, expected), actual) in features.into_iter().zip(actual_values) {
+        if let Some(expected) = expected {
+            report.compare(setting, expected.value, actual);
+        }
+    }
+
+    Ok(())
+}
+
+async fn verify_labels(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    report: &mut VerificationReport,
+) -> RepoRollerResult<()> {
+    if merged_config.labels.is_empty() {
+        return Ok(());
+    }
+
+    let actual: HashSet<String> = client
+        .list_repository_labels(owner, repo)
+        .await
+        .map_err(|e| read_failed("labels", e))?
+        .into_iter()
+        .map(|name| name.to_lowercase())
+        .collect();
+
+    let mut expected: Vec<&String> = merged_config.labels.keys().collect();
+    expected.sort();
+    for name in expected {
+        let present = actual.contains(&name.to_lowercase());
+        report.compare(
+            &format!("labels.{}", name),
+            "present",
+            present.then_some("present"),
+        );
+    }
+
+    Ok(())
+}
+
+async fn verify_branch_protection(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    report: &mut VerificationReport,
+) -> RepoRollerResult<()> {
+    let settings = &merged_config.branch_protection;
+    let expected_review_count = settings
+        .required_approving_review_count
+        .as_ref()
+        .map(|v| v.value);
+    let expected_code_owners = value_of(&settings.require_code_owner_reviews);
+    let expected_dismiss_stale = value_of(&settings.dismiss_stale_reviews);
+    if expected_review_count.is_none()
+        && expected_code_owners.is_none()
+        && expected_dismiss_stale.is_none()
+    {
+        return Ok(());
+    }
+
+    let branch = settings
+        .default_branch
+        .as_ref()
+        .map(|v| v.value.as_str())
+        .unwrap_or(DEFAULT_BRANCH);
+    let protection = client
+        .get_branch_protection(owner, repo, branch)
+        .await
+        .map_err(|e| read_failed("branch protection", e))?;
+    let prefix = format!("branch_protection.{}", branch);
+
+    if let Some(expected) = expected_review_count {
+        let actual = protection
+            .as_ref()
+            .and_then(|p| p.required_approving_review_count)
+            .map(i64::from);
+        report.compare(
+            &format!("{}.required_approving_review_count", prefix),
+            i64::from(expected),
+            actual,
+        );
+    }
+    if let Some(expected) = expected_code_owners {
+        let actual = protection
+            .as_ref()
+            .and_then(|p| p.require_code_owner_reviews);
+        report.compare(
+            &format!("{}.require_code_owner_reviews", prefix),
+            expected,
+            actual,
+        );
+    }
+    if let Some(expected) = expected_dismiss_stale {
+        let actual = protection.as_ref().and_then(|p| p.dismiss_stale_reviews);
+        report.compare(
+            &format!("{}.dismiss_stale_reviews", prefix),
+            expected,
+            actual,
+        );
+    }
+
+    Ok(())
+}
+
+async fn verify_webhooks(
+    client: &dyn RepositoryClient,
+    owner: &str,
+    repo: &str,
+    merged_config: &config_manager::MergedConfiguration,
+    report: &mut VerificationReport,
+) -> RepoRollerResult<()> {
+    if merged_config.webhooks.is_empty() {
+        return Ok(());
+    }
+
+    let actual = client
+        .list_webhooks(owner, repo)
+        .await
+        .map_err(|e| read_failed("webhooks", e))?;
+
+    let mut expected: Vec<&config_manager::settings::WebhookConfig> =
+        merged_config.webhooks.iter().collect();
+    expected.sort_by(|a, b| a.url.cmp(&b.url));
+    for webhook in expected {
+        let setting = format!("webhooks.{}.active", webhook.url);
+        let actual_active = actual
+            .iter()
+            .find(|w| w.config.url == webhook.url)
+            .map(|w| w.active);
+        report.compare(&setting, webhook.active, actual_active);
+    }
+
+    Ok(())
+}
+
+fn value_of(setting: &Option<OverridableValue<bool>>) -> Option<bool> {
+    setting.as_ref().map(|v| v.value)
+}
+
+fn read_failed(what: &str, error: github_client::Error) -> RepoRollerError {
+    RepoRollerError::GitHub(GitHubError::NetworkError {
+        reason: format!("Failed to read {} for verification: {}", what, error),
+    })
+}
@@ -0,0 +1,244 @@
+//! Tests for the verification module.
+
+use super::*;
+use config_manager::settings::{LabelConfig, WebhookConfig};
+use config_manager::MergedConfiguration;
+use github_client::GitHubClient;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Creates a [`GitHubClient`] pointed at a wiremock server for testing.
+fn create_test_github_client(server_uri: &str) -> GitHubClient {
+    let octocrab = Octocrab::builder()
+        .base_uri(server_uri)
+        .expect("valid URI from wiremock")
+        .personal_token("test-token".to_string())
+        .build()
+        .expect("octocrab builder succeeds with valid base_uri");
+    GitHubClient::new(octocrab)
+}
+
+/// Mounts a `GET /repos/test-org/test-repo` response with the given features.
+async fn mount_repository(server: &MockServer, has_issues: bool, has_wiki: bool) {
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123456789,
+            "node_id": "R_kgDOTest",
+            "name": "test-repo",
+            "full_name": "test-org/test-repo",
+            "private": true,
+            "url": "https://api.github.com/repos/test-org/test-repo",
+            "has_issues": has_issues,
+            "has_wiki": has_wiki,
+            "has_projects": true
+        })))
+        .mount(server)
+        .await;
+}
+
+fn label(name: &str) -> LabelConfig {
+    LabelConfig {
+        name: name.to_string(),
+        color: "d73a4a".to_string(),
+        description: String::new(),
+    }
+}
+
+#[test]
+fn test_empty_report_is_compliant() {
+    let report = VerificationReport::default();
+    assert!(report.is_compliant());
+    assert_eq!(report.checked, 0);
+}
+
+#[tokio::test]
+async fn test_unconfigured_settings_are_not_read() {
+    let server = MockServer::start().await;
+    let client = create_test_github_client(&server.uri());
+
+    let report = verify_repository_configuration(
+        &client,
+        "test-org",
+        "test-repo",
+        &MergedConfiguration::new(),
+    )
+    .await
+    .expect("Verification should succeed without any GitHub calls");
+
+    assert!(report.is_compliant());
+    assert_eq!(report.checked, 0);
+}
+
+#[tokio::test]
+async fn test_matching_settings_are_compliant() {
+    let server = MockServer::start().await;
+    mount_repository(&server, true, false).await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "name": "Bug", "color": "d73a4a" }
+        ])))
+        .mount(&server)
+        .await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(OverridableValue::allowed(true));
+    config.repository.wiki = Some(OverridableValue::allowed(false));
+    config.labels.insert("bug".to_string(), label("bug"));
+
+    let report = verify_repository_configuration(&client, "test-org", "test-repo", &config)
+        .await
+        .expect("Verification should succeed");
+
+    assert!(report.is_compliant(), "Unexpected drift: {:?}", report);
+    assert_eq!(report.checked, 3);
+}
+
+#[tokio::test]
+async fn test_drifted_setting_is_reported_as_mismatch() {
+    let server = MockServer::start().await;
+    // The configuration disables the wiki, but the repository has it enabled
+    mount_repository(&server, true, true).await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(OverridableValue::allowed(true));
+    config.repository.wiki = Some(OverridableValue::allowed(false));
+
+    let report = verify_repository_configuration(&client, "test-org", "test-repo", &config)
+        .await
+        .expect("Verification should succeed");
+
+    assert!(!report.is_compliant());
+    assert_eq!(report.checked, 2);
+    assert_eq!(
+        report.mismatches,
+        vec![SettingMismatch {
+            setting: "repository.wiki".to_string(),
+            expected: "false".to_string(),
+            actual: "true".to_string(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_missing_label_and_protection_are_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/branches/main/protection"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Branch not protected"
+        })))
+        .mount(&server)
+        .await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.labels.insert("bug".to_string(), label("bug"));
+    config.branch_protection.required_approving_review_count = Some(OverridableValue::allowed(2));
+
+    let report = verify_repository_configuration(&client, "test-org", "test-repo", &config)
+        .await
+        .expect("Verification should succeed");
+
+    let settings: Vec<&str> = report
+        .mismatches
+        .iter()
+        .map(|m| m.setting.as_str())
+        .collect();
+    assert_eq!(
+        settings,
+        vec![
+            "labels.bug",
+            "branch_protection.main.required_approving_review_count"
+        ]
+    );
+    assert!(report.mismatches.iter().all(|m| m.actual == "missing"));
+}
+
+#[tokio::test]
+async fn test_unreadable_repository_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    let client = create_test_github_client(&server.uri());
+
+    let mut config = MergedConfiguration::new();
+    config.repository.issues = Some(OverridableValue::allowed(true));
+
+    let result = verify_repository_configuration(&client, "test-org", "test-repo", &config).await;
+
+    assert!(matches!(
+        result,
+        Err(RepoRollerError::GitHub(GitHubError::NetworkError { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_missing_and_inactive_webhooks_are_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-org/test-repo/hooks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": 1,
+            "url": "https://api.github.com/repos/test-org/test-repo/hooks/1",
+            "active": false,
+            "events": ["push"],
+            "config": {
+                "url": "https://ci.example.com/hook",
+                "content_type": "json",
+                "insecure_ssl": "0"
+            },
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }])))
+        .mount(&server)
+        .await;
+    let client = create_test_github_client(&server.uri());
+
+    let webhook = |url: &str| WebhookConfig {
+        url: url.to_string(),
+        content_type: "json".to_string(),
+        secret: None,
+        active: true,
+        events: vec!["push".to_string()],
+    };
+    let mut config = MergedConfiguration::new();
+    config.webhooks = vec![
+        webhook("https://ci.example.com/hook"),
+        webhook("https://audit.example.com/hook"),
+    ];
+
+    let report = verify_repository_configuration(&client, "test-org", "test-repo", &config)
+        .await
+        .expect("Verification should succeed");
+
+    assert_eq!(report.checked, 2);
+    assert_eq!(
+        report.mismatches,
+        vec![
+            SettingMismatch {
+                setting: "webhooks.https://audit.example.com/hook.active".to_string(),
+                expected: "true".to_string(),
+                actual: "missing".to_string(),
+            },
+            SettingMismatch {
+                setting: "webhooks.https://ci.example.com/hook.active".to_string(),
+                expected: "true".to_string(),
+                actual: "false".to_string(),
+            },
+        ]
+    );
+}
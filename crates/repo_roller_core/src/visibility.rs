@@ -389,3 +389,30 @@ impl VisibilityResolver {
         Ok(())
     }
 }
+
+/// Environment detector for repositories owned by a personal account.
+///
+/// Personal accounts have no organization plan that can be queried. They can
+/// always create private repositories but never internal ones, so the limits
+/// are fixed rather than fetched from GitHub.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PersonalAccountEnvironment;
+
+#[async_trait::async_trait]
+impl GitHubEnvironmentDetector for PersonalAccountEnvironment {
+    async fn get_plan_limitations(
+        &self,
+        _organization: &str,
+    ) -> Result<PlanLimitations, github_client::Error> {
+        Ok(PlanLimitations {
+            supports_private_repos: true,
+            supports_internal_repos: false,
+            private_repo_limit: None,
+            is_enterprise: false,
+        })
+    }
+
+    async fn is_enterprise(&self, _organization: &str) -> Result<bool, github_client::Error> {
+        Ok(false)
+    }
+}
@@ -378,3 +378,32 @@ async fn test_private_on_free_plan() {
     assert_eq!(decision.visibility, RepositoryVisibility::Private);
     assert_eq!(decision.source, DecisionSource::UserPreference);
 }
+
+/// Test that personal accounts can create private but not internal repositories.
+#[tokio::test]
+async fn test_personal_account_environment_rejects_internal() {
+    let policy_provider = Arc::new(MockPolicyProvider::unrestricted());
+    let resolver = VisibilityResolver::new(policy_provider, Arc::new(PersonalAccountEnvironment));
+
+    let private = resolver
+        .resolve_visibility(VisibilityRequest {
+            organization: OrganizationName::new("octocat").unwrap(),
+            user_preference: Some(RepositoryVisibility::Private),
+            template_default: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(private.visibility, RepositoryVisibility::Private);
+
+    let internal = resolver
+        .resolve_visibility(VisibilityRequest {
+            organization: OrganizationName::new("octocat").unwrap(),
+            user_preference: Some(RepositoryVisibility::Internal),
+            template_default: None,
+        })
+        .await;
+    assert!(matches!(
+        internal,
+        Err(VisibilityError::GitHubConstraint { .. })
+    ));
+}
@@ -3,7 +3,8 @@
 //! This module provides the [`WebhookManager`] component for orchestrating
 //! webhook operations with validation, idempotency, and secret management.
 
-use github_client::{GitHubClient, RepositoryClient, Webhook};
+use github_client::{RepositoryClient, Webhook};
+use std::sync::Arc;
 use tracing::{info, warn};
 
 use crate::{GitHubError, RepoRollerResult, ValidationError};
@@ -38,7 +39,7 @@ use crate::{GitHubError, RepoRollerResult, ValidationError};
 /// ```
 pub struct WebhookManager {
     /// GitHub client for API operations
-    github_client: GitHubClient,
+    github_client: Arc<dyn RepositoryClient>,
 }
 
 impl WebhookManager {
@@ -46,7 +47,8 @@ impl WebhookManager {
     ///
     /// # Arguments
     ///
-    /// * `github_client` - GitHub client for API operations
+    /// * `github_client` - GitHub client for API operations, either a `GitHubClient`
+    ///   or a client shared as `Arc<dyn RepositoryClient>`
     ///
     /// # Examples
     ///
@@ -58,8 +60,10 @@ impl WebhookManager {
     /// let manager = WebhookManager::new(client);
     /// # }
     /// ```
-    pub fn new(github_client: GitHubClient) -> Self {
-        Self { github_client }
+    pub fn new(github_client: impl Into<Arc<dyn RepositoryClient>>) -> Self {
+        Self {
+            github_client: github_client.into(),
+        }
     }
 
     /// Applies webhooks to a repository, creating or updating as needed.
@@ -162,8 +166,35 @@ impl WebhookManager {
                 .iter()
                 .find(|w| w.config.url == webhook_config.url)
             {
-                info!(webhook_id = existing.id, "Webhook already exists, skipping");
-                result.skipped += 1;
+                if webhook_matches(existing, webhook_config) {
+                    info!(webhook_id = existing.id, "Webhook already exists, skipping");
+                    result.skipped += 1;
+                    continue;
+                }
+
+                let params = github_client::UpdateWebhookParams {
+                    url: &webhook_config.url,
+                    content_type: &webhook_config.content_type,
+                    secret: webhook_config.secret.as_deref(),
+                    active: webhook_config.active,
+                    events: &webhook_config.events,
+                };
+
+                match self
+                    .github_client
+                    .update_webhook(owner, repo, existing.id, &params)
+                    .await
+                {
+                    Ok(webhook) => {
+                        info!(webhook_id = webhook.id, "Webhook updated successfully");
+                        result.updated += 1;
+                    }
+                    Err(e) => {
+                        warn!(error = ?e, "Failed to update webhook");
+                        result.failed += 1;
+                        result.failed_webhooks.push(webhook_config.url.clone());
+                    }
+                }
                 continue;
             }
 
@@ -195,6 +226,7 @@ impl WebhookManager {
 
         info!(
             created = result.created,
+            updated = result.updated,
             skipped = result.skipped,
             failed = result.failed,
             "Webhook application complete"
@@ -296,6 +328,24 @@ impl WebhookManager {
     }
 }
 
+/// Returns `true` if an existing webhook already has the configured content
+/// type, active state and events.
+///
+/// Secrets are never returned by GitHub, so they cannot be compared; a webhook
+/// that only differs in its secret is left unchanged.
+fn webhook_matches(existing: &Webhook, config: &config_manager::settings::WebhookConfig) -> bool {
+    let mut existing_events: Vec<&str> = existing.events.iter().map(|e| e.as_str()).collect();
+    let mut configured_events: Vec<&str> = config.events.iter().map(String::as_str).collect();
+    existing_events.sort_unstable();
+    existing_events.dedup();
+    configured_events.sort_unstable();
+    configured_events.dedup();
+
+    existing.active == config.active
+        && existing.config.content_type == config.content_type
+        && existing_events == configured_events
+}
+
 /// Result of applying webhooks to a repository.
 ///
 /// Contains counters for the different outcomes of webhook operations.
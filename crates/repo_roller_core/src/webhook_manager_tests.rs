@@ -189,3 +189,37 @@ fn test_webhook_validation_requirements_documented() {
     // These will be tested when implementation is complete in Phase 2
     // For now, this test documents the planned validation requirements
 }
+
+#[test]
+fn test_webhook_matches_ignores_event_order_and_detects_changes() {
+    let existing: Webhook = serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "url": "https://api.github.com/repos/org/repo/hooks/1",
+        "active": true,
+        "events": ["pull_request", "push"],
+        "config": {
+            "url": "https://example.com/webhook",
+            "content_type": "json",
+            "insecure_ssl": "0"
+        },
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    }))
+    .unwrap();
+    let mut config = WebhookConfig {
+        url: "https://example.com/webhook".to_string(),
+        content_type: "json".to_string(),
+        secret: Some("rotated-secret".to_string()),
+        active: true,
+        events: vec!["push".to_string(), "pull_request".to_string()],
+    };
+
+    assert!(webhook_matches(&existing, &config));
+
+    config.active = false;
+    assert!(!webhook_matches(&existing, &config));
+
+    config.active = true;
+    config.events.push("release".to_string());
+    assert!(!webhook_matches(&existing, &config));
+}
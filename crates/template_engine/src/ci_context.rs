@@ -0,0 +1,118 @@
+//! # CI context variables
+//!
+//! When RepoRoller runs inside a CI system, details of the triggering run are
+//! exposed to templates as built-in variables so that generated content can
+//! record where it came from without the caller passing them explicitly.
+//!
+//! The variables are only produced when the `CI` or `GITHUB_ACTIONS`
+//! environment variable is `true`; outside CI the group is empty.
+//!
+//! | Variable | Source |
+//! |---|---|
+//! | `ci_commit_sha` | `GITHUB_SHA` |
+//! | `ci_actor` | `GITHUB_ACTOR` |
+//! | `ci_run_id` | `GITHUB_RUN_ID` |
+//! | `ci_workflow_context` | `GITHUB_REF`, see [`workflow_context_from_ref`] |
+//!
+//! Variables whose source is unset or empty are omitted.
+//!
+//! ```rust
+//! use template_engine::ci_variables_from;
+//!
+//! let variables = ci_variables_from(|name| match name {
+//!     "GITHUB_ACTIONS" => Some("true".to_string()),
+//!     "GITHUB_SHA" => Some("0123abcd".to_string()),
+//!     _ => None,
+//! });
+//! assert_eq!(variables.get("ci_commit_sha").map(String::as_str), Some("0123abcd"));
+//! assert!(!variables.contains_key("ci_actor"));
+//! ```
+
+use std::collections::HashMap;
+
+use crate::ref_name::sanitize_ref_name;
+
+#[cfg(test)]
+#[path = "ci_context_tests.rs"]
+mod tests;
+
+/// Environment variables mapped directly to CI template variables.
+const CI_VARIABLE_SOURCES: [(&str, &str); 3] = [
+    ("ci_commit_sha", "GITHUB_SHA"),
+    ("ci_actor", "GITHUB_ACTOR"),
+    ("ci_run_id", "GITHUB_RUN_ID"),
+];
+
+/// Describes the workflow that triggered a run from its `GITHUB_REF`.
+///
+/// Returns:
+/// - `pr{number}` for pull request workflows (e.g., "pr123")
+/// - `main` for pushes to the main or master branch
+/// - the branch name, made valid with [`sanitize_ref_name`] and with `/`
+///   replaced by `-`, for other branch pushes
+/// - `local` when no ref is available, it is not recognised or no valid
+///   name remains of the branch
+///
+/// `GITHUB_REF` contains `refs/pull/{number}/merge` for pull requests and
+/// `refs/heads/{branch}` for branch pushes.
+pub fn workflow_context_from_ref(github_ref: Option<&str>) -> String {
+    if let Some(github_ref) = github_ref {
+        if github_ref.starts_with("refs/pull/") {
+            // Extract PR number from refs/pull/{number}/merge
+            if let Some(pr_num) = github_ref.split('/').nth(2) {
+                return format!("pr{}", pr_num);
+            }
+        } else if let Some(branch) = github_ref.strip_prefix("refs/heads/") {
+            if branch == "main" || branch == "master" {
+                return "main".to_string();
+            }
+            if let Some(branch) = sanitize_ref_name(branch) {
+                return branch.replace('/', "-");
+            }
+        }
+    }
+
+    "local".to_string()
+}
+
+/// Returns `true` when the environment indicates a CI run.
+///
+/// `lookup` returns the value of the named environment variable, if set.
+pub fn is_running_in_ci(lookup: impl Fn(&str) -> Option<String>) -> bool {
+    ["CI", "GITHUB_ACTIONS"]
+        .iter()
+        .any(|name| lookup(name).is_some_and(|value| value.eq_ignore_ascii_case("true")))
+}
+
+/// Generates the CI context variables from the given environment.
+///
+/// `lookup` returns the value of the named environment variable, if set.
+/// Returns an empty map when not running in CI.
+pub fn ci_variables_from(lookup: impl Fn(&str) -> Option<String>) -> HashMap<String, String> {
+    let mut variables = HashMap::new();
+    if !is_running_in_ci(&lookup) {
+        return variables;
+    }
+
+    let non_empty = |name: &str| lookup(name).filter(|value| !value.is_empty());
+    for (variable, source) in CI_VARIABLE_SOURCES {
+        if let Some(value) = non_empty(source) {
+            variables.insert(variable.to_string(), value);
+        }
+    }
+    if let Some(github_ref) = non_empty("GITHUB_REF") {
+        variables.insert(
+            "ci_workflow_context".to_string(),
+            workflow_context_from_ref(Some(&github_ref)),
+        );
+    }
+
+    variables
+}
+
+/// Generates the CI context variables from the process environment.
+///
+/// Returns an empty map when not running in CI.
+pub fn generate_ci_variables() -> HashMap<String, String> {
+    ci_variables_from(|name| std::env::var(name).ok())
+}
@@ -0,0 +1,77 @@
+//! Tests for CI context variables.
+
+use super::*;
+
+fn environment(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    move |name| vars.get(name).cloned()
+}
+
+#[test]
+fn test_ci_variables_from_github_actions_environment() {
+    let variables = ci_variables_from(environment(&[
+        ("GITHUB_ACTIONS", "true"),
+        ("GITHUB_SHA", "ffac537e6cbbf934b08745a378932722df287a53"),
+        ("GITHUB_ACTOR", "octocat"),
+        ("GITHUB_RUN_ID", "1658821493"),
+        ("GITHUB_REF", "refs/pull/42/merge"),
+    ]));
+
+    assert_eq!(variables.len(), 4);
+    assert_eq!(
+        variables["ci_commit_sha"],
+        "ffac537e6cbbf934b08745a378932722df287a53"
+    );
+    assert_eq!(variables["ci_actor"], "octocat");
+    assert_eq!(variables["ci_run_id"], "1658821493");
+    assert_eq!(variables["ci_workflow_context"], "pr42");
+}
+
+#[test]
+fn test_ci_variables_from_generic_ci_omits_unset_and_empty_values() {
+    let variables = ci_variables_from(environment(&[
+        ("CI", "true"),
+        ("GITHUB_SHA", "abc123"),
+        ("GITHUB_ACTOR", ""),
+    ]));
+
+    assert_eq!(variables.len(), 1);
+    assert_eq!(variables["ci_commit_sha"], "abc123");
+}
+
+#[test]
+fn test_ci_variables_from_outside_ci_is_empty() {
+    // GitHub variables alone do not mean the process runs in CI.
+    let variables = ci_variables_from(environment(&[
+        ("GITHUB_SHA", "abc123"),
+        ("GITHUB_ACTOR", "octocat"),
+        ("CI", "false"),
+    ]));
+
+    assert!(variables.is_empty());
+    assert!(ci_variables_from(environment(&[])).is_empty());
+}
+
+#[test]
+fn test_workflow_context_from_ref() {
+    assert_eq!(
+        workflow_context_from_ref(Some("refs/pull/456/merge")),
+        "pr456"
+    );
+    assert_eq!(workflow_context_from_ref(Some("refs/heads/main")), "main");
+    assert_eq!(workflow_context_from_ref(Some("refs/heads/master")), "main");
+    assert_eq!(
+        workflow_context_from_ref(Some("refs/heads/feature/ci-vars")),
+        "feature-ci-vars"
+    );
+    assert_eq!(
+        workflow_context_from_ref(Some("refs/heads/my fix/v1..2.lock")),
+        "my-fix-v1.2"
+    );
+    assert_eq!(workflow_context_from_ref(Some("refs/heads/..")), "local");
+    assert_eq!(workflow_context_from_ref(Some("refs/tags/v1.0.0")), "local");
+    assert_eq!(workflow_context_from_ref(None), "local");
+}
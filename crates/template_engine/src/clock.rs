@@ -0,0 +1,59 @@
+//! # Clocks
+//!
+//! Built-in template variables such as `timestamp` and `timestamp_unix` record
+//! when a repository was generated. Reading the system time directly makes any
+//! output that contains them non-deterministic, so the current time is obtained
+//! through a [`Clock`] instead.
+//!
+//! [`SystemClock`] is used by default. [`FixedClock`] always returns the same
+//! instant, which makes generated content reproducible in tests.
+//!
+//! ```rust
+//! use chrono::{TimeZone, Utc};
+//! use template_engine::{Clock, FixedClock};
+//!
+//! let instant = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+//! let clock = FixedClock::new(instant);
+//! assert_eq!(clock.now(), instant);
+//! ```
+
+use chrono::{DateTime, Utc};
+
+#[cfg(test)]
+#[path = "clock_tests.rs"]
+mod tests;
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// Returns the current instant in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock that reads the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    instant: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Creates a clock that always returns `instant`.
+    pub fn new(instant: DateTime<Utc>) -> Self {
+        Self { instant }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.instant
+    }
+}
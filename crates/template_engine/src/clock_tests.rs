@@ -0,0 +1,22 @@
+//! Tests for the clock abstraction.
+
+use super::*;
+use chrono::TimeZone;
+
+#[test]
+fn test_fixed_clock_returns_fixed_instant() {
+    let instant = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+    let clock = FixedClock::new(instant);
+
+    assert_eq!(clock.now(), instant);
+    assert_eq!(clock.now(), instant);
+}
+
+#[test]
+fn test_system_clock_tracks_current_time() {
+    let before = Utc::now();
+    let now = SystemClock.now();
+    let after = Utc::now();
+
+    assert!(before <= now && now <= after);
+}
@@ -108,4 +108,58 @@ pub enum Error {
     /// due to configuration issues, dependency failures, or system constraints.
     #[error("Template engine initialization failed: {0}")]
     EngineInitialization(String),
+
+    /// Two or more template files produce the same output path.
+    ///
+    /// This error occurs when, after variable substitution and path
+    /// normalization, more than one template file would be written to the
+    /// same location (e.g. `{{name}}.rs` and `lib.rs` when `name` is `lib`).
+    /// Silently keeping one of them would make the output depend on file order.
+    #[error("Output path collision: '{path}' is produced by template files {sources:?}")]
+    PathCollision {
+        /// The normalized output path that more than one file resolves to
+        path: String,
+        /// The template file paths that resolve to the same output path
+        sources: Vec<String>,
+    },
+
+    /// A processed file would be placed outside the output directory.
+    ///
+    /// This error occurs when an output path contains a parent, root or drive
+    /// component, or when a directory it would be written through is a
+    /// symbolic link pointing outside the output directory.
+    #[error("Output path '{path}' resolves outside the output directory '{output_dir}'")]
+    OutputPathOutsideDirectory {
+        /// The output path of the processed file
+        path: String,
+        /// The output directory the path was resolved against
+        output_dir: String,
+    },
+
+    /// A processed file path is longer than the configured maximum.
+    ///
+    /// Some platforms, such as Windows without long path support, cannot
+    /// check out files whose paths exceed 260 characters.
+    #[error(
+        "Output path '{path}' is {length} characters long, which exceeds the maximum path length"
+    )]
+    PathTooLong {
+        /// The output path of the processed file
+        path: String,
+        /// The length of the path in characters
+        length: usize,
+    },
+
+    /// Rendering a template exceeded a configured resource limit.
+    ///
+    /// This error is returned instead of hanging or overflowing the stack when
+    /// a template nests blocks or partials too deeply, contains a partial that
+    /// calls itself, or renders to more output than allowed.
+    #[error("Template render limit exceeded: {limit} - {message}")]
+    RenderLimitExceeded {
+        /// The limit that was exceeded (e.g. `render_depth`, `output_size`)
+        limit: String,
+        /// Description of the limit violation
+        message: String,
+    },
 }
@@ -57,3 +57,51 @@ fn test_variable_validation_error_display() {
         "Template variable validation failed: email - Invalid email format"
     );
 }
+
+#[test]
+fn test_render_limit_exceeded_error_display() {
+    let error = Error::RenderLimitExceeded {
+        limit: "render_depth".to_string(),
+        message: "Block nesting depth exceeds limit of 64".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Template render limit exceeded: render_depth - Block nesting depth exceeds limit of 64"
+    );
+}
+
+#[test]
+fn test_path_collision_error_display() {
+    let error = Error::PathCollision {
+        path: "src/lib.rs".to_string(),
+        sources: vec!["src/{{name}}.rs".to_string(), "src/lib.rs".to_string()],
+    };
+    assert_eq!(
+        error.to_string(),
+        "Output path collision: 'src/lib.rs' is produced by template files [\"src/{{name}}.rs\", \"src/lib.rs\"]"
+    );
+}
+
+#[test]
+fn test_output_path_outside_directory_error_display() {
+    let error = Error::OutputPathOutsideDirectory {
+        path: "../escape.txt".to_string(),
+        output_dir: "/tmp/output".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Output path '../escape.txt' resolves outside the output directory '/tmp/output'"
+    );
+}
+
+#[test]
+fn test_path_too_long_error_display() {
+    let error = Error::PathTooLong {
+        path: "a/b/c.txt".to_string(),
+        length: 9,
+    };
+    assert_eq!(
+        error.to_string(),
+        "Output path 'a/b/c.txt' is 9 characters long, which exceeds the maximum path length"
+    );
+}
@@ -51,7 +51,7 @@ use handlebars::{
     RenderErrorReason,
 };
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use thiserror::Error;
 
 #[cfg(test)]
@@ -233,6 +233,35 @@ impl HelperDef for DefaultHelper {
     }
 }
 
+/// Describes a Handlebars render error for diagnostics.
+///
+/// Appends the line and column of the failing expression and the helper that
+/// rejected its parameters, when Handlebars reports them, so that a failure in
+/// a large template file can be located without bisecting it.
+fn describe_render_error(e: &handlebars::RenderError) -> String {
+    let mut details = Vec::new();
+    if let (Some(line), Some(column)) = (e.line_no, e.column_no) {
+        details.push(format!("line {}, column {}", line, column));
+    }
+    match e.reason() {
+        RenderErrorReason::HelperNotFound(helper) => {
+            details.push(format!("helper '{}'", helper));
+        }
+        RenderErrorReason::ParamNotFoundForIndex(helper, _)
+        | RenderErrorReason::ParamNotFoundForName(helper, _) => {
+            details.push(format!("helper '{}'", helper));
+        }
+        _ => {}
+    }
+
+    let reason = e.reason().to_string();
+    if details.is_empty() {
+        reason
+    } else {
+        format!("{} ({})", reason, details.join(", "))
+    }
+}
+
 /// Helper to generate current timestamp.
 struct TimestampHelper;
 
@@ -384,6 +413,14 @@ pub enum HandlebarsError {
 /// It provides a clean interface for passing complex data structures to
 /// the Handlebars template engine.
 ///
+/// ## Strict Contexts
+///
+/// A context created with [`TemplateContext::strict`] only exposes the
+/// variables a template declares. Combined with Handlebars' strict mode, a
+/// reference to an undeclared variable then fails to render instead of
+/// silently producing an empty string (or leaking a value the template was
+/// never meant to see).
+///
 /// ## Variable Structure
 ///
 /// Variables are stored as JSON values, allowing for complex nested structures:
@@ -432,6 +469,11 @@ pub struct TemplateContext {
     /// Controls aspects like error handling, whitespace management, and
     /// security restrictions during template processing.
     pub config: TemplateRenderConfig,
+
+    /// Names of the variables the template declares, for strict contexts.
+    ///
+    /// `None` for contexts that accept any variable.
+    declared_variables: Option<BTreeSet<String>>,
 }
 
 /// Configuration for template rendering behavior.
@@ -444,6 +486,9 @@ pub struct TemplateContext {
 /// - `strict_variables`: Controls handling of undefined variables
 /// - `max_template_size`: Prevents processing of extremely large templates
 /// - `max_render_time`: Prevents runaway template processing
+/// - `max_context_size`: Bounds the size of strict template contexts
+/// - `max_render_depth`: Bounds block nesting and partial recursion
+/// - `max_output_size`: Bounds the size of rendered output
 ///
 /// ## Performance Configuration
 ///
@@ -484,6 +529,29 @@ pub struct TemplateRenderConfig {
     ///
     /// **Default**: `true`
     pub enable_caching: bool,
+
+    /// Maximum serialized size of a strict template context in bytes.
+    ///
+    /// [`TemplateContext::strict`] rejects variable sets larger than this to
+    /// bound the memory used while rendering.
+    ///
+    /// **Default**: 1MB (1,048,576 bytes)
+    pub max_context_size: usize,
+
+    /// Maximum nesting depth of blocks and partials in a template.
+    ///
+    /// Each `{{#each}}`, `{{#with}}` or other block adds one level, as does
+    /// each partial call together with the partial's own nesting. Templates
+    /// deeper than this, and inline partials that call themselves, are
+    /// rejected before rendering so they cannot exhaust the stack.
+    ///
+    /// **Default**: 64
+    pub max_render_depth: usize,
+
+    /// Maximum size of rendered output in bytes.
+    ///
+    /// **Default**: 10MB (10,485,760 bytes)
+    pub max_output_size: usize,
 }
 
 /// Advanced Handlebars template engine with custom helpers and security features.
@@ -579,7 +647,93 @@ impl TemplateContext {
         Self {
             variables,
             config: TemplateRenderConfig::default(),
+            declared_variables: None,
+        }
+    }
+
+    /// Creates a strict template context that only exposes declared variables.
+    ///
+    /// Top-level variables whose names are not in `declared_variables` are
+    /// dropped, and strict variable handling is enabled, so rendering with a
+    /// strict Handlebars engine fails on any reference to an undeclared or
+    /// missing variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `variables` - JSON object containing all template variables
+    /// * `declared_variables` - Names of the variables the template declares
+    ///
+    /// # Errors
+    ///
+    /// Returns `HandlebarsError::VariableValidation` if `variables` is not a JSON
+    /// object, or `HandlebarsError::ResourceLimit` if the declared variables
+    /// exceed [`TemplateRenderConfig::max_context_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use template_engine::TemplateContext;
+    /// use serde_json::json;
+    ///
+    /// let context = TemplateContext::strict(
+    ///     json!({"repo_name": "my-project", "secret_key": "hunter2"}),
+    ///     ["repo_name"],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(context.is_strict());
+    /// assert_eq!(context.variables, json!({"repo_name": "my-project"}));
+    /// ```
+    pub fn strict<I, S>(variables: Value, declared_variables: I) -> Result<Self, HandlebarsError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let Value::Object(all_variables) = variables else {
+            return Err(HandlebarsError::VariableValidation {
+                variable: "context".to_string(),
+                reason: "Template variables must be a JSON object".to_string(),
+            });
+        };
+
+        let declared: BTreeSet<String> = declared_variables.into_iter().map(Into::into).collect();
+        let variables: serde_json::Map<String, Value> = all_variables
+            .into_iter()
+            .filter(|(name, _)| declared.contains(name))
+            .collect();
+        let variables = Value::Object(variables);
+
+        let config = TemplateRenderConfig {
+            strict_variables: true,
+            ..Default::default()
+        };
+
+        let size = variables.to_string().len();
+        if size > config.max_context_size {
+            return Err(HandlebarsError::ResourceLimit {
+                limit_type: "context_size".to_string(),
+                message: format!(
+                    "Template context size {} bytes exceeds limit of {} bytes",
+                    size, config.max_context_size
+                ),
+            });
         }
+
+        Ok(Self {
+            variables,
+            config,
+            declared_variables: Some(declared),
+        })
+    }
+
+    /// Returns `true` if this context only exposes declared variables.
+    pub fn is_strict(&self) -> bool {
+        self.declared_variables.is_some()
+    }
+
+    /// Returns the declared variable names of a strict context.
+    pub fn declared_variables(&self) -> Option<&BTreeSet<String>> {
+        self.declared_variables.as_ref()
     }
 
     /// Creates a template context from a HashMap of string variables.
@@ -637,7 +791,11 @@ impl TemplateContext {
     /// );
     /// ```
     pub fn with_config(variables: Value, config: TemplateRenderConfig) -> Self {
-        Self { variables, config }
+        Self {
+            variables,
+            config,
+            declared_variables: None,
+        }
     }
 }
 
@@ -648,6 +806,9 @@ impl Default for TemplateRenderConfig {
             max_template_size: 1_048_576, // 1MB
             max_render_time_ms: 30_000,   // 30 seconds
             enable_caching: true,
+            max_context_size: 1_048_576, // 1MB
+            max_render_depth: 64,
+            max_output_size: 10_485_760, // 10MB
         }
     }
 }
@@ -890,7 +1051,9 @@ impl HandlebarsTemplateEngine {
     /// - `HandlebarsError::CompilationError`: Template syntax is invalid
     /// - `HandlebarsError::RenderError`: Template rendering failed
     /// - `HandlebarsError::VariableValidation`: Required variables missing
-    /// - `HandlebarsError::ResourceLimit`: Processing exceeded limits
+    /// - `HandlebarsError::ResourceLimit`: Template size, nesting depth or
+    ///   output size exceeded the configured limits, or an inline partial
+    ///   calls itself
     ///
     /// # Examples
     ///
@@ -922,6 +1085,9 @@ impl HandlebarsTemplateEngine {
             });
         }
 
+        // Reject pathological nesting and recursive partials before rendering
+        check_render_depth(template, self.config.max_render_depth)?;
+
         // Render the template with the provided context
         // Note: Variable validation is NOT performed here because:
         // 1. Handlebars' strict mode already validates variables at render time
@@ -932,14 +1098,18 @@ impl HandlebarsTemplateEngine {
             .render_template(template, &context.variables)
             .map_err(|e| {
                 // Check the error type and message to categorize appropriately
-                let err_msg = e.to_string();
+                let err_msg = describe_render_error(&e);
 
                 // Check for missing variable errors in strict mode
                 if err_msg.contains("Failed to access variable")
                     || (err_msg.contains("Variable") && err_msg.contains("not found"))
                 {
+                    let variable = match e.reason() {
+                        RenderErrorReason::MissingVariable(Some(path)) => path.clone(),
+                        _ => "unknown".to_string(),
+                    };
                     HandlebarsError::VariableValidation {
-                        variable: "unknown".to_string(), // Could parse from error message
+                        variable,
                         reason: err_msg,
                     }
                 }
@@ -963,6 +1133,17 @@ impl HandlebarsTemplateEngine {
                 }
             })?;
 
+        if result.len() > self.config.max_output_size {
+            return Err(HandlebarsError::ResourceLimit {
+                limit_type: "output_size".to_string(),
+                message: format!(
+                    "Rendered output of {} bytes exceeds limit of {} bytes",
+                    result.len(),
+                    self.config.max_output_size
+                ),
+            });
+        }
+
         Ok(result)
     }
 
@@ -1156,3 +1337,214 @@ impl HandlebarsTemplateEngine {
         Ok(())
     }
 }
+
+// ================================
+// Render Depth Checks
+// ================================
+
+/// Block nesting and partial calls recorded for one template scope.
+///
+/// The top-level template is one scope and each inline partial
+/// (`{{#*inline "name"}}`) is another.
+#[derive(Default)]
+struct PartialScope {
+    /// Deepest block nesting within the scope
+    max_depth: usize,
+    /// Partial calls as `(depth of the call, partial name)`; `None` is a
+    /// dynamic partial name that could refer to any inline partial
+    calls: Vec<(usize, Option<String>)>,
+}
+
+/// An open block while scanning a template.
+enum OpenBlock {
+    /// A helper or partial block
+    Block,
+    /// An inline partial definition, with the scope and depth to restore
+    Inline {
+        outer: Option<String>,
+        outer_depth: usize,
+    },
+}
+
+/// Reject templates whose blocks and partials nest deeper than `max_depth`.
+///
+/// Templates are scanned for block tags and partial calls without rendering
+/// them. Inline partials are followed through their calls, so a partial that
+/// (directly or indirectly) calls itself is rejected rather than recursing
+/// until the stack overflows. Syntax errors are left for Handlebars to report.
+fn check_render_depth(template: &str, max_depth: usize) -> Result<(), HandlebarsError> {
+    let mut scopes: HashMap<Option<String>, PartialScope> = HashMap::new();
+    scopes.insert(None, PartialScope::default());
+
+    let mut current: Option<String> = None;
+    let mut depth: usize = 0;
+    let mut open: Vec<OpenBlock> = Vec::new();
+
+    for tag in template_tags(template) {
+        if let Some(rest) = tag.strip_prefix("#*inline") {
+            let name = first_argument(rest).map(str::to_string);
+            open.push(OpenBlock::Inline {
+                outer: current.take(),
+                outer_depth: depth,
+            });
+            scopes.entry(name.clone()).or_default();
+            current = name;
+            depth = 0;
+        } else if tag.starts_with('#') || (tag.starts_with('^') && tag.len() > 1) {
+            depth += 1;
+            open.push(OpenBlock::Block);
+            let scope = scopes.entry(current.clone()).or_default();
+            scope.max_depth = scope.max_depth.max(depth);
+            if let Some(partial) = tag.strip_prefix("#>") {
+                scope.calls.push((depth, partial_name(partial)));
+            }
+        } else if tag.starts_with('/') {
+            match open.pop() {
+                Some(OpenBlock::Block) => depth = depth.saturating_sub(1),
+                Some(OpenBlock::Inline { outer, outer_depth }) => {
+                    current = outer;
+                    depth = outer_depth;
+                }
+                None => {}
+            }
+        } else if let Some(partial) = tag.strip_prefix('>') {
+            let scope = scopes.entry(current.clone()).or_default();
+            scope.calls.push((depth + 1, partial_name(partial)));
+        }
+    }
+
+    let mut resolved = HashMap::new();
+    let depth = scope_depth(&None, &scopes, &mut Vec::new(), &mut resolved, max_depth)?;
+    if depth > max_depth {
+        return Err(render_depth_exceeded(depth, max_depth));
+    }
+    Ok(())
+}
+
+/// Effective nesting depth of a scope, following calls into inline partials.
+fn scope_depth(
+    name: &Option<String>,
+    scopes: &HashMap<Option<String>, PartialScope>,
+    calling: &mut Vec<Option<String>>,
+    resolved: &mut HashMap<Option<String>, usize>,
+    max_depth: usize,
+) -> Result<usize, HandlebarsError> {
+    if let Some(depth) = resolved.get(name) {
+        return Ok(*depth);
+    }
+    let Some(scope) = scopes.get(name) else {
+        // Not an inline partial; Handlebars reports unknown partials itself
+        return Ok(0);
+    };
+
+    let mut depth = scope.max_depth;
+    calling.push(name.clone());
+    for (call_depth, callee) in &scope.calls {
+        let callees: Vec<Option<String>> = match callee {
+            Some(callee) => vec![Some(callee.clone())],
+            None => scopes.keys().filter(|k| k.is_some()).cloned().collect(),
+        };
+        for callee in callees {
+            if calling.contains(&callee) {
+                return Err(HandlebarsError::ResourceLimit {
+                    limit_type: "render_depth".to_string(),
+                    message: format!(
+                        "Partial '{}' calls itself recursively",
+                        callee.unwrap_or_default()
+                    ),
+                });
+            }
+            let callee_depth = scope_depth(&callee, scopes, calling, resolved, max_depth)?;
+            depth = depth.max(call_depth + callee_depth);
+            if depth > max_depth {
+                return Err(render_depth_exceeded(depth, max_depth));
+            }
+        }
+    }
+    calling.pop();
+
+    resolved.insert(name.clone(), depth);
+    Ok(depth)
+}
+
+fn render_depth_exceeded(depth: usize, max_depth: usize) -> HandlebarsError {
+    HandlebarsError::ResourceLimit {
+        limit_type: "render_depth".to_string(),
+        message: format!(
+            "Template nesting depth {} exceeds limit of {}",
+            depth, max_depth
+        ),
+    }
+}
+
+/// Collect the trimmed contents of each `{{ ... }}` tag in a template.
+///
+/// Comments, escaped mustaches (`\{{`) and raw blocks (`{{{{raw}}}}`) are
+/// skipped. Whitespace control markers (`~`) are removed.
+fn template_tags(template: &str) -> Vec<&str> {
+    let mut tags = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if rest[..start].ends_with('\\') {
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        let after = &rest[start + 2..];
+        if after.starts_with("{{") {
+            // Raw block: its content is not parsed by Handlebars
+            match after
+                .find("{{{{/")
+                .and_then(|close| after[close..].find("}}}}").map(|end| close + end + 4))
+            {
+                Some(end) => rest = &after[end..],
+                None => break,
+            }
+            continue;
+        }
+        if let Some(comment) = after
+            .strip_prefix("!--")
+            .or_else(|| after.strip_prefix("~!--"))
+        {
+            let close = comment
+                .match_indices("}}")
+                .find(|(i, _)| comment[..*i].ends_with("--") || comment[..*i].ends_with("--~"));
+            match close {
+                Some((end, _)) => rest = &comment[end + 2..],
+                None => break,
+            }
+            continue;
+        }
+
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let tag = after[..end]
+            .trim_start_matches(['{', '~'])
+            .trim_end_matches('~')
+            .trim();
+        rest = &after[end + 2..];
+
+        if !tag.starts_with('!') {
+            tags.push(tag);
+        }
+    }
+
+    tags
+}
+
+/// The first argument of a tag, without surrounding quotes.
+fn first_argument(args: &str) -> Option<&str> {
+    let arg = args.split_whitespace().next()?;
+    Some(arg.trim_matches(|c| c == '"' || c == '\''))
+}
+
+/// The partial named in a partial call; `None` for a dynamic name.
+fn partial_name(args: &str) -> Option<String> {
+    let args = args.trim_start();
+    if args.starts_with('(') {
+        return None;
+    }
+    first_argument(args).map(str::to_string)
+}
@@ -83,6 +83,9 @@ mod tests {
             max_template_size: 512_000,
             max_render_time_ms: 15_000,
             enable_caching: false,
+            max_context_size: 256_000,
+            max_render_depth: 32,
+            max_output_size: 1_000_000,
         };
 
         let engine = HandlebarsTemplateEngine::with_config(config.clone());
@@ -501,6 +504,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_render_template_error_reports_location_and_helper() {
+        let engine = HandlebarsTemplateEngine::new().unwrap();
+        let context = TemplateContext::new(json!({"name": "test"}));
+
+        let result = engine.render_template("# {{name}}\n{{snake_case}}\n", &context);
+
+        match result {
+            Err(HandlebarsError::RenderError { message }) => {
+                assert!(message.contains("line 2"), "message: {message}");
+                assert!(
+                    message.contains("helper 'snake_case'"),
+                    "message: {message}"
+                );
+            }
+            other => panic!("Expected RenderError, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_render_template_missing_variables_strict() {
         let engine = HandlebarsTemplateEngine::new().unwrap();
@@ -550,6 +572,177 @@ mod tests {
         ));
     }
 
+    fn assert_render_depth_error(result: Result<String, HandlebarsError>) {
+        match result {
+            Err(HandlebarsError::ResourceLimit { limit_type, .. }) => {
+                assert_eq!(limit_type, "render_depth");
+            }
+            other => panic!("Expected render_depth limit error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_render_template_deep_nesting_exceeds_limit() {
+        let engine = HandlebarsTemplateEngine::new().unwrap();
+        let context = TemplateContext::new(json!({"items": [[1]]}));
+
+        let template = format!(
+            "{}x{}",
+            "{{#each items}}".repeat(10_000),
+            "{{/each}}".repeat(10_000)
+        );
+
+        assert_render_depth_error(engine.render_template(&template, &context));
+    }
+
+    #[test]
+    fn test_render_template_nesting_within_limit() {
+        let config = TemplateRenderConfig {
+            max_render_depth: 3,
+            ..Default::default()
+        };
+        let engine = HandlebarsTemplateEngine::with_config(config).unwrap();
+        let context = TemplateContext::new(json!({"a": {"b": {"c": "deep"}}}));
+
+        let result = engine
+            .render_template(
+                "{{#with a}}{{#with b}}{{#if c}}{{c}}{{/if}}{{/with}}{{/with}}",
+                &context,
+            )
+            .unwrap();
+        assert_eq!(result, "deep");
+
+        let too_deep =
+            "{{#with a}}{{#with b}}{{#if c}}{{#if c}}{{c}}{{/if}}{{/if}}{{/with}}{{/with}}";
+        assert_render_depth_error(engine.render_template(too_deep, &context));
+    }
+
+    #[test]
+    fn test_render_template_self_referential_partial() {
+        let engine = HandlebarsTemplateEngine::new().unwrap();
+        let context = TemplateContext::new(json!({}));
+
+        let template = "{{#*inline \"loop\"}}again {{> loop}}{{/inline}}{{> loop}}";
+        assert_render_depth_error(engine.render_template(template, &context));
+
+        let mutual =
+            "{{#*inline \"a\"}}{{> b}}{{/inline}}{{#*inline \"b\"}}{{> a}}{{/inline}}{{> a}}";
+        assert_render_depth_error(engine.render_template(mutual, &context));
+    }
+
+    #[test]
+    fn test_render_template_partial_depth_counts_towards_limit() {
+        let config = TemplateRenderConfig {
+            max_render_depth: 2,
+            ..Default::default()
+        };
+        let engine = HandlebarsTemplateEngine::with_config(config).unwrap();
+        let context = TemplateContext::new(json!({"a": {"b": "x"}, "b": "y"}));
+
+        let ok = "{{#*inline \"p\"}}{{#with b}}{{this}}{{/with}}{{/inline}}{{> p}}";
+        assert!(engine.render_template(ok, &context).is_ok());
+
+        let too_deep =
+            "{{#*inline \"p\"}}{{#with b}}{{this}}{{/with}}{{/inline}}{{#with a}}{{> p}}{{/with}}";
+        assert_render_depth_error(engine.render_template(too_deep, &context));
+    }
+
+    #[test]
+    fn test_render_template_ignores_tags_in_comments() {
+        let config = TemplateRenderConfig {
+            max_render_depth: 1,
+            ..Default::default()
+        };
+        let engine = HandlebarsTemplateEngine::with_config(config).unwrap();
+        let context = TemplateContext::new(json!({}));
+
+        let template = "{{!-- {{#if a}}{{#if b}} --}}ok";
+        assert_eq!(engine.render_template(template, &context).unwrap(), "ok");
+    }
+
+    #[test]
+    fn test_render_template_output_size_limit() {
+        let config = TemplateRenderConfig {
+            max_output_size: 10,
+            ..Default::default()
+        };
+        let engine = HandlebarsTemplateEngine::with_config(config).unwrap();
+        let context = TemplateContext::new(json!({"items": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]}));
+
+        match engine.render_template("{{#each items}}{{this}}{{/each}}", &context) {
+            Err(HandlebarsError::ResourceLimit { limit_type, .. }) => {
+                assert_eq!(limit_type, "output_size");
+            }
+            other => panic!("Expected output_size limit error, got {:?}", other),
+        }
+    }
+
+    // ================================
+    // Strict Context Tests
+    // ================================
+
+    #[test]
+    fn test_strict_context_only_exposes_declared_variables() {
+        let context = TemplateContext::strict(
+            json!({"repo_name": "my-project", "secret_key": "hunter2"}),
+            ["repo_name", "author"],
+        )
+        .unwrap();
+
+        assert!(context.is_strict());
+        assert!(context.config.strict_variables);
+        assert_eq!(context.variables, json!({"repo_name": "my-project"}));
+        assert_eq!(
+            context.declared_variables().unwrap().len(),
+            2,
+            "declared variables are kept even when no value was supplied"
+        );
+        assert!(!TemplateContext::new(json!({})).is_strict());
+    }
+
+    #[test]
+    fn test_strict_context_rejects_undeclared_reference() {
+        let mut engine = HandlebarsTemplateEngine::new().unwrap();
+        let context = TemplateContext::strict(
+            json!({"repo_name": "my-project", "secret_key": "hunter2"}),
+            ["repo_name"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            engine.render_template("{{repo_name}}", &context).unwrap(),
+            "my-project"
+        );
+        assert!(engine.render_template("{{secret_key}}", &context).is_err());
+    }
+
+    #[test]
+    fn test_strict_context_requires_json_object() {
+        let result = TemplateContext::strict(json!(["repo_name"]), ["repo_name"]);
+        assert!(matches!(
+            result.unwrap_err(),
+            HandlebarsError::VariableValidation { .. }
+        ));
+    }
+
+    #[test]
+    fn test_strict_context_enforces_size_limit() {
+        let max_size = TemplateRenderConfig::default().max_context_size;
+        let large_value = "a".repeat(max_size + 1);
+
+        let result =
+            TemplateContext::strict(json!({ "description": large_value }), ["description"]);
+        assert!(matches!(
+            result.unwrap_err(),
+            HandlebarsError::ResourceLimit { .. }
+        ));
+
+        // Undeclared variables do not count towards the limit.
+        let large_value = "a".repeat(max_size + 1);
+        let result = TemplateContext::strict(json!({ "description": large_value }), ["name"]);
+        assert!(result.is_ok());
+    }
+
     // ================================
     // Integration Tests
     // ================================
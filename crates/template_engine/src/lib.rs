@@ -18,6 +18,7 @@
 //! - [`TemplateProcessor`] - Main processor for variable substitution and file handling
 //! - [`TemplateFetcher`] - Trait for retrieving template files from various sources
 //! - [`GitHubTemplateFetcher`] - GitHub-specific implementation for template fetching
+//! - [`LocalTemplateFetcher`] - Reads a template from a local directory
 //! - [`VariableConfig`] - Configuration for variable validation and defaults
 //! - [`TemplateProcessingRequest`] - Request structure containing all processing parameters
 //!
@@ -42,6 +43,7 @@
 //!     built_in_variables: HashMap::new(),
 //!     variable_configs: HashMap::new(),
 //!     templating_config: None,
+//!     strict_variables: false,
 //! };
 //!
 //! // Fetch template files (in this example, from a list of files)
@@ -122,7 +124,16 @@ use async_trait::async_trait;
 use glob::{MatchOptions, Pattern};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+pub mod ci_context;
+pub use ci_context::{
+    ci_variables_from, generate_ci_variables, is_running_in_ci, workflow_context_from_ref,
+};
+
+pub mod clock;
+pub use clock::{Clock, FixedClock, SystemClock};
 
 pub mod errors;
 pub use errors::Error;
@@ -130,6 +141,23 @@ pub use errors::Error;
 pub mod handlebars_engine;
 pub use handlebars_engine::*;
 
+pub mod manifest;
+pub use manifest::{build_file_manifest, is_text_content, TemplateFileEntry, TemplateFileKind};
+
+pub mod post_generation;
+pub use post_generation::{apply_post_generation_transforms, PostGenerationTransform};
+
+pub mod ref_name;
+pub use ref_name::{is_valid_ref_name, sanitize_ref_name};
+
+pub mod variable_names;
+pub use variable_names::{
+    is_built_in_variable_name, is_valid_variable_name, BUILT_IN_VARIABLE_NAMES,
+};
+
+pub mod variable_transforms;
+pub use variable_transforms::{apply_variable_transforms, VariableTransform};
+
 #[cfg(test)]
 #[path = "lib_tests.rs"]
 mod tests;
@@ -148,11 +176,14 @@ mod multiple_missing_variables_tests;
 /// ## Method Requirements
 ///
 /// * `fetch_template_files` - Retrieve all files from a template source
+/// * `fetch_file` - Retrieve a single file, e.g. the template configuration, so
+///   that callers can validate a request before downloading the whole template
 ///
 /// ## Source Format
 ///
 /// The `source` parameter format depends on the implementation:
-/// - GitHub: Repository URLs like <https://github.com/owner/repo> or "owner/repo"
+/// - GitHub: Repository URLs like <https://github.com/owner/repo> or "owner/repo",
+///   optionally followed by `//<subpath>` to select a subdirectory
 /// - Local: File system paths like "/path/to/template" or "C:\\templates\\basic"
 /// - HTTP: URLs to downloadable archives like <https://example.com/templates/rust.zip>
 ///
@@ -196,6 +227,67 @@ mod multiple_missing_variables_tests;
 #[async_trait]
 pub trait TemplateFetcher: Send + Sync {
     async fn fetch_template_files(&self, source: &str) -> Result<Vec<(String, Vec<u8>)>, String>;
+
+    /// Fetches a single file from a template source.
+    ///
+    /// `path` is relative to the template root (after any `//<subpath>`).
+    /// Returns `Ok(None)` if the template has no such file.
+    ///
+    /// The default implementation fetches every template file and picks the
+    /// requested one, so it only sees files returned by
+    /// [`fetch_template_files`](Self::fetch_template_files); repository
+    /// metadata such as `.reporoller/template.toml` is not visible through it.
+    /// Implementations that can read one file cheaply should override it.
+    async fn fetch_file(&self, source: &str, path: &str) -> Result<Option<Vec<u8>>, String> {
+        let wanted = normalize_output_path(path);
+        let files = self.fetch_template_files(source).await?;
+
+        Ok(files
+            .into_iter()
+            .find(|(file_path, _)| normalize_output_path(file_path) == wanted)
+            .map(|(_, content)| content))
+    }
+
+    /// Fetches template files at a git ref and reports the commit they came from.
+    ///
+    /// `git_ref` may be a branch, a tag or a full commit SHA; `None` fetches
+    /// the tip of the default branch. Recording the returned
+    /// [`FetchedTemplate::commit_sha`] and passing it back as `git_ref` later
+    /// fetches exactly the same files.
+    ///
+    /// The default implementation only supports `None`, fetches through
+    /// [`fetch_template_files`](Self::fetch_template_files) and reports no
+    /// commit SHA. Sources backed by git should override it.
+    async fn fetch_template_at(
+        &self,
+        source: &str,
+        git_ref: Option<&str>,
+    ) -> Result<FetchedTemplate, String> {
+        if let Some(git_ref) = git_ref {
+            return Err(format!(
+                "Template source '{source}' cannot be fetched at ref '{git_ref}'"
+            ));
+        }
+
+        Ok(FetchedTemplate {
+            files: self.fetch_template_files(source).await?,
+            commit_sha: None,
+        })
+    }
+}
+
+/// Template files together with the commit they were read from.
+///
+/// Returned by [`TemplateFetcher::fetch_template_at`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedTemplate {
+    /// Template files as (path, content) pairs, as returned by
+    /// [`TemplateFetcher::fetch_template_files`]
+    pub files: Vec<(String, Vec<u8>)>,
+
+    /// Full SHA of the commit the files were read from, or `None` when the
+    /// source is not versioned with git
+    pub commit_sha: Option<String>,
 }
 
 /// Parameters for generating built-in template variables.
@@ -281,6 +373,10 @@ pub struct BuiltInVariablesParams<'a> {
 /// and preparing them for template processing. It handles various GitHub URL formats
 /// and filters out repository metadata files (like .git, .github directories).
 ///
+/// A single repository can host several templates in subdirectories. Append
+/// `//<subpath>` to the source to fetch only that subdirectory, for example
+/// `https://github.com/owner/templates//templates/rust-lib`.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -315,36 +411,60 @@ impl GitHubTemplateFetcher {
         Self {}
     }
 
-    /// Clones a Git repository and reads all files from it.
+    /// Fetches one commit of a Git repository and reads all files from it.
     ///
-    /// This method performs a shallow clone of the repository to a temporary
-    /// directory, then reads all files and returns them as a vector of
-    /// (path, content) tuples. The temporary directory is automatically
+    /// This method performs a shallow fetch of `git_ref` (or `HEAD`) into a
+    /// temporary directory, checks it out, then reads all files and returns
+    /// them as a vector of (path, content) tuples together with the SHA of
+    /// the checked out commit. The temporary directory is automatically
     /// cleaned up when the operation completes.
-    async fn fetch_repository_files(&self, url: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+    async fn fetch_repository_files(
+        &self,
+        url: &str,
+        git_ref: Option<&str>,
+    ) -> Result<(Vec<(String, Vec<u8>)>, String), String> {
         use std::process::Command;
         use tempfile::TempDir;
 
-        // Create a temporary directory for cloning
+        // Create a temporary directory for the checkout
         let temp_dir =
             TempDir::new().map_err(|e| format!("Failed to create temporary directory: {e}"))?;
+        let checkout_dir = temp_dir.path().to_str().unwrap();
+
+        // Fetching instead of cloning accepts commit SHAs as well as branches and tags
+        let git_ref = git_ref.unwrap_or("HEAD");
+        let steps: [(&str, Vec<&str>); 3] = [
+            ("init", vec!["init", "--quiet", checkout_dir]),
+            (
+                "fetch",
+                vec!["-C", checkout_dir, "fetch", "--depth", "1", url, git_ref],
+            ),
+            (
+                "checkout",
+                vec!["-C", checkout_dir, "checkout", "--detach", "FETCH_HEAD"],
+            ),
+        ];
+        for (step, args) in steps {
+            let output = Command::new("git")
+                .args(&args)
+                .output()
+                .map_err(|e| format!("Failed to execute git {step}: {e}"))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!("Git {step} of '{git_ref}' failed: {stderr}"));
+            }
+        }
 
-        // Clone the repository
         let output = Command::new("git")
-            .args([
-                "clone",
-                "--depth",
-                "1",
-                url,
-                temp_dir.path().to_str().unwrap(),
-            ])
+            .args(["-C", checkout_dir, "rev-parse", "HEAD"])
             .output()
-            .map_err(|e| format!("Failed to execute git clone: {e}"))?;
-
+            .map_err(|e| format!("Failed to execute git rev-parse: {e}"))?;
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Git clone failed: {stderr}"));
+            return Err(format!("Git rev-parse failed: {stderr}"));
         }
+        let commit_sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
 
         // Read all files from the cloned repository
         let mut files = Vec::new();
@@ -376,42 +496,506 @@ impl GitHubTemplateFetcher {
             return Err(format!("No files found in repository {url}"));
         }
 
-        Ok(files)
+        Ok((files, commit_sha))
+    }
+
+    /// Reads a single file from a Git repository without checking out the tree.
+    ///
+    /// This performs a shallow, blob-less clone so that only the commit and its
+    /// trees are downloaded, then reads the one requested blob. Returns
+    /// `Ok(None)` if the file does not exist at `HEAD`.
+    async fn fetch_repository_file(
+        &self,
+        url: &str,
+        path: &str,
+    ) -> Result<Option<Vec<u8>>, String> {
+        use std::process::Command;
+        use tempfile::TempDir;
+
+        let temp_dir =
+            TempDir::new().map_err(|e| format!("Failed to create temporary directory: {e}"))?;
+        let clone_dir = temp_dir.path().to_str().unwrap();
+
+        let output = Command::new("git")
+            .args([
+                "clone",
+                "--depth",
+                "1",
+                "--filter=blob:none",
+                "--no-checkout",
+                url,
+                clone_dir,
+            ])
+            .output()
+            .map_err(|e| format!("Failed to execute git clone: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Git clone failed: {stderr}"));
+        }
+
+        // Trees are present in a blob-less clone, so listing does not download content
+        let output = Command::new("git")
+            .args([
+                "-C",
+                clone_dir,
+                "ls-tree",
+                "--name-only",
+                "HEAD",
+                "--",
+                path,
+            ])
+            .output()
+            .map_err(|e| format!("Failed to execute git ls-tree: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Git ls-tree failed: {stderr}"));
+        }
+
+        if output.stdout.is_empty() {
+            return Ok(None);
+        }
+
+        let output = Command::new("git")
+            .args(["-C", clone_dir, "show", &format!("HEAD:{path}")])
+            .output()
+            .map_err(|e| format!("Failed to execute git show: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to read {path} from {url}: {stderr}"));
+        }
+
+        Ok(Some(output.stdout))
     }
 }
 
 #[async_trait]
 impl TemplateFetcher for GitHubTemplateFetcher {
+    /// Fetches template files from a GitHub repository.
+    ///
+    /// The `source` may name a subdirectory of the repository by appending
+    /// `//<subpath>`, e.g. `https://github.com/owner/repo//templates/rust-lib`.
+    /// Only files inside that subdirectory are returned, with paths made
+    /// relative to it.
     async fn fetch_template_files(&self, source: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
-        // Fetch all files from the repository using git clone
-        let files = self.fetch_repository_files(source).await?;
+        Ok(self.fetch_template_at(source, None).await?.files)
+    }
 
-        // Filter out unwanted files
-        let filtered_files: Vec<(String, Vec<u8>)> = files
-            .into_iter()
-            .filter(|(path, _)| {
-                // Use OS-agnostic path checking for directories we want to exclude
-                let path_lower = path.to_lowercase();
-                let is_git_dir =
-                    path_lower.starts_with(".git/") || path_lower.starts_with(".git\\");
-                let is_reporoller_dir = path_lower.starts_with(".reporoller/")
-                    || path_lower.starts_with(".reporoller\\");
-                let is_gitignore = path == ".gitignore";
-
-                // Exclude: .git/, .reporoller/, .gitignore
-                // Allow: .github/ (templates can provide workflows for target repos)
-                !is_git_dir && !is_reporoller_dir && !is_gitignore
-            })
-            .collect();
+    /// Fetches template files from a GitHub repository at a branch, tag or
+    /// commit SHA, reporting the SHA of the fetched commit.
+    ///
+    /// A `//<subpath>` in `source` is honoured as for
+    /// [`fetch_template_files`](Self::fetch_template_files).
+    async fn fetch_template_at(
+        &self,
+        source: &str,
+        git_ref: Option<&str>,
+    ) -> Result<FetchedTemplate, String> {
+        let (repository, subpath) = split_template_source(source);
+
+        let (files, commit_sha) = self.fetch_repository_files(repository, git_ref).await?;
+
+        Ok(FetchedTemplate {
+            files: select_template_files(files, subpath, source)?,
+            commit_sha: Some(commit_sha),
+        })
+    }
+
+    /// Fetches a single file from a GitHub repository.
+    ///
+    /// Only the requested file's content is downloaded, which makes this
+    /// suitable for reading `.reporoller/template.toml` before committing to a
+    /// full fetch. A `//<subpath>` in `source` is honoured.
+    async fn fetch_file(&self, source: &str, path: &str) -> Result<Option<Vec<u8>>, String> {
+        let (repository, subpath) = split_template_source(source);
+
+        self.fetch_repository_file(repository, &template_file_path(subpath, path))
+            .await
+    }
+}
+
+/// Fetches template files from a directory on the local file system.
+///
+/// Reads the working tree as it is on disk, including uncommitted changes, so
+/// template authors can preview a template before pushing it. Repository
+/// metadata (`.git/`, `.reporoller/` and `.gitignore`) is excluded in the
+/// same way as for [`GitHubTemplateFetcher`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use template_engine::{LocalTemplateFetcher, TemplateFetcher};
+///
+/// # async {
+/// let files = LocalTemplateFetcher.fetch_template_files("./templates/rust-lib").await?;
+/// # Ok::<(), String>(())
+/// # };
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalTemplateFetcher;
 
-        if filtered_files.is_empty() {
-            return Err(format!("No template files found in repository {source}"));
+#[async_trait]
+impl TemplateFetcher for LocalTemplateFetcher {
+    /// Reads all files below the directory named by `source`.
+    ///
+    /// Returned paths are relative to the directory and use `/` separators.
+    async fn fetch_template_files(&self, source: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+        let root = std::path::Path::new(source);
+        if !root.is_dir() {
+            return Err(format!("Template directory '{source}' does not exist"));
+        }
+
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(root) {
+            let entry = entry.map_err(|e| format!("Failed to read {source}: {e}"))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let relative_path = entry
+                .path()
+                .strip_prefix(root)
+                .map_err(|e| format!("Failed to get relative path: {e}"))?;
+            let content = std::fs::read(entry.path())
+                .map_err(|e| format!("Failed to read file {:?}: {e}", entry.path()))?;
+
+            files.push((relative_path.to_string_lossy().replace('\\', "/"), content));
         }
 
-        Ok(filtered_files)
+        select_template_files(files, None, source)
+    }
+
+    /// Reads a single file below the directory named by `source`.
+    ///
+    /// Unlike [`fetch_template_files`](Self::fetch_template_files), repository
+    /// metadata such as `.reporoller/template.toml` can be read.
+    async fn fetch_file(&self, source: &str, path: &str) -> Result<Option<Vec<u8>>, String> {
+        let root = std::path::Path::new(source);
+        if !root.is_dir() {
+            return Err(format!("Template directory '{source}' does not exist"));
+        }
+
+        let file_path = root.join(normalize_output_path(path));
+        if !file_path.is_file() {
+            return Ok(None);
+        }
+
+        std::fs::read(&file_path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read file {file_path:?}: {e}"))
+    }
+}
+
+/// Returns the repository path of a template file, prefixed with the
+/// template's subpath when the template lives in a subdirectory.
+fn template_file_path(subpath: Option<&str>, path: &str) -> String {
+    let path = normalize_output_path(path);
+    match subpath {
+        Some(subpath) => format!("{subpath}/{path}"),
+        None => path,
+    }
+}
+
+/// Splits a template source into the repository location and an optional subpath.
+///
+/// The subpath is separated from the repository by `//`, e.g.
+/// `owner/repo//templates/rust-lib`. The `//` following a URL scheme
+/// (`https://`) is not treated as a separator. Leading and trailing slashes
+/// are trimmed from the subpath; an empty subpath is treated as absent.
+fn split_template_source(source: &str) -> (&str, Option<&str>) {
+    let search_start = source.find("://").map(|i| i + 3).unwrap_or(0);
+
+    match source[search_start..].find("//") {
+        Some(offset) => {
+            let separator = search_start + offset;
+            let subpath = source[separator + 2..].trim_matches('/');
+            let subpath = if subpath.is_empty() {
+                None
+            } else {
+                Some(subpath)
+            };
+            (&source[..separator], subpath)
+        }
+        None => (source, None),
     }
 }
 
+/// Converts a Handlebars rendering failure into a processing error.
+///
+/// Resource limit violations become [`Error::RenderLimitExceeded`]; all other
+/// failures are reported as a validation error on `variable`, or on the
+/// missing variable when Handlebars names it.
+fn render_error(variable: &str, error: HandlebarsError) -> Error {
+    match error {
+        HandlebarsError::ResourceLimit {
+            limit_type,
+            message,
+        } => Error::RenderLimitExceeded {
+            limit: limit_type,
+            message,
+        },
+        HandlebarsError::VariableValidation {
+            variable: missing,
+            reason,
+        } if missing != "unknown" => Error::VariableValidation {
+            variable: missing,
+            reason: format!("Template rendering failed: {}", reason),
+        },
+        other => Error::VariableValidation {
+            variable: variable.to_string(),
+            reason: format!("Template rendering failed: {}", other),
+        },
+    }
+}
+
+/// Converts a failure rendering the template file at `file_path` into a
+/// processing error that names the file.
+fn file_render_error(file_path: &str, error: HandlebarsError) -> Error {
+    match render_error("template_content", error) {
+        Error::VariableValidation { variable, reason } => Error::VariableValidation {
+            variable,
+            reason: format!("{} in file '{}'", reason, file_path),
+        },
+        Error::RenderLimitExceeded { limit, message } => Error::RenderLimitExceeded {
+            limit,
+            message: format!("{} in file '{}'", message, file_path),
+        },
+        other => other,
+    }
+}
+
+/// Turns a rendered file path into the path the file is written to.
+///
+/// Removes a `.template` suffix and normalizes the result.
+fn final_output_path(processed_path: &str) -> String {
+    normalize_output_path(processed_path.trim_end_matches(".template"))
+}
+
+/// Resolves a normalized output path against the output directory.
+///
+/// Only plain path components are accepted, so the resolved path always lies
+/// inside `output_dir` unless a symbolic link redirects it.
+fn resolve_output_path(output_dir: &Path, path: &str) -> Result<PathBuf, Error> {
+    let relative = Path::new(path);
+    let is_contained = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if path.is_empty() || !is_contained {
+        return Err(Error::OutputPathOutsideDirectory {
+            path: path.to_string(),
+            output_dir: output_dir.display().to_string(),
+        });
+    }
+    Ok(output_dir.join(relative))
+}
+
+/// Writes a processed file to its resolved location inside `output_dir`.
+///
+/// Fails before creating anything if the deepest existing directory on the
+/// way to the file is not inside `output_dir`, or if the target itself is a
+/// symbolic link, since either would let the file land outside the output
+/// directory.
+fn write_output_file(
+    output_dir: &Path,
+    path: &str,
+    target_path: &Path,
+    content: &[u8],
+) -> Result<(), Error> {
+    let outside = || Error::OutputPathOutsideDirectory {
+        path: path.to_string(),
+        output_dir: output_dir.display().to_string(),
+    };
+
+    std::fs::create_dir_all(output_dir)?;
+    let root = output_dir.canonicalize()?;
+
+    let parent = target_path.parent().unwrap_or(output_dir);
+    let existing_ancestor = parent
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .unwrap_or(output_dir);
+    if !existing_ancestor.canonicalize()?.starts_with(&root) {
+        return Err(outside());
+    }
+    if std::fs::symlink_metadata(target_path).is_ok_and(|m| m.file_type().is_symlink()) {
+        return Err(outside());
+    }
+
+    std::fs::create_dir_all(parent)?;
+    std::fs::write(target_path, content)?;
+    Ok(())
+}
+
+/// Normalizes a rendered output path.
+///
+/// Converts backslashes to forward slashes and removes `.` segments and
+/// redundant separators, so that `./src//lib.rs` and `src/lib.rs` compare equal.
+fn normalize_output_path(path: &str) -> String {
+    path.replace('\\', "/")
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Expands `{a,b}` alternatives in a glob pattern into separate patterns.
+///
+/// The `glob` crate has no brace support, so `**/*.{rs,toml}` becomes
+/// `**/*.rs` and `**/*.toml` before matching. Groups may be nested
+/// (`{a,{b,c}}`) and several groups multiply out. A brace, comma or backslash
+/// preceded by a backslash is kept as a literal character. Unclosed braces and
+/// anything inside a `[...]` character class are left as written.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    expand_brace_sequence(&chars, 0, false).0
+}
+
+/// Expands the sequence starting at `start`.
+///
+/// When `nested` is set, stops at an unescaped `,` or `}` that closes the
+/// enclosing alternative. Returns the expansions and the index it stopped at.
+fn expand_brace_sequence(chars: &[char], start: usize, nested: bool) -> (Vec<String>, usize) {
+    let mut expansions = vec![String::new()];
+    let mut i = start;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if matches!(chars.get(i + 1), Some('{' | '}' | ',' | '\\')) => {
+                for expansion in &mut expansions {
+                    expansion.push(chars[i + 1]);
+                }
+                i += 2;
+            }
+            '[' => {
+                // Copy a character class verbatim; `]` directly after `[` is a member.
+                let end = chars[i + 1..]
+                    .iter()
+                    .skip(1)
+                    .position(|&c| c == ']')
+                    .map_or(i, |offset| i + offset + 2);
+                let class: String = chars[i..=end].iter().collect();
+                for expansion in &mut expansions {
+                    expansion.push_str(&class);
+                }
+                i = end + 1;
+            }
+            '{' => match expand_brace_group(chars, i + 1) {
+                Some((alternatives, end)) => {
+                    expansions = expansions
+                        .iter()
+                        .flat_map(|prefix| {
+                            alternatives
+                                .iter()
+                                .map(move |alternative| format!("{prefix}{alternative}"))
+                        })
+                        .collect();
+                    i = end + 1;
+                }
+                None => {
+                    for expansion in &mut expansions {
+                        expansion.push('{');
+                    }
+                    i += 1;
+                }
+            },
+            ',' | '}' if nested => break,
+            c => {
+                for expansion in &mut expansions {
+                    expansion.push(c);
+                }
+                i += 1;
+            }
+        }
+    }
+
+    (expansions, i)
+}
+
+/// Expands the alternatives of a brace group whose `{` precedes `start`.
+///
+/// Returns the alternatives and the index of the closing `}`, or `None` if
+/// the group is never closed.
+fn expand_brace_group(chars: &[char], start: usize) -> Option<(Vec<String>, usize)> {
+    let mut alternatives = Vec::new();
+    let mut i = start;
+
+    loop {
+        let (expansions, end) = expand_brace_sequence(chars, i, true);
+        alternatives.extend(expansions);
+        match chars.get(end) {
+            Some(',') => i = end + 1,
+            Some('}') => return Some((alternatives, end)),
+            _ => return None,
+        }
+    }
+}
+
+/// Selects the template files from the files of a cloned repository.
+///
+/// When a `subpath` is given only files below that directory are kept and
+/// their paths are made relative to it. Repository metadata (`.git/`,
+/// `.reporoller/` and `.gitignore`) is then excluded relative to the
+/// template root.
+///
+/// # Errors
+///
+/// Returns an error if the subpath does not exist in the repository or if no
+/// template files remain after filtering.
+fn select_template_files(
+    files: Vec<(String, Vec<u8>)>,
+    subpath: Option<&str>,
+    source: &str,
+) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let files: Vec<(String, Vec<u8>)> = match subpath {
+        Some(subpath) => {
+            let prefix = format!("{subpath}/");
+            let subtree: Vec<(String, Vec<u8>)> = files
+                .into_iter()
+                .filter_map(|(path, content)| {
+                    let normalized = path.replace('\\', "/");
+                    normalized
+                        .strip_prefix(&prefix)
+                        .map(|relative| (relative.to_string(), content))
+                })
+                .collect();
+
+            if subtree.is_empty() {
+                return Err(format!(
+                    "Template subdirectory '{subpath}' not found in repository {source}"
+                ));
+            }
+
+            subtree
+        }
+        None => files,
+    };
+
+    // Filter out unwanted files
+    let filtered_files: Vec<(String, Vec<u8>)> = files
+        .into_iter()
+        .filter(|(path, _)| {
+            // Use OS-agnostic path checking for directories we want to exclude
+            let path_lower = path.to_lowercase();
+            let is_git_dir = path_lower.starts_with(".git/") || path_lower.starts_with(".git\\");
+            let is_reporoller_dir =
+                path_lower.starts_with(".reporoller/") || path_lower.starts_with(".reporoller\\");
+            let is_gitignore = path == ".gitignore";
+
+            // Exclude: .git/, .reporoller/, .gitignore
+            // Allow: .github/ (templates can provide workflows for target repos)
+            !is_git_dir && !is_reporoller_dir && !is_gitignore
+        })
+        .collect();
+
+    if filtered_files.is_empty() {
+        return Err(format!("No template files found in repository {source}"));
+    }
+
+    Ok(filtered_files)
+}
+
 /// Result of template processing containing the processed files.
 ///
 /// This structure represents the output of template processing, containing
@@ -460,6 +1044,24 @@ pub struct ProcessedTemplate {
     pub files: Vec<(String, Vec<u8>)>, // (path, content)
 }
 
+/// A template file that failed to render during [`TemplateProcessor::validate_render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderFailure {
+    /// Path of the template file as supplied to the processor
+    pub path: String,
+    /// Description of the rendering error
+    pub reason: String,
+}
+
+/// The outcome of [`TemplateProcessor::dry_render`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DryRender {
+    /// Output paths of the files whose paths rendered, in input order
+    pub output_paths: Vec<String>,
+    /// Files that failed to render, in input order
+    pub failures: Vec<RenderFailure>,
+}
+
 /// Configuration for controlling which files are processed during template rendering.
 ///
 /// This structure allows fine-grained control over which files in a template
@@ -470,6 +1072,10 @@ pub struct ProcessedTemplate {
 ///
 /// * `include_patterns` - Glob patterns for files that should be processed
 /// * `exclude_patterns` - Glob patterns for files that should be skipped
+/// * `post_generation` - Built-in transforms applied to the generated output
+/// * `case_insensitive` - Match patterns without regard to case
+/// * `glob_recursive` - Let `*` and `?` match across directory separators
+/// * `strict_variables` - Fail rendering on references to undeclared variables
 ///
 /// ## Pattern Matching
 ///
@@ -477,6 +1083,8 @@ pub struct ProcessedTemplate {
 /// - Patterns are applied relative to the template repository root
 /// - Exclude patterns take precedence over include patterns
 /// - If no include patterns are specified, all files are included by default
+/// - By default matching is case-sensitive and `*` does not cross `/`, so
+///   `*.rs` matches `main.rs` but not `src/main.rs`
 ///
 /// ## Examples
 ///
@@ -495,6 +1103,11 @@ pub struct ProcessedTemplate {
 ///         "**/*_tests.rs".to_string(),
 ///         "target/**".to_string(),
 ///     ],
+///     post_generation: vec![],
+///     case_insensitive: false,
+///     glob_recursive: false,
+///     strict_variables: false,
+///     max_path_length: None,
 /// };
 ///
 /// // Process everything except binary files and build artifacts
@@ -507,6 +1120,11 @@ pub struct ProcessedTemplate {
 ///         "**/target/**".to_string(),
 ///         "**/node_modules/**".to_string(),
 ///     ],
+///     post_generation: vec![],
+///     case_insensitive: false,
+///     glob_recursive: false,
+///     strict_variables: false,
+///     max_path_length: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -515,6 +1133,50 @@ pub struct TemplatingConfig {
     pub include_patterns: Vec<String>,
     #[serde(default)]
     pub exclude_patterns: Vec<String>,
+    /// Built-in transforms to run on the generated output, in order.
+    ///
+    /// See [`PostGenerationTransform`] for the available transforms.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_generation: Vec<PostGenerationTransform>,
+    /// Match include and exclude patterns without regard to case.
+    ///
+    /// Useful when template files are authored on case-insensitive file
+    /// systems. Defaults to `false` (case-sensitive matching).
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// Let `*` and `?` match path separators, so that `*.rs` also matches
+    /// `src/main.rs`.
+    ///
+    /// Defaults to `false`, where `*` matches within a single path segment
+    /// and `**` must be used to match across directories.
+    #[serde(default)]
+    pub glob_recursive: bool,
+    /// Render in strict mode, so that referencing a variable the template
+    /// does not declare fails instead of rendering blank.
+    ///
+    /// Callers building a [`TemplateProcessingRequest`] from a template
+    /// configuration copy this into its `strict_variables` flag. Defaults to
+    /// `false`.
+    #[serde(default)]
+    pub strict_variables: bool,
+    /// Reject output paths longer than this many characters.
+    ///
+    /// Callers processing a template with this configuration pass it to
+    /// [`TemplateProcessor::with_max_path_length`]. Defaults to `None`, which
+    /// accepts paths of any length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_path_length: Option<usize>,
+}
+
+impl TemplatingConfig {
+    /// Returns the glob match options selected by this configuration.
+    pub fn match_options(&self) -> MatchOptions {
+        MatchOptions {
+            case_sensitive: !self.case_insensitive,
+            require_literal_separator: !self.glob_recursive,
+            require_literal_leading_dot: false,
+        }
+    }
 }
 
 /// Complete request for processing a template with variable substitution.
@@ -567,15 +1229,43 @@ pub struct TemplatingConfig {
 ///     templating_config: Some(TemplatingConfig {
 ///         include_patterns: vec!["**/*.rs".to_string(), "**/*.toml".to_string()],
 ///         exclude_patterns: vec!["target/**".to_string()],
+///         post_generation: vec![],
+///         case_insensitive: false,
+///         glob_recursive: false,
+///         strict_variables: false,
+///         max_path_length: None,
 ///     }),
+///     strict_variables: false,
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TemplateProcessingRequest {
     pub variables: HashMap<String, String>,
     pub built_in_variables: HashMap<String, String>,
     pub variable_configs: HashMap<String, VariableConfig>,
     pub templating_config: Option<TemplatingConfig>,
+    /// Only expose declared variables to templates.
+    ///
+    /// When `true`, the template context is built with [`TemplateContext::strict`]
+    /// from the names in `variable_configs` and `built_in_variables`. Templates
+    /// that reference any other variable fail, even if a value was supplied.
+    pub strict_variables: bool,
+}
+
+impl std::fmt::Debug for TemplateProcessingRequest {
+    /// Formats the request with the values of secret variables redacted.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemplateProcessingRequest")
+            .field(
+                "variables",
+                &redact_secret_variables(&self.variables, &self.variable_configs),
+            )
+            .field("built_in_variables", &self.built_in_variables)
+            .field("variable_configs", &self.variable_configs)
+            .field("templating_config", &self.templating_config)
+            .field("strict_variables", &self.strict_variables)
+            .finish()
+    }
 }
 
 /// Template processor that handles variable substitution and file processing.
@@ -617,7 +1307,13 @@ pub struct TemplateProcessingRequest {
 ///     templating_config: Some(TemplatingConfig {
 ///         include_patterns: vec!["**/*.rs".to_string()],
 ///         exclude_patterns: vec!["target/**".to_string()],
+///         post_generation: vec![],
+///         case_insensitive: false,
+///         glob_recursive: false,
+///         strict_variables: false,
+///         max_path_length: None,
 ///     }),
+///     strict_variables: false,
 /// };
 ///
 /// let files = vec![
@@ -632,6 +1328,12 @@ pub struct TemplateProcessingRequest {
 pub struct TemplateProcessor {
     /// The underlying Handlebars template engine for advanced templating
     handlebars_engine: HandlebarsTemplateEngine,
+    /// Source of the current time for built-in timestamp variables
+    clock: Arc<dyn Clock>,
+    /// Whether `process_template` also writes the processed files to disk
+    write_output: bool,
+    /// Longest output path `process_template` accepts, in characters
+    max_path_length: Option<usize>,
 }
 
 impl Default for TemplateProcessor {
@@ -682,7 +1384,75 @@ impl TemplateProcessor {
             Error::EngineInitialization(format!("Failed to register custom helpers: {}", e))
         })?;
 
-        Ok(Self { handlebars_engine })
+        Ok(Self {
+            handlebars_engine,
+            clock: Arc::new(SystemClock),
+            write_output: false,
+            max_path_length: None,
+        })
+    }
+
+    /// Replaces the clock used to generate built-in timestamp variables.
+    ///
+    /// Defaults to [`SystemClock`]. Use a [`FixedClock`] to make generated
+    /// timestamps deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use std::sync::Arc;
+    /// use template_engine::{FixedClock, TemplateProcessor};
+    ///
+    /// let instant = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+    /// let processor = TemplateProcessor::new()?.with_clock(Arc::new(FixedClock::new(instant)));
+    /// # Ok::<(), template_engine::Error>(())
+    /// ```
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Makes [`process_template`](Self::process_template) write the processed
+    /// files into its output directory.
+    ///
+    /// Disabled by default, in which case the processed files are only
+    /// returned and the caller decides where to write them. Missing
+    /// directories are created and existing files are overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use template_engine::TemplateProcessor;
+    ///
+    /// let processor = TemplateProcessor::new()?.with_write_output(true);
+    /// # Ok::<(), template_engine::Error>(())
+    /// ```
+    pub fn with_write_output(mut self, write_output: bool) -> Self {
+        self.write_output = write_output;
+        self
+    }
+
+    /// Makes [`process_template`](Self::process_template) reject output paths
+    /// longer than `max_path_length` characters.
+    ///
+    /// Disabled by default. Paths are measured relative to the output
+    /// directory. Use a limit below 260 to keep repositories checkable out on
+    /// Windows without long path support, leaving room for the clone location.
+    /// Every output path is checked before any file is written. Templates set
+    /// the limit through [`TemplatingConfig::max_path_length`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use template_engine::TemplateProcessor;
+    ///
+    /// let processor = TemplateProcessor::new()?.with_max_path_length(200);
+    /// # Ok::<(), template_engine::Error>(())
+    /// ```
+    pub fn with_max_path_length(mut self, max_path_length: usize) -> Self {
+        self.max_path_length = Some(max_path_length);
+        self
     }
 
     /// Convert HashMap variables to JSON format for Handlebars
@@ -703,6 +1473,19 @@ impl TemplateProcessor {
             }
         }
 
+        // Run each variable's transform pipeline on its resolved value
+        for (var_name, config) in variable_configs {
+            if let Some(value) = all_variables.get_mut(var_name) {
+                *value =
+                    apply_variable_transforms(value, &config.transforms).map_err(|reason| {
+                        Error::VariableValidation {
+                            variable: var_name.clone(),
+                            reason,
+                        }
+                    })?;
+            }
+        }
+
         // Built-in variables override user variables and defaults
         for (key, value) in built_in_variables {
             all_variables.insert(key.clone(), value.clone());
@@ -728,7 +1511,9 @@ impl TemplateProcessor {
     ///
     /// * `files` - Collection of template files as (path, content) tuples
     /// * `request` - Processing request containing variables and configuration
-    /// * `_output_dir` - Target output directory (currently unused but reserved for future use)
+    /// * `output_dir` - Directory the processed paths are resolved against. Every
+    ///   output path must stay inside it. The files are only written there when
+    ///   the processor was built with [`with_write_output`](Self::with_write_output).
     ///
     /// # Returns
     ///
@@ -738,17 +1523,31 @@ impl TemplateProcessor {
     /// # Processing Steps
     ///
     /// 1. Validates all variables according to their configurations
-    /// 2. Combines user variables with built-in variables
+    /// 2. Combines user variables with built-in variables (restricted to declared
+    ///    variables when `strict_variables` is set)
     /// 3. Filters files based on include/exclude patterns
     /// 4. Applies variable substitution to text files
-    /// 5. Copies binary files unchanged
+    /// 5. Copies binary files unchanged; their contents are never rendered or
+    ///    scanned for variables, but their paths are still templated, so a
+    ///    template may consist of binary files only
     /// 6. Removes `.template` suffixes from file names
+    /// 7. Resolves each output path against `output_dir` and checks it against
+    ///    the maximum path length, if one is set
+    /// 8. If enabled, writes the files to `output_dir`; this only happens once
+    ///    every file has been processed and every output path accepted, so a
+    ///    failing template writes nothing
+    ///
+    /// The paths in the returned `ProcessedTemplate` stay relative to `output_dir`.
     ///
     /// # Errors
     ///
     /// This method will return an error if:
     /// - Variable validation fails (missing required variables, pattern mismatches, etc.)
     /// - File content cannot be processed due to encoding issues
+    /// - An output path resolves outside `output_dir`, including through a
+    ///   symbolic link when writing
+    /// - A maximum path length is set and an output path exceeds it
+    /// - Writing is enabled and a file cannot be written
     ///
     /// # Examples
     ///
@@ -773,6 +1572,7 @@ impl TemplateProcessor {
     ///     built_in_variables: HashMap::new(),
     ///     variable_configs: HashMap::new(),
     ///     templating_config: None,
+    ///     strict_variables: false,
     /// };
     ///
     /// let result = processor.process_template(&files, &request, Path::new("./output"))?;
@@ -783,36 +1583,20 @@ impl TemplateProcessor {
         &self,
         files: &[(String, Vec<u8>)],
         request: &TemplateProcessingRequest,
-        _output_dir: &Path,
+        output_dir: &Path,
     ) -> Result<ProcessedTemplate, Error> {
         // Validate variables against configs first
         self.validate_variables(request)?;
 
-        // Convert HashMap variables to JSON format for Handlebars
-        let all_variables = self.convert_variables_to_json(
-            &request.variables,
-            &request.built_in_variables,
-            &request.variable_configs,
-        )?;
-        let context = TemplateContext::new(all_variables);
+        let context = self.build_context(request)?;
 
         // FIRST PASS: Scan all template files for variable references
         // This allows us to report ALL missing variables at once before starting processing
         let mut all_required_vars = std::collections::HashSet::new();
 
         for (file_path, content) in files {
-            // Skip files that match exclude patterns
-            if let Some(ref config) = request.templating_config {
-                if self.should_exclude_file(file_path, &config.exclude_patterns) {
-                    continue;
-                }
-
-                // Only process files that match include patterns
-                if !config.include_patterns.is_empty()
-                    && !self.should_include_file(file_path, &config.include_patterns)
-                {
-                    continue;
-                }
+            if !self.is_file_selected(file_path, request) {
+                continue;
             }
 
             // Check file path template for variables
@@ -844,12 +1628,18 @@ impl TemplateProcessor {
         // If any variables are missing, fail early with comprehensive error message
         if !missing_vars.is_empty() {
             missing_vars.sort();
+            let reason = if context.is_strict() {
+                "were not declared or not provided"
+            } else {
+                "were not provided"
+            };
             return Err(Error::MissingVariables {
                 variables: missing_vars.clone(),
                 message: format!(
-                    "Template requires {} variable(s) that were not provided: {}. \
+                    "Template requires {} variable(s) that {}: {}. \
                      Please provide values for all required variables before processing.",
                     missing_vars.len(),
+                    reason,
                     missing_vars.join(", ")
                 ),
             });
@@ -857,20 +1647,12 @@ impl TemplateProcessor {
 
         // SECOND PASS: Now process all files (we know all variables are present)
         let mut processed_files = Vec::new();
+        let mut target_paths = Vec::new();
+        let mut output_sources: HashMap<String, String> = HashMap::new();
 
         for (file_path, content) in files {
-            // Skip files that match exclude patterns
-            if let Some(ref config) = request.templating_config {
-                if self.should_exclude_file(file_path, &config.exclude_patterns) {
-                    continue;
-                }
-
-                // Only process files that match include patterns
-                if !config.include_patterns.is_empty()
-                    && !self.should_include_file(file_path, &config.include_patterns)
-                {
-                    continue;
-                }
+            if !self.is_file_selected(file_path, request) {
+                continue;
             }
 
             // Process file path template (with security validation)
@@ -879,7 +1661,7 @@ impl TemplateProcessor {
                 .template_file_path(file_path, &context)
                 .map_err(|e| Error::VariableValidation {
                     variable: "file_path".to_string(),
-                    reason: format!("File path templating failed: {}", e),
+                    reason: format!("File path templating failed for '{}': {}", file_path, e),
                 })?;
 
             let processed_content = if self.is_text_file(content) {
@@ -888,31 +1670,223 @@ impl TemplateProcessor {
                 let processed_str = self
                     .handlebars_engine
                     .render_template(&content_str, &context)
-                    .map_err(|e| Error::VariableValidation {
-                        variable: "template_content".to_string(),
-                        reason: format!("Template rendering failed: {}", e),
-                    })?;
+                    .map_err(|e| file_render_error(file_path, e))?;
                 processed_str.into_bytes()
             } else {
                 // Binary files are copied as-is
                 content.clone()
             };
 
-            // Handle .template suffix removal
-            let final_path = if processed_path.ends_with(".template") {
-                processed_path.trim_end_matches(".template").to_string()
-            } else {
-                processed_path
-            };
+            let final_path = final_output_path(&processed_path);
 
+            // Two files rendering to the same path would silently overwrite each other
+            if let Some(existing_source) = output_sources.get(&final_path) {
+                return Err(Error::PathCollision {
+                    path: final_path,
+                    sources: vec![existing_source.clone(), file_path.clone()],
+                });
+            }
+            output_sources.insert(final_path.clone(), file_path.clone());
+
+            if let Some(max_path_length) = self.max_path_length {
+                let length = final_path.chars().count();
+                if length > max_path_length {
+                    return Err(Error::PathTooLong {
+                        path: final_path,
+                        length,
+                    });
+                }
+            }
+
+            target_paths.push(resolve_output_path(output_dir, &final_path)?);
             processed_files.push((final_path, processed_content));
         }
 
+        // Only write once every output path has been checked, so that a
+        // rejected template leaves nothing behind in the output directory
+        if self.write_output {
+            for ((final_path, content), target_path) in processed_files.iter().zip(&target_paths) {
+                write_output_file(output_dir, final_path, target_path, content)?;
+            }
+        }
+
         Ok(ProcessedTemplate {
             files: processed_files,
         })
     }
 
+    /// Renders every selected template file without producing output.
+    ///
+    /// This is a dry run of [`process_template`](Self::process_template) that
+    /// lets callers reject a broken template before performing any external
+    /// side effects (such as creating the repository on GitHub). Unlike
+    /// `process_template`, it does not stop at the first failing file: every
+    /// file path and text file is rendered against the resolved context and
+    /// all failures are collected.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - Collection of template files as (path, content) tuples
+    /// * `request` - Processing request containing variables and configuration
+    ///
+    /// # Returns
+    ///
+    /// The list of files that failed to render, in input order. An empty list
+    /// means every selected file renders successfully.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request itself is invalid (variable validation
+    /// fails or the template context cannot be created); these are not
+    /// per-file failures.
+    pub fn validate_render(
+        &self,
+        files: &[(String, Vec<u8>)],
+        request: &TemplateProcessingRequest,
+    ) -> Result<Vec<RenderFailure>, Error> {
+        Ok(self.dry_render(files, request)?.failures)
+    }
+
+    /// Renders every selected template file and reports the output paths.
+    ///
+    /// Behaves like [`validate_render`](Self::validate_render), but also
+    /// returns the paths [`process_template`](Self::process_template) would
+    /// write, so callers can show what a template produces without writing
+    /// anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - Collection of template files as (path, content) tuples
+    /// * `request` - Processing request containing variables and configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request itself is invalid (variable validation
+    /// fails or the template context cannot be created); these are not
+    /// per-file failures.
+    pub fn dry_render(
+        &self,
+        files: &[(String, Vec<u8>)],
+        request: &TemplateProcessingRequest,
+    ) -> Result<DryRender, Error> {
+        self.validate_variables(request)?;
+
+        let context = self.build_context(request)?;
+
+        let mut output_paths = Vec::new();
+        let mut failures = Vec::new();
+        for (file_path, content) in files {
+            if !self.is_file_selected(file_path, request) {
+                continue;
+            }
+
+            match self
+                .handlebars_engine
+                .template_file_path(file_path, &context)
+            {
+                Ok(processed_path) => output_paths.push(final_output_path(&processed_path)),
+                Err(e) => {
+                    failures.push(RenderFailure {
+                        path: file_path.clone(),
+                        reason: format!("File path templating failed: {}", e),
+                    });
+                    continue;
+                }
+            }
+
+            if self.is_text_file(content) {
+                let content_str = String::from_utf8_lossy(content);
+                if let Err(e) = self
+                    .handlebars_engine
+                    .render_template(&content_str, &context)
+                {
+                    failures.push(RenderFailure {
+                        path: file_path.clone(),
+                        reason: format!("Template rendering failed: {}", e),
+                    });
+                }
+            }
+        }
+
+        Ok(DryRender {
+            output_paths,
+            failures,
+        })
+    }
+
+    /// Renders a single template string against a processing request.
+    ///
+    /// Uses the same variables and context as
+    /// [`process_template`](Self::process_template), so values such as a
+    /// repository description can reference template variables like
+    /// `{{project_name}}`. File include/exclude patterns do not apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template string to render
+    /// * `request` - Processing request containing variables and configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::VariableValidation` if the context cannot be created or
+    /// the string fails to render.
+    pub fn render_string(
+        &self,
+        template: &str,
+        request: &TemplateProcessingRequest,
+    ) -> Result<String, Error> {
+        let context = self.build_context(request)?;
+
+        self.handlebars_engine
+            .render_template(template, &context)
+            .map_err(|e| render_error("template_string", e))
+    }
+
+    /// Builds the rendering context for a request, restricted to declared
+    /// variables when `strict_variables` is set.
+    fn build_context(&self, request: &TemplateProcessingRequest) -> Result<TemplateContext, Error> {
+        // Convert HashMap variables to JSON format for Handlebars
+        let all_variables = self.convert_variables_to_json(
+            &request.variables,
+            &request.built_in_variables,
+            &request.variable_configs,
+        )?;
+
+        if request.strict_variables {
+            let declared = request
+                .variable_configs
+                .keys()
+                .chain(request.built_in_variables.keys())
+                .cloned();
+            TemplateContext::strict(all_variables, declared).map_err(|e| {
+                Error::VariableValidation {
+                    variable: "context".to_string(),
+                    reason: format!("Strict template context creation failed: {}", e),
+                }
+            })
+        } else {
+            Ok(TemplateContext::new(all_variables))
+        }
+    }
+
+    /// Returns whether a file passes the request's include/exclude patterns.
+    fn is_file_selected(&self, file_path: &str, request: &TemplateProcessingRequest) -> bool {
+        let Some(ref config) = request.templating_config else {
+            return true;
+        };
+
+        let options = config.match_options();
+
+        // Skip files that match exclude patterns
+        if self.should_exclude_file(file_path, &config.exclude_patterns, options) {
+            return false;
+        }
+
+        // Only process files that match include patterns
+        config.include_patterns.is_empty()
+            || self.should_include_file(file_path, &config.include_patterns, options)
+    }
+
     /// Generate built-in variables for template processing.
     ///
     /// Creates a set of predefined variables that are automatically available in all templates.
@@ -921,7 +1895,8 @@ impl TemplateProcessor {
     ///
     /// # Built-in Variables
     ///
-    /// * `timestamp` - Current UTC timestamp in RFC3339 format (e.g., "2023-01-01T12:00:00Z")
+    /// * `timestamp` - Current UTC timestamp in RFC3339 format (e.g., "2023-01-01T12:00:00Z"),
+    ///   taken from the processor's [`Clock`]
     /// * `timestamp_unix` - Current UTC timestamp as Unix epoch seconds
     /// * `user_login` - GitHub login/username of the user creating the repository
     /// * `user_name` - Display name of the user
@@ -953,7 +1928,7 @@ impl TemplateProcessor {
     ) -> HashMap<String, String> {
         let mut variables = HashMap::new();
 
-        let now = chrono::Utc::now();
+        let now = self.clock.now();
 
         // Generate timestamp variables for template use
         variables.insert("timestamp".to_string(), now.to_rfc3339());
@@ -990,10 +1965,49 @@ impl TemplateProcessor {
     /// - Values match specified regex patterns
     /// - String lengths are within configured bounds
     /// - Values are from allowed option lists
-    fn validate_variables(&self, request: &TemplateProcessingRequest) -> Result<(), Error> {
+    /// - Declared transforms exist
+    ///
+    /// Constraints are checked against the value after its transforms have
+    /// been applied, which is the value templates see.
+    ///
+    /// [`process_template`](Self::process_template) runs this check itself;
+    /// calling it directly lets callers reject a request before fetching any
+    /// template files.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first variable that fails its constraints.
+    pub fn validate_variables(&self, request: &TemplateProcessingRequest) -> Result<(), Error> {
         for (var_name, config) in &request.variable_configs {
+            // An unknown transform is a configuration error, even without a value
+            if let Some(reason) = config
+                .transforms
+                .iter()
+                .find_map(|name| name.parse::<VariableTransform>().err())
+            {
+                return Err(Error::VariableValidation {
+                    variable: var_name.clone(),
+                    reason,
+                });
+            }
+
+            // A variable may be required unconditionally, or only when another
+            // variable has a specific value
+            let required_by_condition = match &config.required_if {
+                Some((other_name, expected_value)) => {
+                    let other_value = request.variables.get(other_name).or_else(|| {
+                        request
+                            .variable_configs
+                            .get(other_name)
+                            .and_then(|other| other.default.as_ref())
+                    });
+                    other_value == Some(expected_value)
+                }
+                None => false,
+            };
+
             // Check if required variable is provided
-            if config.required.unwrap_or(false)
+            if (config.required.unwrap_or(false) || required_by_condition)
                 && !request.variables.contains_key(var_name)
                 && config.default.is_none()
             {
@@ -1008,6 +2022,13 @@ impl TemplateProcessor {
                     None => continue, // Optional variable not provided
                 },
             };
+            let value =
+                apply_variable_transforms(&value, &config.transforms).map_err(|reason| {
+                    Error::VariableValidation {
+                        variable: var_name.clone(),
+                        reason,
+                    }
+                })?;
 
             // Validate pattern if specified
             if let Some(ref pattern) = config.pattern {
@@ -1045,10 +2066,11 @@ impl TemplateProcessor {
 
             // Validate against allowed options
             if let Some(ref options) = config.options {
-                if !options.contains(&value) {
+                if !options.iter().any(|option| option.value() == value) {
+                    let allowed: Vec<&str> = options.iter().map(VariableOption::value).collect();
                     return Err(Error::VariableValidation {
                         variable: var_name.clone(),
-                        reason: format!("Invalid option, allowed values: {options:?}"),
+                        reason: format!("Invalid option, allowed values: {allowed:?}"),
                     });
                 }
             }
@@ -1061,21 +2083,30 @@ impl TemplateProcessor {
     ///
     /// This method checks if the file path matches any of the provided exclude patterns.
     /// If any pattern matches, the file should be excluded from template processing.
-    fn should_exclude_file(&self, file_path: &str, exclude_patterns: &[String]) -> bool {
-        exclude_patterns.iter().any(|pattern| {
-            // Simple glob matching for MVP - could be enhanced with a proper glob library
-            self.simple_glob_match(pattern, file_path)
-        })
+    fn should_exclude_file(
+        &self,
+        file_path: &str,
+        exclude_patterns: &[String],
+        options: MatchOptions,
+    ) -> bool {
+        exclude_patterns
+            .iter()
+            .any(|pattern| self.simple_glob_match(pattern, file_path, options))
     }
 
     /// Determines if a file should be included in processing based on glob patterns.
     ///
     /// This method checks if the file path matches any of the provided include patterns.
     /// If any pattern matches, the file should be included in template processing.
-    fn should_include_file(&self, file_path: &str, include_patterns: &[String]) -> bool {
+    fn should_include_file(
+        &self,
+        file_path: &str,
+        include_patterns: &[String],
+        options: MatchOptions,
+    ) -> bool {
         include_patterns
             .iter()
-            .any(|pattern| self.simple_glob_match(pattern, file_path))
+            .any(|pattern| self.simple_glob_match(pattern, file_path, options))
     }
 
     /// Performs basic glob pattern matching for file path filtering.
@@ -1083,35 +2114,37 @@ impl TemplateProcessor {
     /// This implementation provides comprehensive glob pattern matching:
     /// - `**` and `**/*` match everything
     /// - `**` in the middle handles recursive directory matching
-    /// - `*` handles single-level wildcards
+    /// - `*` handles single-level wildcards, or crosses directories when
+    ///   `options.require_literal_separator` is `false`
     /// - `?` matches a single character
     /// - `[...]` matches character classes
+    /// - `{a,b}` matches either alternative, see [`expand_braces`]
     /// - Exact string matching for literal patterns
     ///
     /// # Arguments
     ///
     /// * `pattern` - The glob pattern to match against
     /// * `path` - The file path to test
+    /// * `options` - Case sensitivity and separator handling, see
+    ///   [`TemplatingConfig::match_options`]
     ///
     /// # Returns
     ///
     /// `true` if the path matches the pattern, `false` otherwise
-    fn simple_glob_match(&self, pattern: &str, path: &str) -> bool {
-        match Pattern::new(pattern) {
-            Ok(glob_pattern) => {
-                // Use custom match options to handle directory separators correctly
-                let options = MatchOptions {
-                    case_sensitive: true,
-                    require_literal_separator: true,
-                    require_literal_leading_dot: false,
-                };
-                glob_pattern.matches_with(path, options)
-            }
-            Err(_) => {
-                // If the pattern is invalid, fall back to exact string matching
-                pattern == path
-            }
-        }
+    fn simple_glob_match(&self, pattern: &str, path: &str, options: MatchOptions) -> bool {
+        expand_braces(pattern)
+            .iter()
+            .any(|pattern| match Pattern::new(pattern) {
+                Ok(glob_pattern) => glob_pattern.matches_with(path, options),
+                Err(_) => {
+                    // If the pattern is invalid, fall back to exact string matching
+                    if options.case_sensitive {
+                        pattern == path
+                    } else {
+                        pattern.eq_ignore_ascii_case(path)
+                    }
+                }
+            })
     }
 
     /// Determines if file content should be treated as text for variable substitution.
@@ -1123,11 +2156,7 @@ impl TemplateProcessor {
     /// Text files will have variable substitution applied, while binary files
     /// are copied unchanged to preserve their integrity.
     fn is_text_file(&self, content: &[u8]) -> bool {
-        // Simple heuristic: if we can decode as UTF-8 and it doesn't contain null bytes
-        match std::str::from_utf8(content) {
-            Ok(text) => !text.contains('\0'),
-            Err(_) => false,
-        }
+        is_text_content(content)
     }
 }
 
@@ -1145,13 +2174,22 @@ impl TemplateProcessor {
 /// * `pattern` - Optional regex pattern that the variable value must match
 /// * `min_length` - Minimum length for string variables
 /// * `max_length` - Maximum length for string variables
-/// * `options` - List of allowed values (for enumerated variables)
+/// * `options` - List of allowed values (for enumerated variables), each
+///   optionally paired with a display label (see [`VariableOption`])
 /// * `default` - Default value to use if the variable is not provided
+/// * `required_if` - Makes the variable required only when another variable
+///   (first element) has the given value (second element). Unconditional
+///   `required` takes precedence.
+/// * `secret` - Marks the value as sensitive. It is still used for rendering
+///   but replaced by [`REDACTED_VARIABLE_VALUE`] in `Debug` output (see
+///   [`redact_secret_variables`]).
+/// * `transforms` - Names of built-in [`VariableTransform`]s applied, in order,
+///   to the value (including a default) before it is validated and rendered
 ///
 /// ## Examples
 ///
 /// ```rust,ignore
-/// use template_engine::VariableConfig;
+/// use template_engine::{VariableConfig, VariableOption};
 ///
 /// // Simple string variable with description
 /// let name_config = VariableConfig {
@@ -1163,6 +2201,9 @@ impl TemplateProcessor {
 ///     max_length: Some(50),
 ///     options: None,
 ///     default: None,
+///     required_if: None,
+///     secret: None,
+///     transforms: vec![],
 /// };
 ///
 /// // Enumerated variable with predefined options
@@ -1173,8 +2214,15 @@ impl TemplateProcessor {
 ///     pattern: None,
 ///     min_length: None,
 ///     max_length: None,
-///     options: Some(vec!["MIT".to_string(), "Apache-2.0".to_string(), "GPL-3.0".to_string()]),
+///     options: Some(vec![
+///         VariableOption::labeled("MIT", "MIT License"),
+///         VariableOption::labeled("Apache-2.0", "Apache License 2.0"),
+///         "GPL-3.0".into(),
+///     ]),
 ///     default: Some("MIT".to_string()),
+///     required_if: None,
+///     secret: None,
+///     transforms: vec![],
 /// };
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1185,6 +2233,102 @@ pub struct VariableConfig {
     pub pattern: Option<String>,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
-    pub options: Option<Vec<String>>,
+    pub options: Option<Vec<VariableOption>>,
     pub default: Option<String>,
+    pub required_if: Option<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<String>,
+}
+
+impl VariableConfig {
+    /// Returns `true` if the variable's value must not be shown.
+    pub fn is_secret(&self) -> bool {
+        self.secret.unwrap_or(false)
+    }
+}
+
+/// Placeholder shown in place of the value of a secret variable.
+pub const REDACTED_VARIABLE_VALUE: &str = "[REDACTED]";
+
+/// Returns a copy of `variables` with the values of secret variables replaced
+/// by [`REDACTED_VARIABLE_VALUE`].
+///
+/// A variable is secret when its entry in `variable_configs` has
+/// `secret = true`; undeclared variables are kept as they are.
+pub fn redact_secret_variables(
+    variables: &HashMap<String, String>,
+    variable_configs: &HashMap<String, VariableConfig>,
+) -> HashMap<String, String> {
+    variables
+        .iter()
+        .map(|(name, value)| {
+            let is_secret = variable_configs
+                .get(name)
+                .is_some_and(VariableConfig::is_secret);
+            let shown = if is_secret {
+                REDACTED_VARIABLE_VALUE.to_string()
+            } else {
+                value.clone()
+            };
+            (name.clone(), shown)
+        })
+        .collect()
+}
+
+/// An allowed value for an enumerated variable.
+///
+/// Options may be written as plain strings or as `{ value, label }` tables.
+/// The label is only for display; validation and substitution always use the
+/// value.
+///
+/// ```toml
+/// options = ["MIT", { value = "Apache-2.0", label = "Apache License 2.0" }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum VariableOption {
+    /// A value that is displayed as-is
+    Value(String),
+
+    /// A value with a human-readable label
+    Labeled { value: String, label: String },
+}
+
+impl VariableOption {
+    /// Creates an option with a display label.
+    pub fn labeled(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::Labeled {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+
+    /// Returns the value that is validated and substituted.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Value(value) | Self::Labeled { value, .. } => value,
+        }
+    }
+
+    /// Returns the text to display, falling back to the value.
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Value(value) => value,
+            Self::Labeled { label, .. } => label,
+        }
+    }
+}
+
+impl From<&str> for VariableOption {
+    fn from(value: &str) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<String> for VariableOption {
+    fn from(value: String) -> Self {
+        Self::Value(value)
+    }
 }
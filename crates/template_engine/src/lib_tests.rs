@@ -37,6 +37,32 @@ fn test_built_in_variables_generation() {
     assert!(variables.contains_key("timestamp_unix"));
 }
 
+#[test]
+fn test_built_in_timestamp_uses_fixed_clock() {
+    use chrono::TimeZone;
+
+    let instant = chrono::Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+    let processor = TemplateProcessor::new()
+        .expect("Failed to create processor")
+        .with_clock(Arc::new(FixedClock::new(instant)));
+
+    let params = BuiltInVariablesParams {
+        repo_name: "test-repo",
+        org_name: "test-org",
+        template_name: "rust-library",
+        template_repo: "templates/rust-library",
+        user_login: "testuser",
+        user_name: "Test User",
+        default_branch: "main",
+    };
+
+    let variables = processor.generate_built_in_variables(&params);
+
+    assert_eq!(variables["timestamp"], "2024-01-02T03:04:05+00:00");
+    assert_eq!(variables["timestamp"], instant.to_rfc3339());
+    assert_eq!(variables["timestamp_unix"], instant.timestamp().to_string());
+}
+
 #[test]
 fn test_is_text_file() {
     let processor = TemplateProcessor::new().expect("Failed to create processor");
@@ -78,6 +104,7 @@ fn test_process_template_basic() {
         built_in_variables: HashMap::new(),
         variable_configs: HashMap::new(),
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor
@@ -93,6 +120,64 @@ fn test_process_template_basic() {
     assert_eq!(main_content, "// Test Project by John Doe\nfn main() {}");
 }
 
+/// Binary-only template fixture: the contents contain Handlebars syntax, some
+/// of it invalid, which must never be rendered.
+fn binary_only_template_files() -> Vec<(String, Vec<u8>)> {
+    let mut logo = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0x00];
+    logo.extend_from_slice(b"{{project_name}}");
+    let mut font = vec![0xff, 0xfe];
+    font.extend_from_slice(b"{{#if broken");
+    vec![
+        ("assets/{{project_name}}-logo.png".to_string(), logo),
+        ("fonts/brand.woff".to_string(), font),
+    ]
+}
+
+#[test]
+fn test_process_template_binary_only_template() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = binary_only_template_files();
+
+    let request = TemplateProcessingRequest {
+        variables: HashMap::from([("project_name".to_string(), "acme".to_string())]),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let result = processor
+        .process_template(&files, &request, Path::new("."))
+        .expect("Binary-only template should process");
+
+    assert_eq!(
+        result.files,
+        vec![
+            ("assets/acme-logo.png".to_string(), files[0].1.clone()),
+            ("fonts/brand.woff".to_string(), files[1].1.clone()),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_render_skips_binary_content() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let request = TemplateProcessingRequest {
+        variables: HashMap::from([("project_name".to_string(), "acme".to_string())]),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let failures = processor
+        .validate_render(&binary_only_template_files(), &request)
+        .unwrap();
+
+    assert!(failures.is_empty(), "Unexpected failures: {failures:?}");
+}
+
 #[test]
 fn test_process_template_with_filtering() {
     let processor = TemplateProcessor::new().expect("Failed to create processor");
@@ -113,6 +198,11 @@ fn test_process_template_with_filtering() {
     let templating_config = TemplatingConfig {
         include_patterns: vec!["**/*.md".to_string(), "**/*.rs".to_string()],
         exclude_patterns: vec!["target/**".to_string()],
+        post_generation: vec![],
+        case_insensitive: false,
+        glob_recursive: false,
+        strict_variables: false,
+        max_path_length: None,
     };
 
     let request = TemplateProcessingRequest {
@@ -120,6 +210,7 @@ fn test_process_template_with_filtering() {
         built_in_variables: HashMap::new(),
         variable_configs: HashMap::new(),
         templating_config: Some(templating_config),
+        strict_variables: false,
     };
 
     let result = processor
@@ -160,6 +251,7 @@ fn test_process_template_removes_template_suffix() {
         built_in_variables: HashMap::new(),
         variable_configs: HashMap::new(),
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor
@@ -177,26 +269,138 @@ fn test_process_template_removes_template_suffix() {
 #[test]
 fn test_simple_glob_match() {
     let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let options = glob_options_config(false, false).match_options();
 
     // Test exact matches
-    assert!(processor.simple_glob_match("README.md", "README.md"));
-    assert!(!processor.simple_glob_match("README.md", "readme.md"));
+    assert!(processor.simple_glob_match("README.md", "README.md", options));
+    assert!(!processor.simple_glob_match("README.md", "readme.md", options));
 
     // Test ** patterns
-    assert!(processor.simple_glob_match("**", "any/path/file.txt"));
-    assert!(processor.simple_glob_match("**/*", "any/path/file.txt"));
+    assert!(processor.simple_glob_match("**", "any/path/file.txt", options));
+    assert!(processor.simple_glob_match("**/*", "any/path/file.txt", options));
 
     // Test recursive patterns
-    assert!(processor.simple_glob_match("src/**", "src/main.rs"));
-    assert!(processor.simple_glob_match("src/**", "src/lib/mod.rs"));
-    assert!(processor.simple_glob_match("**/*.rs", "src/main.rs"));
-    assert!(processor.simple_glob_match("**/*.rs", "tests/integration.rs"));
-    assert!(processor.simple_glob_match("**/*.rs", "main.rs"));
+    assert!(processor.simple_glob_match("src/**", "src/main.rs", options));
+    assert!(processor.simple_glob_match("src/**", "src/lib/mod.rs", options));
+    assert!(processor.simple_glob_match("**/*.rs", "src/main.rs", options));
+    assert!(processor.simple_glob_match("**/*.rs", "tests/integration.rs", options));
+    assert!(processor.simple_glob_match("**/*.rs", "main.rs", options));
 
     // Test single wildcard
-    assert!(processor.simple_glob_match("*.rs", "main.rs"));
-    assert!(processor.simple_glob_match("test_*", "test_example"));
-    assert!(!processor.simple_glob_match("*.rs", "src/main.rs"));
+    assert!(processor.simple_glob_match("*.rs", "main.rs", options));
+    assert!(processor.simple_glob_match("test_*", "test_example", options));
+    assert!(!processor.simple_glob_match("*.rs", "src/main.rs", options));
+}
+
+#[test]
+fn test_simple_glob_match_brace_alternatives() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let options = glob_options_config(false, false).match_options();
+
+    assert!(processor.simple_glob_match("**/*.{rs,toml}", "src/main.rs", options));
+    assert!(processor.simple_glob_match("**/*.{rs,toml}", "Cargo.toml", options));
+    assert!(!processor.simple_glob_match("**/*.{rs,toml}", "README.md", options));
+    assert!(processor.simple_glob_match("{src,tests}/**/*.rs", "tests/it/mod.rs", options));
+}
+
+#[test]
+fn test_simple_glob_match_escaped_brace_is_literal() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let options = glob_options_config(false, false).match_options();
+
+    assert!(processor.simple_glob_match(r"file\{1,2\}.txt", "file{1,2}.txt", options));
+    assert!(!processor.simple_glob_match(r"file\{1,2\}.txt", "file1.txt", options));
+}
+
+#[test]
+fn test_expand_braces() {
+    assert_eq!(expand_braces("*.rs"), vec!["*.rs"]);
+    assert_eq!(expand_braces("*.{rs,toml}"), vec!["*.rs", "*.toml"]);
+    assert_eq!(
+        expand_braces("{a,b}/{c,d}"),
+        vec!["a/c", "a/d", "b/c", "b/d"]
+    );
+    assert_eq!(expand_braces("x{a,{b,c}}"), vec!["xa", "xb", "xc"]);
+    assert_eq!(expand_braces("*.{rs,}"), vec!["*.rs", "*."]);
+    // Unclosed groups and character classes are left as written.
+    assert_eq!(expand_braces("{a,b"), vec!["{a,b"]);
+    assert_eq!(expand_braces("[{]*"), vec!["[{]*"]);
+}
+
+fn glob_options_config(case_insensitive: bool, glob_recursive: bool) -> TemplatingConfig {
+    TemplatingConfig {
+        include_patterns: vec![],
+        exclude_patterns: vec![],
+        post_generation: vec![],
+        case_insensitive,
+        glob_recursive,
+        strict_variables: false,
+        max_path_length: None,
+    }
+}
+
+#[test]
+fn test_simple_glob_match_case_insensitive_when_configured() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let options = glob_options_config(true, false).match_options();
+
+    assert!(processor.simple_glob_match("README.md", "readme.MD", options));
+    assert!(processor.simple_glob_match("docs/*.MD", "Docs/Guide.md", options));
+    // Case-insensitivity does not make `*` cross directories.
+    assert!(!processor.simple_glob_match("*.md", "docs/guide.md", options));
+}
+
+#[test]
+fn test_simple_glob_match_star_crosses_directories_when_configured() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let options = glob_options_config(false, true).match_options();
+
+    assert!(processor.simple_glob_match("*.rs", "src/main.rs", options));
+    assert!(processor.simple_glob_match("*.rs", "src/bin/tool.rs", options));
+    assert!(processor.simple_glob_match("src/*", "src/lib/mod.rs", options));
+    // Matching stays case-sensitive unless configured otherwise.
+    assert!(!processor.simple_glob_match("*.rs", "src/MAIN.RS", options));
+}
+
+#[test]
+fn test_templating_config_match_options_default_to_strict_matching() {
+    let config: TemplatingConfig =
+        serde_json::from_str(r#"{ "include_patterns": ["*.rs"] }"#).unwrap();
+
+    assert!(!config.case_insensitive);
+    assert!(!config.glob_recursive);
+    let options = config.match_options();
+    assert!(options.case_sensitive);
+    assert!(options.require_literal_separator);
+}
+
+#[test]
+fn test_process_template_applies_configured_match_options() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![
+        ("src/main.rs".to_string(), b"fn main() {}".to_vec()),
+        ("Docs/Guide.MD".to_string(), b"# Guide".to_vec()),
+        ("build.sh".to_string(), b"#!/bin/sh".to_vec()),
+    ];
+    let mut templating_config = glob_options_config(true, true);
+    templating_config.include_patterns = vec!["*.rs".to_string(), "docs/*.md".to_string()];
+
+    let request = TemplateProcessingRequest {
+        variables: HashMap::new(),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: Some(templating_config),
+        strict_variables: false,
+    };
+
+    let result = processor
+        .process_template(&files, &request, Path::new("."))
+        .unwrap();
+
+    let paths: Vec<&str> = result.files.iter().map(|(path, _)| path.as_str()).collect();
+    assert!(paths.contains(&"src/main.rs"));
+    assert!(paths.contains(&"Docs/Guide.MD"));
+    assert!(!paths.contains(&"build.sh"));
 }
 
 #[test]
@@ -215,6 +419,9 @@ fn test_validate_variables_required() {
             max_length: None,
             options: None,
             default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -224,6 +431,7 @@ fn test_validate_variables_required() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -250,6 +458,9 @@ fn test_validate_variables_pattern() {
             max_length: None,
             options: None,
             default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -261,6 +472,7 @@ fn test_validate_variables_pattern() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -291,6 +503,9 @@ fn test_validate_variables_default_value_validated_against_pattern() {
             options: None,
             // Default value deliberately violates the pattern
             default: Some("Invalid123".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -300,6 +515,7 @@ fn test_validate_variables_default_value_validated_against_pattern() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -329,6 +545,9 @@ fn test_validate_variables_length() {
             max_length: Some(10),
             options: None,
             default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -341,6 +560,7 @@ fn test_validate_variables_length() {
         built_in_variables: HashMap::new(),
         variable_configs: variable_configs.clone(),
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -357,6 +577,7 @@ fn test_validate_variables_length() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -382,11 +603,14 @@ fn test_validate_variables_options() {
             min_length: None,
             max_length: None,
             options: Some(vec![
-                "MIT".to_string(),
-                "Apache-2.0".to_string(),
-                "GPL-3.0".to_string(),
+                "MIT".into(),
+                VariableOption::labeled("Apache-2.0", "Apache License 2.0"),
+                "GPL-3.0".into(),
             ]),
             default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -398,6 +622,7 @@ fn test_validate_variables_options() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.validate_variables(&request);
@@ -408,6 +633,64 @@ fn test_validate_variables_options() {
     ));
 }
 
+#[test]
+fn test_validate_variables_labeled_options_check_value() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let mut variable_configs = HashMap::new();
+    variable_configs.insert(
+        "license".to_string(),
+        VariableConfig {
+            description: "License type".to_string(),
+            example: None,
+            required: Some(true),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: Some(vec![VariableOption::labeled(
+                "Apache-2.0",
+                "Apache License 2.0",
+            )]),
+            default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
+        },
+    );
+    let request_with = |value: &str| TemplateProcessingRequest {
+        variables: HashMap::from([("license".to_string(), value.to_string())]),
+        built_in_variables: HashMap::new(),
+        variable_configs: variable_configs.clone(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    assert!(processor
+        .validate_variables(&request_with("Apache-2.0"))
+        .is_ok());
+    assert!(processor
+        .validate_variables(&request_with("Apache License 2.0"))
+        .is_err());
+}
+
+#[test]
+fn test_variable_option_deserializes_plain_and_labeled_forms() {
+    let options: Vec<VariableOption> =
+        serde_json::from_str(r#"["MIT", {"value": "Apache-2.0", "label": "Apache License 2.0"}]"#)
+            .expect("Options should deserialize");
+
+    assert_eq!(
+        options,
+        vec![
+            VariableOption::Value("MIT".to_string()),
+            VariableOption::labeled("Apache-2.0", "Apache License 2.0"),
+        ]
+    );
+    assert_eq!(options[0].label(), "MIT");
+    assert_eq!(options[1].value(), "Apache-2.0");
+    assert_eq!(options[1].label(), "Apache License 2.0");
+}
+
 #[test]
 fn test_process_template_with_default_values() {
     let processor = TemplateProcessor::new().expect("Failed to create processor");
@@ -439,6 +722,9 @@ fn test_process_template_with_default_values() {
             max_length: None,
             options: None,
             default: Some("Default Project".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
     variable_configs.insert(
@@ -452,6 +738,9 @@ fn test_process_template_with_default_values() {
             max_length: None,
             options: None,
             default: Some("Default Author".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
     variable_configs.insert(
@@ -465,6 +754,9 @@ fn test_process_template_with_default_values() {
             max_length: None,
             options: None,
             default: Some("1.0.0".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
     variable_configs.insert(
@@ -478,6 +770,9 @@ fn test_process_template_with_default_values() {
             max_length: None,
             options: None,
             default: Some("development".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -486,6 +781,7 @@ fn test_process_template_with_default_values() {
         built_in_variables: HashMap::new(),
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor
@@ -543,6 +839,9 @@ fn test_integration_test_variable_substitution_scenario() {
             max_length: None,
             options: None,
             default: Some("test-project".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
     variable_configs.insert(
@@ -556,6 +855,9 @@ fn test_integration_test_variable_substitution_scenario() {
             max_length: None,
             options: None,
             default: Some("Integration test project for RepoRoller".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
     variable_configs.insert(
@@ -569,6 +871,9 @@ fn test_integration_test_variable_substitution_scenario() {
             max_length: None,
             options: None,
             default: Some("0.1.0".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
         },
     );
 
@@ -577,6 +882,7 @@ fn test_integration_test_variable_substitution_scenario() {
         built_in_variables,
         variable_configs,
         templating_config: None,
+        strict_variables: false,
     };
 
     // This was failing before our fix, should now succeed
@@ -598,3 +904,956 @@ fn test_integration_test_variable_substitution_scenario() {
     assert!(cargo_content.contains("name = \"test-project\""));
     assert!(cargo_content.contains("version = \"0.1.0\""));
 }
+
+#[test]
+fn test_split_template_source_without_subpath() {
+    assert_eq!(
+        split_template_source("https://github.com/owner/repo"),
+        ("https://github.com/owner/repo", None)
+    );
+    assert_eq!(split_template_source("owner/repo"), ("owner/repo", None));
+}
+
+#[test]
+fn test_split_template_source_with_subpath() {
+    assert_eq!(
+        split_template_source("https://github.com/owner/repo//templates/rust-lib"),
+        ("https://github.com/owner/repo", Some("templates/rust-lib"))
+    );
+    assert_eq!(
+        split_template_source("owner/repo//templates/go-svc/"),
+        ("owner/repo", Some("templates/go-svc"))
+    );
+    assert_eq!(
+        split_template_source("https://github.com/owner/repo//"),
+        ("https://github.com/owner/repo", None)
+    );
+}
+
+#[test]
+fn test_select_template_files_extracts_subdirectory() {
+    let files = vec![
+        ("README.md".to_string(), b"root".to_vec()),
+        (
+            "templates/rust-lib/Cargo.toml".to_string(),
+            b"[package]".to_vec(),
+        ),
+        (
+            "templates/rust-lib/src/lib.rs".to_string(),
+            b"// lib".to_vec(),
+        ),
+        (
+            "templates/rust-lib/.github/workflows/ci.yml".to_string(),
+            b"on: push".to_vec(),
+        ),
+        (
+            "templates/rust-lib/.reporoller/template.toml".to_string(),
+            b"[template]".to_vec(),
+        ),
+        (
+            "templates/rust-lib/.gitignore".to_string(),
+            b"target/".to_vec(),
+        ),
+        (
+            "templates/rust-library/lib.rs".to_string(),
+            b"other".to_vec(),
+        ),
+        (
+            "templates/go-svc/main.go".to_string(),
+            b"package main".to_vec(),
+        ),
+    ];
+
+    let result = select_template_files(files, Some("templates/rust-lib"), "owner/repo").unwrap();
+
+    let mut paths: Vec<&str> = result.iter().map(|(p, _)| p.as_str()).collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![".github/workflows/ci.yml", "Cargo.toml", "src/lib.rs"]
+    );
+}
+
+#[test]
+fn test_select_template_files_missing_subdirectory_errors() {
+    let files = vec![
+        ("README.md".to_string(), b"root".to_vec()),
+        (
+            "templates/go-svc/main.go".to_string(),
+            b"package main".to_vec(),
+        ),
+    ];
+
+    let result = select_template_files(files, Some("templates/rust-lib"), "owner/repo");
+
+    let err = result.unwrap_err();
+    assert!(
+        err.contains("Template subdirectory 'templates/rust-lib' not found"),
+        "Unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_select_template_files_without_subpath_filters_metadata() {
+    let files = vec![
+        ("README.md".to_string(), b"root".to_vec()),
+        (".gitignore".to_string(), b"target/".to_vec()),
+        (
+            ".reporoller/template.toml".to_string(),
+            b"[template]".to_vec(),
+        ),
+    ];
+
+    let result = select_template_files(files, None, "owner/repo").unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, "README.md");
+}
+
+#[test]
+fn test_select_template_files_keeps_binary_only_template() {
+    let mut files = binary_only_template_files();
+    files.push((
+        ".reporoller/template.toml".to_string(),
+        b"[template]".to_vec(),
+    ));
+
+    let result = select_template_files(files, None, "owner/repo").unwrap();
+
+    let paths: Vec<&str> = result.iter().map(|(p, _)| p.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec!["assets/{{project_name}}-logo.png", "fonts/brand.woff"]
+    );
+}
+
+#[test]
+fn test_template_file_path_prefixes_subpath() {
+    assert_eq!(
+        template_file_path(None, ".reporoller/template.toml"),
+        ".reporoller/template.toml"
+    );
+    assert_eq!(
+        template_file_path(Some("templates/rust-lib"), "/.reporoller\\template.toml"),
+        "templates/rust-lib/.reporoller/template.toml"
+    );
+}
+
+/// Fetcher that relies on the default `fetch_file` implementation.
+struct FixedTemplateFetcher;
+
+#[async_trait]
+impl TemplateFetcher for FixedTemplateFetcher {
+    async fn fetch_template_files(&self, _source: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+        Ok(vec![
+            ("README.md".to_string(), b"# readme".to_vec()),
+            ("src\\lib.rs".to_string(), b"// lib".to_vec()),
+        ])
+    }
+}
+
+#[tokio::test]
+async fn test_default_fetch_file_picks_file_from_template_files() {
+    let fetcher = FixedTemplateFetcher;
+
+    assert_eq!(
+        fetcher
+            .fetch_file("owner/repo", "src/lib.rs")
+            .await
+            .unwrap(),
+        Some(b"// lib".to_vec())
+    );
+    assert_eq!(
+        fetcher
+            .fetch_file("owner/repo", "missing.txt")
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_local_fetch_file_reads_template_configuration() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir(temp_dir.path().join(".reporoller")).unwrap();
+    std::fs::write(
+        temp_dir.path().join(".reporoller/template.toml"),
+        "[template]\nname = \"rust-lib\"\n",
+    )
+    .unwrap();
+    std::fs::write(temp_dir.path().join("README.md"), "# readme").unwrap();
+    let source = temp_dir.path().to_str().unwrap();
+
+    let config = LocalTemplateFetcher
+        .fetch_file(source, ".reporoller/template.toml")
+        .await
+        .unwrap();
+    assert_eq!(config, Some(b"[template]\nname = \"rust-lib\"\n".to_vec()));
+
+    let missing = LocalTemplateFetcher
+        .fetch_file(source, "Cargo.toml")
+        .await
+        .unwrap();
+    assert_eq!(missing, None);
+}
+
+/// Run git in `dir` with a fixed identity, returning its trimmed output.
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .expect("git should run");
+    assert!(output.status.success(), "git {args:?} failed: {output:?}");
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+/// Create a template repository with two commits of `README.md`, returning
+/// the repository directory and the SHAs of both commits.
+fn two_commit_template_repository() -> (tempfile::TempDir, String, String) {
+    let repo = tempfile::TempDir::new().unwrap();
+    git(repo.path(), &["init", "--quiet"]);
+
+    std::fs::write(repo.path().join("README.md"), "first").unwrap();
+    git(repo.path(), &["add", "README.md"]);
+    git(repo.path(), &["commit", "--quiet", "-m", "first"]);
+    let first = git(repo.path(), &["rev-parse", "HEAD"]);
+
+    std::fs::write(repo.path().join("README.md"), "second").unwrap();
+    git(repo.path(), &["commit", "--quiet", "-am", "second"]);
+    let second = git(repo.path(), &["rev-parse", "HEAD"]);
+
+    (repo, first, second)
+}
+
+#[tokio::test]
+async fn test_github_fetcher_reports_fetched_commit_sha() {
+    let (repo, _, head) = two_commit_template_repository();
+
+    let fetched = GitHubTemplateFetcher::new()
+        .fetch_template_at(repo.path().to_str().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(fetched.commit_sha, Some(head));
+    assert_eq!(
+        fetched.files,
+        vec![("README.md".to_string(), b"second".to_vec())]
+    );
+}
+
+#[tokio::test]
+async fn test_github_fetcher_fetches_pinned_commit() {
+    let (repo, first, _) = two_commit_template_repository();
+
+    let fetched = GitHubTemplateFetcher::new()
+        .fetch_template_at(repo.path().to_str().unwrap(), Some(&first))
+        .await
+        .unwrap();
+
+    assert_eq!(fetched.commit_sha, Some(first));
+    assert_eq!(
+        fetched.files,
+        vec![("README.md".to_string(), b"first".to_vec())]
+    );
+}
+
+#[tokio::test]
+async fn test_default_fetch_template_at_rejects_refs() {
+    let fetched = FixedTemplateFetcher
+        .fetch_template_at("owner/repo", None)
+        .await
+        .unwrap();
+    assert_eq!(fetched.files.len(), 2);
+    assert_eq!(fetched.commit_sha, None);
+
+    assert!(FixedTemplateFetcher
+        .fetch_template_at("owner/repo", Some("v1.0.0"))
+        .await
+        .is_err());
+}
+
+fn conditional_variable_configs() -> HashMap<String, VariableConfig> {
+    let mut variable_configs = HashMap::new();
+    variable_configs.insert(
+        "database".to_string(),
+        VariableConfig {
+            description: "Database engine".to_string(),
+            example: None,
+            required: Some(false),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: Some("none".to_string()),
+            required_if: None,
+            secret: None,
+            transforms: vec![],
+        },
+    );
+    variable_configs.insert(
+        "db_connection_string".to_string(),
+        VariableConfig {
+            description: "Database connection string".to_string(),
+            example: None,
+            required: None,
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: None,
+            required_if: Some(("database".to_string(), "postgres".to_string())),
+            secret: None,
+            transforms: vec![],
+        },
+    );
+    variable_configs
+}
+
+#[test]
+fn test_validate_variables_required_if_condition_met() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let mut variables = HashMap::new();
+    variables.insert("database".to_string(), "postgres".to_string());
+
+    let request = TemplateProcessingRequest {
+        variables: variables.clone(),
+        built_in_variables: HashMap::new(),
+        variable_configs: conditional_variable_configs(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let result = processor.validate_variables(&request);
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::RequiredVariableMissing(ref name) if name == "db_connection_string"
+    ));
+
+    // Providing the conditionally required variable satisfies validation
+    variables.insert(
+        "db_connection_string".to_string(),
+        "postgres://localhost/db".to_string(),
+    );
+    let request = TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs: conditional_variable_configs(),
+        templating_config: None,
+        strict_variables: false,
+    };
+    assert!(processor.validate_variables(&request).is_ok());
+}
+
+#[test]
+fn test_validate_variables_required_if_condition_not_met() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let mut variables = HashMap::new();
+    variables.insert("database".to_string(), "none".to_string());
+
+    let request = TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs: conditional_variable_configs(),
+        templating_config: None,
+        strict_variables: false,
+    };
+    assert!(processor.validate_variables(&request).is_ok());
+
+    // The referenced variable falls back to its default ("none")
+    let request = TemplateProcessingRequest {
+        variables: HashMap::new(),
+        built_in_variables: HashMap::new(),
+        variable_configs: conditional_variable_configs(),
+        templating_config: None,
+        strict_variables: false,
+    };
+    assert!(processor.validate_variables(&request).is_ok());
+}
+
+#[test]
+fn test_normalize_output_path() {
+    assert_eq!(normalize_output_path("./src//lib.rs"), "src/lib.rs");
+    assert_eq!(
+        normalize_output_path("src/./nested/mod.rs"),
+        "src/nested/mod.rs"
+    );
+    assert_eq!(normalize_output_path("src\\main.rs"), "src/main.rs");
+    assert_eq!(normalize_output_path("README.md"), "README.md");
+}
+
+fn transformed_variable(transforms: &[&str], pattern: Option<&str>) -> VariableConfig {
+    VariableConfig {
+        description: "Service name".to_string(),
+        example: None,
+        required: Some(true),
+        pattern: pattern.map(str::to_string),
+        min_length: None,
+        max_length: None,
+        options: None,
+        default: None,
+        required_if: None,
+        secret: None,
+        transforms: transforms.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_process_template_applies_variable_transform_pipeline() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![(
+        "{{service_name}}/README.md".to_string(),
+        b"# {{service_name}}".to_vec(),
+    )];
+    let request = TemplateProcessingRequest {
+        variables: HashMap::from([(
+            "service_name".to_string(),
+            "  Payments Service ".to_string(),
+        )]),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::from([(
+            "service_name".to_string(),
+            transformed_variable(&["trim", "lower_case", "kebab_case"], Some("^[a-z-]+$")),
+        )]),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let result = processor
+        .process_template(&files, &request, Path::new("."))
+        .expect("Transformed value should satisfy the pattern");
+
+    assert_eq!(result.files[0].0, "payments-service/README.md");
+    assert_eq!(result.files[0].1, b"# payments-service".to_vec());
+}
+
+#[test]
+fn test_validate_variables_rejects_unknown_transform() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let request = TemplateProcessingRequest {
+        variables: HashMap::new(),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::from([(
+            "service_name".to_string(),
+            VariableConfig {
+                required: Some(false),
+                ..transformed_variable(&["trim", "shout"], None)
+            },
+        )]),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    match processor.validate_variables(&request) {
+        Err(Error::VariableValidation { variable, reason }) => {
+            assert_eq!(variable, "service_name");
+            assert!(reason.contains("'shout'"), "{reason}");
+        }
+        other => panic!("Expected VariableValidation error, got {other:?}"),
+    }
+}
+
+fn output_dir_request() -> TemplateProcessingRequest {
+    let mut variables = HashMap::new();
+    variables.insert("module_name".to_string(), "engine".to_string());
+
+    TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    }
+}
+
+#[test]
+fn test_process_template_does_not_write_by_default() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let output_dir = tempfile::TempDir::new().unwrap();
+    let files = vec![(
+        "src/{{module_name}}.rs.template".to_string(),
+        b"// {{module_name}}".to_vec(),
+    )];
+
+    let result = processor
+        .process_template(&files, &output_dir_request(), output_dir.path())
+        .expect("Processing should succeed");
+
+    assert_eq!(result.files[0].0, "src/engine.rs");
+    assert_eq!(std::fs::read_dir(output_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_process_template_writes_files_under_output_dir() {
+    let processor = TemplateProcessor::new()
+        .expect("Failed to create processor")
+        .with_write_output(true);
+    let output_dir = tempfile::TempDir::new().unwrap();
+    let files = vec![
+        (
+            "./src//{{module_name}}.rs.template".to_string(),
+            b"// {{module_name}}".to_vec(),
+        ),
+        ("README.md".to_string(), b"# {{module_name}}".to_vec()),
+    ];
+
+    let result = processor
+        .process_template(&files, &output_dir_request(), output_dir.path())
+        .expect("Processing should succeed");
+
+    let paths: Vec<&str> = result.files.iter().map(|(p, _)| p.as_str()).collect();
+    assert_eq!(paths, vec!["src/engine.rs", "README.md"]);
+    assert_eq!(
+        std::fs::read_to_string(output_dir.path().join("src/engine.rs")).unwrap(),
+        "// engine"
+    );
+    assert_eq!(
+        std::fs::read_to_string(output_dir.path().join("README.md")).unwrap(),
+        "# engine"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_process_template_rejects_symlink_out_of_output_dir() {
+    let processor = TemplateProcessor::new()
+        .expect("Failed to create processor")
+        .with_write_output(true);
+    let output_dir = tempfile::TempDir::new().unwrap();
+    let outside_dir = tempfile::TempDir::new().unwrap();
+    std::os::unix::fs::symlink(outside_dir.path(), output_dir.path().join("src")).unwrap();
+    let files = vec![("src/{{module_name}}.rs".to_string(), b"// escaped".to_vec())];
+
+    let result = processor.process_template(&files, &output_dir_request(), output_dir.path());
+
+    match result {
+        Err(Error::OutputPathOutsideDirectory { path, .. }) => {
+            assert_eq!(path, "src/engine.rs");
+        }
+        other => panic!("Expected OutputPathOutsideDirectory error, got {other:?}"),
+    }
+    assert!(!outside_dir.path().join("engine.rs").exists());
+}
+
+#[test]
+fn test_process_template_rejects_path_longer_than_limit() {
+    let processor = TemplateProcessor::new()
+        .expect("Failed to create processor")
+        .with_max_path_length(260);
+    let deep_dir = vec!["{{module_name}}-nested-directory"; 12].join("/");
+    let files = vec![
+        ("README.md".to_string(), b"# {{module_name}}".to_vec()),
+        (format!("{deep_dir}/lib.rs"), b"// deep".to_vec()),
+    ];
+
+    let result = processor.process_template(&files, &output_dir_request(), Path::new("."));
+
+    match result {
+        Err(Error::PathTooLong { path, length }) => {
+            assert!(path.starts_with("engine-nested-directory/"));
+            assert!(path.ends_with("/lib.rs"));
+            assert_eq!(length, path.chars().count());
+            assert!(length > 260);
+        }
+        other => panic!("Expected PathTooLong, got {other:?}"),
+    }
+
+    let unlimited = TemplateProcessor::new().expect("Failed to create processor");
+    assert!(unlimited
+        .process_template(&files, &output_dir_request(), Path::new("."))
+        .is_ok());
+}
+
+#[test]
+fn test_process_template_writes_nothing_when_later_path_is_too_long() {
+    let processor = TemplateProcessor::new()
+        .expect("Failed to create processor")
+        .with_write_output(true)
+        .with_max_path_length(260);
+    let output_dir = tempfile::TempDir::new().unwrap();
+    let deep_dir = vec!["{{module_name}}-nested-directory"; 12].join("/");
+    let files = vec![
+        ("README.md".to_string(), b"# {{module_name}}".to_vec()),
+        ("src/{{module_name}}.rs".to_string(), b"// engine".to_vec()),
+        (format!("{deep_dir}/lib.rs"), b"// deep".to_vec()),
+    ];
+
+    let result = processor.process_template(&files, &output_dir_request(), output_dir.path());
+
+    assert!(matches!(result, Err(Error::PathTooLong { .. })));
+    assert_eq!(std::fs::read_dir(output_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_resolve_output_path_stays_inside_output_dir() {
+    let output_dir = Path::new("/tmp/output");
+
+    assert_eq!(
+        resolve_output_path(output_dir, "src/lib.rs").unwrap(),
+        output_dir.join("src/lib.rs")
+    );
+    for path in ["../escape.txt", "src/../../escape.txt", "/etc/passwd", ""] {
+        assert!(
+            matches!(
+                resolve_output_path(output_dir, path),
+                Err(Error::OutputPathOutsideDirectory { .. })
+            ),
+            "'{path}' should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_process_template_detects_path_collision_after_substitution() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let files = vec![
+        (
+            "src/{{module_name}}.rs".to_string(),
+            b"// generated".to_vec(),
+        ),
+        ("./src/lib.rs".to_string(), b"// static".to_vec()),
+    ];
+
+    let mut variables = HashMap::new();
+    variables.insert("module_name".to_string(), "lib".to_string());
+
+    let request = TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let result = processor.process_template(&files, &request, Path::new("."));
+
+    match result {
+        Err(Error::PathCollision { path, sources }) => {
+            assert_eq!(path, "src/lib.rs");
+            assert_eq!(
+                sources,
+                vec![
+                    "src/{{module_name}}.rs".to_string(),
+                    "./src/lib.rs".to_string()
+                ]
+            );
+        }
+        other => panic!("Expected PathCollision error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_process_template_normalizes_output_paths() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let files = vec![("./docs//guide.md".to_string(), b"# Guide".to_vec())];
+
+    let request = TemplateProcessingRequest {
+        variables: HashMap::new(),
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let result = processor
+        .process_template(&files, &request, Path::new("."))
+        .expect("Processing should succeed");
+
+    assert_eq!(result.files.len(), 1);
+    assert_eq!(result.files[0].0, "docs/guide.md");
+}
+
+fn strict_variables_request(strict_variables: bool) -> TemplateProcessingRequest {
+    let mut variables = HashMap::new();
+    variables.insert("project_name".to_string(), "my-project".to_string());
+    variables.insert("secret_key".to_string(), "hunter2".to_string());
+
+    let mut variable_configs = HashMap::new();
+    variable_configs.insert(
+        "project_name".to_string(),
+        VariableConfig {
+            description: "Name of the project".to_string(),
+            example: None,
+            required: Some(true),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: None,
+            required_if: None,
+            secret: None,
+            transforms: vec![],
+        },
+    );
+
+    TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs,
+        templating_config: None,
+        strict_variables,
+    }
+}
+
+#[test]
+fn test_process_template_strict_variables_rejects_undeclared_reference() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![(
+        "README.md".to_string(),
+        b"# {{project_name}}\n\nKey: {{secret_key}}".to_vec(),
+    )];
+
+    let result =
+        processor.process_template(&files, &strict_variables_request(true), Path::new("."));
+
+    match result {
+        Err(Error::MissingVariables { variables, message }) => {
+            assert_eq!(variables, vec!["secret_key".to_string()]);
+            assert!(message.contains("not declared"));
+        }
+        other => panic!("Expected MissingVariables error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_process_template_undeclared_reference_allowed_without_strict_variables() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![(
+        "README.md".to_string(),
+        b"# {{project_name}}\n\nKey: {{secret_key}}".to_vec(),
+    )];
+
+    let result = processor
+        .process_template(&files, &strict_variables_request(false), Path::new("."))
+        .expect("Processing should succeed");
+
+    assert_eq!(result.files[0].1, b"# my-project\n\nKey: hunter2".to_vec());
+}
+
+fn render_validation_request() -> TemplateProcessingRequest {
+    let mut variables = HashMap::new();
+    variables.insert("project_name".to_string(), "demo".to_string());
+
+    TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs: HashMap::new(),
+        templating_config: None,
+        strict_variables: false,
+    }
+}
+
+#[test]
+fn test_validate_render_reports_all_failing_files() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![
+        ("README.md".to_string(), b"# {{project_name}}".to_vec()),
+        (
+            "broken_each.md".to_string(),
+            b"{{#each items}}{{this}}".to_vec(),
+        ),
+        ("src/lib.rs".to_string(), b"// {{project_name}}".to_vec()),
+        (
+            "broken_if.txt".to_string(),
+            b"{{#if project_name}}".to_vec(),
+        ),
+    ];
+
+    let failures = processor
+        .validate_render(&files, &render_validation_request())
+        .expect("Request should be valid");
+
+    let failed_paths: Vec<&str> = failures.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(failed_paths, vec!["broken_each.md", "broken_if.txt"]);
+    assert!(failures
+        .iter()
+        .all(|f| f.reason.contains("Template rendering failed")));
+}
+
+#[test]
+fn test_process_template_render_error_names_file_and_location() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![
+        ("README.md".to_string(), b"# {{project_name}}".to_vec()),
+        (
+            "src/lib.rs".to_string(),
+            b"// {{project_name}}\n{{snake_case}}\n".to_vec(),
+        ),
+    ];
+
+    let result = processor.process_template(&files, &render_validation_request(), Path::new("."));
+
+    match result {
+        Err(Error::VariableValidation { reason, .. }) => {
+            assert!(reason.contains("'src/lib.rs'"), "reason: {reason}");
+            assert!(reason.contains("line 2"), "reason: {reason}");
+            assert!(reason.contains("helper 'snake_case'"), "reason: {reason}");
+        }
+        other => panic!("Expected VariableValidation error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_validate_render_returns_empty_for_valid_template() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![
+        (
+            "{{project_name}}/README.md".to_string(),
+            b"# {{project_name}}".to_vec(),
+        ),
+        (
+            "logo.png".to_string(),
+            vec![0x89, 0x50, 0x4E, 0x47, 0x00, 0xFF],
+        ),
+    ];
+
+    let failures = processor
+        .validate_render(&files, &render_validation_request())
+        .expect("Request should be valid");
+
+    assert!(failures.is_empty(), "Unexpected failures: {failures:?}");
+}
+
+#[test]
+fn test_dry_render_reports_output_paths() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![
+        (
+            "{{project_name}}/README.md".to_string(),
+            b"# {{project_name}}".to_vec(),
+        ),
+        ("Cargo.toml.template".to_string(), b"name = \"x\"".to_vec()),
+        ("broken.md".to_string(), b"{{#each items}}".to_vec()),
+    ];
+
+    let dry_render = processor
+        .dry_render(&files, &render_validation_request())
+        .expect("Request should be valid");
+
+    assert_eq!(
+        dry_render.output_paths,
+        vec!["demo/README.md", "Cargo.toml", "broken.md"]
+    );
+    let failed_paths: Vec<&str> = dry_render
+        .failures
+        .iter()
+        .map(|f| f.path.as_str())
+        .collect();
+    assert_eq!(failed_paths, vec!["broken.md"]);
+}
+
+#[test]
+fn test_validate_render_skips_excluded_files() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![("docs/broken.md".to_string(), b"{{#each items}}".to_vec())];
+    let mut request = render_validation_request();
+    request.templating_config = Some(TemplatingConfig {
+        include_patterns: vec![],
+        exclude_patterns: vec!["docs/**".to_string()],
+        post_generation: vec![],
+        case_insensitive: false,
+        glob_recursive: false,
+        strict_variables: false,
+        max_path_length: None,
+    });
+
+    let failures = processor
+        .validate_render(&files, &request)
+        .expect("Request should be valid");
+
+    assert!(failures.is_empty());
+}
+
+#[test]
+fn test_render_string_substitutes_variables() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let rendered = processor
+        .render_string("Service for {{project_name}}", &render_validation_request())
+        .expect("String should render");
+
+    assert_eq!(rendered, "Service for demo");
+}
+
+#[test]
+fn test_render_string_reports_invalid_template() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let result = processor.render_string("{{#each items}}", &render_validation_request());
+
+    assert!(matches!(result, Err(Error::VariableValidation { .. })));
+}
+
+#[test]
+fn test_render_string_rejects_recursive_partial() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+
+    let result = processor.render_string(
+        "{{#*inline \"p\"}}{{#each items}}{{> p}}{{/each}}{{/inline}}{{> p}}",
+        &render_validation_request(),
+    );
+
+    match result {
+        Err(Error::RenderLimitExceeded { limit, message }) => {
+            assert_eq!(limit, "render_depth");
+            assert!(message.contains("'p'"));
+        }
+        other => panic!("Expected RenderLimitExceeded error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_secret_variable_is_rendered_but_redacted_in_debug_output() {
+    let processor = TemplateProcessor::new().expect("Failed to create processor");
+    let files = vec![(
+        "config.toml".to_string(),
+        b"token = \"{{api_token}}\"\nservice = \"{{service}}\"".to_vec(),
+    )];
+
+    let mut variables = HashMap::new();
+    variables.insert("api_token".to_string(), "tok-9f8e7d6c".to_string());
+    variables.insert("service".to_string(), "payments".to_string());
+
+    let mut variable_configs = HashMap::new();
+    variable_configs.insert(
+        "api_token".to_string(),
+        VariableConfig {
+            description: "API token".to_string(),
+            example: None,
+            required: Some(true),
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            options: None,
+            default: None,
+            required_if: None,
+            secret: Some(true),
+            transforms: vec![],
+        },
+    );
+
+    let request = TemplateProcessingRequest {
+        variables,
+        built_in_variables: HashMap::new(),
+        variable_configs,
+        templating_config: None,
+        strict_variables: false,
+    };
+
+    let debug_output = format!("{:?}", request);
+    assert!(!debug_output.contains("tok-9f8e7d6c"));
+    assert!(debug_output.contains(REDACTED_VARIABLE_VALUE));
+    assert!(debug_output.contains("payments"));
+
+    let result = processor
+        .process_template(&files, &request, Path::new("."))
+        .unwrap();
+    let content = String::from_utf8(result.files[0].1.clone()).unwrap();
+    assert_eq!(content, "token = \"tok-9f8e7d6c\"\nservice = \"payments\"");
+}
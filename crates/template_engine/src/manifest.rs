@@ -0,0 +1,83 @@
+//! # Template File Manifests
+//!
+//! A manifest describes the files of a template without rendering it: each
+//! file's path, size, and whether it is treated as text (and therefore has
+//! variable substitution applied) or copied unchanged as binary content.
+//!
+//! Manifests let users browse a template before creating a repository from it.
+//!
+//! ```rust
+//! use template_engine::{build_file_manifest, TemplateFileKind};
+//!
+//! let files = vec![
+//!     ("README.md".to_string(), b"# {{repo_name}}\n".to_vec()),
+//!     ("logo.png".to_string(), vec![0x89, b'P', b'N', b'G', 0x00]),
+//! ];
+//!
+//! let manifest = build_file_manifest(&files);
+//! assert_eq!(manifest[0].path, "README.md");
+//! assert_eq!(manifest[0].kind, TemplateFileKind::Text);
+//! assert_eq!(manifest[1].kind, TemplateFileKind::Binary);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "manifest_tests.rs"]
+mod tests;
+
+/// How a template file is handled during processing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateFileKind {
+    /// UTF-8 content; variables are substituted.
+    Text,
+
+    /// Any other content; copied unchanged.
+    Binary,
+}
+
+/// A single entry in a template file manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateFileEntry {
+    /// Path of the file relative to the template root.
+    pub path: String,
+
+    /// Size of the file content in bytes.
+    pub size: u64,
+
+    /// Whether the file is processed as text or copied as binary content.
+    pub kind: TemplateFileKind,
+}
+
+/// Builds a manifest for a set of fetched template files.
+///
+/// Entries are sorted by path so that the manifest is stable regardless of
+/// the order in which the fetcher returned the files.
+pub fn build_file_manifest(files: &[(String, Vec<u8>)]) -> Vec<TemplateFileEntry> {
+    let mut entries: Vec<TemplateFileEntry> = files
+        .iter()
+        .map(|(path, content)| TemplateFileEntry {
+            path: path.clone(),
+            size: content.len() as u64,
+            kind: if is_text_content(content) {
+                TemplateFileKind::Text
+            } else {
+                TemplateFileKind::Binary
+            },
+        })
+        .collect();
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    entries
+}
+
+/// Returns `true` if the content is treated as text during template processing.
+///
+/// Content is text when it is valid UTF-8 and contains no null bytes, which
+/// typically indicate binary data.
+pub fn is_text_content(content: &[u8]) -> bool {
+    match std::str::from_utf8(content) {
+        Ok(text) => !text.contains('\0'),
+        Err(_) => false,
+    }
+}
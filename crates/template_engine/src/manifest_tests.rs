@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn test_build_file_manifest_classifies_and_sorts_files() {
+    let files = vec![
+        ("src/main.rs".to_string(), b"fn main() {}\n".to_vec()),
+        (
+            "assets/logo.png".to_string(),
+            vec![0x89, b'P', b'N', b'G', 0x00, 0x1a],
+        ),
+        ("README.md".to_string(), b"# {{repo_name}}\n".to_vec()),
+    ];
+
+    let manifest = build_file_manifest(&files);
+
+    assert_eq!(
+        manifest,
+        vec![
+            TemplateFileEntry {
+                path: "README.md".to_string(),
+                size: 16,
+                kind: TemplateFileKind::Text,
+            },
+            TemplateFileEntry {
+                path: "assets/logo.png".to_string(),
+                size: 6,
+                kind: TemplateFileKind::Binary,
+            },
+            TemplateFileEntry {
+                path: "src/main.rs".to_string(),
+                size: 13,
+                kind: TemplateFileKind::Text,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_build_file_manifest_empty() {
+    assert!(build_file_manifest(&[]).is_empty());
+}
+
+#[test]
+fn test_is_text_content() {
+    assert!(is_text_content(b"plain text"));
+    assert!(is_text_content(b""));
+    assert!(!is_text_content(b"nul\0byte"));
+    assert!(!is_text_content(&[0xff, 0xfe, 0xfd]));
+}
+
+#[test]
+fn test_template_file_kind_serializes_lowercase() {
+    assert_eq!(
+        serde_json::to_string(&TemplateFileKind::Binary).unwrap(),
+        "\"binary\""
+    );
+}
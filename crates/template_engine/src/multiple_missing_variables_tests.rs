@@ -89,6 +89,7 @@ fn test_process_template_with_multiple_missing_variables() {
         built_in_variables: std::collections::HashMap::new(),
         variable_configs: std::collections::HashMap::new(),
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.process_template(&files, &request, std::path::Path::new("./output"));
@@ -131,6 +132,7 @@ fn test_no_error_when_all_variables_provided() {
         built_in_variables: std::collections::HashMap::new(),
         variable_configs: std::collections::HashMap::new(),
         templating_config: None,
+        strict_variables: false,
     };
 
     let result = processor.process_template(&files, &request, std::path::Path::new("./output"));
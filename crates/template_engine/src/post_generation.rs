@@ -0,0 +1,204 @@
+//! # Post-Generation Transforms
+//!
+//! Templates sometimes need light clean-up after variable substitution, such as
+//! tidying a `.gitignore` assembled from several conditional blocks. Running
+//! arbitrary scripts from a template would allow code execution on the host, so
+//! instead a template may opt in to a fixed set of built-in transforms that run
+//! in-process on the [`ProcessedTemplate`].
+//!
+//! ## Declaring Transforms
+//!
+//! Transforms are declared in the `[templating]` section of the template
+//! configuration. Unknown transform names are rejected when the configuration
+//! is parsed.
+//!
+//! ```toml
+//! [templating]
+//! post_generation = ["sort-gitignore", "format-toml"]
+//! ```
+//!
+//! Transforms never fail: files that are not valid UTF-8 are left untouched.
+
+use crate::ProcessedTemplate;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "post_generation_tests.rs"]
+mod tests;
+
+/// A built-in transform that may be applied to generated template output.
+///
+/// Each transform only touches the files it recognises (by file name or
+/// extension) and leaves all other files unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PostGenerationTransform {
+    /// Normalise whitespace in `*.toml` files.
+    ///
+    /// Removes trailing whitespace, writes key/value pairs as `key = value`,
+    /// collapses runs of blank lines and ensures a single trailing newline.
+    /// Comments, ordering and string contents are preserved.
+    FormatToml,
+
+    /// Sort and de-duplicate patterns in `.gitignore` files.
+    ///
+    /// Patterns are sorted within each block of consecutive pattern lines;
+    /// blank lines and comments separate blocks and stay where they are.
+    /// Blocks containing negated (`!`) patterns are left unchanged because
+    /// their order is significant.
+    SortGitignore,
+}
+
+impl PostGenerationTransform {
+    /// The name used for this transform in template configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FormatToml => "format-toml",
+            Self::SortGitignore => "sort-gitignore",
+        }
+    }
+
+    /// Applies this transform to every matching file in `processed`.
+    pub fn apply(&self, processed: &mut ProcessedTemplate) {
+        for (path, content) in processed.files.iter_mut() {
+            let file_name = path.rsplit('/').next().unwrap_or(path);
+            let transform: fn(&str) -> String = match self {
+                Self::FormatToml if file_name.ends_with(".toml") => format_toml,
+                Self::SortGitignore if file_name == ".gitignore" => sort_gitignore,
+                _ => continue,
+            };
+
+            if let Ok(text) = std::str::from_utf8(content) {
+                *content = transform(text).into_bytes();
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PostGenerationTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Applies each transform, in declaration order, to the processed template.
+pub fn apply_post_generation_transforms(
+    processed: &mut ProcessedTemplate,
+    transforms: &[PostGenerationTransform],
+) {
+    for transform in transforms {
+        transform.apply(processed);
+    }
+}
+
+/// Sorts and de-duplicates each block of consecutive `.gitignore` patterns.
+fn sort_gitignore(text: &str) -> String {
+    let mut output: Vec<&str> = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+
+    fn flush<'a>(block: &mut Vec<&'a str>, output: &mut Vec<&'a str>) {
+        if !block.iter().any(|line| line.starts_with('!')) {
+            block.sort_unstable();
+            block.dedup();
+        }
+        output.append(block);
+    }
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            flush(&mut block, &mut output);
+            output.push(line);
+        } else {
+            block.push(line);
+        }
+    }
+    flush(&mut block, &mut output);
+
+    let mut result = output.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Normalises whitespace in TOML text without parsing or reordering it.
+fn format_toml(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut multiline_delimiter: Option<&str> = None;
+
+    for line in text.lines() {
+        if let Some(delimiter) = multiline_delimiter {
+            // Multi-line string contents are kept verbatim.
+            if line.matches(delimiter).count() % 2 == 1 {
+                multiline_delimiter = None;
+            }
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+
+        let formatted = format_toml_key_value(line);
+        for delimiter in ["\"\"\"", "'''"] {
+            if formatted.matches(delimiter).count() % 2 == 1 {
+                multiline_delimiter = Some(delimiter);
+            }
+        }
+        lines.push(formatted);
+    }
+
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
+/// Rewrites `key=value` as `key = value`, ignoring `=` inside strings.
+fn format_toml_key_value(line: &str) -> String {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') || trimmed.starts_with('[') {
+        return line.to_string();
+    }
+
+    let mut quote: Option<char> = None;
+    for (index, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q && !is_escaped(line, index, q) => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => break,
+            (None, '=') => {
+                let key = line[..index].trim_end();
+                let value = line[index + 1..].trim_start();
+                return format!("{key} = {value}");
+            }
+            _ => {}
+        }
+    }
+
+    line.to_string()
+}
+
+/// Returns `true` when the quote at `index` is escaped by a preceding backslash.
+///
+/// Only basic (`"`) strings support escapes; literal (`'`) strings do not.
+fn is_escaped(line: &str, index: usize, quote: char) -> bool {
+    if quote != '"' {
+        return false;
+    }
+    let backslashes = line[..index]
+        .chars()
+        .rev()
+        .take_while(|c| *c == '\\')
+        .count();
+    backslashes % 2 == 1
+}
@@ -0,0 +1,124 @@
+//! Tests for post-generation transforms.
+
+use super::*;
+
+fn processed(files: &[(&str, &str)]) -> ProcessedTemplate {
+    ProcessedTemplate {
+        files: files
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.as_bytes().to_vec()))
+            .collect(),
+    }
+}
+
+fn content_of<'a>(processed: &'a ProcessedTemplate, path: &str) -> &'a str {
+    let (_, content) = processed
+        .files
+        .iter()
+        .find(|(p, _)| p == path)
+        .expect("file should exist");
+    std::str::from_utf8(content).unwrap()
+}
+
+#[test]
+fn test_sort_gitignore_reorders_generated_gitignore() {
+    let mut output = processed(&[
+        (
+            ".gitignore",
+            "# Build output\ntarget/\n*.log\nCargo.lock\n\n# Editors\n.vscode/\n.idea/\n.vscode/\n",
+        ),
+        ("README.md", "b\na\n"),
+    ]);
+
+    apply_post_generation_transforms(&mut output, &[PostGenerationTransform::SortGitignore]);
+
+    assert_eq!(
+        content_of(&output, ".gitignore"),
+        "# Build output\n*.log\nCargo.lock\ntarget/\n\n# Editors\n.idea/\n.vscode/\n"
+    );
+    assert_eq!(content_of(&output, "README.md"), "b\na\n");
+}
+
+#[test]
+fn test_sort_gitignore_applies_to_nested_gitignore() {
+    let mut output = processed(&[("web/.gitignore", "node_modules/\ndist/")]);
+
+    PostGenerationTransform::SortGitignore.apply(&mut output);
+
+    assert_eq!(
+        content_of(&output, "web/.gitignore"),
+        "dist/\nnode_modules/"
+    );
+}
+
+#[test]
+fn test_sort_gitignore_leaves_blocks_with_negations_unchanged() {
+    let original = "logs/*\n!logs/.keep\n\nz.txt\na.txt\n";
+    let mut output = processed(&[(".gitignore", original)]);
+
+    PostGenerationTransform::SortGitignore.apply(&mut output);
+
+    assert_eq!(
+        content_of(&output, ".gitignore"),
+        "logs/*\n!logs/.keep\n\na.txt\nz.txt\n"
+    );
+}
+
+#[test]
+fn test_format_toml_normalises_whitespace() {
+    let mut output = processed(&[(
+        "Cargo.toml",
+        "[package]   \nname=\"demo\"\nversion  =   \"0.1.0\"\n\n\n\n[dependencies]\nserde = { version=\"1\" }\nurl=\"a=b\" # key=value\n\n",
+    )]);
+
+    PostGenerationTransform::FormatToml.apply(&mut output);
+
+    assert_eq!(
+        content_of(&output, "Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = { version=\"1\" }\nurl = \"a=b\" # key=value\n"
+    );
+}
+
+#[test]
+fn test_format_toml_preserves_multiline_strings() {
+    let original = "description = \"\"\"\nkey=value\n\n\n  indented  \n\"\"\"\nname=\"x\"\n";
+    let mut output = processed(&[("config.toml", original)]);
+
+    PostGenerationTransform::FormatToml.apply(&mut output);
+
+    assert_eq!(
+        content_of(&output, "config.toml"),
+        "description = \"\"\"\nkey=value\n\n\n  indented  \n\"\"\"\nname = \"x\"\n"
+    );
+}
+
+#[test]
+fn test_transforms_skip_non_utf8_files() {
+    let mut output = ProcessedTemplate {
+        files: vec![(".gitignore".to_string(), vec![0xff, 0xfe, b'\n'])],
+    };
+
+    PostGenerationTransform::SortGitignore.apply(&mut output);
+
+    assert_eq!(output.files[0].1, vec![0xff, 0xfe, b'\n']);
+}
+
+#[test]
+fn test_transform_names_deserialize_from_kebab_case() {
+    let transforms: Vec<PostGenerationTransform> =
+        serde_json::from_str(r#"["format-toml", "sort-gitignore"]"#).unwrap();
+    assert_eq!(
+        transforms,
+        vec![
+            PostGenerationTransform::FormatToml,
+            PostGenerationTransform::SortGitignore
+        ]
+    );
+    assert_eq!(
+        PostGenerationTransform::SortGitignore.to_string(),
+        "sort-gitignore"
+    );
+
+    let unknown: Result<Vec<PostGenerationTransform>, _> = serde_json::from_str(r#"["run-shell"]"#);
+    assert!(unknown.is_err());
+}
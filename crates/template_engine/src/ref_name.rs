@@ -0,0 +1,114 @@
+//! Git reference name rules for branch names derived from user input.
+//!
+//! Branch names taken from requests or variables may contain characters git
+//! does not accept in a reference, such as spaces or `..`. Pushing such a
+//! branch fails late, after the repository was created. [`sanitize_ref_name`]
+//! turns any such name into a valid one, following the rules of
+//! `git check-ref-format --branch`:
+//!
+//! - no ASCII control characters, spaces, `~`, `^`, `:`, `?`, `*`, `[` or `\`
+//! - no `..` and no `@{`
+//! - no empty components, so no leading, trailing or repeated `/`
+//! - no component starting with `.` or ending with `.lock`
+//! - no trailing `.`
+//! - not `@` and not starting with `-`
+
+#[cfg(test)]
+#[path = "ref_name_tests.rs"]
+mod tests;
+
+/// Characters git never allows in a reference name.
+const FORBIDDEN_CHARS: [char; 8] = [' ', '~', '^', ':', '?', '*', '[', '\\'];
+
+/// Returns `true` if `name` is a valid git branch name.
+///
+/// # Examples
+///
+/// ```
+/// use template_engine::is_valid_ref_name;
+///
+/// assert!(is_valid_ref_name("feature/initial-content"));
+/// assert!(!is_valid_ref_name("feature..content"));
+/// assert!(!is_valid_ref_name("main.lock"));
+/// ```
+pub fn is_valid_ref_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "@"
+        && !name.starts_with('-')
+        && !name.ends_with('.')
+        && !name.contains("..")
+        && !name.contains("@{")
+        && !name
+            .chars()
+            .any(|c| c.is_ascii_control() || FORBIDDEN_CHARS.contains(&c))
+        && name.split('/').all(|component| {
+            !component.is_empty() && !component.starts_with('.') && !component.ends_with(".lock")
+        })
+}
+
+/// Turns `name` into a valid git branch name.
+///
+/// Forbidden characters are replaced with `-`, `..` is collapsed to `.`, and
+/// empty components, leading dots, `.lock` suffixes and trailing dots are
+/// removed. Valid names are returned unchanged.
+///
+/// Returns `None` when nothing usable remains, e.g. for `"..."` or `"@"`.
+///
+/// # Examples
+///
+/// ```
+/// use template_engine::sanitize_ref_name;
+///
+/// assert_eq!(
+///     sanitize_ref_name("my feature/v1..2").as_deref(),
+///     Some("my-feature/v1.2")
+/// );
+/// assert_eq!(sanitize_ref_name("release.lock").as_deref(), Some("release"));
+/// assert_eq!(sanitize_ref_name("//"), None);
+/// ```
+pub fn sanitize_ref_name(name: &str) -> Option<String> {
+    let replaced: String = name
+        .replace("@{", "-{")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_control() || FORBIDDEN_CHARS.contains(&c) {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let mut collapsed = replaced;
+    while collapsed.contains("..") {
+        collapsed = collapsed.replace("..", ".");
+    }
+
+    let components: Vec<&str> = collapsed
+        .split('/')
+        .map(sanitize_component)
+        .filter(|component| !component.is_empty())
+        .collect();
+
+    // Trimming `.` and `/` as well keeps the first component valid when it
+    // started with `-.` or `-/`.
+    let sanitized = components.join("/");
+    let sanitized = sanitized.trim_start_matches(['-', '.', '/']);
+    if sanitized.is_empty() || sanitized == "@" {
+        return None;
+    }
+    Some(sanitized.to_string())
+}
+
+/// Strips leading dots and trailing `.lock` suffixes and dots from one
+/// `/`-separated component.
+fn sanitize_component(component: &str) -> &str {
+    let mut component = component.trim_start_matches('.');
+    loop {
+        let trimmed = component.trim_end_matches('.').trim_end_matches(".lock");
+        if trimmed.len() == component.len() {
+            return component;
+        }
+        component = trimmed;
+    }
+}
@@ -0,0 +1,108 @@
+//! Tests for the ref_name module.
+
+use super::*;
+
+#[test]
+fn test_valid_names_are_unchanged() {
+    for name in [
+        "main",
+        "feature/initial-content",
+        "release-1.2",
+        "user@example",
+    ] {
+        assert!(is_valid_ref_name(name), "{} should be valid", name);
+        assert_eq!(sanitize_ref_name(name).as_deref(), Some(name));
+    }
+}
+
+#[test]
+fn test_invalid_names_are_rejected() {
+    for name in [
+        "",
+        "@",
+        "-main",
+        "main.",
+        "a..b",
+        "a@{b",
+        "with space",
+        "tab\there",
+        "a~b",
+        "a^b",
+        "a:b",
+        "a?b",
+        "a*b",
+        "a[b",
+        "a\\b",
+        "/main",
+        "main/",
+        "a//b",
+        ".hidden",
+        "feature/.hidden",
+        "main.lock",
+        "feature.lock/x",
+    ] {
+        assert!(!is_valid_ref_name(name), "{:?} should be invalid", name);
+    }
+}
+
+#[test]
+fn test_forbidden_characters_are_replaced() {
+    assert_eq!(
+        sanitize_ref_name("my feature~1^2:x?y*z[w\\v").as_deref(),
+        Some("my-feature-1-2-x-y-z-w-v")
+    );
+    assert_eq!(sanitize_ref_name("tab\there").as_deref(), Some("tab-here"));
+    assert_eq!(sanitize_ref_name("a@{b}").as_deref(), Some("a-{b}"));
+}
+
+#[test]
+fn test_dot_rules_are_enforced() {
+    assert_eq!(sanitize_ref_name("v1..2").as_deref(), Some("v1.2"));
+    assert_eq!(sanitize_ref_name("v1....2").as_deref(), Some("v1.2"));
+    assert_eq!(sanitize_ref_name("main.").as_deref(), Some("main"));
+    assert_eq!(sanitize_ref_name(".hidden").as_deref(), Some("hidden"));
+    assert_eq!(
+        sanitize_ref_name("release.lock").as_deref(),
+        Some("release")
+    );
+    assert_eq!(
+        sanitize_ref_name("feature.lock/x.lock.lock").as_deref(),
+        Some("feature/x")
+    );
+}
+
+#[test]
+fn test_slashes_and_leading_dashes_are_removed() {
+    assert_eq!(
+        sanitize_ref_name("/feature//x/").as_deref(),
+        Some("feature/x")
+    );
+    assert_eq!(sanitize_ref_name("--main").as_deref(), Some("main"));
+    assert_eq!(sanitize_ref_name("-.-/x").as_deref(), Some("x"));
+}
+
+#[test]
+fn test_names_without_valid_characters_yield_none() {
+    for name in ["", "@", "...", "//", "-", "-./"] {
+        assert_eq!(sanitize_ref_name(name), None, "{:?}", name);
+    }
+}
+
+#[test]
+fn test_sanitized_names_are_valid() {
+    for name in [
+        "my feature/v1..2",
+        " spaced  out ",
+        "-.-/.x.lock/",
+        "a@{b}..lock",
+        "feature/.hidden/",
+    ] {
+        let sanitized = sanitize_ref_name(name).expect("name has valid characters");
+        assert!(
+            is_valid_ref_name(&sanitized),
+            "{:?} sanitized to invalid {:?}",
+            name,
+            sanitized
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! # Template variable names
+//!
+//! Templates reference variables as `{{name}}`. Handlebars only resolves a
+//! simple expression when the name is an identifier, so a variable declared
+//! as `my var` or `1name` silently renders as an empty string. Declared
+//! names are therefore restricted to ASCII letters, digits and underscores,
+//! and may not start with a digit.
+//!
+//! The names of built-in variables are reserved: a template variable with
+//! the same name would be indistinguishable from the value RepoRoller
+//! provides.
+//!
+//! ```rust
+//! use template_engine::{is_built_in_variable_name, is_valid_variable_name};
+//!
+//! assert!(is_valid_variable_name("service_name"));
+//! assert!(!is_valid_variable_name("my var"));
+//! assert!(!is_valid_variable_name("1name"));
+//! assert!(is_built_in_variable_name("repo_name"));
+//! ```
+
+#[cfg(test)]
+#[path = "variable_names_tests.rs"]
+mod tests;
+
+/// Names of the variables the engine and the CI context provide.
+pub const BUILT_IN_VARIABLE_NAMES: [&str; 13] = [
+    "timestamp",
+    "timestamp_unix",
+    "user_login",
+    "user_name",
+    "org_name",
+    "repo_name",
+    "template_name",
+    "template_repo",
+    "default_branch",
+    "ci_commit_sha",
+    "ci_actor",
+    "ci_run_id",
+    "ci_workflow_context",
+];
+
+/// Returns `true` if `name` can be referenced from a Handlebars template.
+///
+/// A valid name is non-empty, starts with an ASCII letter or underscore and
+/// contains only ASCII letters, digits and underscores.
+pub fn is_valid_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `name` is the name of a built-in variable.
+pub fn is_built_in_variable_name(name: &str) -> bool {
+    BUILT_IN_VARIABLE_NAMES.contains(&name)
+}
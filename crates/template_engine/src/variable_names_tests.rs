@@ -0,0 +1,52 @@
+//! Tests for template variable name rules.
+
+use super::*;
+
+#[test]
+fn test_is_valid_variable_name_accepts_identifiers() {
+    for name in ["service_name", "_private", "Port8080", "x"] {
+        assert!(is_valid_variable_name(name), "'{name}' should be valid");
+    }
+}
+
+#[test]
+fn test_is_valid_variable_name_rejects_non_identifiers() {
+    for name in [
+        "",
+        "my var",
+        "1name",
+        "my-var",
+        "dotted.name",
+        "naïve",
+        "{{name}}",
+    ] {
+        assert!(!is_valid_variable_name(name), "'{name}' should be invalid");
+    }
+}
+
+#[test]
+fn test_built_in_names_include_engine_and_ci_variables() {
+    let params = crate::BuiltInVariablesParams {
+        repo_name: "repo",
+        org_name: "org",
+        template_name: "template",
+        template_repo: "org/template",
+        user_login: "octocat",
+        user_name: "Octo Cat",
+        default_branch: "main",
+    };
+    let mut generated = crate::TemplateProcessor::new()
+        .unwrap()
+        .generate_built_in_variables(&params);
+    generated.extend(crate::ci_variables_from(|name| match name {
+        "GITHUB_ACTIONS" => Some("true".to_string()),
+        "GITHUB_REF" => Some("refs/heads/main".to_string()),
+        _ => Some("value".to_string()),
+    }));
+
+    assert_eq!(generated.len(), BUILT_IN_VARIABLE_NAMES.len());
+    for name in generated.keys() {
+        assert!(is_built_in_variable_name(name), "'{name}' is not reserved");
+    }
+    assert!(!is_built_in_variable_name("service_name"));
+}
@@ -0,0 +1,146 @@
+//! # Variable transforms
+//!
+//! A template variable may declare a pipeline of built-in transforms that
+//! are applied, in order, to its value when the template context is built.
+//! This saves repeating the same helpers at every reference, e.g. writing
+//! `{{kebab_case (lower_case name)}}` throughout a template.
+//!
+//! ```toml
+//! [variables.service_name]
+//! description = "Service name"
+//! transforms = ["trim", "lower_case", "kebab_case"]
+//! ```
+//!
+//! The transforms behave like the Handlebars helpers of the same name.
+//! Unknown transform names are rejected when the template configuration is
+//! validated.
+//!
+//! ```rust
+//! use template_engine::apply_variable_transforms;
+//!
+//! let transforms = vec!["trim".to_string(), "kebab_case".to_string()];
+//! let value = apply_variable_transforms("  My Service ", &transforms).unwrap();
+//! assert_eq!(value, "my-service");
+//! ```
+
+use std::str::FromStr;
+
+#[cfg(test)]
+#[path = "variable_transforms_tests.rs"]
+mod tests;
+
+/// A built-in transform applied to a variable's value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableTransform {
+    /// Removes leading and trailing whitespace.
+    Trim,
+    /// Converts the value to lowercase.
+    LowerCase,
+    /// Converts the value to uppercase.
+    UpperCase,
+    /// Trims the value, lowercases ASCII letters and replaces spaces and
+    /// dashes with underscores.
+    SnakeCase,
+    /// Trims the value, lowercases ASCII letters and replaces spaces and
+    /// underscores with dashes.
+    KebabCase,
+    /// Uppercases the first character.
+    Capitalize,
+}
+
+impl VariableTransform {
+    /// Every built-in transform.
+    pub const ALL: [VariableTransform; 6] = [
+        Self::Trim,
+        Self::LowerCase,
+        Self::UpperCase,
+        Self::SnakeCase,
+        Self::KebabCase,
+        Self::Capitalize,
+    ];
+
+    /// The name used for this transform in template configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Trim => "trim",
+            Self::LowerCase => "lower_case",
+            Self::UpperCase => "upper_case",
+            Self::SnakeCase => "snake_case",
+            Self::KebabCase => "kebab_case",
+            Self::Capitalize => "capitalize",
+        }
+    }
+
+    /// Applies this transform to `value`.
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            Self::Trim => value.trim().to_string(),
+            Self::LowerCase => value.to_lowercase(),
+            Self::UpperCase => value.to_uppercase(),
+            Self::SnakeCase => replace_separators(value, &[' ', '-'], '_'),
+            Self::KebabCase => replace_separators(value, &[' ', '_'], '-'),
+            Self::Capitalize => {
+                let mut chars = value.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for VariableTransform {
+    type Err = String;
+
+    /// Parses a transform name, returning an error message naming the valid
+    /// transforms if it is unknown.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|transform| transform.name() == name)
+            .ok_or_else(|| {
+                let known: Vec<&str> = Self::ALL.iter().map(VariableTransform::name).collect();
+                format!(
+                    "Unknown variable transform '{}'; expected one of: {}",
+                    name,
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for VariableTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Applies the named transforms to `value`, in order.
+///
+/// # Errors
+///
+/// Returns the message of the first unknown transform name.
+pub fn apply_variable_transforms(value: &str, transforms: &[String]) -> Result<String, String> {
+    transforms
+        .iter()
+        .try_fold(value.to_string(), |value, name| {
+            Ok(name.parse::<VariableTransform>()?.apply(&value))
+        })
+}
+
+/// Trims `value`, lowercases ASCII letters and replaces each of `separators`
+/// with `replacement`.
+fn replace_separators(value: &str, separators: &[char], replacement: char) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| {
+            if separators.contains(&c) {
+                replacement
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
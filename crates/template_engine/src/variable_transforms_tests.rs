@@ -0,0 +1,42 @@
+//! Tests for the variable_transforms module.
+
+use super::*;
+
+#[test]
+fn test_transform_names_round_trip() {
+    for transform in VariableTransform::ALL {
+        assert_eq!(transform.name().parse::<VariableTransform>(), Ok(transform));
+    }
+}
+
+#[test]
+fn test_pipeline_applies_transforms_in_order() {
+    let transforms = vec![
+        "trim".to_string(),
+        "lower_case".to_string(),
+        "kebab_case".to_string(),
+    ];
+
+    let value = apply_variable_transforms("  Payments Service_API  ", &transforms).unwrap();
+
+    assert_eq!(value, "payments-service-api");
+}
+
+#[test]
+fn test_capitalize_after_snake_case() {
+    let transforms = vec!["snake_case".to_string(), "capitalize".to_string()];
+
+    let value = apply_variable_transforms("my service", &transforms).unwrap();
+
+    assert_eq!(value, "My_service");
+}
+
+#[test]
+fn test_unknown_transform_is_rejected() {
+    let transforms = vec!["trim".to_string(), "title_case".to_string()];
+
+    let error = apply_variable_transforms("value", &transforms).unwrap_err();
+
+    assert!(error.contains("'title_case'"), "{error}");
+    assert!(error.contains("kebab_case"), "{error}");
+}
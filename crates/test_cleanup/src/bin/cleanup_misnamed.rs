@@ -16,12 +16,11 @@
 //! - TEST_ORG: Organization name (e.g., "glitchgrove")
 
 use std::env;
-use test_cleanup::{CleanupConfig, RepositoryCleanup};
+use test_cleanup::{CleanupConfig, RepositoryCleanup, init_logging};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Initialize logging
-    test_cleanup::init_logging();
+    init_logging();
 
     // Get max age from command line args, default to 1 day
     let max_age_days: u64 = env::args().nth(1).and_then(|s| s.parse().ok()).unwrap_or(1);
@@ -13,12 +13,11 @@
 //! - TEST_ORG: Organization name (e.g., "glitchgrove")
 
 use std::env;
-use test_cleanup::{CleanupConfig, RepositoryCleanup};
+use test_cleanup::{CleanupConfig, RepositoryCleanup, init_logging};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Initialize logging
-    test_cleanup::init_logging();
+    init_logging();
 
     // Get PR number from command line args
     let pr_number: u32 = env::args()
@@ -5,11 +5,24 @@
 //! (from test code) and via CLI binaries.
 
 use anyhow::{Context, Result};
-use chrono::Utc;
-use github_client::GitHubClient;
+use chrono::{DateTime, Utc};
+use github_client::retry::is_rate_limit_error;
+use github_client::{GitHubClient, RetryPolicy};
+use octocrab::Octocrab;
 use std::env;
 use tracing::{debug, error, info, warn};
 
+/// Install the tracing subscriber used by the cleanup binaries.
+///
+/// The library only emits events, so each binary calls this at startup. The
+/// filter is read from `RUST_LOG`, and an existing subscriber is left in place.
+pub fn init_logging() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_target(false)
+        .try_init();
+}
+
 /// Configuration for cleanup operations loaded from environment variables.
 #[derive(Debug, Clone)]
 pub struct CleanupConfig {
@@ -54,6 +67,7 @@ impl CleanupConfig {
 pub struct RepositoryCleanup {
     client: GitHubClient,
     test_org: String,
+    retry_policy: RetryPolicy,
 }
 
 impl RepositoryCleanup {
@@ -64,7 +78,17 @@ impl RepositoryCleanup {
     /// * `client` - Authenticated GitHub client
     /// * `test_org` - Organization name where test repositories exist
     pub fn new(client: GitHubClient, test_org: String) -> Self {
-        Self { client, test_org }
+        Self {
+            client,
+            test_org,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Set the backoff policy used when GitHub rate-limits repository listing.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     /// Check if a repository name matches test repository naming patterns.
@@ -156,109 +180,57 @@ impl RepositoryCleanup {
         let installation_client = github_client::create_token_client(&installation_token)
             .context("Failed to create installation token client for cleanup")?;
 
-        // List repositories in the organization with pagination
-        let mut page = 1u32;
-        let per_page = 100u8;
-
-        info!(
-            org = self.test_org,
-            "Starting paginated repository listing (max {} repos per page)", per_page
-        );
-
-        loop {
-            debug!(
-                org = self.test_org,
-                page = page,
-                "Fetching page {} of repositories",
-                page
-            );
+        // List every repository before deleting any, so that deletions cannot
+        // shift later pages and rate-limit retries cannot repeat a deletion
+        let pager = OrgRepositoryPager {
+            client: installation_client,
+            org: self.test_org.clone(),
+        };
+        let repos = list_repositories(&pager, &self.test_org, &self.retry_policy).await?;
+
+        for repo in repos {
+            let repo_name = repo.name;
+            let is_template = repo.is_template;
+
+            // Check if this is a misnamed test repository
+            if !Self::is_misnamed_repository(&repo_name, is_template) {
+                continue;
+            }
 
-            let repos_result = installation_client
-                .orgs(&self.test_org)
-                .list_repos()
-                .per_page(per_page)
-                .page(page)
-                .send()
-                .await;
-
-            match repos_result {
-                Ok(repos) => {
-                    let repo_count = repos.items.len();
-                    debug!(
-                        org = self.test_org,
-                        page = page,
-                        count = repo_count,
-                        "Retrieved {} repositories on page {}",
-                        repo_count,
-                        page
+            // Age-based cleanup
+            let created_at = match repo.created_at {
+                Some(timestamp) => timestamp,
+                None => {
+                    warn!(
+                        repo_name = repo_name,
+                        "Repository has no creation timestamp, using epoch for age check"
                     );
+                    chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(Utc::now)
+                }
+            };
 
-                    if repo_count == 0 {
-                        info!(
-                            org = self.test_org,
-                            total_pages = page - 1,
-                            "No more repositories to process"
-                        );
-                        break;
-                    }
-
-                    for repo in repos.items {
-                        let repo_name = repo.name;
-                        let is_template = repo.is_template.unwrap_or(false);
-
-                        // Check if this is a misnamed test repository
-                        if !Self::is_misnamed_repository(&repo_name, is_template) {
-                            continue;
-                        }
-
-                        // Age-based cleanup
-                        let created_at = match repo.created_at {
-                            Some(timestamp) => timestamp,
-                            None => {
-                                warn!(
-                                    repo_name = repo_name,
-                                    "Repository has no creation timestamp, using epoch for age check"
-                                );
-                                chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(Utc::now)
-                            }
-                        };
-
-                        // Note: unlike cleanup_repositories_internal, this function
-                        // does not support the max_age_hours == 0 bypass (delete
-                        // regardless of age), because misnamed repositories should
-                        // never be intentionally kept and the bypass is not needed.
-                        if created_at < cutoff_time {
-                            info!(
-                                repo_name = repo_name,
-                                created_at = %created_at,
-                                cutoff_time = %cutoff_time,
-                                "Found misnamed test repository, attempting deletion"
-                            );
-
-                            if self.delete_repository(&repo_name).await.is_ok() {
-                                deleted_repos.push(repo_name);
-                            }
-                        } else {
-                            debug!(
-                                repo_name = repo_name,
-                                created_at = %created_at,
-                                age_hours = (Utc::now() - created_at).num_hours(),
-                                "Repository is too new, skipping"
-                            );
-                        }
-                    }
+            // Note: unlike cleanup_repositories_internal, this function
+            // does not support the max_age_hours == 0 bypass (delete
+            // regardless of age), because misnamed repositories should
+            // never be intentionally kept and the bypass is not needed.
+            if created_at < cutoff_time {
+                info!(
+                    repo_name = repo_name,
+                    created_at = %created_at,
+                    cutoff_time = %cutoff_time,
+                    "Found misnamed test repository, attempting deletion"
+                );
 
-                    page += 1;
-                }
-                Err(err) => {
-                    error!(
-                        org = self.test_org,
-                        page = page,
-                        error = %err,
-                        "Failed to list repositories"
-                    );
-                    return Err(err).context("Failed to list organization repositories");
+                if self.delete_repository(&repo_name).await.is_ok() {
+                    deleted_repos.push(repo_name);
                 }
+            } else {
+                debug!(
+                    repo_name = repo_name,
+                    created_at = %created_at,
+                    age_hours = (Utc::now() - created_at).num_hours(),
+                    "Repository is too new, skipping"
+                );
             }
         }
 
@@ -311,136 +283,84 @@ impl RepositoryCleanup {
         let installation_client = github_client::create_token_client(&installation_token)
             .context("Failed to create installation token client for cleanup")?;
 
-        // List repositories in the organization with pagination
-        let mut page = 1u32;
-        let per_page = 100u8;
+        // List every repository before deleting any, so that deletions cannot
+        // shift later pages and rate-limit retries cannot repeat a deletion
+        let pager = OrgRepositoryPager {
+            client: installation_client,
+            org: self.test_org.clone(),
+        };
+        let repos = list_repositories(&pager, &self.test_org, &self.retry_policy).await?;
 
-        info!(
-            org = self.test_org,
-            "Starting paginated repository listing (max {} repos per page)", per_page
-        );
+        for repo in repos {
+            let repo_name = repo.name;
 
-        loop {
-            debug!(
-                org = self.test_org,
-                page = page,
-                "Fetching page {} of repositories",
-                page
-            );
+            // Check if this is a test repository
+            if !Self::is_test_repository(&repo_name) {
+                continue;
+            }
 
-            let repos_result = installation_client
-                .orgs(&self.test_org)
-                .list_repos()
-                .per_page(per_page)
-                .page(page)
-                .send()
-                .await;
-
-            match repos_result {
-                Ok(repos) => {
-                    let repo_count = repos.items.len();
+            // If filtering by PR, check if this repo matches the PR pattern
+            if let Some(pr) = pr_number {
+                let pr_pattern = format!("-pr{}-", pr);
+                if !repo_name.contains(&pr_pattern) {
                     debug!(
-                        org = self.test_org,
-                        page = page,
-                        count = repo_count,
-                        "Retrieved {} repositories on page {}",
-                        repo_count,
-                        page
+                        repo_name = repo_name,
+                        pr_number = pr,
+                        "Skipping repository - not from PR {}",
+                        pr
                     );
+                    continue;
+                }
+
+                info!(
+                    repo_name = repo_name,
+                    pr_number = pr,
+                    "Found PR {} repository, attempting deletion",
+                    pr
+                );
 
-                    if repo_count == 0 {
-                        info!(
-                            org = self.test_org,
-                            total_pages = page - 1,
-                            "No more repositories to process"
+                if self.delete_repository(&repo_name).await.is_ok() {
+                    deleted_repos.push(repo_name);
+                }
+            } else {
+                // Age-based cleanup
+                let created_at = match repo.created_at {
+                    Some(timestamp) => timestamp,
+                    None => {
+                        warn!(
+                            repo_name = repo_name,
+                            "Repository has no creation timestamp, using epoch for age check"
                         );
-                        break;
+                        chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(Utc::now)
                     }
+                };
+
+                // When max_age_hours == 0 the caller means "delete
+                // ALL test repos regardless of age" (no age filter).
+                // This avoids a clock-skew false-negative where
+                // GitHub's server clock is slightly ahead of the
+                // runner clock, making a just-created repo appear
+                // to have a created_at in the future.
+                let is_old_enough = max_age_hours == 0 || created_at < cutoff_time;
+
+                if is_old_enough {
+                    info!(
+                        repo_name = repo_name,
+                        created_at = %created_at,
+                        cutoff_time = %cutoff_time,
+                        "Found orphaned test repository, attempting deletion"
+                    );
 
-                    for repo in repos.items {
-                        let repo_name = repo.name;
-
-                        // Check if this is a test repository
-                        if !Self::is_test_repository(&repo_name) {
-                            continue;
-                        }
-
-                        // If filtering by PR, check if this repo matches the PR pattern
-                        if let Some(pr) = pr_number {
-                            let pr_pattern = format!("-pr{}-", pr);
-                            if !repo_name.contains(&pr_pattern) {
-                                debug!(
-                                    repo_name = repo_name,
-                                    pr_number = pr,
-                                    "Skipping repository - not from PR {}",
-                                    pr
-                                );
-                                continue;
-                            }
-
-                            info!(
-                                repo_name = repo_name,
-                                pr_number = pr,
-                                "Found PR {} repository, attempting deletion",
-                                pr
-                            );
-
-                            if self.delete_repository(&repo_name).await.is_ok() {
-                                deleted_repos.push(repo_name);
-                            }
-                        } else {
-                            // Age-based cleanup
-                            let created_at = match repo.created_at {
-                                Some(timestamp) => timestamp,
-                                None => {
-                                    warn!(
-                                        repo_name = repo_name,
-                                        "Repository has no creation timestamp, using epoch for age check"
-                                    );
-                                    chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(Utc::now)
-                                }
-                            };
-
-                            // When max_age_hours == 0 the caller means "delete
-                            // ALL test repos regardless of age" (no age filter).
-                            // This avoids a clock-skew false-negative where
-                            // GitHub's server clock is slightly ahead of the
-                            // runner clock, making a just-created repo appear
-                            // to have a created_at in the future.
-                            let is_old_enough = max_age_hours == 0 || created_at < cutoff_time;
-
-                            if is_old_enough {
-                                info!(
-                                    repo_name = repo_name,
-                                    created_at = %created_at,
-                                    cutoff_time = %cutoff_time,
-                                    "Found orphaned test repository, attempting deletion"
-                                );
-
-                                if self.delete_repository(&repo_name).await.is_ok() {
-                                    deleted_repos.push(repo_name);
-                                }
-                            } else {
-                                debug!(
-                                    repo_name = repo_name,
-                                    created_at = %created_at,
-                                    age_hours = (Utc::now() - created_at).num_hours(),
-                                    "Repository is too new, skipping"
-                                );
-                            }
-                        }
+                    if self.delete_repository(&repo_name).await.is_ok() {
+                        deleted_repos.push(repo_name);
                     }
-
-                    page += 1;
-                }
-                Err(err) => {
-                    error!(
-                        org = self.test_org,
-                        page = page,
-                        error = %err,
-                        "Failed to list repositories"
+                } else {
+                    debug!(
+                        repo_name = repo_name,
+                        created_at = %created_at,
+                        age_hours = (Utc::now() - created_at).num_hours(),
+                        "Repository is too new, skipping"
                     );
-                    return Err(err).context("Failed to list organization repositories");
                 }
             }
         }
@@ -503,16 +423,152 @@ impl RepositoryCleanup {
     }
 }
 
-/// Initialize logging for cleanup operations.
+/// Number of repositories requested per page when listing an organization.
+const REPOSITORIES_PER_PAGE: u8 = 100;
+
+/// Repository fields used to decide whether a repository should be deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ListedRepository {
+    name: String,
+    is_template: bool,
+    created_at: Option<DateTime<Utc>>,
+}
+
+/// Failure to fetch a single page of repositories.
+#[derive(Debug)]
+enum ListPageError {
+    /// GitHub rate-limited the request; the same page may be retried later.
+    RateLimited(anyhow::Error),
+    /// Any other failure, which is not retried.
+    Failed(anyhow::Error),
+}
+
+/// Source of paginated organization repository listings.
+trait RepositoryPager {
+    /// Fetch one page of repositories. Page numbers start at 1.
+    async fn fetch_page(
+        &self,
+        page: u32,
+        per_page: u8,
+    ) -> Result<Vec<ListedRepository>, ListPageError>;
+}
+
+/// Lists an organization's repositories through the GitHub REST API.
+struct OrgRepositoryPager {
+    client: Octocrab,
+    org: String,
+}
+
+impl RepositoryPager for OrgRepositoryPager {
+    async fn fetch_page(
+        &self,
+        page: u32,
+        per_page: u8,
+    ) -> Result<Vec<ListedRepository>, ListPageError> {
+        let result = self
+            .client
+            .orgs(&self.org)
+            .list_repos()
+            .per_page(per_page)
+            .page(page)
+            .send()
+            .await;
+
+        match result {
+            Ok(repos) => Ok(repos
+                .items
+                .into_iter()
+                .map(|repo| ListedRepository {
+                    name: repo.name,
+                    is_template: repo.is_template.unwrap_or(false),
+                    created_at: repo.created_at,
+                })
+                .collect()),
+            Err(err) if is_rate_limit_error(&err) => Err(ListPageError::RateLimited(err.into())),
+            Err(err) => Err(ListPageError::Failed(err.into())),
+        }
+    }
+}
+
+/// Fetch every page of repositories, backing off when rate-limited.
 ///
-/// Sets up tracing with appropriate formatting for CLI use.
-pub fn init_logging() {
-    use tracing_subscriber::EnvFilter;
+/// A rate-limited page is retried after the delay given by `retry_policy`;
+/// paging then resumes from that same page. The retry budget is reset after
+/// each successful page. Any other failure, or running out of retries, ends
+/// the listing with an error.
+async fn list_repositories<P: RepositoryPager>(
+    pager: &P,
+    org: &str,
+    retry_policy: &RetryPolicy,
+) -> Result<Vec<ListedRepository>> {
+    let mut repositories = Vec::new();
+    let mut page = 1u32;
+    let mut retries = 0u32;
+
+    info!(
+        org = org,
+        "Starting paginated repository listing (max {} repos per page)", REPOSITORIES_PER_PAGE
+    );
+
+    loop {
+        debug!(
+            org = org,
+            page = page,
+            "Fetching page {} of repositories",
+            page
+        );
 
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
-        .with_target(false)
-        .init();
+        match pager.fetch_page(page, REPOSITORIES_PER_PAGE).await {
+            Ok(items) => {
+                let repo_count = items.len();
+                debug!(
+                    org = org,
+                    page = page,
+                    count = repo_count,
+                    "Retrieved {} repositories on page {}",
+                    repo_count,
+                    page
+                );
+
+                if repo_count == 0 {
+                    info!(
+                        org = org,
+                        total_pages = page - 1,
+                        "No more repositories to process"
+                    );
+                    break;
+                }
+
+                repositories.extend(items);
+                page += 1;
+                retries = 0;
+            }
+            Err(ListPageError::RateLimited(err)) if retries < retry_policy.max_retries => {
+                let delay = retry_policy.backoff_for(retries);
+                retries += 1;
+                warn!(
+                    org = org,
+                    page = page,
+                    retry = retries,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %err,
+                    "Rate limited while listing repositories, retrying page after backoff"
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(ListPageError::RateLimited(err)) | Err(ListPageError::Failed(err)) => {
+                error!(
+                    org = org,
+                    page = page,
+                    error = %err,
+                    "Failed to list repositories"
+                );
+                return Err(err).context("Failed to list organization repositories");
+            }
+        }
+    }
+
+    Ok(repositories)
 }
 
 #[cfg(test)]
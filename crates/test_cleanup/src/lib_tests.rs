@@ -54,3 +54,123 @@ fn test_is_test_repository_edge_cases() {
         "E2E-repo-roller-main"
     ));
 }
+
+/// Pager that serves fixed pages and rate-limits a chosen page a set number of times.
+struct MockPager {
+    pages: Vec<Vec<ListedRepository>>,
+    rate_limited_page: u32,
+    rate_limit_count: std::sync::Mutex<u32>,
+    failing_page: Option<u32>,
+    requested_pages: std::sync::Mutex<Vec<u32>>,
+}
+
+impl MockPager {
+    fn new(pages: Vec<Vec<ListedRepository>>) -> Self {
+        Self {
+            pages,
+            rate_limited_page: 0,
+            rate_limit_count: std::sync::Mutex::new(0),
+            failing_page: None,
+            requested_pages: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn rate_limit(mut self, page: u32, times: u32) -> Self {
+        self.rate_limited_page = page;
+        self.rate_limit_count = std::sync::Mutex::new(times);
+        self
+    }
+
+    fn fail_on(mut self, page: u32) -> Self {
+        self.failing_page = Some(page);
+        self
+    }
+
+    fn requested_pages(&self) -> Vec<u32> {
+        self.requested_pages.lock().unwrap().clone()
+    }
+}
+
+impl RepositoryPager for MockPager {
+    async fn fetch_page(
+        &self,
+        page: u32,
+        _per_page: u8,
+    ) -> Result<Vec<ListedRepository>, ListPageError> {
+        self.requested_pages.lock().unwrap().push(page);
+
+        if self.failing_page == Some(page) {
+            return Err(ListPageError::Failed(anyhow::anyhow!("404 Not Found")));
+        }
+
+        if page == self.rate_limited_page {
+            let mut remaining = self.rate_limit_count.lock().unwrap();
+            if *remaining > 0 {
+                *remaining -= 1;
+                return Err(ListPageError::RateLimited(anyhow::anyhow!(
+                    "429 Too Many Requests"
+                )));
+            }
+        }
+
+        Ok(self
+            .pages
+            .get(page as usize - 1)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+fn listed(name: &str) -> ListedRepository {
+    ListedRepository {
+        name: name.to_string(),
+        is_template: false,
+        created_at: None,
+    }
+}
+
+fn immediate_retry_policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        initial_backoff: std::time::Duration::ZERO,
+        max_backoff: std::time::Duration::ZERO,
+    }
+}
+
+#[tokio::test]
+async fn test_list_repositories_resumes_rate_limited_page() {
+    let pager = MockPager::new(vec![
+        vec![listed("repo-a"), listed("repo-b")],
+        vec![listed("repo-c")],
+    ])
+    .rate_limit(2, 1);
+
+    let repos = list_repositories(&pager, "test-org", &immediate_retry_policy(3))
+        .await
+        .expect("listing should succeed after the retry");
+
+    let names: Vec<&str> = repos.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["repo-a", "repo-b", "repo-c"]);
+    assert_eq!(pager.requested_pages(), vec![1, 2, 2, 3]);
+}
+
+#[tokio::test]
+async fn test_list_repositories_fails_when_retries_exhausted() {
+    let pager =
+        MockPager::new(vec![vec![listed("repo-a")], vec![listed("repo-b")]]).rate_limit(2, 10);
+
+    let result = list_repositories(&pager, "test-org", &immediate_retry_policy(2)).await;
+
+    assert!(result.is_err());
+    assert_eq!(pager.requested_pages(), vec![1, 2, 2, 2]);
+}
+
+#[tokio::test]
+async fn test_list_repositories_does_not_retry_other_failures() {
+    let pager = MockPager::new(vec![vec![listed("repo-a")]]).fail_on(1);
+
+    let result = list_repositories(&pager, "test-org", &immediate_retry_policy(3)).await;
+
+    assert!(result.is_err());
+    assert_eq!(pager.requested_pages(), vec![1]);
+}
@@ -14,31 +14,16 @@ use uuid::Uuid;
 /// Returns:
 /// - `pr{number}` for pull request workflows (e.g., "pr123")
 /// - `main` for pushes to main/master branch
+/// - the branch name, made a valid git ref name (see
+///   [`template_engine::sanitize_ref_name`]) with `/` replaced by `-`, for
+///   other branch pushes
 /// - `local` for local development
 ///
 /// Uses GITHUB_REF environment variable which contains:
 /// - `refs/pull/{number}/merge` for pull requests
 /// - `refs/heads/{branch}` for branch pushes
 pub fn get_workflow_context() -> String {
-    // Check if running in GitHub Actions PR context
-    if let Ok(github_ref) = env::var("GITHUB_REF") {
-        if github_ref.starts_with("refs/pull/") {
-            // Extract PR number from refs/pull/{number}/merge
-            if let Some(pr_num) = github_ref.split('/').nth(2) {
-                return format!("pr{}", pr_num);
-            }
-        } else if let Some(branch) = github_ref.strip_prefix("refs/heads/") {
-            // Use 'main' for main/master branches
-            if branch == "main" || branch == "master" {
-                return "main".to_string();
-            }
-            // Use sanitized branch name for other branches (replace / with -)
-            return branch.replace('/', "-");
-        }
-    }
-
-    // Fallback for local development
-    "local".to_string()
+    template_engine::workflow_context_from_ref(env::var("GITHUB_REF").ok().as_deref())
 }
 
 /// Generate a unique test repository name following the naming convention.
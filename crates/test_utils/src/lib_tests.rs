@@ -72,6 +72,19 @@ fn test_get_workflow_context_feature_branch() {
     }
 }
 
+#[test]
+#[serial]
+fn test_get_workflow_context_invalid_branch_name() {
+    unsafe {
+        std::env::set_var("GITHUB_REF", "refs/heads/fix: v1..2");
+    }
+    let context = get_workflow_context();
+    assert_eq!(context, "fix--v1.2");
+    unsafe {
+        std::env::remove_var("GITHUB_REF");
+    }
+}
+
 #[test]
 #[serial]
 fn test_get_workflow_context_local() {